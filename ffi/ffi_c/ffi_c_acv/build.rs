@@ -0,0 +1,20 @@
+// Copyright 2022 WeDPR Lab Project Authors. Licensed under Apache-2.0.
+
+//! Generates the C/C++ header file of ACV FFI as `ffi_c_acv.h` in the build
+//! output directory.
+
+extern crate cbindgen;
+
+use std::{env, path::Path};
+
+fn main() {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+    cbindgen::Builder::new()
+        .with_crate(&crate_dir)
+        .with_language(cbindgen::Language::C)
+        .with_include_guard("WEDPR_FFI_C_ACV_H")
+        .generate()
+        .expect("Unable to generate the C/C++ header file.")
+        .write_to_file(Path::new(&out_dir).join("ffi_c_acv.h"));
+}
@@ -0,0 +1,377 @@
+// Copyright 2022 WeDPR Lab Project Authors. Licensed under Apache-2.0.
+
+//! Library of macros and functions for FFI of a poll coordinator in ACV
+//! solution, targeting C/C++ compatible architectures (including iOS).
+
+// C/C++ FFI: C-style interfaces will be generated.
+
+use libc::{c_char, c_long, c_uint};
+use protobuf::{self, Message};
+use std::{ffi::CString, panic, ptr};
+use wedpr_ffi_common::utils::{
+    bytes_to_string, c_char_pointer_to_string, string_to_bytes,
+};
+
+use wedpr_s_protos::generated::acv::{
+    CandidateList, CounterParametersStorage, DecryptedResultPartStorage,
+    PollParametersStorage, RegistrationRequest, VoteStorage,
+};
+
+/// C interface for 'wedpr_acv_make_poll_parameters'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_make_poll_parameters(
+    candidate_list_cstring: *mut c_char,
+    counter_parameters_cstring: *mut c_char,
+    secret_key_cstring: *mut c_char,
+) -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let candidate_list_pb = c_safe_c_char_pointer_to_proto!(
+            candidate_list_cstring,
+            CandidateList
+        );
+        let counter_parameters_pb = c_safe_c_char_pointer_to_proto!(
+            counter_parameters_cstring,
+            CounterParametersStorage
+        );
+        let secret_key = c_safe_string_to_bytes!(
+            c_safe_c_char_pointer_to_string!(secret_key_cstring)
+        );
+
+        let poll_parameters =
+            match wedpr_s_anonymous_ciphertext_voting::coordinator::make_poll_parameters(
+                &candidate_list_pb,
+                &counter_parameters_pb,
+                &secret_key,
+            ) {
+                Ok(v) => v,
+                Err(_) => return ptr::null_mut(),
+            };
+        c_safe_proto_to_c_char_pointer!(poll_parameters)
+    });
+    c_safe_return!(result)
+}
+
+/// C interface for 'wedpr_acv_make_poll_parameters_unlisted'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_make_poll_parameters_unlisted(
+    candidate_list_cstring: *mut c_char,
+    counter_parameters_cstring: *mut c_char,
+    secret_key_cstring: *mut c_char,
+    min_unlisted_candidate_id: c_uint,
+    max_unlisted_candidate_id: c_uint,
+) -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let candidate_list_pb = c_safe_c_char_pointer_to_proto!(
+            candidate_list_cstring,
+            CandidateList
+        );
+        let counter_parameters_pb = c_safe_c_char_pointer_to_proto!(
+            counter_parameters_cstring,
+            CounterParametersStorage
+        );
+        let secret_key = c_safe_string_to_bytes!(
+            c_safe_c_char_pointer_to_string!(secret_key_cstring)
+        );
+
+        let poll_parameters =
+            match wedpr_s_anonymous_ciphertext_voting::coordinator::make_poll_parameters_unlisted(
+                &candidate_list_pb,
+                &counter_parameters_pb,
+                &secret_key,
+                min_unlisted_candidate_id as u32,
+                max_unlisted_candidate_id as u32,
+            ) {
+                Ok(v) => v,
+                Err(_) => return ptr::null_mut(),
+            };
+        c_safe_proto_to_c_char_pointer!(poll_parameters)
+    });
+    c_safe_return!(result)
+}
+
+/// C interface for 'wedpr_acv_certify_voter'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_certify_voter(
+    poll_parameters_cstring: *mut c_char,
+    secret_key_cstring: *mut c_char,
+    registration_request_cstring: *mut c_char,
+    voter_weight: c_uint,
+) -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let poll_parameters_pb = c_safe_c_char_pointer_to_proto!(
+            poll_parameters_cstring,
+            PollParametersStorage
+        );
+        let secret_key = c_safe_string_to_bytes!(
+            c_safe_c_char_pointer_to_string!(secret_key_cstring)
+        );
+        let registration_request_pb = c_safe_c_char_pointer_to_proto!(
+            registration_request_cstring,
+            RegistrationRequest
+        );
+
+        let registration_response =
+            match wedpr_s_anonymous_ciphertext_voting::coordinator::certify_voter(
+                &poll_parameters_pb,
+                &secret_key,
+                &registration_request_pb,
+                voter_weight as u32,
+            ) {
+                Ok(v) => v,
+                Err(_) => return ptr::null_mut(),
+            };
+        c_safe_proto_to_c_char_pointer!(registration_response)
+    });
+    c_safe_return!(result)
+}
+
+/// C interface for 'wedpr_acv_certify_unbounded_voter'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_certify_unbounded_voter(
+    poll_parameters_cstring: *mut c_char,
+    secret_key_cstring: *mut c_char,
+    registration_request_cstring: *mut c_char,
+    voter_weight: c_uint,
+) -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let poll_parameters_pb = c_safe_c_char_pointer_to_proto!(
+            poll_parameters_cstring,
+            PollParametersStorage
+        );
+        let secret_key = c_safe_string_to_bytes!(
+            c_safe_c_char_pointer_to_string!(secret_key_cstring)
+        );
+        let registration_request_pb = c_safe_c_char_pointer_to_proto!(
+            registration_request_cstring,
+            RegistrationRequest
+        );
+
+        let registration_response =
+            match wedpr_s_anonymous_ciphertext_voting::coordinator::certify_unbounded_voter(
+                &poll_parameters_pb,
+                &secret_key,
+                &registration_request_pb,
+                voter_weight as u32,
+            ) {
+                Ok(v) => v,
+                Err(_) => return ptr::null_mut(),
+            };
+        c_safe_proto_to_c_char_pointer!(registration_response)
+    });
+    c_safe_return!(result)
+}
+
+/// C interface for 'wedpr_acv_aggregate_vote_sum_response'. It returns the
+/// updated vote sum, or a null pointer if the vote part is not aggregated.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_aggregate_vote_sum_response(
+    poll_parameters_cstring: *mut c_char,
+    vote_part_cstring: *mut c_char,
+    vote_sum_cstring: *mut c_char,
+) -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let poll_parameters_pb = c_safe_c_char_pointer_to_proto!(
+            poll_parameters_cstring,
+            PollParametersStorage
+        );
+        let vote_part_pb =
+            c_safe_c_char_pointer_to_proto!(vote_part_cstring, VoteStorage);
+        let mut vote_sum_pb =
+            c_safe_c_char_pointer_to_proto!(vote_sum_cstring, VoteStorage);
+
+        match wedpr_s_anonymous_ciphertext_voting::coordinator::aggregate_vote_sum_response(
+            &poll_parameters_pb,
+            &vote_part_pb,
+            &mut vote_sum_pb,
+        ) {
+            Ok(true) => (),
+            _ => return ptr::null_mut(),
+        };
+        c_safe_proto_to_c_char_pointer!(vote_sum_pb)
+    });
+    c_safe_return!(result)
+}
+
+/// C interface for 'wedpr_acv_aggregate_vote_sum_response_unlisted'. It returns
+/// the updated vote sum, or a null pointer if the vote part is not aggregated.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_aggregate_vote_sum_response_unlisted(
+    poll_parameters_cstring: *mut c_char,
+    vote_part_cstring: *mut c_char,
+    vote_sum_cstring: *mut c_char,
+) -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let poll_parameters_pb = c_safe_c_char_pointer_to_proto!(
+            poll_parameters_cstring,
+            PollParametersStorage
+        );
+        let vote_part_pb =
+            c_safe_c_char_pointer_to_proto!(vote_part_cstring, VoteStorage);
+        let mut vote_sum_pb =
+            c_safe_c_char_pointer_to_proto!(vote_sum_cstring, VoteStorage);
+
+        match wedpr_s_anonymous_ciphertext_voting::coordinator::aggregate_vote_sum_response_unlisted(
+            &poll_parameters_pb,
+            &vote_part_pb,
+            &mut vote_sum_pb,
+        ) {
+            Ok(true) => (),
+            _ => return ptr::null_mut(),
+        };
+        c_safe_proto_to_c_char_pointer!(vote_sum_pb)
+    });
+    c_safe_return!(result)
+}
+
+/// C interface for 'wedpr_acv_aggregate_decrypted_part_sum'. It returns the
+/// updated aggregated decrypted result, or a null pointer if the partially
+/// decrypted result sent by the counter is not aggregated.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_aggregate_decrypted_part_sum(
+    poll_parameters_cstring: *mut c_char,
+    counter_id_cstring: *mut c_char,
+    encrypted_vote_sum_cstring: *mut c_char,
+    partially_decrypted_result_cstring: *mut c_char,
+    aggregated_decrypted_result_cstring: *mut c_char,
+) -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let poll_parameters_pb = c_safe_c_char_pointer_to_proto!(
+            poll_parameters_cstring,
+            PollParametersStorage
+        );
+        let counter_id = c_safe_c_char_pointer_to_string!(counter_id_cstring);
+        let encrypted_vote_sum_pb = c_safe_c_char_pointer_to_proto!(
+            encrypted_vote_sum_cstring,
+            VoteStorage
+        );
+        let partially_decrypted_result_pb = c_safe_c_char_pointer_to_proto!(
+            partially_decrypted_result_cstring,
+            DecryptedResultPartStorage
+        );
+        let mut aggregated_decrypted_result_pb = c_safe_c_char_pointer_to_proto!(
+            aggregated_decrypted_result_cstring,
+            DecryptedResultPartStorage
+        );
+
+        match wedpr_s_anonymous_ciphertext_voting::coordinator::aggregate_decrypted_part_sum(
+            &poll_parameters_pb,
+            &counter_id,
+            &encrypted_vote_sum_pb,
+            &partially_decrypted_result_pb,
+            &mut aggregated_decrypted_result_pb,
+        ) {
+            Ok(None) => (),
+            _ => return ptr::null_mut(),
+        };
+        c_safe_proto_to_c_char_pointer!(aggregated_decrypted_result_pb)
+    });
+    c_safe_return!(result)
+}
+
+/// C interface for 'wedpr_acv_aggregate_decrypted_part_sum_unlisted'. It
+/// returns the updated aggregated decrypted result, or a null pointer if the
+/// partially decrypted result is not aggregated.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_aggregate_decrypted_part_sum_unlisted(
+    poll_parameters_cstring: *mut c_char,
+    partially_decrypted_result_cstring: *mut c_char,
+    aggregated_decrypted_result_cstring: *mut c_char,
+) -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let poll_parameters_pb = c_safe_c_char_pointer_to_proto!(
+            poll_parameters_cstring,
+            PollParametersStorage
+        );
+        let partially_decrypted_result_pb = c_safe_c_char_pointer_to_proto!(
+            partially_decrypted_result_cstring,
+            DecryptedResultPartStorage
+        );
+        let mut aggregated_decrypted_result_pb = c_safe_c_char_pointer_to_proto!(
+            aggregated_decrypted_result_cstring,
+            DecryptedResultPartStorage
+        );
+
+        match wedpr_s_anonymous_ciphertext_voting::coordinator::aggregate_decrypted_part_sum_unlisted(
+            &poll_parameters_pb,
+            &partially_decrypted_result_pb,
+            &mut aggregated_decrypted_result_pb,
+        ) {
+            Ok(true) => (),
+            _ => return ptr::null_mut(),
+        };
+        c_safe_proto_to_c_char_pointer!(aggregated_decrypted_result_pb)
+    });
+    c_safe_return!(result)
+}
+
+/// C interface for 'wedpr_acv_finalize_vote_result'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_finalize_vote_result(
+    poll_parameters_cstring: *mut c_char,
+    vote_sum_cstring: *mut c_char,
+    aggregated_decrypted_result_cstring: *mut c_char,
+    max_vote_limit: c_long,
+) -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let poll_parameters_pb = c_safe_c_char_pointer_to_proto!(
+            poll_parameters_cstring,
+            PollParametersStorage
+        );
+        let vote_sum_pb =
+            c_safe_c_char_pointer_to_proto!(vote_sum_cstring, VoteStorage);
+        let aggregated_decrypted_result_pb = c_safe_c_char_pointer_to_proto!(
+            aggregated_decrypted_result_cstring,
+            DecryptedResultPartStorage
+        );
+
+        let vote_result =
+            match wedpr_s_anonymous_ciphertext_voting::coordinator::finalize_vote_result(
+                &poll_parameters_pb,
+                &vote_sum_pb,
+                &aggregated_decrypted_result_pb,
+                max_vote_limit as i64,
+            ) {
+                Ok(v) => v,
+                Err(_) => return ptr::null_mut(),
+            };
+        c_safe_proto_to_c_char_pointer!(vote_result)
+    });
+    c_safe_return!(result)
+}
+
+/// C interface for 'wedpr_acv_finalize_vote_result_unlisted'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_finalize_vote_result_unlisted(
+    poll_parameters_cstring: *mut c_char,
+    vote_sum_cstring: *mut c_char,
+    aggregated_decrypted_result_cstring: *mut c_char,
+    max_vote_limit: c_long,
+    max_candidate_number: c_long,
+) -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let poll_parameters_pb = c_safe_c_char_pointer_to_proto!(
+            poll_parameters_cstring,
+            PollParametersStorage
+        );
+        let vote_sum_pb =
+            c_safe_c_char_pointer_to_proto!(vote_sum_cstring, VoteStorage);
+        let mut aggregated_decrypted_result_pb = c_safe_c_char_pointer_to_proto!(
+            aggregated_decrypted_result_cstring,
+            DecryptedResultPartStorage
+        );
+
+        let vote_result =
+            match wedpr_s_anonymous_ciphertext_voting::coordinator::finalize_vote_result_unlisted(
+                &poll_parameters_pb,
+                &vote_sum_pb,
+                &mut aggregated_decrypted_result_pb,
+                max_vote_limit as i64,
+                max_candidate_number as i64,
+            ) {
+                Ok(v) => v,
+                Err(_) => return ptr::null_mut(),
+            };
+        c_safe_proto_to_c_char_pointer!(vote_result)
+    });
+    c_safe_return!(result)
+}
@@ -0,0 +1,116 @@
+// Copyright 2022 WeDPR Lab Project Authors. Licensed under Apache-2.0.
+
+//! Library of macros and functions for FFI of a counter in ACV solution,
+//! targeting C/C++ compatible architectures (including iOS).
+
+// C/C++ FFI: C-style interfaces will be generated.
+
+use libc::c_char;
+use protobuf::{self, Message};
+use std::{ffi::CString, panic, ptr};
+use wedpr_ffi_common::utils::{
+    bytes_to_string, c_char_pointer_to_string, string_to_bytes,
+};
+
+use wedpr_s_protos::generated::acv::{CounterSecret, VoteStorage};
+
+/// C interface for 'wedpr_acv_make_counter_secret'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_make_counter_secret() -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let counter_secret =
+            wedpr_s_anonymous_ciphertext_voting::counter::make_counter_secret();
+        c_safe_proto_to_c_char_pointer!(counter_secret)
+    });
+    c_safe_return!(result)
+}
+
+/// C interface for 'wedpr_acv_make_counter_parameters_share'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_make_counter_parameters_share(
+    counter_id_cstring: *mut c_char,
+    counter_secret_cstring: *mut c_char,
+) -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let counter_id = c_safe_c_char_pointer_to_string!(counter_id_cstring);
+        let counter_secret_pb = c_safe_c_char_pointer_to_proto!(
+            counter_secret_cstring,
+            CounterSecret
+        );
+
+        let counter_parameters_share =
+            match wedpr_s_anonymous_ciphertext_voting::counter::make_parameters_share(
+                &counter_id,
+                &counter_secret_pb,
+            ) {
+                Ok(v) => v,
+                Err(_) => return ptr::null_mut(),
+            };
+        c_safe_proto_to_c_char_pointer!(counter_parameters_share)
+    });
+    c_safe_return!(result)
+}
+
+/// C interface for 'wedpr_acv_count'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_count(
+    counter_id_cstring: *mut c_char,
+    counter_secret_cstring: *mut c_char,
+    encrypted_vote_sum_cstring: *mut c_char,
+) -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let counter_id = c_safe_c_char_pointer_to_string!(counter_id_cstring);
+        let counter_secret_pb = c_safe_c_char_pointer_to_proto!(
+            counter_secret_cstring,
+            CounterSecret
+        );
+        let encrypted_vote_sum_pb = c_safe_c_char_pointer_to_proto!(
+            encrypted_vote_sum_cstring,
+            VoteStorage
+        );
+
+        let partially_decrypted_result =
+            match wedpr_s_anonymous_ciphertext_voting::counter::count(
+                &counter_id,
+                &counter_secret_pb,
+                &encrypted_vote_sum_pb,
+            ) {
+                Ok(v) => v,
+                Err(_) => return ptr::null_mut(),
+            };
+        c_safe_proto_to_c_char_pointer!(partially_decrypted_result)
+    });
+    c_safe_return!(result)
+}
+
+/// C interface for 'wedpr_acv_count_unlisted'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_count_unlisted(
+    counter_id_cstring: *mut c_char,
+    counter_secret_cstring: *mut c_char,
+    encrypted_vote_sum_cstring: *mut c_char,
+) -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let counter_id = c_safe_c_char_pointer_to_string!(counter_id_cstring);
+        let counter_secret_pb = c_safe_c_char_pointer_to_proto!(
+            counter_secret_cstring,
+            CounterSecret
+        );
+        let encrypted_vote_sum_pb = c_safe_c_char_pointer_to_proto!(
+            encrypted_vote_sum_cstring,
+            VoteStorage
+        );
+
+        let partially_decrypted_result =
+            match wedpr_s_anonymous_ciphertext_voting::counter::count_unlisted(
+                &counter_id,
+                &counter_secret_pb,
+                &encrypted_vote_sum_pb,
+            ) {
+                Ok(v) => v,
+                Err(_) => return ptr::null_mut(),
+            };
+        c_safe_proto_to_c_char_pointer!(partially_decrypted_result)
+    });
+    c_safe_return!(result)
+}
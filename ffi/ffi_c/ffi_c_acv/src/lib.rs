@@ -0,0 +1,465 @@
+// Copyright 2022 WeDPR Lab Project Authors. Licensed under Apache-2.0.
+
+//! Library of macros and functions for FFI of ACV solution, targeting C/C++
+//! compatible architectures (including iOS).
+
+// C/C++ FFI: C-style interfaces will be generated.
+
+pub mod coordinator;
+pub mod counter;
+pub mod verifier;
+pub mod voter;
+
+#[macro_use]
+extern crate wedpr_ffi_macros;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use libc::c_char;
+    use protobuf::Message;
+    use std::ffi::CString;
+    use wedpr_ffi_common::utils::{
+        bytes_to_string, string_to_bytes, FAILURE, SUCCESS,
+    };
+    use wedpr_l_utils::traits::Signature;
+    use wedpr_s_anonymous_ciphertext_voting::config::POLL_RESULT_KEY_ABSTAINED_BALLOTS;
+    use wedpr_s_protos::{
+        config::SIGNATURE,
+        generated::acv::{
+            AcceptedVoteList, CandidateList, CounterParametersShareRequest,
+            CounterParametersStorage, CounterSecret,
+            DecryptedResultPartStorage, PollParametersStorage,
+            RegistrationRequest, RegistrationResponse, UnlistedVoteChoice,
+            VoteChoice, VoteChoices, VoteRequest, VoteResultStorage,
+            VoteStorage, VoterSecret,
+        },
+    };
+
+    fn bytes_to_c_string<T: ?Sized + AsRef<[u8]>>(bytes: &T) -> CString {
+        CString::new(bytes_to_string(bytes)).unwrap()
+    }
+
+    fn proto_to_c_string<T: Message>(pb: &T) -> CString {
+        bytes_to_c_string(&pb.write_to_bytes().unwrap())
+    }
+
+    fn c_string_to_proto<T: Message>(pointer: *mut c_char) -> T {
+        assert!(!pointer.is_null());
+        let c_string = unsafe { CString::from_raw(pointer) };
+        T::parse_from_bytes(
+            &string_to_bytes(c_string.to_str().unwrap()).unwrap(),
+        )
+        .unwrap()
+    }
+
+    fn as_ptr(c_string: &CString) -> *mut c_char {
+        c_string.as_ptr() as *mut c_char
+    }
+
+    // Makes the counter secrets and the poll parameters through FFI.
+    fn make_poll(
+        counter_id_list: &[&str],
+        candidate_list: &CandidateList,
+        secret_key_cstring: &CString,
+        unlisted: bool,
+    ) -> (
+        Vec<CounterSecret>,
+        Vec<CounterParametersShareRequest>,
+        PollParametersStorage,
+    ) {
+        let mut counter_secret_list = vec![];
+        let mut counter_share_list = vec![];
+        let mut counter_parameters = CounterParametersStorage::new();
+        for counter_id in counter_id_list {
+            let counter_secret: CounterSecret =
+                c_string_to_proto(counter::wedpr_acv_make_counter_secret());
+            let counter_share: CounterParametersShareRequest =
+                c_string_to_proto(
+                    counter::wedpr_acv_make_counter_parameters_share(
+                        as_ptr(&CString::new(*counter_id).unwrap()),
+                        as_ptr(&proto_to_c_string(&counter_secret)),
+                    ),
+                );
+            counter_parameters
+                .mut_counter_parameters_share()
+                .push(counter_share.clone());
+            counter_secret_list.push(counter_secret);
+            counter_share_list.push(counter_share);
+        }
+        let candidate_list_cstring = proto_to_c_string(candidate_list);
+        let counter_parameters_cstring = proto_to_c_string(&counter_parameters);
+        let poll_parameters = if unlisted {
+            coordinator::wedpr_acv_make_poll_parameters_unlisted(
+                as_ptr(&candidate_list_cstring),
+                as_ptr(&counter_parameters_cstring),
+                as_ptr(secret_key_cstring),
+                1,
+                10,
+            )
+        } else {
+            coordinator::wedpr_acv_make_poll_parameters(
+                as_ptr(&candidate_list_cstring),
+                as_ptr(&counter_parameters_cstring),
+                as_ptr(secret_key_cstring),
+            )
+        };
+        (
+            counter_secret_list,
+            counter_share_list,
+            c_string_to_proto(poll_parameters),
+        )
+    }
+
+    #[test]
+    fn test_acv_round_trip() {
+        let counter_id_list = ["1001", "1002"];
+        let mut candidate_list = CandidateList::new();
+        for candidate in ["Kitten", "Doge"] {
+            candidate_list.mut_candidate().push(candidate.to_string());
+        }
+        let (public_key, secret_key) = SIGNATURE.generate_keypair();
+        let public_key_cstring = bytes_to_c_string(&public_key);
+        let secret_key_cstring = bytes_to_c_string(&secret_key);
+        let (counter_secret_list, _, poll_parameters) = make_poll(
+            &counter_id_list,
+            &candidate_list,
+            &secret_key_cstring,
+            false,
+        );
+        let poll_parameters_cstring = proto_to_c_string(&poll_parameters);
+
+        // Voters register and vote.
+        let mut accepted_votes = AcceptedVoteList::new();
+        let mut vote_sum = VoteStorage::new();
+        let mut vote_sum_cstring = proto_to_c_string(&vote_sum);
+        for choice in [[3, 5], [6, 1]] {
+            let voter_secret_cstring =
+                proto_to_c_string(&c_string_to_proto::<VoterSecret>(
+                    voter::wedpr_acv_make_voter_secret(),
+                ));
+            let registration_request: RegistrationRequest =
+                c_string_to_proto(voter::wedpr_acv_make_registration_request(
+                    as_ptr(&voter_secret_cstring),
+                    as_ptr(&poll_parameters_cstring),
+                    as_ptr(&public_key_cstring),
+                ));
+            let registration_request_cstring =
+                proto_to_c_string(&registration_request);
+            let registration_response: RegistrationResponse =
+                c_string_to_proto(coordinator::wedpr_acv_certify_voter(
+                    as_ptr(&poll_parameters_cstring),
+                    as_ptr(&secret_key_cstring),
+                    as_ptr(&registration_request_cstring),
+                    10,
+                ));
+            let registration_response_cstring =
+                proto_to_c_string(&registration_response);
+            assert_eq!(
+                verifier::wedpr_acv_verify_blank_ballot(
+                    as_ptr(&registration_request_cstring),
+                    as_ptr(&registration_response_cstring),
+                ),
+                SUCCESS
+            );
+
+            let mut vote_choices = VoteChoices::new();
+            for (candidate, value) in
+                candidate_list.get_candidate().iter().zip(choice.iter())
+            {
+                let mut vote_choice = VoteChoice::new();
+                vote_choice.set_candidate(candidate.to_string());
+                vote_choice.set_value(*value);
+                vote_choices.mut_choice().push(vote_choice);
+            }
+            let vote_request: VoteRequest =
+                c_string_to_proto(voter::wedpr_acv_vote(
+                    as_ptr(&voter_secret_cstring),
+                    as_ptr(&proto_to_c_string(&vote_choices)),
+                    as_ptr(&registration_response_cstring),
+                    as_ptr(&poll_parameters_cstring),
+                    as_ptr(&public_key_cstring),
+                ));
+            let vote_request_cstring = proto_to_c_string(&vote_request);
+            assert_eq!(
+                verifier::wedpr_acv_verify_vote_request(
+                    as_ptr(&poll_parameters_cstring),
+                    as_ptr(&vote_request_cstring),
+                    as_ptr(&public_key_cstring),
+                ),
+                SUCCESS
+            );
+            assert_eq!(
+                verifier::wedpr_acv_verify_vote_request(
+                    as_ptr(&poll_parameters_cstring),
+                    as_ptr(&vote_request_cstring),
+                    as_ptr(&bytes_to_c_string(&SIGNATURE.generate_keypair().0)),
+                ),
+                FAILURE
+            );
+
+            let vote_part_cstring = proto_to_c_string(vote_request.get_vote());
+            vote_sum = c_string_to_proto(
+                coordinator::wedpr_acv_aggregate_vote_sum_response(
+                    as_ptr(&poll_parameters_cstring),
+                    as_ptr(&vote_part_cstring),
+                    as_ptr(&vote_sum_cstring),
+                ),
+            );
+            vote_sum_cstring = proto_to_c_string(&vote_sum);
+            // The same vote cannot be aggregated twice.
+            assert!(coordinator::wedpr_acv_aggregate_vote_sum_response(
+                as_ptr(&poll_parameters_cstring),
+                as_ptr(&vote_part_cstring),
+                as_ptr(&vote_sum_cstring),
+            )
+            .is_null());
+            accepted_votes
+                .mut_vote()
+                .push(vote_request.get_vote().clone());
+        }
+
+        // Counters count the vote sum.
+        let mut aggregated_decrypted_result_cstring =
+            proto_to_c_string(&DecryptedResultPartStorage::new());
+        for index in 0..counter_id_list.len() {
+            let partially_decrypted_result: DecryptedResultPartStorage =
+                c_string_to_proto(counter::wedpr_acv_count(
+                    as_ptr(&CString::new(counter_id_list[index]).unwrap()),
+                    as_ptr(&proto_to_c_string(&counter_secret_list[index])),
+                    as_ptr(&vote_sum_cstring),
+                ));
+            let partially_decrypted_result_cstring =
+                proto_to_c_string(&partially_decrypted_result);
+            assert_eq!(
+                verifier::wedpr_acv_verify_count_request(
+                    as_ptr(&poll_parameters_cstring),
+                    as_ptr(&CString::new(counter_id_list[index]).unwrap()),
+                    as_ptr(&vote_sum_cstring),
+                    as_ptr(&partially_decrypted_result_cstring),
+                    as_ptr(&public_key_cstring)
+                ),
+                SUCCESS
+            );
+            let aggregated_decrypted_result: DecryptedResultPartStorage =
+                c_string_to_proto(
+                    coordinator::wedpr_acv_aggregate_decrypted_part_sum(
+                        as_ptr(&poll_parameters_cstring),
+                        as_ptr(&CString::new(counter_id_list[index]).unwrap()),
+                        as_ptr(&vote_sum_cstring),
+                        as_ptr(&partially_decrypted_result_cstring),
+                        as_ptr(&aggregated_decrypted_result_cstring),
+                    ),
+                );
+            aggregated_decrypted_result_cstring =
+                proto_to_c_string(&aggregated_decrypted_result);
+        }
+
+        let vote_result: VoteResultStorage =
+            c_string_to_proto(coordinator::wedpr_acv_finalize_vote_result(
+                as_ptr(&poll_parameters_cstring),
+                as_ptr(&vote_sum_cstring),
+                as_ptr(&aggregated_decrypted_result_cstring),
+                20,
+            ));
+        for pair in vote_result.get_result() {
+            let expected_value = match pair.get_key() {
+                "Kitten" => 9,
+                "Doge" => 6,
+                POLL_RESULT_KEY_ABSTAINED_BALLOTS => 5,
+                _ => 20,
+            };
+            assert_eq!(pair.get_value(), expected_value);
+        }
+        assert_eq!(
+            verifier::wedpr_acv_verify_vote_result(
+                as_ptr(&poll_parameters_cstring),
+                as_ptr(&vote_sum_cstring),
+                as_ptr(&bytes_to_c_string(vote_sum.get_vote_root())),
+                as_ptr(&proto_to_c_string(&accepted_votes)),
+                as_ptr(&aggregated_decrypted_result_cstring),
+                as_ptr(&proto_to_c_string(&vote_result)),
+                as_ptr(&public_key_cstring)
+            ),
+            SUCCESS
+        );
+
+        // Malformed inputs are rejected without panicking.
+        let malformed_cstring = CString::new("malformed").unwrap();
+        assert!(voter::wedpr_acv_make_registration_request(
+            as_ptr(&proto_to_c_string(&c_string_to_proto::<VoterSecret>(
+                voter::wedpr_acv_make_voter_secret(),
+            ))),
+            as_ptr(&poll_parameters_cstring),
+            as_ptr(&bytes_to_c_string(&SIGNATURE.generate_keypair().0)),
+        )
+        .is_null());
+        assert!(voter::wedpr_acv_make_registration_request(
+            as_ptr(&malformed_cstring),
+            as_ptr(&poll_parameters_cstring),
+            as_ptr(&public_key_cstring)
+        )
+        .is_null());
+        assert_eq!(
+            verifier::wedpr_acv_verify_vote_result(
+                as_ptr(&poll_parameters_cstring),
+                as_ptr(&vote_sum_cstring),
+                as_ptr(&malformed_cstring),
+                as_ptr(&proto_to_c_string(&accepted_votes)),
+                as_ptr(&aggregated_decrypted_result_cstring),
+                as_ptr(&proto_to_c_string(&vote_result)),
+                as_ptr(&public_key_cstring)
+            ),
+            FAILURE
+        );
+    }
+
+    #[test]
+    fn test_acv_round_trip_unbounded_unlisted() {
+        let counter_id_list = ["1001"];
+        let mut candidate_list = CandidateList::new();
+        for candidate in ["Kitten", "Doge"] {
+            candidate_list.mut_candidate().push(candidate.to_string());
+        }
+        let (public_key, secret_key) = SIGNATURE.generate_keypair();
+        let public_key_cstring = bytes_to_c_string(&public_key);
+        let secret_key_cstring = bytes_to_c_string(&secret_key);
+        let (counter_secret_list, counter_share_list, poll_parameters) =
+            make_poll(
+                &counter_id_list,
+                &candidate_list,
+                &secret_key_cstring,
+                true,
+            );
+        let poll_parameters_cstring = proto_to_c_string(&poll_parameters);
+
+        // Voters give their whole weight or nothing to each candidate.
+        let mut vote_sum_cstring = proto_to_c_string(&VoteStorage::new());
+        for (choice, unlisted_choice) in
+            [([20, 0], vec![(3, 20)]), ([0, 20], vec![(3, 20), (7, 20)])]
+        {
+            let voter_secret_cstring =
+                proto_to_c_string(&c_string_to_proto::<VoterSecret>(
+                    voter::wedpr_acv_make_voter_secret(),
+                ));
+            let zero_secret_cstring =
+                proto_to_c_string(&c_string_to_proto::<VoterSecret>(
+                    voter::wedpr_acv_make_voter_secret(),
+                ));
+            let registration_request: RegistrationRequest = c_string_to_proto(
+                voter::wedpr_acv_make_unbounded_registration_request(
+                    as_ptr(&zero_secret_cstring),
+                    as_ptr(&voter_secret_cstring),
+                    as_ptr(&poll_parameters_cstring),
+                    as_ptr(&public_key_cstring),
+                ),
+            );
+            let registration_request_cstring =
+                proto_to_c_string(&registration_request);
+            let registration_response: RegistrationResponse = c_string_to_proto(
+                coordinator::wedpr_acv_certify_unbounded_voter(
+                    as_ptr(&poll_parameters_cstring),
+                    as_ptr(&secret_key_cstring),
+                    as_ptr(&registration_request_cstring),
+                    20,
+                ),
+            );
+            let registration_response_cstring =
+                proto_to_c_string(&registration_response);
+            assert_eq!(
+                verifier::wedpr_acv_verify_blank_ballot(
+                    as_ptr(&registration_request_cstring),
+                    as_ptr(&registration_response_cstring),
+                ),
+                SUCCESS
+            );
+
+            let mut vote_choices = VoteChoices::new();
+            for (candidate, value) in
+                candidate_list.get_candidate().iter().zip(choice.iter())
+            {
+                let mut vote_choice = VoteChoice::new();
+                vote_choice.set_candidate(candidate.to_string());
+                vote_choice.set_value(*value);
+                vote_choices.mut_choice().push(vote_choice);
+            }
+            for (candidate_id, value) in unlisted_choice {
+                let mut vote_choice = UnlistedVoteChoice::new();
+                vote_choice.set_candidate_id(candidate_id);
+                vote_choice.set_value(value);
+                vote_choices.mut_unlisted_choice().push(vote_choice);
+            }
+            let vote_request: VoteRequest =
+                c_string_to_proto(voter::wedpr_acv_vote_unbounded_unlisted(
+                    as_ptr(&voter_secret_cstring),
+                    as_ptr(&zero_secret_cstring),
+                    as_ptr(&proto_to_c_string(&vote_choices)),
+                    as_ptr(&registration_response_cstring),
+                    as_ptr(&poll_parameters_cstring),
+                    as_ptr(&public_key_cstring),
+                ));
+            assert_eq!(
+                verifier::wedpr_acv_verify_unbounded_vote_request_unlisted(
+                    as_ptr(&poll_parameters_cstring),
+                    as_ptr(&proto_to_c_string(&vote_request)),
+                    as_ptr(&public_key_cstring),
+                ),
+                SUCCESS
+            );
+            let vote_sum: VoteStorage = c_string_to_proto(
+                coordinator::wedpr_acv_aggregate_vote_sum_response_unlisted(
+                    as_ptr(&poll_parameters_cstring),
+                    as_ptr(&proto_to_c_string(vote_request.get_vote())),
+                    as_ptr(&vote_sum_cstring),
+                ),
+            );
+            vote_sum_cstring = proto_to_c_string(&vote_sum);
+        }
+
+        let partially_decrypted_result: DecryptedResultPartStorage =
+            c_string_to_proto(counter::wedpr_acv_count_unlisted(
+                as_ptr(&CString::new(counter_id_list[0]).unwrap()),
+                as_ptr(&proto_to_c_string(&counter_secret_list[0])),
+                as_ptr(&vote_sum_cstring),
+            ));
+        let partially_decrypted_result_cstring =
+            proto_to_c_string(&partially_decrypted_result);
+        assert_eq!(
+            verifier::wedpr_acv_verify_count_request_unlisted(
+                as_ptr(&poll_parameters_cstring),
+                as_ptr(&vote_sum_cstring),
+                as_ptr(&bytes_to_c_string(
+                    counter_share_list[0].get_poll_point_share()
+                )),
+                as_ptr(&partially_decrypted_result_cstring),
+                as_ptr(&public_key_cstring)
+            ),
+            SUCCESS
+        );
+        let aggregated_decrypted_result: DecryptedResultPartStorage =
+            c_string_to_proto(
+                coordinator::wedpr_acv_aggregate_decrypted_part_sum_unlisted(
+                    as_ptr(&poll_parameters_cstring),
+                    as_ptr(&partially_decrypted_result_cstring),
+                    as_ptr(&proto_to_c_string(
+                        &DecryptedResultPartStorage::new(),
+                    )),
+                ),
+            );
+        let vote_result: VoteResultStorage = c_string_to_proto(
+            coordinator::wedpr_acv_finalize_vote_result_unlisted(
+                as_ptr(&poll_parameters_cstring),
+                as_ptr(&vote_sum_cstring),
+                as_ptr(&proto_to_c_string(&aggregated_decrypted_result)),
+                40,
+                10,
+            ),
+        );
+        let unlisted_result = vote_result
+            .get_unlisted_result()
+            .iter()
+            .find(|pair| pair.get_candidate_id() == 3)
+            .unwrap();
+        assert_eq!(unlisted_result.get_value(), 40);
+    }
+}
@@ -0,0 +1,343 @@
+// Copyright 2022 WeDPR Lab Project Authors. Licensed under Apache-2.0.
+
+//! Library of macros and functions for FFI of a poll verifier in ACV solution,
+//! targeting C/C++ compatible architectures (including iOS).
+
+// C/C++ FFI: C-style interfaces will be generated.
+
+use libc::c_char;
+use protobuf::{self, Message};
+use std::panic;
+use wedpr_ffi_common::utils::{
+    c_char_pointer_to_string, string_to_bytes, FAILURE, SUCCESS,
+};
+use wedpr_l_crypto_zkp_utils::bytes_to_point;
+
+use wedpr_s_protos::generated::acv::{
+    AcceptedVoteList, DecryptedResultPartStorage, PollParametersStorage,
+    RegistrationRequest, RegistrationResponse, VoteRequest, VoteResultStorage,
+    VoteStorage,
+};
+
+/// C interface for 'wedpr_acv_verify_vote_request'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_verify_vote_request(
+    poll_parameters_cstring: *mut c_char,
+    vote_request_cstring: *mut c_char,
+    public_key_cstring: *mut c_char,
+) -> i8 {
+    let result = panic::catch_unwind(|| {
+        let poll_parameters_pb = c_safe_c_char_pointer_to_proto_with_error_value!(
+            poll_parameters_cstring,
+            PollParametersStorage,
+            FAILURE
+        );
+        let vote_request_pb = c_safe_c_char_pointer_to_proto_with_error_value!(
+            vote_request_cstring,
+            VoteRequest,
+            FAILURE
+        );
+        let public_key = c_safe_string_to_bytes_with_error_value!(
+            c_safe_c_char_pointer_to_string_with_error_value!(
+                public_key_cstring,
+                FAILURE
+            ),
+            FAILURE
+        );
+
+        match wedpr_s_anonymous_ciphertext_voting::verifier::verify_vote_request(
+            &poll_parameters_pb,
+            &vote_request_pb,
+            &public_key,
+        ) {
+            Ok(true) => SUCCESS,
+            _ => FAILURE,
+        }
+    });
+    c_safe_return_with_error_value!(result, FAILURE)
+}
+
+/// C interface for 'wedpr_acv_verify_unbounded_vote_request'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_verify_unbounded_vote_request(
+    poll_parameters_cstring: *mut c_char,
+    vote_request_cstring: *mut c_char,
+    public_key_cstring: *mut c_char,
+) -> i8 {
+    let result = panic::catch_unwind(|| {
+        let poll_parameters_pb = c_safe_c_char_pointer_to_proto_with_error_value!(
+            poll_parameters_cstring,
+            PollParametersStorage,
+            FAILURE
+        );
+        let vote_request_pb = c_safe_c_char_pointer_to_proto_with_error_value!(
+            vote_request_cstring,
+            VoteRequest,
+            FAILURE
+        );
+        let public_key = c_safe_string_to_bytes_with_error_value!(
+            c_safe_c_char_pointer_to_string_with_error_value!(
+                public_key_cstring,
+                FAILURE
+            ),
+            FAILURE
+        );
+
+        match wedpr_s_anonymous_ciphertext_voting::verifier::verify_unbounded_vote_request(
+            &poll_parameters_pb,
+            &vote_request_pb,
+            &public_key,
+        ) {
+            Ok(true) => SUCCESS,
+            _ => FAILURE,
+        }
+    });
+    c_safe_return_with_error_value!(result, FAILURE)
+}
+
+/// C interface for 'wedpr_acv_verify_unbounded_vote_request_unlisted'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_verify_unbounded_vote_request_unlisted(
+    poll_parameters_cstring: *mut c_char,
+    vote_request_cstring: *mut c_char,
+    public_key_cstring: *mut c_char,
+) -> i8 {
+    let result = panic::catch_unwind(|| {
+        let poll_parameters_pb = c_safe_c_char_pointer_to_proto_with_error_value!(
+            poll_parameters_cstring,
+            PollParametersStorage,
+            FAILURE
+        );
+        let vote_request_pb = c_safe_c_char_pointer_to_proto_with_error_value!(
+            vote_request_cstring,
+            VoteRequest,
+            FAILURE
+        );
+        let public_key = c_safe_string_to_bytes_with_error_value!(
+            c_safe_c_char_pointer_to_string_with_error_value!(
+                public_key_cstring,
+                FAILURE
+            ),
+            FAILURE
+        );
+
+        match wedpr_s_anonymous_ciphertext_voting::verifier::verify_unbounded_vote_request_unlisted(
+            &poll_parameters_pb,
+            &vote_request_pb,
+            &public_key,
+        ) {
+            Ok(true) => SUCCESS,
+            _ => FAILURE,
+        }
+    });
+    c_safe_return_with_error_value!(result, FAILURE)
+}
+
+/// C interface for 'wedpr_acv_verify_count_request'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_verify_count_request(
+    poll_parameters_cstring: *mut c_char,
+    counter_id_cstring: *mut c_char,
+    encrypted_vote_sum_cstring: *mut c_char,
+    partially_decrypted_result_cstring: *mut c_char,
+    public_key_cstring: *mut c_char,
+) -> i8 {
+    let result = panic::catch_unwind(|| {
+        let poll_parameters_pb = c_safe_c_char_pointer_to_proto_with_error_value!(
+            poll_parameters_cstring,
+            PollParametersStorage,
+            FAILURE
+        );
+        let encrypted_vote_sum_pb = c_safe_c_char_pointer_to_proto_with_error_value!(
+            encrypted_vote_sum_cstring,
+            VoteStorage,
+            FAILURE
+        );
+        let partially_decrypted_result_pb = c_safe_c_char_pointer_to_proto_with_error_value!(
+            partially_decrypted_result_cstring,
+            DecryptedResultPartStorage,
+            FAILURE
+        );
+        let counter_id = c_safe_c_char_pointer_to_string_with_error_value!(
+            counter_id_cstring,
+            FAILURE
+        );
+
+        let public_key = c_safe_string_to_bytes_with_error_value!(
+            c_safe_c_char_pointer_to_string_with_error_value!(
+                public_key_cstring,
+                FAILURE
+            ),
+            FAILURE
+        );
+
+        match wedpr_s_anonymous_ciphertext_voting::verifier::verify_count_request(
+            &poll_parameters_pb,
+            &counter_id,
+            &encrypted_vote_sum_pb,
+            &partially_decrypted_result_pb,
+            &public_key,
+        ) {
+            Ok(true) => SUCCESS,
+            _ => FAILURE,
+        }
+    });
+    c_safe_return_with_error_value!(result, FAILURE)
+}
+
+/// C interface for 'wedpr_acv_verify_count_request_unlisted'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_verify_count_request_unlisted(
+    poll_parameters_cstring: *mut c_char,
+    encrypted_vote_sum_cstring: *mut c_char,
+    counter_share_cstring: *mut c_char,
+    partially_decrypted_result_cstring: *mut c_char,
+    public_key_cstring: *mut c_char,
+) -> i8 {
+    let result = panic::catch_unwind(|| {
+        let poll_parameters_pb = c_safe_c_char_pointer_to_proto_with_error_value!(
+            poll_parameters_cstring,
+            PollParametersStorage,
+            FAILURE
+        );
+        let encrypted_vote_sum_pb = c_safe_c_char_pointer_to_proto_with_error_value!(
+            encrypted_vote_sum_cstring,
+            VoteStorage,
+            FAILURE
+        );
+        let partially_decrypted_result_pb = c_safe_c_char_pointer_to_proto_with_error_value!(
+            partially_decrypted_result_cstring,
+            DecryptedResultPartStorage,
+            FAILURE
+        );
+        let counter_share_bytes = c_safe_string_to_bytes_with_error_value!(
+            c_safe_c_char_pointer_to_string_with_error_value!(
+                counter_share_cstring,
+                FAILURE
+            ),
+            FAILURE
+        );
+        let counter_share = match bytes_to_point(&counter_share_bytes) {
+            Ok(v) => v,
+            Err(_) => return FAILURE,
+        };
+
+        let public_key = c_safe_string_to_bytes_with_error_value!(
+            c_safe_c_char_pointer_to_string_with_error_value!(
+                public_key_cstring,
+                FAILURE
+            ),
+            FAILURE
+        );
+
+        match wedpr_s_anonymous_ciphertext_voting::verifier::verify_count_request_unlisted(
+            &poll_parameters_pb,
+            &counter_share,
+            &encrypted_vote_sum_pb,
+            &partially_decrypted_result_pb,
+            &public_key,
+        ) {
+            Ok(true) => SUCCESS,
+            _ => FAILURE,
+        }
+    });
+    c_safe_return_with_error_value!(result, FAILURE)
+}
+
+/// C interface for 'wedpr_acv_verify_vote_result'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_verify_vote_result(
+    poll_parameters_cstring: *mut c_char,
+    vote_sum_cstring: *mut c_char,
+    vote_root_cstring: *mut c_char,
+    accepted_votes_cstring: *mut c_char,
+    aggregated_decrypted_result_cstring: *mut c_char,
+    vote_result_cstring: *mut c_char,
+    public_key_cstring: *mut c_char,
+) -> i8 {
+    let result = panic::catch_unwind(|| {
+        let poll_parameters_pb = c_safe_c_char_pointer_to_proto_with_error_value!(
+            poll_parameters_cstring,
+            PollParametersStorage,
+            FAILURE
+        );
+        let vote_sum_pb = c_safe_c_char_pointer_to_proto_with_error_value!(
+            vote_sum_cstring,
+            VoteStorage,
+            FAILURE
+        );
+        let vote_root = c_safe_string_to_bytes_with_error_value!(
+            c_safe_c_char_pointer_to_string_with_error_value!(
+                vote_root_cstring,
+                FAILURE
+            ),
+            FAILURE
+        );
+        let accepted_votes_pb = c_safe_c_char_pointer_to_proto_with_error_value!(
+            accepted_votes_cstring,
+            AcceptedVoteList,
+            FAILURE
+        );
+        let aggregated_decrypted_result_pb = c_safe_c_char_pointer_to_proto_with_error_value!(
+            aggregated_decrypted_result_cstring,
+            DecryptedResultPartStorage,
+            FAILURE
+        );
+        let vote_result_pb = c_safe_c_char_pointer_to_proto_with_error_value!(
+            vote_result_cstring,
+            VoteResultStorage,
+            FAILURE
+        );
+
+        let public_key = c_safe_string_to_bytes_with_error_value!(
+            c_safe_c_char_pointer_to_string_with_error_value!(
+                public_key_cstring,
+                FAILURE
+            ),
+            FAILURE
+        );
+
+        match wedpr_s_anonymous_ciphertext_voting::verifier::verify_vote_result(
+            &poll_parameters_pb,
+            &vote_sum_pb,
+            &vote_root,
+            accepted_votes_pb.get_vote(),
+            &aggregated_decrypted_result_pb,
+            &vote_result_pb,
+            &public_key,
+        ) {
+            Ok(true) => SUCCESS,
+            _ => FAILURE,
+        }
+    });
+    c_safe_return_with_error_value!(result, FAILURE)
+}
+
+/// C interface for 'wedpr_acv_verify_blank_ballot'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_verify_blank_ballot(
+    registration_request_cstring: *mut c_char,
+    registration_response_cstring: *mut c_char,
+) -> i8 {
+    let result = panic::catch_unwind(|| {
+        let registration_request_pb = c_safe_c_char_pointer_to_proto_with_error_value!(
+            registration_request_cstring,
+            RegistrationRequest,
+            FAILURE
+        );
+        let registration_response_pb = c_safe_c_char_pointer_to_proto_with_error_value!(
+            registration_response_cstring,
+            RegistrationResponse,
+            FAILURE
+        );
+
+        match wedpr_s_anonymous_ciphertext_voting::voter::verify_blank_ballot(
+            &registration_request_pb,
+            &registration_response_pb,
+        ) {
+            Ok(true) => SUCCESS,
+            _ => FAILURE,
+        }
+    });
+    c_safe_return_with_error_value!(result, FAILURE)
+}
@@ -0,0 +1,262 @@
+// Copyright 2022 WeDPR Lab Project Authors. Licensed under Apache-2.0.
+
+//! Library of macros and functions for FFI of a voter in ACV solution,
+//! targeting C/C++ compatible architectures (including iOS).
+
+// C/C++ FFI: C-style interfaces will be generated.
+
+use libc::c_char;
+use protobuf::{self, Message};
+use std::{ffi::CString, panic, ptr};
+use wedpr_ffi_common::utils::{
+    bytes_to_string, c_char_pointer_to_string, string_to_bytes,
+};
+
+use wedpr_s_protos::generated::acv::{
+    PollParametersStorage, RegistrationResponse, VoteChoices, VoterSecret,
+};
+
+/// C interface for 'wedpr_acv_make_voter_secret'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_make_voter_secret() -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let voter_secret =
+            wedpr_s_anonymous_ciphertext_voting::voter::make_voter_secret();
+        c_safe_proto_to_c_char_pointer!(voter_secret)
+    });
+    c_safe_return!(result)
+}
+
+/// C interface for 'wedpr_acv_generate_registration_blinding_point'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_generate_registration_blinding_point(
+    voter_secret_cstring: *mut c_char,
+    poll_parameters_cstring: *mut c_char,
+) -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let voter_secret_pb =
+            c_safe_c_char_pointer_to_proto!(voter_secret_cstring, VoterSecret);
+        let poll_parameters_pb = c_safe_c_char_pointer_to_proto!(
+            poll_parameters_cstring,
+            PollParametersStorage
+        );
+
+        let registration_blinding_point =
+            match wedpr_s_anonymous_ciphertext_voting::voter::generate_registration_blinding_point(
+                &voter_secret_pb,
+                &poll_parameters_pb,
+            ) {
+                Ok(v) => v,
+                Err(_) => return ptr::null_mut(),
+            };
+        c_safe_proto_to_c_char_pointer!(registration_blinding_point)
+    });
+    c_safe_return!(result)
+}
+
+/// C interface for 'wedpr_acv_make_registration_request'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_make_registration_request(
+    voter_secret_cstring: *mut c_char,
+    poll_parameters_cstring: *mut c_char,
+    public_key_cstring: *mut c_char,
+) -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let voter_secret_pb =
+            c_safe_c_char_pointer_to_proto!(voter_secret_cstring, VoterSecret);
+        let poll_parameters_pb = c_safe_c_char_pointer_to_proto!(
+            poll_parameters_cstring,
+            PollParametersStorage
+        );
+
+        let public_key = c_safe_string_to_bytes!(
+            c_safe_c_char_pointer_to_string!(public_key_cstring)
+        );
+
+        let registration_request =
+            match wedpr_s_anonymous_ciphertext_voting::voter::make_registration_request(
+                &voter_secret_pb,
+                &poll_parameters_pb,
+                &public_key,
+            ) {
+                Ok(v) => v,
+                Err(_) => return ptr::null_mut(),
+            };
+        c_safe_proto_to_c_char_pointer!(registration_request)
+    });
+    c_safe_return!(result)
+}
+
+/// C interface for 'wedpr_acv_make_unbounded_registration_request'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_make_unbounded_registration_request(
+    zero_secret_cstring: *mut c_char,
+    voter_secret_cstring: *mut c_char,
+    poll_parameters_cstring: *mut c_char,
+    public_key_cstring: *mut c_char,
+) -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let zero_secret_pb =
+            c_safe_c_char_pointer_to_proto!(zero_secret_cstring, VoterSecret);
+        let voter_secret_pb =
+            c_safe_c_char_pointer_to_proto!(voter_secret_cstring, VoterSecret);
+        let poll_parameters_pb = c_safe_c_char_pointer_to_proto!(
+            poll_parameters_cstring,
+            PollParametersStorage
+        );
+
+        let public_key = c_safe_string_to_bytes!(
+            c_safe_c_char_pointer_to_string!(public_key_cstring)
+        );
+
+        let registration_request =
+            match wedpr_s_anonymous_ciphertext_voting::voter::make_unbounded_registration_request(
+                &zero_secret_pb,
+                &voter_secret_pb,
+                &poll_parameters_pb,
+                &public_key,
+            ) {
+                Ok(v) => v,
+                Err(_) => return ptr::null_mut(),
+            };
+        c_safe_proto_to_c_char_pointer!(registration_request)
+    });
+    c_safe_return!(result)
+}
+
+/// C interface for 'wedpr_acv_vote'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_vote(
+    voter_secret_cstring: *mut c_char,
+    vote_choices_cstring: *mut c_char,
+    registration_response_cstring: *mut c_char,
+    poll_parameters_cstring: *mut c_char,
+    public_key_cstring: *mut c_char,
+) -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let voter_secret_pb =
+            c_safe_c_char_pointer_to_proto!(voter_secret_cstring, VoterSecret);
+        let vote_choices_pb =
+            c_safe_c_char_pointer_to_proto!(vote_choices_cstring, VoteChoices);
+        let registration_response_pb = c_safe_c_char_pointer_to_proto!(
+            registration_response_cstring,
+            RegistrationResponse
+        );
+        let poll_parameters_pb = c_safe_c_char_pointer_to_proto!(
+            poll_parameters_cstring,
+            PollParametersStorage
+        );
+
+        let public_key = c_safe_string_to_bytes!(
+            c_safe_c_char_pointer_to_string!(public_key_cstring)
+        );
+
+        let vote_request =
+            match wedpr_s_anonymous_ciphertext_voting::voter::vote(
+                &voter_secret_pb,
+                &vote_choices_pb,
+                &registration_response_pb,
+                &poll_parameters_pb,
+                &public_key,
+            ) {
+                Ok(v) => v,
+                Err(_) => return ptr::null_mut(),
+            };
+        c_safe_proto_to_c_char_pointer!(vote_request)
+    });
+    c_safe_return!(result)
+}
+
+/// C interface for 'wedpr_acv_vote_unbounded'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_vote_unbounded(
+    voter_secret_cstring: *mut c_char,
+    zero_secret_cstring: *mut c_char,
+    vote_choices_cstring: *mut c_char,
+    registration_response_cstring: *mut c_char,
+    poll_parameters_cstring: *mut c_char,
+    public_key_cstring: *mut c_char,
+) -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let voter_secret_pb =
+            c_safe_c_char_pointer_to_proto!(voter_secret_cstring, VoterSecret);
+        let zero_secret_pb =
+            c_safe_c_char_pointer_to_proto!(zero_secret_cstring, VoterSecret);
+        let vote_choices_pb =
+            c_safe_c_char_pointer_to_proto!(vote_choices_cstring, VoteChoices);
+        let registration_response_pb = c_safe_c_char_pointer_to_proto!(
+            registration_response_cstring,
+            RegistrationResponse
+        );
+        let poll_parameters_pb = c_safe_c_char_pointer_to_proto!(
+            poll_parameters_cstring,
+            PollParametersStorage
+        );
+
+        let public_key = c_safe_string_to_bytes!(
+            c_safe_c_char_pointer_to_string!(public_key_cstring)
+        );
+
+        let vote_request =
+            match wedpr_s_anonymous_ciphertext_voting::voter::vote_unbounded(
+                &voter_secret_pb,
+                &zero_secret_pb,
+                &vote_choices_pb,
+                &registration_response_pb,
+                &poll_parameters_pb,
+                &public_key,
+            ) {
+                Ok(v) => v,
+                Err(_) => return ptr::null_mut(),
+            };
+        c_safe_proto_to_c_char_pointer!(vote_request)
+    });
+    c_safe_return!(result)
+}
+
+/// C interface for 'wedpr_acv_vote_unbounded_unlisted'.
+#[no_mangle]
+pub extern "C" fn wedpr_acv_vote_unbounded_unlisted(
+    voter_secret_cstring: *mut c_char,
+    zero_secret_cstring: *mut c_char,
+    vote_choices_cstring: *mut c_char,
+    registration_response_cstring: *mut c_char,
+    poll_parameters_cstring: *mut c_char,
+    public_key_cstring: *mut c_char,
+) -> *mut c_char {
+    let result = panic::catch_unwind(|| {
+        let voter_secret_pb =
+            c_safe_c_char_pointer_to_proto!(voter_secret_cstring, VoterSecret);
+        let zero_secret_pb =
+            c_safe_c_char_pointer_to_proto!(zero_secret_cstring, VoterSecret);
+        let vote_choices_pb =
+            c_safe_c_char_pointer_to_proto!(vote_choices_cstring, VoteChoices);
+        let registration_response_pb = c_safe_c_char_pointer_to_proto!(
+            registration_response_cstring,
+            RegistrationResponse
+        );
+        let poll_parameters_pb = c_safe_c_char_pointer_to_proto!(
+            poll_parameters_cstring,
+            PollParametersStorage
+        );
+
+        let public_key = c_safe_string_to_bytes!(
+            c_safe_c_char_pointer_to_string!(public_key_cstring)
+        );
+
+        let vote_request =
+            match wedpr_s_anonymous_ciphertext_voting::voter::vote_unbounded_unlisted(
+                &voter_secret_pb,
+                &zero_secret_pb,
+                &vote_choices_pb,
+                &registration_response_pb,
+                &poll_parameters_pb,
+                &public_key,
+            ) {
+                Ok(v) => v,
+                Err(_) => return ptr::null_mut(),
+            };
+        c_safe_proto_to_c_char_pointer!(vote_request)
+    });
+    c_safe_return!(result)
+}
@@ -40,6 +40,7 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_makePollParamet
     _class: JClass,
     candidate_list: JString,
     counter_parameters: JString,
+    secret_key_data: jbyteArray,
 ) -> jobject {
     // get the result object
     let result_jobject = get_result_jobject(&_env);
@@ -59,6 +60,7 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_makePollParamet
         match wedpr_s_anonymous_ciphertext_voting::coordinator::make_poll_parameters(
             &pb_candidate_list,
             &pb_counter_parameters,
+            &java_safe_jbytes_to_bytes!(_env, result_jobject, secret_key_data),
         ) {
             Ok(v) => v,
             Err(e) => {
@@ -78,18 +80,81 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_makePollParamet
     result_jobject.into_inner()
 }
 
+/// Java interface for
+/// 'com.webank.wedpr.acv.NativeInterface->makePollParametersUnlisted'.
+#[no_mangle]
+pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_makePollParametersUnlisted(
+    _env: JNIEnv,
+    _class: JClass,
+    candidate_list: JString,
+    counter_parameters: JString,
+    secret_key_data: jbyteArray,
+    min_unlisted_candidate_id: jint,
+    max_unlisted_candidate_id: jint,
+) -> jobject {
+    // get the result object
+    let result_jobject = get_result_jobject(&_env);
+    let pb_candidate_list = java_safe_jstring_to_pb!(
+        _env,
+        result_jobject,
+        candidate_list,
+        CandidateList
+    );
+    let pb_counter_parameters = java_safe_jstring_to_pb!(
+        _env,
+        result_jobject,
+        counter_parameters,
+        CounterParametersStorage
+    );
+    let poll_parameters =
+        match wedpr_s_anonymous_ciphertext_voting::coordinator::make_poll_parameters_unlisted(
+            &pb_candidate_list,
+            &pb_counter_parameters,
+            &java_safe_jbytes_to_bytes!(_env, result_jobject, secret_key_data),
+            min_unlisted_candidate_id as u32,
+            max_unlisted_candidate_id as u32,
+        ) {
+            Ok(v) => v,
+            Err(e) => {
+                return java_set_error_field_and_extract_jobject(
+                    &_env,
+                    &result_jobject,
+                    &format!(
+                        "make_poll_parameters_unlisted failed, err = {:?}",
+                        e
+                    ),
+                )
+            },
+        };
+    java_safe_set_encoded_pb_field!(
+        _env,
+        result_jobject,
+        poll_parameters,
+        "poll_parameters"
+    );
+    result_jobject.into_inner()
+}
+
 /// Java interface for 'com.webank.wedpr.acv.NativeInterface->certifyVoter'.
 #[no_mangle]
 pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_certifyVoter(
     _env: JNIEnv,
     _class: JClass,
+    poll_parameters: JString,
     secret_key_data: jbyteArray,
     registration_request: JString,
     voter_weight: jint,
 ) -> jobject {
     let result_jobject = get_result_jobject(&_env);
+    let pb_poll_parameters = java_safe_jstring_to_pb!(
+        _env,
+        result_jobject,
+        poll_parameters,
+        PollParametersStorage
+    );
     let certify_result =
         match wedpr_s_anonymous_ciphertext_voting::coordinator::certify_voter(
+            &pb_poll_parameters,
             &java_safe_jbytes_to_bytes!(_env, result_jobject, secret_key_data),
             &java_safe_jstring_to_pb!(
                 _env,
@@ -123,13 +188,21 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_certifyVoter(
 pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_certifyUnboundedVoter(
     _env: JNIEnv,
     _class: JClass,
+    poll_parameters: JString,
     secret_key_data: jbyteArray,
     registration_request: JString,
     voter_weight: jint,
 ) -> jobject {
     let result_jobject = get_result_jobject(&_env);
+    let pb_poll_parameters = java_safe_jstring_to_pb!(
+        _env,
+        result_jobject,
+        poll_parameters,
+        PollParametersStorage
+    );
     let certify_result =
         match wedpr_s_anonymous_ciphertext_voting::coordinator::certify_unbounded_voter(
+            &pb_poll_parameters,
             &java_safe_jbytes_to_bytes!(_env, result_jobject, secret_key_data),
             &java_safe_jstring_to_pb!(
                 _env,
@@ -271,6 +344,8 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_aggregateDecryp
     _env: JNIEnv,
     _class: JClass,
     poll_parameters: JString,
+    counter_id_str: JString,
+    encrypted_vote_sum: JString,
     partially_decrypted_result: JString,
     aggregated_decrypted_result: JString,
 ) -> jobject {
@@ -281,6 +356,14 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_aggregateDecryp
         poll_parameters,
         PollParametersStorage
     );
+    let counter_id =
+        java_safe_jstring_to_string!(_env, result_jobject, counter_id_str);
+    let pb_encrypted_vote_sum = java_safe_jstring_to_pb!(
+        _env,
+        result_jobject,
+        encrypted_vote_sum,
+        VoteStorage
+    );
     let pb_partially_decrypted_result = java_safe_jstring_to_pb!(
         _env,
         result_jobject,
@@ -296,6 +379,8 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_aggregateDecryp
     let result =
         match wedpr_s_anonymous_ciphertext_voting::coordinator::aggregate_decrypted_part_sum(
             &pb_poll_parameters,
+            &counter_id,
+            &pb_encrypted_vote_sum,
             &pb_partially_decrypted_result,
             &mut pb_aggregated_decrypted_result,
         ) {
@@ -308,11 +393,14 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_aggregateDecryp
                 )
             },
         };
-    if !result {
+    if let Some(blame_report) = result {
         return java_set_error_field_and_extract_jobject(
             &_env,
             &result_jobject,
-            &format!("aggregateDecryptedPartSum failed"),
+            &format!(
+                "aggregateDecryptedPartSum failed, blame report = {:?}",
+                blame_report
+            ),
         );
     }
     // write back pb_aggregated_decrypted_result
@@ -20,8 +20,9 @@ use wedpr_l_crypto_zkp_utils::bytes_to_point;
 use wedpr_s_anonymous_ciphertext_voting;
 
 use wedpr_s_protos::generated::acv::{
-    DecryptedResultPartStorage, PollParametersStorage, RegistrationRequest,
-    RegistrationResponse, VoteRequest, VoteResultStorage, VoteStorage,
+    AcceptedVoteList, DecryptedResultPartStorage, PollParametersStorage,
+    RegistrationRequest, RegistrationResponse, VoteRequest, VoteResultStorage,
+    VoteStorage,
 };
 
 // Java FFI: Java interfaces will be generated under
@@ -183,9 +184,10 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_verifyCountRequ
     _env: JNIEnv,
     _class: JClass,
     poll_parameters: JString,
+    counter_id_str: JString,
     encrypted_vote_sum: JString,
-    counter_share: jbyteArray,
     partially_decrypted_result: JString,
+    public_key_bytes: jbyteArray,
 ) -> jobject {
     let result_jobject = get_result_jobject(&_env);
     let pb_poll_parameters = java_safe_jstring_to_pb!(
@@ -206,25 +208,12 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_verifyCountRequ
         partially_decrypted_result,
         DecryptedResultPartStorage
     );
-    let counter_share_bytes =
-        java_safe_jbytes_to_bytes!(_env, result_jobject, counter_share);
-    let counter_share_point =
-        match bytes_to_point(&counter_share_bytes.to_vec()) {
-            Ok(v) => v,
-            Err(e) => {
-                return java_set_error_field_and_extract_jobject(
-                    &_env,
-                    &result_jobject,
-                    &format!(
-                        "verifyCountRequestUnlisted failed for covert \
-                         counter_share error, err = {:?}",
-                        e
-                    ),
-                )
-            },
-        };
+    let counter_id =
+        java_safe_jstring_to_string!(_env, result_jobject, counter_id_str);
+    let public_key =
+        java_safe_jbytes_to_bytes!(_env, result_jobject, public_key_bytes);
     let verify_result = match wedpr_s_anonymous_ciphertext_voting::verifier::verify_count_request(&pb_poll_parameters,
-    &pb_encrypted_vote_sum, &counter_share_point, &pb_partially_decrypted_result)
+    &counter_id, &pb_encrypted_vote_sum, &pb_partially_decrypted_result, &public_key)
     {
         Ok(v) => v,
         Err(e)=>{
@@ -254,6 +243,7 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_verifyCountRequ
     encrypted_vote_sum: JString,
     counter_share: jbyteArray,
     partially_decrypted_result: JString,
+    public_key_bytes: jbyteArray,
 ) -> jobject {
     let result_jobject = get_result_jobject(&_env);
     let pb_poll_parameters = java_safe_jstring_to_pb!(
@@ -291,8 +281,10 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_verifyCountRequ
                 )
             },
         };
+    let public_key =
+        java_safe_jbytes_to_bytes!(_env, result_jobject, public_key_bytes);
     let verify_result = match wedpr_s_anonymous_ciphertext_voting::verifier::verify_count_request_unlisted(&pb_poll_parameters,
-    &counter_share_point, &pb_encrypted_vote_sum, &pb_partially_decrypted_result)
+    &counter_share_point, &pb_encrypted_vote_sum, &pb_partially_decrypted_result, &public_key)
     {
         Ok(v) => v,
         Err(e)=>{
@@ -319,8 +311,11 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_verifyVoteResul
     _class: JClass,
     poll_parameters: JString,
     vote_sum: JString,
+    vote_root: jbyteArray,
+    accepted_votes: JString,
     aggregated_decrypted_result: JString,
     vote_result: JString,
+    public_key_bytes: jbyteArray,
 ) -> jobject {
     let result_jobject = get_result_jobject(&_env);
     let pb_poll_parameters = java_safe_jstring_to_pb!(
@@ -331,6 +326,14 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_verifyVoteResul
     );
     let pb_vote_sum =
         java_safe_jstring_to_pb!(_env, result_jobject, vote_sum, VoteStorage);
+    let vote_root_bytes =
+        java_safe_jbytes_to_bytes!(_env, result_jobject, vote_root);
+    let pb_accepted_votes = java_safe_jstring_to_pb!(
+        _env,
+        result_jobject,
+        accepted_votes,
+        AcceptedVoteList
+    );
     let pb_aggregated_decrypted_result = java_safe_jstring_to_pb!(
         _env,
         result_jobject,
@@ -343,12 +346,17 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_verifyVoteResul
         vote_result,
         VoteResultStorage
     );
+    let public_key =
+        java_safe_jbytes_to_bytes!(_env, result_jobject, public_key_bytes);
     let verify_result =
         match wedpr_s_anonymous_ciphertext_voting::verifier::verify_vote_result(
             &pb_poll_parameters,
             &pb_vote_sum,
+            &vote_root_bytes,
+            pb_accepted_votes.get_vote(),
             &pb_aggregated_decrypted_result,
             &pb_vote_result,
+            &public_key,
         ) {
             Ok(v) => v,
             Err(e) => {
@@ -4,7 +4,7 @@
 //! Java-compatible architectures (including Android).
 use jni::{
     objects::{JClass, JObject, JString, JValue},
-    sys::jobject,
+    sys::{jbyteArray, jobject},
     JNIEnv,
 };
 
@@ -104,6 +104,7 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_makeUnboundedRe
     zero_secret: JString,
     vote_secret: JString,
     poll_parameters: JString,
+    public_key_bytes: jbyteArray,
 ) -> jobject {
     let result_jobject = get_result_jobject(&_env);
     let pb_zero_secret = java_safe_jstring_to_pb!(
@@ -124,8 +125,10 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_makeUnboundedRe
         poll_parameters,
         PollParametersStorage
     );
+    let public_key =
+        java_safe_jbytes_to_bytes!(_env, result_jobject, public_key_bytes);
     let registration_request = match wedpr_s_anonymous_ciphertext_voting::voter::make_unbounded_registration_request(
-        &pb_zero_secret, &pb_vote_secret, &pb_poll_parameters
+        &pb_zero_secret, &pb_vote_secret, &pb_poll_parameters, &public_key
     )
     {
         Ok(v) => v,
@@ -156,6 +159,7 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_vote(
     vote_choices: JString,
     registration_response: JString,
     poll_parameters: JString,
+    public_key_bytes: jbyteArray,
 ) -> jobject {
     let result_jobject = get_result_jobject(&_env);
     let pb_voter_secret = java_safe_jstring_to_pb!(
@@ -182,11 +186,14 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_vote(
         poll_parameters,
         PollParametersStorage
     );
+    let public_key =
+        java_safe_jbytes_to_bytes!(_env, result_jobject, public_key_bytes);
     let vote_request = match wedpr_s_anonymous_ciphertext_voting::voter::vote(
         &pb_voter_secret,
         &pb_vote_choices,
         &pb_registration_response,
         &pb_poll_parameters,
+        &public_key,
     ) {
         Ok(v) => v,
         Err(e) => {
@@ -217,6 +224,7 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_voteUnbounded(
     vote_choices: JString,
     registration_response: JString,
     poll_parameters: JString,
+    public_key_bytes: jbyteArray,
 ) -> jobject {
     let result_jobject = get_result_jobject(&_env);
     let pb_voter_secret = java_safe_jstring_to_pb!(
@@ -249,6 +257,8 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_voteUnbounded(
         poll_parameters,
         PollParametersStorage
     );
+    let public_key =
+        java_safe_jbytes_to_bytes!(_env, result_jobject, public_key_bytes);
     let vote_request =
         match wedpr_s_anonymous_ciphertext_voting::voter::vote_unbounded(
             &pb_voter_secret,
@@ -256,6 +266,7 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_voteUnbounded(
             &pb_vote_choices,
             &pb_registration_response,
             &pb_poll_parameters,
+            &public_key,
         ) {
             Ok(v) => v,
             Err(e) => {
@@ -287,6 +298,7 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_voteUnboundedUn
     vote_choices: JString,
     registration_response: JString,
     poll_parameters: JString,
+    public_key_bytes: jbyteArray,
 ) -> jobject {
     let result_jobject = get_result_jobject(&_env);
     let pb_voter_secret = java_safe_jstring_to_pb!(
@@ -319,6 +331,8 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_voteUnboundedUn
         poll_parameters,
         PollParametersStorage
     );
+    let public_key =
+        java_safe_jbytes_to_bytes!(_env, result_jobject, public_key_bytes);
     let vote_request =
         match wedpr_s_anonymous_ciphertext_voting::voter::vote_unbounded_unlisted(
             &pb_voter_secret,
@@ -326,6 +340,7 @@ pub extern "system" fn Java_com_webank_wedpr_acv_NativeInterface_voteUnboundedUn
             &pb_vote_choices,
             &pb_registration_response,
             &pb_poll_parameters,
+            &public_key,
         ) {
             Ok(v) => v,
             Err(e) => {
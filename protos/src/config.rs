@@ -1,10 +1,22 @@
 // Copyright 2020 WeDPR Lab Project Authors. Licensed under Apache-2.0.
 
 //! Config of anonymous ciphertext voting (ACV) solution.
+use crate::generated::acv::SignatureScheme;
+#[cfg(feature = "wedpr_f_secp256k1_signature")]
+use wedpr_l_crypto_hash_keccak256::WedprKeccak256;
 #[cfg(feature = "wedpr_f_sm_signature")]
 use wedpr_l_crypto_hash_sm3::WedprSm3;
+#[cfg(feature = "wedpr_f_secp256k1_signature")]
+use wedpr_l_crypto_signature_secp256k1::WedprSecp256k1Recover;
 #[cfg(feature = "wedpr_f_sm_signature")]
 use wedpr_l_crypto_signature_sm2::WedprSm2p256v1;
+
+#[cfg(not(any(
+    feature = "wedpr_f_sm_signature",
+    feature = "wedpr_f_secp256k1_signature"
+)))]
+compile_error!("at least one signature scheme feature must be enabled");
+
 #[cfg(feature = "wedpr_f_sm_signature")]
 lazy_static! {
     /// Shared signature algorithm reference for quick implementation replacement.
@@ -13,12 +25,15 @@ lazy_static! {
     /// Shared hash algorithm reference for quick implementation replacement.
     pub static ref HASH: WedprSm3 = WedprSm3::default();
 }
+/// Signature scheme used when a poll does not specify one, which is the SM
+/// scheme if it is enabled.
+#[cfg(feature = "wedpr_f_sm_signature")]
+pub const DEFAULT_SIGNATURE_SCHEME: SignatureScheme = SignatureScheme::SM2_SM3;
 
-#[cfg(feature = "wedpr_f_secp256k1_signature")]
-use wedpr_l_crypto_hash_keccak256::WedprKeccak256;
-#[cfg(feature = "wedpr_f_secp256k1_signature")]
-use wedpr_l_crypto_signature_secp256k1::WedprSecp256k1Recover;
-#[cfg(feature = "wedpr_f_secp256k1_signature")]
+#[cfg(all(
+    feature = "wedpr_f_secp256k1_signature",
+    not(feature = "wedpr_f_sm_signature")
+))]
 lazy_static! {
     /// Shared signature algorithm reference for quick implementation replacement.
     pub static ref SIGNATURE: WedprSecp256k1Recover =
@@ -26,3 +41,42 @@ lazy_static! {
     /// Shared hash algorithm reference for quick implementation replacement.
     pub static ref HASH: WedprKeccak256 = WedprKeccak256::default();
 }
+/// Signature scheme used when a poll does not specify one, which is the SM
+/// scheme if it is enabled.
+#[cfg(all(
+    feature = "wedpr_f_secp256k1_signature",
+    not(feature = "wedpr_f_sm_signature")
+))]
+pub const DEFAULT_SIGNATURE_SCHEME: SignatureScheme =
+    SignatureScheme::SECP256K1_KECCAK256;
+
+#[cfg(feature = "wedpr_f_secp256k1_signature")]
+lazy_static! {
+    /// Signature algorithm of the secp256k1 scheme selectable at runtime.
+    pub static ref SECP256K1_SIGNATURE: WedprSecp256k1Recover =
+        WedprSecp256k1Recover::default();
+    /// Hash algorithm of the secp256k1 scheme selectable at runtime.
+    pub static ref KECCAK256_HASH: WedprKeccak256 = WedprKeccak256::default();
+}
+
+#[cfg(feature = "wedpr_f_sm_signature")]
+lazy_static! {
+    /// Signature algorithm of the SM scheme selectable at runtime.
+    pub static ref SM2_SIGNATURE: WedprSm2p256v1 = WedprSm2p256v1::default();
+    /// Hash algorithm of the SM scheme selectable at runtime.
+    pub static ref SM3_HASH: WedprSm3 = WedprSm3::default();
+}
+
+/// Domain tag of the signed payload of blank ballots.
+pub const BALLOT_SIGNATURE_DOMAIN: &str = "Wedpr_voting_ballot_signature";
+/// Domain tag of the signed poll id of the poll parameters.
+pub const POLL_SIGNATURE_DOMAIN: &str = "Wedpr_voting_poll_signature";
+/// Encoding version of the signed blank ballots used by old polls, which is
+/// the raw concatenation of the ciphertexts.
+pub const LEGACY_BALLOT_SIGNATURE_VERSION: u32 = 0;
+/// Encoding version of the signed blank ballots used by new polls.
+pub const BALLOT_SIGNATURE_VERSION: u32 = 1;
+/// Message type of a signed blank ballot.
+pub const BALLOT_SIGNATURE_MESSAGE_BALLOT: u32 = 1;
+/// Message type of a signed pair of weight and zero ballots.
+pub const BALLOT_SIGNATURE_MESSAGE_BALLOTS: u32 = 2;
@@ -186,6 +186,15 @@ pub struct PollParametersStorage {
     // message fields
     pub poll_point: ::std::vec::Vec<u8>,
     pub candidates: ::protobuf::SingularPtrField<CandidateList>,
+    pub min_unlisted_candidate_id: u32,
+    pub max_unlisted_candidate_id: u32,
+    pub question: ::protobuf::RepeatedField<Question>,
+    pub signature_scheme: SignatureScheme,
+    pub poll_id: ::std::vec::Vec<u8>,
+    pub ballot_signature_version: u32,
+    pub against_voting: bool,
+    pub counter_parameters: ::protobuf::SingularPtrField<CounterParametersStorage>,
+    pub poll_signature: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -260,6 +269,191 @@ impl PollParametersStorage {
     pub fn take_candidates(&mut self) -> CandidateList {
         self.candidates.take().unwrap_or_else(|| CandidateList::new())
     }
+
+    // uint32 min_unlisted_candidate_id = 3;
+
+
+    pub fn get_min_unlisted_candidate_id(&self) -> u32 {
+        self.min_unlisted_candidate_id
+    }
+    pub fn clear_min_unlisted_candidate_id(&mut self) {
+        self.min_unlisted_candidate_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_min_unlisted_candidate_id(&mut self, v: u32) {
+        self.min_unlisted_candidate_id = v;
+    }
+
+    // uint32 max_unlisted_candidate_id = 4;
+
+
+    pub fn get_max_unlisted_candidate_id(&self) -> u32 {
+        self.max_unlisted_candidate_id
+    }
+    pub fn clear_max_unlisted_candidate_id(&mut self) {
+        self.max_unlisted_candidate_id = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_unlisted_candidate_id(&mut self, v: u32) {
+        self.max_unlisted_candidate_id = v;
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.Question question = 5;
+
+
+    pub fn get_question(&self) -> &[Question] {
+        &self.question
+    }
+    pub fn clear_question(&mut self) {
+        self.question.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_question(&mut self, v: ::protobuf::RepeatedField<Question>) {
+        self.question = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_question(&mut self) -> &mut ::protobuf::RepeatedField<Question> {
+        &mut self.question
+    }
+
+    // Take field
+    pub fn take_question(&mut self) -> ::protobuf::RepeatedField<Question> {
+        ::std::mem::replace(&mut self.question, ::protobuf::RepeatedField::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.SignatureScheme signature_scheme = 6;
+
+
+    pub fn get_signature_scheme(&self) -> SignatureScheme {
+        self.signature_scheme
+    }
+    pub fn clear_signature_scheme(&mut self) {
+        self.signature_scheme = SignatureScheme::DEFAULT_SCHEME;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature_scheme(&mut self, v: SignatureScheme) {
+        self.signature_scheme = v;
+    }
+
+    // bytes poll_id = 7;
+
+
+    pub fn get_poll_id(&self) -> &[u8] {
+        &self.poll_id
+    }
+    pub fn clear_poll_id(&mut self) {
+        self.poll_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_poll_id(&mut self, v: ::std::vec::Vec<u8>) {
+        self.poll_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_poll_id(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.poll_id
+    }
+
+    // Take field
+    pub fn take_poll_id(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.poll_id, ::std::vec::Vec::new())
+    }
+
+    // uint32 ballot_signature_version = 8;
+
+
+    pub fn get_ballot_signature_version(&self) -> u32 {
+        self.ballot_signature_version
+    }
+    pub fn clear_ballot_signature_version(&mut self) {
+        self.ballot_signature_version = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ballot_signature_version(&mut self, v: u32) {
+        self.ballot_signature_version = v;
+    }
+
+    // bool against_voting = 9;
+
+
+    pub fn get_against_voting(&self) -> bool {
+        self.against_voting
+    }
+    pub fn clear_against_voting(&mut self) {
+        self.against_voting = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_against_voting(&mut self, v: bool) {
+        self.against_voting = v;
+    }
+
+    // .com.webank.wedpr.acv.proto.CounterParametersStorage counter_parameters = 10;
+
+
+    pub fn get_counter_parameters(&self) -> &CounterParametersStorage {
+        self.counter_parameters.as_ref().unwrap_or_else(|| <CounterParametersStorage as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_counter_parameters(&mut self) {
+        self.counter_parameters.clear();
+    }
+
+    pub fn has_counter_parameters(&self) -> bool {
+        self.counter_parameters.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_counter_parameters(&mut self, v: CounterParametersStorage) {
+        self.counter_parameters = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_counter_parameters(&mut self) -> &mut CounterParametersStorage {
+        if self.counter_parameters.is_none() {
+            self.counter_parameters.set_default();
+        }
+        self.counter_parameters.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_counter_parameters(&mut self) -> CounterParametersStorage {
+        self.counter_parameters.take().unwrap_or_else(|| CounterParametersStorage::new())
+    }
+
+    // bytes poll_signature = 11;
+
+
+    pub fn get_poll_signature(&self) -> &[u8] {
+        &self.poll_signature
+    }
+    pub fn clear_poll_signature(&mut self) {
+        self.poll_signature.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_poll_signature(&mut self, v: ::std::vec::Vec<u8>) {
+        self.poll_signature = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_poll_signature(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.poll_signature
+    }
+
+    // Take field
+    pub fn take_poll_signature(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.poll_signature, ::std::vec::Vec::new())
+    }
 }
 
 impl ::protobuf::Message for PollParametersStorage {
@@ -269,6 +463,16 @@ impl ::protobuf::Message for PollParametersStorage {
                 return false;
             }
         };
+        for v in &self.question {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.counter_parameters {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -282,6 +486,49 @@ impl ::protobuf::Message for PollParametersStorage {
                 2 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.candidates)?;
                 },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.min_unlisted_candidate_id = tmp;
+                },
+                4 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.max_unlisted_candidate_id = tmp;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.question)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.signature_scheme, 6, &mut self.unknown_fields)?
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.poll_id)?;
+                },
+                8 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.ballot_signature_version = tmp;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.against_voting = tmp;
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.counter_parameters)?;
+                },
+                11 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.poll_signature)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -301,6 +548,35 @@ impl ::protobuf::Message for PollParametersStorage {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        if self.min_unlisted_candidate_id != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.min_unlisted_candidate_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.max_unlisted_candidate_id != 0 {
+            my_size += ::protobuf::rt::value_size(4, self.max_unlisted_candidate_id, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.question {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if self.signature_scheme != SignatureScheme::DEFAULT_SCHEME {
+            my_size += ::protobuf::rt::enum_size(6, self.signature_scheme);
+        }
+        if !self.poll_id.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(7, &self.poll_id);
+        }
+        if self.ballot_signature_version != 0 {
+            my_size += ::protobuf::rt::value_size(8, self.ballot_signature_version, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.against_voting != false {
+            my_size += 2;
+        }
+        if let Some(ref v) = self.counter_parameters.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.poll_signature.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(11, &self.poll_signature);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -315,6 +591,37 @@ impl ::protobuf::Message for PollParametersStorage {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        if self.min_unlisted_candidate_id != 0 {
+            os.write_uint32(3, self.min_unlisted_candidate_id)?;
+        }
+        if self.max_unlisted_candidate_id != 0 {
+            os.write_uint32(4, self.max_unlisted_candidate_id)?;
+        }
+        for v in &self.question {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if self.signature_scheme != SignatureScheme::DEFAULT_SCHEME {
+            os.write_enum(6, ::protobuf::ProtobufEnum::value(&self.signature_scheme))?;
+        }
+        if !self.poll_id.is_empty() {
+            os.write_bytes(7, &self.poll_id)?;
+        }
+        if self.ballot_signature_version != 0 {
+            os.write_uint32(8, self.ballot_signature_version)?;
+        }
+        if self.against_voting != false {
+            os.write_bool(9, self.against_voting)?;
+        }
+        if let Some(ref v) = self.counter_parameters.as_ref() {
+            os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.poll_signature.is_empty() {
+            os.write_bytes(11, &self.poll_signature)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -363,6 +670,51 @@ impl ::protobuf::Message for PollParametersStorage {
                 |m: &PollParametersStorage| { &m.candidates },
                 |m: &mut PollParametersStorage| { &mut m.candidates },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "min_unlisted_candidate_id",
+                |m: &PollParametersStorage| { &m.min_unlisted_candidate_id },
+                |m: &mut PollParametersStorage| { &mut m.min_unlisted_candidate_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "max_unlisted_candidate_id",
+                |m: &PollParametersStorage| { &m.max_unlisted_candidate_id },
+                |m: &mut PollParametersStorage| { &mut m.max_unlisted_candidate_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Question>>(
+                "question",
+                |m: &PollParametersStorage| { &m.question },
+                |m: &mut PollParametersStorage| { &mut m.question },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<SignatureScheme>>(
+                "signature_scheme",
+                |m: &PollParametersStorage| { &m.signature_scheme },
+                |m: &mut PollParametersStorage| { &mut m.signature_scheme },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "poll_id",
+                |m: &PollParametersStorage| { &m.poll_id },
+                |m: &mut PollParametersStorage| { &mut m.poll_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "ballot_signature_version",
+                |m: &PollParametersStorage| { &m.ballot_signature_version },
+                |m: &mut PollParametersStorage| { &mut m.ballot_signature_version },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "against_voting",
+                |m: &PollParametersStorage| { &m.against_voting },
+                |m: &mut PollParametersStorage| { &mut m.against_voting },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CounterParametersStorage>>(
+                "counter_parameters",
+                |m: &PollParametersStorage| { &m.counter_parameters },
+                |m: &mut PollParametersStorage| { &mut m.counter_parameters },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "poll_signature",
+                |m: &PollParametersStorage| { &m.poll_signature },
+                |m: &mut PollParametersStorage| { &mut m.poll_signature },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<PollParametersStorage>(
                 "PollParametersStorage",
                 fields,
@@ -381,6 +733,15 @@ impl ::protobuf::Clear for PollParametersStorage {
     fn clear(&mut self) {
         self.poll_point.clear();
         self.candidates.clear();
+        self.min_unlisted_candidate_id = 0;
+        self.max_unlisted_candidate_id = 0;
+        self.question.clear();
+        self.signature_scheme = SignatureScheme::DEFAULT_SCHEME;
+        self.poll_id.clear();
+        self.ballot_signature_version = 0;
+        self.against_voting = false;
+        self.counter_parameters.clear();
+        self.poll_signature.clear();
         self.unknown_fields.clear();
     }
 }
@@ -398,54 +759,93 @@ impl ::protobuf::reflect::ProtobufValue for PollParametersStorage {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct CounterSecret {
+pub struct Question {
     // message fields
-    pub poll_secret_share: ::std::vec::Vec<u8>,
+    pub question_id: ::std::string::String,
+    pub candidates: ::protobuf::SingularPtrField<CandidateList>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a CounterSecret {
-    fn default() -> &'a CounterSecret {
-        <CounterSecret as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Question {
+    fn default() -> &'a Question {
+        <Question as ::protobuf::Message>::default_instance()
     }
 }
 
-impl CounterSecret {
-    pub fn new() -> CounterSecret {
+impl Question {
+    pub fn new() -> Question {
         ::std::default::Default::default()
     }
 
-    // bytes poll_secret_share = 1;
+    // string question_id = 1;
 
 
-    pub fn get_poll_secret_share(&self) -> &[u8] {
-        &self.poll_secret_share
+    pub fn get_question_id(&self) -> &str {
+        &self.question_id
     }
-    pub fn clear_poll_secret_share(&mut self) {
-        self.poll_secret_share.clear();
+    pub fn clear_question_id(&mut self) {
+        self.question_id.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_poll_secret_share(&mut self, v: ::std::vec::Vec<u8>) {
-        self.poll_secret_share = v;
+    pub fn set_question_id(&mut self, v: ::std::string::String) {
+        self.question_id = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_poll_secret_share(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.poll_secret_share
+    pub fn mut_question_id(&mut self) -> &mut ::std::string::String {
+        &mut self.question_id
     }
 
     // Take field
-    pub fn take_poll_secret_share(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.poll_secret_share, ::std::vec::Vec::new())
+    pub fn take_question_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.question_id, ::std::string::String::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.CandidateList candidates = 2;
+
+
+    pub fn get_candidates(&self) -> &CandidateList {
+        self.candidates.as_ref().unwrap_or_else(|| <CandidateList as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_candidates(&mut self) {
+        self.candidates.clear();
+    }
+
+    pub fn has_candidates(&self) -> bool {
+        self.candidates.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_candidates(&mut self, v: CandidateList) {
+        self.candidates = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_candidates(&mut self) -> &mut CandidateList {
+        if self.candidates.is_none() {
+            self.candidates.set_default();
+        }
+        self.candidates.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_candidates(&mut self) -> CandidateList {
+        self.candidates.take().unwrap_or_else(|| CandidateList::new())
     }
 }
 
-impl ::protobuf::Message for CounterSecret {
+impl ::protobuf::Message for Question {
     fn is_initialized(&self) -> bool {
+        for v in &self.candidates {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -454,7 +854,10 @@ impl ::protobuf::Message for CounterSecret {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.poll_secret_share)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.question_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.candidates)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -468,8 +871,12 @@ impl ::protobuf::Message for CounterSecret {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.poll_secret_share.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.poll_secret_share);
+        if !self.question_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.question_id);
+        }
+        if let Some(ref v) = self.candidates.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -477,8 +884,13 @@ impl ::protobuf::Message for CounterSecret {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.poll_secret_share.is_empty() {
-            os.write_bytes(1, &self.poll_secret_share)?;
+        if !self.question_id.is_empty() {
+            os.write_string(1, &self.question_id)?;
+        }
+        if let Some(ref v) = self.candidates.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -510,8 +922,173 @@ impl ::protobuf::Message for CounterSecret {
         Self::descriptor_static()
     }
 
-    fn new() -> CounterSecret {
-        CounterSecret::new()
+    fn new() -> Question {
+        Question::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "question_id",
+                |m: &Question| { &m.question_id },
+                |m: &mut Question| { &mut m.question_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CandidateList>>(
+                "candidates",
+                |m: &Question| { &m.candidates },
+                |m: &mut Question| { &mut m.candidates },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Question>(
+                "Question",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Question {
+        static instance: ::protobuf::rt::LazyV2<Question> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Question::new)
+    }
+}
+
+impl ::protobuf::Clear for Question {
+    fn clear(&mut self) {
+        self.question_id.clear();
+        self.candidates.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Question {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Question {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CounterSecret {
+    // message fields
+    pub poll_secret_share: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CounterSecret {
+    fn default() -> &'a CounterSecret {
+        <CounterSecret as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CounterSecret {
+    pub fn new() -> CounterSecret {
+        ::std::default::Default::default()
+    }
+
+    // bytes poll_secret_share = 1;
+
+
+    pub fn get_poll_secret_share(&self) -> &[u8] {
+        &self.poll_secret_share
+    }
+    pub fn clear_poll_secret_share(&mut self) {
+        self.poll_secret_share.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_poll_secret_share(&mut self, v: ::std::vec::Vec<u8>) {
+        self.poll_secret_share = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_poll_secret_share(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.poll_secret_share
+    }
+
+    // Take field
+    pub fn take_poll_secret_share(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.poll_secret_share, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for CounterSecret {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.poll_secret_share)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.poll_secret_share.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.poll_secret_share);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.poll_secret_share.is_empty() {
+            os.write_bytes(1, &self.poll_secret_share)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CounterSecret {
+        CounterSecret::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
@@ -1154,6 +1731,10 @@ pub struct RegistrationResponse {
     pub ballot: ::protobuf::SingularPtrField<Ballot>,
     pub signature: ::std::vec::Vec<u8>,
     pub zero_ballot: ::protobuf::SingularPtrField<Ballot>,
+    pub certificate: ::protobuf::SingularPtrField<BlindCertificate>,
+    pub weight_key_point: ::std::vec::Vec<u8>,
+    pub encrypted_voter_weight: u32,
+    pub signature_scheme: SignatureScheme,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -1276,6 +1857,95 @@ impl RegistrationResponse {
     pub fn take_zero_ballot(&mut self) -> Ballot {
         self.zero_ballot.take().unwrap_or_else(|| Ballot::new())
     }
+
+    // .com.webank.wedpr.acv.proto.BlindCertificate certificate = 5;
+
+
+    pub fn get_certificate(&self) -> &BlindCertificate {
+        self.certificate.as_ref().unwrap_or_else(|| <BlindCertificate as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_certificate(&mut self) {
+        self.certificate.clear();
+    }
+
+    pub fn has_certificate(&self) -> bool {
+        self.certificate.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_certificate(&mut self, v: BlindCertificate) {
+        self.certificate = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_certificate(&mut self) -> &mut BlindCertificate {
+        if self.certificate.is_none() {
+            self.certificate.set_default();
+        }
+        self.certificate.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_certificate(&mut self) -> BlindCertificate {
+        self.certificate.take().unwrap_or_else(|| BlindCertificate::new())
+    }
+
+    // bytes weight_key_point = 6;
+
+
+    pub fn get_weight_key_point(&self) -> &[u8] {
+        &self.weight_key_point
+    }
+    pub fn clear_weight_key_point(&mut self) {
+        self.weight_key_point.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_weight_key_point(&mut self, v: ::std::vec::Vec<u8>) {
+        self.weight_key_point = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_weight_key_point(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.weight_key_point
+    }
+
+    // Take field
+    pub fn take_weight_key_point(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.weight_key_point, ::std::vec::Vec::new())
+    }
+
+    // uint32 encrypted_voter_weight = 7;
+
+
+    pub fn get_encrypted_voter_weight(&self) -> u32 {
+        self.encrypted_voter_weight
+    }
+    pub fn clear_encrypted_voter_weight(&mut self) {
+        self.encrypted_voter_weight = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_encrypted_voter_weight(&mut self, v: u32) {
+        self.encrypted_voter_weight = v;
+    }
+
+    // .com.webank.wedpr.acv.proto.SignatureScheme signature_scheme = 8;
+
+
+    pub fn get_signature_scheme(&self) -> SignatureScheme {
+        self.signature_scheme
+    }
+    pub fn clear_signature_scheme(&mut self) {
+        self.signature_scheme = SignatureScheme::DEFAULT_SCHEME;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature_scheme(&mut self, v: SignatureScheme) {
+        self.signature_scheme = v;
+    }
 }
 
 impl ::protobuf::Message for RegistrationResponse {
@@ -1290,6 +1960,11 @@ impl ::protobuf::Message for RegistrationResponse {
                 return false;
             }
         };
+        for v in &self.certificate {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -1313,6 +1988,22 @@ impl ::protobuf::Message for RegistrationResponse {
                 4 => {
                     ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.zero_ballot)?;
                 },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.certificate)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.weight_key_point)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.encrypted_voter_weight = tmp;
+                },
+                8 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.signature_scheme, 8, &mut self.unknown_fields)?
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -1339,6 +2030,19 @@ impl ::protobuf::Message for RegistrationResponse {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
+        if let Some(ref v) = self.certificate.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.weight_key_point.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(6, &self.weight_key_point);
+        }
+        if self.encrypted_voter_weight != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.encrypted_voter_weight, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.signature_scheme != SignatureScheme::DEFAULT_SCHEME {
+            my_size += ::protobuf::rt::enum_size(8, self.signature_scheme);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -1361,6 +2065,20 @@ impl ::protobuf::Message for RegistrationResponse {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         }
+        if let Some(ref v) = self.certificate.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.weight_key_point.is_empty() {
+            os.write_bytes(6, &self.weight_key_point)?;
+        }
+        if self.encrypted_voter_weight != 0 {
+            os.write_uint32(7, self.encrypted_voter_weight)?;
+        }
+        if self.signature_scheme != SignatureScheme::DEFAULT_SCHEME {
+            os.write_enum(8, ::protobuf::ProtobufEnum::value(&self.signature_scheme))?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -1419,6 +2137,26 @@ impl ::protobuf::Message for RegistrationResponse {
                 |m: &RegistrationResponse| { &m.zero_ballot },
                 |m: &mut RegistrationResponse| { &mut m.zero_ballot },
             ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BlindCertificate>>(
+                "certificate",
+                |m: &RegistrationResponse| { &m.certificate },
+                |m: &mut RegistrationResponse| { &mut m.certificate },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "weight_key_point",
+                |m: &RegistrationResponse| { &m.weight_key_point },
+                |m: &mut RegistrationResponse| { &mut m.weight_key_point },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "encrypted_voter_weight",
+                |m: &RegistrationResponse| { &m.encrypted_voter_weight },
+                |m: &mut RegistrationResponse| { &mut m.encrypted_voter_weight },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<SignatureScheme>>(
+                "signature_scheme",
+                |m: &RegistrationResponse| { &m.signature_scheme },
+                |m: &mut RegistrationResponse| { &mut m.signature_scheme },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<RegistrationResponse>(
                 "RegistrationResponse",
                 fields,
@@ -1439,6 +2177,10 @@ impl ::protobuf::Clear for RegistrationResponse {
         self.ballot.clear();
         self.signature.clear();
         self.zero_ballot.clear();
+        self.certificate.clear();
+        self.weight_key_point.clear();
+        self.encrypted_voter_weight = 0;
+        self.signature_scheme = SignatureScheme::DEFAULT_SCHEME;
         self.unknown_fields.clear();
     }
 }
@@ -2028,6 +2770,7 @@ pub struct VoteChoice {
     // message fields
     pub candidate: ::std::string::String,
     pub value: u32,
+    pub against_value: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -2084,6 +2827,21 @@ impl VoteChoice {
     pub fn set_value(&mut self, v: u32) {
         self.value = v;
     }
+
+    // uint32 against_value = 3;
+
+
+    pub fn get_against_value(&self) -> u32 {
+        self.against_value
+    }
+    pub fn clear_against_value(&mut self) {
+        self.against_value = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_against_value(&mut self, v: u32) {
+        self.against_value = v;
+    }
 }
 
 impl ::protobuf::Message for VoteChoice {
@@ -2105,6 +2863,13 @@ impl ::protobuf::Message for VoteChoice {
                     let tmp = is.read_uint32()?;
                     self.value = tmp;
                 },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.against_value = tmp;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2123,6 +2888,9 @@ impl ::protobuf::Message for VoteChoice {
         if self.value != 0 {
             my_size += ::protobuf::rt::value_size(2, self.value, ::protobuf::wire_format::WireTypeVarint);
         }
+        if self.against_value != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.against_value, ::protobuf::wire_format::WireTypeVarint);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2135,6 +2903,9 @@ impl ::protobuf::Message for VoteChoice {
         if self.value != 0 {
             os.write_uint32(2, self.value)?;
         }
+        if self.against_value != 0 {
+            os.write_uint32(3, self.against_value)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2183,6 +2954,11 @@ impl ::protobuf::Message for VoteChoice {
                 |m: &VoteChoice| { &m.value },
                 |m: &mut VoteChoice| { &mut m.value },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "against_value",
+                |m: &VoteChoice| { &m.against_value },
+                |m: &mut VoteChoice| { &mut m.against_value },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<VoteChoice>(
                 "VoteChoice",
                 fields,
@@ -2201,6 +2977,7 @@ impl ::protobuf::Clear for VoteChoice {
     fn clear(&mut self) {
         self.candidate.clear();
         self.value = 0;
+        self.against_value = 0;
         self.unknown_fields.clear();
     }
 }
@@ -2409,6 +3186,8 @@ pub struct VoteChoices {
     // message fields
     pub choice: ::protobuf::RepeatedField<VoteChoice>,
     pub unlisted_choice: ::protobuf::RepeatedField<UnlistedVoteChoice>,
+    pub sequence: u32,
+    pub question_choices: ::protobuf::RepeatedField<QuestionVoteChoices>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -2474,12 +3253,52 @@ impl VoteChoices {
     pub fn take_unlisted_choice(&mut self) -> ::protobuf::RepeatedField<UnlistedVoteChoice> {
         ::std::mem::replace(&mut self.unlisted_choice, ::protobuf::RepeatedField::new())
     }
-}
 
-impl ::protobuf::Message for VoteChoices {
-    fn is_initialized(&self) -> bool {
-        for v in &self.choice {
-            if !v.is_initialized() {
+    // uint32 sequence = 3;
+
+
+    pub fn get_sequence(&self) -> u32 {
+        self.sequence
+    }
+    pub fn clear_sequence(&mut self) {
+        self.sequence = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sequence(&mut self, v: u32) {
+        self.sequence = v;
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.QuestionVoteChoices question_choices = 4;
+
+
+    pub fn get_question_choices(&self) -> &[QuestionVoteChoices] {
+        &self.question_choices
+    }
+    pub fn clear_question_choices(&mut self) {
+        self.question_choices.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_question_choices(&mut self, v: ::protobuf::RepeatedField<QuestionVoteChoices>) {
+        self.question_choices = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_question_choices(&mut self) -> &mut ::protobuf::RepeatedField<QuestionVoteChoices> {
+        &mut self.question_choices
+    }
+
+    // Take field
+    pub fn take_question_choices(&mut self) -> ::protobuf::RepeatedField<QuestionVoteChoices> {
+        ::std::mem::replace(&mut self.question_choices, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for VoteChoices {
+    fn is_initialized(&self) -> bool {
+        for v in &self.choice {
+            if !v.is_initialized() {
                 return false;
             }
         };
@@ -2488,6 +3307,11 @@ impl ::protobuf::Message for VoteChoices {
                 return false;
             }
         };
+        for v in &self.question_choices {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -2501,6 +3325,16 @@ impl ::protobuf::Message for VoteChoices {
                 2 => {
                     ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.unlisted_choice)?;
                 },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.sequence = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.question_choices)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2521,6 +3355,13 @@ impl ::protobuf::Message for VoteChoices {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        if self.sequence != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.sequence, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.question_choices {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2537,6 +3378,14 @@ impl ::protobuf::Message for VoteChoices {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         };
+        if self.sequence != 0 {
+            os.write_uint32(3, self.sequence)?;
+        }
+        for v in &self.question_choices {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -2585,6 +3434,16 @@ impl ::protobuf::Message for VoteChoices {
                 |m: &VoteChoices| { &m.unlisted_choice },
                 |m: &mut VoteChoices| { &mut m.unlisted_choice },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "sequence",
+                |m: &VoteChoices| { &m.sequence },
+                |m: &mut VoteChoices| { &mut m.sequence },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<QuestionVoteChoices>>(
+                "question_choices",
+                |m: &VoteChoices| { &m.question_choices },
+                |m: &mut VoteChoices| { &mut m.question_choices },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<VoteChoices>(
                 "VoteChoices",
                 fields,
@@ -2603,6 +3462,8 @@ impl ::protobuf::Clear for VoteChoices {
     fn clear(&mut self) {
         self.choice.clear();
         self.unlisted_choice.clear();
+        self.sequence = 0;
+        self.question_choices.clear();
         self.unknown_fields.clear();
     }
 }
@@ -2619,6 +3480,214 @@ impl ::protobuf::reflect::ProtobufValue for VoteChoices {
     }
 }
 
+#[derive(PartialEq,Clone,Default)]
+pub struct QuestionVoteChoices {
+    // message fields
+    pub question_id: ::std::string::String,
+    pub choice: ::protobuf::RepeatedField<VoteChoice>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QuestionVoteChoices {
+    fn default() -> &'a QuestionVoteChoices {
+        <QuestionVoteChoices as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QuestionVoteChoices {
+    pub fn new() -> QuestionVoteChoices {
+        ::std::default::Default::default()
+    }
+
+    // string question_id = 1;
+
+
+    pub fn get_question_id(&self) -> &str {
+        &self.question_id
+    }
+    pub fn clear_question_id(&mut self) {
+        self.question_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_question_id(&mut self, v: ::std::string::String) {
+        self.question_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_question_id(&mut self) -> &mut ::std::string::String {
+        &mut self.question_id
+    }
+
+    // Take field
+    pub fn take_question_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.question_id, ::std::string::String::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.VoteChoice choice = 2;
+
+
+    pub fn get_choice(&self) -> &[VoteChoice] {
+        &self.choice
+    }
+    pub fn clear_choice(&mut self) {
+        self.choice.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_choice(&mut self, v: ::protobuf::RepeatedField<VoteChoice>) {
+        self.choice = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_choice(&mut self) -> &mut ::protobuf::RepeatedField<VoteChoice> {
+        &mut self.choice
+    }
+
+    // Take field
+    pub fn take_choice(&mut self) -> ::protobuf::RepeatedField<VoteChoice> {
+        ::std::mem::replace(&mut self.choice, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for QuestionVoteChoices {
+    fn is_initialized(&self) -> bool {
+        for v in &self.choice {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.question_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.choice)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.question_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.question_id);
+        }
+        for value in &self.choice {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.question_id.is_empty() {
+            os.write_string(1, &self.question_id)?;
+        }
+        for v in &self.choice {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QuestionVoteChoices {
+        QuestionVoteChoices::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "question_id",
+                |m: &QuestionVoteChoices| { &m.question_id },
+                |m: &mut QuestionVoteChoices| { &mut m.question_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<VoteChoice>>(
+                "choice",
+                |m: &QuestionVoteChoices| { &m.choice },
+                |m: &mut QuestionVoteChoices| { &mut m.choice },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QuestionVoteChoices>(
+                "QuestionVoteChoices",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QuestionVoteChoices {
+        static instance: ::protobuf::rt::LazyV2<QuestionVoteChoices> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QuestionVoteChoices::new)
+    }
+}
+
+impl ::protobuf::Clear for QuestionVoteChoices {
+    fn clear(&mut self) {
+        self.question_id.clear();
+        self.choice.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QuestionVoteChoices {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QuestionVoteChoices {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
 #[derive(PartialEq,Clone,Default)]
 pub struct CandidateBallot {
     // message fields
@@ -2840,6 +3909,7 @@ pub struct BallotProof {
     // message fields
     pub format_proof: ::std::vec::Vec<u8>,
     pub either_equality_proof: ::std::vec::Vec<u8>,
+    pub cost_proof: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -2907,6 +3977,32 @@ impl BallotProof {
     pub fn take_either_equality_proof(&mut self) -> ::std::vec::Vec<u8> {
         ::std::mem::replace(&mut self.either_equality_proof, ::std::vec::Vec::new())
     }
+
+    // bytes cost_proof = 3;
+
+
+    pub fn get_cost_proof(&self) -> &[u8] {
+        &self.cost_proof
+    }
+    pub fn clear_cost_proof(&mut self) {
+        self.cost_proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cost_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.cost_proof = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cost_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.cost_proof
+    }
+
+    // Take field
+    pub fn take_cost_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.cost_proof, ::std::vec::Vec::new())
+    }
 }
 
 impl ::protobuf::Message for BallotProof {
@@ -2924,6 +4020,9 @@ impl ::protobuf::Message for BallotProof {
                 2 => {
                     ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.either_equality_proof)?;
                 },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.cost_proof)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -2942,6 +4041,9 @@ impl ::protobuf::Message for BallotProof {
         if !self.either_equality_proof.is_empty() {
             my_size += ::protobuf::rt::bytes_size(2, &self.either_equality_proof);
         }
+        if !self.cost_proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.cost_proof);
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -2954,6 +4056,9 @@ impl ::protobuf::Message for BallotProof {
         if !self.either_equality_proof.is_empty() {
             os.write_bytes(2, &self.either_equality_proof)?;
         }
+        if !self.cost_proof.is_empty() {
+            os.write_bytes(3, &self.cost_proof)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3002,6 +4107,11 @@ impl ::protobuf::Message for BallotProof {
                 |m: &BallotProof| { &m.either_equality_proof },
                 |m: &mut BallotProof| { &mut m.either_equality_proof },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "cost_proof",
+                |m: &BallotProof| { &m.cost_proof },
+                |m: &mut BallotProof| { &mut m.cost_proof },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<BallotProof>(
                 "BallotProof",
                 fields,
@@ -3020,6 +4130,7 @@ impl ::protobuf::Clear for BallotProof {
     fn clear(&mut self) {
         self.format_proof.clear();
         self.either_equality_proof.clear();
+        self.cost_proof.clear();
         self.unknown_fields.clear();
     }
 }
@@ -3260,6 +4371,14 @@ pub struct VoteRequest {
     pub range_proof: ::std::vec::Vec<u8>,
     pub sum_balance_proof: ::std::vec::Vec<u8>,
     pub unlisted_ballot_proof: ::protobuf::RepeatedField<CipherPointsToBallotProofPair>,
+    pub nullifier_proof: ::std::vec::Vec<u8>,
+    pub sequence_binding_proof: ::std::vec::Vec<u8>,
+    pub question_proof: ::protobuf::RepeatedField<QuestionBallotProof>,
+    pub rest_format_proof: ::std::vec::Vec<u8>,
+    pub credential_proof: ::protobuf::SingularPtrField<CredentialProof>,
+    pub against_ballot_proof: ::protobuf::RepeatedField<StringToBallotProofPair>,
+    pub aggregated_format_proof: ::protobuf::SingularPtrField<AggregatedFormatProof>,
+    pub aggregated_either_equality_proof: ::protobuf::SingularPtrField<AggregatedEitherEqualityProof>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
@@ -3410,24 +4529,276 @@ impl VoteRequest {
     pub fn take_unlisted_ballot_proof(&mut self) -> ::protobuf::RepeatedField<CipherPointsToBallotProofPair> {
         ::std::mem::replace(&mut self.unlisted_ballot_proof, ::protobuf::RepeatedField::new())
     }
-}
 
-impl ::protobuf::Message for VoteRequest {
-    fn is_initialized(&self) -> bool {
-        for v in &self.vote {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.ballot_proof {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.unlisted_ballot_proof {
-            if !v.is_initialized() {
-                return false;
-            }
+    // bytes nullifier_proof = 6;
+
+
+    pub fn get_nullifier_proof(&self) -> &[u8] {
+        &self.nullifier_proof
+    }
+    pub fn clear_nullifier_proof(&mut self) {
+        self.nullifier_proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nullifier_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.nullifier_proof = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nullifier_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.nullifier_proof
+    }
+
+    // Take field
+    pub fn take_nullifier_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.nullifier_proof, ::std::vec::Vec::new())
+    }
+
+    // bytes sequence_binding_proof = 7;
+
+
+    pub fn get_sequence_binding_proof(&self) -> &[u8] {
+        &self.sequence_binding_proof
+    }
+    pub fn clear_sequence_binding_proof(&mut self) {
+        self.sequence_binding_proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sequence_binding_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.sequence_binding_proof = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sequence_binding_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.sequence_binding_proof
+    }
+
+    // Take field
+    pub fn take_sequence_binding_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.sequence_binding_proof, ::std::vec::Vec::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.QuestionBallotProof question_proof = 8;
+
+
+    pub fn get_question_proof(&self) -> &[QuestionBallotProof] {
+        &self.question_proof
+    }
+    pub fn clear_question_proof(&mut self) {
+        self.question_proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_question_proof(&mut self, v: ::protobuf::RepeatedField<QuestionBallotProof>) {
+        self.question_proof = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_question_proof(&mut self) -> &mut ::protobuf::RepeatedField<QuestionBallotProof> {
+        &mut self.question_proof
+    }
+
+    // Take field
+    pub fn take_question_proof(&mut self) -> ::protobuf::RepeatedField<QuestionBallotProof> {
+        ::std::mem::replace(&mut self.question_proof, ::protobuf::RepeatedField::new())
+    }
+
+    // bytes rest_format_proof = 9;
+
+
+    pub fn get_rest_format_proof(&self) -> &[u8] {
+        &self.rest_format_proof
+    }
+    pub fn clear_rest_format_proof(&mut self) {
+        self.rest_format_proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rest_format_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.rest_format_proof = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rest_format_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.rest_format_proof
+    }
+
+    // Take field
+    pub fn take_rest_format_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.rest_format_proof, ::std::vec::Vec::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.CredentialProof credential_proof = 10;
+
+
+    pub fn get_credential_proof(&self) -> &CredentialProof {
+        self.credential_proof.as_ref().unwrap_or_else(|| <CredentialProof as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_credential_proof(&mut self) {
+        self.credential_proof.clear();
+    }
+
+    pub fn has_credential_proof(&self) -> bool {
+        self.credential_proof.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_credential_proof(&mut self, v: CredentialProof) {
+        self.credential_proof = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_credential_proof(&mut self) -> &mut CredentialProof {
+        if self.credential_proof.is_none() {
+            self.credential_proof.set_default();
+        }
+        self.credential_proof.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_credential_proof(&mut self) -> CredentialProof {
+        self.credential_proof.take().unwrap_or_else(|| CredentialProof::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.StringToBallotProofPair against_ballot_proof = 11;
+
+
+    pub fn get_against_ballot_proof(&self) -> &[StringToBallotProofPair] {
+        &self.against_ballot_proof
+    }
+    pub fn clear_against_ballot_proof(&mut self) {
+        self.against_ballot_proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_against_ballot_proof(&mut self, v: ::protobuf::RepeatedField<StringToBallotProofPair>) {
+        self.against_ballot_proof = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_against_ballot_proof(&mut self) -> &mut ::protobuf::RepeatedField<StringToBallotProofPair> {
+        &mut self.against_ballot_proof
+    }
+
+    // Take field
+    pub fn take_against_ballot_proof(&mut self) -> ::protobuf::RepeatedField<StringToBallotProofPair> {
+        ::std::mem::replace(&mut self.against_ballot_proof, ::protobuf::RepeatedField::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.AggregatedFormatProof aggregated_format_proof = 12;
+
+
+    pub fn get_aggregated_format_proof(&self) -> &AggregatedFormatProof {
+        self.aggregated_format_proof.as_ref().unwrap_or_else(|| <AggregatedFormatProof as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_aggregated_format_proof(&mut self) {
+        self.aggregated_format_proof.clear();
+    }
+
+    pub fn has_aggregated_format_proof(&self) -> bool {
+        self.aggregated_format_proof.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_aggregated_format_proof(&mut self, v: AggregatedFormatProof) {
+        self.aggregated_format_proof = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_aggregated_format_proof(&mut self) -> &mut AggregatedFormatProof {
+        if self.aggregated_format_proof.is_none() {
+            self.aggregated_format_proof.set_default();
+        }
+        self.aggregated_format_proof.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_aggregated_format_proof(&mut self) -> AggregatedFormatProof {
+        self.aggregated_format_proof.take().unwrap_or_else(|| AggregatedFormatProof::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.AggregatedEitherEqualityProof aggregated_either_equality_proof = 13;
+
+
+    pub fn get_aggregated_either_equality_proof(&self) -> &AggregatedEitherEqualityProof {
+        self.aggregated_either_equality_proof.as_ref().unwrap_or_else(|| <AggregatedEitherEqualityProof as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_aggregated_either_equality_proof(&mut self) {
+        self.aggregated_either_equality_proof.clear();
+    }
+
+    pub fn has_aggregated_either_equality_proof(&self) -> bool {
+        self.aggregated_either_equality_proof.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_aggregated_either_equality_proof(&mut self, v: AggregatedEitherEqualityProof) {
+        self.aggregated_either_equality_proof = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_aggregated_either_equality_proof(&mut self) -> &mut AggregatedEitherEqualityProof {
+        if self.aggregated_either_equality_proof.is_none() {
+            self.aggregated_either_equality_proof.set_default();
+        }
+        self.aggregated_either_equality_proof.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_aggregated_either_equality_proof(&mut self) -> AggregatedEitherEqualityProof {
+        self.aggregated_either_equality_proof.take().unwrap_or_else(|| AggregatedEitherEqualityProof::new())
+    }
+}
+
+impl ::protobuf::Message for VoteRequest {
+    fn is_initialized(&self) -> bool {
+        for v in &self.vote {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.ballot_proof {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.unlisted_ballot_proof {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.question_proof {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.credential_proof {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.against_ballot_proof {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.aggregated_format_proof {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.aggregated_either_equality_proof {
+            if !v.is_initialized() {
+                return false;
+            }
         };
         true
     }
@@ -3451,6 +4822,30 @@ impl ::protobuf::Message for VoteRequest {
                 5 => {
                     ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.unlisted_ballot_proof)?;
                 },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.nullifier_proof)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.sequence_binding_proof)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.question_proof)?;
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.rest_format_proof)?;
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.credential_proof)?;
+                },
+                11 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.against_ballot_proof)?;
+                },
+                12 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.aggregated_format_proof)?;
+                },
+                13 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.aggregated_either_equality_proof)?;
+                },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
                 },
@@ -3481,6 +4876,35 @@ impl ::protobuf::Message for VoteRequest {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        if !self.nullifier_proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(6, &self.nullifier_proof);
+        }
+        if !self.sequence_binding_proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(7, &self.sequence_binding_proof);
+        }
+        for value in &self.question_proof {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.rest_format_proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(9, &self.rest_format_proof);
+        }
+        if let Some(ref v) = self.credential_proof.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.against_ballot_proof {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.aggregated_format_proof.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.aggregated_either_equality_proof.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
@@ -3508,6 +4932,40 @@ impl ::protobuf::Message for VoteRequest {
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         };
+        if !self.nullifier_proof.is_empty() {
+            os.write_bytes(6, &self.nullifier_proof)?;
+        }
+        if !self.sequence_binding_proof.is_empty() {
+            os.write_bytes(7, &self.sequence_binding_proof)?;
+        }
+        for v in &self.question_proof {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.rest_format_proof.is_empty() {
+            os.write_bytes(9, &self.rest_format_proof)?;
+        }
+        if let Some(ref v) = self.credential_proof.as_ref() {
+            os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.against_ballot_proof {
+            os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.aggregated_format_proof.as_ref() {
+            os.write_tag(12, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.aggregated_either_equality_proof.as_ref() {
+            os.write_tag(13, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -3571,6 +5029,46 @@ impl ::protobuf::Message for VoteRequest {
                 |m: &VoteRequest| { &m.unlisted_ballot_proof },
                 |m: &mut VoteRequest| { &mut m.unlisted_ballot_proof },
             ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "nullifier_proof",
+                |m: &VoteRequest| { &m.nullifier_proof },
+                |m: &mut VoteRequest| { &mut m.nullifier_proof },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "sequence_binding_proof",
+                |m: &VoteRequest| { &m.sequence_binding_proof },
+                |m: &mut VoteRequest| { &mut m.sequence_binding_proof },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<QuestionBallotProof>>(
+                "question_proof",
+                |m: &VoteRequest| { &m.question_proof },
+                |m: &mut VoteRequest| { &mut m.question_proof },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "rest_format_proof",
+                |m: &VoteRequest| { &m.rest_format_proof },
+                |m: &mut VoteRequest| { &mut m.rest_format_proof },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CredentialProof>>(
+                "credential_proof",
+                |m: &VoteRequest| { &m.credential_proof },
+                |m: &mut VoteRequest| { &mut m.credential_proof },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<StringToBallotProofPair>>(
+                "against_ballot_proof",
+                |m: &VoteRequest| { &m.against_ballot_proof },
+                |m: &mut VoteRequest| { &mut m.against_ballot_proof },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AggregatedFormatProof>>(
+                "aggregated_format_proof",
+                |m: &VoteRequest| { &m.aggregated_format_proof },
+                |m: &mut VoteRequest| { &mut m.aggregated_format_proof },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AggregatedEitherEqualityProof>>(
+                "aggregated_either_equality_proof",
+                |m: &VoteRequest| { &m.aggregated_either_equality_proof },
+                |m: &mut VoteRequest| { &mut m.aggregated_either_equality_proof },
+            ));
             ::protobuf::reflect::MessageDescriptor::new_pb_name::<VoteRequest>(
                 "VoteRequest",
                 fields,
@@ -3592,6 +5090,14 @@ impl ::protobuf::Clear for VoteRequest {
         self.range_proof.clear();
         self.sum_balance_proof.clear();
         self.unlisted_ballot_proof.clear();
+        self.nullifier_proof.clear();
+        self.sequence_binding_proof.clear();
+        self.question_proof.clear();
+        self.rest_format_proof.clear();
+        self.credential_proof.clear();
+        self.against_ballot_proof.clear();
+        self.aggregated_format_proof.clear();
+        self.aggregated_either_equality_proof.clear();
         self.unknown_fields.clear();
     }
 }
@@ -3609,229 +5115,201 @@ impl ::protobuf::reflect::ProtobufValue for VoteRequest {
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct VoteStorage {
+pub struct QuestionBallotProof {
     // message fields
-    pub signature: ::std::vec::Vec<u8>,
-    pub blank_ballot: ::protobuf::SingularPtrField<Ballot>,
-    pub rest_ballot: ::protobuf::SingularPtrField<Ballot>,
-    pub voted_ballot: ::protobuf::RepeatedField<CandidateBallot>,
-    pub voted_ballot_unlisted: ::protobuf::RepeatedField<CipherPointsToBallotPair>,
-    pub zero_ballot: ::protobuf::SingularPtrField<Ballot>,
+    pub question_id: ::std::string::String,
+    pub ballot_proof: ::protobuf::RepeatedField<StringToBallotProofPair>,
+    pub range_proof: ::std::vec::Vec<u8>,
+    pub sum_balance_proof: ::std::vec::Vec<u8>,
+    pub rest_format_proof: ::std::vec::Vec<u8>,
+    pub aggregated_format_proof: ::protobuf::SingularPtrField<AggregatedFormatProof>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a VoteStorage {
-    fn default() -> &'a VoteStorage {
-        <VoteStorage as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a QuestionBallotProof {
+    fn default() -> &'a QuestionBallotProof {
+        <QuestionBallotProof as ::protobuf::Message>::default_instance()
     }
 }
 
-impl VoteStorage {
-    pub fn new() -> VoteStorage {
+impl QuestionBallotProof {
+    pub fn new() -> QuestionBallotProof {
         ::std::default::Default::default()
     }
 
-    // bytes signature = 1;
+    // string question_id = 1;
 
 
-    pub fn get_signature(&self) -> &[u8] {
-        &self.signature
+    pub fn get_question_id(&self) -> &str {
+        &self.question_id
     }
-    pub fn clear_signature(&mut self) {
-        self.signature.clear();
+    pub fn clear_question_id(&mut self) {
+        self.question_id.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_signature(&mut self, v: ::std::vec::Vec<u8>) {
-        self.signature = v;
+    pub fn set_question_id(&mut self, v: ::std::string::String) {
+        self.question_id = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_signature(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.signature
+    pub fn mut_question_id(&mut self) -> &mut ::std::string::String {
+        &mut self.question_id
     }
 
     // Take field
-    pub fn take_signature(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.signature, ::std::vec::Vec::new())
+    pub fn take_question_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.question_id, ::std::string::String::new())
     }
 
-    // .com.webank.wedpr.acv.proto.Ballot blank_ballot = 2;
+    // repeated .com.webank.wedpr.acv.proto.StringToBallotProofPair ballot_proof = 2;
 
 
-    pub fn get_blank_ballot(&self) -> &Ballot {
-        self.blank_ballot.as_ref().unwrap_or_else(|| <Ballot as ::protobuf::Message>::default_instance())
-    }
-    pub fn clear_blank_ballot(&mut self) {
-        self.blank_ballot.clear();
+    pub fn get_ballot_proof(&self) -> &[StringToBallotProofPair] {
+        &self.ballot_proof
     }
-
-    pub fn has_blank_ballot(&self) -> bool {
-        self.blank_ballot.is_some()
+    pub fn clear_ballot_proof(&mut self) {
+        self.ballot_proof.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_blank_ballot(&mut self, v: Ballot) {
-        self.blank_ballot = ::protobuf::SingularPtrField::some(v);
+    pub fn set_ballot_proof(&mut self, v: ::protobuf::RepeatedField<StringToBallotProofPair>) {
+        self.ballot_proof = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_blank_ballot(&mut self) -> &mut Ballot {
-        if self.blank_ballot.is_none() {
-            self.blank_ballot.set_default();
-        }
-        self.blank_ballot.as_mut().unwrap()
+    pub fn mut_ballot_proof(&mut self) -> &mut ::protobuf::RepeatedField<StringToBallotProofPair> {
+        &mut self.ballot_proof
     }
 
     // Take field
-    pub fn take_blank_ballot(&mut self) -> Ballot {
-        self.blank_ballot.take().unwrap_or_else(|| Ballot::new())
+    pub fn take_ballot_proof(&mut self) -> ::protobuf::RepeatedField<StringToBallotProofPair> {
+        ::std::mem::replace(&mut self.ballot_proof, ::protobuf::RepeatedField::new())
     }
 
-    // .com.webank.wedpr.acv.proto.Ballot rest_ballot = 3;
+    // bytes range_proof = 3;
 
 
-    pub fn get_rest_ballot(&self) -> &Ballot {
-        self.rest_ballot.as_ref().unwrap_or_else(|| <Ballot as ::protobuf::Message>::default_instance())
-    }
-    pub fn clear_rest_ballot(&mut self) {
-        self.rest_ballot.clear();
+    pub fn get_range_proof(&self) -> &[u8] {
+        &self.range_proof
     }
-
-    pub fn has_rest_ballot(&self) -> bool {
-        self.rest_ballot.is_some()
+    pub fn clear_range_proof(&mut self) {
+        self.range_proof.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_rest_ballot(&mut self, v: Ballot) {
-        self.rest_ballot = ::protobuf::SingularPtrField::some(v);
+    pub fn set_range_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.range_proof = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_rest_ballot(&mut self) -> &mut Ballot {
-        if self.rest_ballot.is_none() {
-            self.rest_ballot.set_default();
-        }
-        self.rest_ballot.as_mut().unwrap()
+    pub fn mut_range_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.range_proof
     }
 
     // Take field
-    pub fn take_rest_ballot(&mut self) -> Ballot {
-        self.rest_ballot.take().unwrap_or_else(|| Ballot::new())
+    pub fn take_range_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.range_proof, ::std::vec::Vec::new())
     }
 
-    // repeated .com.webank.wedpr.acv.proto.CandidateBallot voted_ballot = 4;
+    // bytes sum_balance_proof = 4;
 
 
-    pub fn get_voted_ballot(&self) -> &[CandidateBallot] {
-        &self.voted_ballot
+    pub fn get_sum_balance_proof(&self) -> &[u8] {
+        &self.sum_balance_proof
     }
-    pub fn clear_voted_ballot(&mut self) {
-        self.voted_ballot.clear();
+    pub fn clear_sum_balance_proof(&mut self) {
+        self.sum_balance_proof.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_voted_ballot(&mut self, v: ::protobuf::RepeatedField<CandidateBallot>) {
-        self.voted_ballot = v;
+    pub fn set_sum_balance_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.sum_balance_proof = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_voted_ballot(&mut self) -> &mut ::protobuf::RepeatedField<CandidateBallot> {
-        &mut self.voted_ballot
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sum_balance_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.sum_balance_proof
     }
 
     // Take field
-    pub fn take_voted_ballot(&mut self) -> ::protobuf::RepeatedField<CandidateBallot> {
-        ::std::mem::replace(&mut self.voted_ballot, ::protobuf::RepeatedField::new())
+    pub fn take_sum_balance_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.sum_balance_proof, ::std::vec::Vec::new())
     }
 
-    // repeated .com.webank.wedpr.acv.proto.CipherPointsToBallotPair voted_ballot_unlisted = 5;
+    // bytes rest_format_proof = 5;
 
 
-    pub fn get_voted_ballot_unlisted(&self) -> &[CipherPointsToBallotPair] {
-        &self.voted_ballot_unlisted
+    pub fn get_rest_format_proof(&self) -> &[u8] {
+        &self.rest_format_proof
     }
-    pub fn clear_voted_ballot_unlisted(&mut self) {
-        self.voted_ballot_unlisted.clear();
+    pub fn clear_rest_format_proof(&mut self) {
+        self.rest_format_proof.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_voted_ballot_unlisted(&mut self, v: ::protobuf::RepeatedField<CipherPointsToBallotPair>) {
-        self.voted_ballot_unlisted = v;
+    pub fn set_rest_format_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.rest_format_proof = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_voted_ballot_unlisted(&mut self) -> &mut ::protobuf::RepeatedField<CipherPointsToBallotPair> {
-        &mut self.voted_ballot_unlisted
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rest_format_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.rest_format_proof
     }
 
     // Take field
-    pub fn take_voted_ballot_unlisted(&mut self) -> ::protobuf::RepeatedField<CipherPointsToBallotPair> {
-        ::std::mem::replace(&mut self.voted_ballot_unlisted, ::protobuf::RepeatedField::new())
+    pub fn take_rest_format_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.rest_format_proof, ::std::vec::Vec::new())
     }
 
-    // .com.webank.wedpr.acv.proto.Ballot zero_ballot = 6;
+    // .com.webank.wedpr.acv.proto.AggregatedFormatProof aggregated_format_proof = 6;
 
 
-    pub fn get_zero_ballot(&self) -> &Ballot {
-        self.zero_ballot.as_ref().unwrap_or_else(|| <Ballot as ::protobuf::Message>::default_instance())
+    pub fn get_aggregated_format_proof(&self) -> &AggregatedFormatProof {
+        self.aggregated_format_proof.as_ref().unwrap_or_else(|| <AggregatedFormatProof as ::protobuf::Message>::default_instance())
     }
-    pub fn clear_zero_ballot(&mut self) {
-        self.zero_ballot.clear();
+    pub fn clear_aggregated_format_proof(&mut self) {
+        self.aggregated_format_proof.clear();
     }
 
-    pub fn has_zero_ballot(&self) -> bool {
-        self.zero_ballot.is_some()
+    pub fn has_aggregated_format_proof(&self) -> bool {
+        self.aggregated_format_proof.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_zero_ballot(&mut self, v: Ballot) {
-        self.zero_ballot = ::protobuf::SingularPtrField::some(v);
+    pub fn set_aggregated_format_proof(&mut self, v: AggregatedFormatProof) {
+        self.aggregated_format_proof = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_zero_ballot(&mut self) -> &mut Ballot {
-        if self.zero_ballot.is_none() {
-            self.zero_ballot.set_default();
+    pub fn mut_aggregated_format_proof(&mut self) -> &mut AggregatedFormatProof {
+        if self.aggregated_format_proof.is_none() {
+            self.aggregated_format_proof.set_default();
         }
-        self.zero_ballot.as_mut().unwrap()
+        self.aggregated_format_proof.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_zero_ballot(&mut self) -> Ballot {
-        self.zero_ballot.take().unwrap_or_else(|| Ballot::new())
+    pub fn take_aggregated_format_proof(&mut self) -> AggregatedFormatProof {
+        self.aggregated_format_proof.take().unwrap_or_else(|| AggregatedFormatProof::new())
     }
 }
 
-impl ::protobuf::Message for VoteStorage {
+impl ::protobuf::Message for QuestionBallotProof {
     fn is_initialized(&self) -> bool {
-        for v in &self.blank_ballot {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.rest_ballot {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.voted_ballot {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.voted_ballot_unlisted {
+        for v in &self.ballot_proof {
             if !v.is_initialized() {
                 return false;
             }
         };
-        for v in &self.zero_ballot {
+        for v in &self.aggregated_format_proof {
             if !v.is_initialized() {
                 return false;
             }
@@ -3844,22 +5322,22 @@ impl ::protobuf::Message for VoteStorage {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.signature)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.question_id)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.blank_ballot)?;
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.ballot_proof)?;
                 },
                 3 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.rest_ballot)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.range_proof)?;
                 },
                 4 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.voted_ballot)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.sum_balance_proof)?;
                 },
                 5 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.voted_ballot_unlisted)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.rest_format_proof)?;
                 },
                 6 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.zero_ballot)?;
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.aggregated_format_proof)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -3873,26 +5351,23 @@ impl ::protobuf::Message for VoteStorage {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.signature.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.signature);
-        }
-        if let Some(ref v) = self.blank_ballot.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        }
-        if let Some(ref v) = self.rest_ballot.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        if !self.question_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.question_id);
         }
-        for value in &self.voted_ballot {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        };
-        for value in &self.voted_ballot_unlisted {
+        for value in &self.ballot_proof {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
-        if let Some(ref v) = self.zero_ballot.as_ref() {
+        if !self.range_proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.range_proof);
+        }
+        if !self.sum_balance_proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.sum_balance_proof);
+        }
+        if !self.rest_format_proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.rest_format_proof);
+        }
+        if let Some(ref v) = self.aggregated_format_proof.as_ref() {
             let len = v.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
@@ -3902,30 +5377,24 @@ impl ::protobuf::Message for VoteStorage {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.signature.is_empty() {
-            os.write_bytes(1, &self.signature)?;
+        if !self.question_id.is_empty() {
+            os.write_string(1, &self.question_id)?;
         }
-        if let Some(ref v) = self.blank_ballot.as_ref() {
+        for v in &self.ballot_proof {
             os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.range_proof.is_empty() {
+            os.write_bytes(3, &self.range_proof)?;
         }
-        if let Some(ref v) = self.rest_ballot.as_ref() {
-            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if !self.sum_balance_proof.is_empty() {
+            os.write_bytes(4, &self.sum_balance_proof)?;
         }
-        for v in &self.voted_ballot {
-            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
-        for v in &self.voted_ballot_unlisted {
-            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        };
-        if let Some(ref v) = self.zero_ballot.as_ref() {
+        if !self.rest_format_proof.is_empty() {
+            os.write_bytes(5, &self.rest_format_proof)?;
+        }
+        if let Some(ref v) = self.aggregated_format_proof.as_ref() {
             os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
@@ -3960,182 +5429,183 @@ impl ::protobuf::Message for VoteStorage {
         Self::descriptor_static()
     }
 
-    fn new() -> VoteStorage {
-        VoteStorage::new()
+    fn new() -> QuestionBallotProof {
+        QuestionBallotProof::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                "signature",
-                |m: &VoteStorage| { &m.signature },
-                |m: &mut VoteStorage| { &mut m.signature },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "question_id",
+                |m: &QuestionBallotProof| { &m.question_id },
+                |m: &mut QuestionBallotProof| { &mut m.question_id },
             ));
-            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
-                "blank_ballot",
-                |m: &VoteStorage| { &m.blank_ballot },
-                |m: &mut VoteStorage| { &mut m.blank_ballot },
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<StringToBallotProofPair>>(
+                "ballot_proof",
+                |m: &QuestionBallotProof| { &m.ballot_proof },
+                |m: &mut QuestionBallotProof| { &mut m.ballot_proof },
             ));
-            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
-                "rest_ballot",
-                |m: &VoteStorage| { &m.rest_ballot },
-                |m: &mut VoteStorage| { &mut m.rest_ballot },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "range_proof",
+                |m: &QuestionBallotProof| { &m.range_proof },
+                |m: &mut QuestionBallotProof| { &mut m.range_proof },
             ));
-            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CandidateBallot>>(
-                "voted_ballot",
-                |m: &VoteStorage| { &m.voted_ballot },
-                |m: &mut VoteStorage| { &mut m.voted_ballot },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "sum_balance_proof",
+                |m: &QuestionBallotProof| { &m.sum_balance_proof },
+                |m: &mut QuestionBallotProof| { &mut m.sum_balance_proof },
             ));
-            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CipherPointsToBallotPair>>(
-                "voted_ballot_unlisted",
-                |m: &VoteStorage| { &m.voted_ballot_unlisted },
-                |m: &mut VoteStorage| { &mut m.voted_ballot_unlisted },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "rest_format_proof",
+                |m: &QuestionBallotProof| { &m.rest_format_proof },
+                |m: &mut QuestionBallotProof| { &mut m.rest_format_proof },
             ));
-            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
-                "zero_ballot",
-                |m: &VoteStorage| { &m.zero_ballot },
-                |m: &mut VoteStorage| { &mut m.zero_ballot },
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<AggregatedFormatProof>>(
+                "aggregated_format_proof",
+                |m: &QuestionBallotProof| { &m.aggregated_format_proof },
+                |m: &mut QuestionBallotProof| { &mut m.aggregated_format_proof },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<VoteStorage>(
-                "VoteStorage",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QuestionBallotProof>(
+                "QuestionBallotProof",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static VoteStorage {
-        static instance: ::protobuf::rt::LazyV2<VoteStorage> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(VoteStorage::new)
+    fn default_instance() -> &'static QuestionBallotProof {
+        static instance: ::protobuf::rt::LazyV2<QuestionBallotProof> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QuestionBallotProof::new)
     }
 }
 
-impl ::protobuf::Clear for VoteStorage {
+impl ::protobuf::Clear for QuestionBallotProof {
     fn clear(&mut self) {
-        self.signature.clear();
-        self.blank_ballot.clear();
-        self.rest_ballot.clear();
-        self.voted_ballot.clear();
-        self.voted_ballot_unlisted.clear();
-        self.zero_ballot.clear();
+        self.question_id.clear();
+        self.ballot_proof.clear();
+        self.range_proof.clear();
+        self.sum_balance_proof.clear();
+        self.rest_format_proof.clear();
+        self.aggregated_format_proof.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for VoteStorage {
+impl ::std::fmt::Debug for QuestionBallotProof {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for VoteStorage {
+impl ::protobuf::reflect::ProtobufValue for QuestionBallotProof {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct CipherPointsToBallotPair {
+pub struct AggregatedFormatProof {
     // message fields
-    pub key: ::protobuf::SingularPtrField<CipherPoints>,
-    pub ballot: ::protobuf::SingularPtrField<Ballot>,
+    pub challenge: ::std::vec::Vec<u8>,
+    pub response_value: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub response_blinding: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a CipherPointsToBallotPair {
-    fn default() -> &'a CipherPointsToBallotPair {
-        <CipherPointsToBallotPair as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a AggregatedFormatProof {
+    fn default() -> &'a AggregatedFormatProof {
+        <AggregatedFormatProof as ::protobuf::Message>::default_instance()
     }
 }
 
-impl CipherPointsToBallotPair {
-    pub fn new() -> CipherPointsToBallotPair {
+impl AggregatedFormatProof {
+    pub fn new() -> AggregatedFormatProof {
         ::std::default::Default::default()
     }
 
-    // .com.webank.wedpr.acv.proto.CipherPoints key = 1;
+    // bytes challenge = 1;
 
 
-    pub fn get_key(&self) -> &CipherPoints {
-        self.key.as_ref().unwrap_or_else(|| <CipherPoints as ::protobuf::Message>::default_instance())
-    }
-    pub fn clear_key(&mut self) {
-        self.key.clear();
+    pub fn get_challenge(&self) -> &[u8] {
+        &self.challenge
     }
-
-    pub fn has_key(&self) -> bool {
-        self.key.is_some()
+    pub fn clear_challenge(&mut self) {
+        self.challenge.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_key(&mut self, v: CipherPoints) {
-        self.key = ::protobuf::SingularPtrField::some(v);
+    pub fn set_challenge(&mut self, v: ::std::vec::Vec<u8>) {
+        self.challenge = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_key(&mut self) -> &mut CipherPoints {
-        if self.key.is_none() {
-            self.key.set_default();
-        }
-        self.key.as_mut().unwrap()
+    pub fn mut_challenge(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.challenge
     }
 
     // Take field
-    pub fn take_key(&mut self) -> CipherPoints {
-        self.key.take().unwrap_or_else(|| CipherPoints::new())
+    pub fn take_challenge(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.challenge, ::std::vec::Vec::new())
     }
 
-    // .com.webank.wedpr.acv.proto.Ballot ballot = 2;
+    // repeated bytes response_value = 2;
 
 
-    pub fn get_ballot(&self) -> &Ballot {
-        self.ballot.as_ref().unwrap_or_else(|| <Ballot as ::protobuf::Message>::default_instance())
+    pub fn get_response_value(&self) -> &[::std::vec::Vec<u8>] {
+        &self.response_value
     }
-    pub fn clear_ballot(&mut self) {
-        self.ballot.clear();
+    pub fn clear_response_value(&mut self) {
+        self.response_value.clear();
     }
 
-    pub fn has_ballot(&self) -> bool {
-        self.ballot.is_some()
+    // Param is passed by value, moved
+    pub fn set_response_value(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.response_value = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_response_value(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.response_value
+    }
+
+    // Take field
+    pub fn take_response_value(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.response_value, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated bytes response_blinding = 3;
+
+
+    pub fn get_response_blinding(&self) -> &[::std::vec::Vec<u8>] {
+        &self.response_blinding
+    }
+    pub fn clear_response_blinding(&mut self) {
+        self.response_blinding.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_ballot(&mut self, v: Ballot) {
-        self.ballot = ::protobuf::SingularPtrField::some(v);
+    pub fn set_response_blinding(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.response_blinding = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_ballot(&mut self) -> &mut Ballot {
-        if self.ballot.is_none() {
-            self.ballot.set_default();
-        }
-        self.ballot.as_mut().unwrap()
+    pub fn mut_response_blinding(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.response_blinding
     }
 
     // Take field
-    pub fn take_ballot(&mut self) -> Ballot {
-        self.ballot.take().unwrap_or_else(|| Ballot::new())
+    pub fn take_response_blinding(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.response_blinding, ::protobuf::RepeatedField::new())
     }
 }
 
-impl ::protobuf::Message for CipherPointsToBallotPair {
+impl ::protobuf::Message for AggregatedFormatProof {
     fn is_initialized(&self) -> bool {
-        for v in &self.key {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.ballot {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -4144,10 +5614,13 @@ impl ::protobuf::Message for CipherPointsToBallotPair {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.key)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.challenge)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ballot)?;
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.response_value)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.response_blinding)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -4161,30 +5634,30 @@ impl ::protobuf::Message for CipherPointsToBallotPair {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.key.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        }
-        if let Some(ref v) = self.ballot.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        if !self.challenge.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.challenge);
         }
+        for value in &self.response_value {
+            my_size += ::protobuf::rt::bytes_size(2, &value);
+        };
+        for value in &self.response_blinding {
+            my_size += ::protobuf::rt::bytes_size(3, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.key.as_ref() {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        }
-        if let Some(ref v) = self.ballot.as_ref() {
-            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if !self.challenge.is_empty() {
+            os.write_bytes(1, &self.challenge)?;
         }
+        for v in &self.response_value {
+            os.write_bytes(2, &v)?;
+        };
+        for v in &self.response_blinding {
+            os.write_bytes(3, &v)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4215,158 +5688,191 @@ impl ::protobuf::Message for CipherPointsToBallotPair {
         Self::descriptor_static()
     }
 
-    fn new() -> CipherPointsToBallotPair {
-        CipherPointsToBallotPair::new()
+    fn new() -> AggregatedFormatProof {
+        AggregatedFormatProof::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CipherPoints>>(
-                "key",
-                |m: &CipherPointsToBallotPair| { &m.key },
-                |m: &mut CipherPointsToBallotPair| { &mut m.key },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "challenge",
+                |m: &AggregatedFormatProof| { &m.challenge },
+                |m: &mut AggregatedFormatProof| { &mut m.challenge },
             ));
-            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
-                "ballot",
-                |m: &CipherPointsToBallotPair| { &m.ballot },
-                |m: &mut CipherPointsToBallotPair| { &mut m.ballot },
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "response_value",
+                |m: &AggregatedFormatProof| { &m.response_value },
+                |m: &mut AggregatedFormatProof| { &mut m.response_value },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CipherPointsToBallotPair>(
-                "CipherPointsToBallotPair",
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "response_blinding",
+                |m: &AggregatedFormatProof| { &m.response_blinding },
+                |m: &mut AggregatedFormatProof| { &mut m.response_blinding },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AggregatedFormatProof>(
+                "AggregatedFormatProof",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static CipherPointsToBallotPair {
-        static instance: ::protobuf::rt::LazyV2<CipherPointsToBallotPair> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(CipherPointsToBallotPair::new)
+    fn default_instance() -> &'static AggregatedFormatProof {
+        static instance: ::protobuf::rt::LazyV2<AggregatedFormatProof> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AggregatedFormatProof::new)
     }
 }
 
-impl ::protobuf::Clear for CipherPointsToBallotPair {
+impl ::protobuf::Clear for AggregatedFormatProof {
     fn clear(&mut self) {
-        self.key.clear();
-        self.ballot.clear();
+        self.challenge.clear();
+        self.response_value.clear();
+        self.response_blinding.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for CipherPointsToBallotPair {
+impl ::std::fmt::Debug for AggregatedFormatProof {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for CipherPointsToBallotPair {
+impl ::protobuf::reflect::ProtobufValue for AggregatedFormatProof {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct CipherPointsToBallotProofPair {
+pub struct AggregatedEitherEqualityProof {
     // message fields
-    pub key: ::protobuf::SingularPtrField<CipherPoints>,
-    pub value: ::protobuf::SingularPtrField<BallotProof>,
+    pub challenge: ::std::vec::Vec<u8>,
+    pub weight_challenge: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub weight_response: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub zero_response: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a CipherPointsToBallotProofPair {
-    fn default() -> &'a CipherPointsToBallotProofPair {
-        <CipherPointsToBallotProofPair as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a AggregatedEitherEqualityProof {
+    fn default() -> &'a AggregatedEitherEqualityProof {
+        <AggregatedEitherEqualityProof as ::protobuf::Message>::default_instance()
     }
 }
 
-impl CipherPointsToBallotProofPair {
-    pub fn new() -> CipherPointsToBallotProofPair {
+impl AggregatedEitherEqualityProof {
+    pub fn new() -> AggregatedEitherEqualityProof {
         ::std::default::Default::default()
     }
 
-    // .com.webank.wedpr.acv.proto.CipherPoints key = 1;
+    // bytes challenge = 1;
 
 
-    pub fn get_key(&self) -> &CipherPoints {
-        self.key.as_ref().unwrap_or_else(|| <CipherPoints as ::protobuf::Message>::default_instance())
+    pub fn get_challenge(&self) -> &[u8] {
+        &self.challenge
     }
-    pub fn clear_key(&mut self) {
-        self.key.clear();
+    pub fn clear_challenge(&mut self) {
+        self.challenge.clear();
     }
 
-    pub fn has_key(&self) -> bool {
-        self.key.is_some()
+    // Param is passed by value, moved
+    pub fn set_challenge(&mut self, v: ::std::vec::Vec<u8>) {
+        self.challenge = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_challenge(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.challenge
+    }
+
+    // Take field
+    pub fn take_challenge(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.challenge, ::std::vec::Vec::new())
+    }
+
+    // repeated bytes weight_challenge = 2;
+
+
+    pub fn get_weight_challenge(&self) -> &[::std::vec::Vec<u8>] {
+        &self.weight_challenge
+    }
+    pub fn clear_weight_challenge(&mut self) {
+        self.weight_challenge.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_key(&mut self, v: CipherPoints) {
-        self.key = ::protobuf::SingularPtrField::some(v);
+    pub fn set_weight_challenge(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.weight_challenge = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_key(&mut self) -> &mut CipherPoints {
-        if self.key.is_none() {
-            self.key.set_default();
-        }
-        self.key.as_mut().unwrap()
+    pub fn mut_weight_challenge(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.weight_challenge
     }
 
     // Take field
-    pub fn take_key(&mut self) -> CipherPoints {
-        self.key.take().unwrap_or_else(|| CipherPoints::new())
+    pub fn take_weight_challenge(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.weight_challenge, ::protobuf::RepeatedField::new())
     }
 
-    // .com.webank.wedpr.acv.proto.BallotProof value = 2;
+    // repeated bytes weight_response = 3;
 
 
-    pub fn get_value(&self) -> &BallotProof {
-        self.value.as_ref().unwrap_or_else(|| <BallotProof as ::protobuf::Message>::default_instance())
+    pub fn get_weight_response(&self) -> &[::std::vec::Vec<u8>] {
+        &self.weight_response
     }
-    pub fn clear_value(&mut self) {
-        self.value.clear();
+    pub fn clear_weight_response(&mut self) {
+        self.weight_response.clear();
     }
 
-    pub fn has_value(&self) -> bool {
-        self.value.is_some()
+    // Param is passed by value, moved
+    pub fn set_weight_response(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.weight_response = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_weight_response(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.weight_response
+    }
+
+    // Take field
+    pub fn take_weight_response(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.weight_response, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated bytes zero_response = 4;
+
+
+    pub fn get_zero_response(&self) -> &[::std::vec::Vec<u8>] {
+        &self.zero_response
+    }
+    pub fn clear_zero_response(&mut self) {
+        self.zero_response.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_value(&mut self, v: BallotProof) {
-        self.value = ::protobuf::SingularPtrField::some(v);
+    pub fn set_zero_response(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.zero_response = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_value(&mut self) -> &mut BallotProof {
-        if self.value.is_none() {
-            self.value.set_default();
-        }
-        self.value.as_mut().unwrap()
+    pub fn mut_zero_response(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.zero_response
     }
 
     // Take field
-    pub fn take_value(&mut self) -> BallotProof {
-        self.value.take().unwrap_or_else(|| BallotProof::new())
+    pub fn take_zero_response(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.zero_response, ::protobuf::RepeatedField::new())
     }
 }
 
-impl ::protobuf::Message for CipherPointsToBallotProofPair {
+impl ::protobuf::Message for AggregatedEitherEqualityProof {
     fn is_initialized(&self) -> bool {
-        for v in &self.key {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.value {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
         true
     }
 
@@ -4375,10 +5881,16 @@ impl ::protobuf::Message for CipherPointsToBallotProofPair {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.key)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.challenge)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.value)?;
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.weight_challenge)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.weight_response)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.zero_response)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -4392,30 +5904,36 @@ impl ::protobuf::Message for CipherPointsToBallotProofPair {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.key.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        }
-        if let Some(ref v) = self.value.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        if !self.challenge.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.challenge);
         }
+        for value in &self.weight_challenge {
+            my_size += ::protobuf::rt::bytes_size(2, &value);
+        };
+        for value in &self.weight_response {
+            my_size += ::protobuf::rt::bytes_size(3, &value);
+        };
+        for value in &self.zero_response {
+            my_size += ::protobuf::rt::bytes_size(4, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.key.as_ref() {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        }
-        if let Some(ref v) = self.value.as_ref() {
-            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if !self.challenge.is_empty() {
+            os.write_bytes(1, &self.challenge)?;
         }
+        for v in &self.weight_challenge {
+            os.write_bytes(2, &v)?;
+        };
+        for v in &self.weight_response {
+            os.write_bytes(3, &v)?;
+        };
+        for v in &self.zero_response {
+            os.write_bytes(4, &v)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4446,134 +5964,158 @@ impl ::protobuf::Message for CipherPointsToBallotProofPair {
         Self::descriptor_static()
     }
 
-    fn new() -> CipherPointsToBallotProofPair {
-        CipherPointsToBallotProofPair::new()
+    fn new() -> AggregatedEitherEqualityProof {
+        AggregatedEitherEqualityProof::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CipherPoints>>(
-                "key",
-                |m: &CipherPointsToBallotProofPair| { &m.key },
-                |m: &mut CipherPointsToBallotProofPair| { &mut m.key },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "challenge",
+                |m: &AggregatedEitherEqualityProof| { &m.challenge },
+                |m: &mut AggregatedEitherEqualityProof| { &mut m.challenge },
             ));
-            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BallotProof>>(
-                "value",
-                |m: &CipherPointsToBallotProofPair| { &m.value },
-                |m: &mut CipherPointsToBallotProofPair| { &mut m.value },
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "weight_challenge",
+                |m: &AggregatedEitherEqualityProof| { &m.weight_challenge },
+                |m: &mut AggregatedEitherEqualityProof| { &mut m.weight_challenge },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CipherPointsToBallotProofPair>(
-                "CipherPointsToBallotProofPair",
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "weight_response",
+                |m: &AggregatedEitherEqualityProof| { &m.weight_response },
+                |m: &mut AggregatedEitherEqualityProof| { &mut m.weight_response },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "zero_response",
+                |m: &AggregatedEitherEqualityProof| { &m.zero_response },
+                |m: &mut AggregatedEitherEqualityProof| { &mut m.zero_response },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AggregatedEitherEqualityProof>(
+                "AggregatedEitherEqualityProof",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static CipherPointsToBallotProofPair {
-        static instance: ::protobuf::rt::LazyV2<CipherPointsToBallotProofPair> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(CipherPointsToBallotProofPair::new)
+    fn default_instance() -> &'static AggregatedEitherEqualityProof {
+        static instance: ::protobuf::rt::LazyV2<AggregatedEitherEqualityProof> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AggregatedEitherEqualityProof::new)
     }
 }
 
-impl ::protobuf::Clear for CipherPointsToBallotProofPair {
+impl ::protobuf::Clear for AggregatedEitherEqualityProof {
     fn clear(&mut self) {
-        self.key.clear();
-        self.value.clear();
+        self.challenge.clear();
+        self.weight_challenge.clear();
+        self.weight_response.clear();
+        self.zero_response.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for CipherPointsToBallotProofPair {
+impl ::std::fmt::Debug for AggregatedEitherEqualityProof {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for CipherPointsToBallotProofPair {
+impl ::protobuf::reflect::ProtobufValue for AggregatedEitherEqualityProof {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct CipherPoints {
+pub struct CertifiedBallot {
     // message fields
-    pub ciphertext1: ::std::vec::Vec<u8>,
-    pub ciphertext2: ::std::vec::Vec<u8>,
+    pub ballot: ::protobuf::SingularPtrField<Ballot>,
+    pub signature: ::std::vec::Vec<u8>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a CipherPoints {
-    fn default() -> &'a CipherPoints {
-        <CipherPoints as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a CertifiedBallot {
+    fn default() -> &'a CertifiedBallot {
+        <CertifiedBallot as ::protobuf::Message>::default_instance()
     }
 }
 
-impl CipherPoints {
-    pub fn new() -> CipherPoints {
+impl CertifiedBallot {
+    pub fn new() -> CertifiedBallot {
         ::std::default::Default::default()
     }
 
-    // bytes ciphertext1 = 1;
+    // .com.webank.wedpr.acv.proto.Ballot ballot = 1;
 
 
-    pub fn get_ciphertext1(&self) -> &[u8] {
-        &self.ciphertext1
+    pub fn get_ballot(&self) -> &Ballot {
+        self.ballot.as_ref().unwrap_or_else(|| <Ballot as ::protobuf::Message>::default_instance())
     }
-    pub fn clear_ciphertext1(&mut self) {
-        self.ciphertext1.clear();
+    pub fn clear_ballot(&mut self) {
+        self.ballot.clear();
+    }
+
+    pub fn has_ballot(&self) -> bool {
+        self.ballot.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_ciphertext1(&mut self, v: ::std::vec::Vec<u8>) {
-        self.ciphertext1 = v;
+    pub fn set_ballot(&mut self, v: Ballot) {
+        self.ballot = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_ciphertext1(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.ciphertext1
+    pub fn mut_ballot(&mut self) -> &mut Ballot {
+        if self.ballot.is_none() {
+            self.ballot.set_default();
+        }
+        self.ballot.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_ciphertext1(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.ciphertext1, ::std::vec::Vec::new())
+    pub fn take_ballot(&mut self) -> Ballot {
+        self.ballot.take().unwrap_or_else(|| Ballot::new())
     }
 
-    // bytes ciphertext2 = 2;
+    // bytes signature = 2;
 
 
-    pub fn get_ciphertext2(&self) -> &[u8] {
-        &self.ciphertext2
+    pub fn get_signature(&self) -> &[u8] {
+        &self.signature
     }
-    pub fn clear_ciphertext2(&mut self) {
-        self.ciphertext2.clear();
+    pub fn clear_signature(&mut self) {
+        self.signature.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_ciphertext2(&mut self, v: ::std::vec::Vec<u8>) {
-        self.ciphertext2 = v;
+    pub fn set_signature(&mut self, v: ::std::vec::Vec<u8>) {
+        self.signature = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_ciphertext2(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.ciphertext2
+    pub fn mut_signature(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.signature
     }
 
     // Take field
-    pub fn take_ciphertext2(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.ciphertext2, ::std::vec::Vec::new())
+    pub fn take_signature(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.signature, ::std::vec::Vec::new())
     }
 }
 
-impl ::protobuf::Message for CipherPoints {
+impl ::protobuf::Message for CertifiedBallot {
     fn is_initialized(&self) -> bool {
+        for v in &self.ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
         true
     }
 
@@ -4582,10 +6124,10 @@ impl ::protobuf::Message for CipherPoints {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.ciphertext1)?;
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ballot)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.ciphertext2)?;
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.signature)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -4599,11 +6141,12 @@ impl ::protobuf::Message for CipherPoints {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.ciphertext1.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(1, &self.ciphertext1);
+        if let Some(ref v) = self.ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         }
-        if !self.ciphertext2.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(2, &self.ciphertext2);
+        if !self.signature.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.signature);
         }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -4611,11 +6154,13 @@ impl ::protobuf::Message for CipherPoints {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.ciphertext1.is_empty() {
-            os.write_bytes(1, &self.ciphertext1)?;
+        if let Some(ref v) = self.ballot.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
         }
-        if !self.ciphertext2.is_empty() {
-            os.write_bytes(2, &self.ciphertext2)?;
+        if !self.signature.is_empty() {
+            os.write_bytes(2, &self.signature)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -4647,160 +6192,157 @@ impl ::protobuf::Message for CipherPoints {
         Self::descriptor_static()
     }
 
-    fn new() -> CipherPoints {
-        CipherPoints::new()
+    fn new() -> CertifiedBallot {
+        CertifiedBallot::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                "ciphertext1",
-                |m: &CipherPoints| { &m.ciphertext1 },
-                |m: &mut CipherPoints| { &mut m.ciphertext1 },
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                "ballot",
+                |m: &CertifiedBallot| { &m.ballot },
+                |m: &mut CertifiedBallot| { &mut m.ballot },
             ));
             fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                "ciphertext2",
-                |m: &CipherPoints| { &m.ciphertext2 },
-                |m: &mut CipherPoints| { &mut m.ciphertext2 },
+                "signature",
+                |m: &CertifiedBallot| { &m.signature },
+                |m: &mut CertifiedBallot| { &mut m.signature },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CipherPoints>(
-                "CipherPoints",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CertifiedBallot>(
+                "CertifiedBallot",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static CipherPoints {
-        static instance: ::protobuf::rt::LazyV2<CipherPoints> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(CipherPoints::new)
+    fn default_instance() -> &'static CertifiedBallot {
+        static instance: ::protobuf::rt::LazyV2<CertifiedBallot> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CertifiedBallot::new)
     }
 }
 
-impl ::protobuf::Clear for CipherPoints {
+impl ::protobuf::Clear for CertifiedBallot {
     fn clear(&mut self) {
-        self.ciphertext1.clear();
-        self.ciphertext2.clear();
+        self.ballot.clear();
+        self.signature.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for CipherPoints {
+impl ::std::fmt::Debug for CertifiedBallot {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for CipherPoints {
+impl ::protobuf::reflect::ProtobufValue for CertifiedBallot {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct CountingPart {
+pub struct DelegationMembershipProof {
     // message fields
-    pub counter_id: ::std::string::String,
-    pub blinding_c2: ::std::vec::Vec<u8>,
-    pub equality_proof: ::std::vec::Vec<u8>,
+    pub challenge: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub secret_response: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub blinding_response: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a CountingPart {
-    fn default() -> &'a CountingPart {
-        <CountingPart as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a DelegationMembershipProof {
+    fn default() -> &'a DelegationMembershipProof {
+        <DelegationMembershipProof as ::protobuf::Message>::default_instance()
     }
 }
 
-impl CountingPart {
-    pub fn new() -> CountingPart {
+impl DelegationMembershipProof {
+    pub fn new() -> DelegationMembershipProof {
         ::std::default::Default::default()
     }
 
-    // string counter_id = 1;
+    // repeated bytes challenge = 1;
 
 
-    pub fn get_counter_id(&self) -> &str {
-        &self.counter_id
+    pub fn get_challenge(&self) -> &[::std::vec::Vec<u8>] {
+        &self.challenge
     }
-    pub fn clear_counter_id(&mut self) {
-        self.counter_id.clear();
+    pub fn clear_challenge(&mut self) {
+        self.challenge.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_counter_id(&mut self, v: ::std::string::String) {
-        self.counter_id = v;
+    pub fn set_challenge(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.challenge = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_counter_id(&mut self) -> &mut ::std::string::String {
-        &mut self.counter_id
+    pub fn mut_challenge(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.challenge
     }
 
     // Take field
-    pub fn take_counter_id(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.counter_id, ::std::string::String::new())
+    pub fn take_challenge(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.challenge, ::protobuf::RepeatedField::new())
     }
 
-    // bytes blinding_c2 = 2;
+    // repeated bytes secret_response = 2;
 
 
-    pub fn get_blinding_c2(&self) -> &[u8] {
-        &self.blinding_c2
+    pub fn get_secret_response(&self) -> &[::std::vec::Vec<u8>] {
+        &self.secret_response
     }
-    pub fn clear_blinding_c2(&mut self) {
-        self.blinding_c2.clear();
+    pub fn clear_secret_response(&mut self) {
+        self.secret_response.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_blinding_c2(&mut self, v: ::std::vec::Vec<u8>) {
-        self.blinding_c2 = v;
+    pub fn set_secret_response(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.secret_response = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_blinding_c2(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.blinding_c2
+    pub fn mut_secret_response(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.secret_response
     }
 
     // Take field
-    pub fn take_blinding_c2(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.blinding_c2, ::std::vec::Vec::new())
+    pub fn take_secret_response(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.secret_response, ::protobuf::RepeatedField::new())
     }
 
-    // bytes equality_proof = 3;
+    // repeated bytes blinding_response = 3;
 
 
-    pub fn get_equality_proof(&self) -> &[u8] {
-        &self.equality_proof
+    pub fn get_blinding_response(&self) -> &[::std::vec::Vec<u8>] {
+        &self.blinding_response
     }
-    pub fn clear_equality_proof(&mut self) {
-        self.equality_proof.clear();
+    pub fn clear_blinding_response(&mut self) {
+        self.blinding_response.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_equality_proof(&mut self, v: ::std::vec::Vec<u8>) {
-        self.equality_proof = v;
+    pub fn set_blinding_response(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.blinding_response = v;
     }
 
     // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_equality_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
-        &mut self.equality_proof
+    pub fn mut_blinding_response(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.blinding_response
     }
 
     // Take field
-    pub fn take_equality_proof(&mut self) -> ::std::vec::Vec<u8> {
-        ::std::mem::replace(&mut self.equality_proof, ::std::vec::Vec::new())
+    pub fn take_blinding_response(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.blinding_response, ::protobuf::RepeatedField::new())
     }
 }
 
-impl ::protobuf::Message for CountingPart {
+impl ::protobuf::Message for DelegationMembershipProof {
     fn is_initialized(&self) -> bool {
         true
     }
@@ -4810,13 +6352,13 @@ impl ::protobuf::Message for CountingPart {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.counter_id)?;
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.challenge)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.blinding_c2)?;
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.secret_response)?;
                 },
                 3 => {
-                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.equality_proof)?;
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.blinding_response)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -4830,30 +6372,30 @@ impl ::protobuf::Message for CountingPart {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if !self.counter_id.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.counter_id);
-        }
-        if !self.blinding_c2.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(2, &self.blinding_c2);
-        }
-        if !self.equality_proof.is_empty() {
-            my_size += ::protobuf::rt::bytes_size(3, &self.equality_proof);
-        }
+        for value in &self.challenge {
+            my_size += ::protobuf::rt::bytes_size(1, &value);
+        };
+        for value in &self.secret_response {
+            my_size += ::protobuf::rt::bytes_size(2, &value);
+        };
+        for value in &self.blinding_response {
+            my_size += ::protobuf::rt::bytes_size(3, &value);
+        };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.counter_id.is_empty() {
-            os.write_string(1, &self.counter_id)?;
-        }
-        if !self.blinding_c2.is_empty() {
-            os.write_bytes(2, &self.blinding_c2)?;
-        }
-        if !self.equality_proof.is_empty() {
-            os.write_bytes(3, &self.equality_proof)?;
-        }
+        for v in &self.challenge {
+            os.write_bytes(1, &v)?;
+        };
+        for v in &self.secret_response {
+            os.write_bytes(2, &v)?;
+        };
+        for v in &self.blinding_response {
+            os.write_bytes(3, &v)?;
+        };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -4884,197 +6426,12816 @@ impl ::protobuf::Message for CountingPart {
         Self::descriptor_static()
     }
 
-    fn new() -> CountingPart {
-        CountingPart::new()
+    fn new() -> DelegationMembershipProof {
+        DelegationMembershipProof::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "counter_id",
-                |m: &CountingPart| { &m.counter_id },
-                |m: &mut CountingPart| { &mut m.counter_id },
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "challenge",
+                |m: &DelegationMembershipProof| { &m.challenge },
+                |m: &mut DelegationMembershipProof| { &mut m.challenge },
             ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                "blinding_c2",
-                |m: &CountingPart| { &m.blinding_c2 },
-                |m: &mut CountingPart| { &mut m.blinding_c2 },
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "secret_response",
+                |m: &DelegationMembershipProof| { &m.secret_response },
+                |m: &mut DelegationMembershipProof| { &mut m.secret_response },
             ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
-                "equality_proof",
-                |m: &CountingPart| { &m.equality_proof },
-                |m: &mut CountingPart| { &mut m.equality_proof },
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "blinding_response",
+                |m: &DelegationMembershipProof| { &m.blinding_response },
+                |m: &mut DelegationMembershipProof| { &mut m.blinding_response },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CountingPart>(
-                "CountingPart",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DelegationMembershipProof>(
+                "DelegationMembershipProof",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static CountingPart {
-        static instance: ::protobuf::rt::LazyV2<CountingPart> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(CountingPart::new)
+    fn default_instance() -> &'static DelegationMembershipProof {
+        static instance: ::protobuf::rt::LazyV2<DelegationMembershipProof> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DelegationMembershipProof::new)
     }
 }
 
-impl ::protobuf::Clear for CountingPart {
+impl ::protobuf::Clear for DelegationMembershipProof {
     fn clear(&mut self) {
-        self.counter_id.clear();
-        self.blinding_c2.clear();
-        self.equality_proof.clear();
+        self.challenge.clear();
+        self.secret_response.clear();
+        self.blinding_response.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for CountingPart {
+impl ::std::fmt::Debug for DelegationMembershipProof {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for CountingPart {
+impl ::protobuf::reflect::ProtobufValue for DelegationMembershipProof {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct StringToCountingPartPair {
+pub struct Delegation {
     // message fields
-    pub key: ::std::string::String,
-    pub value: ::protobuf::SingularPtrField<CountingPart>,
+    pub ring: ::protobuf::RepeatedField<CertifiedBallot>,
+    pub nullifier: ::std::vec::Vec<u8>,
+    pub delegate_ballot: ::protobuf::SingularPtrField<Ballot>,
+    pub transfer_ballot: ::protobuf::SingularPtrField<Ballot>,
+    pub membership_proof: ::protobuf::SingularPtrField<DelegationMembershipProof>,
+    pub transfer_key_point: ::std::vec::Vec<u8>,
+    pub encrypted_voter_weight: u32,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a StringToCountingPartPair {
-    fn default() -> &'a StringToCountingPartPair {
-        <StringToCountingPartPair as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a Delegation {
+    fn default() -> &'a Delegation {
+        <Delegation as ::protobuf::Message>::default_instance()
     }
 }
 
-impl StringToCountingPartPair {
-    pub fn new() -> StringToCountingPartPair {
+impl Delegation {
+    pub fn new() -> Delegation {
         ::std::default::Default::default()
     }
 
-    // string key = 1;
+    // repeated .com.webank.wedpr.acv.proto.CertifiedBallot ring = 1;
 
 
-    pub fn get_key(&self) -> &str {
-        &self.key
+    pub fn get_ring(&self) -> &[CertifiedBallot] {
+        &self.ring
     }
-    pub fn clear_key(&mut self) {
-        self.key.clear();
+    pub fn clear_ring(&mut self) {
+        self.ring.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_key(&mut self, v: ::std::string::String) {
-        self.key = v;
+    pub fn set_ring(&mut self, v: ::protobuf::RepeatedField<CertifiedBallot>) {
+        self.ring = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ring(&mut self) -> &mut ::protobuf::RepeatedField<CertifiedBallot> {
+        &mut self.ring
+    }
+
+    // Take field
+    pub fn take_ring(&mut self) -> ::protobuf::RepeatedField<CertifiedBallot> {
+        ::std::mem::replace(&mut self.ring, ::protobuf::RepeatedField::new())
+    }
+
+    // bytes nullifier = 2;
+
+
+    pub fn get_nullifier(&self) -> &[u8] {
+        &self.nullifier
+    }
+    pub fn clear_nullifier(&mut self) {
+        self.nullifier.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nullifier(&mut self, v: ::std::vec::Vec<u8>) {
+        self.nullifier = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_key(&mut self) -> &mut ::std::string::String {
-        &mut self.key
+    pub fn mut_nullifier(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.nullifier
     }
 
     // Take field
-    pub fn take_key(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    pub fn take_nullifier(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.nullifier, ::std::vec::Vec::new())
     }
 
-    // .com.webank.wedpr.acv.proto.CountingPart value = 2;
+    // .com.webank.wedpr.acv.proto.Ballot delegate_ballot = 3;
 
 
-    pub fn get_value(&self) -> &CountingPart {
-        self.value.as_ref().unwrap_or_else(|| <CountingPart as ::protobuf::Message>::default_instance())
+    pub fn get_delegate_ballot(&self) -> &Ballot {
+        self.delegate_ballot.as_ref().unwrap_or_else(|| <Ballot as ::protobuf::Message>::default_instance())
     }
-    pub fn clear_value(&mut self) {
-        self.value.clear();
+    pub fn clear_delegate_ballot(&mut self) {
+        self.delegate_ballot.clear();
     }
 
-    pub fn has_value(&self) -> bool {
-        self.value.is_some()
+    pub fn has_delegate_ballot(&self) -> bool {
+        self.delegate_ballot.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_value(&mut self, v: CountingPart) {
-        self.value = ::protobuf::SingularPtrField::some(v);
+    pub fn set_delegate_ballot(&mut self, v: Ballot) {
+        self.delegate_ballot = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_value(&mut self) -> &mut CountingPart {
-        if self.value.is_none() {
-            self.value.set_default();
+    pub fn mut_delegate_ballot(&mut self) -> &mut Ballot {
+        if self.delegate_ballot.is_none() {
+            self.delegate_ballot.set_default();
         }
-        self.value.as_mut().unwrap()
+        self.delegate_ballot.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_value(&mut self) -> CountingPart {
-        self.value.take().unwrap_or_else(|| CountingPart::new())
+    pub fn take_delegate_ballot(&mut self) -> Ballot {
+        self.delegate_ballot.take().unwrap_or_else(|| Ballot::new())
     }
-}
 
-impl ::protobuf::Message for StringToCountingPartPair {
-    fn is_initialized(&self) -> bool {
-        for v in &self.value {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        true
+    // .com.webank.wedpr.acv.proto.Ballot transfer_ballot = 4;
+
+
+    pub fn get_transfer_ballot(&self) -> &Ballot {
+        self.transfer_ballot.as_ref().unwrap_or_else(|| <Ballot as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_transfer_ballot(&mut self) {
+        self.transfer_ballot.clear();
     }
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
-                },
-                2 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.value)?;
-                },
-                _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
-                },
-            };
-        }
-        ::std::result::Result::Ok(())
+    pub fn has_transfer_ballot(&self) -> bool {
+        self.transfer_ballot.is_some()
     }
 
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        if !self.key.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.key);
-        }
-        if let Some(ref v) = self.value.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        }
-        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
+    // Param is passed by value, moved
+    pub fn set_transfer_ballot(&mut self, v: Ballot) {
+        self.transfer_ballot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_transfer_ballot(&mut self) -> &mut Ballot {
+        if self.transfer_ballot.is_none() {
+            self.transfer_ballot.set_default();
+        }
+        self.transfer_ballot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_transfer_ballot(&mut self) -> Ballot {
+        self.transfer_ballot.take().unwrap_or_else(|| Ballot::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.DelegationMembershipProof membership_proof = 5;
+
+
+    pub fn get_membership_proof(&self) -> &DelegationMembershipProof {
+        self.membership_proof.as_ref().unwrap_or_else(|| <DelegationMembershipProof as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_membership_proof(&mut self) {
+        self.membership_proof.clear();
+    }
+
+    pub fn has_membership_proof(&self) -> bool {
+        self.membership_proof.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_membership_proof(&mut self, v: DelegationMembershipProof) {
+        self.membership_proof = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_membership_proof(&mut self) -> &mut DelegationMembershipProof {
+        if self.membership_proof.is_none() {
+            self.membership_proof.set_default();
+        }
+        self.membership_proof.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_membership_proof(&mut self) -> DelegationMembershipProof {
+        self.membership_proof.take().unwrap_or_else(|| DelegationMembershipProof::new())
+    }
+
+    // bytes transfer_key_point = 6;
+
+
+    pub fn get_transfer_key_point(&self) -> &[u8] {
+        &self.transfer_key_point
+    }
+    pub fn clear_transfer_key_point(&mut self) {
+        self.transfer_key_point.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_transfer_key_point(&mut self, v: ::std::vec::Vec<u8>) {
+        self.transfer_key_point = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_transfer_key_point(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.transfer_key_point
+    }
+
+    // Take field
+    pub fn take_transfer_key_point(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.transfer_key_point, ::std::vec::Vec::new())
+    }
+
+    // uint32 encrypted_voter_weight = 7;
+
+
+    pub fn get_encrypted_voter_weight(&self) -> u32 {
+        self.encrypted_voter_weight
+    }
+    pub fn clear_encrypted_voter_weight(&mut self) {
+        self.encrypted_voter_weight = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_encrypted_voter_weight(&mut self, v: u32) {
+        self.encrypted_voter_weight = v;
+    }
+}
+
+impl ::protobuf::Message for Delegation {
+    fn is_initialized(&self) -> bool {
+        for v in &self.ring {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.delegate_ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.transfer_ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.membership_proof {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.ring)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.nullifier)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.delegate_ballot)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.transfer_ballot)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.membership_proof)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.transfer_key_point)?;
+                },
+                7 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.encrypted_voter_weight = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.ring {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.nullifier.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.nullifier);
+        }
+        if let Some(ref v) = self.delegate_ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.transfer_ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.membership_proof.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.transfer_key_point.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(6, &self.transfer_key_point);
+        }
+        if self.encrypted_voter_weight != 0 {
+            my_size += ::protobuf::rt::value_size(7, self.encrypted_voter_weight, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.ring {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.nullifier.is_empty() {
+            os.write_bytes(2, &self.nullifier)?;
+        }
+        if let Some(ref v) = self.delegate_ballot.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.transfer_ballot.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.membership_proof.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.transfer_key_point.is_empty() {
+            os.write_bytes(6, &self.transfer_key_point)?;
+        }
+        if self.encrypted_voter_weight != 0 {
+            os.write_uint32(7, self.encrypted_voter_weight)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> Delegation {
+        Delegation::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CertifiedBallot>>(
+                "ring",
+                |m: &Delegation| { &m.ring },
+                |m: &mut Delegation| { &mut m.ring },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "nullifier",
+                |m: &Delegation| { &m.nullifier },
+                |m: &mut Delegation| { &mut m.nullifier },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                "delegate_ballot",
+                |m: &Delegation| { &m.delegate_ballot },
+                |m: &mut Delegation| { &mut m.delegate_ballot },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                "transfer_ballot",
+                |m: &Delegation| { &m.transfer_ballot },
+                |m: &mut Delegation| { &mut m.transfer_ballot },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DelegationMembershipProof>>(
+                "membership_proof",
+                |m: &Delegation| { &m.membership_proof },
+                |m: &mut Delegation| { &mut m.membership_proof },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "transfer_key_point",
+                |m: &Delegation| { &m.transfer_key_point },
+                |m: &mut Delegation| { &mut m.transfer_key_point },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "encrypted_voter_weight",
+                |m: &Delegation| { &m.encrypted_voter_weight },
+                |m: &mut Delegation| { &mut m.encrypted_voter_weight },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<Delegation>(
+                "Delegation",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static Delegation {
+        static instance: ::protobuf::rt::LazyV2<Delegation> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(Delegation::new)
+    }
+}
+
+impl ::protobuf::Clear for Delegation {
+    fn clear(&mut self) {
+        self.ring.clear();
+        self.nullifier.clear();
+        self.delegate_ballot.clear();
+        self.transfer_ballot.clear();
+        self.membership_proof.clear();
+        self.transfer_key_point.clear();
+        self.encrypted_voter_weight = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for Delegation {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for Delegation {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct VoteStorage {
+    // message fields
+    pub signature: ::std::vec::Vec<u8>,
+    pub blank_ballot: ::protobuf::SingularPtrField<Ballot>,
+    pub rest_ballot: ::protobuf::SingularPtrField<Ballot>,
+    pub voted_ballot: ::protobuf::RepeatedField<CandidateBallot>,
+    pub voted_ballot_unlisted: ::protobuf::RepeatedField<CipherPointsToBallotPair>,
+    pub zero_ballot: ::protobuf::SingularPtrField<Ballot>,
+    pub nullifier: ::std::vec::Vec<u8>,
+    pub aggregated_nullifier: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub sequence: u32,
+    pub sequence_binding: ::std::vec::Vec<u8>,
+    pub audit_record: ::protobuf::RepeatedField<VoteAuditRecord>,
+    pub certificate: ::protobuf::SingularPtrField<BlindCertificate>,
+    pub question_ballot: ::protobuf::RepeatedField<QuestionBallot>,
+    pub vote_root: ::std::vec::Vec<u8>,
+    pub encrypted_credential: ::protobuf::SingularPtrField<Ballot>,
+    pub cost_ballot: ::protobuf::RepeatedField<CandidateBallot>,
+    pub unused_credit_ballot: ::protobuf::SingularPtrField<Ballot>,
+    pub against_ballot: ::protobuf::RepeatedField<CandidateBallot>,
+    pub delegation: ::protobuf::RepeatedField<Delegation>,
+    pub vote_frontier: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a VoteStorage {
+    fn default() -> &'a VoteStorage {
+        <VoteStorage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl VoteStorage {
+    pub fn new() -> VoteStorage {
+        ::std::default::Default::default()
+    }
+
+    // bytes signature = 1;
+
+
+    pub fn get_signature(&self) -> &[u8] {
+        &self.signature
+    }
+    pub fn clear_signature(&mut self) {
+        self.signature.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature(&mut self, v: ::std::vec::Vec<u8>) {
+        self.signature = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_signature(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.signature
+    }
+
+    // Take field
+    pub fn take_signature(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.signature, ::std::vec::Vec::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.Ballot blank_ballot = 2;
+
+
+    pub fn get_blank_ballot(&self) -> &Ballot {
+        self.blank_ballot.as_ref().unwrap_or_else(|| <Ballot as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_blank_ballot(&mut self) {
+        self.blank_ballot.clear();
+    }
+
+    pub fn has_blank_ballot(&self) -> bool {
+        self.blank_ballot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_blank_ballot(&mut self, v: Ballot) {
+        self.blank_ballot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_blank_ballot(&mut self) -> &mut Ballot {
+        if self.blank_ballot.is_none() {
+            self.blank_ballot.set_default();
+        }
+        self.blank_ballot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_blank_ballot(&mut self) -> Ballot {
+        self.blank_ballot.take().unwrap_or_else(|| Ballot::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.Ballot rest_ballot = 3;
+
+
+    pub fn get_rest_ballot(&self) -> &Ballot {
+        self.rest_ballot.as_ref().unwrap_or_else(|| <Ballot as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_rest_ballot(&mut self) {
+        self.rest_ballot.clear();
+    }
+
+    pub fn has_rest_ballot(&self) -> bool {
+        self.rest_ballot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rest_ballot(&mut self, v: Ballot) {
+        self.rest_ballot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rest_ballot(&mut self) -> &mut Ballot {
+        if self.rest_ballot.is_none() {
+            self.rest_ballot.set_default();
+        }
+        self.rest_ballot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_rest_ballot(&mut self) -> Ballot {
+        self.rest_ballot.take().unwrap_or_else(|| Ballot::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.CandidateBallot voted_ballot = 4;
+
+
+    pub fn get_voted_ballot(&self) -> &[CandidateBallot] {
+        &self.voted_ballot
+    }
+    pub fn clear_voted_ballot(&mut self) {
+        self.voted_ballot.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_voted_ballot(&mut self, v: ::protobuf::RepeatedField<CandidateBallot>) {
+        self.voted_ballot = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_voted_ballot(&mut self) -> &mut ::protobuf::RepeatedField<CandidateBallot> {
+        &mut self.voted_ballot
+    }
+
+    // Take field
+    pub fn take_voted_ballot(&mut self) -> ::protobuf::RepeatedField<CandidateBallot> {
+        ::std::mem::replace(&mut self.voted_ballot, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.CipherPointsToBallotPair voted_ballot_unlisted = 5;
+
+
+    pub fn get_voted_ballot_unlisted(&self) -> &[CipherPointsToBallotPair] {
+        &self.voted_ballot_unlisted
+    }
+    pub fn clear_voted_ballot_unlisted(&mut self) {
+        self.voted_ballot_unlisted.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_voted_ballot_unlisted(&mut self, v: ::protobuf::RepeatedField<CipherPointsToBallotPair>) {
+        self.voted_ballot_unlisted = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_voted_ballot_unlisted(&mut self) -> &mut ::protobuf::RepeatedField<CipherPointsToBallotPair> {
+        &mut self.voted_ballot_unlisted
+    }
+
+    // Take field
+    pub fn take_voted_ballot_unlisted(&mut self) -> ::protobuf::RepeatedField<CipherPointsToBallotPair> {
+        ::std::mem::replace(&mut self.voted_ballot_unlisted, ::protobuf::RepeatedField::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.Ballot zero_ballot = 6;
+
+
+    pub fn get_zero_ballot(&self) -> &Ballot {
+        self.zero_ballot.as_ref().unwrap_or_else(|| <Ballot as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_zero_ballot(&mut self) {
+        self.zero_ballot.clear();
+    }
+
+    pub fn has_zero_ballot(&self) -> bool {
+        self.zero_ballot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_zero_ballot(&mut self, v: Ballot) {
+        self.zero_ballot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_zero_ballot(&mut self) -> &mut Ballot {
+        if self.zero_ballot.is_none() {
+            self.zero_ballot.set_default();
+        }
+        self.zero_ballot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_zero_ballot(&mut self) -> Ballot {
+        self.zero_ballot.take().unwrap_or_else(|| Ballot::new())
+    }
+
+    // bytes nullifier = 7;
+
+
+    pub fn get_nullifier(&self) -> &[u8] {
+        &self.nullifier
+    }
+    pub fn clear_nullifier(&mut self) {
+        self.nullifier.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nullifier(&mut self, v: ::std::vec::Vec<u8>) {
+        self.nullifier = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nullifier(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.nullifier
+    }
+
+    // Take field
+    pub fn take_nullifier(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.nullifier, ::std::vec::Vec::new())
+    }
+
+    // repeated bytes aggregated_nullifier = 8;
+
+
+    pub fn get_aggregated_nullifier(&self) -> &[::std::vec::Vec<u8>] {
+        &self.aggregated_nullifier
+    }
+    pub fn clear_aggregated_nullifier(&mut self) {
+        self.aggregated_nullifier.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_aggregated_nullifier(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.aggregated_nullifier = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_aggregated_nullifier(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.aggregated_nullifier
+    }
+
+    // Take field
+    pub fn take_aggregated_nullifier(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.aggregated_nullifier, ::protobuf::RepeatedField::new())
+    }
+
+    // uint32 sequence = 9;
+
+
+    pub fn get_sequence(&self) -> u32 {
+        self.sequence
+    }
+    pub fn clear_sequence(&mut self) {
+        self.sequence = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sequence(&mut self, v: u32) {
+        self.sequence = v;
+    }
+
+    // bytes sequence_binding = 10;
+
+
+    pub fn get_sequence_binding(&self) -> &[u8] {
+        &self.sequence_binding
+    }
+    pub fn clear_sequence_binding(&mut self) {
+        self.sequence_binding.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sequence_binding(&mut self, v: ::std::vec::Vec<u8>) {
+        self.sequence_binding = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sequence_binding(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.sequence_binding
+    }
+
+    // Take field
+    pub fn take_sequence_binding(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.sequence_binding, ::std::vec::Vec::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.VoteAuditRecord audit_record = 11;
+
+
+    pub fn get_audit_record(&self) -> &[VoteAuditRecord] {
+        &self.audit_record
+    }
+    pub fn clear_audit_record(&mut self) {
+        self.audit_record.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_audit_record(&mut self, v: ::protobuf::RepeatedField<VoteAuditRecord>) {
+        self.audit_record = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_audit_record(&mut self) -> &mut ::protobuf::RepeatedField<VoteAuditRecord> {
+        &mut self.audit_record
+    }
+
+    // Take field
+    pub fn take_audit_record(&mut self) -> ::protobuf::RepeatedField<VoteAuditRecord> {
+        ::std::mem::replace(&mut self.audit_record, ::protobuf::RepeatedField::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.BlindCertificate certificate = 12;
+
+
+    pub fn get_certificate(&self) -> &BlindCertificate {
+        self.certificate.as_ref().unwrap_or_else(|| <BlindCertificate as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_certificate(&mut self) {
+        self.certificate.clear();
+    }
+
+    pub fn has_certificate(&self) -> bool {
+        self.certificate.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_certificate(&mut self, v: BlindCertificate) {
+        self.certificate = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_certificate(&mut self) -> &mut BlindCertificate {
+        if self.certificate.is_none() {
+            self.certificate.set_default();
+        }
+        self.certificate.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_certificate(&mut self) -> BlindCertificate {
+        self.certificate.take().unwrap_or_else(|| BlindCertificate::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.QuestionBallot question_ballot = 13;
+
+
+    pub fn get_question_ballot(&self) -> &[QuestionBallot] {
+        &self.question_ballot
+    }
+    pub fn clear_question_ballot(&mut self) {
+        self.question_ballot.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_question_ballot(&mut self, v: ::protobuf::RepeatedField<QuestionBallot>) {
+        self.question_ballot = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_question_ballot(&mut self) -> &mut ::protobuf::RepeatedField<QuestionBallot> {
+        &mut self.question_ballot
+    }
+
+    // Take field
+    pub fn take_question_ballot(&mut self) -> ::protobuf::RepeatedField<QuestionBallot> {
+        ::std::mem::replace(&mut self.question_ballot, ::protobuf::RepeatedField::new())
+    }
+
+    // bytes vote_root = 14;
+
+
+    pub fn get_vote_root(&self) -> &[u8] {
+        &self.vote_root
+    }
+    pub fn clear_vote_root(&mut self) {
+        self.vote_root.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vote_root(&mut self, v: ::std::vec::Vec<u8>) {
+        self.vote_root = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vote_root(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.vote_root
+    }
+
+    // Take field
+    pub fn take_vote_root(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.vote_root, ::std::vec::Vec::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.Ballot encrypted_credential = 16;
+
+
+    pub fn get_encrypted_credential(&self) -> &Ballot {
+        self.encrypted_credential.as_ref().unwrap_or_else(|| <Ballot as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_encrypted_credential(&mut self) {
+        self.encrypted_credential.clear();
+    }
+
+    pub fn has_encrypted_credential(&self) -> bool {
+        self.encrypted_credential.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_encrypted_credential(&mut self, v: Ballot) {
+        self.encrypted_credential = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_encrypted_credential(&mut self) -> &mut Ballot {
+        if self.encrypted_credential.is_none() {
+            self.encrypted_credential.set_default();
+        }
+        self.encrypted_credential.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_encrypted_credential(&mut self) -> Ballot {
+        self.encrypted_credential.take().unwrap_or_else(|| Ballot::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.CandidateBallot cost_ballot = 17;
+
+
+    pub fn get_cost_ballot(&self) -> &[CandidateBallot] {
+        &self.cost_ballot
+    }
+    pub fn clear_cost_ballot(&mut self) {
+        self.cost_ballot.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cost_ballot(&mut self, v: ::protobuf::RepeatedField<CandidateBallot>) {
+        self.cost_ballot = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_cost_ballot(&mut self) -> &mut ::protobuf::RepeatedField<CandidateBallot> {
+        &mut self.cost_ballot
+    }
+
+    // Take field
+    pub fn take_cost_ballot(&mut self) -> ::protobuf::RepeatedField<CandidateBallot> {
+        ::std::mem::replace(&mut self.cost_ballot, ::protobuf::RepeatedField::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.Ballot unused_credit_ballot = 18;
+
+
+    pub fn get_unused_credit_ballot(&self) -> &Ballot {
+        self.unused_credit_ballot.as_ref().unwrap_or_else(|| <Ballot as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_unused_credit_ballot(&mut self) {
+        self.unused_credit_ballot.clear();
+    }
+
+    pub fn has_unused_credit_ballot(&self) -> bool {
+        self.unused_credit_ballot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_unused_credit_ballot(&mut self, v: Ballot) {
+        self.unused_credit_ballot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_unused_credit_ballot(&mut self) -> &mut Ballot {
+        if self.unused_credit_ballot.is_none() {
+            self.unused_credit_ballot.set_default();
+        }
+        self.unused_credit_ballot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_unused_credit_ballot(&mut self) -> Ballot {
+        self.unused_credit_ballot.take().unwrap_or_else(|| Ballot::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.CandidateBallot against_ballot = 19;
+
+
+    pub fn get_against_ballot(&self) -> &[CandidateBallot] {
+        &self.against_ballot
+    }
+    pub fn clear_against_ballot(&mut self) {
+        self.against_ballot.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_against_ballot(&mut self, v: ::protobuf::RepeatedField<CandidateBallot>) {
+        self.against_ballot = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_against_ballot(&mut self) -> &mut ::protobuf::RepeatedField<CandidateBallot> {
+        &mut self.against_ballot
+    }
+
+    // Take field
+    pub fn take_against_ballot(&mut self) -> ::protobuf::RepeatedField<CandidateBallot> {
+        ::std::mem::replace(&mut self.against_ballot, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.Delegation delegation = 20;
+
+
+    pub fn get_delegation(&self) -> &[Delegation] {
+        &self.delegation
+    }
+    pub fn clear_delegation(&mut self) {
+        self.delegation.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_delegation(&mut self, v: ::protobuf::RepeatedField<Delegation>) {
+        self.delegation = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_delegation(&mut self) -> &mut ::protobuf::RepeatedField<Delegation> {
+        &mut self.delegation
+    }
+
+    // Take field
+    pub fn take_delegation(&mut self) -> ::protobuf::RepeatedField<Delegation> {
+        ::std::mem::replace(&mut self.delegation, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated bytes vote_frontier = 21;
+
+
+    pub fn get_vote_frontier(&self) -> &[::std::vec::Vec<u8>] {
+        &self.vote_frontier
+    }
+    pub fn clear_vote_frontier(&mut self) {
+        self.vote_frontier.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vote_frontier(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.vote_frontier = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_vote_frontier(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.vote_frontier
+    }
+
+    // Take field
+    pub fn take_vote_frontier(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.vote_frontier, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for VoteStorage {
+    fn is_initialized(&self) -> bool {
+        for v in &self.blank_ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.rest_ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.voted_ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.voted_ballot_unlisted {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.zero_ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.audit_record {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.certificate {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.question_ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.encrypted_credential {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.cost_ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.unused_credit_ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.against_ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.delegation {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.signature)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.blank_ballot)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.rest_ballot)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.voted_ballot)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.voted_ballot_unlisted)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.zero_ballot)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.nullifier)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.aggregated_nullifier)?;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.sequence = tmp;
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.sequence_binding)?;
+                },
+                11 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.audit_record)?;
+                },
+                12 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.certificate)?;
+                },
+                13 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.question_ballot)?;
+                },
+                14 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.vote_root)?;
+                },
+                16 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.encrypted_credential)?;
+                },
+                17 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.cost_ballot)?;
+                },
+                18 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.unused_credit_ballot)?;
+                },
+                19 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.against_ballot)?;
+                },
+                20 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.delegation)?;
+                },
+                21 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.vote_frontier)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.signature.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.signature);
+        }
+        if let Some(ref v) = self.blank_ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.rest_ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.voted_ballot {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.voted_ballot_unlisted {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.zero_ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.nullifier.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(7, &self.nullifier);
+        }
+        for value in &self.aggregated_nullifier {
+            my_size += ::protobuf::rt::bytes_size(8, &value);
+        };
+        if self.sequence != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.sequence, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.sequence_binding.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(10, &self.sequence_binding);
+        }
+        for value in &self.audit_record {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.certificate.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.question_ballot {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if !self.vote_root.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(14, &self.vote_root);
+        }
+        if let Some(ref v) = self.encrypted_credential.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.cost_ballot {
+            let len = value.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.unused_credit_ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.against_ballot {
+            let len = value.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.delegation {
+            let len = value.compute_size();
+            my_size += 2 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.vote_frontier {
+            my_size += ::protobuf::rt::bytes_size(21, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.signature.is_empty() {
+            os.write_bytes(1, &self.signature)?;
+        }
+        if let Some(ref v) = self.blank_ballot.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.rest_ballot.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.voted_ballot {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.voted_ballot_unlisted {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.zero_ballot.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.nullifier.is_empty() {
+            os.write_bytes(7, &self.nullifier)?;
+        }
+        for v in &self.aggregated_nullifier {
+            os.write_bytes(8, &v)?;
+        };
+        if self.sequence != 0 {
+            os.write_uint32(9, self.sequence)?;
+        }
+        if !self.sequence_binding.is_empty() {
+            os.write_bytes(10, &self.sequence_binding)?;
+        }
+        for v in &self.audit_record {
+            os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.certificate.as_ref() {
+            os.write_tag(12, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.question_ballot {
+            os.write_tag(13, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if !self.vote_root.is_empty() {
+            os.write_bytes(14, &self.vote_root)?;
+        }
+        if let Some(ref v) = self.encrypted_credential.as_ref() {
+            os.write_tag(16, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.cost_ballot {
+            os.write_tag(17, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.unused_credit_ballot.as_ref() {
+            os.write_tag(18, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.against_ballot {
+            os.write_tag(19, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.delegation {
+            os.write_tag(20, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.vote_frontier {
+            os.write_bytes(21, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> VoteStorage {
+        VoteStorage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "signature",
+                |m: &VoteStorage| { &m.signature },
+                |m: &mut VoteStorage| { &mut m.signature },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                "blank_ballot",
+                |m: &VoteStorage| { &m.blank_ballot },
+                |m: &mut VoteStorage| { &mut m.blank_ballot },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                "rest_ballot",
+                |m: &VoteStorage| { &m.rest_ballot },
+                |m: &mut VoteStorage| { &mut m.rest_ballot },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CandidateBallot>>(
+                "voted_ballot",
+                |m: &VoteStorage| { &m.voted_ballot },
+                |m: &mut VoteStorage| { &mut m.voted_ballot },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CipherPointsToBallotPair>>(
+                "voted_ballot_unlisted",
+                |m: &VoteStorage| { &m.voted_ballot_unlisted },
+                |m: &mut VoteStorage| { &mut m.voted_ballot_unlisted },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                "zero_ballot",
+                |m: &VoteStorage| { &m.zero_ballot },
+                |m: &mut VoteStorage| { &mut m.zero_ballot },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "nullifier",
+                |m: &VoteStorage| { &m.nullifier },
+                |m: &mut VoteStorage| { &mut m.nullifier },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "aggregated_nullifier",
+                |m: &VoteStorage| { &m.aggregated_nullifier },
+                |m: &mut VoteStorage| { &mut m.aggregated_nullifier },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "sequence",
+                |m: &VoteStorage| { &m.sequence },
+                |m: &mut VoteStorage| { &mut m.sequence },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "sequence_binding",
+                |m: &VoteStorage| { &m.sequence_binding },
+                |m: &mut VoteStorage| { &mut m.sequence_binding },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<VoteAuditRecord>>(
+                "audit_record",
+                |m: &VoteStorage| { &m.audit_record },
+                |m: &mut VoteStorage| { &mut m.audit_record },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BlindCertificate>>(
+                "certificate",
+                |m: &VoteStorage| { &m.certificate },
+                |m: &mut VoteStorage| { &mut m.certificate },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<QuestionBallot>>(
+                "question_ballot",
+                |m: &VoteStorage| { &m.question_ballot },
+                |m: &mut VoteStorage| { &mut m.question_ballot },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "vote_root",
+                |m: &VoteStorage| { &m.vote_root },
+                |m: &mut VoteStorage| { &mut m.vote_root },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                "encrypted_credential",
+                |m: &VoteStorage| { &m.encrypted_credential },
+                |m: &mut VoteStorage| { &mut m.encrypted_credential },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CandidateBallot>>(
+                "cost_ballot",
+                |m: &VoteStorage| { &m.cost_ballot },
+                |m: &mut VoteStorage| { &mut m.cost_ballot },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                "unused_credit_ballot",
+                |m: &VoteStorage| { &m.unused_credit_ballot },
+                |m: &mut VoteStorage| { &mut m.unused_credit_ballot },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CandidateBallot>>(
+                "against_ballot",
+                |m: &VoteStorage| { &m.against_ballot },
+                |m: &mut VoteStorage| { &mut m.against_ballot },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Delegation>>(
+                "delegation",
+                |m: &VoteStorage| { &m.delegation },
+                |m: &mut VoteStorage| { &mut m.delegation },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "vote_frontier",
+                |m: &VoteStorage| { &m.vote_frontier },
+                |m: &mut VoteStorage| { &mut m.vote_frontier },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<VoteStorage>(
+                "VoteStorage",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static VoteStorage {
+        static instance: ::protobuf::rt::LazyV2<VoteStorage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(VoteStorage::new)
+    }
+}
+
+impl ::protobuf::Clear for VoteStorage {
+    fn clear(&mut self) {
+        self.signature.clear();
+        self.blank_ballot.clear();
+        self.rest_ballot.clear();
+        self.voted_ballot.clear();
+        self.voted_ballot_unlisted.clear();
+        self.zero_ballot.clear();
+        self.nullifier.clear();
+        self.aggregated_nullifier.clear();
+        self.sequence = 0;
+        self.sequence_binding.clear();
+        self.audit_record.clear();
+        self.certificate.clear();
+        self.question_ballot.clear();
+        self.vote_root.clear();
+        self.encrypted_credential.clear();
+        self.cost_ballot.clear();
+        self.unused_credit_ballot.clear();
+        self.against_ballot.clear();
+        self.delegation.clear();
+        self.vote_frontier.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for VoteStorage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VoteStorage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QuestionBallot {
+    // message fields
+    pub question_id: ::std::string::String,
+    pub voted_ballot: ::protobuf::RepeatedField<CandidateBallot>,
+    pub rest_ballot: ::protobuf::SingularPtrField<Ballot>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QuestionBallot {
+    fn default() -> &'a QuestionBallot {
+        <QuestionBallot as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QuestionBallot {
+    pub fn new() -> QuestionBallot {
+        ::std::default::Default::default()
+    }
+
+    // string question_id = 1;
+
+
+    pub fn get_question_id(&self) -> &str {
+        &self.question_id
+    }
+    pub fn clear_question_id(&mut self) {
+        self.question_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_question_id(&mut self, v: ::std::string::String) {
+        self.question_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_question_id(&mut self) -> &mut ::std::string::String {
+        &mut self.question_id
+    }
+
+    // Take field
+    pub fn take_question_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.question_id, ::std::string::String::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.CandidateBallot voted_ballot = 2;
+
+
+    pub fn get_voted_ballot(&self) -> &[CandidateBallot] {
+        &self.voted_ballot
+    }
+    pub fn clear_voted_ballot(&mut self) {
+        self.voted_ballot.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_voted_ballot(&mut self, v: ::protobuf::RepeatedField<CandidateBallot>) {
+        self.voted_ballot = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_voted_ballot(&mut self) -> &mut ::protobuf::RepeatedField<CandidateBallot> {
+        &mut self.voted_ballot
+    }
+
+    // Take field
+    pub fn take_voted_ballot(&mut self) -> ::protobuf::RepeatedField<CandidateBallot> {
+        ::std::mem::replace(&mut self.voted_ballot, ::protobuf::RepeatedField::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.Ballot rest_ballot = 3;
+
+
+    pub fn get_rest_ballot(&self) -> &Ballot {
+        self.rest_ballot.as_ref().unwrap_or_else(|| <Ballot as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_rest_ballot(&mut self) {
+        self.rest_ballot.clear();
+    }
+
+    pub fn has_rest_ballot(&self) -> bool {
+        self.rest_ballot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rest_ballot(&mut self, v: Ballot) {
+        self.rest_ballot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rest_ballot(&mut self) -> &mut Ballot {
+        if self.rest_ballot.is_none() {
+            self.rest_ballot.set_default();
+        }
+        self.rest_ballot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_rest_ballot(&mut self) -> Ballot {
+        self.rest_ballot.take().unwrap_or_else(|| Ballot::new())
+    }
+}
+
+impl ::protobuf::Message for QuestionBallot {
+    fn is_initialized(&self) -> bool {
+        for v in &self.voted_ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.rest_ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.question_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.voted_ballot)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.rest_ballot)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.question_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.question_id);
+        }
+        for value in &self.voted_ballot {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.rest_ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.question_id.is_empty() {
+            os.write_string(1, &self.question_id)?;
+        }
+        for v in &self.voted_ballot {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.rest_ballot.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QuestionBallot {
+        QuestionBallot::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "question_id",
+                |m: &QuestionBallot| { &m.question_id },
+                |m: &mut QuestionBallot| { &mut m.question_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CandidateBallot>>(
+                "voted_ballot",
+                |m: &QuestionBallot| { &m.voted_ballot },
+                |m: &mut QuestionBallot| { &mut m.voted_ballot },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                "rest_ballot",
+                |m: &QuestionBallot| { &m.rest_ballot },
+                |m: &mut QuestionBallot| { &mut m.rest_ballot },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QuestionBallot>(
+                "QuestionBallot",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QuestionBallot {
+        static instance: ::protobuf::rt::LazyV2<QuestionBallot> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QuestionBallot::new)
+    }
+}
+
+impl ::protobuf::Clear for QuestionBallot {
+    fn clear(&mut self) {
+        self.question_id.clear();
+        self.voted_ballot.clear();
+        self.rest_ballot.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QuestionBallot {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QuestionBallot {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct VoteAuditRecord {
+    // message fields
+    pub nullifier: ::std::vec::Vec<u8>,
+    pub sequence: u32,
+    pub vote_hash: ::std::vec::Vec<u8>,
+    pub replaced_vote_hash: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a VoteAuditRecord {
+    fn default() -> &'a VoteAuditRecord {
+        <VoteAuditRecord as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl VoteAuditRecord {
+    pub fn new() -> VoteAuditRecord {
+        ::std::default::Default::default()
+    }
+
+    // bytes nullifier = 1;
+
+
+    pub fn get_nullifier(&self) -> &[u8] {
+        &self.nullifier
+    }
+    pub fn clear_nullifier(&mut self) {
+        self.nullifier.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_nullifier(&mut self, v: ::std::vec::Vec<u8>) {
+        self.nullifier = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_nullifier(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.nullifier
+    }
+
+    // Take field
+    pub fn take_nullifier(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.nullifier, ::std::vec::Vec::new())
+    }
+
+    // uint32 sequence = 2;
+
+
+    pub fn get_sequence(&self) -> u32 {
+        self.sequence
+    }
+    pub fn clear_sequence(&mut self) {
+        self.sequence = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sequence(&mut self, v: u32) {
+        self.sequence = v;
+    }
+
+    // bytes vote_hash = 3;
+
+
+    pub fn get_vote_hash(&self) -> &[u8] {
+        &self.vote_hash
+    }
+    pub fn clear_vote_hash(&mut self) {
+        self.vote_hash.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vote_hash(&mut self, v: ::std::vec::Vec<u8>) {
+        self.vote_hash = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vote_hash(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.vote_hash
+    }
+
+    // Take field
+    pub fn take_vote_hash(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.vote_hash, ::std::vec::Vec::new())
+    }
+
+    // bytes replaced_vote_hash = 4;
+
+
+    pub fn get_replaced_vote_hash(&self) -> &[u8] {
+        &self.replaced_vote_hash
+    }
+    pub fn clear_replaced_vote_hash(&mut self) {
+        self.replaced_vote_hash.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_replaced_vote_hash(&mut self, v: ::std::vec::Vec<u8>) {
+        self.replaced_vote_hash = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_replaced_vote_hash(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.replaced_vote_hash
+    }
+
+    // Take field
+    pub fn take_replaced_vote_hash(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.replaced_vote_hash, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for VoteAuditRecord {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.nullifier)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.sequence = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.vote_hash)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.replaced_vote_hash)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.nullifier.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.nullifier);
+        }
+        if self.sequence != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.sequence, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.vote_hash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.vote_hash);
+        }
+        if !self.replaced_vote_hash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.replaced_vote_hash);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.nullifier.is_empty() {
+            os.write_bytes(1, &self.nullifier)?;
+        }
+        if self.sequence != 0 {
+            os.write_uint32(2, self.sequence)?;
+        }
+        if !self.vote_hash.is_empty() {
+            os.write_bytes(3, &self.vote_hash)?;
+        }
+        if !self.replaced_vote_hash.is_empty() {
+            os.write_bytes(4, &self.replaced_vote_hash)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> VoteAuditRecord {
+        VoteAuditRecord::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "nullifier",
+                |m: &VoteAuditRecord| { &m.nullifier },
+                |m: &mut VoteAuditRecord| { &mut m.nullifier },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "sequence",
+                |m: &VoteAuditRecord| { &m.sequence },
+                |m: &mut VoteAuditRecord| { &mut m.sequence },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "vote_hash",
+                |m: &VoteAuditRecord| { &m.vote_hash },
+                |m: &mut VoteAuditRecord| { &mut m.vote_hash },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "replaced_vote_hash",
+                |m: &VoteAuditRecord| { &m.replaced_vote_hash },
+                |m: &mut VoteAuditRecord| { &mut m.replaced_vote_hash },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<VoteAuditRecord>(
+                "VoteAuditRecord",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static VoteAuditRecord {
+        static instance: ::protobuf::rt::LazyV2<VoteAuditRecord> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(VoteAuditRecord::new)
+    }
+}
+
+impl ::protobuf::Clear for VoteAuditRecord {
+    fn clear(&mut self) {
+        self.nullifier.clear();
+        self.sequence = 0;
+        self.vote_hash.clear();
+        self.replaced_vote_hash.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for VoteAuditRecord {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VoteAuditRecord {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CipherPointsToBallotPair {
+    // message fields
+    pub key: ::protobuf::SingularPtrField<CipherPoints>,
+    pub ballot: ::protobuf::SingularPtrField<Ballot>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CipherPointsToBallotPair {
+    fn default() -> &'a CipherPointsToBallotPair {
+        <CipherPointsToBallotPair as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CipherPointsToBallotPair {
+    pub fn new() -> CipherPointsToBallotPair {
+        ::std::default::Default::default()
+    }
+
+    // .com.webank.wedpr.acv.proto.CipherPoints key = 1;
+
+
+    pub fn get_key(&self) -> &CipherPoints {
+        self.key.as_ref().unwrap_or_else(|| <CipherPoints as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    pub fn has_key(&self) -> bool {
+        self.key.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: CipherPoints) {
+        self.key = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut CipherPoints {
+        if self.key.is_none() {
+            self.key.set_default();
+        }
+        self.key.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> CipherPoints {
+        self.key.take().unwrap_or_else(|| CipherPoints::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.Ballot ballot = 2;
+
+
+    pub fn get_ballot(&self) -> &Ballot {
+        self.ballot.as_ref().unwrap_or_else(|| <Ballot as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_ballot(&mut self) {
+        self.ballot.clear();
+    }
+
+    pub fn has_ballot(&self) -> bool {
+        self.ballot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ballot(&mut self, v: Ballot) {
+        self.ballot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ballot(&mut self) -> &mut Ballot {
+        if self.ballot.is_none() {
+            self.ballot.set_default();
+        }
+        self.ballot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_ballot(&mut self) -> Ballot {
+        self.ballot.take().unwrap_or_else(|| Ballot::new())
+    }
+}
+
+impl ::protobuf::Message for CipherPointsToBallotPair {
+    fn is_initialized(&self) -> bool {
+        for v in &self.key {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.ballot)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.key.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.key.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.ballot.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CipherPointsToBallotPair {
+        CipherPointsToBallotPair::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CipherPoints>>(
+                "key",
+                |m: &CipherPointsToBallotPair| { &m.key },
+                |m: &mut CipherPointsToBallotPair| { &mut m.key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                "ballot",
+                |m: &CipherPointsToBallotPair| { &m.ballot },
+                |m: &mut CipherPointsToBallotPair| { &mut m.ballot },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CipherPointsToBallotPair>(
+                "CipherPointsToBallotPair",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CipherPointsToBallotPair {
+        static instance: ::protobuf::rt::LazyV2<CipherPointsToBallotPair> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CipherPointsToBallotPair::new)
+    }
+}
+
+impl ::protobuf::Clear for CipherPointsToBallotPair {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.ballot.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CipherPointsToBallotPair {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CipherPointsToBallotPair {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CipherPointsToBallotProofPair {
+    // message fields
+    pub key: ::protobuf::SingularPtrField<CipherPoints>,
+    pub value: ::protobuf::SingularPtrField<BallotProof>,
+    pub candidate_format_proof: ::std::vec::Vec<u8>,
+    pub candidate_range_proof: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CipherPointsToBallotProofPair {
+    fn default() -> &'a CipherPointsToBallotProofPair {
+        <CipherPointsToBallotProofPair as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CipherPointsToBallotProofPair {
+    pub fn new() -> CipherPointsToBallotProofPair {
+        ::std::default::Default::default()
+    }
+
+    // .com.webank.wedpr.acv.proto.CipherPoints key = 1;
+
+
+    pub fn get_key(&self) -> &CipherPoints {
+        self.key.as_ref().unwrap_or_else(|| <CipherPoints as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    pub fn has_key(&self) -> bool {
+        self.key.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: CipherPoints) {
+        self.key = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut CipherPoints {
+        if self.key.is_none() {
+            self.key.set_default();
+        }
+        self.key.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> CipherPoints {
+        self.key.take().unwrap_or_else(|| CipherPoints::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.BallotProof value = 2;
+
+
+    pub fn get_value(&self) -> &BallotProof {
+        self.value.as_ref().unwrap_or_else(|| <BallotProof as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: BallotProof) {
+        self.value = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut BallotProof {
+        if self.value.is_none() {
+            self.value.set_default();
+        }
+        self.value.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> BallotProof {
+        self.value.take().unwrap_or_else(|| BallotProof::new())
+    }
+
+    // bytes candidate_format_proof = 3;
+
+
+    pub fn get_candidate_format_proof(&self) -> &[u8] {
+        &self.candidate_format_proof
+    }
+    pub fn clear_candidate_format_proof(&mut self) {
+        self.candidate_format_proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_candidate_format_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.candidate_format_proof = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_candidate_format_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.candidate_format_proof
+    }
+
+    // Take field
+    pub fn take_candidate_format_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.candidate_format_proof, ::std::vec::Vec::new())
+    }
+
+    // bytes candidate_range_proof = 4;
+
+
+    pub fn get_candidate_range_proof(&self) -> &[u8] {
+        &self.candidate_range_proof
+    }
+    pub fn clear_candidate_range_proof(&mut self) {
+        self.candidate_range_proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_candidate_range_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.candidate_range_proof = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_candidate_range_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.candidate_range_proof
+    }
+
+    // Take field
+    pub fn take_candidate_range_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.candidate_range_proof, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for CipherPointsToBallotProofPair {
+    fn is_initialized(&self) -> bool {
+        for v in &self.key {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.value {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.value)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.candidate_format_proof)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.candidate_range_proof)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.key.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.value.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.candidate_format_proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.candidate_format_proof);
+        }
+        if !self.candidate_range_proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.candidate_range_proof);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.key.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.value.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.candidate_format_proof.is_empty() {
+            os.write_bytes(3, &self.candidate_format_proof)?;
+        }
+        if !self.candidate_range_proof.is_empty() {
+            os.write_bytes(4, &self.candidate_range_proof)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CipherPointsToBallotProofPair {
+        CipherPointsToBallotProofPair::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CipherPoints>>(
+                "key",
+                |m: &CipherPointsToBallotProofPair| { &m.key },
+                |m: &mut CipherPointsToBallotProofPair| { &mut m.key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BallotProof>>(
+                "value",
+                |m: &CipherPointsToBallotProofPair| { &m.value },
+                |m: &mut CipherPointsToBallotProofPair| { &mut m.value },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "candidate_format_proof",
+                |m: &CipherPointsToBallotProofPair| { &m.candidate_format_proof },
+                |m: &mut CipherPointsToBallotProofPair| { &mut m.candidate_format_proof },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "candidate_range_proof",
+                |m: &CipherPointsToBallotProofPair| { &m.candidate_range_proof },
+                |m: &mut CipherPointsToBallotProofPair| { &mut m.candidate_range_proof },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CipherPointsToBallotProofPair>(
+                "CipherPointsToBallotProofPair",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CipherPointsToBallotProofPair {
+        static instance: ::protobuf::rt::LazyV2<CipherPointsToBallotProofPair> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CipherPointsToBallotProofPair::new)
+    }
+}
+
+impl ::protobuf::Clear for CipherPointsToBallotProofPair {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.value.clear();
+        self.candidate_format_proof.clear();
+        self.candidate_range_proof.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CipherPointsToBallotProofPair {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CipherPointsToBallotProofPair {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CipherPoints {
+    // message fields
+    pub ciphertext1: ::std::vec::Vec<u8>,
+    pub ciphertext2: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CipherPoints {
+    fn default() -> &'a CipherPoints {
+        <CipherPoints as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CipherPoints {
+    pub fn new() -> CipherPoints {
+        ::std::default::Default::default()
+    }
+
+    // bytes ciphertext1 = 1;
+
+
+    pub fn get_ciphertext1(&self) -> &[u8] {
+        &self.ciphertext1
+    }
+    pub fn clear_ciphertext1(&mut self) {
+        self.ciphertext1.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ciphertext1(&mut self, v: ::std::vec::Vec<u8>) {
+        self.ciphertext1 = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ciphertext1(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.ciphertext1
+    }
+
+    // Take field
+    pub fn take_ciphertext1(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.ciphertext1, ::std::vec::Vec::new())
+    }
+
+    // bytes ciphertext2 = 2;
+
+
+    pub fn get_ciphertext2(&self) -> &[u8] {
+        &self.ciphertext2
+    }
+    pub fn clear_ciphertext2(&mut self) {
+        self.ciphertext2.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ciphertext2(&mut self, v: ::std::vec::Vec<u8>) {
+        self.ciphertext2 = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ciphertext2(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.ciphertext2
+    }
+
+    // Take field
+    pub fn take_ciphertext2(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.ciphertext2, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for CipherPoints {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.ciphertext1)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.ciphertext2)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.ciphertext1.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.ciphertext1);
+        }
+        if !self.ciphertext2.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.ciphertext2);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.ciphertext1.is_empty() {
+            os.write_bytes(1, &self.ciphertext1)?;
+        }
+        if !self.ciphertext2.is_empty() {
+            os.write_bytes(2, &self.ciphertext2)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CipherPoints {
+        CipherPoints::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "ciphertext1",
+                |m: &CipherPoints| { &m.ciphertext1 },
+                |m: &mut CipherPoints| { &mut m.ciphertext1 },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "ciphertext2",
+                |m: &CipherPoints| { &m.ciphertext2 },
+                |m: &mut CipherPoints| { &mut m.ciphertext2 },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CipherPoints>(
+                "CipherPoints",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CipherPoints {
+        static instance: ::protobuf::rt::LazyV2<CipherPoints> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CipherPoints::new)
+    }
+}
+
+impl ::protobuf::Clear for CipherPoints {
+    fn clear(&mut self) {
+        self.ciphertext1.clear();
+        self.ciphertext2.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CipherPoints {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CipherPoints {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CountingPart {
+    // message fields
+    pub counter_id: ::std::string::String,
+    pub blinding_c2: ::std::vec::Vec<u8>,
+    pub equality_proof: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CountingPart {
+    fn default() -> &'a CountingPart {
+        <CountingPart as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CountingPart {
+    pub fn new() -> CountingPart {
+        ::std::default::Default::default()
+    }
+
+    // string counter_id = 1;
+
+
+    pub fn get_counter_id(&self) -> &str {
+        &self.counter_id
+    }
+    pub fn clear_counter_id(&mut self) {
+        self.counter_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_counter_id(&mut self, v: ::std::string::String) {
+        self.counter_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_counter_id(&mut self) -> &mut ::std::string::String {
+        &mut self.counter_id
+    }
+
+    // Take field
+    pub fn take_counter_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.counter_id, ::std::string::String::new())
+    }
+
+    // bytes blinding_c2 = 2;
+
+
+    pub fn get_blinding_c2(&self) -> &[u8] {
+        &self.blinding_c2
+    }
+    pub fn clear_blinding_c2(&mut self) {
+        self.blinding_c2.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_blinding_c2(&mut self, v: ::std::vec::Vec<u8>) {
+        self.blinding_c2 = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_blinding_c2(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.blinding_c2
+    }
+
+    // Take field
+    pub fn take_blinding_c2(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.blinding_c2, ::std::vec::Vec::new())
+    }
+
+    // bytes equality_proof = 3;
+
+
+    pub fn get_equality_proof(&self) -> &[u8] {
+        &self.equality_proof
+    }
+    pub fn clear_equality_proof(&mut self) {
+        self.equality_proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_equality_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.equality_proof = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_equality_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.equality_proof
+    }
+
+    // Take field
+    pub fn take_equality_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.equality_proof, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for CountingPart {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.counter_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.blinding_c2)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.equality_proof)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.counter_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.counter_id);
+        }
+        if !self.blinding_c2.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.blinding_c2);
+        }
+        if !self.equality_proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.equality_proof);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.counter_id.is_empty() {
+            os.write_string(1, &self.counter_id)?;
+        }
+        if !self.blinding_c2.is_empty() {
+            os.write_bytes(2, &self.blinding_c2)?;
+        }
+        if !self.equality_proof.is_empty() {
+            os.write_bytes(3, &self.equality_proof)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CountingPart {
+        CountingPart::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "counter_id",
+                |m: &CountingPart| { &m.counter_id },
+                |m: &mut CountingPart| { &mut m.counter_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "blinding_c2",
+                |m: &CountingPart| { &m.blinding_c2 },
+                |m: &mut CountingPart| { &mut m.blinding_c2 },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "equality_proof",
+                |m: &CountingPart| { &m.equality_proof },
+                |m: &mut CountingPart| { &mut m.equality_proof },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CountingPart>(
+                "CountingPart",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CountingPart {
+        static instance: ::protobuf::rt::LazyV2<CountingPart> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CountingPart::new)
+    }
+}
+
+impl ::protobuf::Clear for CountingPart {
+    fn clear(&mut self) {
+        self.counter_id.clear();
+        self.blinding_c2.clear();
+        self.equality_proof.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CountingPart {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CountingPart {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StringToCountingPartPair {
+    // message fields
+    pub key: ::std::string::String,
+    pub value: ::protobuf::SingularPtrField<CountingPart>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StringToCountingPartPair {
+    fn default() -> &'a StringToCountingPartPair {
+        <StringToCountingPartPair as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StringToCountingPartPair {
+    pub fn new() -> StringToCountingPartPair {
+        ::std::default::Default::default()
+    }
+
+    // string key = 1;
+
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.CountingPart value = 2;
+
+
+    pub fn get_value(&self) -> &CountingPart {
+        self.value.as_ref().unwrap_or_else(|| <CountingPart as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_value(&mut self) {
+        self.value.clear();
+    }
+
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: CountingPart) {
+        self.value = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_value(&mut self) -> &mut CountingPart {
+        if self.value.is_none() {
+            self.value.set_default();
+        }
+        self.value.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_value(&mut self) -> CountingPart {
+        self.value.take().unwrap_or_else(|| CountingPart::new())
+    }
+}
+
+impl ::protobuf::Message for StringToCountingPartPair {
+    fn is_initialized(&self) -> bool {
+        for v in &self.value {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.value)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
+        }
+        if let Some(ref v) = self.value.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
+        }
+        if let Some(ref v) = self.value.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StringToCountingPartPair {
+        StringToCountingPartPair::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "key",
+                |m: &StringToCountingPartPair| { &m.key },
+                |m: &mut StringToCountingPartPair| { &mut m.key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CountingPart>>(
+                "value",
+                |m: &StringToCountingPartPair| { &m.value },
+                |m: &mut StringToCountingPartPair| { &mut m.value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<StringToCountingPartPair>(
+                "StringToCountingPartPair",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static StringToCountingPartPair {
+        static instance: ::protobuf::rt::LazyV2<StringToCountingPartPair> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(StringToCountingPartPair::new)
+    }
+}
+
+impl ::protobuf::Clear for StringToCountingPartPair {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.value.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StringToCountingPartPair {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StringToCountingPartPair {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct DecryptedResultPartStorage {
+    // message fields
+    pub blank_part: ::protobuf::SingularPtrField<CountingPart>,
+    pub candidate_part: ::protobuf::RepeatedField<StringToCountingPartPair>,
+    pub unlisted_candidate_part: ::protobuf::RepeatedField<UnlistedBallotDecryptedResult>,
+    pub question_part: ::protobuf::RepeatedField<QuestionCountingPart>,
+    pub rest_part: ::protobuf::SingularPtrField<CountingPart>,
+    pub against_part: ::protobuf::RepeatedField<StringToCountingPartPair>,
+    pub aggregated_counter_id: ::protobuf::RepeatedField<::std::string::String>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a DecryptedResultPartStorage {
+    fn default() -> &'a DecryptedResultPartStorage {
+        <DecryptedResultPartStorage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl DecryptedResultPartStorage {
+    pub fn new() -> DecryptedResultPartStorage {
+        ::std::default::Default::default()
+    }
+
+    // .com.webank.wedpr.acv.proto.CountingPart blank_part = 1;
+
+
+    pub fn get_blank_part(&self) -> &CountingPart {
+        self.blank_part.as_ref().unwrap_or_else(|| <CountingPart as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_blank_part(&mut self) {
+        self.blank_part.clear();
+    }
+
+    pub fn has_blank_part(&self) -> bool {
+        self.blank_part.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_blank_part(&mut self, v: CountingPart) {
+        self.blank_part = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_blank_part(&mut self) -> &mut CountingPart {
+        if self.blank_part.is_none() {
+            self.blank_part.set_default();
+        }
+        self.blank_part.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_blank_part(&mut self) -> CountingPart {
+        self.blank_part.take().unwrap_or_else(|| CountingPart::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.StringToCountingPartPair candidate_part = 2;
+
+
+    pub fn get_candidate_part(&self) -> &[StringToCountingPartPair] {
+        &self.candidate_part
+    }
+    pub fn clear_candidate_part(&mut self) {
+        self.candidate_part.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_candidate_part(&mut self, v: ::protobuf::RepeatedField<StringToCountingPartPair>) {
+        self.candidate_part = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_candidate_part(&mut self) -> &mut ::protobuf::RepeatedField<StringToCountingPartPair> {
+        &mut self.candidate_part
+    }
+
+    // Take field
+    pub fn take_candidate_part(&mut self) -> ::protobuf::RepeatedField<StringToCountingPartPair> {
+        ::std::mem::replace(&mut self.candidate_part, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.UnlistedBallotDecryptedResult unlisted_candidate_part = 3;
+
+
+    pub fn get_unlisted_candidate_part(&self) -> &[UnlistedBallotDecryptedResult] {
+        &self.unlisted_candidate_part
+    }
+    pub fn clear_unlisted_candidate_part(&mut self) {
+        self.unlisted_candidate_part.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_unlisted_candidate_part(&mut self, v: ::protobuf::RepeatedField<UnlistedBallotDecryptedResult>) {
+        self.unlisted_candidate_part = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_unlisted_candidate_part(&mut self) -> &mut ::protobuf::RepeatedField<UnlistedBallotDecryptedResult> {
+        &mut self.unlisted_candidate_part
+    }
+
+    // Take field
+    pub fn take_unlisted_candidate_part(&mut self) -> ::protobuf::RepeatedField<UnlistedBallotDecryptedResult> {
+        ::std::mem::replace(&mut self.unlisted_candidate_part, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.QuestionCountingPart question_part = 4;
+
+
+    pub fn get_question_part(&self) -> &[QuestionCountingPart] {
+        &self.question_part
+    }
+    pub fn clear_question_part(&mut self) {
+        self.question_part.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_question_part(&mut self, v: ::protobuf::RepeatedField<QuestionCountingPart>) {
+        self.question_part = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_question_part(&mut self) -> &mut ::protobuf::RepeatedField<QuestionCountingPart> {
+        &mut self.question_part
+    }
+
+    // Take field
+    pub fn take_question_part(&mut self) -> ::protobuf::RepeatedField<QuestionCountingPart> {
+        ::std::mem::replace(&mut self.question_part, ::protobuf::RepeatedField::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.CountingPart rest_part = 5;
+
+
+    pub fn get_rest_part(&self) -> &CountingPart {
+        self.rest_part.as_ref().unwrap_or_else(|| <CountingPart as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_rest_part(&mut self) {
+        self.rest_part.clear();
+    }
+
+    pub fn has_rest_part(&self) -> bool {
+        self.rest_part.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rest_part(&mut self, v: CountingPart) {
+        self.rest_part = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rest_part(&mut self) -> &mut CountingPart {
+        if self.rest_part.is_none() {
+            self.rest_part.set_default();
+        }
+        self.rest_part.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_rest_part(&mut self) -> CountingPart {
+        self.rest_part.take().unwrap_or_else(|| CountingPart::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.StringToCountingPartPair against_part = 6;
+
+
+    pub fn get_against_part(&self) -> &[StringToCountingPartPair] {
+        &self.against_part
+    }
+    pub fn clear_against_part(&mut self) {
+        self.against_part.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_against_part(&mut self, v: ::protobuf::RepeatedField<StringToCountingPartPair>) {
+        self.against_part = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_against_part(&mut self) -> &mut ::protobuf::RepeatedField<StringToCountingPartPair> {
+        &mut self.against_part
+    }
+
+    // Take field
+    pub fn take_against_part(&mut self) -> ::protobuf::RepeatedField<StringToCountingPartPair> {
+        ::std::mem::replace(&mut self.against_part, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated string aggregated_counter_id = 7;
+
+
+    pub fn get_aggregated_counter_id(&self) -> &[::std::string::String] {
+        &self.aggregated_counter_id
+    }
+    pub fn clear_aggregated_counter_id(&mut self) {
+        self.aggregated_counter_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_aggregated_counter_id(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.aggregated_counter_id = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_aggregated_counter_id(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.aggregated_counter_id
+    }
+
+    // Take field
+    pub fn take_aggregated_counter_id(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.aggregated_counter_id, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for DecryptedResultPartStorage {
+    fn is_initialized(&self) -> bool {
+        for v in &self.blank_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.candidate_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.unlisted_candidate_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.question_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.rest_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.against_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.blank_part)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.candidate_part)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.unlisted_candidate_part)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.question_part)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.rest_part)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.against_part)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.aggregated_counter_id)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.blank_part.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.candidate_part {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.unlisted_candidate_part {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.question_part {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.rest_part.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.against_part {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.aggregated_counter_id {
+            my_size += ::protobuf::rt::string_size(7, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.blank_part.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.candidate_part {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.unlisted_candidate_part {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.question_part {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.rest_part.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.against_part {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.aggregated_counter_id {
+            os.write_string(7, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> DecryptedResultPartStorage {
+        DecryptedResultPartStorage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CountingPart>>(
+                "blank_part",
+                |m: &DecryptedResultPartStorage| { &m.blank_part },
+                |m: &mut DecryptedResultPartStorage| { &mut m.blank_part },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<StringToCountingPartPair>>(
+                "candidate_part",
+                |m: &DecryptedResultPartStorage| { &m.candidate_part },
+                |m: &mut DecryptedResultPartStorage| { &mut m.candidate_part },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UnlistedBallotDecryptedResult>>(
+                "unlisted_candidate_part",
+                |m: &DecryptedResultPartStorage| { &m.unlisted_candidate_part },
+                |m: &mut DecryptedResultPartStorage| { &mut m.unlisted_candidate_part },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<QuestionCountingPart>>(
+                "question_part",
+                |m: &DecryptedResultPartStorage| { &m.question_part },
+                |m: &mut DecryptedResultPartStorage| { &mut m.question_part },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CountingPart>>(
+                "rest_part",
+                |m: &DecryptedResultPartStorage| { &m.rest_part },
+                |m: &mut DecryptedResultPartStorage| { &mut m.rest_part },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<StringToCountingPartPair>>(
+                "against_part",
+                |m: &DecryptedResultPartStorage| { &m.against_part },
+                |m: &mut DecryptedResultPartStorage| { &mut m.against_part },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "aggregated_counter_id",
+                |m: &DecryptedResultPartStorage| { &m.aggregated_counter_id },
+                |m: &mut DecryptedResultPartStorage| { &mut m.aggregated_counter_id },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DecryptedResultPartStorage>(
+                "DecryptedResultPartStorage",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static DecryptedResultPartStorage {
+        static instance: ::protobuf::rt::LazyV2<DecryptedResultPartStorage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(DecryptedResultPartStorage::new)
+    }
+}
+
+impl ::protobuf::Clear for DecryptedResultPartStorage {
+    fn clear(&mut self) {
+        self.blank_part.clear();
+        self.candidate_part.clear();
+        self.unlisted_candidate_part.clear();
+        self.question_part.clear();
+        self.rest_part.clear();
+        self.against_part.clear();
+        self.aggregated_counter_id.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for DecryptedResultPartStorage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for DecryptedResultPartStorage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CounterBlameReport {
+    // message fields
+    pub counter_id: ::std::string::String,
+    pub question_id: ::std::string::String,
+    pub candidate: ::std::string::String,
+    pub reason: CounterBlameReason,
+    pub against_part: bool,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CounterBlameReport {
+    fn default() -> &'a CounterBlameReport {
+        <CounterBlameReport as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CounterBlameReport {
+    pub fn new() -> CounterBlameReport {
+        ::std::default::Default::default()
+    }
+
+    // string counter_id = 1;
+
+
+    pub fn get_counter_id(&self) -> &str {
+        &self.counter_id
+    }
+    pub fn clear_counter_id(&mut self) {
+        self.counter_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_counter_id(&mut self, v: ::std::string::String) {
+        self.counter_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_counter_id(&mut self) -> &mut ::std::string::String {
+        &mut self.counter_id
+    }
+
+    // Take field
+    pub fn take_counter_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.counter_id, ::std::string::String::new())
+    }
+
+    // string question_id = 2;
+
+
+    pub fn get_question_id(&self) -> &str {
+        &self.question_id
+    }
+    pub fn clear_question_id(&mut self) {
+        self.question_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_question_id(&mut self, v: ::std::string::String) {
+        self.question_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_question_id(&mut self) -> &mut ::std::string::String {
+        &mut self.question_id
+    }
+
+    // Take field
+    pub fn take_question_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.question_id, ::std::string::String::new())
+    }
+
+    // string candidate = 3;
+
+
+    pub fn get_candidate(&self) -> &str {
+        &self.candidate
+    }
+    pub fn clear_candidate(&mut self) {
+        self.candidate.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_candidate(&mut self, v: ::std::string::String) {
+        self.candidate = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_candidate(&mut self) -> &mut ::std::string::String {
+        &mut self.candidate
+    }
+
+    // Take field
+    pub fn take_candidate(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.candidate, ::std::string::String::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.CounterBlameReason reason = 4;
+
+
+    pub fn get_reason(&self) -> CounterBlameReason {
+        self.reason
+    }
+    pub fn clear_reason(&mut self) {
+        self.reason = CounterBlameReason::INVALID_EQUALITY_PROOF;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_reason(&mut self, v: CounterBlameReason) {
+        self.reason = v;
+    }
+
+    // bool against_part = 5;
+
+
+    pub fn get_against_part(&self) -> bool {
+        self.against_part
+    }
+    pub fn clear_against_part(&mut self) {
+        self.against_part = false;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_against_part(&mut self, v: bool) {
+        self.against_part = v;
+    }
+}
+
+impl ::protobuf::Message for CounterBlameReport {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.counter_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.question_id)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.candidate)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.reason, 4, &mut self.unknown_fields)?
+                },
+                5 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_bool()?;
+                    self.against_part = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.counter_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.counter_id);
+        }
+        if !self.question_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.question_id);
+        }
+        if !self.candidate.is_empty() {
+            my_size += ::protobuf::rt::string_size(3, &self.candidate);
+        }
+        if self.reason != CounterBlameReason::INVALID_EQUALITY_PROOF {
+            my_size += ::protobuf::rt::enum_size(4, self.reason);
+        }
+        if self.against_part != false {
+            my_size += 2;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.counter_id.is_empty() {
+            os.write_string(1, &self.counter_id)?;
+        }
+        if !self.question_id.is_empty() {
+            os.write_string(2, &self.question_id)?;
+        }
+        if !self.candidate.is_empty() {
+            os.write_string(3, &self.candidate)?;
+        }
+        if self.reason != CounterBlameReason::INVALID_EQUALITY_PROOF {
+            os.write_enum(4, ::protobuf::ProtobufEnum::value(&self.reason))?;
+        }
+        if self.against_part != false {
+            os.write_bool(5, self.against_part)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CounterBlameReport {
+        CounterBlameReport::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "counter_id",
+                |m: &CounterBlameReport| { &m.counter_id },
+                |m: &mut CounterBlameReport| { &mut m.counter_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "question_id",
+                |m: &CounterBlameReport| { &m.question_id },
+                |m: &mut CounterBlameReport| { &mut m.question_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "candidate",
+                |m: &CounterBlameReport| { &m.candidate },
+                |m: &mut CounterBlameReport| { &mut m.candidate },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<CounterBlameReason>>(
+                "reason",
+                |m: &CounterBlameReport| { &m.reason },
+                |m: &mut CounterBlameReport| { &mut m.reason },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBool>(
+                "against_part",
+                |m: &CounterBlameReport| { &m.against_part },
+                |m: &mut CounterBlameReport| { &mut m.against_part },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CounterBlameReport>(
+                "CounterBlameReport",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CounterBlameReport {
+        static instance: ::protobuf::rt::LazyV2<CounterBlameReport> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CounterBlameReport::new)
+    }
+}
+
+impl ::protobuf::Clear for CounterBlameReport {
+    fn clear(&mut self) {
+        self.counter_id.clear();
+        self.question_id.clear();
+        self.candidate.clear();
+        self.reason = CounterBlameReason::INVALID_EQUALITY_PROOF;
+        self.against_part = false;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CounterBlameReport {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CounterBlameReport {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QuestionCountingPart {
+    // message fields
+    pub question_id: ::std::string::String,
+    pub candidate_part: ::protobuf::RepeatedField<StringToCountingPartPair>,
+    pub rest_part: ::protobuf::SingularPtrField<CountingPart>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QuestionCountingPart {
+    fn default() -> &'a QuestionCountingPart {
+        <QuestionCountingPart as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QuestionCountingPart {
+    pub fn new() -> QuestionCountingPart {
+        ::std::default::Default::default()
+    }
+
+    // string question_id = 1;
+
+
+    pub fn get_question_id(&self) -> &str {
+        &self.question_id
+    }
+    pub fn clear_question_id(&mut self) {
+        self.question_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_question_id(&mut self, v: ::std::string::String) {
+        self.question_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_question_id(&mut self) -> &mut ::std::string::String {
+        &mut self.question_id
+    }
+
+    // Take field
+    pub fn take_question_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.question_id, ::std::string::String::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.StringToCountingPartPair candidate_part = 2;
+
+
+    pub fn get_candidate_part(&self) -> &[StringToCountingPartPair] {
+        &self.candidate_part
+    }
+    pub fn clear_candidate_part(&mut self) {
+        self.candidate_part.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_candidate_part(&mut self, v: ::protobuf::RepeatedField<StringToCountingPartPair>) {
+        self.candidate_part = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_candidate_part(&mut self) -> &mut ::protobuf::RepeatedField<StringToCountingPartPair> {
+        &mut self.candidate_part
+    }
+
+    // Take field
+    pub fn take_candidate_part(&mut self) -> ::protobuf::RepeatedField<StringToCountingPartPair> {
+        ::std::mem::replace(&mut self.candidate_part, ::protobuf::RepeatedField::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.CountingPart rest_part = 3;
+
+
+    pub fn get_rest_part(&self) -> &CountingPart {
+        self.rest_part.as_ref().unwrap_or_else(|| <CountingPart as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_rest_part(&mut self) {
+        self.rest_part.clear();
+    }
+
+    pub fn has_rest_part(&self) -> bool {
+        self.rest_part.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rest_part(&mut self, v: CountingPart) {
+        self.rest_part = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rest_part(&mut self) -> &mut CountingPart {
+        if self.rest_part.is_none() {
+            self.rest_part.set_default();
+        }
+        self.rest_part.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_rest_part(&mut self) -> CountingPart {
+        self.rest_part.take().unwrap_or_else(|| CountingPart::new())
+    }
+}
+
+impl ::protobuf::Message for QuestionCountingPart {
+    fn is_initialized(&self) -> bool {
+        for v in &self.candidate_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.rest_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.question_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.candidate_part)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.rest_part)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.question_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.question_id);
+        }
+        for value in &self.candidate_part {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.rest_part.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.question_id.is_empty() {
+            os.write_string(1, &self.question_id)?;
+        }
+        for v in &self.candidate_part {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.rest_part.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QuestionCountingPart {
+        QuestionCountingPart::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "question_id",
+                |m: &QuestionCountingPart| { &m.question_id },
+                |m: &mut QuestionCountingPart| { &mut m.question_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<StringToCountingPartPair>>(
+                "candidate_part",
+                |m: &QuestionCountingPart| { &m.candidate_part },
+                |m: &mut QuestionCountingPart| { &mut m.candidate_part },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CountingPart>>(
+                "rest_part",
+                |m: &QuestionCountingPart| { &m.rest_part },
+                |m: &mut QuestionCountingPart| { &mut m.rest_part },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QuestionCountingPart>(
+                "QuestionCountingPart",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QuestionCountingPart {
+        static instance: ::protobuf::rt::LazyV2<QuestionCountingPart> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QuestionCountingPart::new)
+    }
+}
+
+impl ::protobuf::Clear for QuestionCountingPart {
+    fn clear(&mut self) {
+        self.question_id.clear();
+        self.candidate_part.clear();
+        self.rest_part.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QuestionCountingPart {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QuestionCountingPart {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UnlistedBallotDecryptedResult {
+    // message fields
+    pub candidate: i64,
+    pub candidate_cipher: ::protobuf::SingularPtrField<CipherPoints>,
+    pub decrypted_unlisted_candidate: ::protobuf::SingularPtrField<CountingPart>,
+    pub decrypted_unlisted_candidate_ballot: ::protobuf::RepeatedField<CountingPart>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UnlistedBallotDecryptedResult {
+    fn default() -> &'a UnlistedBallotDecryptedResult {
+        <UnlistedBallotDecryptedResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UnlistedBallotDecryptedResult {
+    pub fn new() -> UnlistedBallotDecryptedResult {
+        ::std::default::Default::default()
+    }
+
+    // int64 candidate = 1;
+
+
+    pub fn get_candidate(&self) -> i64 {
+        self.candidate
+    }
+    pub fn clear_candidate(&mut self) {
+        self.candidate = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_candidate(&mut self, v: i64) {
+        self.candidate = v;
+    }
+
+    // .com.webank.wedpr.acv.proto.CipherPoints candidate_cipher = 2;
+
+
+    pub fn get_candidate_cipher(&self) -> &CipherPoints {
+        self.candidate_cipher.as_ref().unwrap_or_else(|| <CipherPoints as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_candidate_cipher(&mut self) {
+        self.candidate_cipher.clear();
+    }
+
+    pub fn has_candidate_cipher(&self) -> bool {
+        self.candidate_cipher.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_candidate_cipher(&mut self, v: CipherPoints) {
+        self.candidate_cipher = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_candidate_cipher(&mut self) -> &mut CipherPoints {
+        if self.candidate_cipher.is_none() {
+            self.candidate_cipher.set_default();
+        }
+        self.candidate_cipher.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_candidate_cipher(&mut self) -> CipherPoints {
+        self.candidate_cipher.take().unwrap_or_else(|| CipherPoints::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.CountingPart decrypted_unlisted_candidate = 4;
+
+
+    pub fn get_decrypted_unlisted_candidate(&self) -> &CountingPart {
+        self.decrypted_unlisted_candidate.as_ref().unwrap_or_else(|| <CountingPart as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_decrypted_unlisted_candidate(&mut self) {
+        self.decrypted_unlisted_candidate.clear();
+    }
+
+    pub fn has_decrypted_unlisted_candidate(&self) -> bool {
+        self.decrypted_unlisted_candidate.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_decrypted_unlisted_candidate(&mut self, v: CountingPart) {
+        self.decrypted_unlisted_candidate = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_decrypted_unlisted_candidate(&mut self) -> &mut CountingPart {
+        if self.decrypted_unlisted_candidate.is_none() {
+            self.decrypted_unlisted_candidate.set_default();
+        }
+        self.decrypted_unlisted_candidate.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_decrypted_unlisted_candidate(&mut self) -> CountingPart {
+        self.decrypted_unlisted_candidate.take().unwrap_or_else(|| CountingPart::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.CountingPart decrypted_unlisted_candidate_ballot = 5;
+
+
+    pub fn get_decrypted_unlisted_candidate_ballot(&self) -> &[CountingPart] {
+        &self.decrypted_unlisted_candidate_ballot
+    }
+    pub fn clear_decrypted_unlisted_candidate_ballot(&mut self) {
+        self.decrypted_unlisted_candidate_ballot.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_decrypted_unlisted_candidate_ballot(&mut self, v: ::protobuf::RepeatedField<CountingPart>) {
+        self.decrypted_unlisted_candidate_ballot = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_decrypted_unlisted_candidate_ballot(&mut self) -> &mut ::protobuf::RepeatedField<CountingPart> {
+        &mut self.decrypted_unlisted_candidate_ballot
+    }
+
+    // Take field
+    pub fn take_decrypted_unlisted_candidate_ballot(&mut self) -> ::protobuf::RepeatedField<CountingPart> {
+        ::std::mem::replace(&mut self.decrypted_unlisted_candidate_ballot, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for UnlistedBallotDecryptedResult {
+    fn is_initialized(&self) -> bool {
+        for v in &self.candidate_cipher {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.decrypted_unlisted_candidate {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.decrypted_unlisted_candidate_ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.candidate = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.candidate_cipher)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.decrypted_unlisted_candidate)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.decrypted_unlisted_candidate_ballot)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.candidate != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.candidate, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if let Some(ref v) = self.candidate_cipher.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.decrypted_unlisted_candidate.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.decrypted_unlisted_candidate_ballot {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.candidate != 0 {
+            os.write_int64(1, self.candidate)?;
+        }
+        if let Some(ref v) = self.candidate_cipher.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.decrypted_unlisted_candidate.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.decrypted_unlisted_candidate_ballot {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UnlistedBallotDecryptedResult {
+        UnlistedBallotDecryptedResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "candidate",
+                |m: &UnlistedBallotDecryptedResult| { &m.candidate },
+                |m: &mut UnlistedBallotDecryptedResult| { &mut m.candidate },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CipherPoints>>(
+                "candidate_cipher",
+                |m: &UnlistedBallotDecryptedResult| { &m.candidate_cipher },
+                |m: &mut UnlistedBallotDecryptedResult| { &mut m.candidate_cipher },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CountingPart>>(
+                "decrypted_unlisted_candidate",
+                |m: &UnlistedBallotDecryptedResult| { &m.decrypted_unlisted_candidate },
+                |m: &mut UnlistedBallotDecryptedResult| { &mut m.decrypted_unlisted_candidate },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CountingPart>>(
+                "decrypted_unlisted_candidate_ballot",
+                |m: &UnlistedBallotDecryptedResult| { &m.decrypted_unlisted_candidate_ballot },
+                |m: &mut UnlistedBallotDecryptedResult| { &mut m.decrypted_unlisted_candidate_ballot },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UnlistedBallotDecryptedResult>(
+                "UnlistedBallotDecryptedResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UnlistedBallotDecryptedResult {
+        static instance: ::protobuf::rt::LazyV2<UnlistedBallotDecryptedResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UnlistedBallotDecryptedResult::new)
+    }
+}
+
+impl ::protobuf::Clear for UnlistedBallotDecryptedResult {
+    fn clear(&mut self) {
+        self.candidate = 0;
+        self.candidate_cipher.clear();
+        self.decrypted_unlisted_candidate.clear();
+        self.decrypted_unlisted_candidate_ballot.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UnlistedBallotDecryptedResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UnlistedBallotDecryptedResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct VoteResultStorage {
+    // message fields
+    pub result: ::protobuf::RepeatedField<StringToInt64Pair>,
+    pub unlisted_result: ::protobuf::RepeatedField<UnlistedVoteChoice>,
+    pub question_result: ::protobuf::RepeatedField<QuestionResult>,
+    pub against_result: ::protobuf::RepeatedField<StringToInt64Pair>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a VoteResultStorage {
+    fn default() -> &'a VoteResultStorage {
+        <VoteResultStorage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl VoteResultStorage {
+    pub fn new() -> VoteResultStorage {
+        ::std::default::Default::default()
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.StringToInt64Pair result = 1;
+
+
+    pub fn get_result(&self) -> &[StringToInt64Pair] {
+        &self.result
+    }
+    pub fn clear_result(&mut self) {
+        self.result.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_result(&mut self, v: ::protobuf::RepeatedField<StringToInt64Pair>) {
+        self.result = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_result(&mut self) -> &mut ::protobuf::RepeatedField<StringToInt64Pair> {
+        &mut self.result
+    }
+
+    // Take field
+    pub fn take_result(&mut self) -> ::protobuf::RepeatedField<StringToInt64Pair> {
+        ::std::mem::replace(&mut self.result, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.UnlistedVoteChoice unlisted_result = 2;
+
+
+    pub fn get_unlisted_result(&self) -> &[UnlistedVoteChoice] {
+        &self.unlisted_result
+    }
+    pub fn clear_unlisted_result(&mut self) {
+        self.unlisted_result.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_unlisted_result(&mut self, v: ::protobuf::RepeatedField<UnlistedVoteChoice>) {
+        self.unlisted_result = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_unlisted_result(&mut self) -> &mut ::protobuf::RepeatedField<UnlistedVoteChoice> {
+        &mut self.unlisted_result
+    }
+
+    // Take field
+    pub fn take_unlisted_result(&mut self) -> ::protobuf::RepeatedField<UnlistedVoteChoice> {
+        ::std::mem::replace(&mut self.unlisted_result, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.QuestionResult question_result = 3;
+
+
+    pub fn get_question_result(&self) -> &[QuestionResult] {
+        &self.question_result
+    }
+    pub fn clear_question_result(&mut self) {
+        self.question_result.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_question_result(&mut self, v: ::protobuf::RepeatedField<QuestionResult>) {
+        self.question_result = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_question_result(&mut self) -> &mut ::protobuf::RepeatedField<QuestionResult> {
+        &mut self.question_result
+    }
+
+    // Take field
+    pub fn take_question_result(&mut self) -> ::protobuf::RepeatedField<QuestionResult> {
+        ::std::mem::replace(&mut self.question_result, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.StringToInt64Pair against_result = 4;
+
+
+    pub fn get_against_result(&self) -> &[StringToInt64Pair] {
+        &self.against_result
+    }
+    pub fn clear_against_result(&mut self) {
+        self.against_result.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_against_result(&mut self, v: ::protobuf::RepeatedField<StringToInt64Pair>) {
+        self.against_result = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_against_result(&mut self) -> &mut ::protobuf::RepeatedField<StringToInt64Pair> {
+        &mut self.against_result
+    }
+
+    // Take field
+    pub fn take_against_result(&mut self) -> ::protobuf::RepeatedField<StringToInt64Pair> {
+        ::std::mem::replace(&mut self.against_result, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for VoteResultStorage {
+    fn is_initialized(&self) -> bool {
+        for v in &self.result {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.unlisted_result {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.question_result {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.against_result {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.result)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.unlisted_result)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.question_result)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.against_result)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.result {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.unlisted_result {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.question_result {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.against_result {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.result {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.unlisted_result {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.question_result {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.against_result {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> VoteResultStorage {
+        VoteResultStorage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<StringToInt64Pair>>(
+                "result",
+                |m: &VoteResultStorage| { &m.result },
+                |m: &mut VoteResultStorage| { &mut m.result },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UnlistedVoteChoice>>(
+                "unlisted_result",
+                |m: &VoteResultStorage| { &m.unlisted_result },
+                |m: &mut VoteResultStorage| { &mut m.unlisted_result },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<QuestionResult>>(
+                "question_result",
+                |m: &VoteResultStorage| { &m.question_result },
+                |m: &mut VoteResultStorage| { &mut m.question_result },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<StringToInt64Pair>>(
+                "against_result",
+                |m: &VoteResultStorage| { &m.against_result },
+                |m: &mut VoteResultStorage| { &mut m.against_result },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<VoteResultStorage>(
+                "VoteResultStorage",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static VoteResultStorage {
+        static instance: ::protobuf::rt::LazyV2<VoteResultStorage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(VoteResultStorage::new)
+    }
+}
+
+impl ::protobuf::Clear for VoteResultStorage {
+    fn clear(&mut self) {
+        self.result.clear();
+        self.unlisted_result.clear();
+        self.question_result.clear();
+        self.against_result.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for VoteResultStorage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VoteResultStorage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct QuestionResult {
+    // message fields
+    pub question_id: ::std::string::String,
+    pub result: ::protobuf::RepeatedField<StringToInt64Pair>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a QuestionResult {
+    fn default() -> &'a QuestionResult {
+        <QuestionResult as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl QuestionResult {
+    pub fn new() -> QuestionResult {
+        ::std::default::Default::default()
+    }
+
+    // string question_id = 1;
+
+
+    pub fn get_question_id(&self) -> &str {
+        &self.question_id
+    }
+    pub fn clear_question_id(&mut self) {
+        self.question_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_question_id(&mut self, v: ::std::string::String) {
+        self.question_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_question_id(&mut self) -> &mut ::std::string::String {
+        &mut self.question_id
+    }
+
+    // Take field
+    pub fn take_question_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.question_id, ::std::string::String::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.StringToInt64Pair result = 2;
+
+
+    pub fn get_result(&self) -> &[StringToInt64Pair] {
+        &self.result
+    }
+    pub fn clear_result(&mut self) {
+        self.result.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_result(&mut self, v: ::protobuf::RepeatedField<StringToInt64Pair>) {
+        self.result = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_result(&mut self) -> &mut ::protobuf::RepeatedField<StringToInt64Pair> {
+        &mut self.result
+    }
+
+    // Take field
+    pub fn take_result(&mut self) -> ::protobuf::RepeatedField<StringToInt64Pair> {
+        ::std::mem::replace(&mut self.result, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for QuestionResult {
+    fn is_initialized(&self) -> bool {
+        for v in &self.result {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.question_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.result)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.question_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.question_id);
+        }
+        for value in &self.result {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.question_id.is_empty() {
+            os.write_string(1, &self.question_id)?;
+        }
+        for v in &self.result {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> QuestionResult {
+        QuestionResult::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "question_id",
+                |m: &QuestionResult| { &m.question_id },
+                |m: &mut QuestionResult| { &mut m.question_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<StringToInt64Pair>>(
+                "result",
+                |m: &QuestionResult| { &m.result },
+                |m: &mut QuestionResult| { &mut m.result },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<QuestionResult>(
+                "QuestionResult",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static QuestionResult {
+        static instance: ::protobuf::rt::LazyV2<QuestionResult> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(QuestionResult::new)
+    }
+}
+
+impl ::protobuf::Clear for QuestionResult {
+    fn clear(&mut self) {
+        self.question_id.clear();
+        self.result.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for QuestionResult {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for QuestionResult {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct StringToInt64Pair {
+    // message fields
+    pub key: ::std::string::String,
+    pub value: i64,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a StringToInt64Pair {
+    fn default() -> &'a StringToInt64Pair {
+        <StringToInt64Pair as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl StringToInt64Pair {
+    pub fn new() -> StringToInt64Pair {
+        ::std::default::Default::default()
+    }
+
+    // string key = 1;
+
+
+    pub fn get_key(&self) -> &str {
+        &self.key
+    }
+    pub fn clear_key(&mut self) {
+        self.key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_key(&mut self, v: ::std::string::String) {
+        self.key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_key(&mut self) -> &mut ::std::string::String {
+        &mut self.key
+    }
+
+    // Take field
+    pub fn take_key(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+    }
+
+    // int64 value = 2;
+
+
+    pub fn get_value(&self) -> i64 {
+        self.value
+    }
+    pub fn clear_value(&mut self) {
+        self.value = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_value(&mut self, v: i64) {
+        self.value = v;
+    }
+}
+
+impl ::protobuf::Message for StringToInt64Pair {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_int64()?;
+                    self.value = tmp;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.key.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.key);
+        }
+        if self.value != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.value, ::protobuf::wire_format::WireTypeVarint);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.key.is_empty() {
+            os.write_string(1, &self.key)?;
+        }
+        if self.value != 0 {
+            os.write_int64(2, self.value)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> StringToInt64Pair {
+        StringToInt64Pair::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "key",
+                |m: &StringToInt64Pair| { &m.key },
+                |m: &mut StringToInt64Pair| { &mut m.key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
+                "value",
+                |m: &StringToInt64Pair| { &m.value },
+                |m: &mut StringToInt64Pair| { &mut m.value },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<StringToInt64Pair>(
+                "StringToInt64Pair",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static StringToInt64Pair {
+        static instance: ::protobuf::rt::LazyV2<StringToInt64Pair> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(StringToInt64Pair::new)
+    }
+}
+
+impl ::protobuf::Clear for StringToInt64Pair {
+    fn clear(&mut self) {
+        self.key.clear();
+        self.value = 0;
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for StringToInt64Pair {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for StringToInt64Pair {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PollState {
+    // message fields
+    pub phase: PollPhase,
+    pub candidates: ::protobuf::SingularPtrField<CandidateList>,
+    pub counter_parameters: ::protobuf::SingularPtrField<CounterParametersStorage>,
+    pub poll_parameters: ::protobuf::SingularPtrField<PollParametersStorage>,
+    pub registration: ::protobuf::RepeatedField<RegistrationResponse>,
+    pub vote_sum: ::protobuf::SingularPtrField<VoteStorage>,
+    pub decrypted_part: ::protobuf::RepeatedField<DecryptedResultPartStorage>,
+    pub vote_result: ::protobuf::SingularPtrField<VoteResultStorage>,
+    pub registration_deadline: u64,
+    pub voting_deadline: u64,
+    pub signature_scheme: SignatureScheme,
+    pub accepted_vote: ::protobuf::RepeatedField<VoteStorage>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PollState {
+    fn default() -> &'a PollState {
+        <PollState as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PollState {
+    pub fn new() -> PollState {
+        ::std::default::Default::default()
+    }
+
+    // .com.webank.wedpr.acv.proto.PollPhase phase = 1;
+
+
+    pub fn get_phase(&self) -> PollPhase {
+        self.phase
+    }
+    pub fn clear_phase(&mut self) {
+        self.phase = PollPhase::SETUP;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_phase(&mut self, v: PollPhase) {
+        self.phase = v;
+    }
+
+    // .com.webank.wedpr.acv.proto.CandidateList candidates = 2;
+
+
+    pub fn get_candidates(&self) -> &CandidateList {
+        self.candidates.as_ref().unwrap_or_else(|| <CandidateList as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_candidates(&mut self) {
+        self.candidates.clear();
+    }
+
+    pub fn has_candidates(&self) -> bool {
+        self.candidates.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_candidates(&mut self, v: CandidateList) {
+        self.candidates = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_candidates(&mut self) -> &mut CandidateList {
+        if self.candidates.is_none() {
+            self.candidates.set_default();
+        }
+        self.candidates.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_candidates(&mut self) -> CandidateList {
+        self.candidates.take().unwrap_or_else(|| CandidateList::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.CounterParametersStorage counter_parameters = 3;
+
+
+    pub fn get_counter_parameters(&self) -> &CounterParametersStorage {
+        self.counter_parameters.as_ref().unwrap_or_else(|| <CounterParametersStorage as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_counter_parameters(&mut self) {
+        self.counter_parameters.clear();
+    }
+
+    pub fn has_counter_parameters(&self) -> bool {
+        self.counter_parameters.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_counter_parameters(&mut self, v: CounterParametersStorage) {
+        self.counter_parameters = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_counter_parameters(&mut self) -> &mut CounterParametersStorage {
+        if self.counter_parameters.is_none() {
+            self.counter_parameters.set_default();
+        }
+        self.counter_parameters.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_counter_parameters(&mut self) -> CounterParametersStorage {
+        self.counter_parameters.take().unwrap_or_else(|| CounterParametersStorage::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.PollParametersStorage poll_parameters = 4;
+
+
+    pub fn get_poll_parameters(&self) -> &PollParametersStorage {
+        self.poll_parameters.as_ref().unwrap_or_else(|| <PollParametersStorage as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_poll_parameters(&mut self) {
+        self.poll_parameters.clear();
+    }
+
+    pub fn has_poll_parameters(&self) -> bool {
+        self.poll_parameters.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_poll_parameters(&mut self, v: PollParametersStorage) {
+        self.poll_parameters = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_poll_parameters(&mut self) -> &mut PollParametersStorage {
+        if self.poll_parameters.is_none() {
+            self.poll_parameters.set_default();
+        }
+        self.poll_parameters.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_poll_parameters(&mut self) -> PollParametersStorage {
+        self.poll_parameters.take().unwrap_or_else(|| PollParametersStorage::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.RegistrationResponse registration = 5;
+
+
+    pub fn get_registration(&self) -> &[RegistrationResponse] {
+        &self.registration
+    }
+    pub fn clear_registration(&mut self) {
+        self.registration.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_registration(&mut self, v: ::protobuf::RepeatedField<RegistrationResponse>) {
+        self.registration = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_registration(&mut self) -> &mut ::protobuf::RepeatedField<RegistrationResponse> {
+        &mut self.registration
+    }
+
+    // Take field
+    pub fn take_registration(&mut self) -> ::protobuf::RepeatedField<RegistrationResponse> {
+        ::std::mem::replace(&mut self.registration, ::protobuf::RepeatedField::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.VoteStorage vote_sum = 6;
+
+
+    pub fn get_vote_sum(&self) -> &VoteStorage {
+        self.vote_sum.as_ref().unwrap_or_else(|| <VoteStorage as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_vote_sum(&mut self) {
+        self.vote_sum.clear();
+    }
+
+    pub fn has_vote_sum(&self) -> bool {
+        self.vote_sum.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vote_sum(&mut self, v: VoteStorage) {
+        self.vote_sum = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vote_sum(&mut self) -> &mut VoteStorage {
+        if self.vote_sum.is_none() {
+            self.vote_sum.set_default();
+        }
+        self.vote_sum.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_vote_sum(&mut self) -> VoteStorage {
+        self.vote_sum.take().unwrap_or_else(|| VoteStorage::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.DecryptedResultPartStorage decrypted_part = 7;
+
+
+    pub fn get_decrypted_part(&self) -> &[DecryptedResultPartStorage] {
+        &self.decrypted_part
+    }
+    pub fn clear_decrypted_part(&mut self) {
+        self.decrypted_part.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_decrypted_part(&mut self, v: ::protobuf::RepeatedField<DecryptedResultPartStorage>) {
+        self.decrypted_part = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_decrypted_part(&mut self) -> &mut ::protobuf::RepeatedField<DecryptedResultPartStorage> {
+        &mut self.decrypted_part
+    }
+
+    // Take field
+    pub fn take_decrypted_part(&mut self) -> ::protobuf::RepeatedField<DecryptedResultPartStorage> {
+        ::std::mem::replace(&mut self.decrypted_part, ::protobuf::RepeatedField::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.VoteResultStorage vote_result = 8;
+
+
+    pub fn get_vote_result(&self) -> &VoteResultStorage {
+        self.vote_result.as_ref().unwrap_or_else(|| <VoteResultStorage as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_vote_result(&mut self) {
+        self.vote_result.clear();
+    }
+
+    pub fn has_vote_result(&self) -> bool {
+        self.vote_result.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vote_result(&mut self, v: VoteResultStorage) {
+        self.vote_result = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vote_result(&mut self) -> &mut VoteResultStorage {
+        if self.vote_result.is_none() {
+            self.vote_result.set_default();
+        }
+        self.vote_result.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_vote_result(&mut self) -> VoteResultStorage {
+        self.vote_result.take().unwrap_or_else(|| VoteResultStorage::new())
+    }
+
+    // uint64 registration_deadline = 9;
+
+
+    pub fn get_registration_deadline(&self) -> u64 {
+        self.registration_deadline
+    }
+    pub fn clear_registration_deadline(&mut self) {
+        self.registration_deadline = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_registration_deadline(&mut self, v: u64) {
+        self.registration_deadline = v;
+    }
+
+    // uint64 voting_deadline = 10;
+
+
+    pub fn get_voting_deadline(&self) -> u64 {
+        self.voting_deadline
+    }
+    pub fn clear_voting_deadline(&mut self) {
+        self.voting_deadline = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_voting_deadline(&mut self, v: u64) {
+        self.voting_deadline = v;
+    }
+
+    // .com.webank.wedpr.acv.proto.SignatureScheme signature_scheme = 11;
+
+
+    pub fn get_signature_scheme(&self) -> SignatureScheme {
+        self.signature_scheme
+    }
+    pub fn clear_signature_scheme(&mut self) {
+        self.signature_scheme = SignatureScheme::DEFAULT_SCHEME;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_signature_scheme(&mut self, v: SignatureScheme) {
+        self.signature_scheme = v;
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.VoteStorage accepted_vote = 12;
+
+
+    pub fn get_accepted_vote(&self) -> &[VoteStorage] {
+        &self.accepted_vote
+    }
+    pub fn clear_accepted_vote(&mut self) {
+        self.accepted_vote.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_accepted_vote(&mut self, v: ::protobuf::RepeatedField<VoteStorage>) {
+        self.accepted_vote = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_accepted_vote(&mut self) -> &mut ::protobuf::RepeatedField<VoteStorage> {
+        &mut self.accepted_vote
+    }
+
+    // Take field
+    pub fn take_accepted_vote(&mut self) -> ::protobuf::RepeatedField<VoteStorage> {
+        ::std::mem::replace(&mut self.accepted_vote, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for PollState {
+    fn is_initialized(&self) -> bool {
+        for v in &self.candidates {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.counter_parameters {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.poll_parameters {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.registration {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.vote_sum {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.decrypted_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.vote_result {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.accepted_vote {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.phase, 1, &mut self.unknown_fields)?
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.candidates)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.counter_parameters)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.poll_parameters)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.registration)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.vote_sum)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.decrypted_part)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.vote_result)?;
+                },
+                9 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.registration_deadline = tmp;
+                },
+                10 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.voting_deadline = tmp;
+                },
+                11 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.signature_scheme, 11, &mut self.unknown_fields)?
+                },
+                12 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.accepted_vote)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.phase != PollPhase::SETUP {
+            my_size += ::protobuf::rt::enum_size(1, self.phase);
+        }
+        if let Some(ref v) = self.candidates.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.counter_parameters.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.poll_parameters.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.registration {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.vote_sum.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.decrypted_part {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.vote_result.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.registration_deadline != 0 {
+            my_size += ::protobuf::rt::value_size(9, self.registration_deadline, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.voting_deadline != 0 {
+            my_size += ::protobuf::rt::value_size(10, self.voting_deadline, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.signature_scheme != SignatureScheme::DEFAULT_SCHEME {
+            my_size += ::protobuf::rt::enum_size(11, self.signature_scheme);
+        }
+        for value in &self.accepted_vote {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.phase != PollPhase::SETUP {
+            os.write_enum(1, ::protobuf::ProtobufEnum::value(&self.phase))?;
+        }
+        if let Some(ref v) = self.candidates.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.counter_parameters.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.poll_parameters.as_ref() {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.registration {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.vote_sum.as_ref() {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.decrypted_part {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.vote_result.as_ref() {
+            os.write_tag(8, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.registration_deadline != 0 {
+            os.write_uint64(9, self.registration_deadline)?;
+        }
+        if self.voting_deadline != 0 {
+            os.write_uint64(10, self.voting_deadline)?;
+        }
+        if self.signature_scheme != SignatureScheme::DEFAULT_SCHEME {
+            os.write_enum(11, ::protobuf::ProtobufEnum::value(&self.signature_scheme))?;
+        }
+        for v in &self.accepted_vote {
+            os.write_tag(12, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PollState {
+        PollState::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<PollPhase>>(
+                "phase",
+                |m: &PollState| { &m.phase },
+                |m: &mut PollState| { &mut m.phase },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CandidateList>>(
+                "candidates",
+                |m: &PollState| { &m.candidates },
+                |m: &mut PollState| { &mut m.candidates },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CounterParametersStorage>>(
+                "counter_parameters",
+                |m: &PollState| { &m.counter_parameters },
+                |m: &mut PollState| { &mut m.counter_parameters },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PollParametersStorage>>(
+                "poll_parameters",
+                |m: &PollState| { &m.poll_parameters },
+                |m: &mut PollState| { &mut m.poll_parameters },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RegistrationResponse>>(
+                "registration",
+                |m: &PollState| { &m.registration },
+                |m: &mut PollState| { &mut m.registration },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<VoteStorage>>(
+                "vote_sum",
+                |m: &PollState| { &m.vote_sum },
+                |m: &mut PollState| { &mut m.vote_sum },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DecryptedResultPartStorage>>(
+                "decrypted_part",
+                |m: &PollState| { &m.decrypted_part },
+                |m: &mut PollState| { &mut m.decrypted_part },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<VoteResultStorage>>(
+                "vote_result",
+                |m: &PollState| { &m.vote_result },
+                |m: &mut PollState| { &mut m.vote_result },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "registration_deadline",
+                |m: &PollState| { &m.registration_deadline },
+                |m: &mut PollState| { &mut m.registration_deadline },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "voting_deadline",
+                |m: &PollState| { &m.voting_deadline },
+                |m: &mut PollState| { &mut m.voting_deadline },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<SignatureScheme>>(
+                "signature_scheme",
+                |m: &PollState| { &m.signature_scheme },
+                |m: &mut PollState| { &mut m.signature_scheme },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<VoteStorage>>(
+                "accepted_vote",
+                |m: &PollState| { &m.accepted_vote },
+                |m: &mut PollState| { &mut m.accepted_vote },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PollState>(
+                "PollState",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PollState {
+        static instance: ::protobuf::rt::LazyV2<PollState> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PollState::new)
+    }
+}
+
+impl ::protobuf::Clear for PollState {
+    fn clear(&mut self) {
+        self.phase = PollPhase::SETUP;
+        self.candidates.clear();
+        self.counter_parameters.clear();
+        self.poll_parameters.clear();
+        self.registration.clear();
+        self.vote_sum.clear();
+        self.decrypted_part.clear();
+        self.vote_result.clear();
+        self.registration_deadline = 0;
+        self.voting_deadline = 0;
+        self.signature_scheme = SignatureScheme::DEFAULT_SCHEME;
+        self.accepted_vote.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PollState {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PollState {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct PollTranscript {
+    // message fields
+    pub counter_parameters: ::protobuf::SingularPtrField<CounterParametersStorage>,
+    pub poll_parameters: ::protobuf::SingularPtrField<PollParametersStorage>,
+    pub coordinator_public_key: ::std::vec::Vec<u8>,
+    pub registration: ::protobuf::RepeatedField<RegistrationResponse>,
+    pub vote_request: ::protobuf::RepeatedField<VoteRequest>,
+    pub decrypted_part: ::protobuf::RepeatedField<DecryptedResultPartStorage>,
+    pub vote_result: ::protobuf::SingularPtrField<VoteResultStorage>,
+    pub poll_mode: PollMode,
+    pub blind_certification_public_key: ::std::vec::Vec<u8>,
+    pub vote_ranking: ::protobuf::SingularPtrField<VoteRanking>,
+    pub credential_filter: ::protobuf::SingularPtrField<CredentialFilterTranscript>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a PollTranscript {
+    fn default() -> &'a PollTranscript {
+        <PollTranscript as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl PollTranscript {
+    pub fn new() -> PollTranscript {
+        ::std::default::Default::default()
+    }
+
+    // .com.webank.wedpr.acv.proto.CounterParametersStorage counter_parameters = 1;
+
+
+    pub fn get_counter_parameters(&self) -> &CounterParametersStorage {
+        self.counter_parameters.as_ref().unwrap_or_else(|| <CounterParametersStorage as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_counter_parameters(&mut self) {
+        self.counter_parameters.clear();
+    }
+
+    pub fn has_counter_parameters(&self) -> bool {
+        self.counter_parameters.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_counter_parameters(&mut self, v: CounterParametersStorage) {
+        self.counter_parameters = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_counter_parameters(&mut self) -> &mut CounterParametersStorage {
+        if self.counter_parameters.is_none() {
+            self.counter_parameters.set_default();
+        }
+        self.counter_parameters.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_counter_parameters(&mut self) -> CounterParametersStorage {
+        self.counter_parameters.take().unwrap_or_else(|| CounterParametersStorage::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.PollParametersStorage poll_parameters = 2;
+
+
+    pub fn get_poll_parameters(&self) -> &PollParametersStorage {
+        self.poll_parameters.as_ref().unwrap_or_else(|| <PollParametersStorage as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_poll_parameters(&mut self) {
+        self.poll_parameters.clear();
+    }
+
+    pub fn has_poll_parameters(&self) -> bool {
+        self.poll_parameters.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_poll_parameters(&mut self, v: PollParametersStorage) {
+        self.poll_parameters = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_poll_parameters(&mut self) -> &mut PollParametersStorage {
+        if self.poll_parameters.is_none() {
+            self.poll_parameters.set_default();
+        }
+        self.poll_parameters.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_poll_parameters(&mut self) -> PollParametersStorage {
+        self.poll_parameters.take().unwrap_or_else(|| PollParametersStorage::new())
+    }
+
+    // bytes coordinator_public_key = 3;
+
+
+    pub fn get_coordinator_public_key(&self) -> &[u8] {
+        &self.coordinator_public_key
+    }
+    pub fn clear_coordinator_public_key(&mut self) {
+        self.coordinator_public_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_coordinator_public_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.coordinator_public_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_coordinator_public_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.coordinator_public_key
+    }
+
+    // Take field
+    pub fn take_coordinator_public_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.coordinator_public_key, ::std::vec::Vec::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.RegistrationResponse registration = 4;
+
+
+    pub fn get_registration(&self) -> &[RegistrationResponse] {
+        &self.registration
+    }
+    pub fn clear_registration(&mut self) {
+        self.registration.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_registration(&mut self, v: ::protobuf::RepeatedField<RegistrationResponse>) {
+        self.registration = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_registration(&mut self) -> &mut ::protobuf::RepeatedField<RegistrationResponse> {
+        &mut self.registration
+    }
+
+    // Take field
+    pub fn take_registration(&mut self) -> ::protobuf::RepeatedField<RegistrationResponse> {
+        ::std::mem::replace(&mut self.registration, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.VoteRequest vote_request = 5;
+
+
+    pub fn get_vote_request(&self) -> &[VoteRequest] {
+        &self.vote_request
+    }
+    pub fn clear_vote_request(&mut self) {
+        self.vote_request.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vote_request(&mut self, v: ::protobuf::RepeatedField<VoteRequest>) {
+        self.vote_request = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_vote_request(&mut self) -> &mut ::protobuf::RepeatedField<VoteRequest> {
+        &mut self.vote_request
+    }
+
+    // Take field
+    pub fn take_vote_request(&mut self) -> ::protobuf::RepeatedField<VoteRequest> {
+        ::std::mem::replace(&mut self.vote_request, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.DecryptedResultPartStorage decrypted_part = 6;
+
+
+    pub fn get_decrypted_part(&self) -> &[DecryptedResultPartStorage] {
+        &self.decrypted_part
+    }
+    pub fn clear_decrypted_part(&mut self) {
+        self.decrypted_part.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_decrypted_part(&mut self, v: ::protobuf::RepeatedField<DecryptedResultPartStorage>) {
+        self.decrypted_part = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_decrypted_part(&mut self) -> &mut ::protobuf::RepeatedField<DecryptedResultPartStorage> {
+        &mut self.decrypted_part
+    }
+
+    // Take field
+    pub fn take_decrypted_part(&mut self) -> ::protobuf::RepeatedField<DecryptedResultPartStorage> {
+        ::std::mem::replace(&mut self.decrypted_part, ::protobuf::RepeatedField::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.VoteResultStorage vote_result = 7;
+
+
+    pub fn get_vote_result(&self) -> &VoteResultStorage {
+        self.vote_result.as_ref().unwrap_or_else(|| <VoteResultStorage as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_vote_result(&mut self) {
+        self.vote_result.clear();
+    }
+
+    pub fn has_vote_result(&self) -> bool {
+        self.vote_result.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vote_result(&mut self, v: VoteResultStorage) {
+        self.vote_result = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vote_result(&mut self) -> &mut VoteResultStorage {
+        if self.vote_result.is_none() {
+            self.vote_result.set_default();
+        }
+        self.vote_result.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_vote_result(&mut self) -> VoteResultStorage {
+        self.vote_result.take().unwrap_or_else(|| VoteResultStorage::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.PollMode poll_mode = 8;
+
+
+    pub fn get_poll_mode(&self) -> PollMode {
+        self.poll_mode
+    }
+    pub fn clear_poll_mode(&mut self) {
+        self.poll_mode = PollMode::BOUNDED;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_poll_mode(&mut self, v: PollMode) {
+        self.poll_mode = v;
+    }
+
+    // bytes blind_certification_public_key = 9;
+
+
+    pub fn get_blind_certification_public_key(&self) -> &[u8] {
+        &self.blind_certification_public_key
+    }
+    pub fn clear_blind_certification_public_key(&mut self) {
+        self.blind_certification_public_key.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_blind_certification_public_key(&mut self, v: ::std::vec::Vec<u8>) {
+        self.blind_certification_public_key = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_blind_certification_public_key(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.blind_certification_public_key
+    }
+
+    // Take field
+    pub fn take_blind_certification_public_key(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.blind_certification_public_key, ::std::vec::Vec::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.VoteRanking vote_ranking = 10;
+
+
+    pub fn get_vote_ranking(&self) -> &VoteRanking {
+        self.vote_ranking.as_ref().unwrap_or_else(|| <VoteRanking as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_vote_ranking(&mut self) {
+        self.vote_ranking.clear();
+    }
+
+    pub fn has_vote_ranking(&self) -> bool {
+        self.vote_ranking.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vote_ranking(&mut self, v: VoteRanking) {
+        self.vote_ranking = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vote_ranking(&mut self) -> &mut VoteRanking {
+        if self.vote_ranking.is_none() {
+            self.vote_ranking.set_default();
+        }
+        self.vote_ranking.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_vote_ranking(&mut self) -> VoteRanking {
+        self.vote_ranking.take().unwrap_or_else(|| VoteRanking::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.CredentialFilterTranscript credential_filter = 11;
+
+
+    pub fn get_credential_filter(&self) -> &CredentialFilterTranscript {
+        self.credential_filter.as_ref().unwrap_or_else(|| <CredentialFilterTranscript as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_credential_filter(&mut self) {
+        self.credential_filter.clear();
+    }
+
+    pub fn has_credential_filter(&self) -> bool {
+        self.credential_filter.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_credential_filter(&mut self, v: CredentialFilterTranscript) {
+        self.credential_filter = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_credential_filter(&mut self) -> &mut CredentialFilterTranscript {
+        if self.credential_filter.is_none() {
+            self.credential_filter.set_default();
+        }
+        self.credential_filter.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_credential_filter(&mut self) -> CredentialFilterTranscript {
+        self.credential_filter.take().unwrap_or_else(|| CredentialFilterTranscript::new())
+    }
+}
+
+impl ::protobuf::Message for PollTranscript {
+    fn is_initialized(&self) -> bool {
+        for v in &self.counter_parameters {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.poll_parameters {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.registration {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.vote_request {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.decrypted_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.vote_result {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.vote_ranking {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.credential_filter {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.counter_parameters)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.poll_parameters)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.coordinator_public_key)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.registration)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.vote_request)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.decrypted_part)?;
+                },
+                7 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.vote_result)?;
+                },
+                8 => {
+                    ::protobuf::rt::read_proto3_enum_with_unknown_fields_into(wire_type, is, &mut self.poll_mode, 8, &mut self.unknown_fields)?
+                },
+                9 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.blind_certification_public_key)?;
+                },
+                10 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.vote_ranking)?;
+                },
+                11 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.credential_filter)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.counter_parameters.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.poll_parameters.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.coordinator_public_key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.coordinator_public_key);
+        }
+        for value in &self.registration {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.vote_request {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.decrypted_part {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.vote_result.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.poll_mode != PollMode::BOUNDED {
+            my_size += ::protobuf::rt::enum_size(8, self.poll_mode);
+        }
+        if !self.blind_certification_public_key.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(9, &self.blind_certification_public_key);
+        }
+        if let Some(ref v) = self.vote_ranking.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.credential_filter.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.counter_parameters.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.poll_parameters.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.coordinator_public_key.is_empty() {
+            os.write_bytes(3, &self.coordinator_public_key)?;
+        }
+        for v in &self.registration {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.vote_request {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.decrypted_part {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.vote_result.as_ref() {
+            os.write_tag(7, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.poll_mode != PollMode::BOUNDED {
+            os.write_enum(8, ::protobuf::ProtobufEnum::value(&self.poll_mode))?;
+        }
+        if !self.blind_certification_public_key.is_empty() {
+            os.write_bytes(9, &self.blind_certification_public_key)?;
+        }
+        if let Some(ref v) = self.vote_ranking.as_ref() {
+            os.write_tag(10, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.credential_filter.as_ref() {
+            os.write_tag(11, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> PollTranscript {
+        PollTranscript::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CounterParametersStorage>>(
+                "counter_parameters",
+                |m: &PollTranscript| { &m.counter_parameters },
+                |m: &mut PollTranscript| { &mut m.counter_parameters },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<PollParametersStorage>>(
+                "poll_parameters",
+                |m: &PollTranscript| { &m.poll_parameters },
+                |m: &mut PollTranscript| { &mut m.poll_parameters },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "coordinator_public_key",
+                |m: &PollTranscript| { &m.coordinator_public_key },
+                |m: &mut PollTranscript| { &mut m.coordinator_public_key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RegistrationResponse>>(
+                "registration",
+                |m: &PollTranscript| { &m.registration },
+                |m: &mut PollTranscript| { &mut m.registration },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<VoteRequest>>(
+                "vote_request",
+                |m: &PollTranscript| { &m.vote_request },
+                |m: &mut PollTranscript| { &mut m.vote_request },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<DecryptedResultPartStorage>>(
+                "decrypted_part",
+                |m: &PollTranscript| { &m.decrypted_part },
+                |m: &mut PollTranscript| { &mut m.decrypted_part },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<VoteResultStorage>>(
+                "vote_result",
+                |m: &PollTranscript| { &m.vote_result },
+                |m: &mut PollTranscript| { &mut m.vote_result },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeEnum<PollMode>>(
+                "poll_mode",
+                |m: &PollTranscript| { &m.poll_mode },
+                |m: &mut PollTranscript| { &mut m.poll_mode },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "blind_certification_public_key",
+                |m: &PollTranscript| { &m.blind_certification_public_key },
+                |m: &mut PollTranscript| { &mut m.blind_certification_public_key },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<VoteRanking>>(
+                "vote_ranking",
+                |m: &PollTranscript| { &m.vote_ranking },
+                |m: &mut PollTranscript| { &mut m.vote_ranking },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CredentialFilterTranscript>>(
+                "credential_filter",
+                |m: &PollTranscript| { &m.credential_filter },
+                |m: &mut PollTranscript| { &mut m.credential_filter },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<PollTranscript>(
+                "PollTranscript",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static PollTranscript {
+        static instance: ::protobuf::rt::LazyV2<PollTranscript> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(PollTranscript::new)
+    }
+}
+
+impl ::protobuf::Clear for PollTranscript {
+    fn clear(&mut self) {
+        self.counter_parameters.clear();
+        self.poll_parameters.clear();
+        self.coordinator_public_key.clear();
+        self.registration.clear();
+        self.vote_request.clear();
+        self.decrypted_part.clear();
+        self.vote_result.clear();
+        self.poll_mode = PollMode::BOUNDED;
+        self.blind_certification_public_key.clear();
+        self.vote_ranking.clear();
+        self.credential_filter.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for PollTranscript {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for PollTranscript {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CredentialFilterTranscript {
+    // message fields
+    pub roster: ::protobuf::SingularPtrField<CredentialRoster>,
+    pub duplicate_blinding_part: ::protobuf::RepeatedField<CredentialBlindingPart>,
+    pub duplicate_decryption_part: ::protobuf::RepeatedField<CredentialDecryptionPart>,
+    pub mix_part: ::protobuf::RepeatedField<CredentialMixPart>,
+    pub match_blinding_part: ::protobuf::RepeatedField<CredentialBlindingPart>,
+    pub match_decryption_part: ::protobuf::RepeatedField<CredentialDecryptionPart>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CredentialFilterTranscript {
+    fn default() -> &'a CredentialFilterTranscript {
+        <CredentialFilterTranscript as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CredentialFilterTranscript {
+    pub fn new() -> CredentialFilterTranscript {
+        ::std::default::Default::default()
+    }
+
+    // .com.webank.wedpr.acv.proto.CredentialRoster roster = 1;
+
+
+    pub fn get_roster(&self) -> &CredentialRoster {
+        self.roster.as_ref().unwrap_or_else(|| <CredentialRoster as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_roster(&mut self) {
+        self.roster.clear();
+    }
+
+    pub fn has_roster(&self) -> bool {
+        self.roster.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_roster(&mut self, v: CredentialRoster) {
+        self.roster = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_roster(&mut self) -> &mut CredentialRoster {
+        if self.roster.is_none() {
+            self.roster.set_default();
+        }
+        self.roster.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_roster(&mut self) -> CredentialRoster {
+        self.roster.take().unwrap_or_else(|| CredentialRoster::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.CredentialBlindingPart duplicate_blinding_part = 2;
+
+
+    pub fn get_duplicate_blinding_part(&self) -> &[CredentialBlindingPart] {
+        &self.duplicate_blinding_part
+    }
+    pub fn clear_duplicate_blinding_part(&mut self) {
+        self.duplicate_blinding_part.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_duplicate_blinding_part(&mut self, v: ::protobuf::RepeatedField<CredentialBlindingPart>) {
+        self.duplicate_blinding_part = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_duplicate_blinding_part(&mut self) -> &mut ::protobuf::RepeatedField<CredentialBlindingPart> {
+        &mut self.duplicate_blinding_part
+    }
+
+    // Take field
+    pub fn take_duplicate_blinding_part(&mut self) -> ::protobuf::RepeatedField<CredentialBlindingPart> {
+        ::std::mem::replace(&mut self.duplicate_blinding_part, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.CredentialDecryptionPart duplicate_decryption_part = 3;
+
+
+    pub fn get_duplicate_decryption_part(&self) -> &[CredentialDecryptionPart] {
+        &self.duplicate_decryption_part
+    }
+    pub fn clear_duplicate_decryption_part(&mut self) {
+        self.duplicate_decryption_part.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_duplicate_decryption_part(&mut self, v: ::protobuf::RepeatedField<CredentialDecryptionPart>) {
+        self.duplicate_decryption_part = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_duplicate_decryption_part(&mut self) -> &mut ::protobuf::RepeatedField<CredentialDecryptionPart> {
+        &mut self.duplicate_decryption_part
+    }
+
+    // Take field
+    pub fn take_duplicate_decryption_part(&mut self) -> ::protobuf::RepeatedField<CredentialDecryptionPart> {
+        ::std::mem::replace(&mut self.duplicate_decryption_part, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.CredentialMixPart mix_part = 4;
+
+
+    pub fn get_mix_part(&self) -> &[CredentialMixPart] {
+        &self.mix_part
+    }
+    pub fn clear_mix_part(&mut self) {
+        self.mix_part.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mix_part(&mut self, v: ::protobuf::RepeatedField<CredentialMixPart>) {
+        self.mix_part = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_mix_part(&mut self) -> &mut ::protobuf::RepeatedField<CredentialMixPart> {
+        &mut self.mix_part
+    }
+
+    // Take field
+    pub fn take_mix_part(&mut self) -> ::protobuf::RepeatedField<CredentialMixPart> {
+        ::std::mem::replace(&mut self.mix_part, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.CredentialBlindingPart match_blinding_part = 5;
+
+
+    pub fn get_match_blinding_part(&self) -> &[CredentialBlindingPart] {
+        &self.match_blinding_part
+    }
+    pub fn clear_match_blinding_part(&mut self) {
+        self.match_blinding_part.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_match_blinding_part(&mut self, v: ::protobuf::RepeatedField<CredentialBlindingPart>) {
+        self.match_blinding_part = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_match_blinding_part(&mut self) -> &mut ::protobuf::RepeatedField<CredentialBlindingPart> {
+        &mut self.match_blinding_part
+    }
+
+    // Take field
+    pub fn take_match_blinding_part(&mut self) -> ::protobuf::RepeatedField<CredentialBlindingPart> {
+        ::std::mem::replace(&mut self.match_blinding_part, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.CredentialDecryptionPart match_decryption_part = 6;
+
+
+    pub fn get_match_decryption_part(&self) -> &[CredentialDecryptionPart] {
+        &self.match_decryption_part
+    }
+    pub fn clear_match_decryption_part(&mut self) {
+        self.match_decryption_part.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_match_decryption_part(&mut self, v: ::protobuf::RepeatedField<CredentialDecryptionPart>) {
+        self.match_decryption_part = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_match_decryption_part(&mut self) -> &mut ::protobuf::RepeatedField<CredentialDecryptionPart> {
+        &mut self.match_decryption_part
+    }
+
+    // Take field
+    pub fn take_match_decryption_part(&mut self) -> ::protobuf::RepeatedField<CredentialDecryptionPart> {
+        ::std::mem::replace(&mut self.match_decryption_part, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CredentialFilterTranscript {
+    fn is_initialized(&self) -> bool {
+        for v in &self.roster {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.duplicate_blinding_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.duplicate_decryption_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.mix_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.match_blinding_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.match_decryption_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.roster)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.duplicate_blinding_part)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.duplicate_decryption_part)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.mix_part)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.match_blinding_part)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.match_decryption_part)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.roster.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.duplicate_blinding_part {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.duplicate_decryption_part {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.mix_part {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.match_blinding_part {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.match_decryption_part {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.roster.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.duplicate_blinding_part {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.duplicate_decryption_part {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.mix_part {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.match_blinding_part {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.match_decryption_part {
+            os.write_tag(6, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CredentialFilterTranscript {
+        CredentialFilterTranscript::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CredentialRoster>>(
+                "roster",
+                |m: &CredentialFilterTranscript| { &m.roster },
+                |m: &mut CredentialFilterTranscript| { &mut m.roster },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CredentialBlindingPart>>(
+                "duplicate_blinding_part",
+                |m: &CredentialFilterTranscript| { &m.duplicate_blinding_part },
+                |m: &mut CredentialFilterTranscript| { &mut m.duplicate_blinding_part },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CredentialDecryptionPart>>(
+                "duplicate_decryption_part",
+                |m: &CredentialFilterTranscript| { &m.duplicate_decryption_part },
+                |m: &mut CredentialFilterTranscript| { &mut m.duplicate_decryption_part },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CredentialMixPart>>(
+                "mix_part",
+                |m: &CredentialFilterTranscript| { &m.mix_part },
+                |m: &mut CredentialFilterTranscript| { &mut m.mix_part },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CredentialBlindingPart>>(
+                "match_blinding_part",
+                |m: &CredentialFilterTranscript| { &m.match_blinding_part },
+                |m: &mut CredentialFilterTranscript| { &mut m.match_blinding_part },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CredentialDecryptionPart>>(
+                "match_decryption_part",
+                |m: &CredentialFilterTranscript| { &m.match_decryption_part },
+                |m: &mut CredentialFilterTranscript| { &mut m.match_decryption_part },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CredentialFilterTranscript>(
+                "CredentialFilterTranscript",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CredentialFilterTranscript {
+        static instance: ::protobuf::rt::LazyV2<CredentialFilterTranscript> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CredentialFilterTranscript::new)
+    }
+}
+
+impl ::protobuf::Clear for CredentialFilterTranscript {
+    fn clear(&mut self) {
+        self.roster.clear();
+        self.duplicate_blinding_part.clear();
+        self.duplicate_decryption_part.clear();
+        self.mix_part.clear();
+        self.match_blinding_part.clear();
+        self.match_decryption_part.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CredentialFilterTranscript {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CredentialFilterTranscript {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BlindedCipherPoints {
+    // message fields
+    pub cipher: ::protobuf::SingularPtrField<CipherPoints>,
+    pub ciphertext1_proof: ::std::vec::Vec<u8>,
+    pub ciphertext2_proof: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BlindedCipherPoints {
+    fn default() -> &'a BlindedCipherPoints {
+        <BlindedCipherPoints as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BlindedCipherPoints {
+    pub fn new() -> BlindedCipherPoints {
+        ::std::default::Default::default()
+    }
+
+    // .com.webank.wedpr.acv.proto.CipherPoints cipher = 1;
+
+
+    pub fn get_cipher(&self) -> &CipherPoints {
+        self.cipher.as_ref().unwrap_or_else(|| <CipherPoints as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_cipher(&mut self) {
+        self.cipher.clear();
+    }
+
+    pub fn has_cipher(&self) -> bool {
+        self.cipher.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_cipher(&mut self, v: CipherPoints) {
+        self.cipher = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_cipher(&mut self) -> &mut CipherPoints {
+        if self.cipher.is_none() {
+            self.cipher.set_default();
+        }
+        self.cipher.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_cipher(&mut self) -> CipherPoints {
+        self.cipher.take().unwrap_or_else(|| CipherPoints::new())
+    }
+
+    // bytes ciphertext1_proof = 2;
+
+
+    pub fn get_ciphertext1_proof(&self) -> &[u8] {
+        &self.ciphertext1_proof
+    }
+    pub fn clear_ciphertext1_proof(&mut self) {
+        self.ciphertext1_proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ciphertext1_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.ciphertext1_proof = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ciphertext1_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.ciphertext1_proof
+    }
+
+    // Take field
+    pub fn take_ciphertext1_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.ciphertext1_proof, ::std::vec::Vec::new())
+    }
+
+    // bytes ciphertext2_proof = 3;
+
+
+    pub fn get_ciphertext2_proof(&self) -> &[u8] {
+        &self.ciphertext2_proof
+    }
+    pub fn clear_ciphertext2_proof(&mut self) {
+        self.ciphertext2_proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ciphertext2_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.ciphertext2_proof = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_ciphertext2_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.ciphertext2_proof
+    }
+
+    // Take field
+    pub fn take_ciphertext2_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.ciphertext2_proof, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for BlindedCipherPoints {
+    fn is_initialized(&self) -> bool {
+        for v in &self.cipher {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.cipher)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.ciphertext1_proof)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.ciphertext2_proof)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.cipher.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if !self.ciphertext1_proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.ciphertext1_proof);
+        }
+        if !self.ciphertext2_proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.ciphertext2_proof);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.cipher.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if !self.ciphertext1_proof.is_empty() {
+            os.write_bytes(2, &self.ciphertext1_proof)?;
+        }
+        if !self.ciphertext2_proof.is_empty() {
+            os.write_bytes(3, &self.ciphertext2_proof)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BlindedCipherPoints {
+        BlindedCipherPoints::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CipherPoints>>(
+                "cipher",
+                |m: &BlindedCipherPoints| { &m.cipher },
+                |m: &mut BlindedCipherPoints| { &mut m.cipher },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "ciphertext1_proof",
+                |m: &BlindedCipherPoints| { &m.ciphertext1_proof },
+                |m: &mut BlindedCipherPoints| { &mut m.ciphertext1_proof },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "ciphertext2_proof",
+                |m: &BlindedCipherPoints| { &m.ciphertext2_proof },
+                |m: &mut BlindedCipherPoints| { &mut m.ciphertext2_proof },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BlindedCipherPoints>(
+                "BlindedCipherPoints",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BlindedCipherPoints {
+        static instance: ::protobuf::rt::LazyV2<BlindedCipherPoints> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BlindedCipherPoints::new)
+    }
+}
+
+impl ::protobuf::Clear for BlindedCipherPoints {
+    fn clear(&mut self) {
+        self.cipher.clear();
+        self.ciphertext1_proof.clear();
+        self.ciphertext2_proof.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BlindedCipherPoints {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BlindedCipherPoints {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UnlistedBlindingPart {
+    // message fields
+    pub counter_id: ::std::string::String,
+    pub blinding_point: ::std::vec::Vec<u8>,
+    pub blinded_candidate_cipher: ::protobuf::RepeatedField<BlindedCipherPoints>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UnlistedBlindingPart {
+    fn default() -> &'a UnlistedBlindingPart {
+        <UnlistedBlindingPart as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UnlistedBlindingPart {
+    pub fn new() -> UnlistedBlindingPart {
+        ::std::default::Default::default()
+    }
+
+    // string counter_id = 1;
+
+
+    pub fn get_counter_id(&self) -> &str {
+        &self.counter_id
+    }
+    pub fn clear_counter_id(&mut self) {
+        self.counter_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_counter_id(&mut self, v: ::std::string::String) {
+        self.counter_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_counter_id(&mut self) -> &mut ::std::string::String {
+        &mut self.counter_id
+    }
+
+    // Take field
+    pub fn take_counter_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.counter_id, ::std::string::String::new())
+    }
+
+    // bytes blinding_point = 2;
+
+
+    pub fn get_blinding_point(&self) -> &[u8] {
+        &self.blinding_point
+    }
+    pub fn clear_blinding_point(&mut self) {
+        self.blinding_point.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_blinding_point(&mut self, v: ::std::vec::Vec<u8>) {
+        self.blinding_point = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_blinding_point(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.blinding_point
+    }
+
+    // Take field
+    pub fn take_blinding_point(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.blinding_point, ::std::vec::Vec::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.BlindedCipherPoints blinded_candidate_cipher = 3;
+
+
+    pub fn get_blinded_candidate_cipher(&self) -> &[BlindedCipherPoints] {
+        &self.blinded_candidate_cipher
+    }
+    pub fn clear_blinded_candidate_cipher(&mut self) {
+        self.blinded_candidate_cipher.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_blinded_candidate_cipher(&mut self, v: ::protobuf::RepeatedField<BlindedCipherPoints>) {
+        self.blinded_candidate_cipher = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_blinded_candidate_cipher(&mut self) -> &mut ::protobuf::RepeatedField<BlindedCipherPoints> {
+        &mut self.blinded_candidate_cipher
+    }
+
+    // Take field
+    pub fn take_blinded_candidate_cipher(&mut self) -> ::protobuf::RepeatedField<BlindedCipherPoints> {
+        ::std::mem::replace(&mut self.blinded_candidate_cipher, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for UnlistedBlindingPart {
+    fn is_initialized(&self) -> bool {
+        for v in &self.blinded_candidate_cipher {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.counter_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.blinding_point)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.blinded_candidate_cipher)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.counter_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.counter_id);
+        }
+        if !self.blinding_point.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.blinding_point);
+        }
+        for value in &self.blinded_candidate_cipher {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.counter_id.is_empty() {
+            os.write_string(1, &self.counter_id)?;
+        }
+        if !self.blinding_point.is_empty() {
+            os.write_bytes(2, &self.blinding_point)?;
+        }
+        for v in &self.blinded_candidate_cipher {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UnlistedBlindingPart {
+        UnlistedBlindingPart::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "counter_id",
+                |m: &UnlistedBlindingPart| { &m.counter_id },
+                |m: &mut UnlistedBlindingPart| { &mut m.counter_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "blinding_point",
+                |m: &UnlistedBlindingPart| { &m.blinding_point },
+                |m: &mut UnlistedBlindingPart| { &mut m.blinding_point },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BlindedCipherPoints>>(
+                "blinded_candidate_cipher",
+                |m: &UnlistedBlindingPart| { &m.blinded_candidate_cipher },
+                |m: &mut UnlistedBlindingPart| { &mut m.blinded_candidate_cipher },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UnlistedBlindingPart>(
+                "UnlistedBlindingPart",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UnlistedBlindingPart {
+        static instance: ::protobuf::rt::LazyV2<UnlistedBlindingPart> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UnlistedBlindingPart::new)
+    }
+}
+
+impl ::protobuf::Clear for UnlistedBlindingPart {
+    fn clear(&mut self) {
+        self.counter_id.clear();
+        self.blinding_point.clear();
+        self.blinded_candidate_cipher.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UnlistedBlindingPart {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UnlistedBlindingPart {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UnlistedTagDecryptedPart {
+    // message fields
+    pub counter_id: ::std::string::String,
+    pub tag_part: ::protobuf::RepeatedField<CountingPart>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UnlistedTagDecryptedPart {
+    fn default() -> &'a UnlistedTagDecryptedPart {
+        <UnlistedTagDecryptedPart as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UnlistedTagDecryptedPart {
+    pub fn new() -> UnlistedTagDecryptedPart {
+        ::std::default::Default::default()
+    }
+
+    // string counter_id = 1;
+
+
+    pub fn get_counter_id(&self) -> &str {
+        &self.counter_id
+    }
+    pub fn clear_counter_id(&mut self) {
+        self.counter_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_counter_id(&mut self, v: ::std::string::String) {
+        self.counter_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_counter_id(&mut self) -> &mut ::std::string::String {
+        &mut self.counter_id
+    }
+
+    // Take field
+    pub fn take_counter_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.counter_id, ::std::string::String::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.CountingPart tag_part = 2;
+
+
+    pub fn get_tag_part(&self) -> &[CountingPart] {
+        &self.tag_part
+    }
+    pub fn clear_tag_part(&mut self) {
+        self.tag_part.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tag_part(&mut self, v: ::protobuf::RepeatedField<CountingPart>) {
+        self.tag_part = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tag_part(&mut self) -> &mut ::protobuf::RepeatedField<CountingPart> {
+        &mut self.tag_part
+    }
+
+    // Take field
+    pub fn take_tag_part(&mut self) -> ::protobuf::RepeatedField<CountingPart> {
+        ::std::mem::replace(&mut self.tag_part, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for UnlistedTagDecryptedPart {
+    fn is_initialized(&self) -> bool {
+        for v in &self.tag_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.counter_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.tag_part)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.counter_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.counter_id);
+        }
+        for value in &self.tag_part {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.counter_id.is_empty() {
+            os.write_string(1, &self.counter_id)?;
+        }
+        for v in &self.tag_part {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UnlistedTagDecryptedPart {
+        UnlistedTagDecryptedPart::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "counter_id",
+                |m: &UnlistedTagDecryptedPart| { &m.counter_id },
+                |m: &mut UnlistedTagDecryptedPart| { &mut m.counter_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CountingPart>>(
+                "tag_part",
+                |m: &UnlistedTagDecryptedPart| { &m.tag_part },
+                |m: &mut UnlistedTagDecryptedPart| { &mut m.tag_part },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UnlistedTagDecryptedPart>(
+                "UnlistedTagDecryptedPart",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UnlistedTagDecryptedPart {
+        static instance: ::protobuf::rt::LazyV2<UnlistedTagDecryptedPart> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UnlistedTagDecryptedPart::new)
+    }
+}
+
+impl ::protobuf::Clear for UnlistedTagDecryptedPart {
+    fn clear(&mut self) {
+        self.counter_id.clear();
+        self.tag_part.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UnlistedTagDecryptedPart {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UnlistedTagDecryptedPart {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct UnlistedGroupingStorage {
+    // message fields
+    pub blinding_part: ::protobuf::RepeatedField<UnlistedBlindingPart>,
+    pub tag_decrypted_part: ::protobuf::RepeatedField<UnlistedTagDecryptedPart>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a UnlistedGroupingStorage {
+    fn default() -> &'a UnlistedGroupingStorage {
+        <UnlistedGroupingStorage as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl UnlistedGroupingStorage {
+    pub fn new() -> UnlistedGroupingStorage {
+        ::std::default::Default::default()
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.UnlistedBlindingPart blinding_part = 1;
+
+
+    pub fn get_blinding_part(&self) -> &[UnlistedBlindingPart] {
+        &self.blinding_part
+    }
+    pub fn clear_blinding_part(&mut self) {
+        self.blinding_part.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_blinding_part(&mut self, v: ::protobuf::RepeatedField<UnlistedBlindingPart>) {
+        self.blinding_part = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_blinding_part(&mut self) -> &mut ::protobuf::RepeatedField<UnlistedBlindingPart> {
+        &mut self.blinding_part
+    }
+
+    // Take field
+    pub fn take_blinding_part(&mut self) -> ::protobuf::RepeatedField<UnlistedBlindingPart> {
+        ::std::mem::replace(&mut self.blinding_part, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.UnlistedTagDecryptedPart tag_decrypted_part = 2;
+
+
+    pub fn get_tag_decrypted_part(&self) -> &[UnlistedTagDecryptedPart] {
+        &self.tag_decrypted_part
+    }
+    pub fn clear_tag_decrypted_part(&mut self) {
+        self.tag_decrypted_part.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_tag_decrypted_part(&mut self, v: ::protobuf::RepeatedField<UnlistedTagDecryptedPart>) {
+        self.tag_decrypted_part = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_tag_decrypted_part(&mut self) -> &mut ::protobuf::RepeatedField<UnlistedTagDecryptedPart> {
+        &mut self.tag_decrypted_part
+    }
+
+    // Take field
+    pub fn take_tag_decrypted_part(&mut self) -> ::protobuf::RepeatedField<UnlistedTagDecryptedPart> {
+        ::std::mem::replace(&mut self.tag_decrypted_part, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for UnlistedGroupingStorage {
+    fn is_initialized(&self) -> bool {
+        for v in &self.blinding_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.tag_decrypted_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.blinding_part)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.tag_decrypted_part)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.blinding_part {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.tag_decrypted_part {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.blinding_part {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.tag_decrypted_part {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> UnlistedGroupingStorage {
+        UnlistedGroupingStorage::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UnlistedBlindingPart>>(
+                "blinding_part",
+                |m: &UnlistedGroupingStorage| { &m.blinding_part },
+                |m: &mut UnlistedGroupingStorage| { &mut m.blinding_part },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UnlistedTagDecryptedPart>>(
+                "tag_decrypted_part",
+                |m: &UnlistedGroupingStorage| { &m.tag_decrypted_part },
+                |m: &mut UnlistedGroupingStorage| { &mut m.tag_decrypted_part },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UnlistedGroupingStorage>(
+                "UnlistedGroupingStorage",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static UnlistedGroupingStorage {
+        static instance: ::protobuf::rt::LazyV2<UnlistedGroupingStorage> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(UnlistedGroupingStorage::new)
+    }
+}
+
+impl ::protobuf::Clear for UnlistedGroupingStorage {
+    fn clear(&mut self) {
+        self.blinding_part.clear();
+        self.tag_decrypted_part.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for UnlistedGroupingStorage {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for UnlistedGroupingStorage {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BlindCertificationCommitment {
+    // message fields
+    pub voter_weight: u32,
+    pub commitment_a: ::std::vec::Vec<u8>,
+    pub commitment_b: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BlindCertificationCommitment {
+    fn default() -> &'a BlindCertificationCommitment {
+        <BlindCertificationCommitment as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BlindCertificationCommitment {
+    pub fn new() -> BlindCertificationCommitment {
+        ::std::default::Default::default()
+    }
+
+    // uint32 voter_weight = 1;
+
+
+    pub fn get_voter_weight(&self) -> u32 {
+        self.voter_weight
+    }
+    pub fn clear_voter_weight(&mut self) {
+        self.voter_weight = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_voter_weight(&mut self, v: u32) {
+        self.voter_weight = v;
+    }
+
+    // bytes commitment_a = 2;
+
+
+    pub fn get_commitment_a(&self) -> &[u8] {
+        &self.commitment_a
+    }
+    pub fn clear_commitment_a(&mut self) {
+        self.commitment_a.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_commitment_a(&mut self, v: ::std::vec::Vec<u8>) {
+        self.commitment_a = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_commitment_a(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.commitment_a
+    }
+
+    // Take field
+    pub fn take_commitment_a(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.commitment_a, ::std::vec::Vec::new())
+    }
+
+    // bytes commitment_b = 3;
+
+
+    pub fn get_commitment_b(&self) -> &[u8] {
+        &self.commitment_b
+    }
+    pub fn clear_commitment_b(&mut self) {
+        self.commitment_b.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_commitment_b(&mut self, v: ::std::vec::Vec<u8>) {
+        self.commitment_b = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_commitment_b(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.commitment_b
+    }
+
+    // Take field
+    pub fn take_commitment_b(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.commitment_b, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for BlindCertificationCommitment {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.voter_weight = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.commitment_a)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.commitment_b)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.voter_weight != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.voter_weight, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.commitment_a.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.commitment_a);
+        }
+        if !self.commitment_b.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.commitment_b);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.voter_weight != 0 {
+            os.write_uint32(1, self.voter_weight)?;
+        }
+        if !self.commitment_a.is_empty() {
+            os.write_bytes(2, &self.commitment_a)?;
+        }
+        if !self.commitment_b.is_empty() {
+            os.write_bytes(3, &self.commitment_b)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BlindCertificationCommitment {
+        BlindCertificationCommitment::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "voter_weight",
+                |m: &BlindCertificationCommitment| { &m.voter_weight },
+                |m: &mut BlindCertificationCommitment| { &mut m.voter_weight },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "commitment_a",
+                |m: &BlindCertificationCommitment| { &m.commitment_a },
+                |m: &mut BlindCertificationCommitment| { &mut m.commitment_a },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "commitment_b",
+                |m: &BlindCertificationCommitment| { &m.commitment_b },
+                |m: &mut BlindCertificationCommitment| { &mut m.commitment_b },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BlindCertificationCommitment>(
+                "BlindCertificationCommitment",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BlindCertificationCommitment {
+        static instance: ::protobuf::rt::LazyV2<BlindCertificationCommitment> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BlindCertificationCommitment::new)
+    }
+}
+
+impl ::protobuf::Clear for BlindCertificationCommitment {
+    fn clear(&mut self) {
+        self.voter_weight = 0;
+        self.commitment_a.clear();
+        self.commitment_b.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BlindCertificationCommitment {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BlindCertificationCommitment {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BlindCertificationSecret {
+    // message fields
+    pub u: ::std::vec::Vec<u8>,
+    pub s: ::std::vec::Vec<u8>,
+    pub d: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BlindCertificationSecret {
+    fn default() -> &'a BlindCertificationSecret {
+        <BlindCertificationSecret as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BlindCertificationSecret {
+    pub fn new() -> BlindCertificationSecret {
+        ::std::default::Default::default()
+    }
+
+    // bytes u = 1;
+
+
+    pub fn get_u(&self) -> &[u8] {
+        &self.u
+    }
+    pub fn clear_u(&mut self) {
+        self.u.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_u(&mut self, v: ::std::vec::Vec<u8>) {
+        self.u = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_u(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.u
+    }
+
+    // Take field
+    pub fn take_u(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.u, ::std::vec::Vec::new())
+    }
+
+    // bytes s = 2;
+
+
+    pub fn get_s(&self) -> &[u8] {
+        &self.s
+    }
+    pub fn clear_s(&mut self) {
+        self.s.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_s(&mut self, v: ::std::vec::Vec<u8>) {
+        self.s = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_s(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.s
+    }
+
+    // Take field
+    pub fn take_s(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.s, ::std::vec::Vec::new())
+    }
+
+    // bytes d = 3;
+
+
+    pub fn get_d(&self) -> &[u8] {
+        &self.d
+    }
+    pub fn clear_d(&mut self) {
+        self.d.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_d(&mut self, v: ::std::vec::Vec<u8>) {
+        self.d = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_d(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.d
+    }
+
+    // Take field
+    pub fn take_d(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.d, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for BlindCertificationSecret {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.u)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.s)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.d)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.u.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.u);
+        }
+        if !self.s.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.s);
+        }
+        if !self.d.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.d);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.u.is_empty() {
+            os.write_bytes(1, &self.u)?;
+        }
+        if !self.s.is_empty() {
+            os.write_bytes(2, &self.s)?;
+        }
+        if !self.d.is_empty() {
+            os.write_bytes(3, &self.d)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BlindCertificationSecret {
+        BlindCertificationSecret::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "u",
+                |m: &BlindCertificationSecret| { &m.u },
+                |m: &mut BlindCertificationSecret| { &mut m.u },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "s",
+                |m: &BlindCertificationSecret| { &m.s },
+                |m: &mut BlindCertificationSecret| { &mut m.s },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "d",
+                |m: &BlindCertificationSecret| { &m.d },
+                |m: &mut BlindCertificationSecret| { &mut m.d },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BlindCertificationSecret>(
+                "BlindCertificationSecret",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BlindCertificationSecret {
+        static instance: ::protobuf::rt::LazyV2<BlindCertificationSecret> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BlindCertificationSecret::new)
+    }
+}
+
+impl ::protobuf::Clear for BlindCertificationSecret {
+    fn clear(&mut self) {
+        self.u.clear();
+        self.s.clear();
+        self.d.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BlindCertificationSecret {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BlindCertificationSecret {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BlindCertificationVoterSecret {
+    // message fields
+    pub t1: ::std::vec::Vec<u8>,
+    pub t2: ::std::vec::Vec<u8>,
+    pub t3: ::std::vec::Vec<u8>,
+    pub t4: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BlindCertificationVoterSecret {
+    fn default() -> &'a BlindCertificationVoterSecret {
+        <BlindCertificationVoterSecret as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BlindCertificationVoterSecret {
+    pub fn new() -> BlindCertificationVoterSecret {
+        ::std::default::Default::default()
+    }
+
+    // bytes t1 = 1;
+
+
+    pub fn get_t1(&self) -> &[u8] {
+        &self.t1
+    }
+    pub fn clear_t1(&mut self) {
+        self.t1.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_t1(&mut self, v: ::std::vec::Vec<u8>) {
+        self.t1 = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_t1(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.t1
+    }
+
+    // Take field
+    pub fn take_t1(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.t1, ::std::vec::Vec::new())
+    }
+
+    // bytes t2 = 2;
+
+
+    pub fn get_t2(&self) -> &[u8] {
+        &self.t2
+    }
+    pub fn clear_t2(&mut self) {
+        self.t2.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_t2(&mut self, v: ::std::vec::Vec<u8>) {
+        self.t2 = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_t2(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.t2
+    }
+
+    // Take field
+    pub fn take_t2(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.t2, ::std::vec::Vec::new())
+    }
+
+    // bytes t3 = 3;
+
+
+    pub fn get_t3(&self) -> &[u8] {
+        &self.t3
+    }
+    pub fn clear_t3(&mut self) {
+        self.t3.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_t3(&mut self, v: ::std::vec::Vec<u8>) {
+        self.t3 = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_t3(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.t3
+    }
+
+    // Take field
+    pub fn take_t3(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.t3, ::std::vec::Vec::new())
+    }
+
+    // bytes t4 = 4;
+
+
+    pub fn get_t4(&self) -> &[u8] {
+        &self.t4
+    }
+    pub fn clear_t4(&mut self) {
+        self.t4.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_t4(&mut self, v: ::std::vec::Vec<u8>) {
+        self.t4 = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_t4(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.t4
+    }
+
+    // Take field
+    pub fn take_t4(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.t4, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for BlindCertificationVoterSecret {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.t1)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.t2)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.t3)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.t4)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.t1.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.t1);
+        }
+        if !self.t2.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.t2);
+        }
+        if !self.t3.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.t3);
+        }
+        if !self.t4.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.t4);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.t1.is_empty() {
+            os.write_bytes(1, &self.t1)?;
+        }
+        if !self.t2.is_empty() {
+            os.write_bytes(2, &self.t2)?;
+        }
+        if !self.t3.is_empty() {
+            os.write_bytes(3, &self.t3)?;
+        }
+        if !self.t4.is_empty() {
+            os.write_bytes(4, &self.t4)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BlindCertificationVoterSecret {
+        BlindCertificationVoterSecret::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "t1",
+                |m: &BlindCertificationVoterSecret| { &m.t1 },
+                |m: &mut BlindCertificationVoterSecret| { &mut m.t1 },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "t2",
+                |m: &BlindCertificationVoterSecret| { &m.t2 },
+                |m: &mut BlindCertificationVoterSecret| { &mut m.t2 },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "t3",
+                |m: &BlindCertificationVoterSecret| { &m.t3 },
+                |m: &mut BlindCertificationVoterSecret| { &mut m.t3 },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "t4",
+                |m: &BlindCertificationVoterSecret| { &m.t4 },
+                |m: &mut BlindCertificationVoterSecret| { &mut m.t4 },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BlindCertificationVoterSecret>(
+                "BlindCertificationVoterSecret",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BlindCertificationVoterSecret {
+        static instance: ::protobuf::rt::LazyV2<BlindCertificationVoterSecret> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BlindCertificationVoterSecret::new)
+    }
+}
+
+impl ::protobuf::Clear for BlindCertificationVoterSecret {
+    fn clear(&mut self) {
+        self.t1.clear();
+        self.t2.clear();
+        self.t3.clear();
+        self.t4.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BlindCertificationVoterSecret {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BlindCertificationVoterSecret {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BlindCertificationRequest {
+    // message fields
+    pub blinded_challenge: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BlindCertificationRequest {
+    fn default() -> &'a BlindCertificationRequest {
+        <BlindCertificationRequest as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BlindCertificationRequest {
+    pub fn new() -> BlindCertificationRequest {
+        ::std::default::Default::default()
+    }
+
+    // bytes blinded_challenge = 1;
+
+
+    pub fn get_blinded_challenge(&self) -> &[u8] {
+        &self.blinded_challenge
+    }
+    pub fn clear_blinded_challenge(&mut self) {
+        self.blinded_challenge.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_blinded_challenge(&mut self, v: ::std::vec::Vec<u8>) {
+        self.blinded_challenge = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_blinded_challenge(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.blinded_challenge
+    }
+
+    // Take field
+    pub fn take_blinded_challenge(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.blinded_challenge, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for BlindCertificationRequest {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.blinded_challenge)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.blinded_challenge.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.blinded_challenge);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.blinded_challenge.is_empty() {
+            os.write_bytes(1, &self.blinded_challenge)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BlindCertificationRequest {
+        BlindCertificationRequest::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "blinded_challenge",
+                |m: &BlindCertificationRequest| { &m.blinded_challenge },
+                |m: &mut BlindCertificationRequest| { &mut m.blinded_challenge },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BlindCertificationRequest>(
+                "BlindCertificationRequest",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BlindCertificationRequest {
+        static instance: ::protobuf::rt::LazyV2<BlindCertificationRequest> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BlindCertificationRequest::new)
+    }
+}
+
+impl ::protobuf::Clear for BlindCertificationRequest {
+    fn clear(&mut self) {
+        self.blinded_challenge.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BlindCertificationRequest {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BlindCertificationRequest {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BlindCertificationResponse {
+    // message fields
+    pub r: ::std::vec::Vec<u8>,
+    pub c: ::std::vec::Vec<u8>,
+    pub s: ::std::vec::Vec<u8>,
+    pub d: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BlindCertificationResponse {
+    fn default() -> &'a BlindCertificationResponse {
+        <BlindCertificationResponse as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BlindCertificationResponse {
+    pub fn new() -> BlindCertificationResponse {
+        ::std::default::Default::default()
+    }
+
+    // bytes r = 1;
+
+
+    pub fn get_r(&self) -> &[u8] {
+        &self.r
+    }
+    pub fn clear_r(&mut self) {
+        self.r.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_r(&mut self, v: ::std::vec::Vec<u8>) {
+        self.r = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_r(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.r
+    }
+
+    // Take field
+    pub fn take_r(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.r, ::std::vec::Vec::new())
+    }
+
+    // bytes c = 2;
+
+
+    pub fn get_c(&self) -> &[u8] {
+        &self.c
+    }
+    pub fn clear_c(&mut self) {
+        self.c.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_c(&mut self, v: ::std::vec::Vec<u8>) {
+        self.c = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_c(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.c
+    }
+
+    // Take field
+    pub fn take_c(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.c, ::std::vec::Vec::new())
+    }
+
+    // bytes s = 3;
+
+
+    pub fn get_s(&self) -> &[u8] {
+        &self.s
+    }
+    pub fn clear_s(&mut self) {
+        self.s.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_s(&mut self, v: ::std::vec::Vec<u8>) {
+        self.s = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_s(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.s
+    }
+
+    // Take field
+    pub fn take_s(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.s, ::std::vec::Vec::new())
+    }
+
+    // bytes d = 4;
+
+
+    pub fn get_d(&self) -> &[u8] {
+        &self.d
+    }
+    pub fn clear_d(&mut self) {
+        self.d.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_d(&mut self, v: ::std::vec::Vec<u8>) {
+        self.d = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_d(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.d
+    }
+
+    // Take field
+    pub fn take_d(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.d, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for BlindCertificationResponse {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.r)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.c)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.s)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.d)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.r.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.r);
+        }
+        if !self.c.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.c);
+        }
+        if !self.s.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.s);
+        }
+        if !self.d.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.d);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.r.is_empty() {
+            os.write_bytes(1, &self.r)?;
+        }
+        if !self.c.is_empty() {
+            os.write_bytes(2, &self.c)?;
+        }
+        if !self.s.is_empty() {
+            os.write_bytes(3, &self.s)?;
+        }
+        if !self.d.is_empty() {
+            os.write_bytes(4, &self.d)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BlindCertificationResponse {
+        BlindCertificationResponse::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "r",
+                |m: &BlindCertificationResponse| { &m.r },
+                |m: &mut BlindCertificationResponse| { &mut m.r },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "c",
+                |m: &BlindCertificationResponse| { &m.c },
+                |m: &mut BlindCertificationResponse| { &mut m.c },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "s",
+                |m: &BlindCertificationResponse| { &m.s },
+                |m: &mut BlindCertificationResponse| { &mut m.s },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "d",
+                |m: &BlindCertificationResponse| { &m.d },
+                |m: &mut BlindCertificationResponse| { &mut m.d },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BlindCertificationResponse>(
+                "BlindCertificationResponse",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BlindCertificationResponse {
+        static instance: ::protobuf::rt::LazyV2<BlindCertificationResponse> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BlindCertificationResponse::new)
+    }
+}
+
+impl ::protobuf::Clear for BlindCertificationResponse {
+    fn clear(&mut self) {
+        self.r.clear();
+        self.c.clear();
+        self.s.clear();
+        self.d.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BlindCertificationResponse {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BlindCertificationResponse {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BlindCertificate {
+    // message fields
+    pub voter_weight: u32,
+    pub rho: ::std::vec::Vec<u8>,
+    pub omega: ::std::vec::Vec<u8>,
+    pub sigma: ::std::vec::Vec<u8>,
+    pub delta: ::std::vec::Vec<u8>,
+    pub weight_proof: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BlindCertificate {
+    fn default() -> &'a BlindCertificate {
+        <BlindCertificate as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BlindCertificate {
+    pub fn new() -> BlindCertificate {
+        ::std::default::Default::default()
+    }
+
+    // uint32 voter_weight = 1;
+
+
+    pub fn get_voter_weight(&self) -> u32 {
+        self.voter_weight
+    }
+    pub fn clear_voter_weight(&mut self) {
+        self.voter_weight = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_voter_weight(&mut self, v: u32) {
+        self.voter_weight = v;
+    }
+
+    // bytes rho = 2;
+
+
+    pub fn get_rho(&self) -> &[u8] {
+        &self.rho
+    }
+    pub fn clear_rho(&mut self) {
+        self.rho.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_rho(&mut self, v: ::std::vec::Vec<u8>) {
+        self.rho = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_rho(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.rho
+    }
+
+    // Take field
+    pub fn take_rho(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.rho, ::std::vec::Vec::new())
+    }
+
+    // bytes omega = 3;
+
+
+    pub fn get_omega(&self) -> &[u8] {
+        &self.omega
+    }
+    pub fn clear_omega(&mut self) {
+        self.omega.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_omega(&mut self, v: ::std::vec::Vec<u8>) {
+        self.omega = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_omega(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.omega
+    }
+
+    // Take field
+    pub fn take_omega(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.omega, ::std::vec::Vec::new())
+    }
+
+    // bytes sigma = 4;
+
+
+    pub fn get_sigma(&self) -> &[u8] {
+        &self.sigma
+    }
+    pub fn clear_sigma(&mut self) {
+        self.sigma.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sigma(&mut self, v: ::std::vec::Vec<u8>) {
+        self.sigma = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_sigma(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.sigma
+    }
+
+    // Take field
+    pub fn take_sigma(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.sigma, ::std::vec::Vec::new())
+    }
+
+    // bytes delta = 5;
+
+
+    pub fn get_delta(&self) -> &[u8] {
+        &self.delta
+    }
+    pub fn clear_delta(&mut self) {
+        self.delta.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_delta(&mut self, v: ::std::vec::Vec<u8>) {
+        self.delta = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_delta(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.delta
+    }
+
+    // Take field
+    pub fn take_delta(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.delta, ::std::vec::Vec::new())
+    }
+
+    // bytes weight_proof = 6;
+
+
+    pub fn get_weight_proof(&self) -> &[u8] {
+        &self.weight_proof
+    }
+    pub fn clear_weight_proof(&mut self) {
+        self.weight_proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_weight_proof(&mut self, v: ::std::vec::Vec<u8>) {
+        self.weight_proof = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_weight_proof(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.weight_proof
+    }
+
+    // Take field
+    pub fn take_weight_proof(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.weight_proof, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for BlindCertificate {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.voter_weight = tmp;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.rho)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.omega)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.sigma)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.delta)?;
+                },
+                6 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.weight_proof)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if self.voter_weight != 0 {
+            my_size += ::protobuf::rt::value_size(1, self.voter_weight, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.rho.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.rho);
+        }
+        if !self.omega.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.omega);
+        }
+        if !self.sigma.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(4, &self.sigma);
+        }
+        if !self.delta.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.delta);
+        }
+        if !self.weight_proof.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(6, &self.weight_proof);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if self.voter_weight != 0 {
+            os.write_uint32(1, self.voter_weight)?;
+        }
+        if !self.rho.is_empty() {
+            os.write_bytes(2, &self.rho)?;
+        }
+        if !self.omega.is_empty() {
+            os.write_bytes(3, &self.omega)?;
+        }
+        if !self.sigma.is_empty() {
+            os.write_bytes(4, &self.sigma)?;
+        }
+        if !self.delta.is_empty() {
+            os.write_bytes(5, &self.delta)?;
+        }
+        if !self.weight_proof.is_empty() {
+            os.write_bytes(6, &self.weight_proof)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BlindCertificate {
+        BlindCertificate::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "voter_weight",
+                |m: &BlindCertificate| { &m.voter_weight },
+                |m: &mut BlindCertificate| { &mut m.voter_weight },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "rho",
+                |m: &BlindCertificate| { &m.rho },
+                |m: &mut BlindCertificate| { &mut m.rho },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "omega",
+                |m: &BlindCertificate| { &m.omega },
+                |m: &mut BlindCertificate| { &mut m.omega },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "sigma",
+                |m: &BlindCertificate| { &m.sigma },
+                |m: &mut BlindCertificate| { &mut m.sigma },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "delta",
+                |m: &BlindCertificate| { &m.delta },
+                |m: &mut BlindCertificate| { &mut m.delta },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "weight_proof",
+                |m: &BlindCertificate| { &m.weight_proof },
+                |m: &mut BlindCertificate| { &mut m.weight_proof },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BlindCertificate>(
+                "BlindCertificate",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BlindCertificate {
+        static instance: ::protobuf::rt::LazyV2<BlindCertificate> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BlindCertificate::new)
+    }
+}
+
+impl ::protobuf::Clear for BlindCertificate {
+    fn clear(&mut self) {
+        self.voter_weight = 0;
+        self.rho.clear();
+        self.omega.clear();
+        self.sigma.clear();
+        self.delta.clear();
+        self.weight_proof.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BlindCertificate {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BlindCertificate {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct VoteRanking {
+    // message fields
+    pub ranked_candidate: ::protobuf::RepeatedField<::std::string::String>,
+    pub comparison: ::protobuf::RepeatedField<RankingComparison>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a VoteRanking {
+    fn default() -> &'a VoteRanking {
+        <VoteRanking as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl VoteRanking {
+    pub fn new() -> VoteRanking {
+        ::std::default::Default::default()
+    }
+
+    // repeated string ranked_candidate = 1;
+
+
+    pub fn get_ranked_candidate(&self) -> &[::std::string::String] {
+        &self.ranked_candidate
+    }
+    pub fn clear_ranked_candidate(&mut self) {
+        self.ranked_candidate.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ranked_candidate(&mut self, v: ::protobuf::RepeatedField<::std::string::String>) {
+        self.ranked_candidate = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ranked_candidate(&mut self) -> &mut ::protobuf::RepeatedField<::std::string::String> {
+        &mut self.ranked_candidate
+    }
+
+    // Take field
+    pub fn take_ranked_candidate(&mut self) -> ::protobuf::RepeatedField<::std::string::String> {
+        ::std::mem::replace(&mut self.ranked_candidate, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.RankingComparison comparison = 2;
+
+
+    pub fn get_comparison(&self) -> &[RankingComparison] {
+        &self.comparison
+    }
+    pub fn clear_comparison(&mut self) {
+        self.comparison.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_comparison(&mut self, v: ::protobuf::RepeatedField<RankingComparison>) {
+        self.comparison = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_comparison(&mut self) -> &mut ::protobuf::RepeatedField<RankingComparison> {
+        &mut self.comparison
+    }
+
+    // Take field
+    pub fn take_comparison(&mut self) -> ::protobuf::RepeatedField<RankingComparison> {
+        ::std::mem::replace(&mut self.comparison, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for VoteRanking {
+    fn is_initialized(&self) -> bool {
+        for v in &self.comparison {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_string_into(wire_type, is, &mut self.ranked_candidate)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.comparison)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.ranked_candidate {
+            my_size += ::protobuf::rt::string_size(1, &value);
+        };
+        for value in &self.comparison {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.ranked_candidate {
+            os.write_string(1, &v)?;
+        };
+        for v in &self.comparison {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> VoteRanking {
+        VoteRanking::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "ranked_candidate",
+                |m: &VoteRanking| { &m.ranked_candidate },
+                |m: &mut VoteRanking| { &mut m.ranked_candidate },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RankingComparison>>(
+                "comparison",
+                |m: &VoteRanking| { &m.comparison },
+                |m: &mut VoteRanking| { &mut m.comparison },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<VoteRanking>(
+                "VoteRanking",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static VoteRanking {
+        static instance: ::protobuf::rt::LazyV2<VoteRanking> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(VoteRanking::new)
+    }
+}
+
+impl ::protobuf::Clear for VoteRanking {
+    fn clear(&mut self) {
+        self.ranked_candidate.clear();
+        self.comparison.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for VoteRanking {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VoteRanking {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RankingComparison {
+    // message fields
+    pub greater_candidate: ::std::string::String,
+    pub lesser_candidate: ::std::string::String,
+    pub max_difference: u32,
+    pub mix_part: ::protobuf::RepeatedField<RankingMixPart>,
+    pub decryption_part: ::protobuf::RepeatedField<RankingDecryptionPart>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RankingComparison {
+    fn default() -> &'a RankingComparison {
+        <RankingComparison as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RankingComparison {
+    pub fn new() -> RankingComparison {
+        ::std::default::Default::default()
+    }
+
+    // string greater_candidate = 1;
+
+
+    pub fn get_greater_candidate(&self) -> &str {
+        &self.greater_candidate
+    }
+    pub fn clear_greater_candidate(&mut self) {
+        self.greater_candidate.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_greater_candidate(&mut self, v: ::std::string::String) {
+        self.greater_candidate = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_greater_candidate(&mut self) -> &mut ::std::string::String {
+        &mut self.greater_candidate
+    }
+
+    // Take field
+    pub fn take_greater_candidate(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.greater_candidate, ::std::string::String::new())
+    }
+
+    // string lesser_candidate = 2;
+
+
+    pub fn get_lesser_candidate(&self) -> &str {
+        &self.lesser_candidate
+    }
+    pub fn clear_lesser_candidate(&mut self) {
+        self.lesser_candidate.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_lesser_candidate(&mut self, v: ::std::string::String) {
+        self.lesser_candidate = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_lesser_candidate(&mut self) -> &mut ::std::string::String {
+        &mut self.lesser_candidate
+    }
+
+    // Take field
+    pub fn take_lesser_candidate(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.lesser_candidate, ::std::string::String::new())
+    }
+
+    // uint32 max_difference = 3;
+
+
+    pub fn get_max_difference(&self) -> u32 {
+        self.max_difference
+    }
+    pub fn clear_max_difference(&mut self) {
+        self.max_difference = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_max_difference(&mut self, v: u32) {
+        self.max_difference = v;
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.RankingMixPart mix_part = 4;
+
+
+    pub fn get_mix_part(&self) -> &[RankingMixPart] {
+        &self.mix_part
+    }
+    pub fn clear_mix_part(&mut self) {
+        self.mix_part.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_mix_part(&mut self, v: ::protobuf::RepeatedField<RankingMixPart>) {
+        self.mix_part = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_mix_part(&mut self) -> &mut ::protobuf::RepeatedField<RankingMixPart> {
+        &mut self.mix_part
+    }
+
+    // Take field
+    pub fn take_mix_part(&mut self) -> ::protobuf::RepeatedField<RankingMixPart> {
+        ::std::mem::replace(&mut self.mix_part, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.RankingDecryptionPart decryption_part = 5;
+
+
+    pub fn get_decryption_part(&self) -> &[RankingDecryptionPart] {
+        &self.decryption_part
+    }
+    pub fn clear_decryption_part(&mut self) {
+        self.decryption_part.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_decryption_part(&mut self, v: ::protobuf::RepeatedField<RankingDecryptionPart>) {
+        self.decryption_part = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_decryption_part(&mut self) -> &mut ::protobuf::RepeatedField<RankingDecryptionPart> {
+        &mut self.decryption_part
+    }
+
+    // Take field
+    pub fn take_decryption_part(&mut self) -> ::protobuf::RepeatedField<RankingDecryptionPart> {
+        ::std::mem::replace(&mut self.decryption_part, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RankingComparison {
+    fn is_initialized(&self) -> bool {
+        for v in &self.mix_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.decryption_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.greater_candidate)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.lesser_candidate)?;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.max_difference = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.mix_part)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.decryption_part)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.greater_candidate.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.greater_candidate);
+        }
+        if !self.lesser_candidate.is_empty() {
+            my_size += ::protobuf::rt::string_size(2, &self.lesser_candidate);
+        }
+        if self.max_difference != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.max_difference, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.mix_part {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.decryption_part {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.greater_candidate.is_empty() {
+            os.write_string(1, &self.greater_candidate)?;
+        }
+        if !self.lesser_candidate.is_empty() {
+            os.write_string(2, &self.lesser_candidate)?;
+        }
+        if self.max_difference != 0 {
+            os.write_uint32(3, self.max_difference)?;
+        }
+        for v in &self.mix_part {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.decryption_part {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RankingComparison {
+        RankingComparison::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "greater_candidate",
+                |m: &RankingComparison| { &m.greater_candidate },
+                |m: &mut RankingComparison| { &mut m.greater_candidate },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "lesser_candidate",
+                |m: &RankingComparison| { &m.lesser_candidate },
+                |m: &mut RankingComparison| { &mut m.lesser_candidate },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "max_difference",
+                |m: &RankingComparison| { &m.max_difference },
+                |m: &mut RankingComparison| { &mut m.max_difference },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RankingMixPart>>(
+                "mix_part",
+                |m: &RankingComparison| { &m.mix_part },
+                |m: &mut RankingComparison| { &mut m.mix_part },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<RankingDecryptionPart>>(
+                "decryption_part",
+                |m: &RankingComparison| { &m.decryption_part },
+                |m: &mut RankingComparison| { &mut m.decryption_part },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RankingComparison>(
+                "RankingComparison",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RankingComparison {
+        static instance: ::protobuf::rt::LazyV2<RankingComparison> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RankingComparison::new)
+    }
+}
+
+impl ::protobuf::Clear for RankingComparison {
+    fn clear(&mut self) {
+        self.greater_candidate.clear();
+        self.lesser_candidate.clear();
+        self.max_difference = 0;
+        self.mix_part.clear();
+        self.decryption_part.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RankingComparison {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RankingComparison {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RankingMixPart {
+    // message fields
+    pub counter_id: ::std::string::String,
+    pub blinded_difference: ::protobuf::RepeatedField<Ballot>,
+    pub equality_proof: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub shuffled_difference: ::protobuf::RepeatedField<Ballot>,
+    pub shuffle_proof: ::protobuf::SingularPtrField<ShuffleProof>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RankingMixPart {
+    fn default() -> &'a RankingMixPart {
+        <RankingMixPart as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RankingMixPart {
+    pub fn new() -> RankingMixPart {
+        ::std::default::Default::default()
+    }
+
+    // string counter_id = 1;
+
+
+    pub fn get_counter_id(&self) -> &str {
+        &self.counter_id
+    }
+    pub fn clear_counter_id(&mut self) {
+        self.counter_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_counter_id(&mut self, v: ::std::string::String) {
+        self.counter_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_counter_id(&mut self) -> &mut ::std::string::String {
+        &mut self.counter_id
+    }
+
+    // Take field
+    pub fn take_counter_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.counter_id, ::std::string::String::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.Ballot blinded_difference = 2;
+
+
+    pub fn get_blinded_difference(&self) -> &[Ballot] {
+        &self.blinded_difference
+    }
+    pub fn clear_blinded_difference(&mut self) {
+        self.blinded_difference.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_blinded_difference(&mut self, v: ::protobuf::RepeatedField<Ballot>) {
+        self.blinded_difference = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_blinded_difference(&mut self) -> &mut ::protobuf::RepeatedField<Ballot> {
+        &mut self.blinded_difference
+    }
+
+    // Take field
+    pub fn take_blinded_difference(&mut self) -> ::protobuf::RepeatedField<Ballot> {
+        ::std::mem::replace(&mut self.blinded_difference, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated bytes equality_proof = 3;
+
+
+    pub fn get_equality_proof(&self) -> &[::std::vec::Vec<u8>] {
+        &self.equality_proof
+    }
+    pub fn clear_equality_proof(&mut self) {
+        self.equality_proof.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_equality_proof(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.equality_proof = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_equality_proof(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.equality_proof
+    }
+
+    // Take field
+    pub fn take_equality_proof(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.equality_proof, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.Ballot shuffled_difference = 4;
+
+
+    pub fn get_shuffled_difference(&self) -> &[Ballot] {
+        &self.shuffled_difference
+    }
+    pub fn clear_shuffled_difference(&mut self) {
+        self.shuffled_difference.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shuffled_difference(&mut self, v: ::protobuf::RepeatedField<Ballot>) {
+        self.shuffled_difference = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_shuffled_difference(&mut self) -> &mut ::protobuf::RepeatedField<Ballot> {
+        &mut self.shuffled_difference
+    }
+
+    // Take field
+    pub fn take_shuffled_difference(&mut self) -> ::protobuf::RepeatedField<Ballot> {
+        ::std::mem::replace(&mut self.shuffled_difference, ::protobuf::RepeatedField::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.ShuffleProof shuffle_proof = 5;
+
+
+    pub fn get_shuffle_proof(&self) -> &ShuffleProof {
+        self.shuffle_proof.as_ref().unwrap_or_else(|| <ShuffleProof as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_shuffle_proof(&mut self) {
+        self.shuffle_proof.clear();
+    }
+
+    pub fn has_shuffle_proof(&self) -> bool {
+        self.shuffle_proof.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shuffle_proof(&mut self, v: ShuffleProof) {
+        self.shuffle_proof = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_shuffle_proof(&mut self) -> &mut ShuffleProof {
+        if self.shuffle_proof.is_none() {
+            self.shuffle_proof.set_default();
+        }
+        self.shuffle_proof.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_shuffle_proof(&mut self) -> ShuffleProof {
+        self.shuffle_proof.take().unwrap_or_else(|| ShuffleProof::new())
+    }
+}
+
+impl ::protobuf::Message for RankingMixPart {
+    fn is_initialized(&self) -> bool {
+        for v in &self.blinded_difference {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.shuffled_difference {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.shuffle_proof {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.counter_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.blinded_difference)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.equality_proof)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.shuffled_difference)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.shuffle_proof)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.counter_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.counter_id);
+        }
+        for value in &self.blinded_difference {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.equality_proof {
+            my_size += ::protobuf::rt::bytes_size(3, &value);
+        };
+        for value in &self.shuffled_difference {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        if let Some(ref v) = self.shuffle_proof.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.counter_id.is_empty() {
+            os.write_string(1, &self.counter_id)?;
+        }
+        for v in &self.blinded_difference {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.equality_proof {
+            os.write_bytes(3, &v)?;
+        };
+        for v in &self.shuffled_difference {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        if let Some(ref v) = self.shuffle_proof.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RankingMixPart {
+        RankingMixPart::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "counter_id",
+                |m: &RankingMixPart| { &m.counter_id },
+                |m: &mut RankingMixPart| { &mut m.counter_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                "blinded_difference",
+                |m: &RankingMixPart| { &m.blinded_difference },
+                |m: &mut RankingMixPart| { &mut m.blinded_difference },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "equality_proof",
+                |m: &RankingMixPart| { &m.equality_proof },
+                |m: &mut RankingMixPart| { &mut m.equality_proof },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                "shuffled_difference",
+                |m: &RankingMixPart| { &m.shuffled_difference },
+                |m: &mut RankingMixPart| { &mut m.shuffled_difference },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ShuffleProof>>(
+                "shuffle_proof",
+                |m: &RankingMixPart| { &m.shuffle_proof },
+                |m: &mut RankingMixPart| { &mut m.shuffle_proof },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RankingMixPart>(
+                "RankingMixPart",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RankingMixPart {
+        static instance: ::protobuf::rt::LazyV2<RankingMixPart> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RankingMixPart::new)
+    }
+}
+
+impl ::protobuf::Clear for RankingMixPart {
+    fn clear(&mut self) {
+        self.counter_id.clear();
+        self.blinded_difference.clear();
+        self.equality_proof.clear();
+        self.shuffled_difference.clear();
+        self.shuffle_proof.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RankingMixPart {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RankingMixPart {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct RankingDecryptionPart {
+    // message fields
+    pub counter_id: ::std::string::String,
+    pub decrypted_part: ::protobuf::RepeatedField<CountingPart>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a RankingDecryptionPart {
+    fn default() -> &'a RankingDecryptionPart {
+        <RankingDecryptionPart as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl RankingDecryptionPart {
+    pub fn new() -> RankingDecryptionPart {
+        ::std::default::Default::default()
+    }
+
+    // string counter_id = 1;
+
+
+    pub fn get_counter_id(&self) -> &str {
+        &self.counter_id
+    }
+    pub fn clear_counter_id(&mut self) {
+        self.counter_id.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_counter_id(&mut self, v: ::std::string::String) {
+        self.counter_id = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_counter_id(&mut self) -> &mut ::std::string::String {
+        &mut self.counter_id
+    }
+
+    // Take field
+    pub fn take_counter_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.counter_id, ::std::string::String::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.CountingPart decrypted_part = 2;
+
+
+    pub fn get_decrypted_part(&self) -> &[CountingPart] {
+        &self.decrypted_part
+    }
+    pub fn clear_decrypted_part(&mut self) {
+        self.decrypted_part.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_decrypted_part(&mut self, v: ::protobuf::RepeatedField<CountingPart>) {
+        self.decrypted_part = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_decrypted_part(&mut self) -> &mut ::protobuf::RepeatedField<CountingPart> {
+        &mut self.decrypted_part
+    }
+
+    // Take field
+    pub fn take_decrypted_part(&mut self) -> ::protobuf::RepeatedField<CountingPart> {
+        ::std::mem::replace(&mut self.decrypted_part, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for RankingDecryptionPart {
+    fn is_initialized(&self) -> bool {
+        for v in &self.decrypted_part {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.counter_id)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.decrypted_part)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.counter_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.counter_id);
+        }
+        for value in &self.decrypted_part {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.counter_id.is_empty() {
+            os.write_string(1, &self.counter_id)?;
+        }
+        for v in &self.decrypted_part {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> RankingDecryptionPart {
+        RankingDecryptionPart::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "counter_id",
+                |m: &RankingDecryptionPart| { &m.counter_id },
+                |m: &mut RankingDecryptionPart| { &mut m.counter_id },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CountingPart>>(
+                "decrypted_part",
+                |m: &RankingDecryptionPart| { &m.decrypted_part },
+                |m: &mut RankingDecryptionPart| { &mut m.decrypted_part },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<RankingDecryptionPart>(
+                "RankingDecryptionPart",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static RankingDecryptionPart {
+        static instance: ::protobuf::rt::LazyV2<RankingDecryptionPart> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(RankingDecryptionPart::new)
+    }
+}
+
+impl ::protobuf::Clear for RankingDecryptionPart {
+    fn clear(&mut self) {
+        self.counter_id.clear();
+        self.decrypted_part.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for RankingDecryptionPart {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for RankingDecryptionPart {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct BallotRow {
+    // message fields
+    pub ballot: ::protobuf::RepeatedField<Ballot>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a BallotRow {
+    fn default() -> &'a BallotRow {
+        <BallotRow as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl BallotRow {
+    pub fn new() -> BallotRow {
+        ::std::default::Default::default()
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.Ballot ballot = 1;
+
+
+    pub fn get_ballot(&self) -> &[Ballot] {
+        &self.ballot
+    }
+    pub fn clear_ballot(&mut self) {
+        self.ballot.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_ballot(&mut self, v: ::protobuf::RepeatedField<Ballot>) {
+        self.ballot = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_ballot(&mut self) -> &mut ::protobuf::RepeatedField<Ballot> {
+        &mut self.ballot
+    }
+
+    // Take field
+    pub fn take_ballot(&mut self) -> ::protobuf::RepeatedField<Ballot> {
+        ::std::mem::replace(&mut self.ballot, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for BallotRow {
+    fn is_initialized(&self) -> bool {
+        for v in &self.ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.ballot)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.ballot {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.ballot {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> BallotRow {
+        BallotRow::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                "ballot",
+                |m: &BallotRow| { &m.ballot },
+                |m: &mut BallotRow| { &mut m.ballot },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<BallotRow>(
+                "BallotRow",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static BallotRow {
+        static instance: ::protobuf::rt::LazyV2<BallotRow> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(BallotRow::new)
+    }
+}
+
+impl ::protobuf::Clear for BallotRow {
+    fn clear(&mut self) {
+        self.ballot.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for BallotRow {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for BallotRow {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ShuffleProof {
+    // message fields
+    pub round: ::protobuf::RepeatedField<ShuffleProofRound>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ShuffleProof {
+    fn default() -> &'a ShuffleProof {
+        <ShuffleProof as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ShuffleProof {
+    pub fn new() -> ShuffleProof {
+        ::std::default::Default::default()
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.ShuffleProofRound round = 1;
+
+
+    pub fn get_round(&self) -> &[ShuffleProofRound] {
+        &self.round
+    }
+    pub fn clear_round(&mut self) {
+        self.round.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_round(&mut self, v: ::protobuf::RepeatedField<ShuffleProofRound>) {
+        self.round = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_round(&mut self) -> &mut ::protobuf::RepeatedField<ShuffleProofRound> {
+        &mut self.round
+    }
+
+    // Take field
+    pub fn take_round(&mut self) -> ::protobuf::RepeatedField<ShuffleProofRound> {
+        ::std::mem::replace(&mut self.round, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for ShuffleProof {
+    fn is_initialized(&self) -> bool {
+        for v in &self.round {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.round)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.round {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.round {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ShuffleProof {
+        ShuffleProof::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ShuffleProofRound>>(
+                "round",
+                |m: &ShuffleProof| { &m.round },
+                |m: &mut ShuffleProof| { &mut m.round },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ShuffleProof>(
+                "ShuffleProof",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ShuffleProof {
+        static instance: ::protobuf::rt::LazyV2<ShuffleProof> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ShuffleProof::new)
+    }
+}
+
+impl ::protobuf::Clear for ShuffleProof {
+    fn clear(&mut self) {
+        self.round.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ShuffleProof {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ShuffleProof {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct ShuffleProofRound {
+    // message fields
+    pub shadow_row: ::protobuf::RepeatedField<BallotRow>,
+    pub permutation: ::std::vec::Vec<u32>,
+    pub blinding: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a ShuffleProofRound {
+    fn default() -> &'a ShuffleProofRound {
+        <ShuffleProofRound as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl ShuffleProofRound {
+    pub fn new() -> ShuffleProofRound {
+        ::std::default::Default::default()
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.BallotRow shadow_row = 1;
+
+
+    pub fn get_shadow_row(&self) -> &[BallotRow] {
+        &self.shadow_row
+    }
+    pub fn clear_shadow_row(&mut self) {
+        self.shadow_row.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_shadow_row(&mut self, v: ::protobuf::RepeatedField<BallotRow>) {
+        self.shadow_row = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_shadow_row(&mut self) -> &mut ::protobuf::RepeatedField<BallotRow> {
+        &mut self.shadow_row
+    }
+
+    // Take field
+    pub fn take_shadow_row(&mut self) -> ::protobuf::RepeatedField<BallotRow> {
+        ::std::mem::replace(&mut self.shadow_row, ::protobuf::RepeatedField::new())
+    }
+
+    // repeated uint32 permutation = 2;
+
+
+    pub fn get_permutation(&self) -> &[u32] {
+        &self.permutation
+    }
+    pub fn clear_permutation(&mut self) {
+        self.permutation.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_permutation(&mut self, v: ::std::vec::Vec<u32>) {
+        self.permutation = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_permutation(&mut self) -> &mut ::std::vec::Vec<u32> {
+        &mut self.permutation
+    }
+
+    // Take field
+    pub fn take_permutation(&mut self) -> ::std::vec::Vec<u32> {
+        ::std::mem::replace(&mut self.permutation, ::std::vec::Vec::new())
+    }
+
+    // repeated bytes blinding = 3;
+
+
+    pub fn get_blinding(&self) -> &[::std::vec::Vec<u8>] {
+        &self.blinding
+    }
+    pub fn clear_blinding(&mut self) {
+        self.blinding.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_blinding(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.blinding = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_blinding(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.blinding
+    }
+
+    // Take field
+    pub fn take_blinding(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.blinding, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for ShuffleProofRound {
+    fn is_initialized(&self) -> bool {
+        for v in &self.shadow_row {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.shadow_row)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_repeated_uint32_into(wire_type, is, &mut self.permutation)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.blinding)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.shadow_row {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        for value in &self.permutation {
+            my_size += ::protobuf::rt::value_size(2, *value, ::protobuf::wire_format::WireTypeVarint);
+        };
+        for value in &self.blinding {
+            my_size += ::protobuf::rt::bytes_size(3, &value);
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.shadow_row {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        for v in &self.permutation {
+            os.write_uint32(2, *v)?;
+        };
+        for v in &self.blinding {
+            os.write_bytes(3, &v)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> ShuffleProofRound {
+        ShuffleProofRound::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BallotRow>>(
+                "shadow_row",
+                |m: &ShuffleProofRound| { &m.shadow_row },
+                |m: &mut ShuffleProofRound| { &mut m.shadow_row },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_vec_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "permutation",
+                |m: &ShuffleProofRound| { &m.permutation },
+                |m: &mut ShuffleProofRound| { &mut m.permutation },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "blinding",
+                |m: &ShuffleProofRound| { &m.blinding },
+                |m: &mut ShuffleProofRound| { &mut m.blinding },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<ShuffleProofRound>(
+                "ShuffleProofRound",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static ShuffleProofRound {
+        static instance: ::protobuf::rt::LazyV2<ShuffleProofRound> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(ShuffleProofRound::new)
+    }
+}
+
+impl ::protobuf::Clear for ShuffleProofRound {
+    fn clear(&mut self) {
+        self.shadow_row.clear();
+        self.permutation.clear();
+        self.blinding.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for ShuffleProofRound {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for ShuffleProofRound {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct VoteReceipt {
+    // message fields
+    pub vote_hash: ::std::vec::Vec<u8>,
+    pub leaf_index: u32,
+    pub leaf_count: u32,
+    pub sibling_hash: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
+    pub vote_root: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a VoteReceipt {
+    fn default() -> &'a VoteReceipt {
+        <VoteReceipt as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl VoteReceipt {
+    pub fn new() -> VoteReceipt {
+        ::std::default::Default::default()
+    }
+
+    // bytes vote_hash = 1;
+
+
+    pub fn get_vote_hash(&self) -> &[u8] {
+        &self.vote_hash
+    }
+    pub fn clear_vote_hash(&mut self) {
+        self.vote_hash.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vote_hash(&mut self, v: ::std::vec::Vec<u8>) {
+        self.vote_hash = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vote_hash(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.vote_hash
+    }
+
+    // Take field
+    pub fn take_vote_hash(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.vote_hash, ::std::vec::Vec::new())
+    }
+
+    // uint32 leaf_index = 2;
+
+
+    pub fn get_leaf_index(&self) -> u32 {
+        self.leaf_index
+    }
+    pub fn clear_leaf_index(&mut self) {
+        self.leaf_index = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_leaf_index(&mut self, v: u32) {
+        self.leaf_index = v;
+    }
+
+    // uint32 leaf_count = 3;
+
+
+    pub fn get_leaf_count(&self) -> u32 {
+        self.leaf_count
+    }
+    pub fn clear_leaf_count(&mut self) {
+        self.leaf_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_leaf_count(&mut self, v: u32) {
+        self.leaf_count = v;
+    }
+
+    // repeated bytes sibling_hash = 4;
+
+
+    pub fn get_sibling_hash(&self) -> &[::std::vec::Vec<u8>] {
+        &self.sibling_hash
+    }
+    pub fn clear_sibling_hash(&mut self) {
+        self.sibling_hash.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_sibling_hash(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.sibling_hash = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_sibling_hash(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.sibling_hash
+    }
+
+    // Take field
+    pub fn take_sibling_hash(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.sibling_hash, ::protobuf::RepeatedField::new())
+    }
+
+    // bytes vote_root = 5;
+
+
+    pub fn get_vote_root(&self) -> &[u8] {
+        &self.vote_root
+    }
+    pub fn clear_vote_root(&mut self) {
+        self.vote_root.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vote_root(&mut self, v: ::std::vec::Vec<u8>) {
+        self.vote_root = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vote_root(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.vote_root
+    }
+
+    // Take field
+    pub fn take_vote_root(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.vote_root, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for VoteReceipt {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.vote_hash)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.leaf_index = tmp;
+                },
+                3 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint32()?;
+                    self.leaf_count = tmp;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.sibling_hash)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.vote_root)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.vote_hash.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.vote_hash);
+        }
+        if self.leaf_index != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.leaf_index, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if self.leaf_count != 0 {
+            my_size += ::protobuf::rt::value_size(3, self.leaf_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        for value in &self.sibling_hash {
+            my_size += ::protobuf::rt::bytes_size(4, &value);
+        };
+        if !self.vote_root.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(5, &self.vote_root);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.vote_hash.is_empty() {
+            os.write_bytes(1, &self.vote_hash)?;
+        }
+        if self.leaf_index != 0 {
+            os.write_uint32(2, self.leaf_index)?;
+        }
+        if self.leaf_count != 0 {
+            os.write_uint32(3, self.leaf_count)?;
+        }
+        for v in &self.sibling_hash {
+            os.write_bytes(4, &v)?;
+        };
+        if !self.vote_root.is_empty() {
+            os.write_bytes(5, &self.vote_root)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> VoteReceipt {
+        VoteReceipt::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "vote_hash",
+                |m: &VoteReceipt| { &m.vote_hash },
+                |m: &mut VoteReceipt| { &mut m.vote_hash },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "leaf_index",
+                |m: &VoteReceipt| { &m.leaf_index },
+                |m: &mut VoteReceipt| { &mut m.leaf_index },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint32>(
+                "leaf_count",
+                |m: &VoteReceipt| { &m.leaf_count },
+                |m: &mut VoteReceipt| { &mut m.leaf_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "sibling_hash",
+                |m: &VoteReceipt| { &m.sibling_hash },
+                |m: &mut VoteReceipt| { &mut m.sibling_hash },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "vote_root",
+                |m: &VoteReceipt| { &m.vote_root },
+                |m: &mut VoteReceipt| { &mut m.vote_root },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<VoteReceipt>(
+                "VoteReceipt",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static VoteReceipt {
+        static instance: ::protobuf::rt::LazyV2<VoteReceipt> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(VoteReceipt::new)
+    }
+}
+
+impl ::protobuf::Clear for VoteReceipt {
+    fn clear(&mut self) {
+        self.vote_hash.clear();
+        self.leaf_index = 0;
+        self.leaf_count = 0;
+        self.sibling_hash.clear();
+        self.vote_root.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for VoteReceipt {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VoteReceipt {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AcceptedVoteList {
+    // message fields
+    pub vote: ::protobuf::RepeatedField<VoteStorage>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AcceptedVoteList {
+    fn default() -> &'a AcceptedVoteList {
+        <AcceptedVoteList as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AcceptedVoteList {
+    pub fn new() -> AcceptedVoteList {
+        ::std::default::Default::default()
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.VoteStorage vote = 1;
+
+
+    pub fn get_vote(&self) -> &[VoteStorage] {
+        &self.vote
+    }
+    pub fn clear_vote(&mut self) {
+        self.vote.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vote(&mut self, v: ::protobuf::RepeatedField<VoteStorage>) {
+        self.vote = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_vote(&mut self) -> &mut ::protobuf::RepeatedField<VoteStorage> {
+        &mut self.vote
+    }
+
+    // Take field
+    pub fn take_vote(&mut self) -> ::protobuf::RepeatedField<VoteStorage> {
+        ::std::mem::replace(&mut self.vote, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for AcceptedVoteList {
+    fn is_initialized(&self) -> bool {
+        for v in &self.vote {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.vote)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.vote {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.vote {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AcceptedVoteList {
+        AcceptedVoteList::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<VoteStorage>>(
+                "vote",
+                |m: &AcceptedVoteList| { &m.vote },
+                |m: &mut AcceptedVoteList| { &mut m.vote },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AcceptedVoteList>(
+                "AcceptedVoteList",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AcceptedVoteList {
+        static instance: ::protobuf::rt::LazyV2<AcceptedVoteList> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AcceptedVoteList::new)
+    }
+}
+
+impl ::protobuf::Clear for AcceptedVoteList {
+    fn clear(&mut self) {
+        self.vote.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AcceptedVoteList {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AcceptedVoteList {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct AggregatorCheckpoint {
+    // message fields
+    pub vote_sum: ::protobuf::SingularPtrField<VoteStorage>,
+    pub vote_count: u64,
+    pub checksum: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a AggregatorCheckpoint {
+    fn default() -> &'a AggregatorCheckpoint {
+        <AggregatorCheckpoint as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl AggregatorCheckpoint {
+    pub fn new() -> AggregatorCheckpoint {
+        ::std::default::Default::default()
+    }
+
+    // .com.webank.wedpr.acv.proto.VoteStorage vote_sum = 1;
+
+
+    pub fn get_vote_sum(&self) -> &VoteStorage {
+        self.vote_sum.as_ref().unwrap_or_else(|| <VoteStorage as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_vote_sum(&mut self) {
+        self.vote_sum.clear();
+    }
+
+    pub fn has_vote_sum(&self) -> bool {
+        self.vote_sum.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vote_sum(&mut self, v: VoteStorage) {
+        self.vote_sum = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vote_sum(&mut self) -> &mut VoteStorage {
+        if self.vote_sum.is_none() {
+            self.vote_sum.set_default();
+        }
+        self.vote_sum.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_vote_sum(&mut self) -> VoteStorage {
+        self.vote_sum.take().unwrap_or_else(|| VoteStorage::new())
+    }
+
+    // uint64 vote_count = 2;
+
+
+    pub fn get_vote_count(&self) -> u64 {
+        self.vote_count
+    }
+    pub fn clear_vote_count(&mut self) {
+        self.vote_count = 0;
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vote_count(&mut self, v: u64) {
+        self.vote_count = v;
+    }
+
+    // bytes checksum = 3;
+
+
+    pub fn get_checksum(&self) -> &[u8] {
+        &self.checksum
+    }
+    pub fn clear_checksum(&mut self) {
+        self.checksum.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_checksum(&mut self, v: ::std::vec::Vec<u8>) {
+        self.checksum = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_checksum(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.checksum
+    }
+
+    // Take field
+    pub fn take_checksum(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.checksum, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for AggregatorCheckpoint {
+    fn is_initialized(&self) -> bool {
+        for v in &self.vote_sum {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.vote_sum)?;
+                },
+                2 => {
+                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
+                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
+                    }
+                    let tmp = is.read_uint64()?;
+                    self.vote_count = tmp;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.checksum)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.vote_sum.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if self.vote_count != 0 {
+            my_size += ::protobuf::rt::value_size(2, self.vote_count, ::protobuf::wire_format::WireTypeVarint);
+        }
+        if !self.checksum.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.checksum);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.vote_sum.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if self.vote_count != 0 {
+            os.write_uint64(2, self.vote_count)?;
+        }
+        if !self.checksum.is_empty() {
+            os.write_bytes(3, &self.checksum)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> AggregatorCheckpoint {
+        AggregatorCheckpoint::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<VoteStorage>>(
+                "vote_sum",
+                |m: &AggregatorCheckpoint| { &m.vote_sum },
+                |m: &mut AggregatorCheckpoint| { &mut m.vote_sum },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeUint64>(
+                "vote_count",
+                |m: &AggregatorCheckpoint| { &m.vote_count },
+                |m: &mut AggregatorCheckpoint| { &mut m.vote_count },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "checksum",
+                |m: &AggregatorCheckpoint| { &m.checksum },
+                |m: &mut AggregatorCheckpoint| { &mut m.checksum },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<AggregatorCheckpoint>(
+                "AggregatorCheckpoint",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static AggregatorCheckpoint {
+        static instance: ::protobuf::rt::LazyV2<AggregatorCheckpoint> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(AggregatorCheckpoint::new)
+    }
+}
+
+impl ::protobuf::Clear for AggregatorCheckpoint {
+    fn clear(&mut self) {
+        self.vote_sum.clear();
+        self.vote_count = 0;
+        self.checksum.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for AggregatorCheckpoint {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for AggregatorCheckpoint {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct VoterCredential {
+    // message fields
+    pub credential: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a VoterCredential {
+    fn default() -> &'a VoterCredential {
+        <VoterCredential as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl VoterCredential {
+    pub fn new() -> VoterCredential {
+        ::std::default::Default::default()
+    }
+
+    // bytes credential = 1;
+
+
+    pub fn get_credential(&self) -> &[u8] {
+        &self.credential
+    }
+    pub fn clear_credential(&mut self) {
+        self.credential.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_credential(&mut self, v: ::std::vec::Vec<u8>) {
+        self.credential = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_credential(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.credential
+    }
+
+    // Take field
+    pub fn take_credential(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.credential, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for VoterCredential {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.credential)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.credential.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.credential);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if !self.credential.is_empty() {
+            os.write_bytes(1, &self.credential)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> VoterCredential {
+        VoterCredential::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "credential",
+                |m: &VoterCredential| { &m.credential },
+                |m: &mut VoterCredential| { &mut m.credential },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<VoterCredential>(
+                "VoterCredential",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static VoterCredential {
+        static instance: ::protobuf::rt::LazyV2<VoterCredential> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(VoterCredential::new)
+    }
+}
+
+impl ::protobuf::Clear for VoterCredential {
+    fn clear(&mut self) {
+        self.credential.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for VoterCredential {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for VoterCredential {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CredentialRosterEntry {
+    // message fields
+    pub blank_ballot: ::protobuf::SingularPtrField<Ballot>,
+    pub encrypted_credential: ::protobuf::SingularPtrField<Ballot>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CredentialRosterEntry {
+    fn default() -> &'a CredentialRosterEntry {
+        <CredentialRosterEntry as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CredentialRosterEntry {
+    pub fn new() -> CredentialRosterEntry {
+        ::std::default::Default::default()
+    }
+
+    // .com.webank.wedpr.acv.proto.Ballot blank_ballot = 1;
+
+
+    pub fn get_blank_ballot(&self) -> &Ballot {
+        self.blank_ballot.as_ref().unwrap_or_else(|| <Ballot as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_blank_ballot(&mut self) {
+        self.blank_ballot.clear();
+    }
+
+    pub fn has_blank_ballot(&self) -> bool {
+        self.blank_ballot.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_blank_ballot(&mut self, v: Ballot) {
+        self.blank_ballot = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_blank_ballot(&mut self) -> &mut Ballot {
+        if self.blank_ballot.is_none() {
+            self.blank_ballot.set_default();
+        }
+        self.blank_ballot.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_blank_ballot(&mut self) -> Ballot {
+        self.blank_ballot.take().unwrap_or_else(|| Ballot::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.Ballot encrypted_credential = 2;
+
+
+    pub fn get_encrypted_credential(&self) -> &Ballot {
+        self.encrypted_credential.as_ref().unwrap_or_else(|| <Ballot as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_encrypted_credential(&mut self) {
+        self.encrypted_credential.clear();
+    }
+
+    pub fn has_encrypted_credential(&self) -> bool {
+        self.encrypted_credential.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_encrypted_credential(&mut self, v: Ballot) {
+        self.encrypted_credential = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_encrypted_credential(&mut self) -> &mut Ballot {
+        if self.encrypted_credential.is_none() {
+            self.encrypted_credential.set_default();
+        }
+        self.encrypted_credential.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_encrypted_credential(&mut self) -> Ballot {
+        self.encrypted_credential.take().unwrap_or_else(|| Ballot::new())
+    }
+}
+
+impl ::protobuf::Message for CredentialRosterEntry {
+    fn is_initialized(&self) -> bool {
+        for v in &self.blank_ballot {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.encrypted_credential {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.blank_ballot)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.encrypted_credential)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if let Some(ref v) = self.blank_ballot.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        if let Some(ref v) = self.encrypted_credential.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        if let Some(ref v) = self.blank_ballot.as_ref() {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        if let Some(ref v) = self.encrypted_credential.as_ref() {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CredentialRosterEntry {
+        CredentialRosterEntry::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                "blank_ballot",
+                |m: &CredentialRosterEntry| { &m.blank_ballot },
+                |m: &mut CredentialRosterEntry| { &mut m.blank_ballot },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                "encrypted_credential",
+                |m: &CredentialRosterEntry| { &m.encrypted_credential },
+                |m: &mut CredentialRosterEntry| { &mut m.encrypted_credential },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CredentialRosterEntry>(
+                "CredentialRosterEntry",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CredentialRosterEntry {
+        static instance: ::protobuf::rt::LazyV2<CredentialRosterEntry> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CredentialRosterEntry::new)
+    }
+}
+
+impl ::protobuf::Clear for CredentialRosterEntry {
+    fn clear(&mut self) {
+        self.blank_ballot.clear();
+        self.encrypted_credential.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CredentialRosterEntry {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CredentialRosterEntry {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CredentialRoster {
+    // message fields
+    pub entry: ::protobuf::RepeatedField<CredentialRosterEntry>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CredentialRoster {
+    fn default() -> &'a CredentialRoster {
+        <CredentialRoster as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CredentialRoster {
+    pub fn new() -> CredentialRoster {
+        ::std::default::Default::default()
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.CredentialRosterEntry entry = 1;
+
+
+    pub fn get_entry(&self) -> &[CredentialRosterEntry] {
+        &self.entry
+    }
+    pub fn clear_entry(&mut self) {
+        self.entry.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_entry(&mut self, v: ::protobuf::RepeatedField<CredentialRosterEntry>) {
+        self.entry = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_entry(&mut self) -> &mut ::protobuf::RepeatedField<CredentialRosterEntry> {
+        &mut self.entry
+    }
+
+    // Take field
+    pub fn take_entry(&mut self) -> ::protobuf::RepeatedField<CredentialRosterEntry> {
+        ::std::mem::replace(&mut self.entry, ::protobuf::RepeatedField::new())
+    }
+}
+
+impl ::protobuf::Message for CredentialRoster {
+    fn is_initialized(&self) -> bool {
+        for v in &self.entry {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.entry)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        for value in &self.entry {
+            let len = value.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        };
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
+    }
+
+    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        for v in &self.entry {
+            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        };
+        os.write_unknown_fields(self.get_unknown_fields())?;
+        ::std::result::Result::Ok(())
+    }
+
+    fn get_cached_size(&self) -> u32 {
+        self.cached_size.get()
+    }
+
+    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
+        &self.unknown_fields
+    }
+
+    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
+        &mut self.unknown_fields
+    }
+
+    fn as_any(&self) -> &dyn (::std::any::Any) {
+        self as &dyn (::std::any::Any)
+    }
+    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
+        self as &mut dyn (::std::any::Any)
+    }
+    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
+        self
+    }
+
+    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
+        Self::descriptor_static()
+    }
+
+    fn new() -> CredentialRoster {
+        CredentialRoster::new()
+    }
+
+    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            let mut fields = ::std::vec::Vec::new();
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CredentialRosterEntry>>(
+                "entry",
+                |m: &CredentialRoster| { &m.entry },
+                |m: &mut CredentialRoster| { &mut m.entry },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CredentialRoster>(
+                "CredentialRoster",
+                fields,
+                file_descriptor_proto()
+            )
+        })
+    }
+
+    fn default_instance() -> &'static CredentialRoster {
+        static instance: ::protobuf::rt::LazyV2<CredentialRoster> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CredentialRoster::new)
+    }
+}
+
+impl ::protobuf::Clear for CredentialRoster {
+    fn clear(&mut self) {
+        self.entry.clear();
+        self.unknown_fields.clear();
+    }
+}
+
+impl ::std::fmt::Debug for CredentialRoster {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
+    }
+}
+
+impl ::protobuf::reflect::ProtobufValue for CredentialRoster {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
+    }
+}
+
+#[derive(PartialEq,Clone,Default)]
+pub struct CredentialProof {
+    // message fields
+    pub challenge: ::std::vec::Vec<u8>,
+    pub response_credential: ::std::vec::Vec<u8>,
+    pub response_blinding: ::std::vec::Vec<u8>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CredentialProof {
+    fn default() -> &'a CredentialProof {
+        <CredentialProof as ::protobuf::Message>::default_instance()
+    }
+}
+
+impl CredentialProof {
+    pub fn new() -> CredentialProof {
+        ::std::default::Default::default()
+    }
+
+    // bytes challenge = 1;
+
+
+    pub fn get_challenge(&self) -> &[u8] {
+        &self.challenge
+    }
+    pub fn clear_challenge(&mut self) {
+        self.challenge.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_challenge(&mut self, v: ::std::vec::Vec<u8>) {
+        self.challenge = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_challenge(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.challenge
+    }
+
+    // Take field
+    pub fn take_challenge(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.challenge, ::std::vec::Vec::new())
+    }
+
+    // bytes response_credential = 2;
+
+
+    pub fn get_response_credential(&self) -> &[u8] {
+        &self.response_credential
+    }
+    pub fn clear_response_credential(&mut self) {
+        self.response_credential.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_response_credential(&mut self, v: ::std::vec::Vec<u8>) {
+        self.response_credential = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_response_credential(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.response_credential
+    }
+
+    // Take field
+    pub fn take_response_credential(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.response_credential, ::std::vec::Vec::new())
+    }
+
+    // bytes response_blinding = 3;
+
+
+    pub fn get_response_blinding(&self) -> &[u8] {
+        &self.response_blinding
+    }
+    pub fn clear_response_blinding(&mut self) {
+        self.response_blinding.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_response_blinding(&mut self, v: ::std::vec::Vec<u8>) {
+        self.response_blinding = v;
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_response_blinding(&mut self) -> &mut ::std::vec::Vec<u8> {
+        &mut self.response_blinding
+    }
+
+    // Take field
+    pub fn take_response_blinding(&mut self) -> ::std::vec::Vec<u8> {
+        ::std::mem::replace(&mut self.response_blinding, ::std::vec::Vec::new())
+    }
+}
+
+impl ::protobuf::Message for CredentialProof {
+    fn is_initialized(&self) -> bool {
+        true
+    }
+
+    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
+        while !is.eof()? {
+            let (field_number, wire_type) = is.read_tag_unpack()?;
+            match field_number {
+                1 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.challenge)?;
+                },
+                2 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.response_credential)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_proto3_bytes_into(wire_type, is, &mut self.response_blinding)?;
+                },
+                _ => {
+                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
+                },
+            };
+        }
+        ::std::result::Result::Ok(())
+    }
+
+    // Compute sizes of nested messages
+    #[allow(unused_variables)]
+    fn compute_size(&self) -> u32 {
+        let mut my_size = 0;
+        if !self.challenge.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(1, &self.challenge);
+        }
+        if !self.response_credential.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(2, &self.response_credential);
+        }
+        if !self.response_blinding.is_empty() {
+            my_size += ::protobuf::rt::bytes_size(3, &self.response_blinding);
+        }
+        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
+        self.cached_size.set(my_size);
+        my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.key.is_empty() {
-            os.write_string(1, &self.key)?;
+        if !self.challenge.is_empty() {
+            os.write_bytes(1, &self.challenge)?;
         }
-        if let Some(ref v) = self.value.as_ref() {
-            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if !self.response_credential.is_empty() {
+            os.write_bytes(2, &self.response_credential)?;
+        }
+        if !self.response_blinding.is_empty() {
+            os.write_bytes(3, &self.response_blinding)?;
         }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -5106,177 +19267,166 @@ impl ::protobuf::Message for StringToCountingPartPair {
         Self::descriptor_static()
     }
 
-    fn new() -> StringToCountingPartPair {
-        StringToCountingPartPair::new()
+    fn new() -> CredentialProof {
+        CredentialProof::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "key",
-                |m: &StringToCountingPartPair| { &m.key },
-                |m: &mut StringToCountingPartPair| { &mut m.key },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "challenge",
+                |m: &CredentialProof| { &m.challenge },
+                |m: &mut CredentialProof| { &mut m.challenge },
             ));
-            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CountingPart>>(
-                "value",
-                |m: &StringToCountingPartPair| { &m.value },
-                |m: &mut StringToCountingPartPair| { &mut m.value },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "response_credential",
+                |m: &CredentialProof| { &m.response_credential },
+                |m: &mut CredentialProof| { &mut m.response_credential },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<StringToCountingPartPair>(
-                "StringToCountingPartPair",
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "response_blinding",
+                |m: &CredentialProof| { &m.response_blinding },
+                |m: &mut CredentialProof| { &mut m.response_blinding },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CredentialProof>(
+                "CredentialProof",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static StringToCountingPartPair {
-        static instance: ::protobuf::rt::LazyV2<StringToCountingPartPair> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(StringToCountingPartPair::new)
+    fn default_instance() -> &'static CredentialProof {
+        static instance: ::protobuf::rt::LazyV2<CredentialProof> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CredentialProof::new)
     }
 }
 
-impl ::protobuf::Clear for StringToCountingPartPair {
+impl ::protobuf::Clear for CredentialProof {
     fn clear(&mut self) {
-        self.key.clear();
-        self.value.clear();
+        self.challenge.clear();
+        self.response_credential.clear();
+        self.response_blinding.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for StringToCountingPartPair {
+impl ::std::fmt::Debug for CredentialProof {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for StringToCountingPartPair {
+impl ::protobuf::reflect::ProtobufValue for CredentialProof {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct DecryptedResultPartStorage {
+pub struct CredentialBlindingPart {
     // message fields
-    pub blank_part: ::protobuf::SingularPtrField<CountingPart>,
-    pub candidate_part: ::protobuf::RepeatedField<StringToCountingPartPair>,
-    pub unlisted_candidate_part: ::protobuf::RepeatedField<UnlistedBallotDecryptedResult>,
+    pub counter_id: ::std::string::String,
+    pub blinded_difference: ::protobuf::RepeatedField<Ballot>,
+    pub equality_proof: ::protobuf::RepeatedField<::std::vec::Vec<u8>>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a DecryptedResultPartStorage {
-    fn default() -> &'a DecryptedResultPartStorage {
-        <DecryptedResultPartStorage as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a CredentialBlindingPart {
+    fn default() -> &'a CredentialBlindingPart {
+        <CredentialBlindingPart as ::protobuf::Message>::default_instance()
     }
 }
 
-impl DecryptedResultPartStorage {
-    pub fn new() -> DecryptedResultPartStorage {
+impl CredentialBlindingPart {
+    pub fn new() -> CredentialBlindingPart {
         ::std::default::Default::default()
     }
 
-    // .com.webank.wedpr.acv.proto.CountingPart blank_part = 1;
+    // string counter_id = 1;
 
 
-    pub fn get_blank_part(&self) -> &CountingPart {
-        self.blank_part.as_ref().unwrap_or_else(|| <CountingPart as ::protobuf::Message>::default_instance())
-    }
-    pub fn clear_blank_part(&mut self) {
-        self.blank_part.clear();
+    pub fn get_counter_id(&self) -> &str {
+        &self.counter_id
     }
-
-    pub fn has_blank_part(&self) -> bool {
-        self.blank_part.is_some()
+    pub fn clear_counter_id(&mut self) {
+        self.counter_id.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_blank_part(&mut self, v: CountingPart) {
-        self.blank_part = ::protobuf::SingularPtrField::some(v);
+    pub fn set_counter_id(&mut self, v: ::std::string::String) {
+        self.counter_id = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_blank_part(&mut self) -> &mut CountingPart {
-        if self.blank_part.is_none() {
-            self.blank_part.set_default();
-        }
-        self.blank_part.as_mut().unwrap()
+    pub fn mut_counter_id(&mut self) -> &mut ::std::string::String {
+        &mut self.counter_id
     }
 
     // Take field
-    pub fn take_blank_part(&mut self) -> CountingPart {
-        self.blank_part.take().unwrap_or_else(|| CountingPart::new())
+    pub fn take_counter_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.counter_id, ::std::string::String::new())
     }
 
-    // repeated .com.webank.wedpr.acv.proto.StringToCountingPartPair candidate_part = 2;
+    // repeated .com.webank.wedpr.acv.proto.Ballot blinded_difference = 2;
 
 
-    pub fn get_candidate_part(&self) -> &[StringToCountingPartPair] {
-        &self.candidate_part
+    pub fn get_blinded_difference(&self) -> &[Ballot] {
+        &self.blinded_difference
     }
-    pub fn clear_candidate_part(&mut self) {
-        self.candidate_part.clear();
+    pub fn clear_blinded_difference(&mut self) {
+        self.blinded_difference.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_candidate_part(&mut self, v: ::protobuf::RepeatedField<StringToCountingPartPair>) {
-        self.candidate_part = v;
+    pub fn set_blinded_difference(&mut self, v: ::protobuf::RepeatedField<Ballot>) {
+        self.blinded_difference = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_candidate_part(&mut self) -> &mut ::protobuf::RepeatedField<StringToCountingPartPair> {
-        &mut self.candidate_part
+    pub fn mut_blinded_difference(&mut self) -> &mut ::protobuf::RepeatedField<Ballot> {
+        &mut self.blinded_difference
     }
 
     // Take field
-    pub fn take_candidate_part(&mut self) -> ::protobuf::RepeatedField<StringToCountingPartPair> {
-        ::std::mem::replace(&mut self.candidate_part, ::protobuf::RepeatedField::new())
+    pub fn take_blinded_difference(&mut self) -> ::protobuf::RepeatedField<Ballot> {
+        ::std::mem::replace(&mut self.blinded_difference, ::protobuf::RepeatedField::new())
     }
 
-    // repeated .com.webank.wedpr.acv.proto.UnlistedBallotDecryptedResult unlisted_candidate_part = 3;
+    // repeated bytes equality_proof = 3;
 
 
-    pub fn get_unlisted_candidate_part(&self) -> &[UnlistedBallotDecryptedResult] {
-        &self.unlisted_candidate_part
+    pub fn get_equality_proof(&self) -> &[::std::vec::Vec<u8>] {
+        &self.equality_proof
     }
-    pub fn clear_unlisted_candidate_part(&mut self) {
-        self.unlisted_candidate_part.clear();
+    pub fn clear_equality_proof(&mut self) {
+        self.equality_proof.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_unlisted_candidate_part(&mut self, v: ::protobuf::RepeatedField<UnlistedBallotDecryptedResult>) {
-        self.unlisted_candidate_part = v;
+    pub fn set_equality_proof(&mut self, v: ::protobuf::RepeatedField<::std::vec::Vec<u8>>) {
+        self.equality_proof = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_unlisted_candidate_part(&mut self) -> &mut ::protobuf::RepeatedField<UnlistedBallotDecryptedResult> {
-        &mut self.unlisted_candidate_part
+    pub fn mut_equality_proof(&mut self) -> &mut ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        &mut self.equality_proof
     }
 
     // Take field
-    pub fn take_unlisted_candidate_part(&mut self) -> ::protobuf::RepeatedField<UnlistedBallotDecryptedResult> {
-        ::std::mem::replace(&mut self.unlisted_candidate_part, ::protobuf::RepeatedField::new())
+    pub fn take_equality_proof(&mut self) -> ::protobuf::RepeatedField<::std::vec::Vec<u8>> {
+        ::std::mem::replace(&mut self.equality_proof, ::protobuf::RepeatedField::new())
     }
 }
 
-impl ::protobuf::Message for DecryptedResultPartStorage {
+impl ::protobuf::Message for CredentialBlindingPart {
     fn is_initialized(&self) -> bool {
-        for v in &self.blank_part {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.candidate_part {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.unlisted_candidate_part {
+        for v in &self.blinded_difference {
             if !v.is_initialized() {
                 return false;
             }
@@ -5289,13 +19439,13 @@ impl ::protobuf::Message for DecryptedResultPartStorage {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.blank_part)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.counter_id)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.candidate_part)?;
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.blinded_difference)?;
                 },
                 3 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.unlisted_candidate_part)?;
+                    ::protobuf::rt::read_repeated_bytes_into(wire_type, is, &mut self.equality_proof)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -5309,17 +19459,15 @@ impl ::protobuf::Message for DecryptedResultPartStorage {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if let Some(ref v) = self.blank_part.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        if !self.counter_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.counter_id);
         }
-        for value in &self.candidate_part {
+        for value in &self.blinded_difference {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
-        for value in &self.unlisted_candidate_part {
-            let len = value.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        for value in &self.equality_proof {
+            my_size += ::protobuf::rt::bytes_size(3, &value);
         };
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
@@ -5327,20 +19475,16 @@ impl ::protobuf::Message for DecryptedResultPartStorage {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if let Some(ref v) = self.blank_part.as_ref() {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        if !self.counter_id.is_empty() {
+            os.write_string(1, &self.counter_id)?;
         }
-        for v in &self.candidate_part {
+        for v in &self.blinded_difference {
             os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         };
-        for v in &self.unlisted_candidate_part {
-            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
+        for v in &self.equality_proof {
+            os.write_bytes(3, &v)?;
         };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -5372,207 +19516,140 @@ impl ::protobuf::Message for DecryptedResultPartStorage {
         Self::descriptor_static()
     }
 
-    fn new() -> DecryptedResultPartStorage {
-        DecryptedResultPartStorage::new()
+    fn new() -> CredentialBlindingPart {
+        CredentialBlindingPart::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CountingPart>>(
-                "blank_part",
-                |m: &DecryptedResultPartStorage| { &m.blank_part },
-                |m: &mut DecryptedResultPartStorage| { &mut m.blank_part },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "counter_id",
+                |m: &CredentialBlindingPart| { &m.counter_id },
+                |m: &mut CredentialBlindingPart| { &mut m.counter_id },
             ));
-            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<StringToCountingPartPair>>(
-                "candidate_part",
-                |m: &DecryptedResultPartStorage| { &m.candidate_part },
-                |m: &mut DecryptedResultPartStorage| { &mut m.candidate_part },
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<Ballot>>(
+                "blinded_difference",
+                |m: &CredentialBlindingPart| { &m.blinded_difference },
+                |m: &mut CredentialBlindingPart| { &mut m.blinded_difference },
             ));
-            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UnlistedBallotDecryptedResult>>(
-                "unlisted_candidate_part",
-                |m: &DecryptedResultPartStorage| { &m.unlisted_candidate_part },
-                |m: &mut DecryptedResultPartStorage| { &mut m.unlisted_candidate_part },
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeBytes>(
+                "equality_proof",
+                |m: &CredentialBlindingPart| { &m.equality_proof },
+                |m: &mut CredentialBlindingPart| { &mut m.equality_proof },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<DecryptedResultPartStorage>(
-                "DecryptedResultPartStorage",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CredentialBlindingPart>(
+                "CredentialBlindingPart",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static DecryptedResultPartStorage {
-        static instance: ::protobuf::rt::LazyV2<DecryptedResultPartStorage> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(DecryptedResultPartStorage::new)
-    }
-}
-
-impl ::protobuf::Clear for DecryptedResultPartStorage {
-    fn clear(&mut self) {
-        self.blank_part.clear();
-        self.candidate_part.clear();
-        self.unlisted_candidate_part.clear();
-        self.unknown_fields.clear();
-    }
-}
-
-impl ::std::fmt::Debug for DecryptedResultPartStorage {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
-    }
-}
-
-impl ::protobuf::reflect::ProtobufValue for DecryptedResultPartStorage {
-    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
-        ::protobuf::reflect::ReflectValueRef::Message(self)
-    }
-}
-
-#[derive(PartialEq,Clone,Default)]
-pub struct UnlistedBallotDecryptedResult {
-    // message fields
-    pub candidate: i64,
-    pub candidate_cipher: ::protobuf::SingularPtrField<CipherPoints>,
-    pub decrypted_unlisted_candidate: ::protobuf::SingularPtrField<CountingPart>,
-    pub decrypted_unlisted_candidate_ballot: ::protobuf::RepeatedField<CountingPart>,
-    // special fields
-    pub unknown_fields: ::protobuf::UnknownFields,
-    pub cached_size: ::protobuf::CachedSize,
-}
-
-impl<'a> ::std::default::Default for &'a UnlistedBallotDecryptedResult {
-    fn default() -> &'a UnlistedBallotDecryptedResult {
-        <UnlistedBallotDecryptedResult as ::protobuf::Message>::default_instance()
-    }
-}
-
-impl UnlistedBallotDecryptedResult {
-    pub fn new() -> UnlistedBallotDecryptedResult {
-        ::std::default::Default::default()
-    }
-
-    // int64 candidate = 1;
-
-
-    pub fn get_candidate(&self) -> i64 {
-        self.candidate
-    }
-    pub fn clear_candidate(&mut self) {
-        self.candidate = 0;
-    }
-
-    // Param is passed by value, moved
-    pub fn set_candidate(&mut self, v: i64) {
-        self.candidate = v;
+    fn default_instance() -> &'static CredentialBlindingPart {
+        static instance: ::protobuf::rt::LazyV2<CredentialBlindingPart> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CredentialBlindingPart::new)
     }
+}
 
-    // .com.webank.wedpr.acv.proto.CipherPoints candidate_cipher = 2;
-
-
-    pub fn get_candidate_cipher(&self) -> &CipherPoints {
-        self.candidate_cipher.as_ref().unwrap_or_else(|| <CipherPoints as ::protobuf::Message>::default_instance())
-    }
-    pub fn clear_candidate_cipher(&mut self) {
-        self.candidate_cipher.clear();
+impl ::protobuf::Clear for CredentialBlindingPart {
+    fn clear(&mut self) {
+        self.counter_id.clear();
+        self.blinded_difference.clear();
+        self.equality_proof.clear();
+        self.unknown_fields.clear();
     }
+}
 
-    pub fn has_candidate_cipher(&self) -> bool {
-        self.candidate_cipher.is_some()
+impl ::std::fmt::Debug for CredentialBlindingPart {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        ::protobuf::text_format::fmt(self, f)
     }
+}
 
-    // Param is passed by value, moved
-    pub fn set_candidate_cipher(&mut self, v: CipherPoints) {
-        self.candidate_cipher = ::protobuf::SingularPtrField::some(v);
+impl ::protobuf::reflect::ProtobufValue for CredentialBlindingPart {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Message(self)
     }
+}
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_candidate_cipher(&mut self) -> &mut CipherPoints {
-        if self.candidate_cipher.is_none() {
-            self.candidate_cipher.set_default();
-        }
-        self.candidate_cipher.as_mut().unwrap()
+#[derive(PartialEq,Clone,Default)]
+pub struct CredentialDecryptionPart {
+    // message fields
+    pub counter_id: ::std::string::String,
+    pub decrypted_part: ::protobuf::RepeatedField<CountingPart>,
+    // special fields
+    pub unknown_fields: ::protobuf::UnknownFields,
+    pub cached_size: ::protobuf::CachedSize,
+}
+
+impl<'a> ::std::default::Default for &'a CredentialDecryptionPart {
+    fn default() -> &'a CredentialDecryptionPart {
+        <CredentialDecryptionPart as ::protobuf::Message>::default_instance()
     }
+}
 
-    // Take field
-    pub fn take_candidate_cipher(&mut self) -> CipherPoints {
-        self.candidate_cipher.take().unwrap_or_else(|| CipherPoints::new())
+impl CredentialDecryptionPart {
+    pub fn new() -> CredentialDecryptionPart {
+        ::std::default::Default::default()
     }
 
-    // .com.webank.wedpr.acv.proto.CountingPart decrypted_unlisted_candidate = 4;
+    // string counter_id = 1;
 
 
-    pub fn get_decrypted_unlisted_candidate(&self) -> &CountingPart {
-        self.decrypted_unlisted_candidate.as_ref().unwrap_or_else(|| <CountingPart as ::protobuf::Message>::default_instance())
-    }
-    pub fn clear_decrypted_unlisted_candidate(&mut self) {
-        self.decrypted_unlisted_candidate.clear();
+    pub fn get_counter_id(&self) -> &str {
+        &self.counter_id
     }
-
-    pub fn has_decrypted_unlisted_candidate(&self) -> bool {
-        self.decrypted_unlisted_candidate.is_some()
+    pub fn clear_counter_id(&mut self) {
+        self.counter_id.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_decrypted_unlisted_candidate(&mut self, v: CountingPart) {
-        self.decrypted_unlisted_candidate = ::protobuf::SingularPtrField::some(v);
+    pub fn set_counter_id(&mut self, v: ::std::string::String) {
+        self.counter_id = v;
     }
 
     // Mutable pointer to the field.
     // If field is not initialized, it is initialized with default value first.
-    pub fn mut_decrypted_unlisted_candidate(&mut self) -> &mut CountingPart {
-        if self.decrypted_unlisted_candidate.is_none() {
-            self.decrypted_unlisted_candidate.set_default();
-        }
-        self.decrypted_unlisted_candidate.as_mut().unwrap()
+    pub fn mut_counter_id(&mut self) -> &mut ::std::string::String {
+        &mut self.counter_id
     }
 
     // Take field
-    pub fn take_decrypted_unlisted_candidate(&mut self) -> CountingPart {
-        self.decrypted_unlisted_candidate.take().unwrap_or_else(|| CountingPart::new())
+    pub fn take_counter_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.counter_id, ::std::string::String::new())
     }
 
-    // repeated .com.webank.wedpr.acv.proto.CountingPart decrypted_unlisted_candidate_ballot = 5;
+    // repeated .com.webank.wedpr.acv.proto.CountingPart decrypted_part = 2;
 
 
-    pub fn get_decrypted_unlisted_candidate_ballot(&self) -> &[CountingPart] {
-        &self.decrypted_unlisted_candidate_ballot
+    pub fn get_decrypted_part(&self) -> &[CountingPart] {
+        &self.decrypted_part
     }
-    pub fn clear_decrypted_unlisted_candidate_ballot(&mut self) {
-        self.decrypted_unlisted_candidate_ballot.clear();
+    pub fn clear_decrypted_part(&mut self) {
+        self.decrypted_part.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_decrypted_unlisted_candidate_ballot(&mut self, v: ::protobuf::RepeatedField<CountingPart>) {
-        self.decrypted_unlisted_candidate_ballot = v;
+    pub fn set_decrypted_part(&mut self, v: ::protobuf::RepeatedField<CountingPart>) {
+        self.decrypted_part = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_decrypted_unlisted_candidate_ballot(&mut self) -> &mut ::protobuf::RepeatedField<CountingPart> {
-        &mut self.decrypted_unlisted_candidate_ballot
+    pub fn mut_decrypted_part(&mut self) -> &mut ::protobuf::RepeatedField<CountingPart> {
+        &mut self.decrypted_part
     }
 
     // Take field
-    pub fn take_decrypted_unlisted_candidate_ballot(&mut self) -> ::protobuf::RepeatedField<CountingPart> {
-        ::std::mem::replace(&mut self.decrypted_unlisted_candidate_ballot, ::protobuf::RepeatedField::new())
+    pub fn take_decrypted_part(&mut self) -> ::protobuf::RepeatedField<CountingPart> {
+        ::std::mem::replace(&mut self.decrypted_part, ::protobuf::RepeatedField::new())
     }
 }
 
-impl ::protobuf::Message for UnlistedBallotDecryptedResult {
+impl ::protobuf::Message for CredentialDecryptionPart {
     fn is_initialized(&self) -> bool {
-        for v in &self.candidate_cipher {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.decrypted_unlisted_candidate {
-            if !v.is_initialized() {
-                return false;
-            }
-        };
-        for v in &self.decrypted_unlisted_candidate_ballot {
+        for v in &self.decrypted_part {
             if !v.is_initialized() {
                 return false;
             }
@@ -5585,20 +19662,10 @@ impl ::protobuf::Message for UnlistedBallotDecryptedResult {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.candidate = tmp;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.counter_id)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.candidate_cipher)?;
-                },
-                4 => {
-                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.decrypted_unlisted_candidate)?;
-                },
-                5 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.decrypted_unlisted_candidate_ballot)?;
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.decrypted_part)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -5612,18 +19679,10 @@ impl ::protobuf::Message for UnlistedBallotDecryptedResult {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        if self.candidate != 0 {
-            my_size += ::protobuf::rt::value_size(1, self.candidate, ::protobuf::wire_format::WireTypeVarint);
-        }
-        if let Some(ref v) = self.candidate_cipher.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
-        }
-        if let Some(ref v) = self.decrypted_unlisted_candidate.as_ref() {
-            let len = v.compute_size();
-            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        if !self.counter_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.counter_id);
         }
-        for value in &self.decrypted_unlisted_candidate_ballot {
+        for value in &self.decrypted_part {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
@@ -5633,23 +19692,13 @@ impl ::protobuf::Message for UnlistedBallotDecryptedResult {
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if self.candidate != 0 {
-            os.write_int64(1, self.candidate)?;
+        if !self.counter_id.is_empty() {
+            os.write_string(1, &self.counter_id)?;
         }
-        if let Some(ref v) = self.candidate_cipher.as_ref() {
+        for v in &self.decrypted_part {
             os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
-        }
-        if let Some(ref v) = self.decrypted_unlisted_candidate.as_ref() {
-            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
-        }
-        for v in &self.decrypted_unlisted_candidate_ballot {
-            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
-            os.write_raw_varint32(v.get_cached_size())?;
-            v.write_to_with_cached_sizes(os)?;
         };
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
@@ -5681,150 +19730,243 @@ impl ::protobuf::Message for UnlistedBallotDecryptedResult {
         Self::descriptor_static()
     }
 
-    fn new() -> UnlistedBallotDecryptedResult {
-        UnlistedBallotDecryptedResult::new()
+    fn new() -> CredentialDecryptionPart {
+        CredentialDecryptionPart::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "candidate",
-                |m: &UnlistedBallotDecryptedResult| { &m.candidate },
-                |m: &mut UnlistedBallotDecryptedResult| { &mut m.candidate },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CipherPoints>>(
-                "candidate_cipher",
-                |m: &UnlistedBallotDecryptedResult| { &m.candidate_cipher },
-                |m: &mut UnlistedBallotDecryptedResult| { &mut m.candidate_cipher },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CountingPart>>(
-                "decrypted_unlisted_candidate",
-                |m: &UnlistedBallotDecryptedResult| { &m.decrypted_unlisted_candidate },
-                |m: &mut UnlistedBallotDecryptedResult| { &mut m.decrypted_unlisted_candidate },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "counter_id",
+                |m: &CredentialDecryptionPart| { &m.counter_id },
+                |m: &mut CredentialDecryptionPart| { &mut m.counter_id },
             ));
             fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<CountingPart>>(
-                "decrypted_unlisted_candidate_ballot",
-                |m: &UnlistedBallotDecryptedResult| { &m.decrypted_unlisted_candidate_ballot },
-                |m: &mut UnlistedBallotDecryptedResult| { &mut m.decrypted_unlisted_candidate_ballot },
+                "decrypted_part",
+                |m: &CredentialDecryptionPart| { &m.decrypted_part },
+                |m: &mut CredentialDecryptionPart| { &mut m.decrypted_part },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<UnlistedBallotDecryptedResult>(
-                "UnlistedBallotDecryptedResult",
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CredentialDecryptionPart>(
+                "CredentialDecryptionPart",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static UnlistedBallotDecryptedResult {
-        static instance: ::protobuf::rt::LazyV2<UnlistedBallotDecryptedResult> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(UnlistedBallotDecryptedResult::new)
+    fn default_instance() -> &'static CredentialDecryptionPart {
+        static instance: ::protobuf::rt::LazyV2<CredentialDecryptionPart> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CredentialDecryptionPart::new)
     }
 }
 
-impl ::protobuf::Clear for UnlistedBallotDecryptedResult {
+impl ::protobuf::Clear for CredentialDecryptionPart {
     fn clear(&mut self) {
-        self.candidate = 0;
-        self.candidate_cipher.clear();
-        self.decrypted_unlisted_candidate.clear();
-        self.decrypted_unlisted_candidate_ballot.clear();
+        self.counter_id.clear();
+        self.decrypted_part.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for UnlistedBallotDecryptedResult {
+impl ::std::fmt::Debug for CredentialDecryptionPart {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for UnlistedBallotDecryptedResult {
+impl ::protobuf::reflect::ProtobufValue for CredentialDecryptionPart {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
 #[derive(PartialEq,Clone,Default)]
-pub struct VoteResultStorage {
+pub struct CredentialMixPart {
     // message fields
-    pub result: ::protobuf::RepeatedField<StringToInt64Pair>,
-    pub unlisted_result: ::protobuf::RepeatedField<UnlistedVoteChoice>,
+    pub counter_id: ::std::string::String,
+    pub vote_row: ::protobuf::RepeatedField<BallotRow>,
+    pub vote_shuffle_proof: ::protobuf::SingularPtrField<ShuffleProof>,
+    pub roster_row: ::protobuf::RepeatedField<BallotRow>,
+    pub roster_shuffle_proof: ::protobuf::SingularPtrField<ShuffleProof>,
     // special fields
     pub unknown_fields: ::protobuf::UnknownFields,
     pub cached_size: ::protobuf::CachedSize,
 }
 
-impl<'a> ::std::default::Default for &'a VoteResultStorage {
-    fn default() -> &'a VoteResultStorage {
-        <VoteResultStorage as ::protobuf::Message>::default_instance()
+impl<'a> ::std::default::Default for &'a CredentialMixPart {
+    fn default() -> &'a CredentialMixPart {
+        <CredentialMixPart as ::protobuf::Message>::default_instance()
     }
 }
 
-impl VoteResultStorage {
-    pub fn new() -> VoteResultStorage {
+impl CredentialMixPart {
+    pub fn new() -> CredentialMixPart {
         ::std::default::Default::default()
     }
 
-    // repeated .com.webank.wedpr.acv.proto.StringToInt64Pair result = 1;
+    // string counter_id = 1;
 
 
-    pub fn get_result(&self) -> &[StringToInt64Pair] {
-        &self.result
+    pub fn get_counter_id(&self) -> &str {
+        &self.counter_id
     }
-    pub fn clear_result(&mut self) {
-        self.result.clear();
+    pub fn clear_counter_id(&mut self) {
+        self.counter_id.clear();
     }
 
     // Param is passed by value, moved
-    pub fn set_result(&mut self, v: ::protobuf::RepeatedField<StringToInt64Pair>) {
-        self.result = v;
+    pub fn set_counter_id(&mut self, v: ::std::string::String) {
+        self.counter_id = v;
     }
 
     // Mutable pointer to the field.
-    pub fn mut_result(&mut self) -> &mut ::protobuf::RepeatedField<StringToInt64Pair> {
-        &mut self.result
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_counter_id(&mut self) -> &mut ::std::string::String {
+        &mut self.counter_id
     }
 
     // Take field
-    pub fn take_result(&mut self) -> ::protobuf::RepeatedField<StringToInt64Pair> {
-        ::std::mem::replace(&mut self.result, ::protobuf::RepeatedField::new())
+    pub fn take_counter_id(&mut self) -> ::std::string::String {
+        ::std::mem::replace(&mut self.counter_id, ::std::string::String::new())
     }
 
-    // repeated .com.webank.wedpr.acv.proto.UnlistedVoteChoice unlisted_result = 2;
+    // repeated .com.webank.wedpr.acv.proto.BallotRow vote_row = 2;
 
 
-    pub fn get_unlisted_result(&self) -> &[UnlistedVoteChoice] {
-        &self.unlisted_result
+    pub fn get_vote_row(&self) -> &[BallotRow] {
+        &self.vote_row
+    }
+    pub fn clear_vote_row(&mut self) {
+        self.vote_row.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vote_row(&mut self, v: ::protobuf::RepeatedField<BallotRow>) {
+        self.vote_row = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_vote_row(&mut self) -> &mut ::protobuf::RepeatedField<BallotRow> {
+        &mut self.vote_row
+    }
+
+    // Take field
+    pub fn take_vote_row(&mut self) -> ::protobuf::RepeatedField<BallotRow> {
+        ::std::mem::replace(&mut self.vote_row, ::protobuf::RepeatedField::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.ShuffleProof vote_shuffle_proof = 3;
+
+
+    pub fn get_vote_shuffle_proof(&self) -> &ShuffleProof {
+        self.vote_shuffle_proof.as_ref().unwrap_or_else(|| <ShuffleProof as ::protobuf::Message>::default_instance())
+    }
+    pub fn clear_vote_shuffle_proof(&mut self) {
+        self.vote_shuffle_proof.clear();
+    }
+
+    pub fn has_vote_shuffle_proof(&self) -> bool {
+        self.vote_shuffle_proof.is_some()
+    }
+
+    // Param is passed by value, moved
+    pub fn set_vote_shuffle_proof(&mut self, v: ShuffleProof) {
+        self.vote_shuffle_proof = ::protobuf::SingularPtrField::some(v);
+    }
+
+    // Mutable pointer to the field.
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_vote_shuffle_proof(&mut self) -> &mut ShuffleProof {
+        if self.vote_shuffle_proof.is_none() {
+            self.vote_shuffle_proof.set_default();
+        }
+        self.vote_shuffle_proof.as_mut().unwrap()
+    }
+
+    // Take field
+    pub fn take_vote_shuffle_proof(&mut self) -> ShuffleProof {
+        self.vote_shuffle_proof.take().unwrap_or_else(|| ShuffleProof::new())
+    }
+
+    // repeated .com.webank.wedpr.acv.proto.BallotRow roster_row = 4;
+
+
+    pub fn get_roster_row(&self) -> &[BallotRow] {
+        &self.roster_row
+    }
+    pub fn clear_roster_row(&mut self) {
+        self.roster_row.clear();
+    }
+
+    // Param is passed by value, moved
+    pub fn set_roster_row(&mut self, v: ::protobuf::RepeatedField<BallotRow>) {
+        self.roster_row = v;
+    }
+
+    // Mutable pointer to the field.
+    pub fn mut_roster_row(&mut self) -> &mut ::protobuf::RepeatedField<BallotRow> {
+        &mut self.roster_row
+    }
+
+    // Take field
+    pub fn take_roster_row(&mut self) -> ::protobuf::RepeatedField<BallotRow> {
+        ::std::mem::replace(&mut self.roster_row, ::protobuf::RepeatedField::new())
+    }
+
+    // .com.webank.wedpr.acv.proto.ShuffleProof roster_shuffle_proof = 5;
+
+
+    pub fn get_roster_shuffle_proof(&self) -> &ShuffleProof {
+        self.roster_shuffle_proof.as_ref().unwrap_or_else(|| <ShuffleProof as ::protobuf::Message>::default_instance())
     }
-    pub fn clear_unlisted_result(&mut self) {
-        self.unlisted_result.clear();
+    pub fn clear_roster_shuffle_proof(&mut self) {
+        self.roster_shuffle_proof.clear();
+    }
+
+    pub fn has_roster_shuffle_proof(&self) -> bool {
+        self.roster_shuffle_proof.is_some()
     }
 
     // Param is passed by value, moved
-    pub fn set_unlisted_result(&mut self, v: ::protobuf::RepeatedField<UnlistedVoteChoice>) {
-        self.unlisted_result = v;
+    pub fn set_roster_shuffle_proof(&mut self, v: ShuffleProof) {
+        self.roster_shuffle_proof = ::protobuf::SingularPtrField::some(v);
     }
 
     // Mutable pointer to the field.
-    pub fn mut_unlisted_result(&mut self) -> &mut ::protobuf::RepeatedField<UnlistedVoteChoice> {
-        &mut self.unlisted_result
+    // If field is not initialized, it is initialized with default value first.
+    pub fn mut_roster_shuffle_proof(&mut self) -> &mut ShuffleProof {
+        if self.roster_shuffle_proof.is_none() {
+            self.roster_shuffle_proof.set_default();
+        }
+        self.roster_shuffle_proof.as_mut().unwrap()
     }
 
     // Take field
-    pub fn take_unlisted_result(&mut self) -> ::protobuf::RepeatedField<UnlistedVoteChoice> {
-        ::std::mem::replace(&mut self.unlisted_result, ::protobuf::RepeatedField::new())
+    pub fn take_roster_shuffle_proof(&mut self) -> ShuffleProof {
+        self.roster_shuffle_proof.take().unwrap_or_else(|| ShuffleProof::new())
     }
 }
 
-impl ::protobuf::Message for VoteResultStorage {
+impl ::protobuf::Message for CredentialMixPart {
     fn is_initialized(&self) -> bool {
-        for v in &self.result {
+        for v in &self.vote_row {
             if !v.is_initialized() {
                 return false;
             }
         };
-        for v in &self.unlisted_result {
+        for v in &self.vote_shuffle_proof {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.roster_row {
+            if !v.is_initialized() {
+                return false;
+            }
+        };
+        for v in &self.roster_shuffle_proof {
             if !v.is_initialized() {
                 return false;
             }
@@ -5837,10 +19979,19 @@ impl ::protobuf::Message for VoteResultStorage {
             let (field_number, wire_type) = is.read_tag_unpack()?;
             match field_number {
                 1 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.result)?;
+                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.counter_id)?;
                 },
                 2 => {
-                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.unlisted_result)?;
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.vote_row)?;
+                },
+                3 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.vote_shuffle_proof)?;
+                },
+                4 => {
+                    ::protobuf::rt::read_repeated_message_into(wire_type, is, &mut self.roster_row)?;
+                },
+                5 => {
+                    ::protobuf::rt::read_singular_message_into(wire_type, is, &mut self.roster_shuffle_proof)?;
                 },
                 _ => {
                     ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
@@ -5854,30 +20005,54 @@ impl ::protobuf::Message for VoteResultStorage {
     #[allow(unused_variables)]
     fn compute_size(&self) -> u32 {
         let mut my_size = 0;
-        for value in &self.result {
+        if !self.counter_id.is_empty() {
+            my_size += ::protobuf::rt::string_size(1, &self.counter_id);
+        }
+        for value in &self.vote_row {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
-        for value in &self.unlisted_result {
+        if let Some(ref v) = self.vote_shuffle_proof.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
+        for value in &self.roster_row {
             let len = value.compute_size();
             my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
         };
+        if let Some(ref v) = self.roster_shuffle_proof.as_ref() {
+            let len = v.compute_size();
+            my_size += 1 + ::protobuf::rt::compute_raw_varint32_size(len) + len;
+        }
         my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
         self.cached_size.set(my_size);
         my_size
     }
 
     fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        for v in &self.result {
-            os.write_tag(1, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+        if !self.counter_id.is_empty() {
+            os.write_string(1, &self.counter_id)?;
+        }
+        for v in &self.vote_row {
+            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         };
-        for v in &self.unlisted_result {
-            os.write_tag(2, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+        if let Some(ref v) = self.vote_shuffle_proof.as_ref() {
+            os.write_tag(3, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
+        for v in &self.roster_row {
+            os.write_tag(4, ::protobuf::wire_format::WireTypeLengthDelimited)?;
             os.write_raw_varint32(v.get_cached_size())?;
             v.write_to_with_cached_sizes(os)?;
         };
+        if let Some(ref v) = self.roster_shuffle_proof.as_ref() {
+            os.write_tag(5, ::protobuf::wire_format::WireTypeLengthDelimited)?;
+            os.write_raw_varint32(v.get_cached_size())?;
+            v.write_to_with_cached_sizes(os)?;
+        }
         os.write_unknown_fields(self.get_unknown_fields())?;
         ::std::result::Result::Ok(())
     }
@@ -5908,338 +20083,654 @@ impl ::protobuf::Message for VoteResultStorage {
         Self::descriptor_static()
     }
 
-    fn new() -> VoteResultStorage {
-        VoteResultStorage::new()
+    fn new() -> CredentialMixPart {
+        CredentialMixPart::new()
     }
 
     fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
         static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
             let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<StringToInt64Pair>>(
-                "result",
-                |m: &VoteResultStorage| { &m.result },
-                |m: &mut VoteResultStorage| { &mut m.result },
+            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
+                "counter_id",
+                |m: &CredentialMixPart| { &m.counter_id },
+                |m: &mut CredentialMixPart| { &mut m.counter_id },
             ));
-            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<UnlistedVoteChoice>>(
-                "unlisted_result",
-                |m: &VoteResultStorage| { &m.unlisted_result },
-                |m: &mut VoteResultStorage| { &mut m.unlisted_result },
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BallotRow>>(
+                "vote_row",
+                |m: &CredentialMixPart| { &m.vote_row },
+                |m: &mut CredentialMixPart| { &mut m.vote_row },
             ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<VoteResultStorage>(
-                "VoteResultStorage",
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ShuffleProof>>(
+                "vote_shuffle_proof",
+                |m: &CredentialMixPart| { &m.vote_shuffle_proof },
+                |m: &mut CredentialMixPart| { &mut m.vote_shuffle_proof },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_repeated_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<BallotRow>>(
+                "roster_row",
+                |m: &CredentialMixPart| { &m.roster_row },
+                |m: &mut CredentialMixPart| { &mut m.roster_row },
+            ));
+            fields.push(::protobuf::reflect::accessor::make_singular_ptr_field_accessor::<_, ::protobuf::types::ProtobufTypeMessage<ShuffleProof>>(
+                "roster_shuffle_proof",
+                |m: &CredentialMixPart| { &m.roster_shuffle_proof },
+                |m: &mut CredentialMixPart| { &mut m.roster_shuffle_proof },
+            ));
+            ::protobuf::reflect::MessageDescriptor::new_pb_name::<CredentialMixPart>(
+                "CredentialMixPart",
                 fields,
                 file_descriptor_proto()
             )
         })
     }
 
-    fn default_instance() -> &'static VoteResultStorage {
-        static instance: ::protobuf::rt::LazyV2<VoteResultStorage> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(VoteResultStorage::new)
+    fn default_instance() -> &'static CredentialMixPart {
+        static instance: ::protobuf::rt::LazyV2<CredentialMixPart> = ::protobuf::rt::LazyV2::INIT;
+        instance.get(CredentialMixPart::new)
     }
 }
 
-impl ::protobuf::Clear for VoteResultStorage {
+impl ::protobuf::Clear for CredentialMixPart {
     fn clear(&mut self) {
-        self.result.clear();
-        self.unlisted_result.clear();
+        self.counter_id.clear();
+        self.vote_row.clear();
+        self.vote_shuffle_proof.clear();
+        self.roster_row.clear();
+        self.roster_shuffle_proof.clear();
         self.unknown_fields.clear();
     }
 }
 
-impl ::std::fmt::Debug for VoteResultStorage {
+impl ::std::fmt::Debug for CredentialMixPart {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         ::protobuf::text_format::fmt(self, f)
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for VoteResultStorage {
+impl ::protobuf::reflect::ProtobufValue for CredentialMixPart {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
         ::protobuf::reflect::ReflectValueRef::Message(self)
     }
 }
 
-#[derive(PartialEq,Clone,Default)]
-pub struct StringToInt64Pair {
-    // message fields
-    pub key: ::std::string::String,
-    pub value: i64,
-    // special fields
-    pub unknown_fields: ::protobuf::UnknownFields,
-    pub cached_size: ::protobuf::CachedSize,
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum SignatureScheme {
+    DEFAULT_SCHEME = 0,
+    SECP256K1_KECCAK256 = 1,
+    SM2_SM3 = 2,
 }
 
-impl<'a> ::std::default::Default for &'a StringToInt64Pair {
-    fn default() -> &'a StringToInt64Pair {
-        <StringToInt64Pair as ::protobuf::Message>::default_instance()
+impl ::protobuf::ProtobufEnum for SignatureScheme {
+    fn value(&self) -> i32 {
+        *self as i32
     }
-}
 
-impl StringToInt64Pair {
-    pub fn new() -> StringToInt64Pair {
-        ::std::default::Default::default()
+    fn from_i32(value: i32) -> ::std::option::Option<SignatureScheme> {
+        match value {
+            0 => ::std::option::Option::Some(SignatureScheme::DEFAULT_SCHEME),
+            1 => ::std::option::Option::Some(SignatureScheme::SECP256K1_KECCAK256),
+            2 => ::std::option::Option::Some(SignatureScheme::SM2_SM3),
+            _ => ::std::option::Option::None
+        }
     }
 
-    // string key = 1;
-
-
-    pub fn get_key(&self) -> &str {
-        &self.key
-    }
-    pub fn clear_key(&mut self) {
-        self.key.clear();
+    fn values() -> &'static [Self] {
+        static values: &'static [SignatureScheme] = &[
+            SignatureScheme::DEFAULT_SCHEME,
+            SignatureScheme::SECP256K1_KECCAK256,
+            SignatureScheme::SM2_SM3,
+        ];
+        values
     }
 
-    // Param is passed by value, moved
-    pub fn set_key(&mut self, v: ::std::string::String) {
-        self.key = v;
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<SignatureScheme>("SignatureScheme", file_descriptor_proto())
+        })
     }
+}
 
-    // Mutable pointer to the field.
-    // If field is not initialized, it is initialized with default value first.
-    pub fn mut_key(&mut self) -> &mut ::std::string::String {
-        &mut self.key
-    }
+impl ::std::marker::Copy for SignatureScheme {
+}
 
-    // Take field
-    pub fn take_key(&mut self) -> ::std::string::String {
-        ::std::mem::replace(&mut self.key, ::std::string::String::new())
+impl ::std::default::Default for SignatureScheme {
+    fn default() -> Self {
+        SignatureScheme::DEFAULT_SCHEME
     }
+}
 
-    // int64 value = 2;
+impl ::protobuf::reflect::ProtobufValue for SignatureScheme {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
+    }
+}
 
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum CounterBlameReason {
+    INVALID_EQUALITY_PROOF = 0,
+    MISSING_PART = 1,
+    MALFORMED_POINT = 2,
+    MALFORMED_PROOF = 3,
+    UNKNOWN_COUNTER = 4,
+    MISMATCHED_COUNTER = 5,
+    DUPLICATE_COUNTER = 6,
+}
 
-    pub fn get_value(&self) -> i64 {
-        self.value
+impl ::protobuf::ProtobufEnum for CounterBlameReason {
+    fn value(&self) -> i32 {
+        *self as i32
+    }
+
+    fn from_i32(value: i32) -> ::std::option::Option<CounterBlameReason> {
+        match value {
+            0 => ::std::option::Option::Some(CounterBlameReason::INVALID_EQUALITY_PROOF),
+            1 => ::std::option::Option::Some(CounterBlameReason::MISSING_PART),
+            2 => ::std::option::Option::Some(CounterBlameReason::MALFORMED_POINT),
+            3 => ::std::option::Option::Some(CounterBlameReason::MALFORMED_PROOF),
+            4 => ::std::option::Option::Some(CounterBlameReason::UNKNOWN_COUNTER),
+            5 => ::std::option::Option::Some(CounterBlameReason::MISMATCHED_COUNTER),
+            6 => ::std::option::Option::Some(CounterBlameReason::DUPLICATE_COUNTER),
+            _ => ::std::option::Option::None
+        }
     }
-    pub fn clear_value(&mut self) {
-        self.value = 0;
+
+    fn values() -> &'static [Self] {
+        static values: &'static [CounterBlameReason] = &[
+            CounterBlameReason::INVALID_EQUALITY_PROOF,
+            CounterBlameReason::MISSING_PART,
+            CounterBlameReason::MALFORMED_POINT,
+            CounterBlameReason::MALFORMED_PROOF,
+            CounterBlameReason::UNKNOWN_COUNTER,
+            CounterBlameReason::MISMATCHED_COUNTER,
+            CounterBlameReason::DUPLICATE_COUNTER,
+        ];
+        values
     }
 
-    // Param is passed by value, moved
-    pub fn set_value(&mut self, v: i64) {
-        self.value = v;
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<CounterBlameReason>("CounterBlameReason", file_descriptor_proto())
+        })
     }
 }
 
-impl ::protobuf::Message for StringToInt64Pair {
-    fn is_initialized(&self) -> bool {
-        true
+impl ::std::marker::Copy for CounterBlameReason {
+}
+
+impl ::std::default::Default for CounterBlameReason {
+    fn default() -> Self {
+        CounterBlameReason::INVALID_EQUALITY_PROOF
     }
+}
 
-    fn merge_from(&mut self, is: &mut ::protobuf::CodedInputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        while !is.eof()? {
-            let (field_number, wire_type) = is.read_tag_unpack()?;
-            match field_number {
-                1 => {
-                    ::protobuf::rt::read_singular_proto3_string_into(wire_type, is, &mut self.key)?;
-                },
-                2 => {
-                    if wire_type != ::protobuf::wire_format::WireTypeVarint {
-                        return ::std::result::Result::Err(::protobuf::rt::unexpected_wire_type(wire_type));
-                    }
-                    let tmp = is.read_int64()?;
-                    self.value = tmp;
-                },
-                _ => {
-                    ::protobuf::rt::read_unknown_or_skip_group(field_number, wire_type, is, self.mut_unknown_fields())?;
-                },
-            };
-        }
-        ::std::result::Result::Ok(())
+impl ::protobuf::reflect::ProtobufValue for CounterBlameReason {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
     }
+}
 
-    // Compute sizes of nested messages
-    #[allow(unused_variables)]
-    fn compute_size(&self) -> u32 {
-        let mut my_size = 0;
-        if !self.key.is_empty() {
-            my_size += ::protobuf::rt::string_size(1, &self.key);
-        }
-        if self.value != 0 {
-            my_size += ::protobuf::rt::value_size(2, self.value, ::protobuf::wire_format::WireTypeVarint);
-        }
-        my_size += ::protobuf::rt::unknown_fields_size(self.get_unknown_fields());
-        self.cached_size.set(my_size);
-        my_size
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum PollPhase {
+    SETUP = 0,
+    REGISTRATION = 1,
+    VOTING = 2,
+    COUNTING = 3,
+    FINALIZED = 4,
+}
+
+impl ::protobuf::ProtobufEnum for PollPhase {
+    fn value(&self) -> i32 {
+        *self as i32
     }
 
-    fn write_to_with_cached_sizes(&self, os: &mut ::protobuf::CodedOutputStream<'_>) -> ::protobuf::ProtobufResult<()> {
-        if !self.key.is_empty() {
-            os.write_string(1, &self.key)?;
+    fn from_i32(value: i32) -> ::std::option::Option<PollPhase> {
+        match value {
+            0 => ::std::option::Option::Some(PollPhase::SETUP),
+            1 => ::std::option::Option::Some(PollPhase::REGISTRATION),
+            2 => ::std::option::Option::Some(PollPhase::VOTING),
+            3 => ::std::option::Option::Some(PollPhase::COUNTING),
+            4 => ::std::option::Option::Some(PollPhase::FINALIZED),
+            _ => ::std::option::Option::None
         }
-        if self.value != 0 {
-            os.write_int64(2, self.value)?;
-        }
-        os.write_unknown_fields(self.get_unknown_fields())?;
-        ::std::result::Result::Ok(())
     }
 
-    fn get_cached_size(&self) -> u32 {
-        self.cached_size.get()
+    fn values() -> &'static [Self] {
+        static values: &'static [PollPhase] = &[
+            PollPhase::SETUP,
+            PollPhase::REGISTRATION,
+            PollPhase::VOTING,
+            PollPhase::COUNTING,
+            PollPhase::FINALIZED,
+        ];
+        values
     }
 
-    fn get_unknown_fields(&self) -> &::protobuf::UnknownFields {
-        &self.unknown_fields
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
+        descriptor.get(|| {
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<PollPhase>("PollPhase", file_descriptor_proto())
+        })
     }
+}
 
-    fn mut_unknown_fields(&mut self) -> &mut ::protobuf::UnknownFields {
-        &mut self.unknown_fields
-    }
+impl ::std::marker::Copy for PollPhase {
+}
 
-    fn as_any(&self) -> &dyn (::std::any::Any) {
-        self as &dyn (::std::any::Any)
+impl ::std::default::Default for PollPhase {
+    fn default() -> Self {
+        PollPhase::SETUP
     }
-    fn as_any_mut(&mut self) -> &mut dyn (::std::any::Any) {
-        self as &mut dyn (::std::any::Any)
+}
+
+impl ::protobuf::reflect::ProtobufValue for PollPhase {
+    fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
     }
-    fn into_any(self: ::std::boxed::Box<Self>) -> ::std::boxed::Box<dyn (::std::any::Any)> {
-        self
+}
+
+#[derive(Clone,PartialEq,Eq,Debug,Hash)]
+pub enum PollMode {
+    BOUNDED = 0,
+    QUADRATIC = 1,
+    UNBOUNDED = 2,
+    UNLISTED = 3,
+    COERCION_RESISTANT = 4,
+}
+
+impl ::protobuf::ProtobufEnum for PollMode {
+    fn value(&self) -> i32 {
+        *self as i32
     }
 
-    fn descriptor(&self) -> &'static ::protobuf::reflect::MessageDescriptor {
-        Self::descriptor_static()
+    fn from_i32(value: i32) -> ::std::option::Option<PollMode> {
+        match value {
+            0 => ::std::option::Option::Some(PollMode::BOUNDED),
+            1 => ::std::option::Option::Some(PollMode::QUADRATIC),
+            2 => ::std::option::Option::Some(PollMode::UNBOUNDED),
+            3 => ::std::option::Option::Some(PollMode::UNLISTED),
+            4 => ::std::option::Option::Some(PollMode::COERCION_RESISTANT),
+            _ => ::std::option::Option::None
+        }
     }
 
-    fn new() -> StringToInt64Pair {
-        StringToInt64Pair::new()
+    fn values() -> &'static [Self] {
+        static values: &'static [PollMode] = &[
+            PollMode::BOUNDED,
+            PollMode::QUADRATIC,
+            PollMode::UNBOUNDED,
+            PollMode::UNLISTED,
+            PollMode::COERCION_RESISTANT,
+        ];
+        values
     }
 
-    fn descriptor_static() -> &'static ::protobuf::reflect::MessageDescriptor {
-        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::MessageDescriptor> = ::protobuf::rt::LazyV2::INIT;
+    fn enum_descriptor_static() -> &'static ::protobuf::reflect::EnumDescriptor {
+        static descriptor: ::protobuf::rt::LazyV2<::protobuf::reflect::EnumDescriptor> = ::protobuf::rt::LazyV2::INIT;
         descriptor.get(|| {
-            let mut fields = ::std::vec::Vec::new();
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeString>(
-                "key",
-                |m: &StringToInt64Pair| { &m.key },
-                |m: &mut StringToInt64Pair| { &mut m.key },
-            ));
-            fields.push(::protobuf::reflect::accessor::make_simple_field_accessor::<_, ::protobuf::types::ProtobufTypeInt64>(
-                "value",
-                |m: &StringToInt64Pair| { &m.value },
-                |m: &mut StringToInt64Pair| { &mut m.value },
-            ));
-            ::protobuf::reflect::MessageDescriptor::new_pb_name::<StringToInt64Pair>(
-                "StringToInt64Pair",
-                fields,
-                file_descriptor_proto()
-            )
+            ::protobuf::reflect::EnumDescriptor::new_pb_name::<PollMode>("PollMode", file_descriptor_proto())
         })
     }
-
-    fn default_instance() -> &'static StringToInt64Pair {
-        static instance: ::protobuf::rt::LazyV2<StringToInt64Pair> = ::protobuf::rt::LazyV2::INIT;
-        instance.get(StringToInt64Pair::new)
-    }
 }
 
-impl ::protobuf::Clear for StringToInt64Pair {
-    fn clear(&mut self) {
-        self.key.clear();
-        self.value = 0;
-        self.unknown_fields.clear();
-    }
+impl ::std::marker::Copy for PollMode {
 }
 
-impl ::std::fmt::Debug for StringToInt64Pair {
-    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-        ::protobuf::text_format::fmt(self, f)
+impl ::std::default::Default for PollMode {
+    fn default() -> Self {
+        PollMode::BOUNDED
     }
 }
 
-impl ::protobuf::reflect::ProtobufValue for StringToInt64Pair {
+impl ::protobuf::reflect::ProtobufValue for PollMode {
     fn as_ref(&self) -> ::protobuf::reflect::ReflectValueRef {
-        ::protobuf::reflect::ReflectValueRef::Message(self)
+        ::protobuf::reflect::ReflectValueRef::Enum(::protobuf::ProtobufEnum::descriptor(self))
     }
 }
 
 static file_descriptor_proto_data: &'static [u8] = b"\
     \n\x16solution/acv/acv.proto\x12\x1acom.webank.wedpr.acv.proto\"-\n\rCan\
-    didateList\x12\x1c\n\tcandidate\x18\x01\x20\x03(\tR\tcandidate\"\x81\x01\
+    didateList\x12\x1c\n\tcandidate\x18\x01\x20\x03(\tR\tcandidate\"\x97\x05\
     \n\x15PollParametersStorage\x12\x1d\n\npoll_point\x18\x01\x20\x01(\x0cR\
     \tpollPoint\x12I\n\ncandidates\x18\x02\x20\x01(\x0b2).com.webank.wedpr.a\
-    cv.proto.CandidateListR\ncandidates\";\n\rCounterSecret\x12*\n\x11poll_s\
-    ecret_share\x18\x01\x20\x01(\x0cR\x0fpollSecretShare\"0\n\x0bVoterSecret\
-    \x12!\n\x0cvoter_secret\x18\x01\x20\x01(\x0cR\x0bvoterSecret\"\xc5\x01\n\
-    \x13RegistrationRequest\x12X\n\x0cweight_point\x18\x01\x20\x01(\x0b25.co\
-    m.webank.wedpr.acv.proto.RegistrationBlindingPointR\x0bweightPoint\x12T\
-    \n\nzero_point\x18\x02\x20\x01(\x0b25.com.webank.wedpr.acv.proto.Registr\
-    ationBlindingPointR\tzeroPoint\"\x7f\n\x19RegistrationBlindingPoint\x12.\
-    \n\x13blinding_poll_point\x18\x01\x20\x01(\x0cR\x11blindingPollPoint\x12\
-    2\n\x15blinding_basepoint_g2\x18\x02\x20\x01(\x0cR\x13blindingBasepointG\
-    2\"\xd8\x01\n\x14RegistrationResponse\x12!\n\x0cvoter_weight\x18\x01\x20\
-    \x01(\rR\x0bvoterWeight\x12:\n\x06ballot\x18\x02\x20\x01(\x0b2\".com.web\
-    ank.wedpr.acv.proto.BallotR\x06ballot\x12\x1c\n\tsignature\x18\x03\x20\
-    \x01(\x0cR\tsignature\x12C\n\x0bzero_ballot\x18\x04\x20\x01(\x0b2\".com.\
-    webank.wedpr.acv.proto.BallotR\nzeroBallot\"L\n\x06Ballot\x12\x20\n\x0bc\
-    iphertext1\x18\x01\x20\x01(\x0cR\x0bciphertext1\x12\x20\n\x0bciphertext2\
+    cv.proto.CandidateListR\ncandidates\x129\n\x19min_unlisted_candidate_id\
+    \x18\x03\x20\x01(\rR\x16minUnlistedCandidateId\x129\n\x19max_unlisted_ca\
+    ndidate_id\x18\x04\x20\x01(\rR\x16maxUnlistedCandidateId\x12@\n\x08quest\
+    ion\x18\x05\x20\x03(\x0b2$.com.webank.wedpr.acv.proto.QuestionR\x08quest\
+    ion\x12V\n\x10signature_scheme\x18\x06\x20\x01(\x0e2+.com.webank.wedpr.a\
+    cv.proto.SignatureSchemeR\x0fsignatureScheme\x12\x17\n\x07poll_id\x18\
+    \x07\x20\x01(\x0cR\x06pollId\x128\n\x18ballot_signature_version\x18\x08\
+    \x20\x01(\rR\x16ballotSignatureVersion\x12%\n\x0eagainst_voting\x18\t\
+    \x20\x01(\x08R\ragainstVoting\x12c\n\x12counter_parameters\x18\n\x20\x01\
+    (\x0b24.com.webank.wedpr.acv.proto.CounterParametersStorageR\x11counterP\
+    arameters\x12%\n\x0epoll_signature\x18\x0b\x20\x01(\x0cR\rpollSignature\
+    \"v\n\x08Question\x12\x1f\n\x0bquestion_id\x18\x01\x20\x01(\tR\nquestion\
+    Id\x12I\n\ncandidates\x18\x02\x20\x01(\x0b2).com.webank.wedpr.acv.proto.\
+    CandidateListR\ncandidates\";\n\rCounterSecret\x12*\n\x11poll_secret_sha\
+    re\x18\x01\x20\x01(\x0cR\x0fpollSecretShare\"0\n\x0bVoterSecret\x12!\n\
+    \x0cvoter_secret\x18\x01\x20\x01(\x0cR\x0bvoterSecret\"\xc5\x01\n\x13Reg\
+    istrationRequest\x12X\n\x0cweight_point\x18\x01\x20\x01(\x0b25.com.weban\
+    k.wedpr.acv.proto.RegistrationBlindingPointR\x0bweightPoint\x12T\n\nzero\
+    _point\x18\x02\x20\x01(\x0b25.com.webank.wedpr.acv.proto.RegistrationBli\
+    ndingPointR\tzeroPoint\"\x7f\n\x19RegistrationBlindingPoint\x12.\n\x13bl\
+    inding_poll_point\x18\x01\x20\x01(\x0cR\x11blindingPollPoint\x122\n\x15b\
+    linding_basepoint_g2\x18\x02\x20\x01(\x0cR\x13blindingBasepointG2\"\xe0\
+    \x03\n\x14RegistrationResponse\x12!\n\x0cvoter_weight\x18\x01\x20\x01(\r\
+    R\x0bvoterWeight\x12:\n\x06ballot\x18\x02\x20\x01(\x0b2\".com.webank.wed\
+    pr.acv.proto.BallotR\x06ballot\x12\x1c\n\tsignature\x18\x03\x20\x01(\x0c\
+    R\tsignature\x12C\n\x0bzero_ballot\x18\x04\x20\x01(\x0b2\".com.webank.we\
+    dpr.acv.proto.BallotR\nzeroBallot\x12N\n\x0bcertificate\x18\x05\x20\x01(\
+    \x0b2,.com.webank.wedpr.acv.proto.BlindCertificateR\x0bcertificate\x12(\
+    \n\x10weight_key_point\x18\x06\x20\x01(\x0cR\x0eweightKeyPoint\x124\n\
+    \x16encrypted_voter_weight\x18\x07\x20\x01(\rR\x14encryptedVoterWeight\
+    \x12V\n\x10signature_scheme\x18\x08\x20\x01(\x0e2+.com.webank.wedpr.acv.\
+    proto.SignatureSchemeR\x0fsignatureScheme\"L\n\x06Ballot\x12\x20\n\x0bci\
+    phertext1\x18\x01\x20\x01(\x0cR\x0bciphertext1\x12\x20\n\x0bciphertext2\
     \x18\x02\x20\x01(\x0cR\x0bciphertext2\"h\n\x1dCounterParametersShareRequ\
     est\x12\x1d\n\ncounter_id\x18\x01\x20\x01(\tR\tcounterId\x12(\n\x10poll_\
     point_share\x18\x02\x20\x01(\x0cR\x0epollPointShare\"\x8f\x01\n\x18Count\
     erParametersStorage\x12s\n\x18counter_parameters_share\x18\x01\x20\x03(\
     \x0b29.com.webank.wedpr.acv.proto.CounterParametersShareRequestR\x16coun\
-    terParametersShare\"@\n\nVoteChoice\x12\x1c\n\tcandidate\x18\x01\x20\x01\
-    (\tR\tcandidate\x12\x14\n\x05value\x18\x02\x20\x01(\rR\x05value\"M\n\x12\
-    UnlistedVoteChoice\x12!\n\x0ccandidate_id\x18\x01\x20\x01(\rR\x0bcandida\
-    teId\x12\x14\n\x05value\x18\x02\x20\x01(\rR\x05value\"\xa6\x01\n\x0bVote\
-    Choices\x12>\n\x06choice\x18\x01\x20\x03(\x0b2&.com.webank.wedpr.acv.pro\
-    to.VoteChoiceR\x06choice\x12W\n\x0funlisted_choice\x18\x02\x20\x03(\x0b2\
-    ..com.webank.wedpr.acv.proto.UnlistedVoteChoiceR\x0eunlistedChoice\"k\n\
-    \x0fCandidateBallot\x12\x1c\n\tcandidate\x18\x01\x20\x01(\tR\tcandidate\
-    \x12:\n\x06ballot\x18\x02\x20\x01(\x0b2\".com.webank.wedpr.acv.proto.Bal\
-    lotR\x06ballot\"d\n\x0bBallotProof\x12!\n\x0cformat_proof\x18\x01\x20\
-    \x01(\x0cR\x0bformatProof\x122\n\x15either_equality_proof\x18\x02\x20\
-    \x01(\x0cR\x13eitherEqualityProof\"j\n\x17StringToBallotProofPair\x12\
-    \x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12=\n\x05value\x18\x02\x20\x01\
-    (\x0b2'.com.webank.wedpr.acv.proto.BallotProofR\x05value\"\xde\x02\n\x0b\
-    VoteRequest\x12;\n\x04vote\x18\x01\x20\x01(\x0b2'.com.webank.wedpr.acv.p\
-    roto.VoteStorageR\x04vote\x12V\n\x0cballot_proof\x18\x02\x20\x03(\x0b23.\
-    com.webank.wedpr.acv.proto.StringToBallotProofPairR\x0bballotProof\x12\
-    \x1f\n\x0brange_proof\x18\x03\x20\x01(\x0cR\nrangeProof\x12*\n\x11sum_ba\
-    lance_proof\x18\x04\x20\x01(\x0cR\x0fsumBalanceProof\x12m\n\x15unlisted_\
-    ballot_proof\x18\x05\x20\x03(\x0b29.com.webank.wedpr.acv.proto.CipherPoi\
-    ntsToBallotProofPairR\x13unlistedBallotProof\"\xb6\x03\n\x0bVoteStorage\
-    \x12\x1c\n\tsignature\x18\x01\x20\x01(\x0cR\tsignature\x12E\n\x0cblank_b\
-    allot\x18\x02\x20\x01(\x0b2\".com.webank.wedpr.acv.proto.BallotR\x0bblan\
-    kBallot\x12C\n\x0brest_ballot\x18\x03\x20\x01(\x0b2\".com.webank.wedpr.a\
-    cv.proto.BallotR\nrestBallot\x12N\n\x0cvoted_ballot\x18\x04\x20\x03(\x0b\
-    2+.com.webank.wedpr.acv.proto.CandidateBallotR\x0bvotedBallot\x12h\n\x15\
-    voted_ballot_unlisted\x18\x05\x20\x03(\x0b24.com.webank.wedpr.acv.proto.\
-    CipherPointsToBallotPairR\x13votedBallotUnlisted\x12C\n\x0bzero_ballot\
-    \x18\x06\x20\x01(\x0b2\".com.webank.wedpr.acv.proto.BallotR\nzeroBallot\
-    \"\x92\x01\n\x18CipherPointsToBallotPair\x12:\n\x03key\x18\x01\x20\x01(\
-    \x0b2(.com.webank.wedpr.acv.proto.CipherPointsR\x03key\x12:\n\x06ballot\
-    \x18\x02\x20\x01(\x0b2\".com.webank.wedpr.acv.proto.BallotR\x06ballot\"\
-    \x9a\x01\n\x1dCipherPointsToBallotProofPair\x12:\n\x03key\x18\x01\x20\
-    \x01(\x0b2(.com.webank.wedpr.acv.proto.CipherPointsR\x03key\x12=\n\x05va\
-    lue\x18\x02\x20\x01(\x0b2'.com.webank.wedpr.acv.proto.BallotProofR\x05va\
-    lue\"R\n\x0cCipherPoints\x12\x20\n\x0bciphertext1\x18\x01\x20\x01(\x0cR\
-    \x0bciphertext1\x12\x20\n\x0bciphertext2\x18\x02\x20\x01(\x0cR\x0bcipher\
-    text2\"u\n\x0cCountingPart\x12\x1d\n\ncounter_id\x18\x01\x20\x01(\tR\tco\
-    unterId\x12\x1f\n\x0bblinding_c2\x18\x02\x20\x01(\x0cR\nblindingC2\x12%\
-    \n\x0eequality_proof\x18\x03\x20\x01(\x0cR\requalityProof\"l\n\x18String\
-    ToCountingPartPair\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12>\n\
-    \x05value\x18\x02\x20\x01(\x0b2(.com.webank.wedpr.acv.proto.CountingPart\
-    R\x05value\"\xb5\x02\n\x1aDecryptedResultPartStorage\x12G\n\nblank_part\
-    \x18\x01\x20\x01(\x0b2(.com.webank.wedpr.acv.proto.CountingPartR\tblankP\
-    art\x12[\n\x0ecandidate_part\x18\x02\x20\x03(\x0b24.com.webank.wedpr.acv\
-    .proto.StringToCountingPartPairR\rcandidatePart\x12q\n\x17unlisted_candi\
-    date_part\x18\x03\x20\x03(\x0b29.com.webank.wedpr.acv.proto.UnlistedBall\
-    otDecryptedResultR\x15unlistedCandidatePart\"\xf7\x02\n\x1dUnlistedBallo\
-    tDecryptedResult\x12\x1c\n\tcandidate\x18\x01\x20\x01(\x03R\tcandidate\
-    \x12S\n\x10candidate_cipher\x18\x02\x20\x01(\x0b2(.com.webank.wedpr.acv.\
-    proto.CipherPointsR\x0fcandidateCipher\x12j\n\x1cdecrypted_unlisted_cand\
-    idate\x18\x04\x20\x01(\x0b2(.com.webank.wedpr.acv.proto.CountingPartR\
-    \x1adecryptedUnlistedCandidate\x12w\n#decrypted_unlisted_candidate_ballo\
-    t\x18\x05\x20\x03(\x0b2(.com.webank.wedpr.acv.proto.CountingPartR\x20dec\
-    ryptedUnlistedCandidateBallot\"\xb3\x01\n\x11VoteResultStorage\x12E\n\
-    \x06result\x18\x01\x20\x03(\x0b2-.com.webank.wedpr.acv.proto.StringToInt\
-    64PairR\x06result\x12W\n\x0funlisted_result\x18\x02\x20\x03(\x0b2..com.w\
-    ebank.wedpr.acv.proto.UnlistedVoteChoiceR\x0eunlistedResult\";\n\x11Stri\
-    ngToInt64Pair\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05v\
-    alue\x18\x02\x20\x01(\x03R\x05valueB\x1e\n\x1acom.webank.wedpr.acv.proto\
-    P\x01b\x06proto3\
+    terParametersShare\"e\n\nVoteChoice\x12\x1c\n\tcandidate\x18\x01\x20\x01\
+    (\tR\tcandidate\x12\x14\n\x05value\x18\x02\x20\x01(\rR\x05value\x12#\n\r\
+    against_value\x18\x03\x20\x01(\rR\x0cagainstValue\"M\n\x12UnlistedVoteCh\
+    oice\x12!\n\x0ccandidate_id\x18\x01\x20\x01(\rR\x0bcandidateId\x12\x14\n\
+    \x05value\x18\x02\x20\x01(\rR\x05value\"\x9e\x02\n\x0bVoteChoices\x12>\n\
+    \x06choice\x18\x01\x20\x03(\x0b2&.com.webank.wedpr.acv.proto.VoteChoiceR\
+    \x06choice\x12W\n\x0funlisted_choice\x18\x02\x20\x03(\x0b2..com.webank.w\
+    edpr.acv.proto.UnlistedVoteChoiceR\x0eunlistedChoice\x12\x1a\n\x08sequen\
+    ce\x18\x03\x20\x01(\rR\x08sequence\x12Z\n\x10question_choices\x18\x04\
+    \x20\x03(\x0b2/.com.webank.wedpr.acv.proto.QuestionVoteChoicesR\x0fquest\
+    ionChoices\"v\n\x13QuestionVoteChoices\x12\x1f\n\x0bquestion_id\x18\x01\
+    \x20\x01(\tR\nquestionId\x12>\n\x06choice\x18\x02\x20\x03(\x0b2&.com.web\
+    ank.wedpr.acv.proto.VoteChoiceR\x06choice\"k\n\x0fCandidateBallot\x12\
+    \x1c\n\tcandidate\x18\x01\x20\x01(\tR\tcandidate\x12:\n\x06ballot\x18\
+    \x02\x20\x01(\x0b2\".com.webank.wedpr.acv.proto.BallotR\x06ballot\"\x83\
+    \x01\n\x0bBallotProof\x12!\n\x0cformat_proof\x18\x01\x20\x01(\x0cR\x0bfo\
+    rmatProof\x122\n\x15either_equality_proof\x18\x02\x20\x01(\x0cR\x13eithe\
+    rEqualityProof\x12\x1d\n\ncost_proof\x18\x03\x20\x01(\x0cR\tcostProof\"j\
+    \n\x17StringToBallotProofPair\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03ke\
+    y\x12=\n\x05value\x18\x02\x20\x01(\x0b2'.com.webank.wedpr.acv.proto.Ball\
+    otProofR\x05value\"\xf0\x07\n\x0bVoteRequest\x12;\n\x04vote\x18\x01\x20\
+    \x01(\x0b2'.com.webank.wedpr.acv.proto.VoteStorageR\x04vote\x12V\n\x0cba\
+    llot_proof\x18\x02\x20\x03(\x0b23.com.webank.wedpr.acv.proto.StringToBal\
+    lotProofPairR\x0bballotProof\x12\x1f\n\x0brange_proof\x18\x03\x20\x01(\
+    \x0cR\nrangeProof\x12*\n\x11sum_balance_proof\x18\x04\x20\x01(\x0cR\x0fs\
+    umBalanceProof\x12m\n\x15unlisted_ballot_proof\x18\x05\x20\x03(\x0b29.co\
+    m.webank.wedpr.acv.proto.CipherPointsToBallotProofPairR\x13unlistedBallo\
+    tProof\x12'\n\x0fnullifier_proof\x18\x06\x20\x01(\x0cR\x0enullifierProof\
+    \x124\n\x16sequence_binding_proof\x18\x07\x20\x01(\x0cR\x14sequenceBindi\
+    ngProof\x12V\n\x0equestion_proof\x18\x08\x20\x03(\x0b2/.com.webank.wedpr\
+    .acv.proto.QuestionBallotProofR\rquestionProof\x12*\n\x11rest_format_pro\
+    of\x18\t\x20\x01(\x0cR\x0frestFormatProof\x12V\n\x10credential_proof\x18\
+    \n\x20\x01(\x0b2+.com.webank.wedpr.acv.proto.CredentialProofR\x0fcredent\
+    ialProof\x12e\n\x14against_ballot_proof\x18\x0b\x20\x03(\x0b23.com.weban\
+    k.wedpr.acv.proto.StringToBallotProofPairR\x12againstBallotProof\x12i\n\
+    \x17aggregated_format_proof\x18\x0c\x20\x01(\x0b21.com.webank.wedpr.acv.\
+    proto.AggregatedFormatProofR\x15aggregatedFormatProof\x12\x82\x01\n\x20a\
+    ggregated_either_equality_proof\x18\r\x20\x01(\x0b29.com.webank.wedpr.ac\
+    v.proto.AggregatedEitherEqualityProofR\x1daggregatedEitherEqualityProof\
+    \"\xf2\x02\n\x13QuestionBallotProof\x12\x1f\n\x0bquestion_id\x18\x01\x20\
+    \x01(\tR\nquestionId\x12V\n\x0cballot_proof\x18\x02\x20\x03(\x0b23.com.w\
+    ebank.wedpr.acv.proto.StringToBallotProofPairR\x0bballotProof\x12\x1f\n\
+    \x0brange_proof\x18\x03\x20\x01(\x0cR\nrangeProof\x12*\n\x11sum_balance_\
+    proof\x18\x04\x20\x01(\x0cR\x0fsumBalanceProof\x12*\n\x11rest_format_pro\
+    of\x18\x05\x20\x01(\x0cR\x0frestFormatProof\x12i\n\x17aggregated_format_\
+    proof\x18\x06\x20\x01(\x0b21.com.webank.wedpr.acv.proto.AggregatedFormat\
+    ProofR\x15aggregatedFormatProof\"\x89\x01\n\x15AggregatedFormatProof\x12\
+    \x1c\n\tchallenge\x18\x01\x20\x01(\x0cR\tchallenge\x12%\n\x0eresponse_va\
+    lue\x18\x02\x20\x03(\x0cR\rresponseValue\x12+\n\x11response_blinding\x18\
+    \x03\x20\x03(\x0cR\x10responseBlinding\"\xb6\x01\n\x1dAggregatedEitherEq\
+    ualityProof\x12\x1c\n\tchallenge\x18\x01\x20\x01(\x0cR\tchallenge\x12)\n\
+    \x10weight_challenge\x18\x02\x20\x03(\x0cR\x0fweightChallenge\x12'\n\x0f\
+    weight_response\x18\x03\x20\x03(\x0cR\x0eweightResponse\x12#\n\rzero_res\
+    ponse\x18\x04\x20\x03(\x0cR\x0czeroResponse\"k\n\x0fCertifiedBallot\x12:\
+    \n\x06ballot\x18\x01\x20\x01(\x0b2\".com.webank.wedpr.acv.proto.BallotR\
+    \x06ballot\x12\x1c\n\tsignature\x18\x02\x20\x01(\x0cR\tsignature\"\x8f\
+    \x01\n\x19DelegationMembershipProof\x12\x1c\n\tchallenge\x18\x01\x20\x03\
+    (\x0cR\tchallenge\x12'\n\x0fsecret_response\x18\x02\x20\x03(\x0cR\x0esec\
+    retResponse\x12+\n\x11blinding_response\x18\x03\x20\x03(\x0cR\x10blindin\
+    gResponse\"\xcb\x03\n\nDelegation\x12?\n\x04ring\x18\x01\x20\x03(\x0b2+.\
+    com.webank.wedpr.acv.proto.CertifiedBallotR\x04ring\x12\x1c\n\tnullifier\
+    \x18\x02\x20\x01(\x0cR\tnullifier\x12K\n\x0fdelegate_ballot\x18\x03\x20\
+    \x01(\x0b2\".com.webank.wedpr.acv.proto.BallotR\x0edelegateBallot\x12K\n\
+    \x0ftransfer_ballot\x18\x04\x20\x01(\x0b2\".com.webank.wedpr.acv.proto.B\
+    allotR\x0etransferBallot\x12`\n\x10membership_proof\x18\x05\x20\x01(\x0b\
+    25.com.webank.wedpr.acv.proto.DelegationMembershipProofR\x0fmembershipPr\
+    oof\x12,\n\x12transfer_key_point\x18\x06\x20\x01(\x0cR\x10transferKeyPoi\
+    nt\x124\n\x16encrypted_voter_weight\x18\x07\x20\x01(\rR\x14encryptedVote\
+    rWeight\"\xa2\n\n\x0bVoteStorage\x12\x1c\n\tsignature\x18\x01\x20\x01(\
+    \x0cR\tsignature\x12E\n\x0cblank_ballot\x18\x02\x20\x01(\x0b2\".com.weba\
+    nk.wedpr.acv.proto.BallotR\x0bblankBallot\x12C\n\x0brest_ballot\x18\x03\
+    \x20\x01(\x0b2\".com.webank.wedpr.acv.proto.BallotR\nrestBallot\x12N\n\
+    \x0cvoted_ballot\x18\x04\x20\x03(\x0b2+.com.webank.wedpr.acv.proto.Candi\
+    dateBallotR\x0bvotedBallot\x12h\n\x15voted_ballot_unlisted\x18\x05\x20\
+    \x03(\x0b24.com.webank.wedpr.acv.proto.CipherPointsToBallotPairR\x13vote\
+    dBallotUnlisted\x12C\n\x0bzero_ballot\x18\x06\x20\x01(\x0b2\".com.webank\
+    .wedpr.acv.proto.BallotR\nzeroBallot\x12\x1c\n\tnullifier\x18\x07\x20\
+    \x01(\x0cR\tnullifier\x121\n\x14aggregated_nullifier\x18\x08\x20\x03(\
+    \x0cR\x13aggregatedNullifier\x12\x1a\n\x08sequence\x18\t\x20\x01(\rR\x08\
+    sequence\x12)\n\x10sequence_binding\x18\n\x20\x01(\x0cR\x0fsequenceBindi\
+    ng\x12N\n\x0caudit_record\x18\x0b\x20\x03(\x0b2+.com.webank.wedpr.acv.pr\
+    oto.VoteAuditRecordR\x0bauditRecord\x12N\n\x0bcertificate\x18\x0c\x20\
+    \x01(\x0b2,.com.webank.wedpr.acv.proto.BlindCertificateR\x0bcertificate\
+    \x12S\n\x0fquestion_ballot\x18\r\x20\x03(\x0b2*.com.webank.wedpr.acv.pro\
+    to.QuestionBallotR\x0equestionBallot\x12\x1b\n\tvote_root\x18\x0e\x20\
+    \x01(\x0cR\x08voteRoot\x12U\n\x14encrypted_credential\x18\x10\x20\x01(\
+    \x0b2\".com.webank.wedpr.acv.proto.BallotR\x13encryptedCredential\x12L\n\
+    \x0bcost_ballot\x18\x11\x20\x03(\x0b2+.com.webank.wedpr.acv.proto.Candid\
+    ateBallotR\ncostBallot\x12T\n\x14unused_credit_ballot\x18\x12\x20\x01(\
+    \x0b2\".com.webank.wedpr.acv.proto.BallotR\x12unusedCreditBallot\x12R\n\
+    \x0eagainst_ballot\x18\x13\x20\x03(\x0b2+.com.webank.wedpr.acv.proto.Can\
+    didateBallotR\ragainstBallot\x12F\n\ndelegation\x18\x14\x20\x03(\x0b2&.c\
+    om.webank.wedpr.acv.proto.DelegationR\ndelegation\x12#\n\rvote_frontier\
+    \x18\x15\x20\x03(\x0cR\x0cvoteFrontierJ\x04\x08\x0f\x10\x10\"\xc6\x01\n\
+    \x0eQuestionBallot\x12\x1f\n\x0bquestion_id\x18\x01\x20\x01(\tR\nquestio\
+    nId\x12N\n\x0cvoted_ballot\x18\x02\x20\x03(\x0b2+.com.webank.wedpr.acv.p\
+    roto.CandidateBallotR\x0bvotedBallot\x12C\n\x0brest_ballot\x18\x03\x20\
+    \x01(\x0b2\".com.webank.wedpr.acv.proto.BallotR\nrestBallot\"\x96\x01\n\
+    \x0fVoteAuditRecord\x12\x1c\n\tnullifier\x18\x01\x20\x01(\x0cR\tnullifie\
+    r\x12\x1a\n\x08sequence\x18\x02\x20\x01(\rR\x08sequence\x12\x1b\n\tvote_\
+    hash\x18\x03\x20\x01(\x0cR\x08voteHash\x12,\n\x12replaced_vote_hash\x18\
+    \x04\x20\x01(\x0cR\x10replacedVoteHash\"\x92\x01\n\x18CipherPointsToBall\
+    otPair\x12:\n\x03key\x18\x01\x20\x01(\x0b2(.com.webank.wedpr.acv.proto.C\
+    ipherPointsR\x03key\x12:\n\x06ballot\x18\x02\x20\x01(\x0b2\".com.webank.\
+    wedpr.acv.proto.BallotR\x06ballot\"\x84\x02\n\x1dCipherPointsToBallotPro\
+    ofPair\x12:\n\x03key\x18\x01\x20\x01(\x0b2(.com.webank.wedpr.acv.proto.C\
+    ipherPointsR\x03key\x12=\n\x05value\x18\x02\x20\x01(\x0b2'.com.webank.we\
+    dpr.acv.proto.BallotProofR\x05value\x124\n\x16candidate_format_proof\x18\
+    \x03\x20\x01(\x0cR\x14candidateFormatProof\x122\n\x15candidate_range_pro\
+    of\x18\x04\x20\x01(\x0cR\x13candidateRangeProof\"R\n\x0cCipherPoints\x12\
+    \x20\n\x0bciphertext1\x18\x01\x20\x01(\x0cR\x0bciphertext1\x12\x20\n\x0b\
+    ciphertext2\x18\x02\x20\x01(\x0cR\x0bciphertext2\"u\n\x0cCountingPart\
+    \x12\x1d\n\ncounter_id\x18\x01\x20\x01(\tR\tcounterId\x12\x1f\n\x0bblind\
+    ing_c2\x18\x02\x20\x01(\x0cR\nblindingC2\x12%\n\x0eequality_proof\x18\
+    \x03\x20\x01(\x0cR\requalityProof\"l\n\x18StringToCountingPartPair\x12\
+    \x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12>\n\x05value\x18\x02\x20\x01\
+    (\x0b2(.com.webank.wedpr.acv.proto.CountingPartR\x05value\"\xe0\x04\n\
+    \x1aDecryptedResultPartStorage\x12G\n\nblank_part\x18\x01\x20\x01(\x0b2(\
+    .com.webank.wedpr.acv.proto.CountingPartR\tblankPart\x12[\n\x0ecandidate\
+    _part\x18\x02\x20\x03(\x0b24.com.webank.wedpr.acv.proto.StringToCounting\
+    PartPairR\rcandidatePart\x12q\n\x17unlisted_candidate_part\x18\x03\x20\
+    \x03(\x0b29.com.webank.wedpr.acv.proto.UnlistedBallotDecryptedResultR\
+    \x15unlistedCandidatePart\x12U\n\rquestion_part\x18\x04\x20\x03(\x0b20.c\
+    om.webank.wedpr.acv.proto.QuestionCountingPartR\x0cquestionPart\x12E\n\t\
+    rest_part\x18\x05\x20\x01(\x0b2(.com.webank.wedpr.acv.proto.CountingPart\
+    R\x08restPart\x12W\n\x0cagainst_part\x18\x06\x20\x03(\x0b24.com.webank.w\
+    edpr.acv.proto.StringToCountingPartPairR\x0bagainstPart\x122\n\x15aggreg\
+    ated_counter_id\x18\x07\x20\x03(\tR\x13aggregatedCounterId\"\xdd\x01\n\
+    \x12CounterBlameReport\x12\x1d\n\ncounter_id\x18\x01\x20\x01(\tR\tcounte\
+    rId\x12\x1f\n\x0bquestion_id\x18\x02\x20\x01(\tR\nquestionId\x12\x1c\n\t\
+    candidate\x18\x03\x20\x01(\tR\tcandidate\x12F\n\x06reason\x18\x04\x20\
+    \x01(\x0e2..com.webank.wedpr.acv.proto.CounterBlameReasonR\x06reason\x12\
+    !\n\x0cagainst_part\x18\x05\x20\x01(\x08R\x0bagainstPart\"\xdb\x01\n\x14\
+    QuestionCountingPart\x12\x1f\n\x0bquestion_id\x18\x01\x20\x01(\tR\nquest\
+    ionId\x12[\n\x0ecandidate_part\x18\x02\x20\x03(\x0b24.com.webank.wedpr.a\
+    cv.proto.StringToCountingPartPairR\rcandidatePart\x12E\n\trest_part\x18\
+    \x03\x20\x01(\x0b2(.com.webank.wedpr.acv.proto.CountingPartR\x08restPart\
+    \"\xf7\x02\n\x1dUnlistedBallotDecryptedResult\x12\x1c\n\tcandidate\x18\
+    \x01\x20\x01(\x03R\tcandidate\x12S\n\x10candidate_cipher\x18\x02\x20\x01\
+    (\x0b2(.com.webank.wedpr.acv.proto.CipherPointsR\x0fcandidateCipher\x12j\
+    \n\x1cdecrypted_unlisted_candidate\x18\x04\x20\x01(\x0b2(.com.webank.wed\
+    pr.acv.proto.CountingPartR\x1adecryptedUnlistedCandidate\x12w\n#decrypte\
+    d_unlisted_candidate_ballot\x18\x05\x20\x03(\x0b2(.com.webank.wedpr.acv.\
+    proto.CountingPartR\x20decryptedUnlistedCandidateBallot\"\xde\x02\n\x11V\
+    oteResultStorage\x12E\n\x06result\x18\x01\x20\x03(\x0b2-.com.webank.wedp\
+    r.acv.proto.StringToInt64PairR\x06result\x12W\n\x0funlisted_result\x18\
+    \x02\x20\x03(\x0b2..com.webank.wedpr.acv.proto.UnlistedVoteChoiceR\x0eun\
+    listedResult\x12S\n\x0fquestion_result\x18\x03\x20\x03(\x0b2*.com.webank\
+    .wedpr.acv.proto.QuestionResultR\x0equestionResult\x12T\n\x0eagainst_res\
+    ult\x18\x04\x20\x03(\x0b2-.com.webank.wedpr.acv.proto.StringToInt64PairR\
+    \ragainstResult\"x\n\x0eQuestionResult\x12\x1f\n\x0bquestion_id\x18\x01\
+    \x20\x01(\tR\nquestionId\x12E\n\x06result\x18\x02\x20\x03(\x0b2-.com.web\
+    ank.wedpr.acv.proto.StringToInt64PairR\x06result\";\n\x11StringToInt64Pa\
+    ir\x12\x10\n\x03key\x18\x01\x20\x01(\tR\x03key\x12\x14\n\x05value\x18\
+    \x02\x20\x01(\x03R\x05value\"\xa1\x07\n\tPollState\x12;\n\x05phase\x18\
+    \x01\x20\x01(\x0e2%.com.webank.wedpr.acv.proto.PollPhaseR\x05phase\x12I\
+    \n\ncandidates\x18\x02\x20\x01(\x0b2).com.webank.wedpr.acv.proto.Candida\
+    teListR\ncandidates\x12c\n\x12counter_parameters\x18\x03\x20\x01(\x0b24.\
+    com.webank.wedpr.acv.proto.CounterParametersStorageR\x11counterParameter\
+    s\x12Z\n\x0fpoll_parameters\x18\x04\x20\x01(\x0b21.com.webank.wedpr.acv.\
+    proto.PollParametersStorageR\x0epollParameters\x12T\n\x0cregistration\
+    \x18\x05\x20\x03(\x0b20.com.webank.wedpr.acv.proto.RegistrationResponseR\
+    \x0cregistration\x12B\n\x08vote_sum\x18\x06\x20\x01(\x0b2'.com.webank.we\
+    dpr.acv.proto.VoteStorageR\x07voteSum\x12]\n\x0edecrypted_part\x18\x07\
+    \x20\x03(\x0b26.com.webank.wedpr.acv.proto.DecryptedResultPartStorageR\r\
+    decryptedPart\x12N\n\x0bvote_result\x18\x08\x20\x01(\x0b2-.com.webank.we\
+    dpr.acv.proto.VoteResultStorageR\nvoteResult\x123\n\x15registration_dead\
+    line\x18\t\x20\x01(\x04R\x14registrationDeadline\x12'\n\x0fvoting_deadli\
+    ne\x18\n\x20\x01(\x04R\x0evotingDeadline\x12V\n\x10signature_scheme\x18\
+    \x0b\x20\x01(\x0e2+.com.webank.wedpr.acv.proto.SignatureSchemeR\x0fsigna\
+    tureScheme\x12L\n\raccepted_vote\x18\x0c\x20\x03(\x0b2'.com.webank.wedpr\
+    .acv.proto.VoteStorageR\x0cacceptedVote\"\x91\x07\n\x0ePollTranscript\
+    \x12c\n\x12counter_parameters\x18\x01\x20\x01(\x0b24.com.webank.wedpr.ac\
+    v.proto.CounterParametersStorageR\x11counterParameters\x12Z\n\x0fpoll_pa\
+    rameters\x18\x02\x20\x01(\x0b21.com.webank.wedpr.acv.proto.PollParameter\
+    sStorageR\x0epollParameters\x124\n\x16coordinator_public_key\x18\x03\x20\
+    \x01(\x0cR\x14coordinatorPublicKey\x12T\n\x0cregistration\x18\x04\x20\
+    \x03(\x0b20.com.webank.wedpr.acv.proto.RegistrationResponseR\x0cregistra\
+    tion\x12J\n\x0cvote_request\x18\x05\x20\x03(\x0b2'.com.webank.wedpr.acv.\
+    proto.VoteRequestR\x0bvoteRequest\x12]\n\x0edecrypted_part\x18\x06\x20\
+    \x03(\x0b26.com.webank.wedpr.acv.proto.DecryptedResultPartStorageR\rdecr\
+    yptedPart\x12N\n\x0bvote_result\x18\x07\x20\x01(\x0b2-.com.webank.wedpr.\
+    acv.proto.VoteResultStorageR\nvoteResult\x12A\n\tpoll_mode\x18\x08\x20\
+    \x01(\x0e2$.com.webank.wedpr.acv.proto.PollModeR\x08pollMode\x12C\n\x1eb\
+    lind_certification_public_key\x18\t\x20\x01(\x0cR\x1bblindCertificationP\
+    ublicKey\x12J\n\x0cvote_ranking\x18\n\x20\x01(\x0b2'.com.webank.wedpr.ac\
+    v.proto.VoteRankingR\x0bvoteRanking\x12c\n\x11credential_filter\x18\x0b\
+    \x20\x01(\x0b26.com.webank.wedpr.acv.proto.CredentialFilterTranscriptR\
+    \x10credentialFilter\"\xd8\x04\n\x1aCredentialFilterTranscript\x12D\n\
+    \x06roster\x18\x01\x20\x01(\x0b2,.com.webank.wedpr.acv.proto.CredentialR\
+    osterR\x06roster\x12j\n\x17duplicate_blinding_part\x18\x02\x20\x03(\x0b2\
+    2.com.webank.wedpr.acv.proto.CredentialBlindingPartR\x15duplicateBlindin\
+    gPart\x12p\n\x19duplicate_decryption_part\x18\x03\x20\x03(\x0b24.com.web\
+    ank.wedpr.acv.proto.CredentialDecryptionPartR\x17duplicateDecryptionPart\
+    \x12H\n\x08mix_part\x18\x04\x20\x03(\x0b2-.com.webank.wedpr.acv.proto.Cr\
+    edentialMixPartR\x07mixPart\x12b\n\x13match_blinding_part\x18\x05\x20\
+    \x03(\x0b22.com.webank.wedpr.acv.proto.CredentialBlindingPartR\x11matchB\
+    lindingPart\x12h\n\x15match_decryption_part\x18\x06\x20\x03(\x0b24.com.w\
+    ebank.wedpr.acv.proto.CredentialDecryptionPartR\x13matchDecryptionPart\"\
+    \xb1\x01\n\x13BlindedCipherPoints\x12@\n\x06cipher\x18\x01\x20\x01(\x0b2\
+    (.com.webank.wedpr.acv.proto.CipherPointsR\x06cipher\x12+\n\x11ciphertex\
+    t1_proof\x18\x02\x20\x01(\x0cR\x10ciphertext1Proof\x12+\n\x11ciphertext2\
+    _proof\x18\x03\x20\x01(\x0cR\x10ciphertext2Proof\"\xc7\x01\n\x14Unlisted\
+    BlindingPart\x12\x1d\n\ncounter_id\x18\x01\x20\x01(\tR\tcounterId\x12%\n\
+    \x0eblinding_point\x18\x02\x20\x01(\x0cR\rblindingPoint\x12i\n\x18blinde\
+    d_candidate_cipher\x18\x03\x20\x03(\x0b2/.com.webank.wedpr.acv.proto.Bli\
+    ndedCipherPointsR\x16blindedCandidateCipher\"~\n\x18UnlistedTagDecrypted\
+    Part\x12\x1d\n\ncounter_id\x18\x01\x20\x01(\tR\tcounterId\x12C\n\x08tag_\
+    part\x18\x02\x20\x03(\x0b2(.com.webank.wedpr.acv.proto.CountingPartR\x07\
+    tagPart\"\xd4\x01\n\x17UnlistedGroupingStorage\x12U\n\rblinding_part\x18\
+    \x01\x20\x03(\x0b20.com.webank.wedpr.acv.proto.UnlistedBlindingPartR\x0c\
+    blindingPart\x12b\n\x12tag_decrypted_part\x18\x02\x20\x03(\x0b24.com.web\
+    ank.wedpr.acv.proto.UnlistedTagDecryptedPartR\x10tagDecryptedPart\"\x87\
+    \x01\n\x1cBlindCertificationCommitment\x12!\n\x0cvoter_weight\x18\x01\
+    \x20\x01(\rR\x0bvoterWeight\x12!\n\x0ccommitment_a\x18\x02\x20\x01(\x0cR\
+    \x0bcommitmentA\x12!\n\x0ccommitment_b\x18\x03\x20\x01(\x0cR\x0bcommitme\
+    ntB\"D\n\x18BlindCertificationSecret\x12\x0c\n\x01u\x18\x01\x20\x01(\x0c\
+    R\x01u\x12\x0c\n\x01s\x18\x02\x20\x01(\x0cR\x01s\x12\x0c\n\x01d\x18\x03\
+    \x20\x01(\x0cR\x01d\"_\n\x1dBlindCertificationVoterSecret\x12\x0e\n\x02t\
+    1\x18\x01\x20\x01(\x0cR\x02t1\x12\x0e\n\x02t2\x18\x02\x20\x01(\x0cR\x02t\
+    2\x12\x0e\n\x02t3\x18\x03\x20\x01(\x0cR\x02t3\x12\x0e\n\x02t4\x18\x04\
+    \x20\x01(\x0cR\x02t4\"H\n\x19BlindCertificationRequest\x12+\n\x11blinded\
+    _challenge\x18\x01\x20\x01(\x0cR\x10blindedChallenge\"T\n\x1aBlindCertif\
+    icationResponse\x12\x0c\n\x01r\x18\x01\x20\x01(\x0cR\x01r\x12\x0c\n\x01c\
+    \x18\x02\x20\x01(\x0cR\x01c\x12\x0c\n\x01s\x18\x03\x20\x01(\x0cR\x01s\
+    \x12\x0c\n\x01d\x18\x04\x20\x01(\x0cR\x01d\"\xac\x01\n\x10BlindCertifica\
+    te\x12!\n\x0cvoter_weight\x18\x01\x20\x01(\rR\x0bvoterWeight\x12\x10\n\
+    \x03rho\x18\x02\x20\x01(\x0cR\x03rho\x12\x14\n\x05omega\x18\x03\x20\x01(\
+    \x0cR\x05omega\x12\x14\n\x05sigma\x18\x04\x20\x01(\x0cR\x05sigma\x12\x14\
+    \n\x05delta\x18\x05\x20\x01(\x0cR\x05delta\x12!\n\x0cweight_proof\x18\
+    \x06\x20\x01(\x0cR\x0bweightProof\"\x87\x01\n\x0bVoteRanking\x12)\n\x10r\
+    anked_candidate\x18\x01\x20\x03(\tR\x0frankedCandidate\x12M\n\ncompariso\
+    n\x18\x02\x20\x03(\x0b2-.com.webank.wedpr.acv.proto.RankingComparisonR\n\
+    comparison\"\xb5\x02\n\x11RankingComparison\x12+\n\x11greater_candidate\
+    \x18\x01\x20\x01(\tR\x10greaterCandidate\x12)\n\x10lesser_candidate\x18\
+    \x02\x20\x01(\tR\x0flesserCandidate\x12%\n\x0emax_difference\x18\x03\x20\
+    \x01(\rR\rmaxDifference\x12E\n\x08mix_part\x18\x04\x20\x03(\x0b2*.com.we\
+    bank.wedpr.acv.proto.RankingMixPartR\x07mixPart\x12Z\n\x0fdecryption_par\
+    t\x18\x05\x20\x03(\x0b21.com.webank.wedpr.acv.proto.RankingDecryptionPar\
+    tR\x0edecryptionPart\"\xcd\x02\n\x0eRankingMixPart\x12\x1d\n\ncounter_id\
+    \x18\x01\x20\x01(\tR\tcounterId\x12Q\n\x12blinded_difference\x18\x02\x20\
+    \x03(\x0b2\".com.webank.wedpr.acv.proto.BallotR\x11blindedDifference\x12\
+    %\n\x0eequality_proof\x18\x03\x20\x03(\x0cR\requalityProof\x12S\n\x13shu\
+    ffled_difference\x18\x04\x20\x03(\x0b2\".com.webank.wedpr.acv.proto.Ball\
+    otR\x12shuffledDifference\x12M\n\rshuffle_proof\x18\x05\x20\x01(\x0b2(.c\
+    om.webank.wedpr.acv.proto.ShuffleProofR\x0cshuffleProof\"\x87\x01\n\x15R\
+    ankingDecryptionPart\x12\x1d\n\ncounter_id\x18\x01\x20\x01(\tR\tcounterI\
+    d\x12O\n\x0edecrypted_part\x18\x02\x20\x03(\x0b2(.com.webank.wedpr.acv.p\
+    roto.CountingPartR\rdecryptedPart\"G\n\tBallotRow\x12:\n\x06ballot\x18\
+    \x01\x20\x03(\x0b2\".com.webank.wedpr.acv.proto.BallotR\x06ballot\"S\n\
+    \x0cShuffleProof\x12C\n\x05round\x18\x01\x20\x03(\x0b2-.com.webank.wedpr\
+    .acv.proto.ShuffleProofRoundR\x05round\"\x97\x01\n\x11ShuffleProofRound\
+    \x12D\n\nshadow_row\x18\x01\x20\x03(\x0b2%.com.webank.wedpr.acv.proto.Ba\
+    llotRowR\tshadowRow\x12\x20\n\x0bpermutation\x18\x02\x20\x03(\rR\x0bperm\
+    utation\x12\x1a\n\x08blinding\x18\x03\x20\x03(\x0cR\x08blinding\"\xa8\
+    \x01\n\x0bVoteReceipt\x12\x1b\n\tvote_hash\x18\x01\x20\x01(\x0cR\x08vote\
+    Hash\x12\x1d\n\nleaf_index\x18\x02\x20\x01(\rR\tleafIndex\x12\x1d\n\nlea\
+    f_count\x18\x03\x20\x01(\rR\tleafCount\x12!\n\x0csibling_hash\x18\x04\
+    \x20\x03(\x0cR\x0bsiblingHash\x12\x1b\n\tvote_root\x18\x05\x20\x01(\x0cR\
+    \x08voteRoot\"O\n\x10AcceptedVoteList\x12;\n\x04vote\x18\x01\x20\x03(\
+    \x0b2'.com.webank.wedpr.acv.proto.VoteStorageR\x04vote\"\x95\x01\n\x14Ag\
+    gregatorCheckpoint\x12B\n\x08vote_sum\x18\x01\x20\x01(\x0b2'.com.webank.\
+    wedpr.acv.proto.VoteStorageR\x07voteSum\x12\x1d\n\nvote_count\x18\x02\
+    \x20\x01(\x04R\tvoteCount\x12\x1a\n\x08checksum\x18\x03\x20\x01(\x0cR\
+    \x08checksum\"1\n\x0fVoterCredential\x12\x1e\n\ncredential\x18\x01\x20\
+    \x01(\x0cR\ncredential\"\xb5\x01\n\x15CredentialRosterEntry\x12E\n\x0cbl\
+    ank_ballot\x18\x01\x20\x01(\x0b2\".com.webank.wedpr.acv.proto.BallotR\
+    \x0bblankBallot\x12U\n\x14encrypted_credential\x18\x02\x20\x01(\x0b2\".c\
+    om.webank.wedpr.acv.proto.BallotR\x13encryptedCredential\"[\n\x10Credent\
+    ialRoster\x12G\n\x05entry\x18\x01\x20\x03(\x0b21.com.webank.wedpr.acv.pr\
+    oto.CredentialRosterEntryR\x05entry\"\x8d\x01\n\x0fCredentialProof\x12\
+    \x1c\n\tchallenge\x18\x01\x20\x01(\x0cR\tchallenge\x12/\n\x13response_cr\
+    edential\x18\x02\x20\x01(\x0cR\x12responseCredential\x12+\n\x11response_\
+    blinding\x18\x03\x20\x01(\x0cR\x10responseBlinding\"\xb1\x01\n\x16Creden\
+    tialBlindingPart\x12\x1d\n\ncounter_id\x18\x01\x20\x01(\tR\tcounterId\
+    \x12Q\n\x12blinded_difference\x18\x02\x20\x03(\x0b2\".com.webank.wedpr.a\
+    cv.proto.BallotR\x11blindedDifference\x12%\n\x0eequality_proof\x18\x03\
+    \x20\x03(\x0cR\requalityProof\"\x8a\x01\n\x18CredentialDecryptionPart\
+    \x12\x1d\n\ncounter_id\x18\x01\x20\x01(\tR\tcounterId\x12O\n\x0edecrypte\
+    d_part\x18\x02\x20\x03(\x0b2(.com.webank.wedpr.acv.proto.CountingPartR\r\
+    decryptedPart\"\xee\x02\n\x11CredentialMixPart\x12\x1d\n\ncounter_id\x18\
+    \x01\x20\x01(\tR\tcounterId\x12@\n\x08vote_row\x18\x02\x20\x03(\x0b2%.co\
+    m.webank.wedpr.acv.proto.BallotRowR\x07voteRow\x12V\n\x12vote_shuffle_pr\
+    oof\x18\x03\x20\x01(\x0b2(.com.webank.wedpr.acv.proto.ShuffleProofR\x10v\
+    oteShuffleProof\x12D\n\nroster_row\x18\x04\x20\x03(\x0b2%.com.webank.wed\
+    pr.acv.proto.BallotRowR\trosterRow\x12Z\n\x14roster_shuffle_proof\x18\
+    \x05\x20\x01(\x0b2(.com.webank.wedpr.acv.proto.ShuffleProofR\x12rosterSh\
+    uffleProof*K\n\x0fSignatureScheme\x12\x12\n\x0eDEFAULT_SCHEME\x10\0\x12\
+    \x17\n\x13SECP256K1_KECCAK256\x10\x01\x12\x0b\n\x07SM2_SM3\x10\x02*\xb0\
+    \x01\n\x12CounterBlameReason\x12\x1a\n\x16INVALID_EQUALITY_PROOF\x10\0\
+    \x12\x10\n\x0cMISSING_PART\x10\x01\x12\x13\n\x0fMALFORMED_POINT\x10\x02\
+    \x12\x13\n\x0fMALFORMED_PROOF\x10\x03\x12\x13\n\x0fUNKNOWN_COUNTER\x10\
+    \x04\x12\x16\n\x12MISMATCHED_COUNTER\x10\x05\x12\x15\n\x11DUPLICATE_COUN\
+    TER\x10\x06*Q\n\tPollPhase\x12\t\n\x05SETUP\x10\0\x12\x10\n\x0cREGISTRAT\
+    ION\x10\x01\x12\n\n\x06VOTING\x10\x02\x12\x0c\n\x08COUNTING\x10\x03\x12\
+    \r\n\tFINALIZED\x10\x04*[\n\x08PollMode\x12\x0b\n\x07BOUNDED\x10\0\x12\r\
+    \n\tQUADRATIC\x10\x01\x12\r\n\tUNBOUNDED\x10\x02\x12\x0c\n\x08UNLISTED\
+    \x10\x03\x12\x16\n\x12COERCION_RESISTANT\x10\x04B\x1e\n\x1acom.webank.we\
+    dpr.acv.protoP\x01b\x06proto3\
 ";
 
 static file_descriptor_proto_lazy: ::protobuf::rt::LazyV2<::protobuf::descriptor::FileDescriptorProto> = ::protobuf::rt::LazyV2::INIT;
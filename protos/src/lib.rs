@@ -3,9 +3,21 @@
 //! Library of protobuf definitions and their generated code.
 #[macro_use]
 extern crate lazy_static;
+#[cfg(feature = "wedpr_f_secp256k1_signature")]
+use crate::config::{KECCAK256_HASH, SECP256K1_SIGNATURE};
+#[cfg(feature = "wedpr_f_sm_signature")]
+use crate::config::{SM2_SIGNATURE, SM3_HASH};
 use crate::{
-    config::{HASH, SIGNATURE},
-    generated::{acv::Ballot, zkp::PBBalanceProof},
+    config::{
+        BALLOT_SIGNATURE_DOMAIN, BALLOT_SIGNATURE_MESSAGE_BALLOT,
+        BALLOT_SIGNATURE_MESSAGE_BALLOTS, BALLOT_SIGNATURE_VERSION,
+        DEFAULT_SIGNATURE_SCHEME, LEGACY_BALLOT_SIGNATURE_VERSION,
+        POLL_SIGNATURE_DOMAIN,
+    },
+    generated::{
+        acv::{Ballot, PollParametersStorage, SignatureScheme},
+        zkp::PBBalanceProof,
+    },
 };
 use wedpr_l_utils::{
     error::WedprError,
@@ -66,65 +78,212 @@ pub fn pb_to_arithmetric_proof(
     })
 }
 
+/// Resolves the default scheme to the one selected at compile time.
+pub fn resolve_signature_scheme(
+    signature_scheme: SignatureScheme,
+) -> SignatureScheme {
+    match signature_scheme {
+        SignatureScheme::DEFAULT_SCHEME => DEFAULT_SIGNATURE_SCHEME,
+        _ => signature_scheme,
+    }
+}
+
+// Hashes the message and signs it with the given scheme, which must be built
+// by its feature.
+fn sign_message(
+    signature_scheme: SignatureScheme,
+    secret_key: &[u8],
+    message: &[u8],
+) -> Result<Vec<u8>, WedprError> {
+    match resolve_signature_scheme(signature_scheme) {
+        #[cfg(feature = "wedpr_f_sm_signature")]
+        SignatureScheme::SM2_SM3 => {
+            SM2_SIGNATURE.sign(secret_key, &SM3_HASH.hash(message))
+        },
+        #[cfg(feature = "wedpr_f_secp256k1_signature")]
+        SignatureScheme::SECP256K1_KECCAK256 => {
+            SECP256K1_SIGNATURE.sign(secret_key, &KECCAK256_HASH.hash(message))
+        },
+        _ => Err(WedprError::ArgumentError),
+    }
+}
+
+// Hashes the message and verifies its signature with the given scheme, which
+// must be built by its feature.
+fn verify_message(
+    signature_scheme: SignatureScheme,
+    public_key: &[u8],
+    message: &[u8],
+    signature: &[u8],
+) -> Result<bool, WedprError> {
+    match resolve_signature_scheme(signature_scheme) {
+        #[cfg(feature = "wedpr_f_sm_signature")]
+        SignatureScheme::SM2_SM3 => Ok(SM2_SIGNATURE.verify(
+            public_key,
+            SM3_HASH.hash(message).as_ref(),
+            signature,
+        )),
+        #[cfg(feature = "wedpr_f_secp256k1_signature")]
+        SignatureScheme::SECP256K1_KECCAK256 => Ok(SECP256K1_SIGNATURE.verify(
+            public_key,
+            KECCAK256_HASH.hash(message).as_ref(),
+            signature,
+        )),
+        _ => Err(WedprError::ArgumentError),
+    }
+}
+
+// Appends the length-prefixed bytes to the payload.
+fn append_length_prefixed(payload: &mut Vec<u8>, bytes: &[u8]) {
+    payload.extend_from_slice(&(bytes.len() as u64).to_be_bytes());
+    payload.extend_from_slice(bytes);
+}
+
+// Encodes the signed payload of blank ballots. The legacy payload of old polls
+// is the raw concatenation of the ciphertexts. The versioned payload is length
+// prefixed, and binds the domain tag, the message type and the poll id, so that
+// a signature cannot be reused for another poll or message layout. The voter
+// weight is bound by the signed blank ballot encrypting it, which the voter
+// checks at registration, so that the published votes do not reveal it.
+fn encode_ballot_signature_payload(
+    poll_parameters: &PollParametersStorage,
+    message_type: u32,
+    ballot_list: &[&Ballot],
+) -> Result<Vec<u8>, WedprError> {
+    let mut payload = Vec::new();
+    match poll_parameters.get_ballot_signature_version() {
+        LEGACY_BALLOT_SIGNATURE_VERSION => {
+            for ballot in ballot_list {
+                payload.extend_from_slice(ballot.get_ciphertext1());
+                payload.extend_from_slice(ballot.get_ciphertext2());
+            }
+        },
+        BALLOT_SIGNATURE_VERSION => {
+            if poll_parameters.get_poll_id().is_empty() {
+                return Err(WedprError::ArgumentError);
+            }
+            append_length_prefixed(
+                &mut payload,
+                BALLOT_SIGNATURE_DOMAIN.as_bytes(),
+            );
+            payload.extend_from_slice(&BALLOT_SIGNATURE_VERSION.to_be_bytes());
+            payload.extend_from_slice(&message_type.to_be_bytes());
+            append_length_prefixed(&mut payload, poll_parameters.get_poll_id());
+            payload
+                .extend_from_slice(&(ballot_list.len() as u64).to_be_bytes());
+            for ballot in ballot_list {
+                append_length_prefixed(&mut payload, ballot.get_ciphertext1());
+                append_length_prefixed(&mut payload, ballot.get_ciphertext2());
+            }
+        },
+        _ => return Err(WedprError::ArgumentError),
+    }
+    Ok(payload)
+}
+
+// Encodes the signed payload of the poll parameters, which binds the domain tag
+// and the poll id committing to all the other parameters.
+fn encode_poll_signature_payload(
+    poll_parameters: &PollParametersStorage,
+) -> Result<Vec<u8>, WedprError> {
+    if poll_parameters.get_poll_id().is_empty() {
+        return Err(WedprError::ArgumentError);
+    }
+    let mut payload = Vec::new();
+    append_length_prefixed(&mut payload, POLL_SIGNATURE_DOMAIN.as_bytes());
+    append_length_prefixed(&mut payload, poll_parameters.get_poll_id());
+    Ok(payload)
+}
+
+/// Signs the poll id of the poll parameters with the signature scheme of the
+/// poll.
+pub fn generate_poll_signature(
+    poll_parameters: &PollParametersStorage,
+    secret_key: &[u8],
+) -> Result<Vec<u8>, WedprError> {
+    let payload = encode_poll_signature_payload(poll_parameters)?;
+    sign_message(poll_parameters.get_signature_scheme(), secret_key, &payload)
+}
+
+/// Verifies the signature of the poll id of the poll parameters.
+pub fn verify_poll_signature(
+    poll_parameters: &PollParametersStorage,
+    public_key: &[u8],
+) -> Result<bool, WedprError> {
+    let payload = encode_poll_signature_payload(poll_parameters)?;
+    verify_message(
+        poll_parameters.get_signature_scheme(),
+        public_key,
+        &payload,
+        poll_parameters.get_poll_signature(),
+    )
+}
+
 // generate signature for the ballot
 pub fn generate_ballot_signature(
+    poll_parameters: &PollParametersStorage,
     secret_key: &[u8],
     ballot: &Ballot,
 ) -> Result<Vec<u8>, WedprError> {
-    let mut hash_vec = Vec::new();
-    hash_vec.append(&mut ballot.get_ciphertext1().to_vec());
-    hash_vec.append(&mut ballot.get_ciphertext2().to_vec());
-    let message_hash = HASH.hash(&hash_vec);
-    SIGNATURE.sign(secret_key, &message_hash)
+    let payload = encode_ballot_signature_payload(
+        poll_parameters,
+        BALLOT_SIGNATURE_MESSAGE_BALLOT,
+        &[ballot],
+    )?;
+    sign_message(poll_parameters.get_signature_scheme(), secret_key, &payload)
 }
 
 pub fn generate_ballots_signature(
+    poll_parameters: &PollParametersStorage,
     secret_key: &[u8],
     weight_ballot: &Ballot,
     zero_ballot: &Ballot,
 ) -> Result<Vec<u8>, WedprError> {
-    let mut hash_vec = Vec::new();
-    hash_vec.append(&mut weight_ballot.get_ciphertext1().to_vec());
-    hash_vec.append(&mut weight_ballot.get_ciphertext2().to_vec());
-    hash_vec.append(&mut zero_ballot.get_ciphertext1().to_vec());
-    hash_vec.append(&mut zero_ballot.get_ciphertext2().to_vec());
-    let message_hash = HASH.hash(&hash_vec);
-    SIGNATURE.sign(secret_key, &message_hash)
+    let payload = encode_ballot_signature_payload(
+        poll_parameters,
+        BALLOT_SIGNATURE_MESSAGE_BALLOTS,
+        &[weight_ballot, zero_ballot],
+    )?;
+    sign_message(poll_parameters.get_signature_scheme(), secret_key, &payload)
 }
 
 pub fn verify_ballot_signature(
+    poll_parameters: &PollParametersStorage,
     public_key: &[u8],
     ballot: &Ballot,
     signature: &Vec<u8>,
 ) -> Result<bool, WedprError> {
-    let mut hash_vec = Vec::new();
-    hash_vec.append(&mut ballot.get_ciphertext1().to_vec());
-    hash_vec.append(&mut ballot.get_ciphertext2().to_vec());
-    let message_hash: Vec<u8> = HASH.hash(&hash_vec);
-    Ok(SIGNATURE.verify(
-        &public_key,
-        &message_hash.as_ref(),
-        &signature.as_slice(),
-    ))
+    let payload = encode_ballot_signature_payload(
+        poll_parameters,
+        BALLOT_SIGNATURE_MESSAGE_BALLOT,
+        &[ballot],
+    )?;
+    verify_message(
+        poll_parameters.get_signature_scheme(),
+        public_key,
+        &payload,
+        signature.as_slice(),
+    )
 }
 
 pub fn verify_ballots_signature(
+    poll_parameters: &PollParametersStorage,
     public_key: &[u8],
     weight_ballot: &Ballot,
     zero_ballot: &Ballot,
     signature: &Vec<u8>,
 ) -> Result<bool, WedprError> {
-    let mut hash_vec = Vec::new();
-    hash_vec.append(&mut weight_ballot.get_ciphertext1().to_vec());
-    hash_vec.append(&mut weight_ballot.get_ciphertext2().to_vec());
-    hash_vec.append(&mut zero_ballot.get_ciphertext1().to_vec());
-    hash_vec.append(&mut zero_ballot.get_ciphertext2().to_vec());
-    let message_hash: Vec<u8> = HASH.hash(&hash_vec);
-    Ok(SIGNATURE.verify(
-        &public_key,
-        &message_hash.as_ref(),
-        &signature.as_slice(),
-    ))
+    let payload = encode_ballot_signature_payload(
+        poll_parameters,
+        BALLOT_SIGNATURE_MESSAGE_BALLOTS,
+        &[weight_ballot, zero_ballot],
+    )?;
+    verify_message(
+        poll_parameters.get_signature_scheme(),
+        public_key,
+        &payload,
+        signature.as_slice(),
+    )
 }
 
 #[cfg(test)]
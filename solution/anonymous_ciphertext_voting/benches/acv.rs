@@ -0,0 +1,186 @@
+// Copyright 2020 WeDPR Lab Project Authors. Licensed under Apache-2.0.
+
+//! Performance tests for ACV vote requests, comparing the format proof for
+//! each candidate ballot with a single aggregated format proof.
+//!
+//! The size of a vote request does not depend on the timing, so it is not
+//! measured here. A separate format proof encodes two points and two scalars
+//! for each candidate ballot, while the aggregated format proof encodes two
+//! scalars for each candidate ballot and a single challenge, which saves about
+//! 64 bytes for each candidate. `test_aggregated_format_proof` checks that the
+//! encoded vote request is smaller.
+
+extern crate criterion;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+extern crate wedpr_s_anonymous_ciphertext_voting;
+use wedpr_l_utils::traits::Signature;
+use wedpr_s_anonymous_ciphertext_voting::{
+    coordinator, counter, verifier, voter,
+};
+use wedpr_s_protos::{
+    config::SIGNATURE,
+    generated::acv::{
+        CandidateList, CounterParametersStorage, PollParametersStorage,
+        RegistrationResponse, VoteChoices, VoteRequest, VoterSecret,
+    },
+};
+
+const CANDIDATE_NUMBER: usize = 10;
+const VOTER_WEIGHT: u32 = 100;
+
+#[derive(Clone)]
+struct VoteSetup {
+    poll_parameters: PollParametersStorage,
+    public_key: Vec<u8>,
+    voter_secret: VoterSecret,
+    registration_response: RegistrationResponse,
+    vote_choices: VoteChoices,
+}
+
+fn make_vote_setup(candidate_number: usize) -> VoteSetup {
+    let mut counter_parameters = CounterParametersStorage::new();
+    for counter_id in ["1001", "1002"] {
+        counter_parameters.mut_counter_parameters_share().push(
+            counter::make_parameters_share(
+                counter_id,
+                &counter::make_counter_secret(),
+            )
+            .unwrap(),
+        );
+    }
+    let mut candidate_list = CandidateList::new();
+    for i in 0..candidate_number {
+        candidate_list
+            .mut_candidate()
+            .push(format!("candidate_{}", i));
+    }
+    let (public_key, secret_key) = SIGNATURE.generate_keypair();
+    let poll_parameters = coordinator::make_poll_parameters(
+        &candidate_list,
+        &counter_parameters,
+        &secret_key,
+    )
+    .unwrap();
+    let voter_secret = voter::make_voter_secret();
+    let registration_request = voter::make_registration_request(
+        &voter_secret,
+        &poll_parameters,
+        &public_key,
+    )
+    .unwrap();
+    let registration_response = coordinator::certify_voter(
+        &poll_parameters,
+        &secret_key,
+        &registration_request,
+        VOTER_WEIGHT,
+    )
+    .unwrap();
+    let choice_list: Vec<u32> =
+        (0..candidate_number as u32).map(|i| i % 3).collect();
+    let vote_choices = voter::make_vote_choices(&choice_list, &candidate_list);
+    VoteSetup {
+        poll_parameters,
+        public_key,
+        voter_secret,
+        registration_response,
+        vote_choices,
+    }
+}
+
+fn make_vote_request(
+    setup: &VoteSetup,
+    aggregated_format_proof: bool,
+) -> VoteRequest {
+    let vote = if aggregated_format_proof {
+        voter::vote_with_aggregated_format_proof
+    } else {
+        voter::vote
+    };
+    vote(
+        &setup.voter_secret,
+        &setup.vote_choices,
+        &setup.registration_response,
+        &setup.poll_parameters,
+        &setup.public_key,
+    )
+    .unwrap()
+}
+
+fn get_format_proof_name(aggregated_format_proof: bool) -> &'static str {
+    if aggregated_format_proof {
+        "aggregated_format_proof"
+    } else {
+        "separate_format_proofs"
+    }
+}
+
+fn create_vote_helper(c: &mut Criterion, aggregated_format_proof: bool) {
+    let label = format!(
+        "create_vote_helper_{}_candidates_{}",
+        CANDIDATE_NUMBER,
+        get_format_proof_name(aggregated_format_proof)
+    );
+    let setup = make_vote_setup(CANDIDATE_NUMBER);
+
+    c.bench_function(&label, move |b| {
+        b.iter(|| {
+            let _ = make_vote_request(&setup, aggregated_format_proof);
+        });
+    });
+}
+
+fn create_verify_vote_request_helper(
+    c: &mut Criterion,
+    aggregated_format_proof: bool,
+) {
+    let label = format!(
+        "create_verify_vote_request_helper_{}_candidates_{}",
+        CANDIDATE_NUMBER,
+        get_format_proof_name(aggregated_format_proof)
+    );
+    let setup = make_vote_setup(CANDIDATE_NUMBER);
+    let vote_request = make_vote_request(&setup, aggregated_format_proof);
+
+    c.bench_function(&label, move |b| {
+        b.iter(|| {
+            assert!(verifier::verify_vote_request(
+                &setup.poll_parameters,
+                &vote_request,
+                &setup.public_key
+            )
+            .unwrap());
+        });
+    });
+}
+
+fn create_vote_with_separate_format_proofs_helper(c: &mut Criterion) {
+    create_vote_helper(c, false);
+}
+
+fn create_vote_with_aggregated_format_proof_helper(c: &mut Criterion) {
+    create_vote_helper(c, true);
+}
+
+fn create_verify_vote_request_with_separate_format_proofs_helper(
+    c: &mut Criterion,
+) {
+    create_verify_vote_request_helper(c, false);
+}
+
+fn create_verify_vote_request_with_aggregated_format_proof_helper(
+    c: &mut Criterion,
+) {
+    create_verify_vote_request_helper(c, true);
+}
+
+criterion_group! {
+    name = acv_benches;
+    config = Criterion::default().sample_size(10);
+    targets =
+    create_vote_with_separate_format_proofs_helper,
+    create_vote_with_aggregated_format_proof_helper,
+    create_verify_vote_request_with_separate_format_proofs_helper,
+    create_verify_vote_request_with_aggregated_format_proof_helper
+}
+criterion_main!(acv_benches);
@@ -0,0 +1,839 @@
+// Copyright 2021 WeDPR Lab Project Authors. Licensed under Apache-2.0.
+
+//! Library for aggregating votes incrementally with persistent checkpoints.
+
+use curve25519_dalek::ristretto::RistrettoPoint;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    io::{ErrorKind, Write},
+    path::PathBuf,
+    sync::{Mutex, MutexGuard},
+};
+use wedpr_l_crypto_zkp_utils::{bytes_to_point, point_to_bytes};
+use wedpr_l_utils::error::WedprError;
+use wedpr_s_protos::{
+    bytes_to_proto,
+    generated::acv::{
+        AggregatorCheckpoint, Ballot, CandidateBallot, PollParametersStorage,
+        QuestionBallot, VoteAuditRecord, VoteStorage,
+    },
+    proto_to_bytes,
+};
+
+use crate::{
+    coordinator::check_vote_replacement,
+    utils::{
+        append_vote_audit_record, compute_checkpoint_checksum,
+        compute_vote_hash, get_ballot_by_candidate, get_ballot_from_list,
+        get_combined_blank_ballot, get_question_ballot,
+    },
+};
+
+/// Store of the latest checkpoint written by a vote aggregator.
+pub trait CheckpointStore: Send + Sync {
+    /// Saves a checkpoint, replacing the previous one.
+    fn save(&self, checkpoint: &[u8]) -> Result<(), WedprError>;
+
+    /// Loads the latest checkpoint, or None if no checkpoint has been saved.
+    fn load(&self) -> Result<Option<Vec<u8>>, WedprError>;
+}
+
+/// Checkpoint store backed by a file. A checkpoint is written to a temporary
+/// file and then renamed, so that a crash never leaves a partially written
+/// checkpoint behind.
+#[derive(Clone, Debug)]
+pub struct FileCheckpointStore {
+    path: PathBuf,
+}
+
+impl FileCheckpointStore {
+    pub fn new<P: Into<PathBuf>>(path: P) -> Self {
+        FileCheckpointStore { path: path.into() }
+    }
+}
+
+impl CheckpointStore for FileCheckpointStore {
+    fn save(&self, checkpoint: &[u8]) -> Result<(), WedprError> {
+        let mut temp_path = self.path.clone().into_os_string();
+        temp_path.push(".tmp");
+        let write_checkpoint = || -> std::io::Result<()> {
+            let mut file = fs::File::create(&temp_path)?;
+            file.write_all(checkpoint)?;
+            file.sync_all()?;
+            fs::rename(&temp_path, &self.path)
+        };
+        match write_checkpoint() {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                wedpr_println!("save checkpoint failed: {}", e);
+                Err(WedprError::ArgumentError)
+            },
+        }
+    }
+
+    fn load(&self) -> Result<Option<Vec<u8>>, WedprError> {
+        match fs::read(&self.path) {
+            Ok(v) => Ok(Some(v)),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => {
+                wedpr_println!("load checkpoint failed: {}", e);
+                Err(WedprError::ArgumentError)
+            },
+        }
+    }
+}
+
+type CipherPair = (RistrettoPoint, RistrettoPoint);
+
+// Decompressed ciphertexts of the ballots of a vote, or of the vote sum.
+#[derive(Clone, Default)]
+struct BallotPoints {
+    blank: CipherPair,
+    rest: Option<CipherPair>,
+    candidate: Vec<CipherPair>,
+    question: Vec<QuestionPoints>,
+    // only present in a for/against poll
+    against: Vec<CipherPair>,
+}
+
+#[derive(Clone, Default)]
+struct QuestionPoints {
+    candidate: Vec<CipherPair>,
+    rest: Option<CipherPair>,
+}
+
+fn ballot_to_points(ballot: &Ballot) -> Result<CipherPair, WedprError> {
+    Ok((
+        bytes_to_point(ballot.get_ciphertext1())?,
+        bytes_to_point(ballot.get_ciphertext2())?,
+    ))
+}
+
+fn points_to_ballot(points: &CipherPair) -> Ballot {
+    let mut ballot = Ballot::new();
+    ballot.set_ciphertext1(point_to_bytes(&points.0));
+    ballot.set_ciphertext2(point_to_bytes(&points.1));
+    ballot
+}
+
+fn points_to_candidate_ballot_list(
+    candidate_list: &[String],
+    points_list: &[CipherPair],
+) -> Vec<CandidateBallot> {
+    candidate_list
+        .iter()
+        .zip(points_list)
+        .map(|(candidate, points)| {
+            let mut ballot_pair = CandidateBallot::new();
+            ballot_pair.set_candidate(candidate.to_string());
+            ballot_pair.set_ballot(points_to_ballot(points));
+            ballot_pair
+        })
+        .collect()
+}
+
+// Adds the new points to the sum, and removes the replaced points if any.
+fn update_points(
+    sum: &mut CipherPair,
+    new_points: &CipherPair,
+    previous_points: Option<&CipherPair>,
+) {
+    sum.0 += new_points.0;
+    sum.1 += new_points.1;
+    if let Some(previous_points) = previous_points {
+        sum.0 -= previous_points.0;
+        sum.1 -= previous_points.1;
+    }
+}
+
+// Updates the sum of optional points, where an absent sum is treated as zero.
+fn update_optional_points(
+    sum: &mut Option<CipherPair>,
+    new_points: &Option<CipherPair>,
+    previous_points: Option<&Option<CipherPair>>,
+) {
+    if let Some(new_points) = new_points {
+        update_points(
+            sum.get_or_insert_with(Default::default),
+            new_points,
+            previous_points.and_then(|v| v.as_ref()),
+        );
+    }
+}
+
+impl BallotPoints {
+    fn zero(poll_parameters: &PollParametersStorage) -> Self {
+        BallotPoints {
+            blank: Default::default(),
+            rest: None,
+            candidate: vec![
+                Default::default();
+                poll_parameters
+                    .get_candidates()
+                    .get_candidate()
+                    .len()
+            ],
+            question: poll_parameters
+                .get_question()
+                .iter()
+                .map(|question| QuestionPoints {
+                    candidate: vec![
+                        Default::default();
+                        question
+                            .get_candidates()
+                            .get_candidate()
+                            .len()
+                    ],
+                    rest: None,
+                })
+                .collect(),
+            against: if poll_parameters.get_against_voting() {
+                vec![
+                    Default::default();
+                    poll_parameters.get_candidates().get_candidate().len()
+                ]
+            } else {
+                Vec::new()
+            },
+        }
+    }
+
+    // Decompresses the ballots of a vote in the order of the poll candidates.
+    fn from_vote(
+        poll_parameters: &PollParametersStorage,
+        vote: &VoteStorage,
+    ) -> Result<Self, WedprError> {
+        let mut candidate = Vec::new();
+        for candidate_id in poll_parameters.get_candidates().get_candidate() {
+            candidate.push(ballot_to_points(&get_ballot_by_candidate(
+                vote,
+                candidate_id,
+            )?)?);
+        }
+        let mut question = Vec::new();
+        for poll_question in poll_parameters.get_question() {
+            let question_ballot =
+                get_question_ballot(vote, poll_question.get_question_id())?;
+            let mut question_candidate = Vec::new();
+            for candidate_id in poll_question.get_candidates().get_candidate() {
+                question_candidate.push(ballot_to_points(
+                    &get_ballot_from_list(
+                        question_ballot.get_voted_ballot(),
+                        candidate_id,
+                    )?,
+                )?);
+            }
+            question.push(QuestionPoints {
+                candidate: question_candidate,
+                rest: if question_ballot.has_rest_ballot() {
+                    Some(ballot_to_points(question_ballot.get_rest_ballot())?)
+                } else {
+                    None
+                },
+            });
+        }
+        let mut against = Vec::new();
+        if poll_parameters.get_against_voting() {
+            for candidate_id in poll_parameters.get_candidates().get_candidate()
+            {
+                against.push(ballot_to_points(&get_ballot_from_list(
+                    vote.get_against_ballot(),
+                    candidate_id,
+                )?)?);
+            }
+        }
+        Ok(BallotPoints {
+            blank: ballot_to_points(&get_combined_blank_ballot(vote)?)?,
+            rest: if vote.has_rest_ballot() {
+                Some(ballot_to_points(vote.get_rest_ballot())?)
+            } else {
+                None
+            },
+            candidate,
+            question,
+            against,
+        })
+    }
+
+    // Adds the ballots of a vote, and removes the ballots of the vote it
+    // replaces if any.
+    fn update(
+        &mut self,
+        new_points: &BallotPoints,
+        previous_points: Option<&BallotPoints>,
+    ) {
+        update_points(
+            &mut self.blank,
+            &new_points.blank,
+            previous_points.map(|v| &v.blank),
+        );
+        update_optional_points(
+            &mut self.rest,
+            &new_points.rest,
+            previous_points.map(|v| &v.rest),
+        );
+        for (i, sum) in self.candidate.iter_mut().enumerate() {
+            update_points(
+                sum,
+                &new_points.candidate[i],
+                previous_points.map(|v| &v.candidate[i]),
+            );
+        }
+        for (i, question_sum) in self.question.iter_mut().enumerate() {
+            let previous_question = previous_points.map(|v| &v.question[i]);
+            for (j, sum) in question_sum.candidate.iter_mut().enumerate() {
+                update_points(
+                    sum,
+                    &new_points.question[i].candidate[j],
+                    previous_question.map(|v| &v.candidate[j]),
+                );
+            }
+            update_optional_points(
+                &mut question_sum.rest,
+                &new_points.question[i].rest,
+                previous_question.map(|v| &v.rest),
+            );
+        }
+        for (i, sum) in self.against.iter_mut().enumerate() {
+            update_points(
+                sum,
+                &new_points.against[i],
+                previous_points.map(|v| &v.against[i]),
+            );
+        }
+    }
+
+    // Writes the ballots back to the vote sum.
+    fn write_to(
+        &self,
+        poll_parameters: &PollParametersStorage,
+        vote_sum: &mut VoteStorage,
+    ) {
+        vote_sum.set_blank_ballot(points_to_ballot(&self.blank));
+        vote_sum.set_voted_ballot(
+            points_to_candidate_ballot_list(
+                poll_parameters.get_candidates().get_candidate(),
+                &self.candidate,
+            )
+            .into(),
+        );
+        if let Some(rest) = &self.rest {
+            vote_sum.set_rest_ballot(points_to_ballot(rest));
+        }
+        vote_sum.clear_question_ballot();
+        for (question, question_points) in
+            poll_parameters.get_question().iter().zip(&self.question)
+        {
+            let mut question_ballot = QuestionBallot::new();
+            question_ballot
+                .set_question_id(question.get_question_id().to_string());
+            question_ballot.set_voted_ballot(
+                points_to_candidate_ballot_list(
+                    question.get_candidates().get_candidate(),
+                    &question_points.candidate,
+                )
+                .into(),
+            );
+            if let Some(rest) = &question_points.rest {
+                question_ballot.set_rest_ballot(points_to_ballot(rest));
+            }
+            vote_sum.mut_question_ballot().push(question_ballot);
+        }
+        vote_sum.set_against_ballot(
+            points_to_candidate_ballot_list(
+                poll_parameters.get_candidates().get_candidate(),
+                &self.against,
+            )
+            .into(),
+        );
+    }
+}
+
+struct AggregatorState {
+    // the aggregated nullifiers and the audit trail of the vote sum
+    vote_sum: VoteStorage,
+    ballot_sum: BallotPoints,
+    accepted_votes: Vec<VoteStorage>,
+    // the index of the latest accepted vote of each nullifier
+    latest_votes: BTreeMap<Vec<u8>, usize>,
+}
+
+// Snapshot of the vote sum taken under the lock, and written to the store
+// after releasing it.
+struct CheckpointSnapshot {
+    vote_count: u64,
+    vote_sum: VoteStorage,
+}
+
+/// Aggregator of the votes of a poll, which keeps the vote sum in decompressed
+/// points instead of re-serializing them for every vote, and accepts votes
+/// from many threads. It writes a checksummed checkpoint of the vote sum to
+/// the store after every `checkpoint_interval` accepted votes, where 0 means
+/// only on request. The votes should have been verified before being added.
+pub struct VoteAggregator {
+    poll_parameters: PollParametersStorage,
+    store: Box<dyn CheckpointStore>,
+    checkpoint_interval: usize,
+    state: Mutex<AggregatorState>,
+    // the vote count of the latest saved checkpoint, which also orders the
+    // writes of the checkpoints taken concurrently
+    saved_vote_count: Mutex<u64>,
+}
+
+impl VoteAggregator {
+    pub fn new(
+        poll_parameters: &PollParametersStorage,
+        store: Box<dyn CheckpointStore>,
+        checkpoint_interval: usize,
+    ) -> Self {
+        VoteAggregator {
+            poll_parameters: poll_parameters.clone(),
+            store,
+            checkpoint_interval,
+            state: Mutex::new(AggregatorState {
+                vote_sum: VoteStorage::new(),
+                ballot_sum: BallotPoints::zero(poll_parameters),
+                accepted_votes: Vec::new(),
+                latest_votes: BTreeMap::new(),
+            }),
+            saved_vote_count: Mutex::new(0),
+        }
+    }
+
+    /// Restores an aggregator after a crash from the latest checkpoint in the
+    /// store and the log of accepted votes in the order of aggregation. The
+    /// ballot sum and the spent nullifiers are loaded from the checkpoint,
+    /// whose audit trail must match the prefix of the log, and only the
+    /// votes accepted after the checkpoint are aggregated again. It returns
+    /// a verification error if the checkpoint is corrupted, or does not
+    /// match the log.
+    pub fn restore(
+        poll_parameters: &PollParametersStorage,
+        store: Box<dyn CheckpointStore>,
+        checkpoint_interval: usize,
+        accepted_votes: &[VoteStorage],
+    ) -> Result<Self, WedprError> {
+        let aggregator =
+            VoteAggregator::new(poll_parameters, store, checkpoint_interval);
+        let mut checkpoint = match aggregator.store.load()? {
+            Some(v) => {
+                let checkpoint = bytes_to_proto::<AggregatorCheckpoint>(&v)?;
+                if checkpoint.get_checksum()
+                    != compute_checkpoint_checksum(
+                        poll_parameters.get_poll_id(),
+                        checkpoint.get_vote_count(),
+                        checkpoint.get_vote_sum(),
+                    )?
+                    .as_slice()
+                {
+                    wedpr_println!("verify checkpoint checksum failed!");
+                    return Err(WedprError::VerificationError);
+                }
+                checkpoint
+            },
+            None => AggregatorCheckpoint::new(),
+        };
+        let vote_count = checkpoint.get_vote_count() as usize;
+        if vote_count > accepted_votes.len() {
+            wedpr_println!("checkpoint is ahead of the accepted vote log!");
+            return Err(WedprError::VerificationError);
+        }
+        let audit_record_list = checkpoint.get_vote_sum().get_audit_record();
+        if audit_record_list.len() != vote_count {
+            wedpr_println!("checkpoint audit trail is incomplete!");
+            return Err(WedprError::VerificationError);
+        }
+        for (audit_record, vote) in
+            audit_record_list.iter().zip(&accepted_votes[..vote_count])
+        {
+            if audit_record.get_nullifier() != vote.get_nullifier()
+                || audit_record.get_vote_hash()
+                    != compute_vote_hash(vote)?.as_slice()
+            {
+                wedpr_println!("checkpoint does not match the vote log!");
+                return Err(WedprError::VerificationError);
+            }
+        }
+
+        {
+            let mut state = aggregator.lock_state()?;
+            // The vote sum is empty before the first vote.
+            if vote_count != 0 {
+                state.ballot_sum = BallotPoints::from_vote(
+                    poll_parameters,
+                    checkpoint.get_vote_sum(),
+                )?;
+                state.vote_sum = checkpoint.take_vote_sum();
+            }
+            for (index, vote) in accepted_votes[..vote_count].iter().enumerate()
+            {
+                state
+                    .latest_votes
+                    .insert(vote.get_nullifier().to_vec(), index);
+            }
+            state.accepted_votes = accepted_votes[..vote_count].to_vec();
+            aggregator.replay(&mut state, &accepted_votes[vote_count..])?;
+        }
+        aggregator.checkpoint()?;
+        Ok(aggregator)
+    }
+
+    /// Adds a vote to the vote sum. A vote with the nullifier of an accepted
+    /// vote replaces it as `replace_vote_sum_response` does. It returns false
+    /// without updating the vote sum if the vote has no nullifier, cannot
+    /// replace the accepted vote, or spends a nullifier already spent by a
+    /// delegation. The periodic checkpoint is written after releasing the
+    /// vote sum, and a failure to write it does not reject the vote: call
+    /// `checkpoint` to write it again and get the error.
+    pub fn add_vote(&self, vote: &VoteStorage) -> Result<bool, WedprError> {
+        if vote.get_nullifier().is_empty() {
+            return Ok(false);
+        }
+        // Decompress the points before locking, so that votes are decoded
+        // concurrently.
+        let ballot_points =
+            BallotPoints::from_vote(&self.poll_parameters, vote)?;
+        let snapshot = {
+            let mut state = self.lock_state()?;
+            if !self.aggregate(&mut state, vote, ballot_points)? {
+                return Ok(false);
+            }
+            if self.checkpoint_interval != 0
+                && state.accepted_votes.len() % self.checkpoint_interval == 0
+            {
+                Some(self.make_snapshot(&state))
+            } else {
+                None
+            }
+        };
+        if let Some(snapshot) = snapshot {
+            if self.write_checkpoint(snapshot).is_err() {
+                wedpr_println!("write periodic checkpoint failed!");
+            }
+        }
+        Ok(true)
+    }
+
+    /// Writes a checkpoint of the current vote sum to the store.
+    pub fn checkpoint(&self) -> Result<(), WedprError> {
+        let snapshot = {
+            let state = self.lock_state()?;
+            self.make_snapshot(&state)
+        };
+        self.write_checkpoint(snapshot)
+    }
+
+    /// Gets the current vote sum, which is the same as aggregating the
+    /// accepted votes with `aggregate_vote_sum_response` and
+    /// `replace_vote_sum_response` in the same order.
+    pub fn get_vote_sum(&self) -> Result<VoteStorage, WedprError> {
+        let state = self.lock_state()?;
+        Ok(self.make_vote_sum(&state))
+    }
+
+    /// Gets the log of accepted votes in the order of aggregation.
+    pub fn get_accepted_votes(&self) -> Result<Vec<VoteStorage>, WedprError> {
+        Ok(self.lock_state()?.accepted_votes.clone())
+    }
+
+    fn lock_state(&self) -> Result<MutexGuard<AggregatorState>, WedprError> {
+        match self.state.lock() {
+            Ok(v) => Ok(v),
+            Err(_) => {
+                wedpr_println!("aggregator state is poisoned!");
+                Err(WedprError::ArgumentError)
+            },
+        }
+    }
+
+    // Aggregates the accepted votes again, which must all be aggregated.
+    fn replay(
+        &self,
+        state: &mut AggregatorState,
+        accepted_votes: &[VoteStorage],
+    ) -> Result<(), WedprError> {
+        for vote in accepted_votes {
+            let ballot_points =
+                BallotPoints::from_vote(&self.poll_parameters, vote)?;
+            if !self.aggregate(state, vote, ballot_points)? {
+                wedpr_println!("aggregate accepted vote failed!");
+                return Err(WedprError::VerificationError);
+            }
+        }
+        Ok(())
+    }
+
+    fn aggregate(
+        &self,
+        state: &mut AggregatorState,
+        vote: &VoteStorage,
+        ballot_points: BallotPoints,
+    ) -> Result<bool, WedprError> {
+        let nullifier = vote.get_nullifier();
+        let mut audit_record = VoteAuditRecord::new();
+        audit_record.set_nullifier(nullifier.to_vec());
+        audit_record.set_sequence(vote.get_sequence());
+        audit_record.set_vote_hash(compute_vote_hash(vote)?);
+        match state.latest_votes.get(nullifier) {
+            Some(previous_index) => {
+                let previous_vote = &state.accepted_votes[*previous_index];
+                let previous_vote_hash = match check_vote_replacement(
+                    previous_vote,
+                    vote,
+                    &state.vote_sum,
+                )? {
+                    Some(v) => v,
+                    None => return Ok(false),
+                };
+                // Replacements are rare, so the ballots of the replaced vote
+                // are decompressed again instead of being kept.
+                let previous_points = BallotPoints::from_vote(
+                    &self.poll_parameters,
+                    previous_vote,
+                )?;
+                state
+                    .ballot_sum
+                    .update(&ballot_points, Some(&previous_points));
+                audit_record.set_replaced_vote_hash(previous_vote_hash);
+            },
+            None => {
+                // The nullifiers of the delegations are spent together with
+                // the nullifier of the vote, and can not be spent again.
+                let mut spent_nullifier_set = BTreeSet::new();
+                spent_nullifier_set.insert(nullifier);
+                for delegation in vote.get_delegation() {
+                    if !spent_nullifier_set.insert(delegation.get_nullifier()) {
+                        return Ok(false);
+                    }
+                }
+                let aggregated_nullifier =
+                    state.vote_sum.mut_aggregated_nullifier();
+                let mut nullifier_index_list = Vec::new();
+                for spent_nullifier in &spent_nullifier_set {
+                    match aggregated_nullifier
+                        .binary_search_by(|v| v.as_slice().cmp(spent_nullifier))
+                    {
+                        Ok(_) => return Ok(false),
+                        Err(index) => nullifier_index_list.push(index),
+                    }
+                }
+                // Insert from the last index so that earlier indexes are kept.
+                for (spent_nullifier, nullifier_index) in
+                    spent_nullifier_set.iter().zip(nullifier_index_list).rev()
+                {
+                    aggregated_nullifier
+                        .insert(nullifier_index, spent_nullifier.to_vec());
+                }
+                state.ballot_sum.update(&ballot_points, None);
+            },
+        }
+        append_vote_audit_record(&mut state.vote_sum, audit_record);
+        state
+            .latest_votes
+            .insert(nullifier.to_vec(), state.accepted_votes.len());
+        state.accepted_votes.push(vote.clone());
+        Ok(true)
+    }
+
+    fn make_vote_sum(&self, state: &AggregatorState) -> VoteStorage {
+        // The vote sum is empty before the first vote.
+        if state.accepted_votes.is_empty() {
+            return VoteStorage::new();
+        }
+        let mut vote_sum = state.vote_sum.clone();
+        state
+            .ballot_sum
+            .write_to(&self.poll_parameters, &mut vote_sum);
+        vote_sum
+    }
+
+    fn make_snapshot(&self, state: &AggregatorState) -> CheckpointSnapshot {
+        CheckpointSnapshot {
+            vote_count: state.accepted_votes.len() as u64,
+            vote_sum: self.make_vote_sum(state),
+        }
+    }
+
+    fn write_checkpoint(
+        &self,
+        snapshot: CheckpointSnapshot,
+    ) -> Result<(), WedprError> {
+        let mut checkpoint = AggregatorCheckpoint::new();
+        checkpoint.set_checksum(compute_checkpoint_checksum(
+            self.poll_parameters.get_poll_id(),
+            snapshot.vote_count,
+            &snapshot.vote_sum,
+        )?);
+        checkpoint.set_vote_count(snapshot.vote_count);
+        checkpoint.set_vote_sum(snapshot.vote_sum);
+        let checkpoint_bytes = proto_to_bytes(&checkpoint)?;
+        let mut saved_vote_count = match self.saved_vote_count.lock() {
+            Ok(v) => v,
+            Err(_) => {
+                wedpr_println!("aggregator checkpoint is poisoned!");
+                return Err(WedprError::ArgumentError);
+            },
+        };
+        // A snapshot taken before the saved checkpoint is outdated.
+        if snapshot.vote_count < *saved_vote_count {
+            return Ok(());
+        }
+        self.store.save(&checkpoint_bytes)?;
+        *saved_vote_count = snapshot.vote_count;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{coordinator, test_utils::TestPoll, voter};
+
+    #[test]
+    fn test_vote_aggregator() {
+        let test_poll = TestPoll::new(&["1001"], &["Kitten", "Doge"]);
+        let checkpoint_path = std::env::temp_dir()
+            .join(format!("wedpr_acv_checkpoint_{}", std::process::id()));
+        let _ = std::fs::remove_file(&checkpoint_path);
+
+        let mut vote_secret_list = vec![];
+        let mut registration_response_list = vec![];
+        let mut vote_list = vec![];
+        for choice in [vec![1, 2], vec![3, 4], vec![5, 0], vec![0, 6]] {
+            let (vote_secret, registration_response) =
+                test_poll.register_voter(10);
+            let vote_request = voter::vote(
+                &vote_secret,
+                &voter::make_vote_choices(&choice, &test_poll.candidate_list),
+                &registration_response,
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            )
+            .unwrap();
+            vote_list.push(vote_request.get_vote().clone());
+            vote_secret_list.push(vote_secret);
+            registration_response_list.push(registration_response);
+        }
+
+        // Votes are accepted concurrently, and a checkpoint is written after
+        // every two votes.
+        let aggregator = std::sync::Arc::new(VoteAggregator::new(
+            &test_poll.poll_parameters,
+            Box::new(FileCheckpointStore::new(&checkpoint_path)),
+            2,
+        ));
+        let handle_list: Vec<_> = vote_list
+            .iter()
+            .cloned()
+            .map(|vote| {
+                let aggregator = aggregator.clone();
+                std::thread::spawn(move || aggregator.add_vote(&vote).unwrap())
+            })
+            .collect();
+        for handle in handle_list {
+            assert!(handle.join().unwrap());
+        }
+        assert!(!aggregator.add_vote(&vote_list[0]).unwrap());
+        let mut vote_choices =
+            voter::make_vote_choices(&vec![7, 3], &test_poll.candidate_list);
+        vote_choices.set_sequence(1);
+        let new_vote = voter::vote(
+            &vote_secret_list[0],
+            &vote_choices,
+            &registration_response_list[0],
+            &test_poll.poll_parameters,
+            &test_poll.public_key,
+        )
+        .unwrap()
+        .get_vote()
+        .clone();
+        assert!(aggregator.add_vote(&new_vote).unwrap());
+
+        // The vote sum is the same as aggregating the accepted votes one by
+        // one.
+        let accepted_votes = aggregator.get_accepted_votes().unwrap();
+        assert_eq!(accepted_votes.len(), 5);
+        let mut expected_vote_sum = VoteStorage::new();
+        for vote in &accepted_votes[..4] {
+            assert!(coordinator::aggregate_vote_sum_response(
+                &test_poll.poll_parameters,
+                vote,
+                &mut expected_vote_sum
+            )
+            .unwrap());
+        }
+        assert!(coordinator::replace_vote_sum_response(
+            &test_poll.poll_parameters,
+            &vote_list[0],
+            &new_vote,
+            &mut expected_vote_sum
+        )
+        .unwrap());
+        let vote_sum = aggregator.get_vote_sum().unwrap();
+        assert_eq!(vote_sum, expected_vote_sum);
+
+        // The aggregator is restored from the checkpoint of four votes and the
+        // accepted vote log.
+        let restored_aggregator = VoteAggregator::restore(
+            &test_poll.poll_parameters,
+            Box::new(FileCheckpointStore::new(&checkpoint_path)),
+            2,
+            &accepted_votes,
+        )
+        .unwrap();
+        assert_eq!(restored_aggregator.get_vote_sum().unwrap(), vote_sum);
+
+        // A log not matching the checkpoint or a corrupted checkpoint is
+        // rejected.
+        assert!(VoteAggregator::restore(
+            &test_poll.poll_parameters,
+            Box::new(FileCheckpointStore::new(&checkpoint_path)),
+            2,
+            &accepted_votes[1..],
+        )
+        .is_err());
+        let mut reordered_votes = accepted_votes.clone();
+        reordered_votes.swap(0, 1);
+        aggregator.checkpoint().unwrap();
+        assert!(VoteAggregator::restore(
+            &test_poll.poll_parameters,
+            Box::new(FileCheckpointStore::new(&checkpoint_path)),
+            2,
+            &reordered_votes,
+        )
+        .is_err());
+        let mut checkpoint = std::fs::read(&checkpoint_path).unwrap();
+        let last = checkpoint.len() - 1;
+        checkpoint[last] ^= 1;
+        std::fs::write(&checkpoint_path, &checkpoint).unwrap();
+        assert!(VoteAggregator::restore(
+            &test_poll.poll_parameters,
+            Box::new(FileCheckpointStore::new(&checkpoint_path)),
+            2,
+            &accepted_votes,
+        )
+        .is_err());
+        std::fs::remove_file(&checkpoint_path).unwrap();
+
+        // A vote is accepted even if its checkpoint cannot be written, and the
+        // failure is reported by writing the checkpoint on request.
+        struct UnavailableStore;
+        impl CheckpointStore for UnavailableStore {
+            fn save(&self, _: &[u8]) -> Result<(), WedprError> {
+                Err(WedprError::ArgumentError)
+            }
+
+            fn load(&self) -> Result<Option<Vec<u8>>, WedprError> {
+                Ok(None)
+            }
+        }
+        let aggregator = VoteAggregator::new(
+            &test_poll.poll_parameters,
+            Box::new(UnavailableStore),
+            1,
+        );
+        assert!(aggregator.add_vote(&vote_list[1]).unwrap());
+        assert!(aggregator.checkpoint().is_err());
+    }
+}
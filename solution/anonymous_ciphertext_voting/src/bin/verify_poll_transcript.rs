@@ -0,0 +1,39 @@
+// Copyright 2021 WeDPR Lab Project Authors. Licensed under Apache-2.0.
+
+//! Tool to verify the transcript of a finished poll.
+
+use std::{env, fs, process};
+use wedpr_s_anonymous_ciphertext_voting::verifier::verify_poll_transcript;
+use wedpr_s_protos::{bytes_to_proto, generated::acv::PollTranscript};
+
+/// Uses `cargo run --bin verify_poll_transcript <transcript_file>` to verify a
+/// protobuf encoded `PollTranscript` file. It exits with a non-zero code if
+/// the transcript is invalid.
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() != 2 {
+        eprintln!("Usage: {} <transcript_file>", args[0]);
+        process::exit(2);
+    }
+    let transcript_bytes = match fs::read(&args[1]) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("Failed to read {}: {}", args[1], e);
+            process::exit(2);
+        },
+    };
+    let transcript = match bytes_to_proto::<PollTranscript>(&transcript_bytes) {
+        Ok(v) => v,
+        Err(_) => {
+            eprintln!("Failed to decode the poll transcript.");
+            process::exit(2);
+        },
+    };
+    match verify_poll_transcript(&transcript) {
+        Ok(true) => println!("The poll transcript is valid."),
+        _ => {
+            println!("The poll transcript is invalid!");
+            process::exit(1);
+        },
+    }
+}
@@ -1,3 +1,63 @@
 // Copyright 2020 WeDPR Lab Project Authors. Licensed under Apache-2.0.
 /// Poll result key for total ballots.
 pub const POLL_RESULT_KEY_TOTAL_BALLOTS: &str = "Wedpr_voting_total_ballots";
+/// Poll result key for abstained ballots, i.e. the unused votes of all voters.
+pub const POLL_RESULT_KEY_ABSTAINED_BALLOTS: &str =
+    "Wedpr_voting_abstained_ballots";
+/// Maximum id of an unlisted candidate, which bounds the decryption of the
+/// unlisted candidate ids by exhaustive search.
+pub const MAX_UNLISTED_CANDIDATE_ID: u32 = 1 << 16;
+/// Domain separator for deriving the poll-specific nullifier basepoint.
+pub const NULLIFIER_BASEPOINT_DOMAIN: &str = "Wedpr_voting_nullifier_basepoint";
+/// Domain separator for deriving the basepoint binding a vote sequence number.
+pub const SEQUENCE_BINDING_BASEPOINT_DOMAIN: &str =
+    "Wedpr_voting_sequence_binding_basepoint";
+/// Domain separator for deriving the common information point of blind
+/// certificates.
+pub const BLIND_CERTIFICATE_INFO_DOMAIN: &str =
+    "Wedpr_voting_blind_certificate_info";
+/// Domain separator for computing the challenge of blind certificates.
+pub const BLIND_CERTIFICATE_CHALLENGE_DOMAIN: &str =
+    "Wedpr_voting_blind_certificate_challenge";
+/// Domain separator for deriving the key of the encrypted voter weight.
+pub const CONFIDENTIAL_WEIGHT_KEY_DOMAIN: &str =
+    "Wedpr_voting_confidential_weight_key";
+/// Domain separator for computing the challenge bits of a shuffle proof.
+pub const SHUFFLE_CHALLENGE_DOMAIN: &str = "Wedpr_voting_shuffle_challenge";
+/// Number of rounds of a shuffle proof, a cheating shuffle passes each round
+/// with probability 1/2.
+pub const SHUFFLE_PROOF_ROUNDS: usize = 64;
+/// Maximum vote difference of a ranking comparison. Each counter blinds and
+/// shuffles max_difference + 1 differences with a shadow shuffle for every
+/// shuffle proof round, so the bound keeps the cost of mixing a comparison
+/// within a few megabytes.
+pub const MAX_RANKING_DIFFERENCE: u32 = 1 << 12;
+/// Domain separator for hashing a vote into a leaf of the vote Merkle tree.
+pub const VOTE_LEAF_HASH_DOMAIN: &str = "Wedpr_voting_vote_leaf_hash";
+/// Domain separator for hashing two children into a node of the vote Merkle
+/// tree.
+pub const VOTE_NODE_HASH_DOMAIN: &str = "Wedpr_voting_vote_node_hash";
+/// Domain separator for computing the id of a poll.
+pub const POLL_ID_DOMAIN: &str = "Wedpr_voting_poll_id";
+/// Domain separator for computing the checksum of an aggregator checkpoint.
+pub const CHECKPOINT_CHECKSUM_DOMAIN: &str = "Wedpr_voting_checkpoint_checksum";
+/// Domain separator for computing the challenge of a credential proof.
+pub const CREDENTIAL_CHALLENGE_DOMAIN: &str =
+    "Wedpr_voting_credential_challenge";
+/// Domain separator for computing the challenge combining the credential and
+/// the blank ballot in the plaintext equivalence tests against the roster.
+pub const CREDENTIAL_MATCH_CHALLENGE_DOMAIN: &str =
+    "Wedpr_voting_credential_match_challenge";
+/// Domain separator for computing the challenge of an aggregated format proof.
+pub const AGGREGATED_FORMAT_CHALLENGE_DOMAIN: &str =
+    "Wedpr_voting_aggregated_format_challenge";
+/// Domain separator for computing the challenge of an aggregated
+/// either-equality proof.
+pub const AGGREGATED_EITHER_EQUALITY_CHALLENGE_DOMAIN: &str =
+    "Wedpr_voting_aggregated_either_equality_challenge";
+/// Domain separator for deriving the blinding of a delegation transfer ballot.
+pub const DELEGATION_BLINDING_DOMAIN: &str = "Wedpr_voting_delegation_blinding";
+/// Domain separator for computing the challenge of a delegation membership
+/// proof.
+pub const DELEGATION_MEMBERSHIP_CHALLENGE_DOMAIN: &str =
+    "Wedpr_voting_delegation_membership_challenge";
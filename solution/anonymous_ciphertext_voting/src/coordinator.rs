@@ -2,30 +2,60 @@
 
 //! Library for a poll coordinator.
 
-use curve25519_dalek::{ristretto::RistrettoPoint, scalar::Scalar};
-use wedpr_l_crypto_zkp_utils::{bytes_to_point, point_to_bytes, BASEPOINT_G1};
+use curve25519_dalek::{
+    ristretto::RistrettoPoint, scalar::Scalar, traits::MultiscalarMul,
+};
+use wedpr_l_crypto_zkp_utils::{
+    bytes_to_point, bytes_to_scalar, get_random_scalar, point_to_bytes,
+    scalar_to_bytes, BASEPOINT_G1, BASEPOINT_G2,
+};
 use wedpr_l_utils::error::WedprError;
 
 use wedpr_s_protos::{
+    config::BALLOT_SIGNATURE_VERSION,
     generate_ballot_signature, generate_ballots_signature,
+    generate_poll_signature,
     generated::acv::{
-        Ballot, CandidateBallot, CandidateList, CounterParametersStorage,
-        CountingPart, DecryptedResultPartStorage, PollParametersStorage,
-        RegistrationRequest, RegistrationResponse, StringToCountingPartPair,
-        StringToInt64Pair, UnlistedBallotDecryptedResult, UnlistedVoteChoice,
-        VoteResultStorage, VoteStorage,
+        Ballot, BlindCertificationCommitment, BlindCertificationRequest,
+        BlindCertificationResponse, BlindCertificationSecret, CandidateBallot,
+        CandidateList, CipherPoints, CipherPointsToBallotPair,
+        CounterBlameReason, CounterBlameReport, CounterParametersStorage,
+        CountingPart, CredentialBlindingPart, CredentialDecryptionPart,
+        CredentialMixPart, CredentialRosterEntry, DecryptedResultPartStorage,
+        PollParametersStorage, Question, QuestionBallot, QuestionCountingPart,
+        QuestionResult, RankingComparison, RegistrationRequest,
+        RegistrationResponse, StringToCountingPartPair, StringToInt64Pair,
+        UnlistedBallotDecryptedResult, UnlistedGroupingStorage,
+        UnlistedVoteChoice, VoteAuditRecord, VoteRanking, VoteReceipt,
+        VoteResultStorage, VoteStorage, VoterCredential,
     },
 };
 
 use crate::{
-    config::POLL_RESULT_KEY_TOTAL_BALLOTS,
-    utils::{get_ballot_by_candidate, get_counting_part_by_candidate},
+    config::{
+        MAX_RANKING_DIFFERENCE, MAX_UNLISTED_CANDIDATE_ID,
+        POLL_RESULT_KEY_ABSTAINED_BALLOTS, POLL_RESULT_KEY_TOTAL_BALLOTS,
+    },
+    utils::{
+        append_vote_audit_record, compute_poll_id, compute_vote_hash,
+        get_ballot_by_candidate, get_ballot_from_list,
+        get_blind_certificate_info_point, get_combined_blank_ballot,
+        get_confidential_weight_key, get_counting_part_by_candidate,
+        get_counting_part_from_list, get_credential_duplicate_pair_list,
+        get_question_ballot, get_question_counting_part,
+        get_ranking_comparison_pair_list, get_vote_merkle_path,
+    },
+    verifier::blame_checked_count_request,
 };
 
-use std::collections::BTreeMap;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::{Mutex, MutexGuard},
+};
 
-/// Makes system parameters for a new poll.
-pub fn make_poll_parameters(
+// Makes the unsigned system parameters for a new poll, which aggregates the
+// poll point from the shares of all counters.
+pub(crate) fn make_unsigned_poll_parameters(
     candidate_list: &CandidateList,
     counter_parameters: &CounterParametersStorage,
 ) -> Result<PollParametersStorage, WedprError> {
@@ -37,12 +67,150 @@ pub fn make_poll_parameters(
     let mut storage = PollParametersStorage::default();
     storage.set_candidates(candidate_list.clone());
     storage.set_poll_point(point_to_bytes(&poll_point));
+    storage.set_counter_parameters(counter_parameters.clone());
+    storage.set_ballot_signature_version(BALLOT_SIGNATURE_VERSION);
+    Ok(storage)
+}
+
+/// Commits to all the poll parameters by the poll id and signs it with the
+/// signature scheme of the poll. It must be called again whenever the
+/// parameters are changed, e.g. after selecting another signature scheme.
+pub fn sign_poll_parameters(
+    poll_parameters: &mut PollParametersStorage,
+    secret_key: &[u8],
+) -> Result<(), WedprError> {
+    poll_parameters.set_poll_id(compute_poll_id(poll_parameters)?);
+    let poll_signature = generate_poll_signature(poll_parameters, secret_key)?;
+    poll_parameters.set_poll_signature(poll_signature);
+    Ok(())
+}
+
+/// Makes system parameters for a new poll, signed by the coordinator so that
+/// the candidates cannot be changed after the poll is published.
+pub fn make_poll_parameters(
+    candidate_list: &CandidateList,
+    counter_parameters: &CounterParametersStorage,
+    secret_key: &[u8],
+) -> Result<PollParametersStorage, WedprError> {
+    let mut storage =
+        make_unsigned_poll_parameters(candidate_list, counter_parameters)?;
+    sign_poll_parameters(&mut storage, secret_key)?;
+    Ok(storage)
+}
+
+/// Makes system parameters for a new poll accepting unlisted candidates, whose
+/// ids must be in the range of [min_unlisted_candidate_id,
+/// max_unlisted_candidate_id]. The range must start from 1, since a poll with
+/// the range of [0, 0] does not accept unlisted candidates, end at most at
+/// MAX_UNLISTED_CANDIDATE_ID to keep the ids decryptable, and contain no id of
+/// a listed candidate.
+pub fn make_poll_parameters_unlisted(
+    candidate_list: &CandidateList,
+    counter_parameters: &CounterParametersStorage,
+    secret_key: &[u8],
+    min_unlisted_candidate_id: u32,
+    max_unlisted_candidate_id: u32,
+) -> Result<PollParametersStorage, WedprError> {
+    if min_unlisted_candidate_id == 0
+        || min_unlisted_candidate_id > max_unlisted_candidate_id
+        || max_unlisted_candidate_id > MAX_UNLISTED_CANDIDATE_ID
+    {
+        wedpr_println!(
+            "unlisted candidate ids should be in [1, {}]!",
+            MAX_UNLISTED_CANDIDATE_ID
+        );
+        return Err(WedprError::ArgumentError);
+    }
+    let unlisted_candidate_ids =
+        min_unlisted_candidate_id..=max_unlisted_candidate_id;
+    if let Some(candidate) =
+        candidate_list.get_candidate().iter().find(|candidate| {
+            candidate
+                .parse::<u32>()
+                .map_or(false, |id| unlisted_candidate_ids.contains(&id))
+        })
+    {
+        wedpr_println!(
+            "listed candidate {} is in the range of unlisted candidate ids!",
+            candidate
+        );
+        return Err(WedprError::ArgumentError);
+    }
+    let mut storage =
+        make_unsigned_poll_parameters(candidate_list, counter_parameters)?;
+    storage.set_min_unlisted_candidate_id(min_unlisted_candidate_id);
+    storage.set_max_unlisted_candidate_id(max_unlisted_candidate_id);
+    sign_poll_parameters(&mut storage, secret_key)?;
+    Ok(storage)
+}
+
+/// Makes system parameters for a new for/against poll, where each candidate
+/// has separate ballots for and against it, and the vote result reports the
+/// net support of each candidate.
+pub fn make_poll_parameters_for_against(
+    candidate_list: &CandidateList,
+    counter_parameters: &CounterParametersStorage,
+    secret_key: &[u8],
+) -> Result<PollParametersStorage, WedprError> {
+    let mut storage =
+        make_unsigned_poll_parameters(candidate_list, counter_parameters)?;
+    storage.set_against_voting(true);
+    sign_poll_parameters(&mut storage, secret_key)?;
+    Ok(storage)
+}
+
+/// Makes system parameters for a new multi-question poll, where each question
+/// has its own candidates and sum constraint, but all questions share the same
+/// registration, counters and counting round.
+pub fn make_poll_parameters_multi_question(
+    question_list: &[Question],
+    counter_parameters: &CounterParametersStorage,
+    secret_key: &[u8],
+) -> Result<PollParametersStorage, WedprError> {
+    let mut question_id_set = BTreeSet::new();
+    if !question_list
+        .iter()
+        .all(|question| question_id_set.insert(question.get_question_id()))
+    {
+        wedpr_println!("duplicate question ids");
+        return Err(WedprError::ArgumentError);
+    }
+    let mut storage = make_unsigned_poll_parameters(
+        &CandidateList::new(),
+        counter_parameters,
+    )?;
+    for question in question_list {
+        storage.mut_question().push(question.clone());
+    }
+    sign_poll_parameters(&mut storage, secret_key)?;
     Ok(storage)
 }
 
 /// Certifies a voter's registration. It confirm its weight which indicates the
-/// maximum votes that the voter can vote for all candidates in a poll.
+/// maximum votes that the voter can vote for all candidates in a poll. The
+/// blank ballot is signed with the signature scheme of the poll, and the
+/// signature is bound to the poll and the weight encrypted in the blank ballot.
+/// The weight is only returned to the voter, and is not published in the
+/// votes.
 pub fn certify_voter(
+    poll_parameters: &PollParametersStorage,
+    secret_key: &[u8],
+    registration_request: &RegistrationRequest,
+    voter_weight: u32,
+) -> Result<RegistrationResponse, WedprError> {
+    let mut response = make_signed_blank_ballot(
+        poll_parameters,
+        secret_key,
+        registration_request,
+        voter_weight,
+    )?;
+    response.set_voter_weight(voter_weight);
+    Ok(response)
+}
+
+// Makes the blank ballot of the voter weight and signs it.
+fn make_signed_blank_ballot(
+    poll_parameters: &PollParametersStorage,
     secret_key: &[u8],
     registration_request: &RegistrationRequest,
     voter_weight: u32,
@@ -64,13 +232,184 @@ pub fn certify_voter(
     );
     let mut response = RegistrationResponse::new();
     // Sign the above data.
-    response.set_signature(generate_ballot_signature(secret_key, &ballot)?);
+    response.set_signature(generate_ballot_signature(
+        poll_parameters,
+        secret_key,
+        &ballot,
+    )?);
+    response.set_signature_scheme(poll_parameters.get_signature_scheme());
     response.set_ballot(ballot);
-    response.set_voter_weight(voter_weight as u32);
     Ok(response)
 }
 
+/// Certifies a voter's registration with a confidential weight. The weight is
+/// only encrypted in the blank ballot and encrypted for the voter, so anyone
+/// else seeing the response cannot learn it. The split of the weight is proved
+/// by the balance proof of the vote, and only the total weight of all voters
+/// is revealed when the poll is finalized.
+pub fn certify_voter_confidential(
+    poll_parameters: &PollParametersStorage,
+    secret_key: &[u8],
+    registration_request: &RegistrationRequest,
+    voter_weight: u32,
+) -> Result<RegistrationResponse, WedprError> {
+    let mut response = make_signed_blank_ballot(
+        poll_parameters,
+        secret_key,
+        registration_request,
+        voter_weight,
+    )?;
+    let blinding_basepoint_g2 = bytes_to_point(
+        registration_request
+            .get_weight_point()
+            .get_blinding_basepoint_g2(),
+    )?;
+    let ephemeral_secret = get_random_scalar();
+    let weight_key = get_confidential_weight_key(
+        &(blinding_basepoint_g2 * ephemeral_secret),
+    );
+    response.set_weight_key_point(point_to_bytes(
+        &(*BASEPOINT_G2 * ephemeral_secret),
+    ));
+    response.set_encrypted_voter_weight(voter_weight ^ weight_key);
+    Ok(response)
+}
+
+/// Makes a key pair for certifying voters blindly, and returns the public key
+/// and the secret key.
+pub fn make_blind_certification_keypair() -> (Vec<u8>, Vec<u8>) {
+    let secret_key = get_random_scalar();
+    let public_key = *BASEPOINT_G1 * secret_key;
+    (point_to_bytes(&public_key), scalar_to_bytes(&secret_key))
+}
+
+/// Certifier of voters with blind certificates, which keeps the session secret
+/// of the open blind certification session. Finishing many concurrent sessions
+/// of blind Schnorr signatures allows forging certificates, so it opens at
+/// most one session at a time for its key, and refuses to begin another
+/// session until the open one is finished or cancelled.
+pub struct BlindCertifier {
+    secret_key: Vec<u8>,
+    session_secret: Mutex<Option<BlindCertificationSecret>>,
+}
+
+impl BlindCertifier {
+    pub fn new(secret_key: &[u8]) -> Self {
+        BlindCertifier {
+            secret_key: secret_key.to_vec(),
+            session_secret: Mutex::new(None),
+        }
+    }
+
+    /// Starts a blind certification session for a voter of the given weight.
+    /// It returns an argument error if another session is still open.
+    pub fn begin_blind_certification(
+        &self,
+        poll_parameters: &PollParametersStorage,
+        voter_weight: u32,
+    ) -> Result<BlindCertificationCommitment, WedprError> {
+        let mut session_secret = self.lock_session_secret()?;
+        if session_secret.is_some() {
+            wedpr_println!("another blind certification session is open!");
+            return Err(WedprError::ArgumentError);
+        }
+        let (secret, commitment) =
+            begin_blind_certification(poll_parameters, voter_weight)?;
+        *session_secret = Some(secret);
+        Ok(commitment)
+    }
+
+    /// Certifies a voter's blank ballot blindly to finish the open blind
+    /// certification session. The coordinator never sees the blank ballot, so
+    /// it cannot link the vote of the voter to this registration. The session
+    /// is closed even if the request is invalid, since its secret must only be
+    /// used once.
+    pub fn certify_voter_blindly(
+        &self,
+        request: &BlindCertificationRequest,
+    ) -> Result<BlindCertificationResponse, WedprError> {
+        let open_secret = self.lock_session_secret()?.take();
+        let secret = match open_secret {
+            Some(v) => v,
+            None => {
+                wedpr_println!("no blind certification session is open!");
+                return Err(WedprError::ArgumentError);
+            },
+        };
+        certify_voter_blindly(&self.secret_key, &secret, request)
+    }
+
+    /// Cancels the open blind certification session without certifying the
+    /// voter, e.g. when the voter does not send the request in time.
+    pub fn cancel_blind_certification(&self) -> Result<(), WedprError> {
+        *self.lock_session_secret()? = None;
+        Ok(())
+    }
+
+    fn lock_session_secret(
+        &self,
+    ) -> Result<MutexGuard<Option<BlindCertificationSecret>>, WedprError> {
+        match self.session_secret.lock() {
+            Ok(v) => Ok(v),
+            Err(_) => {
+                wedpr_println!("blind certification session is poisoned!");
+                Err(WedprError::ArgumentError)
+            },
+        }
+    }
+}
+
+// Starts a blind certification session for a voter of the given weight. The
+// returned secret must only be used once to finish this session.
+fn begin_blind_certification(
+    poll_parameters: &PollParametersStorage,
+    voter_weight: u32,
+) -> Result<(BlindCertificationSecret, BlindCertificationCommitment), WedprError>
+{
+    let info_point =
+        get_blind_certificate_info_point(poll_parameters, voter_weight)?;
+    let u = get_random_scalar();
+    let s = get_random_scalar();
+    let d = get_random_scalar();
+    let commitment_a = *BASEPOINT_G1 * u;
+    let commitment_b =
+        RistrettoPoint::multiscalar_mul(&[s, d], &[*BASEPOINT_G1, info_point]);
+    let mut session_secret = BlindCertificationSecret::new();
+    session_secret.set_u(scalar_to_bytes(&u));
+    session_secret.set_s(scalar_to_bytes(&s));
+    session_secret.set_d(scalar_to_bytes(&d));
+    let mut commitment = BlindCertificationCommitment::new();
+    commitment.set_voter_weight(voter_weight);
+    commitment.set_commitment_a(point_to_bytes(&commitment_a));
+    commitment.set_commitment_b(point_to_bytes(&commitment_b));
+    Ok((session_secret, commitment))
+}
+
+// Finishes a blind certification session with its session secret.
+fn certify_voter_blindly(
+    secret_key: &[u8],
+    session_secret: &BlindCertificationSecret,
+    request: &BlindCertificationRequest,
+) -> Result<BlindCertificationResponse, WedprError> {
+    let x = bytes_to_scalar(secret_key)?;
+    let u = bytes_to_scalar(session_secret.get_u())?;
+    let d = bytes_to_scalar(session_secret.get_d())?;
+    let blinded_challenge = bytes_to_scalar(request.get_blinded_challenge())?;
+    let c = blinded_challenge - d;
+    let r = u - c * x;
+    let mut response = BlindCertificationResponse::new();
+    response.set_r(scalar_to_bytes(&r));
+    response.set_c(scalar_to_bytes(&c));
+    response.set_s(session_secret.get_s().to_vec());
+    response.set_d(session_secret.get_d().to_vec());
+    Ok(response)
+}
+
+/// Certifies an unbounded voter's registration. The weight and zero ballots
+/// are signed with the signature scheme of the poll, and the signature is bound
+/// to the poll and the weight encrypted in the weight ballot.
 pub fn certify_unbounded_voter(
+    poll_parameters: &PollParametersStorage,
     secret_key: &[u8],
     registration_request: &RegistrationRequest,
     value: u32,
@@ -108,22 +447,80 @@ pub fn certify_unbounded_voter(
 
     let mut response = RegistrationResponse::new();
     response.set_signature(generate_ballots_signature(
+        poll_parameters,
         secret_key,
         &weight_ballot,
         &zero_ballot,
     )?);
+    response.set_signature_scheme(poll_parameters.get_signature_scheme());
     response.set_ballot(weight_ballot);
     response.set_zero_ballot(zero_ballot);
     response.set_voter_weight(value);
     Ok(response)
 }
 
-/// Aggregates all ciphertext ballots from a voter.
+/// Issues the secret credential of a certified voter for coercion-resistant
+/// voting, and makes its entry on the credential roster. The credential should
+/// be sent to the voter through an untappable channel, and the entry should be
+/// published on the roster.
+pub fn issue_voter_credential(
+    poll_parameters: &PollParametersStorage,
+    registration_response: &RegistrationResponse,
+) -> Result<(VoterCredential, CredentialRosterEntry), WedprError> {
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+    let credential = get_random_scalar();
+    let blinding = get_random_scalar();
+    let mut roster_entry = CredentialRosterEntry::new();
+    roster_entry.set_blank_ballot(registration_response.get_ballot().clone());
+    let encrypted_credential = roster_entry.mut_encrypted_credential();
+    encrypted_credential.set_ciphertext1(point_to_bytes(
+        &RistrettoPoint::multiscalar_mul(&[credential, blinding], &[
+            *BASEPOINT_G1,
+            poll_point,
+        ]),
+    ));
+    encrypted_credential
+        .set_ciphertext2(point_to_bytes(&(*BASEPOINT_G2 * blinding)));
+    let mut voter_credential = VoterCredential::new();
+    voter_credential.set_credential(scalar_to_bytes(&credential));
+    Ok((voter_credential, roster_entry))
+}
+
+/// Aggregates all ciphertext ballots from a voter. It returns false without
+/// updating the vote sum if the nullifier of the vote has been aggregated.
 pub fn aggregate_vote_sum_response(
     poll_parameters: &PollParametersStorage,
     vote_part: &VoteStorage,
     vote_sum: &mut VoteStorage,
 ) -> Result<bool, WedprError> {
+    // Reject double voting, the aggregated nullifiers are kept sorted. The
+    // nullifiers of the delegations are spent together with the nullifier of
+    // the vote, so that a delegator can not vote again.
+    let nullifier = vote_part.get_nullifier();
+    if nullifier.is_empty() {
+        return Ok(false);
+    }
+    let mut spent_nullifier_set = BTreeSet::new();
+    spent_nullifier_set.insert(nullifier);
+    for delegation in vote_part.get_delegation() {
+        if !spent_nullifier_set.insert(delegation.get_nullifier()) {
+            return Ok(false);
+        }
+    }
+    for spent_nullifier in &spent_nullifier_set {
+        if vote_sum
+            .get_aggregated_nullifier()
+            .binary_search_by(|v| v.as_slice().cmp(spent_nullifier))
+            .is_ok()
+        {
+            return Ok(false);
+        }
+    }
+    let updated_question_ballot_list =
+        update_question_ballot_sum(poll_parameters, vote_part, None, vote_sum)?;
+    let updated_against_ballot_list =
+        update_against_ballot_sum(poll_parameters, vote_part, None, vote_sum)?;
+
     // Initialize for the first part.
     if !vote_sum.has_blank_ballot() {
         let blank_ballot = vote_sum.mut_blank_ballot();
@@ -142,13 +539,12 @@ pub fn aggregate_vote_sum_response(
         }
     }
 
-    let c1_point =
-        bytes_to_point(&vote_part.get_blank_ballot().get_ciphertext1())?;
+    let combined_blank_ballot = get_combined_blank_ballot(vote_part)?;
+    let c1_point = bytes_to_point(combined_blank_ballot.get_ciphertext1())?;
     let blank_c1_sum =
         bytes_to_point(&vote_sum.get_blank_ballot().get_ciphertext1())?
             + c1_point;
-    let c2_point =
-        bytes_to_point(&vote_part.get_blank_ballot().get_ciphertext2())?;
+    let c2_point = bytes_to_point(combined_blank_ballot.get_ciphertext2())?;
     let blank_c2_sum =
         bytes_to_point(&vote_sum.get_blank_ballot().get_ciphertext2())?
             + c2_point;
@@ -184,15 +580,358 @@ pub fn aggregate_vote_sum_response(
     vote_sum
         .mut_blank_ballot()
         .set_ciphertext2(point_to_bytes(&blank_c2_sum));
+    // Aggregate the rest ballots as the abstained votes. Unbounded votes and
+    // quadratic votes have no rest ballot.
+    if vote_part.has_rest_ballot() {
+        let rest_ballot_sum = update_ballot_sum(
+            if vote_sum.has_rest_ballot() {
+                Some(vote_sum.get_rest_ballot())
+            } else {
+                None
+            },
+            vote_part.get_rest_ballot(),
+            None,
+        )?;
+        vote_sum.set_rest_ballot(rest_ballot_sum);
+    }
+    vote_sum.clear_question_ballot();
+    for question_ballot in updated_question_ballot_list {
+        vote_sum.mut_question_ballot().push(question_ballot);
+    }
+    vote_sum.clear_against_ballot();
+    for against_ballot in updated_against_ballot_list {
+        vote_sum.mut_against_ballot().push(against_ballot);
+    }
+    for spent_nullifier in spent_nullifier_set {
+        let aggregated_nullifier_list = vote_sum.mut_aggregated_nullifier();
+        let nullifier_index = aggregated_nullifier_list
+            .binary_search_by(|v| v.as_slice().cmp(spent_nullifier))
+            .unwrap_or_else(|index| index);
+        aggregated_nullifier_list
+            .insert(nullifier_index, spent_nullifier.to_vec());
+    }
+    let mut audit_record = VoteAuditRecord::new();
+    audit_record.set_nullifier(nullifier.to_vec());
+    audit_record.set_sequence(vote_part.get_sequence());
+    audit_record.set_vote_hash(compute_vote_hash(vote_part)?);
+    append_vote_audit_record(vote_sum, audit_record);
+    Ok(true)
+}
+
+// Computes the ballot sums of all questions after adding the ballots of a vote,
+// and removing the ballots of the replaced vote if any.
+fn update_question_ballot_sum(
+    poll_parameters: &PollParametersStorage,
+    vote_part: &VoteStorage,
+    previous_vote_part: Option<&VoteStorage>,
+    vote_sum: &VoteStorage,
+) -> Result<Vec<QuestionBallot>, WedprError> {
+    let mut updated_question_ballot_list = Vec::new();
+    for question in poll_parameters.get_question() {
+        let question_id = question.get_question_id();
+        let new_question_ballot = get_question_ballot(vote_part, question_id)?;
+        let previous_question_ballot = match previous_vote_part {
+            Some(v) => Some(get_question_ballot(v, question_id)?),
+            None => None,
+        };
+        // The vote sum has no question ballot before the first part.
+        let sum_question_ballot = if vote_sum.get_question_ballot().is_empty() {
+            None
+        } else {
+            Some(get_question_ballot(vote_sum, question_id)?)
+        };
+
+        let mut updated_question_ballot = QuestionBallot::new();
+        updated_question_ballot.set_question_id(question_id.to_string());
+        for candidate in question.get_candidates().get_candidate() {
+            let new_ballot = get_ballot_from_list(
+                new_question_ballot.get_voted_ballot(),
+                candidate,
+            )?;
+            let mut candidate_voted_c1_sum =
+                bytes_to_point(new_ballot.get_ciphertext1())?;
+            let mut candidate_voted_c2_sum =
+                bytes_to_point(new_ballot.get_ciphertext2())?;
+            if let Some(sum_question_ballot) = &sum_question_ballot {
+                let sum_ballot = get_ballot_from_list(
+                    sum_question_ballot.get_voted_ballot(),
+                    candidate,
+                )?;
+                candidate_voted_c1_sum +=
+                    bytes_to_point(sum_ballot.get_ciphertext1())?;
+                candidate_voted_c2_sum +=
+                    bytes_to_point(sum_ballot.get_ciphertext2())?;
+            }
+            if let Some(previous_question_ballot) = &previous_question_ballot {
+                let previous_ballot = get_ballot_from_list(
+                    previous_question_ballot.get_voted_ballot(),
+                    candidate,
+                )?;
+                candidate_voted_c1_sum -=
+                    bytes_to_point(previous_ballot.get_ciphertext1())?;
+                candidate_voted_c2_sum -=
+                    bytes_to_point(previous_ballot.get_ciphertext2())?;
+            }
+
+            // Write back.
+            let mut new_sum_ballot = Ballot::new();
+            new_sum_ballot
+                .set_ciphertext1(point_to_bytes(&candidate_voted_c1_sum));
+            new_sum_ballot
+                .set_ciphertext2(point_to_bytes(&candidate_voted_c2_sum));
+            let mut new_pair = CandidateBallot::new();
+            new_pair.set_candidate(candidate.to_string());
+            new_pair.set_ballot(new_sum_ballot);
+            updated_question_ballot.mut_voted_ballot().push(new_pair);
+        }
+        if new_question_ballot.has_rest_ballot() {
+            updated_question_ballot.set_rest_ballot(update_ballot_sum(
+                sum_question_ballot
+                    .as_ref()
+                    .filter(|v| v.has_rest_ballot())
+                    .map(|v| v.get_rest_ballot()),
+                new_question_ballot.get_rest_ballot(),
+                previous_question_ballot
+                    .as_ref()
+                    .map(|v| v.get_rest_ballot()),
+            )?);
+        }
+        updated_question_ballot_list.push(updated_question_ballot);
+    }
+    Ok(updated_question_ballot_list)
+}
+
+// Computes the updated sums of the ballots against each candidate, which are
+// only voted in a for/against poll.
+fn update_against_ballot_sum(
+    poll_parameters: &PollParametersStorage,
+    vote_part: &VoteStorage,
+    previous_vote_part: Option<&VoteStorage>,
+    vote_sum: &VoteStorage,
+) -> Result<Vec<CandidateBallot>, WedprError> {
+    let mut updated_against_ballot_list = Vec::new();
+    if !poll_parameters.get_against_voting() {
+        return Ok(updated_against_ballot_list);
+    }
+    for candidate in poll_parameters.get_candidates().get_candidate() {
+        let new_ballot =
+            get_ballot_from_list(vote_part.get_against_ballot(), candidate)?;
+        // The vote sum has no against ballot before the first part.
+        let sum_ballot = if vote_sum.get_against_ballot().is_empty() {
+            None
+        } else {
+            Some(get_ballot_from_list(
+                vote_sum.get_against_ballot(),
+                candidate,
+            )?)
+        };
+        let previous_ballot = match previous_vote_part {
+            Some(v) => {
+                Some(get_ballot_from_list(v.get_against_ballot(), candidate)?)
+            },
+            None => None,
+        };
+        let mut new_pair = CandidateBallot::new();
+        new_pair.set_candidate(candidate.to_string());
+        new_pair.set_ballot(update_ballot_sum(
+            sum_ballot.as_ref(),
+            &new_ballot,
+            previous_ballot.as_ref(),
+        )?);
+        updated_against_ballot_list.push(new_pair);
+    }
+    Ok(updated_against_ballot_list)
+}
+
+// Adds a ballot to the ballot sum, and removes the ballot it replaces if any.
+// An absent ballot sum is treated as zero.
+fn update_ballot_sum(
+    sum_ballot: Option<&Ballot>,
+    new_ballot: &Ballot,
+    previous_ballot: Option<&Ballot>,
+) -> Result<Ballot, WedprError> {
+    let mut c1_sum = bytes_to_point(new_ballot.get_ciphertext1())?;
+    let mut c2_sum = bytes_to_point(new_ballot.get_ciphertext2())?;
+    if let Some(sum_ballot) = sum_ballot {
+        c1_sum += bytes_to_point(sum_ballot.get_ciphertext1())?;
+        c2_sum += bytes_to_point(sum_ballot.get_ciphertext2())?;
+    }
+    if let Some(previous_ballot) = previous_ballot {
+        c1_sum -= bytes_to_point(previous_ballot.get_ciphertext1())?;
+        c2_sum -= bytes_to_point(previous_ballot.get_ciphertext2())?;
+    }
+    let mut ballot = Ballot::new();
+    ballot.set_ciphertext1(point_to_bytes(&c1_sum));
+    ballot.set_ciphertext2(point_to_bytes(&c2_sum));
+    Ok(ballot)
+}
+
+// Checks whether a vote can replace the previous vote of the same voter, and
+// returns the hash of the previous vote if it can.
+pub(crate) fn check_vote_replacement(
+    previous_vote_part: &VoteStorage,
+    vote_part: &VoteStorage,
+    vote_sum: &VoteStorage,
+) -> Result<Option<Vec<u8>>, WedprError> {
+    // The new vote must be linked to the same registration.
+    let nullifier = vote_part.get_nullifier();
+    if nullifier.is_empty()
+        || previous_vote_part.get_nullifier() != nullifier
+        || previous_vote_part.get_blank_ballot() != vote_part.get_blank_ballot()
+        || previous_vote_part.get_signature() != vote_part.get_signature()
+        || previous_vote_part.get_delegation() != vote_part.get_delegation()
+    {
+        return Ok(None);
+    }
+    if vote_part.get_sequence() <= previous_vote_part.get_sequence() {
+        return Ok(None);
+    }
+    // The previous vote must be the latest aggregated vote of the voter.
+    let previous_vote_hash = compute_vote_hash(previous_vote_part)?;
+    let latest_audit_record = vote_sum
+        .get_audit_record()
+        .iter()
+        .rev()
+        .find(|record| record.get_nullifier() == nullifier);
+    match latest_audit_record {
+        Some(record)
+            if record.get_sequence() == previous_vote_part.get_sequence()
+                && record.get_vote_hash() == previous_vote_hash.as_slice() =>
+        {
+            Ok(Some(previous_vote_hash))
+        },
+        _ => Ok(None),
+    }
+}
+
+/// Replaces the latest aggregated vote of a voter with a newer vote linked by
+/// the same nullifier, so that the last vote counts. It returns false without
+/// updating the vote sum if the previous vote is not the latest aggregated
+/// vote of the voter, or the new vote does not have a larger sequence number.
+pub fn replace_vote_sum_response(
+    poll_parameters: &PollParametersStorage,
+    previous_vote_part: &VoteStorage,
+    vote_part: &VoteStorage,
+    vote_sum: &mut VoteStorage,
+) -> Result<bool, WedprError> {
+    let previous_vote_hash = match check_vote_replacement(
+        previous_vote_part,
+        vote_part,
+        vote_sum,
+    )? {
+        Some(v) => v,
+        None => return Ok(false),
+    };
+
+    // The blank ballot is unchanged, only the voted ballots are replaced.
+    let updated_question_ballot_list = update_question_ballot_sum(
+        poll_parameters,
+        vote_part,
+        Some(previous_vote_part),
+        vote_sum,
+    )?;
+    let updated_against_ballot_list = update_against_ballot_sum(
+        poll_parameters,
+        vote_part,
+        Some(previous_vote_part),
+        vote_sum,
+    )?;
+    let mut updated_vote_sum_list = Vec::new();
+    for candidate in poll_parameters.get_candidates().get_candidate() {
+        let sum_ballot = get_ballot_by_candidate(vote_sum, candidate)?;
+        let previous_ballot =
+            get_ballot_by_candidate(previous_vote_part, candidate)?;
+        let new_ballot = get_ballot_by_candidate(vote_part, candidate)?;
+        let candidate_voted_c1_sum =
+            bytes_to_point(sum_ballot.get_ciphertext1())?
+                - bytes_to_point(previous_ballot.get_ciphertext1())?
+                + bytes_to_point(new_ballot.get_ciphertext1())?;
+        let candidate_voted_c2_sum =
+            bytes_to_point(sum_ballot.get_ciphertext2())?
+                - bytes_to_point(previous_ballot.get_ciphertext2())?
+                + bytes_to_point(new_ballot.get_ciphertext2())?;
+
+        // Write back.
+        let mut new_sum_ballot = Ballot::new();
+        new_sum_ballot.set_ciphertext1(point_to_bytes(&candidate_voted_c1_sum));
+        new_sum_ballot.set_ciphertext2(point_to_bytes(&candidate_voted_c2_sum));
+        let mut new_pair = CandidateBallot::new();
+        new_pair.set_candidate(candidate.to_string());
+        new_pair.set_ballot(new_sum_ballot);
+        updated_vote_sum_list.push(new_pair);
+    }
+    vote_sum.clear_voted_ballot();
+    for voted_ballot in updated_vote_sum_list {
+        vote_sum.mut_voted_ballot().push(voted_ballot);
+    }
+    if vote_part.has_rest_ballot() {
+        let rest_ballot_sum = update_ballot_sum(
+            if vote_sum.has_rest_ballot() {
+                Some(vote_sum.get_rest_ballot())
+            } else {
+                None
+            },
+            vote_part.get_rest_ballot(),
+            Some(previous_vote_part.get_rest_ballot()),
+        )?;
+        vote_sum.set_rest_ballot(rest_ballot_sum);
+    }
+    vote_sum.clear_question_ballot();
+    for question_ballot in updated_question_ballot_list {
+        vote_sum.mut_question_ballot().push(question_ballot);
+    }
+    vote_sum.clear_against_ballot();
+    for against_ballot in updated_against_ballot_list {
+        vote_sum.mut_against_ballot().push(against_ballot);
+    }
+    let mut audit_record = VoteAuditRecord::new();
+    audit_record.set_nullifier(vote_part.get_nullifier().to_vec());
+    audit_record.set_sequence(vote_part.get_sequence());
+    audit_record.set_vote_hash(compute_vote_hash(vote_part)?);
+    audit_record.set_replaced_vote_hash(previous_vote_hash);
+    append_vote_audit_record(vote_sum, audit_record);
     Ok(true)
 }
 
+/// Makes the receipt proving that a vote is included in the audit trail of the
+/// vote sum. The receipt should be made after the voting phase, so that the
+/// voter can check it against the vote root published with the final vote
+/// sum.
+pub fn make_vote_receipt(
+    vote_sum: &VoteStorage,
+    vote_part: &VoteStorage,
+) -> Result<VoteReceipt, WedprError> {
+    let vote_hash = compute_vote_hash(vote_part)?;
+    let leaf_index = match vote_sum
+        .get_audit_record()
+        .iter()
+        .position(|record| record.get_vote_hash() == vote_hash.as_slice())
+    {
+        Some(v) => v,
+        None => {
+            wedpr_println!("the vote has not been aggregated");
+            return Err(WedprError::ArgumentError);
+        },
+    };
+    let mut receipt = VoteReceipt::new();
+    receipt.set_vote_hash(vote_hash);
+    receipt.set_leaf_index(leaf_index as u32);
+    receipt.set_leaf_count(vote_sum.get_audit_record().len() as u32);
+    for sibling_hash in get_vote_merkle_path(vote_sum, leaf_index) {
+        receipt.mut_sibling_hash().push(sibling_hash);
+    }
+    receipt.set_vote_root(vote_sum.get_vote_root().to_vec());
+    Ok(receipt)
+}
+
 pub fn aggregate_vote_sum_response_unlisted(
     poll_parameters: &PollParametersStorage,
     vote_part: &VoteStorage,
     vote_sum: &mut VoteStorage,
 ) -> Result<bool, WedprError> {
-    aggregate_vote_sum_response(poll_parameters, vote_part, vote_sum)?;
+    if !aggregate_vote_sum_response(poll_parameters, vote_part, vote_sum)? {
+        return Ok(false);
+    }
     // aggregate all unlisted voting cipher ballot in vote_part
     for unlisted_ballot in vote_part.get_voted_ballot_unlisted() {
         vote_sum
@@ -202,12 +941,198 @@ pub fn aggregate_vote_sum_response_unlisted(
     Ok(true)
 }
 
-/// Aggregates a partially decrypted result from a counter.
+/// Replaces the latest aggregated vote of a voter in a poll accepting unlisted
+/// candidates, as replace_vote_sum_response does, and also replaces the
+/// unlisted ballots of the previous vote. It returns false without updating
+/// the vote sum if any unlisted ballot of the previous vote is not found in the
+/// vote sum, or the vote cannot be replaced.
+pub fn replace_vote_sum_response_unlisted(
+    poll_parameters: &PollParametersStorage,
+    previous_vote_part: &VoteStorage,
+    vote_part: &VoteStorage,
+    vote_sum: &mut VoteStorage,
+) -> Result<bool, WedprError> {
+    // all previous unlisted ballots must be found in the vote sum
+    let mut remaining_unlisted_ballots =
+        vote_sum.get_voted_ballot_unlisted().to_vec();
+    for previous_unlisted_ballot in
+        previous_vote_part.get_voted_ballot_unlisted()
+    {
+        match remaining_unlisted_ballots
+            .iter()
+            .position(|v| v == previous_unlisted_ballot)
+        {
+            Some(index) => {
+                remaining_unlisted_ballots.remove(index);
+            },
+            None => return Ok(false),
+        }
+    }
+    if !replace_vote_sum_response(
+        poll_parameters,
+        previous_vote_part,
+        vote_part,
+        vote_sum,
+    )? {
+        return Ok(false);
+    }
+    // replace the unlisted ballots of the previous vote
+    vote_sum.clear_voted_ballot_unlisted();
+    for unlisted_ballot in remaining_unlisted_ballots {
+        vote_sum.mut_voted_ballot_unlisted().push(unlisted_ballot);
+    }
+    for unlisted_ballot in vote_part.get_voted_ballot_unlisted() {
+        vote_sum
+            .mut_voted_ballot_unlisted()
+            .push(unlisted_ballot.clone());
+    }
+    Ok(true)
+}
+
+/// Groups the unlisted ballots of the vote sum by candidate according to the
+/// decrypted tags of the blinded candidate ciphers, and returns a vote sum
+/// with one aggregated unlisted ballot for each candidate. The returned vote
+/// sum can be counted with `count_unlisted`, so that only the total ballots of
+/// each unlisted candidate are decrypted, and the counting results are verified
+/// with `verify_grouped_count_request_unlisted`. All blinding parts and tag
+/// parts should be verified before grouping.
+pub fn group_unlisted_ballots(
+    vote_sum: &VoteStorage,
+    grouping: &UnlistedGroupingStorage,
+) -> Result<VoteStorage, WedprError> {
+    let blinding_part = match grouping.get_blinding_part().last() {
+        Some(v) => v,
+        None => return Err(WedprError::ArgumentError),
+    };
+    let unlisted_ballot_list = vote_sum.get_voted_ballot_unlisted();
+    let blinded_cipher_list = blinding_part.get_blinded_candidate_cipher();
+    if blinded_cipher_list.len() != unlisted_ballot_list.len()
+        || grouping.get_tag_decrypted_part().is_empty()
+    {
+        return Err(WedprError::ArgumentError);
+    }
+    // The tag of an unlisted ballot is its candidate id multiplied by the
+    // product of all blinding secrets.
+    let mut tag_list = Vec::new();
+    for (index, blinded_cipher) in blinded_cipher_list.iter().enumerate() {
+        let mut tag =
+            bytes_to_point(blinded_cipher.get_cipher().get_ciphertext1())?;
+        for tag_decrypted_part in grouping.get_tag_decrypted_part() {
+            let tag_part = match tag_decrypted_part.get_tag_part().get(index) {
+                Some(v) => v,
+                None => return Err(WedprError::ArgumentError),
+            };
+            tag -= bytes_to_point(tag_part.get_blinding_c2())?;
+        }
+        tag_list.push(point_to_bytes(&tag));
+    }
+
+    // Aggregate the unlisted ballots with the same tag, the first candidate
+    // cipher of each group is used to decrypt the candidate.
+    let mut group_index_map: BTreeMap<&[u8], usize> = BTreeMap::new();
+    let mut grouped_ballot_list: Vec<(
+        CipherPoints,
+        RistrettoPoint,
+        RistrettoPoint,
+    )> = Vec::new();
+    for (unlisted_ballot, tag) in unlisted_ballot_list.iter().zip(&tag_list) {
+        let ballot = unlisted_ballot.get_ballot();
+        let c1 = bytes_to_point(ballot.get_ciphertext1())?;
+        let c2 = bytes_to_point(ballot.get_ciphertext2())?;
+        match group_index_map.get(tag.as_slice()) {
+            Some(index) => {
+                grouped_ballot_list[*index].1 += c1;
+                grouped_ballot_list[*index].2 += c2;
+            },
+            None => {
+                group_index_map.insert(tag, grouped_ballot_list.len());
+                grouped_ballot_list.push((
+                    unlisted_ballot.get_key().clone(),
+                    c1,
+                    c2,
+                ));
+            },
+        }
+    }
+    let mut grouped_vote_sum = vote_sum.clone();
+    grouped_vote_sum.clear_voted_ballot_unlisted();
+    for (candidate_cipher, c1, c2) in grouped_ballot_list {
+        let mut grouped_ballot = CipherPointsToBallotPair::new();
+        grouped_ballot.set_key(candidate_cipher);
+        grouped_ballot
+            .mut_ballot()
+            .set_ciphertext1(point_to_bytes(&c1));
+        grouped_ballot
+            .mut_ballot()
+            .set_ciphertext2(point_to_bytes(&c2));
+        grouped_vote_sum
+            .mut_voted_ballot_unlisted()
+            .push(grouped_ballot);
+    }
+    Ok(grouped_vote_sum)
+}
+
+/// Aggregates a partially decrypted result sent by a counter after checking it
+/// against the share of that counter in the poll parameters. A misbehaving
+/// counter is excluded by returning its blame report without updating the
+/// aggregated result, including a result declaring another counter id and a
+/// counter that has already been aggregated. Every counter is needed to
+/// decrypt the vote sum, so the vote result can only be finalized after an
+/// excluded counter sends a valid partially decrypted result.
 pub fn aggregate_decrypted_part_sum(
     poll_parameters: &PollParametersStorage,
+    counter_id: &str,
+    encrypted_vote_sum: &VoteStorage,
     partially_decrypted_result: &DecryptedResultPartStorage,
     aggregated_decrypted_result: &mut DecryptedResultPartStorage,
-) -> Result<bool, WedprError> {
+) -> Result<Option<CounterBlameReport>, WedprError> {
+    if aggregated_decrypted_result
+        .get_aggregated_counter_id()
+        .iter()
+        .any(|aggregated_counter_id| aggregated_counter_id == counter_id)
+    {
+        let mut blame_report = CounterBlameReport::new();
+        blame_report.set_counter_id(counter_id.to_string());
+        blame_report.set_reason(CounterBlameReason::DUPLICATE_COUNTER);
+        return Ok(Some(blame_report));
+    }
+    if let Some(blame_report) = blame_checked_count_request(
+        poll_parameters,
+        counter_id,
+        encrypted_vote_sum,
+        partially_decrypted_result,
+    )? {
+        return Ok(Some(blame_report));
+    }
+    add_decrypted_part_sum(
+        poll_parameters,
+        partially_decrypted_result,
+        aggregated_decrypted_result,
+    )?;
+    aggregated_decrypted_result
+        .mut_aggregated_counter_id()
+        .push(counter_id.to_string());
+    Ok(None)
+}
+
+// Adds a partially decrypted result to the aggregated result without checking
+// it.
+fn add_decrypted_part_sum(
+    poll_parameters: &PollParametersStorage,
+    partially_decrypted_result: &DecryptedResultPartStorage,
+    aggregated_decrypted_result: &mut DecryptedResultPartStorage,
+) -> Result<(), WedprError> {
+    let updated_question_part_list = aggregate_question_counting_part(
+        poll_parameters,
+        partially_decrypted_result,
+        aggregated_decrypted_result,
+    )?;
+    let updated_against_part_list = aggregate_against_counting_part(
+        poll_parameters,
+        partially_decrypted_result,
+        aggregated_decrypted_result,
+    )?;
+
     // Initialize for the first part.
     if !aggregated_decrypted_result.has_blank_part() {
         let blank_part = aggregated_decrypted_result.mut_blank_part();
@@ -269,7 +1194,146 @@ pub fn aggregate_decrypted_part_sum(
             .mut_candidate_part()
             .push(updated_candidate_part);
     }
-    Ok(true)
+    if partially_decrypted_result.has_rest_part() {
+        let rest_part_sum = update_counting_part_sum(
+            if aggregated_decrypted_result.has_rest_part() {
+                Some(aggregated_decrypted_result.get_rest_part())
+            } else {
+                None
+            },
+            partially_decrypted_result.get_rest_part(),
+        )?;
+        aggregated_decrypted_result.set_rest_part(rest_part_sum);
+    }
+    aggregated_decrypted_result.clear_question_part();
+    for updated_question_part in updated_question_part_list {
+        aggregated_decrypted_result
+            .mut_question_part()
+            .push(updated_question_part);
+    }
+    aggregated_decrypted_result.clear_against_part();
+    for updated_against_part in updated_against_part_list {
+        aggregated_decrypted_result
+            .mut_against_part()
+            .push(updated_against_part);
+    }
+    Ok(())
+}
+
+// Computes the aggregated decrypted parts of all questions after adding a
+// partially decrypted result.
+fn aggregate_question_counting_part(
+    poll_parameters: &PollParametersStorage,
+    partially_decrypted_result: &DecryptedResultPartStorage,
+    aggregated_decrypted_result: &DecryptedResultPartStorage,
+) -> Result<Vec<QuestionCountingPart>, WedprError> {
+    let mut updated_question_part_list = Vec::new();
+    for question in poll_parameters.get_question() {
+        let question_id = question.get_question_id();
+        let new_question_part = get_question_counting_part(
+            partially_decrypted_result,
+            question_id,
+        )?;
+        // The aggregated result has no question part before the first part.
+        let aggregated_question_part =
+            if aggregated_decrypted_result.has_blank_part() {
+                Some(get_question_counting_part(
+                    aggregated_decrypted_result,
+                    question_id,
+                )?)
+            } else {
+                None
+            };
+
+        let mut updated_question_part = QuestionCountingPart::new();
+        updated_question_part.set_question_id(question_id.to_string());
+        for candidate in question.get_candidates().get_candidate() {
+            let new_part = get_counting_part_from_list(
+                new_question_part.get_candidate_part(),
+                candidate,
+            )?;
+            let mut candidate_c2_r_sum =
+                bytes_to_point(new_part.get_blinding_c2())?;
+            if let Some(aggregated_question_part) = &aggregated_question_part {
+                let aggregated_part = get_counting_part_from_list(
+                    aggregated_question_part.get_candidate_part(),
+                    candidate,
+                )?;
+                candidate_c2_r_sum +=
+                    bytes_to_point(aggregated_part.get_blinding_c2())?;
+            }
+
+            // Write back.
+            let mut candidate_part = CountingPart::new();
+            candidate_part.set_blinding_c2(point_to_bytes(&candidate_c2_r_sum));
+            let mut new_pair = StringToCountingPartPair::new();
+            new_pair.set_key(candidate.to_string());
+            new_pair.set_value(candidate_part);
+            updated_question_part.mut_candidate_part().push(new_pair);
+        }
+        if new_question_part.has_rest_part() {
+            updated_question_part.set_rest_part(update_counting_part_sum(
+                aggregated_question_part
+                    .as_ref()
+                    .filter(|v| v.has_rest_part())
+                    .map(|v| v.get_rest_part()),
+                new_question_part.get_rest_part(),
+            )?);
+        }
+        updated_question_part_list.push(updated_question_part);
+    }
+    Ok(updated_question_part_list)
+}
+
+// Computes the updated aggregated parts of the ballots against each candidate,
+// which are only counted in a for/against poll.
+fn aggregate_against_counting_part(
+    poll_parameters: &PollParametersStorage,
+    partially_decrypted_result: &DecryptedResultPartStorage,
+    aggregated_decrypted_result: &DecryptedResultPartStorage,
+) -> Result<Vec<StringToCountingPartPair>, WedprError> {
+    let mut updated_against_part_list = Vec::new();
+    if !poll_parameters.get_against_voting() {
+        return Ok(updated_against_part_list);
+    }
+    for candidate in poll_parameters.get_candidates().get_candidate() {
+        let new_part = get_counting_part_from_list(
+            partially_decrypted_result.get_against_part(),
+            candidate,
+        )?;
+        // The aggregated result has no against part before the first part.
+        let aggregated_part = if aggregated_decrypted_result.has_blank_part() {
+            Some(get_counting_part_from_list(
+                aggregated_decrypted_result.get_against_part(),
+                candidate,
+            )?)
+        } else {
+            None
+        };
+        let mut new_pair = StringToCountingPartPair::new();
+        new_pair.set_key(candidate.to_string());
+        new_pair.set_value(update_counting_part_sum(
+            aggregated_part.as_ref(),
+            &new_part,
+        )?);
+        updated_against_part_list.push(new_pair);
+    }
+    Ok(updated_against_part_list)
+}
+
+// Adds a partially decrypted part to the aggregated part. An absent aggregated
+// part is treated as zero.
+fn update_counting_part_sum(
+    sum_part: Option<&CountingPart>,
+    new_part: &CountingPart,
+) -> Result<CountingPart, WedprError> {
+    let mut c2_r_sum = bytes_to_point(new_part.get_blinding_c2())?;
+    if let Some(sum_part) = sum_part {
+        c2_r_sum += bytes_to_point(sum_part.get_blinding_c2())?;
+    }
+    let mut counting_part = CountingPart::new();
+    counting_part.set_blinding_c2(point_to_bytes(&c2_r_sum));
+    Ok(counting_part)
 }
 
 pub fn aggregate_decrypted_part_for_specify_unlisted_candidate(
@@ -310,7 +1374,7 @@ pub fn aggregate_decrypted_part_sum_unlisted(
     partially_decrypted_result: &DecryptedResultPartStorage,
     aggregated_decrypted_result: &mut DecryptedResultPartStorage,
 ) -> Result<bool, WedprError> {
-    aggregate_decrypted_part_sum(
+    add_decrypted_part_sum(
         poll_parameters,
         partially_decrypted_result,
         aggregated_decrypted_result,
@@ -345,7 +1409,187 @@ pub fn aggregate_decrypted_part_sum_unlisted(
     Ok(true)
 }
 
+/// Aggregates the blinded differences from all counters for each tested pair
+/// of the duplicate tests or the roster tests.
+pub fn aggregate_credential_blinding_parts(
+    blinding_parts: &[CredentialBlindingPart],
+) -> Result<Vec<Ballot>, WedprError> {
+    let difference_count = match blinding_parts.first() {
+        Some(v) => v.get_blinded_difference().len(),
+        None => return Err(WedprError::ArgumentError),
+    };
+    let mut blinded_difference_sum =
+        vec![
+            (RistrettoPoint::default(), RistrettoPoint::default());
+            difference_count
+        ];
+    for blinding_part in blinding_parts {
+        if blinding_part.get_blinded_difference().len() != difference_count {
+            return Err(WedprError::ArgumentError);
+        }
+        for (sum, blinded_difference) in blinded_difference_sum
+            .iter_mut()
+            .zip(blinding_part.get_blinded_difference())
+        {
+            sum.0 += bytes_to_point(blinded_difference.get_ciphertext1())?;
+            sum.1 += bytes_to_point(blinded_difference.get_ciphertext2())?;
+        }
+    }
+    Ok(blinded_difference_sum
+        .iter()
+        .map(|sum| {
+            let mut ballot = Ballot::new();
+            ballot.set_ciphertext1(point_to_bytes(&sum.0));
+            ballot.set_ciphertext2(point_to_bytes(&sum.1));
+            ballot
+        })
+        .collect())
+}
+
+// Decides the plaintext equivalence tests with the partial decryptions from
+// all counters, where a blinded difference decrypts to zero only if the
+// tested ciphertexts encrypt the same value.
+fn decide_credential_tests(
+    blinded_difference_sum: &[Ballot],
+    decryption_parts: &[CredentialDecryptionPart],
+) -> Result<Vec<bool>, WedprError> {
+    let mut result_list = Vec::with_capacity(blinded_difference_sum.len());
+    for (i, blinded_difference) in blinded_difference_sum.iter().enumerate() {
+        let mut target_difference =
+            bytes_to_point(blinded_difference.get_ciphertext1())?;
+        for decryption_part in decryption_parts {
+            let decrypted_part =
+                match decryption_part.get_decrypted_part().get(i) {
+                    Some(v) => v,
+                    None => return Err(WedprError::ArgumentError),
+                };
+            target_difference -=
+                bytes_to_point(decrypted_part.get_blinding_c2())?;
+        }
+        // A difference blinded with zero always decrypts to zero.
+        result_list.push(
+            target_difference == RistrettoPoint::default()
+                && bytes_to_point(blinded_difference.get_ciphertext2())?
+                    != RistrettoPoint::default(),
+        );
+    }
+    Ok(result_list)
+}
+
+/// Removes the votes with duplicate credentials from the ballot box with the
+/// partially decrypted blinded differences of the duplicate tests from all
+/// counters. Among the votes with the same credential, the vote with the
+/// largest sequence number is kept, or the last one of them in the ballot box,
+/// so that each credential is counted at most once. The kept votes are
+/// returned in the order of the ballot box, and should be mixed by all
+/// counters. The duplicate tests only reveal which votes share a credential,
+/// but not whether the credential is real.
+pub fn remove_credential_duplicates(
+    ballot_box: &[VoteStorage],
+    blinded_difference_sum: &[Ballot],
+    decryption_parts: &[CredentialDecryptionPart],
+) -> Result<Vec<VoteStorage>, WedprError> {
+    let pair_list = get_credential_duplicate_pair_list(ballot_box.len());
+    if blinded_difference_sum.len() != pair_list.len() {
+        return Err(WedprError::ArgumentError);
+    }
+    let mut replaced = vec![false; ballot_box.len()];
+    for ((i, j), duplicated) in pair_list.iter().zip(decide_credential_tests(
+        blinded_difference_sum,
+        decryption_parts,
+    )?) {
+        if !duplicated {
+            continue;
+        }
+        if ballot_box[*i].get_sequence() > ballot_box[*j].get_sequence() {
+            replaced[*j] = true;
+        } else {
+            replaced[*i] = true;
+        }
+    }
+    Ok(ballot_box
+        .iter()
+        .zip(replaced)
+        .filter(|(_, replaced)| !replaced)
+        .map(|(vote, _)| vote.clone())
+        .collect())
+}
+
+/// Filters the mixed ballot box with the partially decrypted blinded
+/// differences of the roster tests from all counters, and aggregates the kept
+/// vote rows into the vote sum to count. A mixed vote row is kept if its
+/// credential and its blank ballot match a mixed roster row not matched by an
+/// earlier vote row, so that the votes with a fake credential are discarded
+/// and each voter is counted with its own weight at most once. Since both the
+/// votes and the roster are mixed, the filtering cannot be linked to the
+/// ballot box or to the roster.
+pub fn filter_votes_by_credential(
+    poll_parameters: &PollParametersStorage,
+    mix_parts: &[CredentialMixPart],
+    blinded_difference_sum: &[Ballot],
+    decryption_parts: &[CredentialDecryptionPart],
+) -> Result<VoteStorage, WedprError> {
+    let mix_part = match mix_parts.last() {
+        Some(v) => v,
+        None => return Err(WedprError::ArgumentError),
+    };
+    let candidate_list = poll_parameters.get_candidates().get_candidate();
+    // credential, blank ballot, candidate ballots and rest ballot
+    let row_width = candidate_list.len() + 3;
+    let roster_count = mix_part.get_roster_row().len();
+    if blinded_difference_sum.len()
+        != mix_part.get_vote_row().len() * roster_count
+    {
+        return Err(WedprError::ArgumentError);
+    }
+    let matched_list =
+        decide_credential_tests(blinded_difference_sum, decryption_parts)?;
+    let mut sum_list =
+        vec![(RistrettoPoint::default(), RistrettoPoint::default()); row_width];
+    let mut matched_roster_set = BTreeSet::new();
+    for (i, vote_row) in mix_part.get_vote_row().iter().enumerate() {
+        let ballot_list = vote_row.get_ballot();
+        if ballot_list.len() != row_width {
+            return Err(WedprError::ArgumentError);
+        }
+        let roster_index = match (0..roster_count).find(|j| {
+            matched_list[i * roster_count + j]
+                && !matched_roster_set.contains(j)
+        }) {
+            Some(v) => v,
+            None => continue,
+        };
+        matched_roster_set.insert(roster_index);
+        for (sum, ballot) in sum_list.iter_mut().zip(ballot_list).skip(1) {
+            sum.0 += bytes_to_point(ballot.get_ciphertext1())?;
+            sum.1 += bytes_to_point(ballot.get_ciphertext2())?;
+        }
+    }
+    let sum_ballot_list: Vec<Ballot> = sum_list
+        .iter()
+        .map(|sum| {
+            let mut ballot = Ballot::new();
+            ballot.set_ciphertext1(point_to_bytes(&sum.0));
+            ballot.set_ciphertext2(point_to_bytes(&sum.1));
+            ballot
+        })
+        .collect();
+    let mut vote_sum = VoteStorage::new();
+    vote_sum.set_blank_ballot(sum_ballot_list[1].clone());
+    for (candidate, ballot) in candidate_list.iter().zip(&sum_ballot_list[2..])
+    {
+        let mut candidate_ballot = CandidateBallot::new();
+        candidate_ballot.set_candidate(candidate.to_string());
+        candidate_ballot.set_ballot(ballot.clone());
+        vote_sum.mut_voted_ballot().push(candidate_ballot);
+    }
+    vote_sum.set_rest_ballot(sum_ballot_list[row_width - 1].clone());
+    Ok(vote_sum)
+}
+
 /// Computes the final vote result from aggregated partially decrypted results.
+/// In a for/against poll, the result of each candidate is the net support, and
+/// the votes against each candidate are reported in the against result.
 pub fn finalize_vote_result(
     poll_parameters: &PollParametersStorage,
     vote_sum: &VoteStorage,
@@ -362,18 +1606,15 @@ pub fn finalize_vote_result(
     )?;
 
     // Compute the total votes.
-    let target_total = blank_c1_sum - blank_c2_r_sum;
-    for i in 1..=max_vote_limit {
-        if target_total.eq(&(*BASEPOINT_G1 * Scalar::from(i as u64))) {
-            let mut new_pair = StringToInt64Pair::new();
-            new_pair.set_key(POLL_RESULT_KEY_TOTAL_BALLOTS.to_string());
-            new_pair.set_value(i);
-            result.mut_result().push(new_pair);
-            break;
-        }
-    }
+    let total_result =
+        decrypt_vote_value(&(blank_c1_sum - blank_c2_r_sum), max_vote_limit)?;
+    let mut new_pair = StringToInt64Pair::new();
+    new_pair.set_key(POLL_RESULT_KEY_TOTAL_BALLOTS.to_string());
+    new_pair.set_value(total_result);
+    result.mut_result().push(new_pair);
 
     // Compute the votes for each candidate.
+    let mut candidate_result_sum = 0;
     for candidate in poll_parameters.get_candidates().get_candidate() {
         let ballot = get_ballot_by_candidate(vote_sum, candidate)?;
         let candidate_counting_part = get_counting_part_by_candidate(
@@ -384,20 +1625,133 @@ pub fn finalize_vote_result(
             bytes_to_point(candidate_counting_part.get_blinding_c2())?;
         let target_candidate =
             bytes_to_point(ballot.get_ciphertext1())? - candidate_c2_r_sum;
+        let candidate_result =
+            decrypt_vote_value(&target_candidate, max_vote_limit)?;
+        candidate_result_sum += candidate_result;
+        let mut new_pair = StringToInt64Pair::new();
+        new_pair.set_key(candidate.to_string());
+        new_pair.set_value(candidate_result);
 
-        for i in 0..=max_vote_limit {
-            if target_candidate.eq(&(*BASEPOINT_G1 * Scalar::from(i as u64))) {
-                let mut new_pair = StringToInt64Pair::new();
-                new_pair.set_key(candidate.to_string());
-                new_pair.set_value(i);
-                result.mut_result().push(new_pair);
-                break;
-            }
+        // Report the net support in a for/against poll.
+        if poll_parameters.get_against_voting() {
+            let against_ballot =
+                get_ballot_from_list(vote_sum.get_against_ballot(), candidate)?;
+            let against_counting_part = get_counting_part_from_list(
+                aggregated_decrypted_result.get_against_part(),
+                candidate,
+            )?;
+            let target_against =
+                bytes_to_point(against_ballot.get_ciphertext1())?
+                    - bytes_to_point(against_counting_part.get_blinding_c2())?;
+            let against_result =
+                decrypt_vote_value(&target_against, max_vote_limit)?;
+            candidate_result_sum += against_result;
+            new_pair.set_value(candidate_result - against_result);
+            let mut against_pair = StringToInt64Pair::new();
+            against_pair.set_key(candidate.to_string());
+            against_pair.set_value(against_result);
+            result.mut_against_result().push(against_pair);
         }
+        result.mut_result().push(new_pair);
+    }
+    if vote_sum.has_rest_ballot() {
+        let abstained_result = finalize_abstained_result(
+            vote_sum.get_rest_ballot(),
+            aggregated_decrypted_result.get_rest_part(),
+            candidate_result_sum,
+            total_result,
+            max_vote_limit,
+        )?;
+        result.mut_result().push(abstained_result);
+    }
+
+    // Compute the votes for each candidate of each question.
+    for question in poll_parameters.get_question() {
+        let question_id = question.get_question_id();
+        let question_ballot = get_question_ballot(vote_sum, question_id)?;
+        let question_part = get_question_counting_part(
+            aggregated_decrypted_result,
+            question_id,
+        )?;
+        let mut question_result = QuestionResult::new();
+        question_result.set_question_id(question_id.to_string());
+        let mut candidate_result_sum = 0;
+        for candidate in question.get_candidates().get_candidate() {
+            let ballot = get_ballot_from_list(
+                question_ballot.get_voted_ballot(),
+                candidate,
+            )?;
+            let candidate_counting_part = get_counting_part_from_list(
+                question_part.get_candidate_part(),
+                candidate,
+            )?;
+            let target_candidate = bytes_to_point(ballot.get_ciphertext1())?
+                - bytes_to_point(candidate_counting_part.get_blinding_c2())?;
+            let candidate_result =
+                decrypt_vote_value(&target_candidate, max_vote_limit)?;
+            candidate_result_sum += candidate_result;
+            let mut new_pair = StringToInt64Pair::new();
+            new_pair.set_key(candidate.to_string());
+            new_pair.set_value(candidate_result);
+            question_result.mut_result().push(new_pair);
+        }
+        if question_ballot.has_rest_ballot() {
+            let abstained_result = finalize_abstained_result(
+                question_ballot.get_rest_ballot(),
+                question_part.get_rest_part(),
+                candidate_result_sum,
+                total_result,
+                max_vote_limit,
+            )?;
+            question_result.mut_result().push(abstained_result);
+        }
+        result.mut_question_result().push(question_result);
     }
     Ok(result)
 }
 
+// Decrypts a vote value by searching within the max vote limit. It returns an
+// error if the value exceeds the limit, so that no entry is omitted from the
+// vote result.
+fn decrypt_vote_value(
+    target: &RistrettoPoint,
+    max_vote_limit: i64,
+) -> Result<i64, WedprError> {
+    for i in 0..=max_vote_limit {
+        if target.eq(&(*BASEPOINT_G1 * Scalar::from(i as u64))) {
+            return Ok(i);
+        }
+    }
+    wedpr_println!(
+        "decrypted value exceeds max_vote_limit {}!",
+        max_vote_limit
+    );
+    Err(WedprError::ArgumentError)
+}
+
+// Decrypts the abstained votes from the aggregated rest ballot, and checks that
+// the candidate votes and the abstained votes partition the total votes.
+fn finalize_abstained_result(
+    rest_ballot: &Ballot,
+    rest_part: &CountingPart,
+    candidate_result_sum: i64,
+    total_result: i64,
+    max_vote_limit: i64,
+) -> Result<StringToInt64Pair, WedprError> {
+    let target_abstained = bytes_to_point(rest_ballot.get_ciphertext1())?
+        - bytes_to_point(rest_part.get_blinding_c2())?;
+    let abstained_result =
+        decrypt_vote_value(&target_abstained, max_vote_limit)?;
+    if candidate_result_sum + abstained_result != total_result {
+        wedpr_println!("vote result does not partition the total votes!");
+        return Err(WedprError::VerificationError);
+    }
+    let mut abstained_pair = StringToInt64Pair::new();
+    abstained_pair.set_key(POLL_RESULT_KEY_ABSTAINED_BALLOTS.to_string());
+    abstained_pair.set_value(abstained_result);
+    Ok(abstained_pair)
+}
+
 pub fn decrypt_unlisted_candidate_ballot(
     decrypted_unlisted_candidate_ballot_result: &mut BTreeMap<u64, u64>,
     unlisted_candidate_part: &mut UnlistedBallotDecryptedResult,
@@ -426,7 +1780,12 @@ pub fn decrypt_unlisted_candidate_ballot(
         }
     }
     if !decrypt_candidate_success {
-        return Ok(false);
+        wedpr_println!(
+            "unlisted candidate id is not in [0, {}], the candidate cipher \
+             cannot be decrypted!",
+            max_candidate_number
+        );
+        return Err(WedprError::DecodeError);
     }
     // decrypt the unlisted candidate ballot value when decrypt candidate
     // success
@@ -452,6 +1811,7 @@ pub fn decrypt_unlisted_candidate_ballot(
         )?;
         let target_total = c1 - blinding_c2_sum;
         // decrypt the ballot value
+        let mut decrypt_ballot_success = false;
         for i in 0..=max_vote_limit {
             let try_num = Scalar::from(i as u64);
             if !target_total.eq(&(*BASEPOINT_G1 * try_num)) {
@@ -459,19 +1819,19 @@ pub fn decrypt_unlisted_candidate_ballot(
             }
             // merge the  candidate unlisted value
             let candidate = unlisted_candidate_part.get_candidate() as u64;
-            if decrypted_unlisted_candidate_ballot_result
-                .contains_key(&candidate)
-            {
-                let result_sum = decrypted_unlisted_candidate_ballot_result
-                    .get(&candidate)
-                    .unwrap()
-                    + (i as u64);
-                decrypted_unlisted_candidate_ballot_result
-                    .insert(candidate, result_sum);
-            } else {
-                decrypted_unlisted_candidate_ballot_result
-                    .insert(candidate, i as u64);
-            }
+            *decrypted_unlisted_candidate_ballot_result
+                .entry(candidate)
+                .or_insert(0) += i as u64;
+            decrypt_ballot_success = true;
+            break;
+        }
+        if !decrypt_ballot_success {
+            wedpr_println!(
+                "unlisted ballot value is not in [0, {}], the ballot cannot \
+                 be decrypted!",
+                max_vote_limit
+            );
+            return Err(WedprError::DecodeError);
         }
     }
     Ok(true)
@@ -515,3 +1875,110 @@ pub fn finalize_vote_result_unlisted(
     }
     Ok(vote_result)
 }
+
+/// Makes a comparison of whether a candidate has no fewer votes than another
+/// without decrypting the votes or their difference. The comparison is mixed
+/// by all counters in turn with mix_ranking_comparison, then decrypted by all
+/// of them with decrypt_ranking_comparison, and only holds if the vote
+/// difference is in [0, max_difference], which should be the total ballots.
+/// The cost of mixing grows with max_difference, which is at most
+/// MAX_RANKING_DIFFERENCE, so the polls with more total ballots cannot be
+/// ranked by comparisons.
+pub fn make_ranking_comparison(
+    poll_parameters: &PollParametersStorage,
+    greater_candidate: &str,
+    lesser_candidate: &str,
+    max_difference: u32,
+) -> Result<RankingComparison, WedprError> {
+    // The ranking compares the votes for the candidates, which are not the
+    // net support reported by a for/against poll.
+    let candidate_list = poll_parameters.get_candidates().get_candidate();
+    if greater_candidate == lesser_candidate
+        || max_difference > MAX_RANKING_DIFFERENCE
+        || poll_parameters.get_against_voting()
+        || !candidate_list.iter().any(|c| c == greater_candidate)
+        || !candidate_list.iter().any(|c| c == lesser_candidate)
+    {
+        return Err(WedprError::ArgumentError);
+    }
+    let mut comparison = RankingComparison::new();
+    comparison.set_greater_candidate(greater_candidate.to_string());
+    comparison.set_lesser_candidate(lesser_candidate.to_string());
+    comparison.set_max_difference(max_difference);
+    Ok(comparison)
+}
+
+/// Decides whether the greater candidate of a mixed and decrypted ranking
+/// comparison has no fewer votes than the lesser candidate, which is true if
+/// any of the mixed differences decrypts to zero. Only the outcome is learned,
+/// as the mixed differences are blinded and cannot be linked to the values
+/// they are compared with. The comparison should be verified by
+/// verify_ranking_comparison first.
+pub fn decide_ranking_comparison(
+    comparison: &RankingComparison,
+) -> Result<bool, WedprError> {
+    let mix_part = match comparison.get_mix_part().last() {
+        Some(v) => v,
+        None => return Err(WedprError::ArgumentError),
+    };
+    for (index, shuffled_difference) in
+        mix_part.get_shuffled_difference().iter().enumerate()
+    {
+        let mut target_difference =
+            bytes_to_point(shuffled_difference.get_ciphertext1())?;
+        for decryption_part in comparison.get_decryption_part() {
+            let decrypted_part =
+                match decryption_part.get_decrypted_part().get(index) {
+                    Some(v) => v,
+                    None => return Err(WedprError::ArgumentError),
+                };
+            target_difference -=
+                bytes_to_point(decrypted_part.get_blinding_c2())?;
+        }
+        if target_difference == RistrettoPoint::default() {
+            return Ok(true);
+        }
+    }
+    Ok(false)
+}
+
+/// Makes the ranking of the top candidates in descending order of their votes
+/// from the decided comparisons, which must hold for every pair of candidates
+/// compared by the ranking, i.e. the ranked candidates one by one, and the
+/// last of them with all the other candidates. The coordinator finds the
+/// ranking with comparisons as well, e.g. by selecting the candidate with no
+/// fewer votes than all the rest in turn, so no vote or vote difference is
+/// ever decrypted.
+pub fn make_vote_ranking(
+    poll_parameters: &PollParametersStorage,
+    ranked_candidate_list: &[String],
+    comparison_list: &[RankingComparison],
+) -> Result<VoteRanking, WedprError> {
+    let candidate_list = poll_parameters.get_candidates().get_candidate();
+    if ranked_candidate_list.is_empty()
+        || ranked_candidate_list.len() > candidate_list.len()
+        || ranked_candidate_list
+            .iter()
+            .any(|candidate| !candidate_list.contains(candidate))
+    {
+        return Err(WedprError::ArgumentError);
+    }
+    let mut vote_ranking = VoteRanking::new();
+    for candidate in ranked_candidate_list {
+        vote_ranking.mut_ranked_candidate().push(candidate.clone());
+    }
+    for (greater_candidate, lesser_candidate) in
+        get_ranking_comparison_pair_list(candidate_list, ranked_candidate_list)
+    {
+        let comparison = match comparison_list.iter().find(|comparison| {
+            comparison.get_greater_candidate() == greater_candidate
+                && comparison.get_lesser_candidate() == lesser_candidate
+                && decide_ranking_comparison(comparison).unwrap_or(false)
+        }) {
+            Some(v) => v,
+            None => return Err(WedprError::ArgumentError),
+        };
+        vote_ranking.mut_comparison().push(comparison.clone());
+    }
+    Ok(vote_ranking)
+}
@@ -2,6 +2,7 @@
 
 //! Library for a poll counter.
 
+use curve25519_dalek::{ristretto::RistrettoPoint, scalar::Scalar};
 use wedpr_l_crypto_zkp_discrete_logarithm_proof::prove_equality_relationship_proof;
 use wedpr_l_crypto_zkp_utils::{
     bytes_to_point, bytes_to_scalar, get_random_scalar, point_to_bytes,
@@ -10,9 +11,24 @@ use wedpr_l_crypto_zkp_utils::{
 use wedpr_l_utils::error::WedprError;
 
 use wedpr_s_protos::generated::acv::{
+    Ballot, BallotRow, BlindedCipherPoints, CandidateBallot,
     CounterParametersShareRequest, CounterSecret, CountingPart,
-    DecryptedResultPartStorage, StringToCountingPartPair,
-    UnlistedBallotDecryptedResult, VoteStorage,
+    CredentialBlindingPart, CredentialDecryptionPart, CredentialMixPart,
+    CredentialRoster, DecryptedResultPartStorage, PollParametersStorage,
+    QuestionCountingPart, RankingComparison, RankingDecryptionPart,
+    RankingMixPart, ShuffleProof, ShuffleProofRound, StringToCountingPartPair,
+    UnlistedBallotDecryptedResult, UnlistedBlindingPart,
+    UnlistedGroupingStorage, UnlistedTagDecryptedPart, VoteStorage,
+};
+
+use crate::{
+    config::SHUFFLE_PROOF_ROUNDS,
+    utils::{
+        check_ranking_max_difference, compute_shuffle_challenge,
+        get_credential_duplicate_difference_list,
+        get_credential_match_difference_list, get_credential_mix_input,
+        get_ranking_mix_input, get_unlisted_candidate_cipher_list,
+    },
 };
 
 /// Makes secrets used by a counter.
@@ -41,6 +57,128 @@ pub fn make_parameters_share(
     })
 }
 
+// Blinds each difference with a random secret, and generates the ZKP proofs
+// that each difference is blinded with a secret.
+fn blind_difference_list(
+    counter_id: &str,
+    difference_list: &[(RistrettoPoint, RistrettoPoint)],
+) -> CredentialBlindingPart {
+    let mut blinding_part = CredentialBlindingPart::new();
+    blinding_part.set_counter_id(counter_id.to_string());
+    for (difference1, difference2) in difference_list {
+        let blinding = get_random_scalar();
+        let mut blinded_difference = Ballot::new();
+        blinded_difference
+            .set_ciphertext1(point_to_bytes(&(difference1 * blinding)));
+        blinded_difference
+            .set_ciphertext2(point_to_bytes(&(difference2 * blinding)));
+        let equality_proof = prove_equality_relationship_proof(
+            &blinding,
+            difference1,
+            difference2,
+        );
+        blinding_part
+            .mut_blinded_difference()
+            .push(blinded_difference);
+        blinding_part
+            .mut_equality_proof()
+            .push(equality_proof.serialize());
+    }
+    blinding_part
+}
+
+/// Blinds the differences between the encrypted credentials of each pair of
+/// votes in the ballot box, and generates the ZKP proofs that each difference
+/// is blinded with a secret. It is the first round of removing the votes with
+/// duplicate credentials from the ballot box before mixing it.
+pub fn blind_credential_duplicates(
+    counter_id: &str,
+    ballot_box: &[VoteStorage],
+) -> Result<CredentialBlindingPart, WedprError> {
+    Ok(blind_difference_list(
+        counter_id,
+        &get_credential_duplicate_difference_list(ballot_box)?,
+    ))
+}
+
+/// Shuffles and re-encrypts the vote rows of the deduplicated ballot box and
+/// the roster rows of the credential roster as mixed by the previous counters,
+/// and generates the ZKP proofs of both shuffles. Every counter mixes the rows
+/// in turn, so that no counter can link a vote or a roster entry to its mixed
+/// row.
+pub fn mix_credential_rows(
+    counter_id: &str,
+    poll_parameters: &PollParametersStorage,
+    roster: &CredentialRoster,
+    deduplicated_votes: &[VoteStorage],
+    mix_parts: &[CredentialMixPart],
+) -> Result<CredentialMixPart, WedprError> {
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+    let (vote_row_list, roster_row_list) = get_credential_mix_input(
+        poll_parameters,
+        roster,
+        deduplicated_votes,
+        mix_parts,
+    )?;
+    let (shuffled_vote_row_list, vote_shuffle_proof) =
+        shuffle_ballot_rows(&poll_point, &vote_row_list)?;
+    let (shuffled_roster_row_list, roster_shuffle_proof) =
+        shuffle_ballot_rows(&poll_point, &roster_row_list)?;
+    let mut mix_part = CredentialMixPart::new();
+    mix_part.set_counter_id(counter_id.to_string());
+    for shuffled_row in shuffled_vote_row_list {
+        let mut ballot_row = BallotRow::new();
+        ballot_row.set_ballot(shuffled_row.into());
+        mix_part.mut_vote_row().push(ballot_row);
+    }
+    mix_part.set_vote_shuffle_proof(vote_shuffle_proof);
+    for shuffled_row in shuffled_roster_row_list {
+        let mut ballot_row = BallotRow::new();
+        ballot_row.set_ballot(shuffled_row.into());
+        mix_part.mut_roster_row().push(ballot_row);
+    }
+    mix_part.set_roster_shuffle_proof(roster_shuffle_proof);
+    Ok(mix_part)
+}
+
+/// Blinds the differences between each mixed vote row and each mixed roster
+/// row of the last mix part, and generates the ZKP proofs that each difference
+/// is blinded with a secret. It is the first round of filtering the mixed
+/// ballot box before counting.
+pub fn blind_credential_matches(
+    counter_id: &str,
+    mix_parts: &[CredentialMixPart],
+) -> Result<CredentialBlindingPart, WedprError> {
+    let mix_part = match mix_parts.last() {
+        Some(v) => v,
+        None => return Err(WedprError::ArgumentError),
+    };
+    Ok(blind_difference_list(
+        counter_id,
+        &get_credential_match_difference_list(mix_part)?,
+    ))
+}
+
+/// Partially decrypts the aggregated blinded differences of the duplicate
+/// tests or the roster tests, and generates associated ZKP proofs. It is the
+/// second round of both tests.
+pub fn decrypt_credential_differences(
+    counter_id: &str,
+    counter_secret: &CounterSecret,
+    blinded_difference_sum: &[Ballot],
+) -> Result<CredentialDecryptionPart, WedprError> {
+    let secret_share = bytes_to_scalar(counter_secret.get_poll_secret_share())?;
+    let mut decryption_part = CredentialDecryptionPart::new();
+    decryption_part.set_counter_id(counter_id.to_string());
+    for blinded_difference in blinded_difference_sum {
+        let mut decrypted_part =
+            decrypt_ballot_part(&secret_share, blinded_difference)?;
+        decrypted_part.set_counter_id(counter_id.to_string());
+        decryption_part.mut_decrypted_part().push(decrypted_part);
+    }
+    Ok(decryption_part)
+}
+
 /// Counts the aggregated ciphertext ballots and generates associated ZKP
 /// proofs.
 pub fn count(
@@ -51,31 +189,51 @@ pub fn count(
     let secret_share =
         bytes_to_scalar(&counter_secret.get_poll_secret_share())?;
     let mut partially_decrypted_result = DecryptedResultPartStorage::new();
-    for candidate_ballot_pair in encrypted_vote_sum.get_voted_ballot() {
-        // Count by partially decrypting the aggregated ciphertext ballots.
-        let ballot = candidate_ballot_pair.get_ballot();
-        let candidate_part_share = bytes_to_point(ballot.get_ciphertext2())?;
-        let mut counting_part = CountingPart::new();
-        counting_part.set_blinding_c2(point_to_bytes(
-            &(&candidate_part_share * (secret_share)),
-        ));
-
-        // Prove the equality for each candidate.
-        let equality_proof = prove_equality_relationship_proof(
-            &secret_share,
-            &BASEPOINT_G2,
-            &candidate_part_share,
-        );
-        counting_part.set_equality_proof(equality_proof.serialize());
-        // Write back.
-        let candidate = candidate_ballot_pair.get_candidate();
-        let mut candidate_counting_part_pair = StringToCountingPartPair::new();
-        candidate_counting_part_pair.set_key(candidate.to_string());
-        candidate_counting_part_pair.set_value(counting_part);
+    for candidate_counting_part_pair in count_candidate_ballots(
+        &secret_share,
+        encrypted_vote_sum.get_voted_ballot(),
+    )? {
         partially_decrypted_result
             .mut_candidate_part()
             .push(candidate_counting_part_pair);
     }
+    for against_counting_part_pair in count_candidate_ballots(
+        &secret_share,
+        encrypted_vote_sum.get_against_ballot(),
+    )? {
+        partially_decrypted_result
+            .mut_against_part()
+            .push(against_counting_part_pair);
+    }
+    if encrypted_vote_sum.has_rest_ballot() {
+        partially_decrypted_result.set_rest_part(decrypt_ballot_part(
+            &secret_share,
+            encrypted_vote_sum.get_rest_ballot(),
+        )?);
+    }
+    // Count the ballots of each question in the same round.
+    for question_ballot in encrypted_vote_sum.get_question_ballot() {
+        let mut question_part = QuestionCountingPart::new();
+        question_part
+            .set_question_id(question_ballot.get_question_id().to_string());
+        for candidate_counting_part_pair in count_candidate_ballots(
+            &secret_share,
+            question_ballot.get_voted_ballot(),
+        )? {
+            question_part
+                .mut_candidate_part()
+                .push(candidate_counting_part_pair);
+        }
+        if question_ballot.has_rest_ballot() {
+            question_part.set_rest_part(decrypt_ballot_part(
+                &secret_share,
+                question_ballot.get_rest_ballot(),
+            )?);
+        }
+        partially_decrypted_result
+            .mut_question_part()
+            .push(question_part);
+    }
 
     // Prove the equality for the blank ballot.
     let blank_ciphertext2_sum = bytes_to_point(
@@ -96,6 +254,56 @@ pub fn count(
     Ok(partially_decrypted_result)
 }
 
+// Counts by partially decrypting the aggregated ciphertext ballots of
+// candidates.
+fn count_candidate_ballots(
+    secret_share: &Scalar,
+    candidate_ballot_list: &[CandidateBallot],
+) -> Result<Vec<StringToCountingPartPair>, WedprError> {
+    let mut candidate_counting_part_list = Vec::new();
+    for candidate_ballot_pair in candidate_ballot_list {
+        let ballot = candidate_ballot_pair.get_ballot();
+        let candidate_part_share = bytes_to_point(ballot.get_ciphertext2())?;
+        let mut counting_part = CountingPart::new();
+        counting_part.set_blinding_c2(point_to_bytes(
+            &(candidate_part_share * secret_share),
+        ));
+
+        // Prove the equality for each candidate.
+        let equality_proof = prove_equality_relationship_proof(
+            secret_share,
+            &BASEPOINT_G2,
+            &candidate_part_share,
+        );
+        counting_part.set_equality_proof(equality_proof.serialize());
+        // Write back.
+        let candidate = candidate_ballot_pair.get_candidate();
+        let mut candidate_counting_part_pair = StringToCountingPartPair::new();
+        candidate_counting_part_pair.set_key(candidate.to_string());
+        candidate_counting_part_pair.set_value(counting_part);
+        candidate_counting_part_list.push(candidate_counting_part_pair);
+    }
+    Ok(candidate_counting_part_list)
+}
+
+// Partially decrypts a ciphertext, such as the aggregated rest ballot of the
+// abstained votes, and proves the equality to the secret share.
+fn decrypt_ballot_part(
+    secret_share: &Scalar,
+    ballot: &Ballot,
+) -> Result<CountingPart, WedprError> {
+    let part_share = bytes_to_point(ballot.get_ciphertext2())?;
+    let mut counting_part = CountingPart::new();
+    counting_part.set_blinding_c2(point_to_bytes(&(part_share * secret_share)));
+    let equality_proof = prove_equality_relationship_proof(
+        secret_share,
+        &BASEPOINT_G2,
+        &part_share,
+    );
+    counting_part.set_equality_proof(equality_proof.serialize());
+    Ok(counting_part)
+}
+
 pub fn count_unlisted(
     counter_id: &str,
     counter_secret: &CounterSecret,
@@ -164,3 +372,781 @@ pub fn count_unlisted(
     }
     Ok(partially_decrypted_result)
 }
+
+/// Blinds the latest unlisted candidate ciphers with a fresh secret, and
+/// generates associated ZKP proofs. After all counters have blinded the
+/// ciphers in turn, equal candidates still have equal blinded plaintexts,
+/// which allows grouping the unlisted ballots without decrypting any of them.
+pub fn blind_unlisted_candidates(
+    counter_id: &str,
+    encrypted_vote_sum: &VoteStorage,
+    grouping: &UnlistedGroupingStorage,
+) -> Result<UnlistedBlindingPart, WedprError> {
+    let blinding = get_random_scalar();
+    let blinding_point = *BASEPOINT_G2 * blinding;
+    let mut blinding_part = UnlistedBlindingPart::new();
+    blinding_part.set_counter_id(counter_id.to_string());
+    blinding_part.set_blinding_point(point_to_bytes(&blinding_point));
+    for candidate_cipher in
+        get_unlisted_candidate_cipher_list(encrypted_vote_sum, grouping)
+    {
+        let ciphertext1 = bytes_to_point(candidate_cipher.get_ciphertext1())?;
+        let ciphertext2 = bytes_to_point(candidate_cipher.get_ciphertext2())?;
+        let ciphertext1_proof = prove_equality_relationship_proof(
+            &blinding,
+            &BASEPOINT_G2,
+            &ciphertext1,
+        );
+        let ciphertext2_proof = prove_equality_relationship_proof(
+            &blinding,
+            &BASEPOINT_G2,
+            &ciphertext2,
+        );
+        let mut blinded_cipher = BlindedCipherPoints::new();
+        blinded_cipher
+            .mut_cipher()
+            .set_ciphertext1(point_to_bytes(&(ciphertext1 * blinding)));
+        blinded_cipher
+            .mut_cipher()
+            .set_ciphertext2(point_to_bytes(&(ciphertext2 * blinding)));
+        blinded_cipher.set_ciphertext1_proof(ciphertext1_proof.serialize());
+        blinded_cipher.set_ciphertext2_proof(ciphertext2_proof.serialize());
+        blinding_part
+            .mut_blinded_candidate_cipher()
+            .push(blinded_cipher);
+    }
+    Ok(blinding_part)
+}
+
+/// Partially decrypts the unlisted candidate ciphers blinded by all counters
+/// into tags, and generates associated ZKP proofs.
+pub fn decrypt_unlisted_tags(
+    counter_id: &str,
+    counter_secret: &CounterSecret,
+    grouping: &UnlistedGroupingStorage,
+) -> Result<UnlistedTagDecryptedPart, WedprError> {
+    let blinding_part = match grouping.get_blinding_part().last() {
+        Some(v) => v,
+        None => return Err(WedprError::ArgumentError),
+    };
+    let secret_share = bytes_to_scalar(counter_secret.get_poll_secret_share())?;
+    let mut tag_decrypted_part = UnlistedTagDecryptedPart::new();
+    tag_decrypted_part.set_counter_id(counter_id.to_string());
+    for blinded_cipher in blinding_part.get_blinded_candidate_cipher() {
+        let ciphertext2 =
+            bytes_to_point(blinded_cipher.get_cipher().get_ciphertext2())?;
+        let equality_proof = prove_equality_relationship_proof(
+            &secret_share,
+            &BASEPOINT_G2,
+            &ciphertext2,
+        );
+        let mut tag_part = CountingPart::new();
+        tag_part.set_counter_id(counter_id.to_string());
+        tag_part.set_blinding_c2(point_to_bytes(&(ciphertext2 * secret_share)));
+        tag_part.set_equality_proof(equality_proof.serialize());
+        tag_decrypted_part.mut_tag_part().push(tag_part);
+    }
+    Ok(tag_decrypted_part)
+}
+
+/// Blinds every difference of a ranking comparison after the previous mix
+/// parts with a random secret, then shuffles and re-encrypts the blinded
+/// differences, and generates the ZKP proofs of both steps. Every counter
+/// mixes the comparison in turn, so that the differences cannot be linked to
+/// the values they are compared with.
+pub fn mix_ranking_comparison(
+    counter_id: &str,
+    poll_parameters: &PollParametersStorage,
+    vote_sum: &VoteStorage,
+    comparison: &RankingComparison,
+) -> Result<RankingMixPart, WedprError> {
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+    let difference_list = get_ranking_mix_input(
+        vote_sum,
+        comparison,
+        comparison.get_mix_part().len(),
+    )?;
+    let mut mix_part = RankingMixPart::new();
+    mix_part.set_counter_id(counter_id.to_string());
+    let mut blinded_row_list = Vec::new();
+    for difference in &difference_list {
+        let difference1 = bytes_to_point(difference.get_ciphertext1())?;
+        let difference2 = bytes_to_point(difference.get_ciphertext2())?;
+        let blinding = get_random_scalar();
+        let mut blinded_difference = Ballot::new();
+        blinded_difference
+            .set_ciphertext1(point_to_bytes(&(difference1 * blinding)));
+        blinded_difference
+            .set_ciphertext2(point_to_bytes(&(difference2 * blinding)));
+        let equality_proof = prove_equality_relationship_proof(
+            &blinding,
+            &difference1,
+            &difference2,
+        );
+        mix_part
+            .mut_blinded_difference()
+            .push(blinded_difference.clone());
+        mix_part
+            .mut_equality_proof()
+            .push(equality_proof.serialize());
+        blinded_row_list.push(vec![blinded_difference]);
+    }
+    let (shuffled_row_list, shuffle_proof) =
+        shuffle_ballot_rows(&poll_point, &blinded_row_list)?;
+    for mut shuffled_row in shuffled_row_list {
+        mix_part
+            .mut_shuffled_difference()
+            .push(shuffled_row.remove(0));
+    }
+    mix_part.set_shuffle_proof(shuffle_proof);
+    Ok(mix_part)
+}
+
+/// Partially decrypts the differences of the last mix part of a ranking
+/// comparison and generates associated ZKP proofs, after all counters have
+/// mixed the comparison.
+pub fn decrypt_ranking_comparison(
+    counter_id: &str,
+    counter_secret: &CounterSecret,
+    comparison: &RankingComparison,
+) -> Result<RankingDecryptionPart, WedprError> {
+    check_ranking_max_difference(comparison)?;
+    let mix_part = match comparison.get_mix_part().last() {
+        Some(v) => v,
+        None => return Err(WedprError::ArgumentError),
+    };
+    if mix_part.get_shuffled_difference().len()
+        != comparison.get_max_difference() as usize + 1
+    {
+        return Err(WedprError::ArgumentError);
+    }
+    let secret_share = bytes_to_scalar(counter_secret.get_poll_secret_share())?;
+    let mut decryption_part = RankingDecryptionPart::new();
+    decryption_part.set_counter_id(counter_id.to_string());
+    for shuffled_difference in mix_part.get_shuffled_difference() {
+        let mut decrypted_part =
+            decrypt_ballot_part(&secret_share, shuffled_difference)?;
+        decrypted_part.set_counter_id(counter_id.to_string());
+        decryption_part.mut_decrypted_part().push(decrypted_part);
+    }
+    Ok(decryption_part)
+}
+
+// Re-encrypts every ballot of the rows in a random order, and returns the
+// order, the re-encryption blindings and the re-encrypted rows.
+fn reencrypt_ballot_rows(
+    poll_point: &RistrettoPoint,
+    point_row_list: &[Vec<(RistrettoPoint, RistrettoPoint)>],
+) -> (Vec<usize>, Vec<Vec<Scalar>>, Vec<Vec<Ballot>>) {
+    // Fisher-Yates shuffle, the modulo bias of a 64-bit random number is
+    // negligible.
+    let mut permutation: Vec<usize> = (0..point_row_list.len()).collect();
+    for i in (1..permutation.len()).rev() {
+        let mut random_bytes = [0u8; 8];
+        random_bytes.copy_from_slice(&get_random_scalar().to_bytes()[..8]);
+        let j = (u64::from_le_bytes(random_bytes) % (i as u64 + 1)) as usize;
+        permutation.swap(i, j);
+    }
+    let mut blinding_row_list = Vec::with_capacity(permutation.len());
+    let mut row_list = Vec::with_capacity(permutation.len());
+    for index in &permutation {
+        let mut blinding_row = Vec::new();
+        let mut row = Vec::new();
+        for (ciphertext1, ciphertext2) in &point_row_list[*index] {
+            let blinding = get_random_scalar();
+            let mut ballot = Ballot::new();
+            ballot.set_ciphertext1(point_to_bytes(
+                &(ciphertext1 + poll_point * blinding),
+            ));
+            ballot.set_ciphertext2(point_to_bytes(
+                &(ciphertext2 + *BASEPOINT_G2 * blinding),
+            ));
+            blinding_row.push(blinding);
+            row.push(ballot);
+        }
+        blinding_row_list.push(blinding_row);
+        row_list.push(row);
+    }
+    (permutation, blinding_row_list, row_list)
+}
+
+// Shuffles and re-encrypts the ballot rows, and generates the shuffle proof
+// with a shadow shuffle for each round. A round opens either the shadow
+// shuffle itself, or the shuffle from the shadow rows to the output rows.
+pub(crate) fn shuffle_ballot_rows(
+    poll_point: &RistrettoPoint,
+    row_list: &[Vec<Ballot>],
+) -> Result<(Vec<Vec<Ballot>>, ShuffleProof), WedprError> {
+    let mut point_row_list = Vec::with_capacity(row_list.len());
+    for row in row_list {
+        let mut point_row = Vec::with_capacity(row.len());
+        for ballot in row {
+            point_row.push((
+                bytes_to_point(ballot.get_ciphertext1())?,
+                bytes_to_point(ballot.get_ciphertext2())?,
+            ));
+        }
+        point_row_list.push(point_row);
+    }
+    let (permutation, blinding_row_list, shuffled_row_list) =
+        reencrypt_ballot_rows(poll_point, &point_row_list);
+    let mut shadow_list = Vec::with_capacity(SHUFFLE_PROOF_ROUNDS);
+    let mut shuffle_proof = ShuffleProof::new();
+    for _ in 0..SHUFFLE_PROOF_ROUNDS {
+        let (shadow_permutation, shadow_blinding_row_list, shadow_row_list) =
+            reencrypt_ballot_rows(poll_point, &point_row_list);
+        let mut round = ShuffleProofRound::new();
+        for shadow_row in shadow_row_list {
+            let mut ballot_row = BallotRow::new();
+            ballot_row.set_ballot(shadow_row.into());
+            round.mut_shadow_row().push(ballot_row);
+        }
+        shuffle_proof.mut_round().push(round);
+        shadow_list.push((shadow_permutation, shadow_blinding_row_list));
+    }
+    let challenge = compute_shuffle_challenge(
+        poll_point,
+        row_list,
+        &shuffled_row_list,
+        &shuffle_proof,
+    );
+    for ((round, (shadow_permutation, shadow_blinding_row_list)), opened) in
+        shuffle_proof
+            .mut_round()
+            .iter_mut()
+            .zip(shadow_list)
+            .zip(challenge)
+    {
+        if !opened {
+            // Open the shuffle from the input rows to the shadow rows.
+            for (index, blinding_row) in
+                shadow_permutation.iter().zip(shadow_blinding_row_list)
+            {
+                round.mut_permutation().push(*index as u32);
+                for blinding in blinding_row {
+                    round.mut_blinding().push(scalar_to_bytes(&blinding));
+                }
+            }
+            continue;
+        }
+        // Open the shuffle from the shadow rows to the output rows, where the
+        // output row i comes from the input row permutation[i], which is the
+        // shadow row at the position of it in the shadow permutation.
+        let mut shadow_position = vec![0; shadow_permutation.len()];
+        for (position, index) in shadow_permutation.iter().enumerate() {
+            shadow_position[*index] = position;
+        }
+        for (index, blinding_row) in permutation.iter().zip(&blinding_row_list)
+        {
+            let position = shadow_position[*index];
+            round.mut_permutation().push(position as u32);
+            for (blinding, shadow_blinding) in
+                blinding_row.iter().zip(&shadow_blinding_row_list[position])
+            {
+                round
+                    .mut_blinding()
+                    .push(scalar_to_bytes(&(blinding - shadow_blinding)));
+            }
+        }
+    }
+    Ok((shuffled_row_list, shuffle_proof))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        config::{
+            MAX_RANKING_DIFFERENCE, POLL_RESULT_KEY_ABSTAINED_BALLOTS,
+            POLL_RESULT_KEY_TOTAL_BALLOTS,
+        },
+        coordinator,
+        test_utils::TestPoll,
+        verifier, voter,
+    };
+
+    #[test]
+    fn test_coercion_resistant_voting() {
+        let test_poll = TestPoll::new(&["1001", "1002"], &["Kitten", "Doge"]);
+
+        // Each voter gets a credential, and its encrypted credential is
+        // published on the roster.
+        let mut roster = CredentialRoster::new();
+        let mut voter_list = vec![];
+        for _ in 0..3 {
+            let (vote_secret, registration_response) =
+                test_poll.register_voter(10);
+            let (credential, roster_entry) =
+                coordinator::issue_voter_credential(
+                    &test_poll.poll_parameters,
+                    &registration_response,
+                )
+                .unwrap();
+            roster.mut_entry().push(roster_entry);
+            voter_list.push((vote_secret, registration_response, credential));
+        }
+
+        // The first voter is coerced and hands over a fake credential, then
+        // votes again with the real credential. The third voter votes twice.
+        let fake_credential = voter::make_fake_credential();
+        let mut ballot_box = vec![];
+        for (voter_index, use_fake_credential, choice, sequence) in [
+            (0, true, vec![10, 0], u32::MAX),
+            (0, false, vec![0, 10], 1),
+            (1, false, vec![3, 4], 0),
+            (2, false, vec![5, 5], 0),
+            (2, false, vec![1, 1], 2),
+        ] {
+            let (vote_secret, registration_response, credential) =
+                &voter_list[voter_index];
+            let mut vote_choices =
+                voter::make_vote_choices(&choice, &test_poll.candidate_list);
+            vote_choices.set_sequence(sequence);
+            let vote_request = voter::vote_with_credential(
+                vote_secret,
+                if use_fake_credential {
+                    &fake_credential
+                } else {
+                    credential
+                },
+                &vote_choices,
+                registration_response,
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            )
+            .unwrap();
+            // A vote with the fake credential is accepted as the real one.
+            assert!(verifier::verify_vote_request(
+                &test_poll.poll_parameters,
+                &vote_request,
+                &test_poll.public_key
+            )
+            .unwrap());
+            ballot_box.push(vote_request.get_vote().clone());
+
+            // The encrypted credential on the roster cannot be copied.
+            let mut forged_vote_request = vote_request.clone();
+            forged_vote_request.mut_vote().set_encrypted_credential(
+                roster.get_entry()[voter_index]
+                    .get_encrypted_credential()
+                    .clone(),
+            );
+            assert!(!verifier::verify_credential_proof(
+                &test_poll.poll_parameters,
+                &forged_vote_request
+            )
+            .unwrap());
+        }
+
+        // The counters first remove the votes with duplicate credentials, and
+        // only the last vote of each credential is kept.
+        let blinding_parts: Vec<_> = test_poll
+            .counters()
+            .map(|(counter_id, _)| {
+                blind_credential_duplicates(counter_id, &ballot_box).unwrap()
+            })
+            .collect();
+        let blinded_difference_sum =
+            coordinator::aggregate_credential_blinding_parts(&blinding_parts)
+                .unwrap();
+        let decryption_parts: Vec<_> = test_poll
+            .counters()
+            .map(|(counter_id, counter_secret)| {
+                decrypt_credential_differences(
+                    counter_id,
+                    counter_secret,
+                    &blinded_difference_sum,
+                )
+                .unwrap()
+            })
+            .collect();
+        let deduplicated_votes = coordinator::remove_credential_duplicates(
+            &ballot_box,
+            &blinded_difference_sum,
+            &decryption_parts,
+        )
+        .unwrap();
+        assert_eq!(deduplicated_votes, vec![
+            ballot_box[0].clone(),
+            ballot_box[1].clone(),
+            ballot_box[2].clone(),
+            ballot_box[4].clone()
+        ]);
+        assert!(verifier::verify_credential_deduplication(
+            &test_poll.counter_parameters,
+            &ballot_box,
+            &blinding_parts,
+            &decryption_parts,
+            &deduplicated_votes
+        )
+        .unwrap());
+        assert!(!verifier::verify_credential_deduplication(
+            &test_poll.counter_parameters,
+            &ballot_box,
+            &blinding_parts,
+            &decryption_parts,
+            &ballot_box[1..]
+        )
+        .unwrap());
+        let mut forged_blinding_parts = blinding_parts.clone();
+        forged_blinding_parts[0].mut_blinded_difference().swap(0, 1);
+        assert!(!verifier::verify_credential_deduplication(
+            &test_poll.counter_parameters,
+            &ballot_box,
+            &forged_blinding_parts,
+            &decryption_parts,
+            &deduplicated_votes
+        )
+        .unwrap());
+        let duplicate_blinding_parts =
+            vec![blinding_parts[0].clone(), blinding_parts[0].clone()];
+        assert!(!verifier::verify_credential_deduplication(
+            &test_poll.counter_parameters,
+            &ballot_box,
+            &duplicate_blinding_parts,
+            &decryption_parts,
+            &deduplicated_votes
+        )
+        .unwrap());
+
+        // Each counter in turn mixes the votes and the roster, so that no one
+        // can link a mixed vote to a voter.
+        let mut mix_parts = vec![];
+        for (counter_id, _) in test_poll.counters() {
+            let mix_part = mix_credential_rows(
+                counter_id,
+                &test_poll.poll_parameters,
+                &roster,
+                &deduplicated_votes,
+                &mix_parts,
+            )
+            .unwrap();
+            mix_parts.push(mix_part);
+        }
+        assert!(verifier::verify_credential_mix(
+            &test_poll.poll_parameters,
+            &test_poll.counter_parameters,
+            &roster,
+            &deduplicated_votes,
+            &mix_parts
+        )
+        .unwrap());
+        let mut forged_mix_parts = mix_parts.clone();
+        forged_mix_parts[0].mut_vote_row().swap(0, 1);
+        assert!(!verifier::verify_credential_mix(
+            &test_poll.poll_parameters,
+            &test_poll.counter_parameters,
+            &roster,
+            &deduplicated_votes,
+            &forged_mix_parts
+        )
+        .unwrap());
+        assert!(!verifier::verify_credential_mix(
+            &test_poll.poll_parameters,
+            &test_poll.counter_parameters,
+            &roster,
+            &deduplicated_votes,
+            &mix_parts[..1]
+        )
+        .unwrap());
+
+        // Then the counters test the mixed votes against the mixed roster, and
+        // only the votes with a valid credential are summed.
+        let blinding_parts: Vec<_> = test_poll
+            .counters()
+            .map(|(counter_id, _)| {
+                blind_credential_matches(counter_id, &mix_parts).unwrap()
+            })
+            .collect();
+        let blinded_difference_sum =
+            coordinator::aggregate_credential_blinding_parts(&blinding_parts)
+                .unwrap();
+        let decryption_parts: Vec<_> = test_poll
+            .counters()
+            .map(|(counter_id, counter_secret)| {
+                decrypt_credential_differences(
+                    counter_id,
+                    counter_secret,
+                    &blinded_difference_sum,
+                )
+                .unwrap()
+            })
+            .collect();
+        let filtered_vote_sum = coordinator::filter_votes_by_credential(
+            &test_poll.poll_parameters,
+            &mix_parts,
+            &blinded_difference_sum,
+            &decryption_parts,
+        )
+        .unwrap();
+        assert!(verifier::verify_credential_filter(
+            &test_poll.poll_parameters,
+            &test_poll.counter_parameters,
+            &mix_parts,
+            &blinding_parts,
+            &decryption_parts,
+            &filtered_vote_sum
+        )
+        .unwrap());
+        let mut forged_vote_sum = filtered_vote_sum.clone();
+        forged_vote_sum
+            .set_blank_ballot(deduplicated_votes[0].get_blank_ballot().clone());
+        assert!(!verifier::verify_credential_filter(
+            &test_poll.poll_parameters,
+            &test_poll.counter_parameters,
+            &mix_parts,
+            &blinding_parts,
+            &decryption_parts,
+            &forged_vote_sum
+        )
+        .unwrap());
+        let mut forged_blinding_parts = blinding_parts;
+        forged_blinding_parts[0].mut_blinded_difference().swap(0, 1);
+        assert!(!verifier::verify_credential_filter(
+            &test_poll.poll_parameters,
+            &test_poll.counter_parameters,
+            &mix_parts,
+            &forged_blinding_parts,
+            &decryption_parts,
+            &filtered_vote_sum
+        )
+        .unwrap());
+
+        // Only the votes with a valid credential are counted.
+        let aggregated_decrypted_result = test_poll.count(&filtered_vote_sum);
+        let vote_result = coordinator::finalize_vote_result(
+            &test_poll.poll_parameters,
+            &filtered_vote_sum,
+            &aggregated_decrypted_result,
+            50,
+        )
+        .unwrap();
+        let result: Vec<(&str, i64)> = vote_result
+            .get_result()
+            .iter()
+            .map(|pair| (pair.get_key(), pair.get_value()))
+            .collect();
+        assert_eq!(result, vec![
+            (POLL_RESULT_KEY_TOTAL_BALLOTS, 30),
+            ("Kitten", 4),
+            ("Doge", 15),
+            (POLL_RESULT_KEY_ABSTAINED_BALLOTS, 11),
+        ]);
+        assert!(verifier::verify_filtered_vote_result(
+            &test_poll.poll_parameters,
+            &filtered_vote_sum,
+            &aggregated_decrypted_result,
+            &vote_result,
+            &test_poll.public_key
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn test_vote_ranking() {
+        let test_poll =
+            TestPoll::new(&["1001", "1002"], &["Kitten", "Doge", "Bunny"]);
+        let mut encrypted_vote_sum = VoteStorage::new();
+        //          Kitten  Doge  Bunny
+        // voter1:     1     3      1
+        // voter2:     2     2      0
+        for choice in [vec![1, 3, 1], vec![2, 2, 0]] {
+            let (vote_secret, registration_response) =
+                test_poll.register_voter(10);
+            let vote_request = voter::vote(
+                &vote_secret,
+                &voter::make_vote_choices(&choice, &test_poll.candidate_list),
+                &registration_response,
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            )
+            .unwrap();
+            assert!(coordinator::aggregate_vote_sum_response(
+                &test_poll.poll_parameters,
+                vote_request.get_vote(),
+                &mut encrypted_vote_sum
+            )
+            .unwrap());
+        }
+
+        // The counters mix and decrypt a comparison in turn, so that only
+        // whether the vote difference is in [0, 9] is revealed.
+        let compare = |greater_candidate: &str, lesser_candidate: &str| {
+            let mut comparison = coordinator::make_ranking_comparison(
+                &test_poll.poll_parameters,
+                greater_candidate,
+                lesser_candidate,
+                9,
+            )
+            .unwrap();
+            for counter_id in &test_poll.counter_id_list {
+                let mix_part = mix_ranking_comparison(
+                    counter_id,
+                    &test_poll.poll_parameters,
+                    &encrypted_vote_sum,
+                    &comparison,
+                )
+                .unwrap();
+                comparison.mut_mix_part().push(mix_part);
+            }
+            for (counter_id, counter_secret) in test_poll.counters() {
+                let decryption_part = decrypt_ranking_comparison(
+                    counter_id,
+                    counter_secret,
+                    &comparison,
+                )
+                .unwrap();
+                comparison.mut_decryption_part().push(decryption_part);
+            }
+            assert!(verifier::verify_ranking_comparison(
+                &test_poll.poll_parameters,
+                &test_poll.counter_parameters,
+                &encrypted_vote_sum,
+                &comparison
+            )
+            .unwrap());
+            comparison
+        };
+
+        // The coordinator selects the candidate with no fewer votes than all
+        // the rest in turn, without decrypting any vote.
+        let mut comparison_list = vec![];
+        let mut remaining_candidate_list =
+            test_poll.candidate_list.get_candidate().to_vec();
+        let mut ranked_candidate_list = vec![];
+        while !remaining_candidate_list.is_empty() {
+            let mut top_index = 0;
+            for index in 1..remaining_candidate_list.len() {
+                let comparison = compare(
+                    &remaining_candidate_list[top_index],
+                    &remaining_candidate_list[index],
+                );
+                if !coordinator::decide_ranking_comparison(&comparison).unwrap()
+                {
+                    top_index = index;
+                }
+                comparison_list.push(comparison);
+            }
+            ranked_candidate_list
+                .push(remaining_candidate_list.remove(top_index));
+        }
+        assert_eq!(ranked_candidate_list, ["Doge", "Kitten", "Bunny"]);
+        // The winner is compared with all the other candidates.
+        comparison_list.push(compare("Doge", "Kitten"));
+
+        // Only the winner is disclosed.
+        let winner_ranking = coordinator::make_vote_ranking(
+            &test_poll.poll_parameters,
+            &ranked_candidate_list[..1],
+            &comparison_list,
+        )
+        .unwrap();
+        assert!(verifier::verify_vote_ranking(
+            &test_poll.poll_parameters,
+            &test_poll.counter_parameters,
+            &encrypted_vote_sum,
+            &winner_ranking,
+            &test_poll.public_key
+        )
+        .unwrap());
+        let full_ranking = coordinator::make_vote_ranking(
+            &test_poll.poll_parameters,
+            &ranked_candidate_list,
+            &comparison_list,
+        )
+        .unwrap();
+        assert!(verifier::verify_vote_ranking(
+            &test_poll.poll_parameters,
+            &test_poll.counter_parameters,
+            &encrypted_vote_sum,
+            &full_ranking,
+            &test_poll.public_key
+        )
+        .unwrap());
+
+        // A comparison whose vote difference bound is too large to mix is
+        // rejected by the coordinator, the counters and the verifier.
+        assert!(coordinator::make_ranking_comparison(
+            &test_poll.poll_parameters,
+            "Doge",
+            "Kitten",
+            MAX_RANKING_DIFFERENCE + 1,
+        )
+        .is_err());
+        let mut oversized_comparison = comparison_list[0].clone();
+        oversized_comparison.set_max_difference(MAX_RANKING_DIFFERENCE + 1);
+        assert!(!verifier::verify_ranking_comparison(
+            &test_poll.poll_parameters,
+            &test_poll.counter_parameters,
+            &encrypted_vote_sum,
+            &oversized_comparison
+        )
+        .unwrap());
+        assert!(decrypt_ranking_comparison(
+            &test_poll.counter_id_list[0],
+            &test_poll.counter_secret_list[0],
+            &oversized_comparison
+        )
+        .is_err());
+        oversized_comparison.clear_mix_part();
+        assert!(mix_ranking_comparison(
+            &test_poll.counter_id_list[0],
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            &oversized_comparison
+        )
+        .is_err());
+
+        // A ranking with another winner cannot be made or verified.
+        assert!(coordinator::make_vote_ranking(
+            &test_poll.poll_parameters,
+            &["Kitten".to_string()],
+            &comparison_list,
+        )
+        .is_err());
+        let mut forged_ranking = winner_ranking.clone();
+        forged_ranking.mut_ranked_candidate()[0] = "Kitten".to_string();
+        assert!(!verifier::verify_vote_ranking(
+            &test_poll.poll_parameters,
+            &test_poll.counter_parameters,
+            &encrypted_vote_sum,
+            &forged_ranking,
+            &test_poll.public_key
+        )
+        .unwrap());
+        // The comparisons of another winner do not hold.
+        forged_ranking.clear_comparison();
+        for lesser_candidate in ["Doge", "Bunny"] {
+            let comparison = comparison_list
+                .iter()
+                .find(|comparison| {
+                    comparison.get_greater_candidate() == "Kitten"
+                        && comparison.get_lesser_candidate() == lesser_candidate
+                })
+                .unwrap();
+            forged_ranking.mut_comparison().push(comparison.clone());
+        }
+        assert!(!verifier::verify_vote_ranking(
+            &test_poll.poll_parameters,
+            &test_poll.counter_parameters,
+            &encrypted_vote_sum,
+            &forged_ranking,
+            &test_poll.public_key
+        )
+        .unwrap());
+
+        // A mix part shuffling the differences without a valid proof is
+        // rejected.
+        let mut forged_comparison = winner_ranking.get_comparison()[0].clone();
+        forged_comparison.mut_mix_part()[0]
+            .mut_shuffled_difference()
+            .swap(0, 1);
+        assert!(!verifier::verify_ranking_comparison(
+            &test_poll.poll_parameters,
+            &test_poll.counter_parameters,
+            &encrypted_vote_sum,
+            &forged_comparison
+        )
+        .unwrap());
+    }
+}
@@ -8,9 +8,13 @@ extern crate lazy_static;
 #[macro_use]
 extern crate wedpr_l_macros;
 
+pub mod aggregator;
 pub mod config;
 pub mod coordinator;
 pub mod counter;
+pub mod poll;
+#[cfg(test)]
+mod test_utils;
 mod utils;
 pub mod verifier;
 pub mod voter;
@@ -18,17 +22,31 @@ pub mod voter;
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{config::POLL_RESULT_KEY_TOTAL_BALLOTS, coordinator};
+    use crate::{
+        config::{
+            MAX_UNLISTED_CANDIDATE_ID, POLL_RESULT_KEY_ABSTAINED_BALLOTS,
+            POLL_RESULT_KEY_TOTAL_BALLOTS,
+        },
+        coordinator,
+        test_utils::{make_question_list, TestPoll},
+    };
+    use curve25519_dalek::scalar::Scalar;
     use wedpr_l_crypto_zkp_utils::{
-        bytes_to_point, get_random_scalar, scalar_to_bytes,
+        bytes_to_point, get_random_scalar, point_to_bytes, scalar_to_bytes,
+        BASEPOINT_G1,
     };
-    use wedpr_l_utils::traits::Signature;
+    use wedpr_l_utils::{error::WedprError, traits::Signature};
     use wedpr_s_protos::{
-        config::SIGNATURE,
+        config::{
+            BALLOT_SIGNATURE_VERSION, LEGACY_BALLOT_SIGNATURE_VERSION,
+            SIGNATURE,
+        },
         generated::acv::{
-            CandidateList, CounterParametersStorage, CounterSecret,
-            DecryptedResultPartStorage, UnlistedVoteChoice, VoteChoice,
-            VoteChoices, VoteStorage, VoterSecret,
+            CandidateList, CertifiedBallot, CipherPointsToBallotProofPair,
+            CounterBlameReason, CounterParametersStorage, CounterSecret,
+            CredentialBlindingPart, DecryptedResultPartStorage, PollMode,
+            PollTranscript, UnlistedGroupingStorage, UnlistedVoteChoice,
+            VoteChoice, VoteChoices, VoteStorage, VoterSecret,
         },
     };
 
@@ -60,6 +78,7 @@ mod tests {
         let poll_parameters = coordinator::make_poll_parameters(
             &candidate_list,
             &counter_parameters,
+            &secret_key,
         )
         .unwrap();
         // Initialize all voters.
@@ -74,9 +93,11 @@ mod tests {
             let registration_request = voter::make_registration_request(
                 &vote_secret,
                 &poll_parameters,
+                &public_key,
             )
             .unwrap();
             let registration_response = coordinator::certify_voter(
+                &poll_parameters,
                 &secret_key,
                 &registration_request,
                 voter_weight,
@@ -114,6 +135,7 @@ mod tests {
                 &vote_choices,
                 &voter_registration_list[index],
                 &poll_parameters,
+                &public_key,
             )
             .unwrap();
             assert!(verifier::verify_vote_request(
@@ -140,25 +162,24 @@ mod tests {
                 &encrypted_vote_sum,
             )
             .unwrap();
-            let counter_share = bytes_to_point(
-                counter_parameters.get_counter_parameters_share()[index]
-                    .get_poll_point_share(),
-            )
-            .unwrap();
             assert!(verifier::verify_count_request(
                 &poll_parameters,
+                counter_id_list[index],
                 &encrypted_vote_sum,
-                &counter_share,
-                &partially_decrypted_result
+                &partially_decrypted_result,
+                &public_key
             )
             .unwrap());
             // Coordinator aggregates parts of decrypted poll result.
             assert!(coordinator::aggregate_decrypted_part_sum(
                 &poll_parameters,
+                counter_id_list[index],
+                &encrypted_vote_sum,
                 &partially_decrypted_result,
                 &mut aggregated_decrypted_result
             )
-            .unwrap());
+            .unwrap()
+            .is_none());
         }
         // Coordinator decrypts the final poll result by enumerating all
         // possible value and checking ZKP data.
@@ -172,11 +193,18 @@ mod tests {
             max_vote_limit,
         )
         .unwrap();
+        let accepted_votes: Vec<VoteStorage> = vote_request_list
+            .iter()
+            .map(|vote_request| vote_request.get_vote().clone())
+            .collect();
         assert!(verifier::verify_vote_result(
             &poll_parameters,
             &encrypted_vote_sum,
+            encrypted_vote_sum.get_vote_root(),
+            &accepted_votes,
             &aggregated_decrypted_result,
             &vote_result,
+            &public_key
         )
         .unwrap());
     }
@@ -227,9 +255,11 @@ mod tests {
         counter_parameters
             .mut_counter_parameters_share()
             .push(counter_share3.clone());
+        let coordinator_key_pair = SIGNATURE.generate_keypair();
         let poll_parameters = coordinator::make_poll_parameters(
             &pb_candidate_list,
             &counter_parameters,
+            &coordinator_key_pair.1,
         )
         .unwrap();
         pub struct VoterSecretPair {
@@ -253,6 +283,7 @@ mod tests {
             &voter1_secret_pair.zero_sercret,
             &voter1_secret_pair.weight_secret,
             &poll_parameters,
+            &coordinator_key_pair.0,
         )
         .unwrap();
 
@@ -261,6 +292,7 @@ mod tests {
             &voter2_secret_pair.zero_sercret,
             &voter2_secret_pair.weight_secret,
             &poll_parameters,
+            &coordinator_key_pair.0,
         )
         .unwrap();
 
@@ -269,12 +301,13 @@ mod tests {
             &voter3_secret_pair.zero_sercret,
             &voter3_secret_pair.weight_secret,
             &poll_parameters,
+            &coordinator_key_pair.0,
         )
         .unwrap();
 
         // certify_unbounded_voter
-        let coordinator_key_pair = SIGNATURE.generate_keypair();
         let response1 = coordinator::certify_unbounded_voter(
+            &poll_parameters,
             &coordinator_key_pair.1,
             &registration_request1,
             blank_ballot_count[0],
@@ -282,6 +315,7 @@ mod tests {
         .unwrap();
 
         let response2 = coordinator::certify_unbounded_voter(
+            &poll_parameters,
             &coordinator_key_pair.1,
             &registration_request2,
             blank_ballot_count[1],
@@ -289,6 +323,7 @@ mod tests {
         .unwrap();
 
         let response3 = coordinator::certify_unbounded_voter(
+            &poll_parameters,
             &coordinator_key_pair.1,
             &registration_request3,
             blank_ballot_count[2],
@@ -329,6 +364,7 @@ mod tests {
             &choice1,
             &response1,
             &poll_parameters,
+            &coordinator_key_pair.0,
         )
         .unwrap();
         // verify the vote
@@ -361,6 +397,7 @@ mod tests {
             &choice2,
             &response2,
             &poll_parameters,
+            &coordinator_key_pair.0,
         )
         .unwrap();
         // verify the vote request
@@ -392,6 +429,7 @@ mod tests {
             &choice3,
             &response3,
             &poll_parameters,
+            &coordinator_key_pair.0,
         )
         .unwrap();
         // verify the vote request
@@ -427,10 +465,10 @@ mod tests {
             true,
             verifier::verify_count_request(
                 &poll_parameters,
+                counter_id_list[0],
                 &encrypted_vote_sum,
-                &bytes_to_point(&counter_share1.get_poll_point_share())
-                    .unwrap(),
-                &decrypt_request1
+                &decrypt_request1,
+                &coordinator_key_pair.0
             )
             .unwrap()
         );
@@ -438,10 +476,13 @@ mod tests {
             true,
             coordinator::aggregate_decrypted_part_sum(
                 &poll_parameters,
+                counter_id_list[0],
+                &encrypted_vote_sum,
                 &decrypt_request1,
-                &mut vote_sum_total,
+                &mut vote_sum_total
             )
             .unwrap()
+            .is_none()
         );
 
         let decrypt_request2 =
@@ -452,10 +493,10 @@ mod tests {
             true,
             verifier::verify_count_request(
                 &poll_parameters,
+                counter_id_list[1],
                 &encrypted_vote_sum,
-                &bytes_to_point(&counter_share2.get_poll_point_share())
-                    .unwrap(),
-                &decrypt_request2
+                &decrypt_request2,
+                &coordinator_key_pair.0
             )
             .unwrap()
         );
@@ -463,10 +504,13 @@ mod tests {
             true,
             coordinator::aggregate_decrypted_part_sum(
                 &poll_parameters,
+                counter_id_list[1],
+                &encrypted_vote_sum,
                 &decrypt_request2,
-                &mut vote_sum_total,
+                &mut vote_sum_total
             )
             .unwrap()
+            .is_none()
         );
         // count3
         let decrypt_request3 =
@@ -477,10 +521,10 @@ mod tests {
             true,
             verifier::verify_count_request(
                 &poll_parameters,
+                counter_id_list[2],
                 &encrypted_vote_sum,
-                &bytes_to_point(&counter_share3.get_poll_point_share())
-                    .unwrap(),
-                &decrypt_request3
+                &decrypt_request3,
+                &coordinator_key_pair.0
             )
             .unwrap()
         );
@@ -488,10 +532,13 @@ mod tests {
             true,
             coordinator::aggregate_decrypted_part_sum(
                 &poll_parameters,
+                counter_id_list[2],
+                &encrypted_vote_sum,
                 &decrypt_request3,
-                &mut vote_sum_total,
+                &mut vote_sum_total
             )
             .unwrap()
+            .is_none()
         );
 
         // finalize_vote_result
@@ -506,8 +553,15 @@ mod tests {
         let result = verifier::verify_vote_result(
             &poll_parameters,
             &encrypted_vote_sum,
+            encrypted_vote_sum.get_vote_root(),
+            &[
+                vote_request1.get_vote().clone(),
+                vote_request2.get_vote().clone(),
+                vote_request3.get_vote().clone(),
+            ],
             &vote_sum_total,
             &final_result_request,
+            &coordinator_key_pair.0,
         )
         .unwrap();
         assert!(result);
@@ -560,9 +614,13 @@ mod tests {
         counter_parameters
             .mut_counter_parameters_share()
             .push(counter_share3.clone());
-        let poll_parameters = coordinator::make_poll_parameters(
+        let coordinator_key_pair = SIGNATURE.generate_keypair();
+        let poll_parameters = coordinator::make_poll_parameters_unlisted(
             &pb_candidate_list,
             &counter_parameters,
+            &coordinator_key_pair.1,
+            1,
+            max_candidate_number as u32,
         )
         .unwrap();
         pub struct VoterSecretPair {
@@ -585,6 +643,7 @@ mod tests {
             &voter1_secret_pair.zero_sercret,
             &voter1_secret_pair.weight_secret,
             &poll_parameters,
+            &coordinator_key_pair.0,
         )
         .unwrap();
 
@@ -593,6 +652,7 @@ mod tests {
             &voter2_secret_pair.zero_sercret,
             &voter2_secret_pair.weight_secret,
             &poll_parameters,
+            &coordinator_key_pair.0,
         )
         .unwrap();
 
@@ -601,11 +661,12 @@ mod tests {
             &voter3_secret_pair.zero_sercret,
             &voter3_secret_pair.weight_secret,
             &poll_parameters,
+            &coordinator_key_pair.0,
         )
         .unwrap();
 
-        let coordinator_key_pair = SIGNATURE.generate_keypair();
         let response1 = coordinator::certify_unbounded_voter(
+            &poll_parameters,
             &coordinator_key_pair.1,
             &registration_request1,
             blank_ballot_count[0],
@@ -613,6 +674,7 @@ mod tests {
         .unwrap();
 
         let response2 = coordinator::certify_unbounded_voter(
+            &poll_parameters,
             &coordinator_key_pair.1,
             &registration_request2,
             blank_ballot_count[1],
@@ -620,6 +682,7 @@ mod tests {
         .unwrap();
 
         let response3 = coordinator::certify_unbounded_voter(
+            &poll_parameters,
             &coordinator_key_pair.1,
             &registration_request3,
             blank_ballot_count[2],
@@ -671,6 +734,7 @@ mod tests {
             &choice1,
             &response1,
             &poll_parameters,
+            &coordinator_key_pair.0,
         )
         .unwrap();
         // verify
@@ -711,6 +775,7 @@ mod tests {
             &choice2,
             &response2,
             &poll_parameters,
+            &coordinator_key_pair.0,
         )
         .unwrap();
         assert_eq!(
@@ -749,6 +814,7 @@ mod tests {
             &choice3,
             &response3,
             &poll_parameters,
+            &coordinator_key_pair.0,
         )
         .unwrap();
         wedpr_println!("##### aggregate_vote_sum_response_unlisted request3");
@@ -795,7 +861,8 @@ mod tests {
                 &bytes_to_point(&counter_share1.get_poll_point_share())
                     .unwrap(),
                 &encrypted_vote_sum,
-                &decrypt_request1
+                &decrypt_request1,
+                &coordinator_key_pair.0
             )
             .unwrap()
         );
@@ -834,7 +901,8 @@ mod tests {
                 &bytes_to_point(&counter_share2.get_poll_point_share())
                     .unwrap(),
                 &encrypted_vote_sum,
-                &decrypt_request2
+                &decrypt_request2,
+                &coordinator_key_pair.0
             )
             .unwrap()
         );
@@ -873,7 +941,8 @@ mod tests {
                 &bytes_to_point(&counter_share3.get_poll_point_share())
                     .unwrap(),
                 &encrypted_vote_sum,
-                &decrypt_request3
+                &decrypt_request3,
+                &coordinator_key_pair.0
             )
             .unwrap()
         );
@@ -912,12 +981,33 @@ mod tests {
             "final result unlisted is : {:?}",
             final_result_request_unlisted
         );
+        // An unlisted ballot whose value exceeds the vote limit can not be
+        // decrypted, instead of being skipped silently.
+        let mut unlisted_candidate_part =
+            vote_sum_total.get_unlisted_candidate_part()[0].clone();
+        assert_eq!(
+            coordinator::decrypt_unlisted_candidate_ballot(
+                &mut std::collections::BTreeMap::new(),
+                &mut unlisted_candidate_part,
+                &encrypted_vote_sum,
+                5,
+                max_candidate_number,
+            ),
+            Err(WedprError::DecodeError)
+        );
 
         let result = verifier::verify_vote_result(
             &poll_parameters,
             &encrypted_vote_sum,
+            encrypted_vote_sum.get_vote_root(),
+            &[
+                vote_request1.get_vote().clone(),
+                vote_request2.get_vote().clone(),
+                vote_request3.get_vote().clone(),
+            ],
             &vote_sum_total,
             &final_result_request_unlisted,
+            &coordinator_key_pair.0,
         )
         .unwrap();
         assert!(result);
@@ -955,4 +1045,3051 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_double_voting_with_nullifier() {
+        let test_poll = TestPoll::new(&["1001"], &["Kitten", "Doge"]);
+
+        let (vote_secret, registration_response) = test_poll.register_voter(10);
+
+        // The same registration produces the same nullifier for every vote.
+        let mut encrypted_vote_sum = VoteStorage::new();
+        let mut vote_request_list = vec![];
+        for choice in [vec![1, 2], vec![3, 4]] {
+            let vote_choices =
+                voter::make_vote_choices(&choice, &test_poll.candidate_list);
+            let vote_request = voter::vote(
+                &vote_secret,
+                &vote_choices,
+                &registration_response,
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            )
+            .unwrap();
+            assert!(verifier::verify_vote_request(
+                &test_poll.poll_parameters,
+                &vote_request,
+                &test_poll.public_key
+            )
+            .unwrap());
+            vote_request_list.push(vote_request);
+        }
+        assert_eq!(
+            vote_request_list[0].get_vote().get_nullifier(),
+            vote_request_list[1].get_vote().get_nullifier()
+        );
+        assert!(coordinator::aggregate_vote_sum_response(
+            &test_poll.poll_parameters,
+            vote_request_list[0].get_vote(),
+            &mut encrypted_vote_sum
+        )
+        .unwrap());
+        let vote_sum_snapshot = encrypted_vote_sum.clone();
+        assert!(!coordinator::aggregate_vote_sum_response(
+            &test_poll.poll_parameters,
+            vote_request_list[1].get_vote(),
+            &mut encrypted_vote_sum
+        )
+        .unwrap());
+        assert_eq!(encrypted_vote_sum, vote_sum_snapshot);
+
+        // A nullifier not derived from the voter secret is rejected.
+        let mut forged_vote_request = vote_request_list[1].clone();
+        let (forged_nullifier, forged_nullifier_proof) =
+            voter::generate_nullifier(
+                &voter::make_voter_secret(),
+                &test_poll.poll_parameters,
+            )
+            .unwrap();
+        forged_vote_request
+            .mut_vote()
+            .set_nullifier(forged_nullifier);
+        forged_vote_request.set_nullifier_proof(forged_nullifier_proof);
+        assert!(!verifier::verify_nullifier(
+            &test_poll.poll_parameters,
+            &forged_vote_request
+        )
+        .unwrap());
+
+        // Nullifiers of the same voter secret differ across polls.
+        let another_counter_secret = counter::make_counter_secret();
+        let mut another_counter_parameters = CounterParametersStorage::new();
+        another_counter_parameters
+            .mut_counter_parameters_share()
+            .push(
+                counter::make_parameters_share("1001", &another_counter_secret)
+                    .unwrap(),
+            );
+        let another_poll_parameters = coordinator::make_poll_parameters(
+            &test_poll.candidate_list,
+            &another_counter_parameters,
+            &test_poll.secret_key,
+        )
+        .unwrap();
+        let (another_nullifier, _) =
+            voter::generate_nullifier(&vote_secret, &another_poll_parameters)
+                .unwrap();
+        assert_ne!(
+            another_nullifier,
+            vote_request_list[0].get_vote().get_nullifier()
+        );
+    }
+
+    #[test]
+    fn test_revoting() {
+        let test_poll = TestPoll::new(&["1001"], &["Kitten", "Doge"]);
+
+        let (vote_secret, registration_response) = test_poll.register_voter(10);
+
+        // The voter votes twice, the second vote has a larger sequence.
+        let mut vote_request_list = vec![];
+        for (sequence, choice) in [(0, vec![1, 2]), (1, vec![3, 4])] {
+            let mut vote_choices =
+                voter::make_vote_choices(&choice, &test_poll.candidate_list);
+            vote_choices.set_sequence(sequence);
+            let vote_request = voter::vote(
+                &vote_secret,
+                &vote_choices,
+                &registration_response,
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            )
+            .unwrap();
+            assert!(verifier::verify_vote_request(
+                &test_poll.poll_parameters,
+                &vote_request,
+                &test_poll.public_key
+            )
+            .unwrap());
+            vote_request_list.push(vote_request);
+        }
+        let mut encrypted_vote_sum = VoteStorage::new();
+        assert!(coordinator::aggregate_vote_sum_response(
+            &test_poll.poll_parameters,
+            vote_request_list[0].get_vote(),
+            &mut encrypted_vote_sum
+        )
+        .unwrap());
+        // A vote with a smaller sequence cannot replace a newer vote.
+        assert!(!coordinator::replace_vote_sum_response(
+            &test_poll.poll_parameters,
+            vote_request_list[1].get_vote(),
+            vote_request_list[0].get_vote(),
+            &mut encrypted_vote_sum
+        )
+        .unwrap());
+        assert!(coordinator::replace_vote_sum_response(
+            &test_poll.poll_parameters,
+            vote_request_list[0].get_vote(),
+            vote_request_list[1].get_vote(),
+            &mut encrypted_vote_sum
+        )
+        .unwrap());
+        // The replaced vote can no longer be replaced.
+        let vote_sum_snapshot = encrypted_vote_sum.clone();
+        assert!(!coordinator::replace_vote_sum_response(
+            &test_poll.poll_parameters,
+            vote_request_list[0].get_vote(),
+            vote_request_list[1].get_vote(),
+            &mut encrypted_vote_sum
+        )
+        .unwrap());
+        assert_eq!(encrypted_vote_sum, vote_sum_snapshot);
+        assert_eq!(encrypted_vote_sum.get_audit_record().len(), 2);
+        assert_eq!(
+            encrypted_vote_sum.get_audit_record()[1].get_replaced_vote_hash(),
+            encrypted_vote_sum.get_audit_record()[0].get_vote_hash()
+        );
+
+        // Only the last vote is counted.
+        let aggregated_decrypted_result = test_poll.count(&encrypted_vote_sum);
+        let vote_result = coordinator::finalize_vote_result(
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            &aggregated_decrypted_result,
+            20,
+        )
+        .unwrap();
+        for candidate_result in vote_result.get_result() {
+            let expected_value = match candidate_result.get_key() {
+                "Kitten" => 3,
+                "Doge" => 4,
+                POLL_RESULT_KEY_ABSTAINED_BALLOTS => 3,
+                _ => 10,
+            };
+            assert_eq!(candidate_result.get_value(), expected_value);
+        }
+    }
+
+    // Makes the transcript of a poll with two counters in the given mode, and
+    // returns it with the poll it records.
+    fn make_poll_transcript(
+        poll_mode: PollMode,
+        candidates: &[&str],
+    ) -> (PollTranscript, TestPoll) {
+        let test_poll = if poll_mode == PollMode::UNLISTED {
+            TestPoll::new_unlisted(&["1001", "1002"], candidates, 1, 10)
+        } else {
+            TestPoll::new(&["1001", "1002"], candidates)
+        };
+        let mut transcript = PollTranscript::new();
+        transcript.set_poll_mode(poll_mode);
+        transcript.set_counter_parameters(test_poll.counter_parameters.clone());
+        transcript.set_poll_parameters(test_poll.poll_parameters.clone());
+        transcript.set_coordinator_public_key(test_poll.public_key.clone());
+        (transcript, test_poll)
+    }
+
+    // Registers a voter of the given weight in a transcript, and returns the
+    // voter secret.
+    fn register_transcript_voter(
+        transcript: &mut PollTranscript,
+        test_poll: &TestPoll,
+        voter_weight: u32,
+    ) -> VoterSecret {
+        let (vote_secret, registration_response) =
+            test_poll.register_voter(voter_weight);
+        transcript.mut_registration().push(registration_response);
+        vote_secret
+    }
+
+    // Aggregates the votes of a transcript in order, where a later vote of the
+    // same voter replaces its previous vote.
+    fn aggregate_transcript_votes(transcript: &PollTranscript) -> VoteStorage {
+        let poll_parameters = transcript.get_poll_parameters();
+        let mut encrypted_vote_sum = VoteStorage::new();
+        let mut vote_list: Vec<&VoteStorage> = vec![];
+        for vote_request in transcript.get_vote_request() {
+            let vote = vote_request.get_vote();
+            match vote_list
+                .iter()
+                .position(|v| v.get_nullifier() == vote.get_nullifier())
+            {
+                Some(index) => {
+                    assert!(coordinator::replace_vote_sum_response_unlisted(
+                        poll_parameters,
+                        vote_list[index],
+                        vote,
+                        &mut encrypted_vote_sum
+                    )
+                    .unwrap());
+                    vote_list[index] = vote;
+                },
+                None => {
+                    assert!(coordinator::aggregate_vote_sum_response_unlisted(
+                        poll_parameters,
+                        vote,
+                        &mut encrypted_vote_sum
+                    )
+                    .unwrap());
+                    vote_list.push(vote);
+                },
+            }
+        }
+        encrypted_vote_sum
+    }
+
+    // Counts a vote sum by all counters of a transcript, and records the
+    // counting parts and the vote result in the transcript.
+    fn count_poll_transcript(
+        transcript: &mut PollTranscript,
+        test_poll: &TestPoll,
+        encrypted_vote_sum: &VoteStorage,
+        max_vote_limit: i64,
+    ) {
+        let poll_parameters = &test_poll.poll_parameters;
+        let unlisted = transcript.get_poll_mode() == PollMode::UNLISTED;
+        let mut aggregated_decrypted_result = DecryptedResultPartStorage::new();
+        for (counter_id, counter_secret) in test_poll.counters() {
+            let partially_decrypted_result = if unlisted {
+                let partially_decrypted_result = counter::count_unlisted(
+                    counter_id,
+                    counter_secret,
+                    encrypted_vote_sum,
+                )
+                .unwrap();
+                assert!(coordinator::aggregate_decrypted_part_sum_unlisted(
+                    poll_parameters,
+                    &partially_decrypted_result,
+                    &mut aggregated_decrypted_result
+                )
+                .unwrap());
+                partially_decrypted_result
+            } else {
+                let partially_decrypted_result = counter::count(
+                    counter_id,
+                    counter_secret,
+                    encrypted_vote_sum,
+                )
+                .unwrap();
+                assert!(coordinator::aggregate_decrypted_part_sum(
+                    poll_parameters,
+                    counter_id,
+                    encrypted_vote_sum,
+                    &partially_decrypted_result,
+                    &mut aggregated_decrypted_result
+                )
+                .unwrap()
+                .is_none());
+                partially_decrypted_result
+            };
+            transcript
+                .mut_decrypted_part()
+                .push(partially_decrypted_result);
+        }
+        let vote_result = if unlisted {
+            coordinator::finalize_vote_result_unlisted(
+                poll_parameters,
+                encrypted_vote_sum,
+                &mut aggregated_decrypted_result,
+                max_vote_limit,
+                10,
+            )
+        } else {
+            coordinator::finalize_vote_result(
+                poll_parameters,
+                encrypted_vote_sum,
+                &aggregated_decrypted_result,
+                max_vote_limit,
+            )
+        }
+        .unwrap();
+        transcript.set_vote_result(vote_result);
+    }
+
+    // Returns the results of a transcript other than the unlisted results.
+    fn get_transcript_result(transcript: &PollTranscript) -> Vec<(&str, i64)> {
+        transcript
+            .get_vote_result()
+            .get_result()
+            .iter()
+            .map(|pair| (pair.get_key(), pair.get_value()))
+            .collect()
+    }
+
+    #[test]
+    fn test_verify_poll_transcript() {
+        let (mut transcript, test_poll) =
+            make_poll_transcript(PollMode::BOUNDED, &["Kitten", "Doge"]);
+        let voter_secret_list: Vec<VoterSecret> = (0..3)
+            .map(|_| register_transcript_voter(&mut transcript, &test_poll, 10))
+            .collect();
+        let registration_list = transcript.get_registration().to_vec();
+
+        // The third voter delegates to the second voter, hidden among all
+        // registered voters.
+        let ring_ballot_list: Vec<CertifiedBallot> = registration_list
+            .iter()
+            .map(|registration_response| {
+                let mut member = CertifiedBallot::new();
+                member.set_ballot(registration_response.get_ballot().clone());
+                member.set_signature(
+                    registration_response.get_signature().to_vec(),
+                );
+                member
+            })
+            .collect();
+        let delegation = voter::make_delegation(
+            &voter_secret_list[2],
+            &registration_list[2],
+            registration_list[1].get_ballot(),
+            &ring_ballot_list,
+            &test_poll.poll_parameters,
+            &test_poll.public_key,
+        )
+        .unwrap();
+
+        // Another voter is certified blindly, so that its vote cannot be linked
+        // to a registration.
+        let (blind_public_key, blind_secret_key) =
+            coordinator::make_blind_certification_keypair();
+        transcript.set_blind_certification_public_key(blind_public_key.clone());
+        let certifier = coordinator::BlindCertifier::new(&blind_secret_key);
+        let anonymous_secret = voter::make_voter_secret();
+        let commitment = certifier
+            .begin_blind_certification(&test_poll.poll_parameters, 10)
+            .unwrap();
+        let (blinding_secret, request) =
+            voter::make_blind_certification_request(
+                &anonymous_secret,
+                &test_poll.poll_parameters,
+                &blind_public_key,
+                &commitment,
+                &test_poll.public_key,
+            )
+            .unwrap();
+        let anonymous_registration = voter::unblind_certificate(
+            &anonymous_secret,
+            &test_poll.poll_parameters,
+            &blind_public_key,
+            &commitment,
+            &blinding_secret,
+            &certifier.certify_voter_blindly(&request).unwrap(),
+            &test_poll.public_key,
+        )
+        .unwrap();
+
+        // The first voter re-votes, only its last vote counts.
+        //            Kitten  Doge
+        // voter1:       1      2   (replaced)
+        // voter2:       5     15   (with the weight of voter3)
+        // anonymous:    2      3
+        // voter1:       3      4
+        let make_choices = |choice: Vec<u32>, sequence: u32| {
+            let mut vote_choices =
+                voter::make_vote_choices(&choice, &test_poll.candidate_list);
+            vote_choices.set_sequence(sequence);
+            vote_choices
+        };
+        let vote_request_list = [
+            voter::vote(
+                &voter_secret_list[0],
+                &make_choices(vec![1, 2], 0),
+                &registration_list[0],
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            ),
+            voter::vote_with_delegations(
+                &voter_secret_list[1],
+                &make_choices(vec![5, 15], 0),
+                &registration_list[1],
+                &[delegation],
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            ),
+            voter::vote(
+                &anonymous_secret,
+                &make_choices(vec![2, 3], 0),
+                &anonymous_registration,
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            ),
+            voter::vote(
+                &voter_secret_list[0],
+                &make_choices(vec![3, 4], 1),
+                &registration_list[0],
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            ),
+        ];
+        for vote_request in vote_request_list {
+            transcript.mut_vote_request().push(vote_request.unwrap());
+        }
+        let encrypted_vote_sum = aggregate_transcript_votes(&transcript);
+        count_poll_transcript(
+            &mut transcript,
+            &test_poll,
+            &encrypted_vote_sum,
+            40,
+        );
+        assert_eq!(get_transcript_result(&transcript), vec![
+            (POLL_RESULT_KEY_TOTAL_BALLOTS, 40),
+            ("Kitten", 10),
+            ("Doge", 22),
+            (POLL_RESULT_KEY_ABSTAINED_BALLOTS, 8),
+        ]);
+        assert!(verifier::verify_poll_transcript(&transcript).unwrap());
+
+        // A tampered result is rejected.
+        let mut tampered_transcript = transcript.clone();
+        for candidate_result in tampered_transcript
+            .mut_vote_result()
+            .mut_result()
+            .iter_mut()
+        {
+            if candidate_result.get_key() == "Kitten" {
+                candidate_result.set_value(candidate_result.get_value() + 1);
+            }
+        }
+        assert!(
+            !verifier::verify_poll_transcript(&tampered_transcript).unwrap()
+        );
+
+        // A missing counting part is rejected.
+        let mut tampered_transcript = transcript.clone();
+        tampered_transcript.mut_decrypted_part().pop();
+        assert!(
+            !verifier::verify_poll_transcript(&tampered_transcript).unwrap()
+        );
+
+        // A replayed vote is rejected.
+        let mut tampered_transcript = transcript.clone();
+        let replayed_vote_request = transcript.get_vote_request()[1].clone();
+        tampered_transcript
+            .mut_vote_request()
+            .push(replayed_vote_request);
+        assert!(
+            !verifier::verify_poll_transcript(&tampered_transcript).unwrap()
+        );
+
+        // A vote from an unknown registration is rejected, and so is a
+        // delegation from an unknown registration.
+        for index in [0, 2] {
+            let mut tampered_transcript = transcript.clone();
+            tampered_transcript.mut_registration().remove(index);
+            assert!(!verifier::verify_poll_transcript(&tampered_transcript)
+                .unwrap());
+        }
+
+        // A vote certified blindly by another key is rejected.
+        let mut tampered_transcript = transcript.clone();
+        tampered_transcript.set_blind_certification_public_key(
+            coordinator::make_blind_certification_keypair().0,
+        );
+        assert!(
+            !verifier::verify_poll_transcript(&tampered_transcript).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_verify_quadratic_poll_transcript() {
+        let (mut transcript, test_poll) =
+            make_poll_transcript(PollMode::QUADRATIC, &["Kitten", "Doge"]);
+        // Each voter has 30 credits, and voting w votes costs w * w credits.
+        for choice in [vec![5, 2], vec![3, 3]] {
+            let vote_secret =
+                register_transcript_voter(&mut transcript, &test_poll, 30);
+            let vote_request = voter::vote_quadratic(
+                &vote_secret,
+                &voter::make_vote_choices(&choice, &test_poll.candidate_list),
+                transcript.get_registration().last().unwrap(),
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            )
+            .unwrap();
+            transcript.mut_vote_request().push(vote_request);
+        }
+        let encrypted_vote_sum = aggregate_transcript_votes(&transcript);
+        count_poll_transcript(
+            &mut transcript,
+            &test_poll,
+            &encrypted_vote_sum,
+            60,
+        );
+        assert_eq!(get_transcript_result(&transcript), vec![
+            (POLL_RESULT_KEY_TOTAL_BALLOTS, 60),
+            ("Kitten", 8),
+            ("Doge", 5),
+        ]);
+        assert!(verifier::verify_poll_transcript(&transcript).unwrap());
+
+        // The quadratic votes are not valid in a bounded poll.
+        let mut tampered_transcript = transcript;
+        tampered_transcript.set_poll_mode(PollMode::BOUNDED);
+        assert!(
+            !verifier::verify_poll_transcript(&tampered_transcript).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_verify_unbounded_poll_transcript() {
+        let (mut transcript, test_poll) =
+            make_poll_transcript(PollMode::UNBOUNDED, &[
+                "Alice", "Bob", "charlie",
+            ]);
+        for (voter_weight, choice) in
+            [(10, vec![10, 0, 10]), (20, vec![0, 20, 20])]
+        {
+            let (weight_secret, zero_secret, registration_response) =
+                test_poll.register_unbounded_voter(voter_weight);
+            let vote_request = voter::vote_unbounded(
+                &weight_secret,
+                &zero_secret,
+                &voter::make_vote_choices(&choice, &test_poll.candidate_list),
+                &registration_response,
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            )
+            .unwrap();
+            transcript.mut_registration().push(registration_response);
+            transcript.mut_vote_request().push(vote_request);
+        }
+        let encrypted_vote_sum = aggregate_transcript_votes(&transcript);
+        count_poll_transcript(
+            &mut transcript,
+            &test_poll,
+            &encrypted_vote_sum,
+            60,
+        );
+        assert_eq!(get_transcript_result(&transcript), vec![
+            (POLL_RESULT_KEY_TOTAL_BALLOTS, 30),
+            ("Alice", 10),
+            ("Bob", 20),
+            ("charlie", 30),
+        ]);
+        assert!(verifier::verify_poll_transcript(&transcript).unwrap());
+
+        // The unbounded votes are not valid in a bounded poll.
+        let mut tampered_transcript = transcript;
+        tampered_transcript.set_poll_mode(PollMode::BOUNDED);
+        assert!(
+            !verifier::verify_poll_transcript(&tampered_transcript).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_verify_unlisted_poll_transcript() {
+        let (mut transcript, test_poll) =
+            make_poll_transcript(PollMode::UNLISTED, &["Alice", "Bob"]);
+        //          Alice  Bob  unlisted 1  unlisted 2
+        // voter1:    10    0       10          0
+        // voter2:     0   20        -         20
+        for (voter_weight, choice, unlisted_choice) in [
+            (10, vec![10, 0], vec![(1, 10), (2, 0)]),
+            (20, vec![0, 20], vec![(2, 20)]),
+        ] {
+            let (weight_secret, zero_secret, registration_response) =
+                test_poll.register_unbounded_voter(voter_weight);
+            let mut vote_choices =
+                voter::make_vote_choices(&choice, &test_poll.candidate_list);
+            for (candidate_id, value) in unlisted_choice {
+                let mut choice = UnlistedVoteChoice::new();
+                choice.set_candidate_id(candidate_id);
+                choice.set_value(value);
+                vote_choices.mut_unlisted_choice().push(choice);
+            }
+            let vote_request = voter::vote_unbounded_unlisted(
+                &weight_secret,
+                &zero_secret,
+                &vote_choices,
+                &registration_response,
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            )
+            .unwrap();
+            transcript.mut_registration().push(registration_response);
+            transcript.mut_vote_request().push(vote_request);
+        }
+        let encrypted_vote_sum = aggregate_transcript_votes(&transcript);
+        count_poll_transcript(
+            &mut transcript,
+            &test_poll,
+            &encrypted_vote_sum,
+            60,
+        );
+        let unlisted_result: Vec<(u32, u32)> = transcript
+            .get_vote_result()
+            .get_unlisted_result()
+            .iter()
+            .map(|choice| (choice.get_candidate_id(), choice.get_value()))
+            .collect();
+        assert_eq!(unlisted_result, vec![(1, 10), (2, 20)]);
+        assert!(verifier::verify_poll_transcript(&transcript).unwrap());
+
+        // A tampered unlisted result is rejected, and so is an unlisted result
+        // missing a candidate.
+        let mut tampered_transcript = transcript.clone();
+        tampered_transcript.mut_vote_result().mut_unlisted_result()[1]
+            .set_value(10);
+        assert!(
+            !verifier::verify_poll_transcript(&tampered_transcript).unwrap()
+        );
+        let mut tampered_transcript = transcript.clone();
+        tampered_transcript
+            .mut_vote_result()
+            .mut_unlisted_result()
+            .remove(0);
+        assert!(
+            !verifier::verify_poll_transcript(&tampered_transcript).unwrap()
+        );
+
+        // An unlisted ballot without a valid counting part is rejected.
+        let mut tampered_transcript = transcript;
+        tampered_transcript.mut_decrypted_part()[0]
+            .mut_unlisted_candidate_part()
+            .swap(0, 1);
+        assert!(
+            !verifier::verify_poll_transcript(&tampered_transcript).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_verify_ranked_poll_transcript() {
+        let (mut transcript, test_poll) =
+            make_poll_transcript(PollMode::BOUNDED, &["Kitten", "Doge"]);
+        for choice in [vec![1, 3], vec![2, 2]] {
+            let vote_secret =
+                register_transcript_voter(&mut transcript, &test_poll, 5);
+            let vote_request = voter::vote(
+                &vote_secret,
+                &voter::make_vote_choices(&choice, &test_poll.candidate_list),
+                transcript.get_registration().last().unwrap(),
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            )
+            .unwrap();
+            transcript.mut_vote_request().push(vote_request);
+        }
+        let encrypted_vote_sum = aggregate_transcript_votes(&transcript);
+
+        // Only the winner is published instead of the vote result.
+        let mut comparison = coordinator::make_ranking_comparison(
+            &test_poll.poll_parameters,
+            "Doge",
+            "Kitten",
+            10,
+        )
+        .unwrap();
+        for counter_id in &test_poll.counter_id_list {
+            let mix_part = counter::mix_ranking_comparison(
+                counter_id,
+                &test_poll.poll_parameters,
+                &encrypted_vote_sum,
+                &comparison,
+            )
+            .unwrap();
+            comparison.mut_mix_part().push(mix_part);
+        }
+        for (counter_id, counter_secret) in test_poll.counters() {
+            let decryption_part = counter::decrypt_ranking_comparison(
+                counter_id,
+                counter_secret,
+                &comparison,
+            )
+            .unwrap();
+            comparison.mut_decryption_part().push(decryption_part);
+        }
+        let vote_ranking = coordinator::make_vote_ranking(
+            &test_poll.poll_parameters,
+            &["Doge".to_string()],
+            &[comparison],
+        )
+        .unwrap();
+        transcript.set_vote_ranking(vote_ranking);
+        assert!(verifier::verify_poll_transcript(&transcript).unwrap());
+
+        // A ranking with another winner is rejected.
+        let mut tampered_transcript = transcript.clone();
+        tampered_transcript
+            .mut_vote_ranking()
+            .mut_ranked_candidate()[0] = "Kitten".to_string();
+        assert!(
+            !verifier::verify_poll_transcript(&tampered_transcript).unwrap()
+        );
+
+        // A ranking is not published together with the vote result.
+        let mut tampered_transcript = transcript.clone();
+        count_poll_transcript(
+            &mut tampered_transcript,
+            &test_poll,
+            &encrypted_vote_sum,
+            10,
+        );
+        assert!(
+            !verifier::verify_poll_transcript(&tampered_transcript).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_verify_coercion_resistant_poll_transcript() {
+        let (mut transcript, test_poll) =
+            make_poll_transcript(PollMode::COERCION_RESISTANT, &[
+                "Kitten", "Doge",
+            ]);
+        let mut voter_list = vec![];
+        for _ in 0..2 {
+            let vote_secret =
+                register_transcript_voter(&mut transcript, &test_poll, 10);
+            let (credential, roster_entry) =
+                coordinator::issue_voter_credential(
+                    &test_poll.poll_parameters,
+                    transcript.get_registration().last().unwrap(),
+                )
+                .unwrap();
+            transcript
+                .mut_credential_filter()
+                .mut_roster()
+                .mut_entry()
+                .push(roster_entry);
+            voter_list.push((vote_secret, credential));
+        }
+
+        // The first voter is coerced and hands over a fake credential, then
+        // votes again with the real credential.
+        let fake_credential = voter::make_fake_credential();
+        for (voter_index, use_fake_credential, choice, sequence) in [
+            (0, true, vec![10, 0], u32::MAX),
+            (0, false, vec![0, 10], 1),
+            (1, false, vec![3, 4], 0),
+        ] {
+            let (vote_secret, credential) = &voter_list[voter_index];
+            let mut vote_choices =
+                voter::make_vote_choices(&choice, &test_poll.candidate_list);
+            vote_choices.set_sequence(sequence);
+            let vote_request = voter::vote_with_credential(
+                vote_secret,
+                if use_fake_credential {
+                    &fake_credential
+                } else {
+                    credential
+                },
+                &vote_choices,
+                &transcript.get_registration()[voter_index],
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            )
+            .unwrap();
+            transcript.mut_vote_request().push(vote_request);
+        }
+
+        // The counters remove the votes with duplicate credentials, mix the
+        // votes and the roster, and filter the mixed votes by the roster.
+        let decrypt_differences =
+            |blinding_parts: &[CredentialBlindingPart]| {
+                let blinded_difference_sum =
+                    coordinator::aggregate_credential_blinding_parts(
+                        blinding_parts,
+                    )
+                    .unwrap();
+                let decryption_parts: Vec<_> = test_poll
+                    .counters()
+                    .map(|(counter_id, counter_secret)| {
+                        counter::decrypt_credential_differences(
+                            counter_id,
+                            counter_secret,
+                            &blinded_difference_sum,
+                        )
+                        .unwrap()
+                    })
+                    .collect();
+                (blinded_difference_sum, decryption_parts)
+            };
+        let ballot_box: Vec<VoteStorage> = transcript
+            .get_vote_request()
+            .iter()
+            .map(|vote_request| vote_request.get_vote().clone())
+            .collect();
+        let blinding_parts: Vec<_> = test_poll
+            .counter_id_list
+            .iter()
+            .map(|counter_id| {
+                counter::blind_credential_duplicates(counter_id, &ballot_box)
+                    .unwrap()
+            })
+            .collect();
+        let (blinded_difference_sum, decryption_parts) =
+            decrypt_differences(&blinding_parts);
+        let deduplicated_votes = coordinator::remove_credential_duplicates(
+            &ballot_box,
+            &blinded_difference_sum,
+            &decryption_parts,
+        )
+        .unwrap();
+        let mut credential_filter = transcript.get_credential_filter().clone();
+        credential_filter.set_duplicate_blinding_part(blinding_parts.into());
+        credential_filter
+            .set_duplicate_decryption_part(decryption_parts.into());
+        let mut mix_parts = vec![];
+        for counter_id in &test_poll.counter_id_list {
+            let mix_part = counter::mix_credential_rows(
+                counter_id,
+                &test_poll.poll_parameters,
+                credential_filter.get_roster(),
+                &deduplicated_votes,
+                &mix_parts,
+            )
+            .unwrap();
+            mix_parts.push(mix_part);
+        }
+        let blinding_parts: Vec<_> = test_poll
+            .counter_id_list
+            .iter()
+            .map(|counter_id| {
+                counter::blind_credential_matches(counter_id, &mix_parts)
+                    .unwrap()
+            })
+            .collect();
+        let (blinded_difference_sum, decryption_parts) =
+            decrypt_differences(&blinding_parts);
+        let filtered_vote_sum = coordinator::filter_votes_by_credential(
+            &test_poll.poll_parameters,
+            &mix_parts,
+            &blinded_difference_sum,
+            &decryption_parts,
+        )
+        .unwrap();
+        credential_filter.set_mix_part(mix_parts.into());
+        credential_filter.set_match_blinding_part(blinding_parts.into());
+        credential_filter.set_match_decryption_part(decryption_parts.into());
+        transcript.set_credential_filter(credential_filter);
+        assert_eq!(
+            test_poll.counter_parameters,
+            *transcript.get_counter_parameters()
+        );
+
+        // Only the votes with a valid credential are counted.
+        count_poll_transcript(
+            &mut transcript,
+            &test_poll,
+            &filtered_vote_sum,
+            20,
+        );
+        assert_eq!(get_transcript_result(&transcript), vec![
+            (POLL_RESULT_KEY_TOTAL_BALLOTS, 20),
+            ("Kitten", 3),
+            ("Doge", 14),
+            (POLL_RESULT_KEY_ABSTAINED_BALLOTS, 3),
+        ]);
+        assert!(verifier::verify_poll_transcript(&transcript).unwrap());
+
+        // A missing mix part is rejected.
+        let mut tampered_transcript = transcript.clone();
+        tampered_transcript
+            .mut_credential_filter()
+            .mut_mix_part()
+            .pop();
+        assert!(
+            !verifier::verify_poll_transcript(&tampered_transcript).unwrap()
+        );
+
+        // A roster entry counted twice is rejected.
+        let mut tampered_transcript = transcript.clone();
+        let roster_entry =
+            transcript.get_credential_filter().get_roster().get_entry()[0]
+                .clone();
+        tampered_transcript
+            .mut_credential_filter()
+            .mut_roster()
+            .mut_entry()
+            .push(roster_entry);
+        assert!(
+            !verifier::verify_poll_transcript(&tampered_transcript).unwrap()
+        );
+
+        // The votes are not counted without filtering them by the roster.
+        let mut tampered_transcript = transcript;
+        tampered_transcript.set_poll_mode(PollMode::BOUNDED);
+        assert!(
+            !verifier::verify_poll_transcript(&tampered_transcript).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_verify_vote_requests_in_batch() {
+        let test_poll = TestPoll::new(&["1001"], &["Kitten", "Doge", "Bunny"]);
+
+        // The third and the fifth voters vote with credentials.
+        let mut vote_request_list = vec![];
+        for (voter_weight, with_credential) in [
+            (10, false),
+            (20, false),
+            (30, true),
+            (40, false),
+            (50, true),
+        ] {
+            let (vote_secret, registration_response) =
+                test_poll.register_voter(voter_weight);
+            let vote_choices = voter::make_vote_choices(
+                &vec![1, 2, 3],
+                &test_poll.candidate_list,
+            );
+            let vote_request = if with_credential {
+                let (credential, _) = coordinator::issue_voter_credential(
+                    &test_poll.poll_parameters,
+                    &registration_response,
+                )
+                .unwrap();
+                voter::vote_with_credential(
+                    &vote_secret,
+                    &credential,
+                    &vote_choices,
+                    &registration_response,
+                    &test_poll.poll_parameters,
+                    &test_poll.public_key,
+                )
+            } else {
+                voter::vote(
+                    &vote_secret,
+                    &vote_choices,
+                    &registration_response,
+                    &test_poll.poll_parameters,
+                    &test_poll.public_key,
+                )
+            };
+            vote_request_list.push(vote_request.unwrap());
+        }
+        assert!(verifier::verify_vote_requests_in_batch(
+            &test_poll.poll_parameters,
+            &vote_request_list,
+            &test_poll.public_key
+        )
+        .unwrap()
+        .is_empty());
+
+        // Tamper a range proof, a credential proof and a balance proof.
+        let another_range_proof =
+            vote_request_list[0].get_range_proof().to_vec();
+        vote_request_list[1].set_range_proof(another_range_proof);
+        vote_request_list[2]
+            .mut_credential_proof()
+            .set_response_credential(scalar_to_bytes(&get_random_scalar()));
+        assert!(!verifier::verify_vote_request(
+            &test_poll.poll_parameters,
+            &vote_request_list[2],
+            &test_poll.public_key
+        )
+        .unwrap_or(false));
+        let another_balance_proof =
+            vote_request_list[0].get_sum_balance_proof().to_vec();
+        vote_request_list[3].set_sum_balance_proof(another_balance_proof);
+        assert_eq!(
+            verifier::verify_vote_requests_in_batch(
+                &test_poll.poll_parameters,
+                &vote_request_list,
+                &test_poll.public_key
+            )
+            .unwrap(),
+            vec![1, 2, 3]
+        );
+        assert!(verifier::verify_vote_requests_in_batch(
+            &test_poll.poll_parameters,
+            &[],
+            &test_poll.public_key
+        )
+        .unwrap()
+        .is_empty());
+    }
+
+    #[test]
+    fn test_unlisted_grouping() {
+        let test_poll =
+            TestPoll::new_unlisted(&["1001", "1002"], &["Kitten"], 1, 10);
+        // The range of unlisted candidate ids must start from 1, be
+        // decryptable and not contain the id of a listed candidate.
+        let mut numbered_candidate_list = test_poll.candidate_list.clone();
+        numbered_candidate_list
+            .mut_candidate()
+            .push("8".to_string());
+        for (candidates, min_candidate_id, max_candidate_id) in [
+            (&test_poll.candidate_list, 0, 10),
+            (&test_poll.candidate_list, 1, MAX_UNLISTED_CANDIDATE_ID + 1),
+            (&numbered_candidate_list, 1, 10),
+        ] {
+            assert!(coordinator::make_poll_parameters_unlisted(
+                candidates,
+                &test_poll.counter_parameters,
+                &test_poll.secret_key,
+                min_candidate_id,
+                max_candidate_id,
+            )
+            .is_err());
+        }
+        // A poll without the range accepts no unlisted candidate.
+        let listed_poll_parameters = coordinator::make_poll_parameters(
+            &test_poll.candidate_list,
+            &test_poll.counter_parameters,
+            &test_poll.secret_key,
+        )
+        .unwrap();
+        assert!(voter::generate_candidate_cipher_with_proof(
+            0,
+            &listed_poll_parameters,
+            &test_poll.public_key
+        )
+        .is_err());
+
+        // All voters vote for unlisted candidates.
+        //          Kitten  7     9
+        // voter1:     0   10     0
+        // voter2:     0   20    20
+        // voter3:    30    0    30
+        let voter_weight_list = [10, 20, 30];
+        let unlisted_choice_list =
+            [vec![(7, 10)], vec![(7, 20), (9, 20)], vec![(9, 30)]];
+        let listed_choice_list = [0, 0, 30];
+        let mut encrypted_vote_sum = VoteStorage::new();
+        for index in 0..voter_weight_list.len() {
+            let (weight_secret, zero_secret, registration_response) =
+                test_poll.register_unbounded_voter(voter_weight_list[index]);
+            let mut vote_choices = voter::make_vote_choices(
+                &vec![listed_choice_list[index]],
+                &test_poll.candidate_list,
+            );
+            for (candidate_id, value) in &unlisted_choice_list[index] {
+                let mut unlisted_choice = UnlistedVoteChoice::new();
+                unlisted_choice.set_candidate_id(*candidate_id);
+                unlisted_choice.set_value(*value);
+                vote_choices.mut_unlisted_choice().push(unlisted_choice);
+            }
+            let vote_request = voter::vote_unbounded_unlisted(
+                &weight_secret,
+                &zero_secret,
+                &vote_choices,
+                &registration_response,
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            )
+            .unwrap();
+            assert!(verifier::verify_unbounded_vote_request_unlisted(
+                &test_poll.poll_parameters,
+                &vote_request,
+                &test_poll.public_key
+            )
+            .unwrap());
+            assert!(coordinator::aggregate_vote_sum_response_unlisted(
+                &test_poll.poll_parameters,
+                vote_request.get_vote(),
+                &mut encrypted_vote_sum
+            )
+            .unwrap());
+        }
+
+        // Unlisted candidate ids out of the valid range are rejected.
+        assert!(voter::generate_candidate_cipher_with_proof(
+            0,
+            &test_poll.poll_parameters,
+            &test_poll.public_key
+        )
+        .is_err());
+        assert!(voter::generate_candidate_cipher_with_proof(
+            11,
+            &test_poll.poll_parameters,
+            &test_poll.public_key
+        )
+        .is_err());
+        let mut proof_pair = CipherPointsToBallotProofPair::new();
+        let (candidate_cipher, candidate_format_proof, _) =
+            voter::generate_candidate_cipher_with_proof(
+                7,
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            )
+            .unwrap();
+        let (_, _, another_candidate_range_proof) =
+            voter::generate_candidate_cipher_with_proof(
+                9,
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            )
+            .unwrap();
+        proof_pair.set_key(candidate_cipher);
+        proof_pair.set_candidate_format_proof(candidate_format_proof);
+        proof_pair.set_candidate_range_proof(another_candidate_range_proof);
+        assert!(!verifier::verify_unlisted_candidate_proof(
+            &test_poll.poll_parameters,
+            &proof_pair
+        )
+        .unwrap());
+
+        // Counters blind the unlisted candidate ciphers in turn, then decrypt
+        // the tags.
+        let mut grouping = UnlistedGroupingStorage::new();
+        for counter_id in &test_poll.counter_id_list {
+            let blinding_part = counter::blind_unlisted_candidates(
+                counter_id,
+                &encrypted_vote_sum,
+                &grouping,
+            )
+            .unwrap();
+            assert!(verifier::verify_unlisted_blinding_part(
+                &encrypted_vote_sum,
+                &grouping,
+                &blinding_part
+            )
+            .unwrap());
+            grouping.mut_blinding_part().push(blinding_part);
+        }
+        for index in 0..test_poll.counter_id_list.len() {
+            let tag_decrypted_part = counter::decrypt_unlisted_tags(
+                &test_poll.counter_id_list[index],
+                &test_poll.counter_secret_list[index],
+                &grouping,
+            )
+            .unwrap();
+            let counter_share = bytes_to_point(
+                test_poll.counter_parameters.get_counter_parameters_share()
+                    [index]
+                    .get_poll_point_share(),
+            )
+            .unwrap();
+            assert!(verifier::verify_unlisted_tag_part(
+                &counter_share,
+                &grouping,
+                &tag_decrypted_part
+            )
+            .unwrap());
+            grouping.mut_tag_decrypted_part().push(tag_decrypted_part);
+        }
+        let grouped_vote_sum =
+            coordinator::group_unlisted_ballots(&encrypted_vote_sum, &grouping)
+                .unwrap();
+        assert_eq!(grouped_vote_sum.get_voted_ballot_unlisted().len(), 2);
+        assert!(verifier::verify_unlisted_grouping(
+            &test_poll.counter_parameters,
+            &encrypted_vote_sum,
+            &grouping,
+            &grouped_vote_sum
+        )
+        .unwrap());
+        let mut tampered_vote_sum = grouped_vote_sum.clone();
+        tampered_vote_sum.mut_voted_ballot_unlisted().swap(0, 1);
+        assert!(!verifier::verify_unlisted_grouping(
+            &test_poll.counter_parameters,
+            &encrypted_vote_sum,
+            &grouping,
+            &tampered_vote_sum
+        )
+        .unwrap());
+
+        // Only the total ballots of each unlisted candidate are decrypted.
+        let mut aggregated_decrypted_result = DecryptedResultPartStorage::new();
+        for index in 0..test_poll.counter_id_list.len() {
+            let partially_decrypted_result = counter::count_unlisted(
+                &test_poll.counter_id_list[index],
+                &test_poll.counter_secret_list[index],
+                &grouped_vote_sum,
+            )
+            .unwrap();
+            let counter_share = bytes_to_point(
+                test_poll.counter_parameters.get_counter_parameters_share()
+                    [index]
+                    .get_poll_point_share(),
+            )
+            .unwrap();
+            assert!(verifier::verify_grouped_count_request_unlisted(
+                &test_poll.poll_parameters,
+                &counter_share,
+                &encrypted_vote_sum,
+                &grouping,
+                &grouped_vote_sum,
+                &partially_decrypted_result,
+                &test_poll.public_key
+            )
+            .unwrap());
+            assert!(!verifier::verify_grouped_count_request_unlisted(
+                &test_poll.poll_parameters,
+                &counter_share,
+                &encrypted_vote_sum,
+                &grouping,
+                &tampered_vote_sum,
+                &partially_decrypted_result,
+                &test_poll.public_key
+            )
+            .unwrap());
+            assert!(coordinator::aggregate_decrypted_part_sum_unlisted(
+                &test_poll.poll_parameters,
+                &partially_decrypted_result,
+                &mut aggregated_decrypted_result
+            )
+            .unwrap());
+        }
+        let vote_result = coordinator::finalize_vote_result_unlisted(
+            &test_poll.poll_parameters,
+            &grouped_vote_sum,
+            &mut aggregated_decrypted_result,
+            60,
+            10,
+        )
+        .unwrap();
+        let unlisted_result: Vec<(u32, u32)> = vote_result
+            .get_unlisted_result()
+            .iter()
+            .map(|result| (result.get_candidate_id(), result.get_value()))
+            .collect();
+        assert_eq!(unlisted_result, vec![(7, 30), (9, 50)]);
+    }
+
+    #[test]
+    fn test_blind_certification() {
+        let test_poll = TestPoll::new(&["1001"], &["Kitten", "Doge"]);
+        let (blind_public_key, blind_secret_key) =
+            coordinator::make_blind_certification_keypair();
+
+        // The coordinator certifies the voter without seeing the blank ballot,
+        // and opens only one session at a time.
+        let certifier = coordinator::BlindCertifier::new(&blind_secret_key);
+        let vote_secret = voter::make_voter_secret();
+        let commitment = certifier
+            .begin_blind_certification(&test_poll.poll_parameters, 10)
+            .unwrap();
+        assert!(certifier
+            .begin_blind_certification(&test_poll.poll_parameters, 10)
+            .is_err());
+        let (blinding_secret, request) =
+            voter::make_blind_certification_request(
+                &vote_secret,
+                &test_poll.poll_parameters,
+                &blind_public_key,
+                &commitment,
+                &test_poll.public_key,
+            )
+            .unwrap();
+        let response = certifier.certify_voter_blindly(&request).unwrap();
+        // The session secret is only used once.
+        assert!(certifier.certify_voter_blindly(&request).is_err());
+        // A cancelled session can not be finished, and another session can
+        // be opened after it.
+        certifier
+            .begin_blind_certification(&test_poll.poll_parameters, 10)
+            .unwrap();
+        certifier.cancel_blind_certification().unwrap();
+        assert!(certifier.certify_voter_blindly(&request).is_err());
+        assert!(certifier
+            .begin_blind_certification(&test_poll.poll_parameters, 10)
+            .is_ok());
+        let registration_response = voter::unblind_certificate(
+            &vote_secret,
+            &test_poll.poll_parameters,
+            &blind_public_key,
+            &commitment,
+            &blinding_secret,
+            &response,
+            &test_poll.public_key,
+        )
+        .unwrap();
+        let certificate = registration_response.get_certificate();
+        assert_ne!(certificate.get_omega(), response.get_c());
+        assert_ne!(certificate.get_rho(), response.get_r());
+
+        let vote_choices =
+            voter::make_vote_choices(&vec![3, 4], &test_poll.candidate_list);
+        let vote_request = voter::vote(
+            &vote_secret,
+            &vote_choices,
+            &registration_response,
+            &test_poll.poll_parameters,
+            &test_poll.public_key,
+        )
+        .unwrap();
+        assert!(verifier::verify_anonymous_vote_request(
+            &test_poll.poll_parameters,
+            &vote_request,
+            &blind_public_key,
+            &test_poll.public_key
+        )
+        .unwrap());
+
+        // A certificate of another weight is rejected.
+        let mut forged_vote_request = vote_request.clone();
+        forged_vote_request
+            .mut_vote()
+            .mut_certificate()
+            .set_voter_weight(20);
+        assert!(verifier::verify_anonymous_vote_request(
+            &test_poll.poll_parameters,
+            &forged_vote_request,
+            &blind_public_key,
+            &test_poll.public_key
+        )
+        .is_err());
+
+        // A certificate over another blank ballot is rejected.
+        let (another_secret, another_registration_response) =
+            test_poll.register_voter(10);
+        let mut forged_vote_request = voter::vote(
+            &another_secret,
+            &vote_choices,
+            &another_registration_response,
+            &test_poll.poll_parameters,
+            &test_poll.public_key,
+        )
+        .unwrap();
+        forged_vote_request
+            .mut_vote()
+            .set_certificate(certificate.clone());
+        assert!(verifier::verify_anonymous_vote_request(
+            &test_poll.poll_parameters,
+            &forged_vote_request,
+            &blind_public_key,
+            &test_poll.public_key
+        )
+        .is_err());
+
+        // A vote request without a certificate is rejected.
+        let mut forged_vote_request = vote_request;
+        forged_vote_request.mut_vote().clear_certificate();
+        assert!(verifier::verify_anonymous_vote_request(
+            &test_poll.poll_parameters,
+            &forged_vote_request,
+            &blind_public_key,
+            &test_poll.public_key
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_multi_question_voting() {
+        let question_list = make_question_list();
+        let test_poll =
+            TestPoll::with_questions(&["1001", "1002"], &question_list);
+        assert!(coordinator::make_poll_parameters_multi_question(
+            &[question_list[0].clone(), question_list[0].clone()],
+            &test_poll.counter_parameters,
+            &test_poll.secret_key,
+        )
+        .is_err());
+
+        // Each voter registers once and votes for all questions, each of
+        // which is bounded by the voter weight.
+        let mut encrypted_vote_sum = VoteStorage::new();
+        let mut accepted_votes = vec![];
+        for choice_list in [vec![vec![10, 0], vec![3, 3, 4]], vec![
+            vec![2, 5],
+            vec![0, 10, 0],
+        ]] {
+            let (vote_secret, registration_response) =
+                test_poll.register_voter(10);
+            let vote_choices =
+                voter::make_question_vote_choices(&choice_list, &question_list);
+            let vote_request = voter::vote(
+                &vote_secret,
+                &vote_choices,
+                &registration_response,
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            )
+            .unwrap();
+            assert!(verifier::verify_vote_request(
+                &test_poll.poll_parameters,
+                &vote_request,
+                &test_poll.public_key
+            )
+            .unwrap());
+
+            // A vote missing a question is rejected.
+            let mut forged_vote_request = vote_request.clone();
+            forged_vote_request.mut_vote().mut_question_ballot().pop();
+            forged_vote_request.mut_question_proof().pop();
+            assert!(verifier::verify_vote_request(
+                &test_poll.poll_parameters,
+                &forged_vote_request,
+                &test_poll.public_key
+            )
+            .is_err());
+
+            // Votes over the weight in any question are rejected.
+            let mut over_choice_list = choice_list.clone();
+            over_choice_list[1][0] += 1;
+            assert!(voter::vote(
+                &vote_secret,
+                &voter::make_question_vote_choices(
+                    &over_choice_list,
+                    &question_list
+                ),
+                &registration_response,
+                &test_poll.poll_parameters,
+                &test_poll.public_key
+            )
+            .is_err());
+
+            assert!(coordinator::aggregate_vote_sum_response(
+                &test_poll.poll_parameters,
+                vote_request.get_vote(),
+                &mut encrypted_vote_sum
+            )
+            .unwrap());
+            accepted_votes.push(vote_request.get_vote().clone());
+        }
+
+        // All questions are counted in one round.
+        for (counter_id, counter_secret) in test_poll.counters() {
+            let partially_decrypted_result =
+                counter::count(counter_id, counter_secret, &encrypted_vote_sum)
+                    .unwrap();
+            assert!(verifier::verify_count_request(
+                &test_poll.poll_parameters,
+                counter_id,
+                &encrypted_vote_sum,
+                &partially_decrypted_result,
+                &test_poll.public_key
+            )
+            .unwrap());
+        }
+        let aggregated_decrypted_result = test_poll.count(&encrypted_vote_sum);
+        let vote_result = coordinator::finalize_vote_result(
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            &aggregated_decrypted_result,
+            100,
+        )
+        .unwrap();
+        assert!(verifier::verify_vote_result(
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            encrypted_vote_sum.get_vote_root(),
+            &accepted_votes,
+            &aggregated_decrypted_result,
+            &vote_result,
+            &test_poll.public_key
+        )
+        .unwrap());
+        let question_result: Vec<(String, Vec<i64>)> = vote_result
+            .get_question_result()
+            .iter()
+            .map(|question_result| {
+                (
+                    question_result.get_question_id().to_string(),
+                    question_result
+                        .get_result()
+                        .iter()
+                        .map(|pair| pair.get_value())
+                        .collect(),
+                )
+            })
+            .collect();
+        assert_eq!(question_result, vec![
+            ("resolution1".to_string(), vec![12, 5, 3]),
+            ("resolution2".to_string(), vec![3, 13, 4, 0]),
+        ]);
+    }
+
+    #[test]
+    fn test_vote_receipt() {
+        let test_poll = TestPoll::new(&["1001"], &["Kitten", "Doge"]);
+
+        // Five voters vote, and the first voter replaces its vote, so that the
+        // vote Merkle tree has an odd level.
+        let mut vote_secret_list = vec![];
+        let mut registration_response_list = vec![];
+        let mut accepted_votes = vec![];
+        let mut encrypted_vote_sum = VoteStorage::new();
+        for choice in
+            [vec![1, 2], vec![3, 4], vec![5, 0], vec![0, 6], vec![2, 2]]
+        {
+            let (vote_secret, registration_response) =
+                test_poll.register_voter(10);
+            let vote_request = voter::vote(
+                &vote_secret,
+                &voter::make_vote_choices(&choice, &test_poll.candidate_list),
+                &registration_response,
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            )
+            .unwrap();
+            assert!(coordinator::aggregate_vote_sum_response(
+                &test_poll.poll_parameters,
+                vote_request.get_vote(),
+                &mut encrypted_vote_sum
+            )
+            .unwrap());
+            // The vote root updated from the frontier matches the Merkle path
+            // of the latest vote.
+            let receipt = coordinator::make_vote_receipt(
+                &encrypted_vote_sum,
+                vote_request.get_vote(),
+            )
+            .unwrap();
+            assert!(verifier::verify_vote_receipt(
+                vote_request.get_vote(),
+                &receipt,
+                encrypted_vote_sum.get_vote_root()
+            )
+            .unwrap());
+            accepted_votes.push(vote_request.get_vote().clone());
+            vote_secret_list.push(vote_secret);
+            registration_response_list.push(registration_response);
+        }
+        let mut vote_choices =
+            voter::make_vote_choices(&vec![7, 3], &test_poll.candidate_list);
+        vote_choices.set_sequence(1);
+        let new_vote_request = voter::vote(
+            &vote_secret_list[0],
+            &vote_choices,
+            &registration_response_list[0],
+            &test_poll.poll_parameters,
+            &test_poll.public_key,
+        )
+        .unwrap();
+        assert!(coordinator::replace_vote_sum_response(
+            &test_poll.poll_parameters,
+            &accepted_votes[0],
+            new_vote_request.get_vote(),
+            &mut encrypted_vote_sum
+        )
+        .unwrap());
+        accepted_votes.push(new_vote_request.get_vote().clone());
+        let vote_root = encrypted_vote_sum.get_vote_root();
+
+        // Every accepted vote gets a receipt against the vote root.
+        for vote in &accepted_votes {
+            let receipt =
+                coordinator::make_vote_receipt(&encrypted_vote_sum, vote)
+                    .unwrap();
+            assert!(verifier::verify_vote_receipt(vote, &receipt, vote_root)
+                .unwrap());
+        }
+        let receipt = coordinator::make_vote_receipt(
+            &encrypted_vote_sum,
+            &accepted_votes[4],
+        )
+        .unwrap();
+        assert!(!verifier::verify_vote_receipt(
+            &accepted_votes[3],
+            &receipt,
+            vote_root
+        )
+        .unwrap());
+        let mut forged_receipt = receipt.clone();
+        forged_receipt.mut_sibling_hash()[0] = vec![0; 32];
+        assert!(!verifier::verify_vote_receipt(
+            &accepted_votes[4],
+            &forged_receipt,
+            vote_root
+        )
+        .unwrap());
+        let mut forged_receipt = receipt;
+        forged_receipt.set_leaf_index(3);
+        assert!(!verifier::verify_vote_receipt(
+            &accepted_votes[4],
+            &forged_receipt,
+            vote_root
+        )
+        .unwrap());
+        let mut unknown_vote = accepted_votes[1].clone();
+        unknown_vote.set_sequence(2);
+        assert!(coordinator::make_vote_receipt(
+            &encrypted_vote_sum,
+            &unknown_vote
+        )
+        .is_err());
+
+        // The vote result is verified against all accepted votes.
+        let aggregated_decrypted_result = test_poll.count(&encrypted_vote_sum);
+        let vote_result = coordinator::finalize_vote_result(
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            &aggregated_decrypted_result,
+            50,
+        )
+        .unwrap();
+        assert!(verifier::verify_vote_result(
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            vote_root,
+            &accepted_votes,
+            &aggregated_decrypted_result,
+            &vote_result,
+            &test_poll.public_key
+        )
+        .unwrap());
+        // A dropped vote or another vote root is detected.
+        assert!(!verifier::verify_vote_result(
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            vote_root,
+            &accepted_votes[1..],
+            &aggregated_decrypted_result,
+            &vote_result,
+            &test_poll.public_key
+        )
+        .unwrap());
+        let another_vote_root = vec![0; 32];
+        assert!(!verifier::verify_vote_result(
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            &another_vote_root,
+            &accepted_votes,
+            &aggregated_decrypted_result,
+            &vote_result,
+            &test_poll.public_key
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn test_ballot_signature_encoding() {
+        let test_poll = TestPoll::new(&["1001"], &["Kitten", "Doge"]);
+        let mut another_counter_parameters = CounterParametersStorage::new();
+        another_counter_parameters
+            .mut_counter_parameters_share()
+            .push(
+                counter::make_parameters_share(
+                    "1001",
+                    &counter::make_counter_secret(),
+                )
+                .unwrap(),
+            );
+        let poll_parameters = &test_poll.poll_parameters;
+        let another_poll_parameters = &coordinator::make_poll_parameters(
+            &test_poll.candidate_list,
+            &another_counter_parameters,
+            &test_poll.secret_key,
+        )
+        .unwrap();
+        assert_eq!(
+            poll_parameters.get_ballot_signature_version(),
+            BALLOT_SIGNATURE_VERSION
+        );
+        assert_ne!(
+            poll_parameters.get_poll_id(),
+            another_poll_parameters.get_poll_id()
+        );
+
+        let vote_secret = voter::make_voter_secret();
+        let registration_request = voter::make_registration_request(
+            &vote_secret,
+            poll_parameters,
+            &test_poll.public_key,
+        )
+        .unwrap();
+        let registration_response = coordinator::certify_voter(
+            poll_parameters,
+            &test_poll.secret_key,
+            &registration_request,
+            10,
+        )
+        .unwrap();
+        let blank_ballot = registration_response.get_ballot();
+        let signature = registration_response.get_signature().to_vec();
+        assert!(wedpr_s_protos::verify_ballot_signature(
+            poll_parameters,
+            &test_poll.public_key,
+            blank_ballot,
+            &signature
+        )
+        .unwrap());
+        // The signature is bound to the poll.
+        assert!(!wedpr_s_protos::verify_ballot_signature(
+            another_poll_parameters,
+            &test_poll.public_key,
+            blank_ballot,
+            &signature
+        )
+        .unwrap());
+
+        // The weight is bound by the signed blank ballot, so a vote spending
+        // another weight is rejected.
+        let mut vote_request = voter::vote(
+            &vote_secret,
+            &voter::make_vote_choices(&vec![3, 5], &test_poll.candidate_list),
+            &registration_response,
+            poll_parameters,
+            &test_poll.public_key,
+        )
+        .unwrap();
+        assert!(verifier::verify_vote_request(
+            poll_parameters,
+            &vote_request,
+            &test_poll.public_key
+        )
+        .unwrap());
+        let blank_ciphertext1 = bytes_to_point(
+            vote_request.get_vote().get_blank_ballot().get_ciphertext1(),
+        )
+        .unwrap();
+        vote_request.mut_vote().mut_blank_ballot().set_ciphertext1(
+            point_to_bytes(
+                &(blank_ciphertext1 + *BASEPOINT_G1 * Scalar::from(10u64)),
+            ),
+        );
+        assert!(verifier::verify_vote_request(
+            poll_parameters,
+            &vote_request,
+            &test_poll.public_key
+        )
+        .is_err());
+
+        // Old polls keep the legacy encoding, which is not compatible with the
+        // versioned encoding.
+        let mut legacy_poll_parameters = poll_parameters.clone();
+        legacy_poll_parameters
+            .set_ballot_signature_version(LEGACY_BALLOT_SIGNATURE_VERSION);
+        legacy_poll_parameters.clear_poll_id();
+        let legacy_registration_response = coordinator::certify_voter(
+            &legacy_poll_parameters,
+            &test_poll.secret_key,
+            &registration_request,
+            10,
+        )
+        .unwrap();
+        let legacy_signature =
+            legacy_registration_response.get_signature().to_vec();
+        assert!(wedpr_s_protos::verify_ballot_signature(
+            &legacy_poll_parameters,
+            &test_poll.public_key,
+            blank_ballot,
+            &legacy_signature
+        )
+        .unwrap());
+        assert!(!wedpr_s_protos::verify_ballot_signature(
+            poll_parameters,
+            &test_poll.public_key,
+            blank_ballot,
+            &legacy_signature
+        )
+        .unwrap());
+        assert!(!wedpr_s_protos::verify_ballot_signature(
+            &legacy_poll_parameters,
+            &test_poll.public_key,
+            blank_ballot,
+            &signature
+        )
+        .unwrap());
+
+        // Unknown versions and versioned polls without a poll id are rejected.
+        let mut invalid_poll_parameters = poll_parameters.clone();
+        invalid_poll_parameters
+            .set_ballot_signature_version(BALLOT_SIGNATURE_VERSION + 1);
+        assert!(coordinator::certify_voter(
+            &invalid_poll_parameters,
+            &test_poll.secret_key,
+            &registration_request,
+            10,
+        )
+        .is_err());
+        let mut invalid_poll_parameters = poll_parameters.clone();
+        invalid_poll_parameters.clear_poll_id();
+        assert!(coordinator::certify_voter(
+            &invalid_poll_parameters,
+            &test_poll.secret_key,
+            &registration_request,
+            10,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_vote_result_partition() {
+        let test_poll = TestPoll::new(&["1001"], &["Kitten", "Doge"]);
+
+        // Three voters vote, and the first voter replaces its vote.
+        let mut vote_secret_list = vec![];
+        let mut registration_response_list = vec![];
+        let mut accepted_votes = vec![];
+        let mut encrypted_vote_sum = VoteStorage::new();
+        for choice in [vec![1, 2], vec![3, 4], vec![0, 0]] {
+            let (vote_secret, registration_response) =
+                test_poll.register_voter(10);
+            let vote_request = voter::vote(
+                &vote_secret,
+                &voter::make_vote_choices(&choice, &test_poll.candidate_list),
+                &registration_response,
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            )
+            .unwrap();
+            assert!(verifier::verify_vote_request(
+                &test_poll.poll_parameters,
+                &vote_request,
+                &test_poll.public_key
+            )
+            .unwrap());
+            assert!(coordinator::aggregate_vote_sum_response(
+                &test_poll.poll_parameters,
+                vote_request.get_vote(),
+                &mut encrypted_vote_sum
+            )
+            .unwrap());
+            accepted_votes.push(vote_request.get_vote().clone());
+            vote_secret_list.push(vote_secret);
+            registration_response_list.push(registration_response);
+        }
+        let mut vote_choices =
+            voter::make_vote_choices(&vec![5, 5], &test_poll.candidate_list);
+        vote_choices.set_sequence(1);
+        let new_vote_request = voter::vote(
+            &vote_secret_list[0],
+            &vote_choices,
+            &registration_response_list[0],
+            &test_poll.poll_parameters,
+            &test_poll.public_key,
+        )
+        .unwrap();
+        assert!(coordinator::replace_vote_sum_response(
+            &test_poll.poll_parameters,
+            &accepted_votes[0],
+            new_vote_request.get_vote(),
+            &mut encrypted_vote_sum
+        )
+        .unwrap());
+        accepted_votes.push(new_vote_request.get_vote().clone());
+
+        // A rest ballot without a valid format proof is rejected.
+        let mut forged_vote_request = new_vote_request.clone();
+        forged_vote_request
+            .mut_vote()
+            .mut_rest_ballot()
+            .set_ciphertext2(
+                accepted_votes[1]
+                    .get_rest_ballot()
+                    .get_ciphertext2()
+                    .to_vec(),
+            );
+        assert!(verifier::verify_vote_request(
+            &test_poll.poll_parameters,
+            &forged_vote_request,
+            &test_poll.public_key
+        )
+        .is_err());
+
+        let partially_decrypted_result = counter::count(
+            "1001",
+            &test_poll.counter_secret_list[0],
+            &encrypted_vote_sum,
+        )
+        .unwrap();
+        assert!(verifier::verify_count_request(
+            &test_poll.poll_parameters,
+            "1001",
+            &encrypted_vote_sum,
+            &partially_decrypted_result,
+            &test_poll.public_key
+        )
+        .unwrap());
+        let aggregated_decrypted_result = test_poll.count(&encrypted_vote_sum);
+
+        // The candidate votes and the abstained votes partition the total.
+        let vote_result = coordinator::finalize_vote_result(
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            &aggregated_decrypted_result,
+            50,
+        )
+        .unwrap();
+        let result: Vec<(&str, i64)> = vote_result
+            .get_result()
+            .iter()
+            .map(|pair| (pair.get_key(), pair.get_value()))
+            .collect();
+        assert_eq!(result, vec![
+            (POLL_RESULT_KEY_TOTAL_BALLOTS, 30),
+            ("Kitten", 8),
+            ("Doge", 9),
+            (POLL_RESULT_KEY_ABSTAINED_BALLOTS, 13),
+        ]);
+        assert!(verifier::verify_vote_result(
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            encrypted_vote_sum.get_vote_root(),
+            &accepted_votes,
+            &aggregated_decrypted_result,
+            &vote_result,
+            &test_poll.public_key
+        )
+        .unwrap());
+
+        // A value beyond the max vote limit is an error instead of being
+        // omitted.
+        assert!(coordinator::finalize_vote_result(
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            &aggregated_decrypted_result,
+            20,
+        )
+        .is_err());
+
+        // A missing entry or another rest ballot sum is detected.
+        let mut forged_vote_result = vote_result.clone();
+        forged_vote_result.mut_result().pop();
+        assert!(!verifier::verify_vote_result(
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            encrypted_vote_sum.get_vote_root(),
+            &accepted_votes,
+            &aggregated_decrypted_result,
+            &forged_vote_result,
+            &test_poll.public_key
+        )
+        .unwrap());
+        let mut forged_vote_sum = encrypted_vote_sum.clone();
+        forged_vote_sum
+            .set_rest_ballot(accepted_votes[1].get_rest_ballot().clone());
+        assert!(!verifier::verify_vote_result(
+            &test_poll.poll_parameters,
+            &forged_vote_sum,
+            encrypted_vote_sum.get_vote_root(),
+            &accepted_votes,
+            &aggregated_decrypted_result,
+            &vote_result,
+            &test_poll.public_key
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn test_quadratic_voting() {
+        let test_poll =
+            TestPoll::new(&["1001", "1002"], &["Kitten", "Doge", "Bunny"]);
+
+        // Each voter has 30 credits, and voting w votes costs w * w credits.
+        let mut encrypted_vote_sum = VoteStorage::new();
+        let mut vote_list = vec![];
+        for choice in [vec![5, 2, 0], vec![3, 3, 3], vec![1, 5, 0]] {
+            let (vote_secret, registration_response) =
+                test_poll.register_voter(30);
+
+            // Spending more credits than the voter weight is rejected.
+            let over_budget_choices = voter::make_vote_choices(
+                &vec![6, 0, 0],
+                &test_poll.candidate_list,
+            );
+            assert!(voter::vote_quadratic(
+                &vote_secret,
+                &over_budget_choices,
+                &registration_response,
+                &test_poll.poll_parameters,
+                &test_poll.public_key
+            )
+            .is_err());
+
+            let vote_choices =
+                voter::make_vote_choices(&choice, &test_poll.candidate_list);
+            let vote_request = voter::vote_quadratic(
+                &vote_secret,
+                &vote_choices,
+                &registration_response,
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            )
+            .unwrap();
+            assert!(verifier::verify_quadratic_vote_request(
+                &test_poll.poll_parameters,
+                &vote_request,
+                &test_poll.public_key
+            )
+            .unwrap());
+            // A quadratic vote is not a valid linear vote, and vice versa.
+            assert!(verifier::verify_vote_request(
+                &test_poll.poll_parameters,
+                &vote_request,
+                &test_poll.public_key
+            )
+            .is_err());
+            let linear_vote_request = voter::vote(
+                &vote_secret,
+                &voter::make_vote_choices(
+                    &vec![10, 10, 10],
+                    &test_poll.candidate_list,
+                ),
+                &registration_response,
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            )
+            .unwrap();
+            assert!(verifier::verify_quadratic_vote_request(
+                &test_poll.poll_parameters,
+                &linear_vote_request,
+                &test_poll.public_key
+            )
+            .is_err());
+
+            // The cost ballots cannot be swapped.
+            let mut forged_vote_request = vote_request.clone();
+            forged_vote_request.mut_vote().mut_cost_ballot().swap(0, 1);
+            assert!(verifier::verify_quadratic_vote_request(
+                &test_poll.poll_parameters,
+                &forged_vote_request,
+                &test_poll.public_key
+            )
+            .is_err());
+
+            assert!(coordinator::aggregate_vote_sum_response(
+                &test_poll.poll_parameters,
+                vote_request.get_vote(),
+                &mut encrypted_vote_sum
+            )
+            .unwrap());
+            vote_list.push(vote_request.get_vote().clone());
+        }
+
+        // The quadratic votes are counted as usual.
+        let aggregated_decrypted_result = test_poll.count(&encrypted_vote_sum);
+        let vote_result = coordinator::finalize_vote_result(
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            &aggregated_decrypted_result,
+            100,
+        )
+        .unwrap();
+        let result: Vec<(&str, i64)> = vote_result
+            .get_result()
+            .iter()
+            .map(|pair| (pair.get_key(), pair.get_value()))
+            .collect();
+        assert_eq!(result, vec![
+            (POLL_RESULT_KEY_TOTAL_BALLOTS, 90),
+            ("Kitten", 9),
+            ("Doge", 10),
+            ("Bunny", 3),
+        ]);
+        assert!(verifier::verify_vote_result(
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            encrypted_vote_sum.get_vote_root(),
+            &vote_list,
+            &aggregated_decrypted_result,
+            &vote_result,
+            &test_poll.public_key
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn test_counter_blame() {
+        let question_list = make_question_list();
+        let test_poll =
+            TestPoll::with_questions(&["1001", "1002", "1003"], &question_list);
+        let mut encrypted_vote_sum = VoteStorage::new();
+        let mut accepted_votes = vec![];
+        for choice_list in [vec![vec![10, 0], vec![3, 3, 4]], vec![
+            vec![2, 5],
+            vec![0, 10, 0],
+        ]] {
+            let (vote_secret, registration_response) =
+                test_poll.register_voter(10);
+            let vote_request = voter::vote(
+                &vote_secret,
+                &voter::make_question_vote_choices(
+                    &choice_list,
+                    &question_list,
+                ),
+                &registration_response,
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            )
+            .unwrap();
+            assert!(coordinator::aggregate_vote_sum_response(
+                &test_poll.poll_parameters,
+                vote_request.get_vote(),
+                &mut encrypted_vote_sum
+            )
+            .unwrap());
+            accepted_votes.push(vote_request.get_vote().clone());
+        }
+        let decrypted_part_list: Vec<DecryptedResultPartStorage> = test_poll
+            .counters()
+            .map(|(counter_id, counter_secret)| {
+                counter::count(counter_id, counter_secret, &encrypted_vote_sum)
+                    .unwrap()
+            })
+            .collect();
+
+        // Each misbehaving counter is excluded with a report of the invalid
+        // part and the failed check.
+        let mut aggregated_decrypted_result = DecryptedResultPartStorage::new();
+        assert!(coordinator::aggregate_decrypted_part_sum(
+            &test_poll.poll_parameters,
+            &test_poll.counter_id_list[0],
+            &encrypted_vote_sum,
+            &decrypted_part_list[0],
+            &mut aggregated_decrypted_result
+        )
+        .unwrap()
+        .is_none());
+        let valid_part = &decrypted_part_list[1];
+        let mut forged_part_list = vec![];
+        let mut forged_part = valid_part.clone();
+        let blank_blinding_c2 =
+            forged_part.get_blank_part().get_blinding_c2().to_vec();
+        forged_part.mut_question_part()[1].mut_candidate_part()[2]
+            .mut_value()
+            .set_blinding_c2(blank_blinding_c2);
+        forged_part_list.push((
+            forged_part,
+            "resolution2",
+            "Carol",
+            CounterBlameReason::INVALID_EQUALITY_PROOF,
+        ));
+        let mut forged_part = valid_part.clone();
+        forged_part.mut_question_part()[0].clear_rest_part();
+        forged_part_list.push((
+            forged_part,
+            "resolution1",
+            POLL_RESULT_KEY_ABSTAINED_BALLOTS,
+            CounterBlameReason::MISSING_PART,
+        ));
+        let mut forged_part = valid_part.clone();
+        forged_part.clear_question_part();
+        forged_part_list.push((
+            forged_part,
+            "resolution1",
+            "",
+            CounterBlameReason::MISSING_PART,
+        ));
+        let mut forged_part = valid_part.clone();
+        forged_part.mut_blank_part().set_blinding_c2(vec![1; 3]);
+        forged_part_list.push((
+            forged_part,
+            "",
+            POLL_RESULT_KEY_TOTAL_BALLOTS,
+            CounterBlameReason::MALFORMED_POINT,
+        ));
+        let mut forged_part = valid_part.clone();
+        forged_part.mut_question_part()[0].mut_candidate_part()[0]
+            .mut_value()
+            .clear_equality_proof();
+        forged_part_list.push((
+            forged_part,
+            "resolution1",
+            "For",
+            CounterBlameReason::MALFORMED_PROOF,
+        ));
+        let aggregated_snapshot = aggregated_decrypted_result.clone();
+        for (forged_part, question_id, candidate, reason) in forged_part_list {
+            assert!(!verifier::verify_count_request(
+                &test_poll.poll_parameters,
+                &test_poll.counter_id_list[1],
+                &encrypted_vote_sum,
+                &forged_part,
+                &test_poll.public_key
+            )
+            .unwrap());
+            let blame_report = coordinator::aggregate_decrypted_part_sum(
+                &test_poll.poll_parameters,
+                &test_poll.counter_id_list[1],
+                &encrypted_vote_sum,
+                &forged_part,
+                &mut aggregated_decrypted_result,
+            )
+            .unwrap()
+            .unwrap();
+            assert_eq!(blame_report.get_counter_id(), "1002");
+            assert_eq!(blame_report.get_question_id(), question_id);
+            assert_eq!(blame_report.get_candidate(), candidate);
+            assert_eq!(blame_report.get_reason(), reason);
+            assert_eq!(aggregated_decrypted_result, aggregated_snapshot);
+        }
+        let blame_report = coordinator::aggregate_decrypted_part_sum(
+            &test_poll.poll_parameters,
+            "1004",
+            &encrypted_vote_sum,
+            valid_part,
+            &mut aggregated_decrypted_result,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(blame_report.get_counter_id(), "1004");
+        assert_eq!(
+            blame_report.get_reason(),
+            CounterBlameReason::UNKNOWN_COUNTER
+        );
+        // A counter cannot send the result of another counter, or send its
+        // result twice.
+        let blame_report = coordinator::aggregate_decrypted_part_sum(
+            &test_poll.poll_parameters,
+            &test_poll.counter_id_list[2],
+            &encrypted_vote_sum,
+            valid_part,
+            &mut aggregated_decrypted_result,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            blame_report.get_counter_id(),
+            &test_poll.counter_id_list[2]
+        );
+        assert_eq!(
+            blame_report.get_reason(),
+            CounterBlameReason::MISMATCHED_COUNTER
+        );
+        let blame_report = coordinator::aggregate_decrypted_part_sum(
+            &test_poll.poll_parameters,
+            &test_poll.counter_id_list[0],
+            &encrypted_vote_sum,
+            &decrypted_part_list[0],
+            &mut aggregated_decrypted_result,
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            blame_report.get_reason(),
+            CounterBlameReason::DUPLICATE_COUNTER
+        );
+        assert_eq!(aggregated_decrypted_result, aggregated_snapshot);
+
+        // The vote result is finalized once every counter sends a valid part.
+        for (counter_id, decrypted_part) in test_poll
+            .counter_id_list
+            .iter()
+            .zip(&decrypted_part_list)
+            .skip(1)
+        {
+            assert!(coordinator::aggregate_decrypted_part_sum(
+                &test_poll.poll_parameters,
+                counter_id,
+                &encrypted_vote_sum,
+                decrypted_part,
+                &mut aggregated_decrypted_result
+            )
+            .unwrap()
+            .is_none());
+        }
+        let vote_result = coordinator::finalize_vote_result(
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            &aggregated_decrypted_result,
+            20,
+        )
+        .unwrap();
+        assert!(verifier::verify_vote_result(
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            encrypted_vote_sum.get_vote_root(),
+            &accepted_votes,
+            &aggregated_decrypted_result,
+            &vote_result,
+            &test_poll.public_key
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn test_for_against_voting() {
+        let test_poll = TestPoll::with_parameters(
+            &["1001", "1002"],
+            &["Kitten", "Doge", "Bunny"],
+            coordinator::make_poll_parameters_for_against,
+        );
+
+        // The votes for and against the candidates share the voter weight.
+        let mut encrypted_vote_sum = VoteStorage::new();
+        let mut vote_list = vec![];
+        for choice in [
+            vec![(5, 0), (0, 3), (0, 2)],
+            vec![(2, 1), (0, 4), (3, 0)],
+            vec![(0, 0), (1, 2), (0, 4)],
+        ] {
+            let (vote_secret, registration_response) =
+                test_poll.register_voter(10);
+
+            // The votes against the candidates cannot exceed the voter weight.
+            let over_weight_choices = voter::make_for_against_vote_choices(
+                &[(5, 0), (0, 6), (0, 0)],
+                &test_poll.candidate_list,
+            );
+            assert!(voter::vote(
+                &vote_secret,
+                &over_weight_choices,
+                &registration_response,
+                &test_poll.poll_parameters,
+                &test_poll.public_key
+            )
+            .is_err());
+
+            let vote_choices = voter::make_for_against_vote_choices(
+                &choice,
+                &test_poll.candidate_list,
+            );
+            let vote_request = voter::vote(
+                &vote_secret,
+                &vote_choices,
+                &registration_response,
+                &test_poll.poll_parameters,
+                &test_poll.public_key,
+            )
+            .unwrap();
+            assert!(verifier::verify_vote_request(
+                &test_poll.poll_parameters,
+                &vote_request,
+                &test_poll.public_key
+            )
+            .unwrap());
+
+            // The against ballots cannot be swapped or dropped.
+            let mut forged_vote_request = vote_request.clone();
+            forged_vote_request
+                .mut_vote()
+                .mut_against_ballot()
+                .swap(0, 1);
+            assert!(verifier::verify_vote_request(
+                &test_poll.poll_parameters,
+                &forged_vote_request,
+                &test_poll.public_key
+            )
+            .is_err());
+            let mut forged_vote_request = vote_request.clone();
+            forged_vote_request.mut_vote().clear_against_ballot();
+            forged_vote_request.clear_against_ballot_proof();
+            assert!(verifier::verify_vote_request(
+                &test_poll.poll_parameters,
+                &forged_vote_request,
+                &test_poll.public_key
+            )
+            .is_err());
+
+            assert!(coordinator::aggregate_vote_sum_response(
+                &test_poll.poll_parameters,
+                vote_request.get_vote(),
+                &mut encrypted_vote_sum
+            )
+            .unwrap());
+            vote_list.push(vote_request.get_vote().clone());
+        }
+
+        // Votes against a candidate are rejected in a poll without against
+        // ballots.
+        let plain_poll =
+            TestPoll::new(&["1001", "1002"], &["Kitten", "Doge", "Bunny"]);
+        let (vote_secret, registration_response) =
+            plain_poll.register_voter(10);
+        assert!(voter::vote(
+            &vote_secret,
+            &voter::make_for_against_vote_choices(
+                &[(1, 1), (0, 0), (0, 0)],
+                &plain_poll.candidate_list
+            ),
+            &registration_response,
+            &plain_poll.poll_parameters,
+            &plain_poll.public_key
+        )
+        .is_err());
+
+        let aggregated_decrypted_result = test_poll.count(&encrypted_vote_sum);
+        let vote_result = coordinator::finalize_vote_result(
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            &aggregated_decrypted_result,
+            100,
+        )
+        .unwrap();
+        let result: Vec<(&str, i64)> = vote_result
+            .get_result()
+            .iter()
+            .map(|pair| (pair.get_key(), pair.get_value()))
+            .collect();
+        assert_eq!(result, vec![
+            (POLL_RESULT_KEY_TOTAL_BALLOTS, 30),
+            ("Kitten", 6),
+            ("Doge", -8),
+            ("Bunny", -3),
+            (POLL_RESULT_KEY_ABSTAINED_BALLOTS, 3),
+        ]);
+        let against_result: Vec<(&str, i64)> = vote_result
+            .get_against_result()
+            .iter()
+            .map(|pair| (pair.get_key(), pair.get_value()))
+            .collect();
+        assert_eq!(against_result, vec![
+            ("Kitten", 1),
+            ("Doge", 9),
+            ("Bunny", 6)
+        ]);
+        assert!(verifier::verify_vote_result(
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            encrypted_vote_sum.get_vote_root(),
+            &vote_list,
+            &aggregated_decrypted_result,
+            &vote_result,
+            &test_poll.public_key
+        )
+        .unwrap());
+
+        // Moving votes between the for and against results is detected.
+        let mut forged_vote_result = vote_result.clone();
+        forged_vote_result.mut_result()[2].set_value(-7);
+        forged_vote_result.mut_against_result()[1].set_value(8);
+        assert!(!verifier::verify_vote_result(
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            encrypted_vote_sum.get_vote_root(),
+            &vote_list,
+            &aggregated_decrypted_result,
+            &forged_vote_result,
+            &test_poll.public_key
+        )
+        .unwrap());
+
+        // The ranking does not apply to the net support.
+        let candidates =
+            test_poll.poll_parameters.get_candidates().get_candidate();
+        assert!(coordinator::make_ranking_comparison(
+            &test_poll.poll_parameters,
+            &candidates[0],
+            &candidates[1],
+            10
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_aggregated_format_proof() {
+        let test_poll = TestPoll::new(&["1001", "1002"], &[
+            "Kitten", "Doge", "Bunny", "Panda",
+        ]);
+        let (vote_secret, registration_response) = test_poll.register_voter(10);
+        let vote_choices = voter::make_vote_choices(
+            &vec![4, 3, 0, 1],
+            &test_poll.candidate_list,
+        );
+        let vote_request = voter::vote_with_aggregated_format_proof(
+            &vote_secret,
+            &vote_choices,
+            &registration_response,
+            &test_poll.poll_parameters,
+            &test_poll.public_key,
+        )
+        .unwrap();
+        assert!(vote_request.get_ballot_proof().is_empty());
+        assert!(verifier::verify_vote_request(
+            &test_poll.poll_parameters,
+            &vote_request,
+            &test_poll.public_key
+        )
+        .unwrap());
+
+        // The aggregated proof makes a smaller vote request.
+        let separate_vote_request = voter::vote(
+            &vote_secret,
+            &vote_choices,
+            &registration_response,
+            &test_poll.poll_parameters,
+            &test_poll.public_key,
+        )
+        .unwrap();
+        assert!(
+            wedpr_s_protos::proto_to_bytes(&vote_request).unwrap().len()
+                < wedpr_s_protos::proto_to_bytes(&separate_vote_request)
+                    .unwrap()
+                    .len()
+        );
+
+        // The responses cannot be forged or reordered.
+        let mut forged_vote_request = vote_request.clone();
+        forged_vote_request
+            .mut_aggregated_format_proof()
+            .mut_response_blinding()
+            .swap(0, 1);
+        assert!(verifier::verify_vote_request(
+            &test_poll.poll_parameters,
+            &forged_vote_request,
+            &test_poll.public_key
+        )
+        .is_err());
+        let mut forged_vote_request = vote_request.clone();
+        forged_vote_request
+            .mut_aggregated_format_proof()
+            .mut_response_value()
+            .pop();
+        assert!(verifier::verify_vote_request(
+            &test_poll.poll_parameters,
+            &forged_vote_request,
+            &test_poll.public_key
+        )
+        .is_err());
+        // The aggregated proof cannot be mixed with separate proofs.
+        let mut forged_vote_request = vote_request;
+        forged_vote_request
+            .set_ballot_proof(separate_vote_request.get_ballot_proof().into());
+        assert!(verifier::verify_vote_request(
+            &test_poll.poll_parameters,
+            &forged_vote_request,
+            &test_poll.public_key
+        )
+        .is_err());
+
+        // Each question of a multi-question poll has its own aggregated proof.
+        let question_list = make_question_list();
+        let question_poll =
+            TestPoll::with_questions(&["1001", "1002"], &question_list);
+        let (vote_secret, registration_response) =
+            question_poll.register_voter(10);
+        let vote_request = voter::vote_with_aggregated_format_proof(
+            &vote_secret,
+            &voter::make_question_vote_choices(
+                &[vec![10, 0], vec![3, 3, 4]],
+                &question_list,
+            ),
+            &registration_response,
+            &question_poll.poll_parameters,
+            &question_poll.public_key,
+        )
+        .unwrap();
+        assert!(verifier::verify_vote_request(
+            &question_poll.poll_parameters,
+            &vote_request,
+            &question_poll.public_key
+        )
+        .unwrap());
+        let mut forged_vote_request = vote_request;
+        forged_vote_request.mut_question_proof()[1]
+            .mut_aggregated_format_proof()
+            .mut_response_value()
+            .swap(0, 2);
+        assert!(verifier::verify_vote_request(
+            &question_poll.poll_parameters,
+            &forged_vote_request,
+            &question_poll.public_key
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_aggregated_unbounded_proof() {
+        let test_poll = TestPoll::new(&["1001", "1002"], &[
+            "Kitten", "Doge", "Bunny", "Panda",
+        ]);
+        let (weight_secret, zero_secret, registration_response) =
+            test_poll.register_unbounded_voter(10);
+        let vote_choices = voter::make_vote_choices(
+            &vec![10, 0, 10, 0],
+            &test_poll.candidate_list,
+        );
+        let vote_request = voter::vote_unbounded_with_aggregated_proof(
+            &weight_secret,
+            &zero_secret,
+            &vote_choices,
+            &registration_response,
+            &test_poll.poll_parameters,
+            &test_poll.public_key,
+        )
+        .unwrap();
+        assert!(vote_request.get_ballot_proof().is_empty());
+        assert!(verifier::verify_unbounded_vote_request(
+            &test_poll.poll_parameters,
+            &vote_request,
+            &test_poll.public_key
+        )
+        .unwrap());
+
+        // The aggregated proofs make a smaller vote request.
+        let separate_vote_request = voter::vote_unbounded(
+            &weight_secret,
+            &zero_secret,
+            &vote_choices,
+            &registration_response,
+            &test_poll.poll_parameters,
+            &test_poll.public_key,
+        )
+        .unwrap();
+        assert!(
+            wedpr_s_protos::proto_to_bytes(&vote_request).unwrap().len()
+                < wedpr_s_protos::proto_to_bytes(&separate_vote_request)
+                    .unwrap()
+                    .len()
+        );
+
+        // A ballot can only encrypt the voter weight or zero.
+        assert!(voter::vote_unbounded_with_aggregated_proof(
+            &weight_secret,
+            &zero_secret,
+            &voter::make_vote_choices(
+                &vec![10, 5, 0, 0],
+                &test_poll.candidate_list
+            ),
+            &registration_response,
+            &test_poll.poll_parameters,
+            &test_poll.public_key
+        )
+        .is_err());
+
+        // The branches of the ballots cannot be forged or reordered.
+        let mut forged_vote_request = vote_request.clone();
+        forged_vote_request
+            .mut_aggregated_either_equality_proof()
+            .mut_weight_challenge()
+            .swap(0, 1);
+        assert!(verifier::verify_unbounded_vote_request(
+            &test_poll.poll_parameters,
+            &forged_vote_request,
+            &test_poll.public_key
+        )
+        .is_err());
+        let mut forged_vote_request = vote_request.clone();
+        forged_vote_request.mut_vote().mut_voted_ballot().swap(0, 1);
+        assert!(verifier::verify_unbounded_vote_request(
+            &test_poll.poll_parameters,
+            &forged_vote_request,
+            &test_poll.public_key
+        )
+        .is_err());
+        let mut forged_vote_request = vote_request.clone();
+        forged_vote_request.clear_aggregated_either_equality_proof();
+        assert!(verifier::verify_unbounded_vote_request(
+            &test_poll.poll_parameters,
+            &forged_vote_request,
+            &test_poll.public_key
+        )
+        .is_err());
+        // The aggregated proofs cannot be mixed with separate proofs.
+        let mut forged_vote_request = vote_request;
+        forged_vote_request
+            .set_ballot_proof(separate_vote_request.get_ballot_proof().into());
+        assert!(verifier::verify_unbounded_vote_request(
+            &test_poll.poll_parameters,
+            &forged_vote_request,
+            &test_poll.public_key
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_poll_parameters_integrity() {
+        let test_poll =
+            TestPoll::new(&["1001", "1002"], &["Kitten", "Doge", "Bunny"]);
+        assert!(verifier::verify_poll_id(&test_poll.poll_parameters).unwrap());
+        assert!(verifier::verify_poll_parameters(
+            &test_poll.poll_parameters,
+            &test_poll.public_key
+        )
+        .unwrap());
+        let (another_public_key, another_secret_key) =
+            SIGNATURE.generate_keypair();
+        assert!(!verifier::verify_poll_parameters(
+            &test_poll.poll_parameters,
+            &another_public_key
+        )
+        .unwrap());
+
+        let (vote_secret, registration_response) = test_poll.register_voter(10);
+        let vote_choices =
+            voter::make_vote_choices(&vec![3, 5, 2], &test_poll.candidate_list);
+        let vote_request = voter::vote(
+            &vote_secret,
+            &vote_choices,
+            &registration_response,
+            &test_poll.poll_parameters,
+            &test_poll.public_key,
+        )
+        .unwrap();
+        assert!(verifier::verify_vote_request(
+            &test_poll.poll_parameters,
+            &vote_request,
+            &test_poll.public_key
+        )
+        .unwrap());
+
+        // A relay swapping the candidate order is detected by voters and
+        // verifiers.
+        let mut swapped_poll_parameters = test_poll.poll_parameters.clone();
+        swapped_poll_parameters
+            .mut_candidates()
+            .mut_candidate()
+            .swap(0, 1);
+        assert!(!verifier::verify_poll_id(&swapped_poll_parameters).unwrap());
+        assert!(voter::make_registration_request(
+            &vote_secret,
+            &swapped_poll_parameters,
+            &test_poll.public_key
+        )
+        .is_err());
+        assert!(voter::vote(
+            &vote_secret,
+            &vote_choices,
+            &registration_response,
+            &swapped_poll_parameters,
+            &test_poll.public_key
+        )
+        .is_err());
+        assert!(verifier::verify_vote_request(
+            &swapped_poll_parameters,
+            &vote_request,
+            &test_poll.public_key
+        )
+        .is_err());
+
+        // So is a relay adding a candidate, even with a recomputed poll id.
+        let mut added_poll_parameters = test_poll.poll_parameters.clone();
+        added_poll_parameters
+            .mut_candidates()
+            .mut_candidate()
+            .push("Panda".to_string());
+        assert!(!verifier::verify_poll_id(&added_poll_parameters).unwrap());
+        coordinator::sign_poll_parameters(
+            &mut added_poll_parameters,
+            &another_secret_key,
+        )
+        .unwrap();
+        assert!(verifier::verify_poll_id(&added_poll_parameters).unwrap());
+        assert!(!verifier::verify_poll_parameters(
+            &added_poll_parameters,
+            &test_poll.public_key
+        )
+        .unwrap());
+        assert!(verifier::verify_vote_request(
+            &added_poll_parameters,
+            &vote_request,
+            &test_poll.public_key
+        )
+        .is_err());
+        assert!(voter::make_registration_request(
+            &vote_secret,
+            &added_poll_parameters,
+            &test_poll.public_key
+        )
+        .is_err());
+        assert!(voter::vote(
+            &vote_secret,
+            &vote_choices,
+            &registration_response,
+            &added_poll_parameters,
+            &test_poll.public_key
+        )
+        .is_err());
+
+        // Downgrading the poll to the legacy encoding does not skip the checks.
+        let mut legacy_poll_parameters = test_poll.poll_parameters.clone();
+        legacy_poll_parameters
+            .set_ballot_signature_version(LEGACY_BALLOT_SIGNATURE_VERSION);
+        assert!(!verifier::verify_poll_id(&legacy_poll_parameters).unwrap());
+        assert!(!verifier::verify_poll_parameters(
+            &legacy_poll_parameters,
+            &test_poll.public_key
+        )
+        .unwrap());
+        assert!(voter::make_registration_request(
+            &vote_secret,
+            &legacy_poll_parameters,
+            &test_poll.public_key
+        )
+        .is_err());
+
+        // The poll point must be aggregated from the committed counter shares.
+        let mut dropped_poll_parameters = test_poll.poll_parameters;
+        dropped_poll_parameters
+            .mut_counter_parameters()
+            .mut_counter_parameters_share()
+            .pop();
+        coordinator::sign_poll_parameters(
+            &mut dropped_poll_parameters,
+            &test_poll.secret_key,
+        )
+        .unwrap();
+        assert!(!verifier::verify_poll_parameters(
+            &dropped_poll_parameters,
+            &test_poll.public_key
+        )
+        .unwrap());
+    }
+
+    #[test]
+    fn test_delegated_voting() {
+        let test_poll = TestPoll::new(&["1001"], &["Kitten", "Doge"]);
+
+        // The delegator, the delegate and another voter.
+        let mut voter_secret_list = vec![];
+        let mut registration_response_list = vec![];
+        for weight in [10, 5, 7] {
+            let (voter_secret, registration_response) =
+                test_poll.register_voter(weight);
+            voter_secret_list.push(voter_secret);
+            registration_response_list.push(registration_response);
+        }
+        // The delegator is hidden among the certified ballots of all voters.
+        let ring_ballot_list: Vec<CertifiedBallot> = registration_response_list
+            .iter()
+            .map(|registration_response| {
+                let mut member = CertifiedBallot::new();
+                member.set_ballot(registration_response.get_ballot().clone());
+                member.set_signature(
+                    registration_response.get_signature().to_vec(),
+                );
+                member
+            })
+            .collect();
+        let delegate_ballot = registration_response_list[1].get_ballot();
+        let delegation = voter::make_delegation(
+            &voter_secret_list[0],
+            &registration_response_list[0],
+            delegate_ballot,
+            &ring_ballot_list,
+            &test_poll.poll_parameters,
+            &test_poll.public_key,
+        )
+        .unwrap();
+        assert_eq!(delegation.get_ring().len(), 3);
+        assert!(verifier::verify_delegation(
+            &test_poll.poll_parameters,
+            &delegation,
+            &test_poll.public_key
+        )
+        .unwrap());
+
+        // The delegate spends the combined weight without learning the
+        // identity of the delegator from the vote.
+        let delegated_vote_request = voter::vote_with_delegations(
+            &voter_secret_list[1],
+            &voter::make_vote_choices(&vec![8, 6], &test_poll.candidate_list),
+            &registration_response_list[1],
+            &[delegation.clone()],
+            &test_poll.poll_parameters,
+            &test_poll.public_key,
+        )
+        .unwrap();
+        assert!(verifier::verify_vote_request(
+            &test_poll.poll_parameters,
+            &delegated_vote_request,
+            &test_poll.public_key
+        )
+        .unwrap());
+        let other_vote_request = voter::vote(
+            &voter_secret_list[2],
+            &voter::make_vote_choices(&vec![3, 4], &test_poll.candidate_list),
+            &registration_response_list[2],
+            &test_poll.poll_parameters,
+            &test_poll.public_key,
+        )
+        .unwrap();
+        let delegator_vote_request = voter::vote(
+            &voter_secret_list[0],
+            &voter::make_vote_choices(&vec![0, 10], &test_poll.candidate_list),
+            &registration_response_list[0],
+            &test_poll.poll_parameters,
+            &test_poll.public_key,
+        )
+        .unwrap();
+        assert!(verifier::verify_vote_request(
+            &test_poll.poll_parameters,
+            &delegator_vote_request,
+            &test_poll.public_key
+        )
+        .unwrap());
+
+        // The delegator can not vote after the delegate has voted.
+        let mut encrypted_vote_sum = VoteStorage::new();
+        for vote_request in [&delegated_vote_request, &other_vote_request] {
+            assert!(coordinator::aggregate_vote_sum_response(
+                &test_poll.poll_parameters,
+                vote_request.get_vote(),
+                &mut encrypted_vote_sum
+            )
+            .unwrap());
+        }
+        let vote_sum_snapshot = encrypted_vote_sum.clone();
+        assert!(!coordinator::aggregate_vote_sum_response(
+            &test_poll.poll_parameters,
+            delegator_vote_request.get_vote(),
+            &mut encrypted_vote_sum
+        )
+        .unwrap());
+        assert_eq!(encrypted_vote_sum, vote_sum_snapshot);
+
+        // The delegate can not vote after the delegator has voted.
+        let aggregator = aggregator::VoteAggregator::new(
+            &test_poll.poll_parameters,
+            Box::new(aggregator::FileCheckpointStore::new(
+                std::env::temp_dir().join(format!(
+                    "wedpr_acv_delegation_{}",
+                    std::process::id()
+                )),
+            )),
+            0,
+        );
+        assert!(aggregator
+            .add_vote(delegator_vote_request.get_vote())
+            .unwrap());
+        assert!(!aggregator
+            .add_vote(delegated_vote_request.get_vote())
+            .unwrap());
+
+        // The delegated weight is counted with the vote of the delegate.
+        let aggregated_decrypted_result = test_poll.count(&encrypted_vote_sum);
+        let vote_result = coordinator::finalize_vote_result(
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            &aggregated_decrypted_result,
+            30,
+        )
+        .unwrap();
+        for candidate_result in vote_result.get_result() {
+            let expected_value = match candidate_result.get_key() {
+                "Kitten" => 11,
+                "Doge" => 10,
+                POLL_RESULT_KEY_ABSTAINED_BALLOTS => 1,
+                _ => 22,
+            };
+            assert_eq!(candidate_result.get_value(), expected_value);
+        }
+        assert!(verifier::verify_vote_result(
+            &test_poll.poll_parameters,
+            &encrypted_vote_sum,
+            encrypted_vote_sum.get_vote_root(),
+            &[
+                delegated_vote_request.get_vote().clone(),
+                other_vote_request.get_vote().clone()
+            ],
+            &aggregated_decrypted_result,
+            &vote_result,
+            &test_poll.public_key
+        )
+        .unwrap());
+
+        // A voter can not delegate to itself.
+        assert!(voter::make_delegation(
+            &voter_secret_list[0],
+            &registration_response_list[0],
+            registration_response_list[0].get_ballot(),
+            &ring_ballot_list,
+            &test_poll.poll_parameters,
+            &test_poll.public_key
+        )
+        .is_err());
+        // A transfer ballot not re-encrypting the delegated weight is
+        // rejected.
+        let mut forged_delegation = delegation.clone();
+        forged_delegation.set_transfer_ballot(delegate_ballot.clone());
+        assert!(!verifier::verify_delegation(
+            &test_poll.poll_parameters,
+            &forged_delegation,
+            &test_poll.public_key
+        )
+        .unwrap());
+        // A nullifier of another voter in the ring is rejected.
+        let (other_nullifier, _) = voter::generate_nullifier(
+            &voter_secret_list[2],
+            &test_poll.poll_parameters,
+        )
+        .unwrap();
+        let mut forged_delegation = delegation.clone();
+        forged_delegation.set_nullifier(other_nullifier);
+        assert!(!verifier::verify_delegation(
+            &test_poll.poll_parameters,
+            &forged_delegation,
+            &test_poll.public_key
+        )
+        .unwrap());
+        // A ring with an uncertified ballot is rejected.
+        let mut forged_delegation = delegation.clone();
+        let forged_signature =
+            forged_delegation.get_ring()[1].get_signature().to_vec();
+        forged_delegation.mut_ring()[0].set_signature(forged_signature);
+        assert!(!verifier::verify_delegation(
+            &test_poll.poll_parameters,
+            &forged_delegation,
+            &test_poll.public_key
+        )
+        .unwrap());
+        // A ring that is not sorted or misses a member is rejected.
+        let mut forged_delegation = delegation.clone();
+        forged_delegation.mut_ring().swap(0, 1);
+        assert!(!verifier::verify_delegation(
+            &test_poll.poll_parameters,
+            &forged_delegation,
+            &test_poll.public_key
+        )
+        .unwrap());
+        let mut forged_delegation = delegation.clone();
+        forged_delegation.mut_ring().remove(2);
+        assert!(!verifier::verify_delegation(
+            &test_poll.poll_parameters,
+            &forged_delegation,
+            &test_poll.public_key
+        )
+        .unwrap());
+        // A delegation to another voter can not be spent by the delegate.
+        let other_delegation = voter::make_delegation(
+            &voter_secret_list[0],
+            &registration_response_list[0],
+            registration_response_list[2].get_ballot(),
+            &ring_ballot_list,
+            &test_poll.poll_parameters,
+            &test_poll.public_key,
+        )
+        .unwrap();
+        assert!(voter::vote_with_delegations(
+            &voter_secret_list[1],
+            &voter::make_vote_choices(&vec![8, 6], &test_poll.candidate_list),
+            &registration_response_list[1],
+            &[other_delegation],
+            &test_poll.poll_parameters,
+            &test_poll.public_key
+        )
+        .is_err());
+        // The same delegation can not be spent twice.
+        let duplicated_vote_request = voter::vote_with_delegations(
+            &voter_secret_list[1],
+            &voter::make_vote_choices(&vec![15, 10], &test_poll.candidate_list),
+            &registration_response_list[1],
+            &[delegation.clone(), delegation],
+            &test_poll.poll_parameters,
+            &test_poll.public_key,
+        )
+        .unwrap();
+        assert!(!verifier::verify_vote_request(
+            &test_poll.poll_parameters,
+            &duplicated_vote_request,
+            &test_poll.public_key
+        )
+        .unwrap_or(false));
+    }
 }
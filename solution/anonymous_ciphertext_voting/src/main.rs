@@ -1,948 +1,822 @@
 // Copyright 2020 WeDPR Lab Project Authors. Licensed under Apache-2.0.
 
-//! Minimalist demo of anonymous ciphertext voting.
-
-use wedpr_l_crypto_zkp_utils::bytes_to_point;
-use wedpr_l_utils::traits::Signature;
-use wedpr_s_protos::config::SIGNATURE;
-
-use wedpr_s_protos::generated::acv::{
-    CandidateList, CounterParametersStorage, CounterSecret,
-    DecryptedResultPartStorage, VoteStorage, VoterSecret,
+//! Scripted multi-party simulation of anonymous ciphertext voting.
+//!
+//! Uses `cargo run --bin wedpr_s_anonymous_ciphertext_voting [config_file]`
+//! to run the full protocol for a poll described by the config file, and
+//! reports the time and the message sizes of each phase together with the
+//! verified vote result. The config file has one `key = value` setting per
+//! line, and `#` starts a comment. All settings are optional:
+//!
+//! ```text
+//! counters = 3              # number of counters
+//! voters = 10               # number of voters
+//! candidates = 3            # number of listed candidates
+//! weights = 100, 50         # voter weights, assigned to voters in turn
+//! distribution = random     # even, single or random
+//! unlisted_choices = 0      # unlisted choices of each voter
+//! max_unlisted_candidate_id = 10
+//! seed = 1                  # seed of the simulated vote choices
+//! ```
+//!
+//! A poll with unlisted choices runs the unbounded voting flow, where a voter
+//! votes either the whole weight or nothing for each candidate. Otherwise the
+//! voter weight is split among the candidates, and the rest is abstained.
+
+use std::{
+    collections::BTreeMap,
+    env, fs, process,
+    time::{Duration, Instant},
 };
 
-extern crate wedpr_s_anonymous_ciphertext_voting;
-use colored::Colorize;
+use wedpr_l_crypto_zkp_utils::{
+    bytes_to_point, get_random_scalar, scalar_to_bytes,
+};
+use wedpr_l_utils::{error::WedprError, traits::Signature};
 use wedpr_s_anonymous_ciphertext_voting::{
-    config::POLL_RESULT_KEY_TOTAL_BALLOTS, coordinator, counter, verifier,
-    voter,
+    config::{
+        POLL_RESULT_KEY_ABSTAINED_BALLOTS, POLL_RESULT_KEY_TOTAL_BALLOTS,
+    },
+    coordinator, counter, verifier, voter,
+};
+use wedpr_s_protos::{
+    config::SIGNATURE,
+    generated::acv::{
+        CandidateList, CounterParametersStorage, DecryptedResultPartStorage,
+        PollParametersStorage, RegistrationResponse, UnlistedVoteChoice,
+        VoteChoices, VoteRequest, VoteResultStorage, VoteStorage, VoterSecret,
+    },
+    proto_to_bytes,
 };
 
-fn main() {
-    print_highlight2(
-        "#\n# Welcome to anonymous ciphertext voting (ACV) demo!",
-        "# 欢迎来到多方密文决策demo演示!\n#",
-    );
-    println!();
-    print_alert2(
-        "Please select the display language used for this demonstration: ▼▼▼",
-        "请选择演示所使用的显示语言：▼▼▼",
-    );
-
-    print_alert2(
-        " ▶ Enter \"1\" to select English (default option)",
-        " ▶ 输入 \"2\" 选择中文",
-    );
-    println!();
-    let mut choice = wait_for_input();
-    loop {
-        // The default option.
-        if choice == "1" || choice.is_empty() {
-            flow_en();
-            break;
-        } else if choice == "2" {
-            flow_cn();
-            break;
-        } else {
-            print_alert2(
-                "输入错误！请重新输入：",
-                "Invalid input! Please try again:",
-            );
-            choice = wait_for_input();
-        }
-    }
+/// How a voter splits the voter weight among the candidates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum VoteDistribution {
+    /// Every candidate gets the same share of the voter weight.
+    Even,
+    /// One candidate, chosen in turn, gets the whole voter weight.
+    Single,
+    /// Every candidate gets a random share of the voter weight.
+    Random,
 }
 
-fn flow_cn() {
-    print_wide(
-        "投票demo流程中，您将体验多方密文决策的全过程，具体包括：\n
-        1. 投票者如何使用密文选票进行隐匿投票；\n
-        2. 计票者如何联合解密得到计票结果；\n
-        3. 任意验证者如何借助零知识证明来验证整个过程中投票者与计票者行为的正确性。",
-    );
-    print_wide("为了更容易理解其效果，我们设定了如下示例场景。");
-
-    println!(
-        "{}\n{}\n{}\n",
-        "【场景介绍】".yellow(),
-        "4个投票者为3个候选人进行投票，\
-         每个投票者都可向其中任意一个或多个候选人投出包含一定数值的密文选票，",
-        "3个计票者需合作才能统计出每个候选人的最终得票。",
-    );
-    println!(
-        "{}\n{}\n{}\n{}\n{}\n{}\n",
-        "【流程介绍】".yellow(),
-        "1. 投票者申请密文空白选票；",
-        "2. 投票者决定为各候选人分别投出多少票数，\
-         生成并投出对应的密文选票和零知识证明；",
-        "3. 任意验证者验证投票者投出的密文选票是否正确有效；",
-        "4. 计票者联合计算每个候选人的得票，公布统计结果和零知识证明；",
-        "5. 任意验证者验证计票者的计票过程及计票结果是否正确有效。"
-    );
-    pause_cn();
-
-    println!(
-        "{} {} {}\n",
-        "【演示进度】",
-        "<<申请密文空白选票>>".yellow(),
-        "↦ 投出对各候选人的密文选票 ↦ 验证密文选票 ↦ 联合计票并公布计票信息↦ \
-         验证计票过程 ↦ 公布计票结果 ↦ 验证计票结果",
-    );
-
-    print_alert(
-        "现在请输入当前投票者的密文空白选票的权重：\
-         ▼▼（密文空白选票的权重表示：该投票者可以投出的密文选票最大总票数）",
-    );
-    print_highlight(
-        "这里，我们暂定密文空白选票权重上限为100（真实业务可按需扩展），\
-         请输入0到100之间的整数",
-    );
-    let last_voter_weight = wait_for_number_cn();
-    let mut voter_weight_list = vec![100, 100, 100];
-    voter_weight_list.push(last_voter_weight as u32);
-    let last_voter_id = voter_weight_list.len() - 1;
-
-    // Initialize a group of counters.
-    let counter_id_list = vec!["1001", "1002", "1003"];
-    let mut counter_secret_list: Vec<CounterSecret> = vec![];
-    let mut counter_parameters = CounterParametersStorage::default();
-    for id in counter_id_list.clone() {
-        let counter_secret = counter::make_counter_secret();
-        let counter_parameters_share =
-            counter::make_parameters_share(id, &counter_secret).unwrap();
-        counter_parameters
-            .mut_counter_parameters_share()
-            .push(counter_parameters_share);
-        counter_secret_list.push(counter_secret);
-    }
-
-    // Initialize the coordinator.
-    let (public_key, secret_key) = SIGNATURE.generate_keypair();
+/// Settings of a simulated poll.
+#[derive(Debug)]
+struct SimulationConfig {
+    counter_count: usize,
+    voter_count: usize,
+    candidate_count: usize,
+    weight_list: Vec<u32>,
+    distribution: VoteDistribution,
+    unlisted_choice_count: usize,
+    max_unlisted_candidate_id: u32,
+    seed: u64,
+}
 
-    // Coordinator initializes a new poll.
-    let mut candidate_list = CandidateList::new();
-    for candidate in ["张三", "李四", "王五"] {
-        candidate_list.mut_candidate().push(candidate.to_string());
-    }
-    let poll_parameters =
-        coordinator::make_poll_parameters(&candidate_list, &counter_parameters)
-            .unwrap();
-
-    // Initialize all voters.
-    let mut voter_secret_list: Vec<VoterSecret> = vec![];
-    let mut voter_registration_list = vec![];
-    // Voter weight is the max voting power of each voter assigned by
-    // coordinator.
-    for blank_ballot in voter_weight_list {
-        let vote_secret = voter::make_voter_secret();
-        // Register a voter with coordinator.
-        let registration_request =
-            voter::make_registration_request(&vote_secret, &poll_parameters)
-                .unwrap();
-        let registration_response = coordinator::certify_voter(
-            &secret_key,
-            &registration_request,
-            blank_ballot,
-        )
-        .unwrap();
-        // Verify the blank ballot contained in registration_response.
-        assert!(voter::verify_blank_ballot(
-            &registration_request,
-            &registration_response
-        )
-        .unwrap());
-        voter_registration_list.push(registration_response);
-        voter_secret_list.push(vote_secret);
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        SimulationConfig {
+            counter_count: 3,
+            voter_count: 10,
+            candidate_count: 3,
+            weight_list: vec![100],
+            distribution: VoteDistribution::Random,
+            unlisted_choice_count: 0,
+            max_unlisted_candidate_id: 10,
+            seed: 1,
+        }
     }
-    println!(
-        "您的密文空白选票为：\n{:?}\n",
-        voter_registration_list[last_voter_id].get_ballot()
-    );
-    print_alert("注：投票者申请的密文空白选票中不包含投票者的身份及其权重。");
-    pause_cn();
-
-    println!(
-        "{} {} {} \n",
-        "【演示进度】 申请密文空白选票 ↦",
-        "<<投出对各候选人的密文选票>>".yellow(),
-        "↦ 验证密文选票 ↦ 联合计票并公布计票信息↦ 验证计票过程 ↦ 公布计票结果 \
-         ↦ 验证计票结果",
-    );
-
-    print_alert("现在请输入您对 张三 投票的票数：▼▼");
-    let candidate1_votes = wait_for_number_cn();
-    print_alert("现在请输入您对 李四 投票的票数：▼▼");
-    let candidate2_votes = wait_for_number_cn();
-    print_alert("现在请输入您对 王五 投票的票数：▼▼");
-    print_highlight(
-        "注：当投出的总票数大于密文空白选票权重时，会导致投票失败。",
-    );
-    let candidate3_votes = wait_for_number_cn();
-
-    // All voters vote.
-    let candidate1_default = 10;
-    let candidate2_default = 20;
-    let candidate3_default = 30;
-    let voting_ballot_count: Vec<Vec<u32>> = vec![
-        vec![candidate1_default, candidate2_default, candidate3_default],
-        vec![candidate1_default, candidate2_default, candidate3_default],
-        vec![candidate1_default, candidate2_default, candidate3_default],
-        vec![candidate1_votes, candidate2_votes, candidate3_votes],
-    ];
-
-    let mut vote_request_list = vec![];
-    let mut encrypted_vote_sum = VoteStorage::new();
-    for index in 0..voting_ballot_count.len() {
-        let vote_choices = voter::make_vote_choices(
-            &voting_ballot_count[index],
-            &candidate_list,
-        );
-        let vote_request = match voter::vote(
-            &voter_secret_list[index],
-            &vote_choices,
-            &voter_registration_list[index],
-            &poll_parameters,
-        ) {
-            Ok(v) => v,
-            Err(_) => {
-                println!(
-                    "\n投票失败：您投出的总票数超出您的权重限制：{} + {} + {} \
-                     > {}\n",
-                    candidate1_votes,
-                    candidate2_votes,
-                    candidate3_votes,
-                    last_voter_weight
-                );
-                return;
-            },
-        };
+}
 
-        // Coordinator aggregates individual ciphertext ballots.
-        coordinator::aggregate_vote_sum_response(
-            &poll_parameters,
-            &vote_request.get_vote(),
-            &mut encrypted_vote_sum,
-        )
-        .unwrap();
-        vote_request_list.push(vote_request);
-    }
-    println!(
-        "\n您投出的密文选票为：\n{:?}\n",
-        vote_request_list[last_voter_id].get_vote()
-    );
-    print_alert(
-        "注：投票者投出的密文选票中不包含投票者身份、候选人获得票数等信息。",
-    );
-    println!("假定其他投票者对三位候选人的投票均为[10，20，30]。");
-    pause_cn();
-
-    println!(
-        "{} {} {} \n",
-        "【演示进度】 申请密文空白选票 ↦ 投出对各候选人的密文选票 ↦",
-        "<<验证密文选票>>".yellow(),
-        "↦ 联合计票并公布计票信息↦ 验证计票过程 ↦ 公布计票结果 ↦ 验证计票结果",
-    );
-
-    println!(
-        "验证内容包括：\n1. 投给每个候选人的密文选票票数非负；\n2. \
-         每个密文选票格式正确（否则会导致后续计票失败）；\n3. \
-         投票者投出的密文选票票数之和小于等于其密文空白选票权重。"
-    );
-    print_alert("\n注：只有通过验证的密文选票，才会进入后续计票流程。");
-    pause_cn();
-
-    // Verifier verifies all ciphertext ballots.
-    for index in 0..vote_request_list.len() {
-        println!(
-            "对投票者{}的验证结果为：{}",
-            index,
-            if verifier::verify_vote_request(
-                &poll_parameters,
-                &vote_request_list[index],
-                &public_key,
-            )
-            .unwrap()
-            {
-                "✓"
-            } else {
-                "✗"
+impl SimulationConfig {
+    /// Parses the settings of a config file over the default settings.
+    fn parse(content: &str) -> Result<Self, String> {
+        let mut config = SimulationConfig::default();
+        for (line_index, line) in content.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
             }
-        );
-    }
-    pause_cn();
-
-    println!(
-        "{} {} {} \n",
-        "【演示进度】 申请密文空白选票 ↦ 投出对各候选人的密文选票 ↦ \
-         验证密文选票 ↦",
-        "<<联合计票并公布计票信息>>".yellow(),
-        "↦ 验证计票过程 ↦ 公布计票结果 ↦ 验证计票结果",
-    );
-
-    // All counters decrypt the poll result in a distributed manner.
-    let mut aggregated_decrypted_result = DecryptedResultPartStorage::new();
-    let mut partially_decrypted_result_list = vec![];
-    let mut counter_share_list = vec![];
-    for index in 0..counter_secret_list.len() {
-        let partially_decrypted_result = counter::count(
-            &counter_id_list[index],
-            &counter_secret_list[index],
-            &encrypted_vote_sum,
-        )
-        .unwrap();
-        let counter_share = bytes_to_point(
-            counter_parameters.get_counter_parameters_share()[index]
-                .get_poll_point_share(),
-        )
-        .unwrap();
-
-        // Coordinator aggregates parts of decrypted poll result.
-        assert_eq!(
-            true,
-            coordinator::aggregate_decrypted_part_sum(
-                &poll_parameters,
-                &partially_decrypted_result,
-                &mut aggregated_decrypted_result
-            )
-            .unwrap()
-        );
-        partially_decrypted_result_list.push(partially_decrypted_result);
-        counter_share_list.push(counter_share);
-    }
-    println!(
-        "所有计票者联合计票的聚合计票信息为：\n{:?}",
-        aggregated_decrypted_result
-    );
-    print_alert(
-        "注：计票者公布的聚合计票信息中无法反推出个人投票数等隐私信息。",
-    );
-    pause_cn();
-
-    println!(
-        "{} {} {} \n",
-        "【演示进度】 申请密文空白选票 ↦ 投出对各候选人的密文选票 ↦ \
-         验证密文选票 ↦ 联合计票并公布计票信息 ↦",
-        "<<验证计票过程>>".yellow(),
-        "↦ 公布计票结果 ↦ 验证计票结果",
-    );
-
-    println!(
-        "验证内容包括：\n
-         验证计票者公布的计票信息是使用正确的计票者密钥计算而得，\
-         而不是随意构造而得。\n"
-    );
-    for index in 0..counter_secret_list.len() {
-        println!(
-            "对计票者{}的验证结果为：{}",
-            index,
-            if verifier::verify_count_request(
-                &poll_parameters,
-                &encrypted_vote_sum,
-                &counter_share_list[index],
-                &partially_decrypted_result_list[index],
-            )
-            .unwrap()
-            {
-                "✓"
-            } else {
-                "✗"
+            let (key, value) = match line.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim()),
+                None => {
+                    return Err(format!(
+                        "line {}: expected `key = value`",
+                        line_index + 1
+                    ))
+                },
+            };
+            let invalid_value = || {
+                format!("line {}: invalid {} `{}`", line_index + 1, key, value)
+            };
+            match key {
+                "counters" => {
+                    config.counter_count =
+                        value.parse().map_err(|_| invalid_value())?
+                },
+                "voters" => {
+                    config.voter_count =
+                        value.parse().map_err(|_| invalid_value())?
+                },
+                "candidates" => {
+                    config.candidate_count =
+                        value.parse().map_err(|_| invalid_value())?
+                },
+                "weights" => {
+                    config.weight_list = value
+                        .split(',')
+                        .map(|weight| weight.trim().parse())
+                        .collect::<Result<_, _>>()
+                        .map_err(|_| invalid_value())?
+                },
+                "distribution" => {
+                    config.distribution = match value {
+                        "even" => VoteDistribution::Even,
+                        "single" => VoteDistribution::Single,
+                        "random" => VoteDistribution::Random,
+                        _ => return Err(invalid_value()),
+                    }
+                },
+                "unlisted_choices" => {
+                    config.unlisted_choice_count =
+                        value.parse().map_err(|_| invalid_value())?
+                },
+                "max_unlisted_candidate_id" => {
+                    config.max_unlisted_candidate_id =
+                        value.parse().map_err(|_| invalid_value())?
+                },
+                "seed" => {
+                    config.seed = value.parse().map_err(|_| invalid_value())?
+                },
+                _ => {
+                    return Err(format!(
+                        "line {}: unknown setting `{}`",
+                        line_index + 1,
+                        key
+                    ))
+                },
             }
-        );
-    }
-
-    pause_cn();
-
-    println!(
-        "{} {} {} \n",
-        "【演示进度】 申请密文空白选票 ↦ 投出对各候选人的密文选票 ↦ \
-         验证密文选票 ↦ 联合计票并公布计票信息 ↦ 验证计票过程 ↦",
-        "<<公布计票结果>>".yellow(),
-        "↦ 验证计票结果",
-    );
-
-    let max_vote_limit = 20000;
-    let vote_result = coordinator::finalize_vote_result(
-        &poll_parameters,
-        &encrypted_vote_sum,
-        &aggregated_decrypted_result,
-        max_vote_limit,
-    )
-    .unwrap();
-    for result in vote_result.get_result() {
-        if result.get_key() == POLL_RESULT_KEY_TOTAL_BALLOTS {
-            continue;
         }
-        println!("{} 最终得票为 {}", result.get_key(), result.get_value());
+        config.check()?;
+        Ok(config)
     }
 
-    // TODO: Refactor to use a more elegant way for the following code.
-    print_alert("\n对比传统明文计票结果：");
-    let plaintext_result1 = (candidate1_default
-        + candidate1_default
-        + candidate1_default
-        + candidate1_votes) as i64;
-    let plaintext_result2 = (candidate2_default
-        + candidate2_default
-        + candidate2_default
-        + candidate2_votes) as i64;
-    let plaintext_result3 = (candidate3_default
-        + candidate3_default
-        + candidate3_default
-        + candidate3_votes) as i64;
-    println!(
-        "张三得票：{} + {} + {} + {} = {}",
-        candidate1_default,
-        candidate1_default,
-        candidate1_default,
-        candidate1_votes,
-        plaintext_result1
-    );
-    println!(
-        "李四得票：{} + {} + {} + {} = {}",
-        candidate2_default,
-        candidate2_default,
-        candidate2_default,
-        candidate2_votes,
-        plaintext_result2
-    );
-    println!(
-        "王五得票：{} + {} + {} + {} = {}",
-        candidate3_default,
-        candidate3_default,
-        candidate3_default,
-        candidate3_votes,
-        plaintext_result3
-    );
-    assert!(
-        vote_result.get_result()[1].get_value() == plaintext_result1
-            && vote_result.get_result()[2].get_value() == plaintext_result2
-            && vote_result.get_result()[3].get_value() == plaintext_result3
-    );
-    print_alert("\n注：密文选票的计票结果与传统明文计票结果一致。");
-    pause_cn();
-
-    println!(
-        "{} {} \n",
-        "【演示进度】 申请密文空白选票 ↦ 投出对各候选人的密文选票 ↦ \
-         验证密文选票 ↦ 联合计票并公布计票信息 ↦ 验证计票过程 ↦ 公布计票结果 ↦",
-        "<<验证计票结果>>".yellow(),
-    );
-    println!(
-        "\n最终计票结果的有效性：{}",
-        if verifier::verify_vote_result(
-            &poll_parameters,
-            &encrypted_vote_sum,
-            &aggregated_decrypted_result,
-            &vote_result,
-        )
-        .unwrap()
+    fn check(&self) -> Result<(), String> {
+        if self.counter_count == 0
+            || self.voter_count == 0
+            || self.candidate_count == 0
         {
-            "✓"
-        } else {
-            "✗"
+            return Err(
+                "counters, voters and candidates must be positive".to_string()
+            );
         }
-    );
-    pause_cn();
-
-    print_alert("十分感谢您的试用！");
-    println!(
-        "\n{}\n\n{}\n{}\n",
-        "关于WeDPR，如需了解更多，欢迎通过以下方式联系我们",
-        "1. 微信公众号【微众银行区块链】",
-        "2. 官方邮箱【wedpr@webank.com】"
-    );
-    println!();
-}
-
-fn flow_en() {
-    print_wide(
-        "In this demo, you will experience how to use anonymous ciphertext \
-         voting, including: \n
-        1. A certified voter generates ciphertext ballots to protect real \
-         opinion. \n
-        2. A group of counters jointly decrypt and count the voting results. \n
-        3. A public verifier verifies the validity of the whole voting process \
-         via advance zero-knowledge proof (ZKP).",
-    );
-    print_wide(
-        "We use the following application scenario for easy demonstration of \
-         this new capability.",
-    );
-
-    println!(
-        "{}\n{}\n",
-        "[Background]".yellow(),
-        "4 voters vote for 3 candidates, where each voter can vote for \
-         multiple candidates as long as the total number of voted ballots are \
-         no more than its preassigned weight.",
-    );
-    println!(
-        "{}\n{}\n{}\n{}\n{}\n{}\n",
-        "[Story]".yellow(),
-        "1. A voter apply for certified blank ciphertext ballots.",
-        "2. A voter votes for chosen candidates and generates ZKP proofs.",
-        "3. A public verifier verifies the validity of voting via ZKP.",
-        "4. A group of counters jointly decrypt and count the voting results \
-         and generates ZKP proofs.",
-        "5. A public verifier verifies the validity of counting via ZKP."
-    );
-    pause_en();
-
-    println!(
-        "{} {} {}\n",
-        "[Demo progress]",
-        "<<Apply for certified blank ballots>>".yellow(),
-        "↦ Vote ↦ Verify voting ↦ Count jointly ↦ Verify counting ↦ Publish \
-         result ↦ Verify result",
-    );
-
-    print_alert(
-        "Please enter your voter weight: ▼▼ The voter weight specifies the \
-         max votes you can vote.",
-    );
-    print_highlight(
-        "In this demo, we use 100 as the weight limit, which could be \
-         extended for a higher limit in real applications. Please enter a \
-         integer number between 0 and 100.",
-    );
-    let last_voter_weight = wait_for_number_en();
-    let mut voter_weight_list = vec![100, 100, 100];
-    voter_weight_list.push(last_voter_weight as u32);
-    let last_voter_id = voter_weight_list.len() - 1;
-
-    // Initialize a group of counters.
-    let counter_id_list = vec!["1001", "1002", "1003"];
-    let mut counter_secret_list: Vec<CounterSecret> = vec![];
-    let mut counter_parameters = CounterParametersStorage::default();
-    for id in counter_id_list.clone() {
-        let counter_secret = counter::make_counter_secret();
-        let counter_parameters_share =
-            counter::make_parameters_share(id, &counter_secret).unwrap();
-        counter_parameters
-            .mut_counter_parameters_share()
-            .push(counter_parameters_share);
-        counter_secret_list.push(counter_secret);
+        if self.weight_list.is_empty() {
+            return Err("weights must not be empty".to_string());
+        }
+        if self.unlisted_choice_count > self.max_unlisted_candidate_id as usize
+        {
+            return Err("unlisted_choices must not exceed \
+                        max_unlisted_candidate_id"
+                .to_string());
+        }
+        Ok(())
     }
 
-    // Initialize the coordinator.
-    let (public_key, secret_key) = SIGNATURE.generate_keypair();
-
-    // Coordinator initializes a new poll.
-    let mut candidate_list = CandidateList::new();
-    for candidate in ["Kitten", "Doge", "Bunny"] {
-        candidate_list.mut_candidate().push(candidate.to_string());
+    fn is_unlisted(&self) -> bool {
+        self.unlisted_choice_count > 0
     }
-    let poll_parameters =
-        coordinator::make_poll_parameters(&candidate_list, &counter_parameters)
-            .unwrap();
-
-    // Initialize all voters.
-    let mut voter_secret_list: Vec<VoterSecret> = vec![];
-    let mut voter_registration_list = vec![];
-    // Voter weight is the max voting power of each voter assigned by
-    // coordinator.
-    for blank_ballot in voter_weight_list {
-        let vote_secret = voter::make_voter_secret();
-        // Register a voter with coordinator.
-        let registration_request =
-            voter::make_registration_request(&vote_secret, &poll_parameters)
-                .unwrap();
-        let registration_response = coordinator::certify_voter(
-            &secret_key,
-            &registration_request,
-            blank_ballot,
-        )
-        .unwrap();
-        // Verify the blank ballot contained in registration_response.
-        assert!(voter::verify_blank_ballot(
-            &registration_request,
-            &registration_response
-        )
-        .unwrap());
-        voter_registration_list.push(registration_response);
-        voter_secret_list.push(vote_secret);
-    }
-    println!(
-        "You will receive the following certified blank ballots: \n{:?}\n",
-        voter_registration_list[last_voter_id].get_ballot()
-    );
-    print_alert(
-        "Notice: No voter id or weight in certified blank ciphertext ballots.",
-    );
-    pause_en();
-
-    println!(
-        "{} {} {} \n",
-        "[Demo progress] Apply for certified blank ballots ↦",
-        "<<Vote>>".yellow(),
-        "↦ Verify voting ↦ Count jointly ↦ Verify counting ↦ Publish result ↦ \
-         Verify result",
-    );
-
-    print_alert("Please enter your votes for Kitten ▼▼");
-    let candidate1_votes = wait_for_number_en();
-    print_alert("Please enter your votes for Doge ▼▼");
-    let candidate2_votes = wait_for_number_en();
-    print_alert("Please enter your votes for Bunny ▼▼");
-    print_highlight(
-        "Notice: Your total votes cannot exceed your voter weight.",
-    );
-    let candidate3_votes = wait_for_number_en();
-
-    // All voters vote.
-    let candidate1_default = 10;
-    let candidate2_default = 20;
-    let candidate3_default = 30;
-    let voting_ballot_count: Vec<Vec<u32>> = vec![
-        vec![candidate1_default, candidate2_default, candidate3_default],
-        vec![candidate1_default, candidate2_default, candidate3_default],
-        vec![candidate1_default, candidate2_default, candidate3_default],
-        vec![candidate1_votes, candidate2_votes, candidate3_votes],
-    ];
-
-    let mut vote_request_list = vec![];
-    let mut encrypted_vote_sum = VoteStorage::new();
-    for index in 0..voting_ballot_count.len() {
-        let vote_choices = voter::make_vote_choices(
-            &voting_ballot_count[index],
-            &candidate_list,
-        );
-        let vote_request = match voter::vote(
-            &voter_secret_list[index],
-            &vote_choices,
-            &voter_registration_list[index],
-            &poll_parameters,
-        ) {
-            Ok(v) => v,
-            Err(_) => {
-                println!(
-                    "\nVote Failed: Your total votes has exceeded your voter \
-                     weight: {} + {} + {} > {}\n",
-                    candidate1_votes,
-                    candidate2_votes,
-                    candidate3_votes,
-                    last_voter_weight
-                );
-                return;
-            },
-        };
 
-        // Coordinator aggregates individual ciphertext ballots.
-        coordinator::aggregate_vote_sum_response(
-            &poll_parameters,
-            &vote_request.get_vote(),
-            &mut encrypted_vote_sum,
-        )
-        .unwrap();
-        vote_request_list.push(vote_request);
+    fn get_voter_weight(&self, voter_index: usize) -> u32 {
+        self.weight_list[voter_index % self.weight_list.len()]
     }
-    println!(
-        "\nYour voted ciphertext ballots are: \n{:?}\n",
-        vote_request_list[last_voter_id].get_vote()
-    );
-    print_alert(
-        "Notice: No voter id or candidate votes in ciphertext ballots.",
-    );
-    println!(
-        "For simplicity, we assume the other three voters voted [10, 20, 30] \
-         for our candidates."
-    );
-    pause_en();
-
-    println!(
-        "{} {} {} \n",
-        "[Demo progress] Apply for certified blank ballots ↦ Vote ↦",
-        "<<Verify voting>>".yellow(),
-        "↦ Count jointly ↦ Verify counting ↦ Publish result ↦ Verify result",
-    );
-
-    println!(
-        "Now verifying: \n1. All votes in ciphertext ballots is not negative \
-         numbers.\n2. All ciphertext ballots are in a valid format.\n3. The \
-         vote sum in ciphertext ballots from the same voter is no larger than \
-         its weight."
-    );
-    print_alert(
-        "\nNotice: Only verified ciphertext ballots will be later counted.",
-    );
-    pause_en();
-
-    // Verifier verifies all ciphertext ballots.
-    for index in 0..vote_request_list.len() {
-        println!(
-            "Voter {}'s voted ciphertext ballots: {}",
-            index,
-            if verifier::verify_vote_request(
-                &poll_parameters,
-                &vote_request_list[index],
-                &public_key,
-            )
-            .unwrap()
-            {
-                "✓"
-            } else {
-                "✗"
-            }
-        );
-    }
-    pause_en();
-
-    println!(
-        "{} {} {} \n",
-        "[Demo progress] Apply for certified blank ballots ↦ Vote ↦ Verify \
-         voting ↦",
-        "<<Count jointly>>".yellow(),
-        "↦ Verify counting ↦ Publish result ↦ Verify result",
-    );
-
-    // All counters decrypt the poll result in a distributed manner.
-    let mut aggregated_decrypted_result = DecryptedResultPartStorage::new();
-    let mut partially_decrypted_result_list = vec![];
-    let mut counter_share_list = vec![];
-    for index in 0..counter_secret_list.len() {
-        let partially_decrypted_result = counter::count(
-            &counter_id_list[index],
-            &counter_secret_list[index],
-            &encrypted_vote_sum,
-        )
-        .unwrap();
-        let counter_share = bytes_to_point(
-            counter_parameters.get_counter_parameters_share()[index]
-                .get_poll_point_share(),
-        )
-        .unwrap();
-
-        // Coordinator aggregates parts of decrypted poll result.
-        assert_eq!(
-            true,
-            coordinator::aggregate_decrypted_part_sum(
-                &poll_parameters,
-                &partially_decrypted_result,
-                &mut aggregated_decrypted_result
-            )
-            .unwrap()
-        );
-        partially_decrypted_result_list.push(partially_decrypted_result);
-        counter_share_list.push(counter_share);
+
+    fn get_total_weight(&self) -> i64 {
+        (0..self.voter_count)
+            .map(|voter_index| self.get_voter_weight(voter_index) as i64)
+            .sum()
     }
-    println!(
-        "Aggregated decrypted voting result is: \n{:?}",
-        aggregated_decrypted_result
-    );
-    print_alert(
-        "Notice: Individual votes cannot be recovered from aggregated \
-         decrypted voting result.",
-    );
-    pause_en();
-
-    println!(
-        "{} {} {} \n",
-        "[Demo progress] Apply for certified blank ballots ↦ Vote ↦ Verify \
-         voting ↦ Count jointly ↦",
-        "<<Verify counting>>".yellow(),
-        "↦ Publish result ↦ Verify result",
-    );
-
-    println!(
-        "Now verifying:\n1. All counters use the correct secret share to \
-         count partially decrypted voting result.\n"
-    );
-    for index in 0..counter_secret_list.len() {
-        println!(
-            "Counter {}'s partially decrypted voting result: {}",
-            index,
-            if verifier::verify_count_request(
-                &poll_parameters,
-                &encrypted_vote_sum,
-                &counter_share_list[index],
-                &partially_decrypted_result_list[index],
-            )
-            .unwrap()
-            {
-                "✓"
-            } else {
-                "✗"
-            }
-        );
+}
+
+// Xorshift generator of the simulated vote choices, so that a simulation can
+// be replayed with the same seed.
+struct ChoiceGenerator(u64);
+
+impl ChoiceGenerator {
+    fn new(seed: u64) -> Self {
+        // Xorshift never leaves the zero state.
+        ChoiceGenerator(seed.max(1))
     }
 
-    pause_en();
+    fn next_below(&mut self, bound: u64) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0 % bound
+    }
+}
 
-    println!(
-        "{} {} {} \n",
-        "[Demo progress] Apply for certified blank ballots ↦ Vote ↦ Verify \
-         voting ↦ Count jointly ↦ Verify counting ↦",
-        "<<Publish result>>".yellow(),
-        "↦ Verify result",
-    );
+/// Plaintext vote choices of a simulated voter.
+struct SimulatedChoices {
+    value_list: Vec<u32>,
+    unlisted_value_list: Vec<(u32, u32)>,
+}
 
-    let max_vote_limit = 20000;
-    let vote_result = coordinator::finalize_vote_result(
-        &poll_parameters,
-        &encrypted_vote_sum,
-        &aggregated_decrypted_result,
-        max_vote_limit,
-    )
-    .unwrap();
-    for result in vote_result.get_result() {
-        if result.get_key() == POLL_RESULT_KEY_TOTAL_BALLOTS {
-            continue;
+impl SimulatedChoices {
+    fn generate(
+        config: &SimulationConfig,
+        generator: &mut ChoiceGenerator,
+        voter_index: usize,
+    ) -> Self {
+        let weight = config.get_voter_weight(voter_index);
+        let candidate_count = config.candidate_count;
+        let value_list = if config.is_unlisted() {
+            // Unbounded votes are either the whole weight or nothing.
+            (0..candidate_count)
+                .map(|i| match config.distribution {
+                    VoteDistribution::Even => weight,
+                    VoteDistribution::Single => {
+                        if i == voter_index % candidate_count {
+                            weight
+                        } else {
+                            0
+                        }
+                    },
+                    VoteDistribution::Random => {
+                        weight * generator.next_below(2) as u32
+                    },
+                })
+                .collect()
+        } else {
+            let mut unused_weight = weight;
+            (0..candidate_count)
+                .map(|i| {
+                    let value = match config.distribution {
+                        VoteDistribution::Even => {
+                            weight / candidate_count as u32
+                        },
+                        VoteDistribution::Single => {
+                            if i == voter_index % candidate_count {
+                                weight
+                            } else {
+                                0
+                            }
+                        },
+                        VoteDistribution::Random => generator
+                            .next_below(unused_weight as u64 + 1)
+                            as u32,
+                    };
+                    unused_weight -= value;
+                    value
+                })
+                .collect()
+        };
+        // Unlisted candidate ids are consecutive from a random start.
+        let max_candidate_id = config.max_unlisted_candidate_id;
+        let first_candidate_id = if config.is_unlisted() {
+            generator.next_below(max_candidate_id as u64) as u32
+        } else {
+            0
+        };
+        let unlisted_value_list = (0..config.unlisted_choice_count)
+            .map(|i| {
+                let candidate_id =
+                    (first_candidate_id + i as u32) % max_candidate_id + 1;
+                let value = match config.distribution {
+                    VoteDistribution::Even => weight,
+                    VoteDistribution::Single => {
+                        if i == 0 {
+                            weight
+                        } else {
+                            0
+                        }
+                    },
+                    VoteDistribution::Random => {
+                        weight * generator.next_below(2) as u32
+                    },
+                };
+                (candidate_id, value)
+            })
+            .collect();
+        SimulatedChoices {
+            value_list,
+            unlisted_value_list,
         }
-        println!("{}: total votes = {}", result.get_key(), result.get_value());
     }
 
-    // TODO: Refactor to use a more elegant way for the following code.
-    print_alert("\nCompare with traditional plaintext counting:");
-    let plaintext_result1 = (candidate1_default
-        + candidate1_default
-        + candidate1_default
-        + candidate1_votes) as i64;
-    let plaintext_result2 = (candidate2_default
-        + candidate2_default
-        + candidate2_default
-        + candidate2_votes) as i64;
-    let plaintext_result3 = (candidate3_default
-        + candidate3_default
-        + candidate3_default
-        + candidate3_votes) as i64;
-    println!(
-        "Kitten: {} + {} + {} + {} = {}",
-        candidate1_default,
-        candidate1_default,
-        candidate1_default,
-        candidate1_votes,
-        plaintext_result1
-    );
-    println!(
-        "Doge: {} + {} + {} + {} = {}",
-        candidate2_default,
-        candidate2_default,
-        candidate2_default,
-        candidate2_votes,
-        plaintext_result2
-    );
-    println!(
-        "Bunny: {} + {} + {} + {} = {}",
-        candidate3_default,
-        candidate3_default,
-        candidate3_default,
-        candidate3_votes,
-        plaintext_result3
-    );
-    assert!(
-        vote_result.get_result()[1].get_value() == plaintext_result1
-            && vote_result.get_result()[2].get_value() == plaintext_result2
-            && vote_result.get_result()[3].get_value() == plaintext_result3
-    );
-    print_alert(
-        "\nNotice: ciphertext ballot counting yield the same result as \
-         traditional plaintext counting.",
-    );
-    pause_en();
-
-    println!(
-        "{} {} \n",
-        "[Demo progress] Apply for certified blank ballots ↦ Vote ↦ Verify \
-         voting ↦ Count jointly ↦ Verify counting ↦ Publish result ↦",
-        "<<Verify result>>".yellow(),
-    );
-    println!(
-        "\nFinal voting result's validity: {}",
-        if verifier::verify_vote_result(
-            &poll_parameters,
-            &encrypted_vote_sum,
-            &aggregated_decrypted_result,
-            &vote_result,
-        )
-        .unwrap()
-        {
-            "✓"
-        } else {
-            "✗"
+    fn to_vote_choices(&self, candidate_list: &CandidateList) -> VoteChoices {
+        let mut vote_choices =
+            voter::make_vote_choices(&self.value_list, candidate_list);
+        for (candidate_id, value) in &self.unlisted_value_list {
+            let mut unlisted_choice = UnlistedVoteChoice::new();
+            unlisted_choice.set_candidate_id(*candidate_id);
+            unlisted_choice.set_value(*value);
+            vote_choices.mut_unlisted_choice().push(unlisted_choice);
         }
-    );
-    pause_en();
-
-    print_alert("Thank you for your time!");
-    println!(
-        "\n{}\n\n{}\n",
-        "Welcome to contact us for more information about WeDPR by the \
-         following Email:",
-        "wedpr@webank.com"
-    );
-    println!();
+        vote_choices
+    }
 }
 
-// Utility functions
-// TODO: Extract those common functions to solution utility.
-fn print_highlight(message: &str) {
-    println!("{}", message.green());
+/// Time and message sizes of a protocol phase.
+struct PhaseReport {
+    name: &'static str,
+    elapsed: Duration,
+    message_size_list: Vec<usize>,
 }
 
-fn print_highlight2(message1: &str, message2: &str) {
-    println!("{}\n{}", message1.green(), message2.green());
+/// Runs the phases of a simulated poll and records their reports.
+struct Simulation {
+    report_list: Vec<PhaseReport>,
 }
 
-fn print_alert(message: &str) {
-    println!("{}", message.yellow());
-}
+impl Simulation {
+    // Runs a phase, which returns its output and the sizes of the messages it
+    // produces.
+    fn run_phase<T, F>(
+        &mut self,
+        name: &'static str,
+        phase: F,
+    ) -> Result<T, String>
+    where
+        F: FnOnce() -> Result<(T, Vec<usize>), WedprError>,
+    {
+        let start = Instant::now();
+        let (output, message_size_list) =
+            phase().map_err(|e| format!("{} failed: {:?}", name, e))?;
+        self.report_list.push(PhaseReport {
+            name,
+            elapsed: start.elapsed(),
+            message_size_list,
+        });
+        Ok(output)
+    }
 
-fn print_alert2(message1: &str, message2: &str) {
-    println!("{}\n{}", message1.yellow(), message2.yellow());
+    fn print_reports(&self) {
+        println!(
+            "{:<20} {:>12} {:>10} {:>14} {:>12}",
+            "phase", "time (ms)", "messages", "total bytes", "avg bytes"
+        );
+        for report in &self.report_list {
+            let message_count = report.message_size_list.len();
+            let total_size: usize = report.message_size_list.iter().sum();
+            println!(
+                "{:<20} {:>12.3} {:>10} {:>14} {:>12}",
+                report.name,
+                report.elapsed.as_secs_f64() * 1000.0,
+                message_count,
+                total_size,
+                if message_count == 0 {
+                    0
+                } else {
+                    total_size / message_count
+                }
+            );
+        }
+    }
 }
 
-fn print_wide(message: &str) {
-    println!("\n{}\n", message);
+// Requires a verification to pass.
+fn check_verified(
+    verify_result: Result<bool, WedprError>,
+) -> Result<(), WedprError> {
+    if verify_result? {
+        Ok(())
+    } else {
+        Err(WedprError::VerificationError)
+    }
 }
 
-fn wait_for_input() -> String {
-    let mut input = String::new();
-    std::io::stdin()
-        .read_line(&mut input)
-        .expect("Failed to read line.");
-    input.trim().to_string()
+// Secrets of a registered voter. The zero secret is only used by unbounded
+// voting.
+struct VoterSecretPair {
+    weight_secret: VoterSecret,
+    zero_secret: VoterSecret,
 }
 
-// In this demo, we set the upper limit of input value to 100.
-const MAX_INPUT_VALUE: i32 = 100;
-
-fn wait_for_number(error_message: &str) -> u32 {
-    let mut input = wait_for_input();
-    let mut input_num = input.parse::<i32>();
-    loop {
-        match input_num {
-            // TODO: Enable negative input in the demo.
-            Ok(v) if (v >= 0) && (v <= MAX_INPUT_VALUE) => return v as u32,
-            _ => {
-                print_alert(error_message);
-                input = wait_for_input();
-                input_num = input.parse::<i32>();
-            },
-        }
+fn make_voter_secret_pair() -> VoterSecretPair {
+    let mut zero_secret = VoterSecret::new();
+    zero_secret.set_voter_secret(scalar_to_bytes(&get_random_scalar()));
+    VoterSecretPair {
+        weight_secret: voter::make_voter_secret(),
+        zero_secret,
     }
 }
 
-fn wait_for_number_cn() -> u32 {
-    wait_for_number("请输入有效数字：")
-}
+fn simulate(
+    config: &SimulationConfig,
+    simulation: &mut Simulation,
+) -> Result<VoteResultStorage, String> {
+    let mut generator = ChoiceGenerator::new(config.seed);
+    let mut candidate_list = CandidateList::new();
+    for i in 0..config.candidate_count {
+        candidate_list
+            .mut_candidate()
+            .push(format!("candidate-{}", i + 1));
+    }
+    let counter_id_list: Vec<String> = (0..config.counter_count)
+        .map(|i| format!("counter-{}", i + 1))
+        .collect();
+    let max_vote_limit = config.get_total_weight();
+    let (public_key, secret_key) = SIGNATURE.generate_keypair();
 
-fn wait_for_number_en() -> u32 {
-    wait_for_number("Please input a valid number:")
+    // Initialize the counters and the poll.
+    let (counter_secret_list, counter_parameters, poll_parameters) = simulation
+        .run_phase("setup", || {
+            let mut counter_secret_list = vec![];
+            let mut counter_parameters = CounterParametersStorage::new();
+            let mut message_size_list = vec![];
+            for counter_id in &counter_id_list {
+                let counter_secret = counter::make_counter_secret();
+                let counter_parameters_share = counter::make_parameters_share(
+                    counter_id,
+                    &counter_secret,
+                )?;
+                message_size_list
+                    .push(proto_to_bytes(&counter_parameters_share)?.len());
+                counter_parameters
+                    .mut_counter_parameters_share()
+                    .push(counter_parameters_share);
+                counter_secret_list.push(counter_secret);
+            }
+            let poll_parameters = if config.is_unlisted() {
+                coordinator::make_poll_parameters_unlisted(
+                    &candidate_list,
+                    &counter_parameters,
+                    &secret_key,
+                    1,
+                    config.max_unlisted_candidate_id,
+                )?
+            } else {
+                coordinator::make_poll_parameters(
+                    &candidate_list,
+                    &counter_parameters,
+                    &secret_key,
+                )?
+            };
+            message_size_list.push(proto_to_bytes(&poll_parameters)?.len());
+            Ok((
+                (counter_secret_list, counter_parameters, poll_parameters),
+                message_size_list,
+            ))
+        })?;
+
+    // Register the voters.
+    let voter_list: Vec<(VoterSecretPair, RegistrationResponse)> =
+        simulation.run_phase("registration", || {
+            let mut voter_list = vec![];
+            let mut message_size_list = vec![];
+            for voter_index in 0..config.voter_count {
+                let secret_pair = make_voter_secret_pair();
+                let registration_request = if config.is_unlisted() {
+                    voter::make_unbounded_registration_request(
+                        &secret_pair.zero_secret,
+                        &secret_pair.weight_secret,
+                        &poll_parameters,
+                        &public_key,
+                    )?
+                } else {
+                    voter::make_registration_request(
+                        &secret_pair.weight_secret,
+                        &poll_parameters,
+                        &public_key,
+                    )?
+                };
+                let voter_weight = config.get_voter_weight(voter_index);
+                let registration_response = if config.is_unlisted() {
+                    coordinator::certify_unbounded_voter(
+                        &poll_parameters,
+                        &secret_key,
+                        &registration_request,
+                        voter_weight,
+                    )?
+                } else {
+                    coordinator::certify_voter(
+                        &poll_parameters,
+                        &secret_key,
+                        &registration_request,
+                        voter_weight,
+                    )?
+                };
+                check_verified(voter::verify_blank_ballot(
+                    &registration_request,
+                    &registration_response,
+                ))?;
+                message_size_list
+                    .push(proto_to_bytes(&registration_request)?.len());
+                message_size_list
+                    .push(proto_to_bytes(&registration_response)?.len());
+                voter_list.push((secret_pair, registration_response));
+            }
+            Ok((voter_list, message_size_list))
+        })?;
+
+    // Vote with the simulated choices.
+    let choices_list: Vec<SimulatedChoices> = (0..config.voter_count)
+        .map(|voter_index| {
+            SimulatedChoices::generate(config, &mut generator, voter_index)
+        })
+        .collect();
+    let vote_request_list: Vec<VoteRequest> =
+        simulation.run_phase("voting", || {
+            let mut vote_request_list = vec![];
+            let mut message_size_list = vec![];
+            for ((secret_pair, registration_response), choices) in
+                voter_list.iter().zip(&choices_list)
+            {
+                let vote_choices = choices.to_vote_choices(&candidate_list);
+                let vote_request = if config.is_unlisted() {
+                    voter::vote_unbounded_unlisted(
+                        &secret_pair.weight_secret,
+                        &secret_pair.zero_secret,
+                        &vote_choices,
+                        registration_response,
+                        &poll_parameters,
+                        &public_key,
+                    )?
+                } else {
+                    voter::vote(
+                        &secret_pair.weight_secret,
+                        &vote_choices,
+                        registration_response,
+                        &poll_parameters,
+                        &public_key,
+                    )?
+                };
+                message_size_list.push(proto_to_bytes(&vote_request)?.len());
+                vote_request_list.push(vote_request);
+            }
+            Ok((vote_request_list, message_size_list))
+        })?;
+    simulation.run_phase("vote verification", || {
+        for vote_request in &vote_request_list {
+            check_verified(if config.is_unlisted() {
+                verifier::verify_unbounded_vote_request_unlisted(
+                    &poll_parameters,
+                    vote_request,
+                    &public_key,
+                )
+            } else {
+                verifier::verify_vote_request(
+                    &poll_parameters,
+                    vote_request,
+                    &public_key,
+                )
+            })?;
+        }
+        Ok(((), vec![]))
+    })?;
+
+    // Aggregate the votes.
+    let vote_list: Vec<VoteStorage> = vote_request_list
+        .iter()
+        .map(|vote_request| vote_request.get_vote().clone())
+        .collect();
+    let vote_sum = simulation.run_phase("aggregation", || {
+        let mut vote_sum = VoteStorage::new();
+        for vote in &vote_list {
+            check_verified(if config.is_unlisted() {
+                coordinator::aggregate_vote_sum_response_unlisted(
+                    &poll_parameters,
+                    vote,
+                    &mut vote_sum,
+                )
+            } else {
+                coordinator::aggregate_vote_sum_response(
+                    &poll_parameters,
+                    vote,
+                    &mut vote_sum,
+                )
+            })?;
+        }
+        let vote_sum_size = proto_to_bytes(&vote_sum)?.len();
+        Ok((vote_sum, vec![vote_sum_size]))
+    })?;
+
+    // Count the votes jointly.
+    let decrypted_part_list = simulation.run_phase("counting", || {
+        let mut decrypted_part_list = vec![];
+        let mut message_size_list = vec![];
+        for (counter_id, counter_secret) in
+            counter_id_list.iter().zip(&counter_secret_list)
+        {
+            let decrypted_part = if config.is_unlisted() {
+                counter::count_unlisted(counter_id, counter_secret, &vote_sum)?
+            } else {
+                counter::count(counter_id, counter_secret, &vote_sum)?
+            };
+            message_size_list.push(proto_to_bytes(&decrypted_part)?.len());
+            decrypted_part_list.push(decrypted_part);
+        }
+        Ok((decrypted_part_list, message_size_list))
+    })?;
+    simulation.run_phase("count verification", || {
+        for (counter_parameters_share, decrypted_part) in counter_parameters
+            .get_counter_parameters_share()
+            .iter()
+            .zip(&decrypted_part_list)
+        {
+            let counter_share = bytes_to_point(
+                counter_parameters_share.get_poll_point_share(),
+            )?;
+            check_verified(if config.is_unlisted() {
+                verifier::verify_count_request_unlisted(
+                    &poll_parameters,
+                    &counter_share,
+                    &vote_sum,
+                    decrypted_part,
+                    &public_key,
+                )
+            } else {
+                verifier::verify_count_request(
+                    &poll_parameters,
+                    counter_parameters_share.get_counter_id(),
+                    &vote_sum,
+                    decrypted_part,
+                    &public_key,
+                )
+            })?;
+        }
+        Ok(((), vec![]))
+    })?;
+    let (aggregated_decrypted_result, vote_result) =
+        simulation.run_phase("finalization", || {
+            let mut aggregated_decrypted_result =
+                DecryptedResultPartStorage::new();
+            for (counter_id, decrypted_part) in
+                counter_id_list.iter().zip(&decrypted_part_list)
+            {
+                check_verified(if config.is_unlisted() {
+                    coordinator::aggregate_decrypted_part_sum_unlisted(
+                        &poll_parameters,
+                        decrypted_part,
+                        &mut aggregated_decrypted_result,
+                    )
+                } else {
+                    coordinator::aggregate_decrypted_part_sum(
+                        &poll_parameters,
+                        counter_id,
+                        &vote_sum,
+                        decrypted_part,
+                        &mut aggregated_decrypted_result,
+                    )
+                    .map(|blame_report| blame_report.is_none())
+                })?;
+            }
+            let vote_result = if config.is_unlisted() {
+                coordinator::finalize_vote_result_unlisted(
+                    &poll_parameters,
+                    &vote_sum,
+                    &mut aggregated_decrypted_result,
+                    max_vote_limit,
+                    config.max_unlisted_candidate_id as i64,
+                )?
+            } else {
+                coordinator::finalize_vote_result(
+                    &poll_parameters,
+                    &vote_sum,
+                    &aggregated_decrypted_result,
+                    max_vote_limit,
+                )?
+            };
+            let vote_result_size = proto_to_bytes(&vote_result)?.len();
+            Ok(((aggregated_decrypted_result, vote_result), vec![
+                vote_result_size,
+            ]))
+        })?;
+    simulation.run_phase("result verification", || {
+        check_verified(verifier::verify_vote_result(
+            &poll_parameters,
+            &vote_sum,
+            vote_sum.get_vote_root(),
+            &vote_list,
+            &aggregated_decrypted_result,
+            &vote_result,
+            &public_key,
+        ))?;
+        Ok(((), vec![]))
+    })?;
+    check_expected_result(
+        config,
+        &poll_parameters,
+        &choices_list,
+        &vote_result,
+    )?;
+    Ok(vote_result)
 }
 
-fn pause(info_message: &str) {
-    let mut enter_continue = String::new();
-    print_wide(info_message);
-    std::io::stdin()
-        .read_line(&mut enter_continue)
-        .expect("read_line should not fail");
-    println!("... {}\n", enter_continue.trim());
+// Checks the vote result against the plaintext choices of all voters.
+fn check_expected_result(
+    config: &SimulationConfig,
+    poll_parameters: &PollParametersStorage,
+    choices_list: &[SimulatedChoices],
+    vote_result: &VoteResultStorage,
+) -> Result<(), String> {
+    let total_weight = config.get_total_weight();
+    let mut expected_result = BTreeMap::new();
+    expected_result
+        .insert(POLL_RESULT_KEY_TOTAL_BALLOTS.to_string(), total_weight);
+    let mut voted_weight = 0;
+    for (i, candidate) in poll_parameters
+        .get_candidates()
+        .get_candidate()
+        .iter()
+        .enumerate()
+    {
+        let candidate_votes: i64 = choices_list
+            .iter()
+            .map(|choices| choices.value_list[i] as i64)
+            .sum();
+        voted_weight += candidate_votes;
+        expected_result.insert(candidate.to_string(), candidate_votes);
+    }
+    if !config.is_unlisted() {
+        expected_result.insert(
+            POLL_RESULT_KEY_ABSTAINED_BALLOTS.to_string(),
+            total_weight - voted_weight,
+        );
+    }
+    let result: BTreeMap<String, i64> = vote_result
+        .get_result()
+        .iter()
+        .map(|pair| (pair.get_key().to_string(), pair.get_value()))
+        .collect();
+    if result != expected_result {
+        return Err("the vote result does not match the choices".to_string());
+    }
+
+    let mut expected_unlisted_result: BTreeMap<u32, i64> = BTreeMap::new();
+    for choices in choices_list {
+        for (candidate_id, value) in &choices.unlisted_value_list {
+            *expected_unlisted_result.entry(*candidate_id).or_insert(0) +=
+                *value as i64;
+        }
+    }
+    let unlisted_result: BTreeMap<u32, i64> = vote_result
+        .get_unlisted_result()
+        .iter()
+        .map(|choice| (choice.get_candidate_id(), choice.get_value() as i64))
+        .collect();
+    // Unlisted candidates voted with nothing may be left out of the result.
+    expected_unlisted_result.retain(|candidate_id, value| {
+        *value != 0 || unlisted_result.contains_key(candidate_id)
+    });
+    if unlisted_result != expected_unlisted_result {
+        return Err(
+            "the unlisted vote result does not match the choices".to_string()
+        );
+    }
+    Ok(())
 }
 
-fn pause_cn() {
-    pause("按任意键继续...");
+fn print_vote_result(vote_result: &VoteResultStorage) {
+    println!("{:<32} {:>12}", "candidate", "votes");
+    for pair in vote_result.get_result() {
+        println!("{:<32} {:>12}", pair.get_key(), pair.get_value());
+    }
+    for choice in vote_result.get_unlisted_result() {
+        println!(
+            "{:<32} {:>12}",
+            format!("unlisted candidate {}", choice.get_candidate_id()),
+            choice.get_value()
+        );
+    }
 }
 
-fn pause_en() {
-    pause("Press any key to continue...");
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    if args.len() > 2 {
+        eprintln!("Usage: {} [config_file]", args[0]);
+        process::exit(2);
+    }
+    let config_result = match args.get(1) {
+        Some(config_file) => match fs::read_to_string(config_file) {
+            Ok(content) => SimulationConfig::parse(&content),
+            Err(e) => Err(format!("failed to read {}: {}", config_file, e)),
+        },
+        None => Ok(SimulationConfig::default()),
+    };
+    let config = match config_result {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("Invalid config: {}", e);
+            process::exit(2);
+        },
+    };
+    println!("{:?}\n", config);
+
+    let mut simulation = Simulation {
+        report_list: vec![],
+    };
+    let simulation_result = simulate(&config, &mut simulation);
+    simulation.print_reports();
+    println!();
+    match simulation_result {
+        Ok(vote_result) => {
+            print_vote_result(&vote_result);
+            println!("\nThe vote result is verified.");
+        },
+        Err(e) => {
+            println!("The simulation failed: {}", e);
+            process::exit(1);
+        },
+    }
 }
@@ -0,0 +1,896 @@
+// Copyright 2021 WeDPR Lab Project Authors. Licensed under Apache-2.0.
+
+//! Library for managing the lifecycle of a poll.
+
+use wedpr_l_crypto_zkp_utils::bytes_to_point;
+use wedpr_l_utils::error::WedprError;
+use wedpr_s_protos::{
+    bytes_to_proto,
+    generated::acv::{
+        CandidateList, CounterParametersShareRequest,
+        DecryptedResultPartStorage, PollParametersStorage, PollPhase,
+        PollState, RegistrationRequest, RegistrationResponse, SignatureScheme,
+        VoteRequest, VoteResultStorage, VoteStorage,
+    },
+    proto_to_bytes,
+};
+
+use crate::{coordinator, verifier};
+
+/// Poll managed by the coordinator, which enforces the phases of
+/// setup -> registration -> voting -> counting -> finalized. Operations called
+/// outside their phase are rejected with an argument error, while invalid
+/// requests from voters and counters are rejected by returning false.
+/// A phase with a deadline rejects operations after the deadline, and can only
+/// be closed after the deadline.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Poll {
+    state: PollState,
+}
+
+impl Poll {
+    /// Creates a poll in the setup phase. The deadlines are in seconds since
+    /// the Unix epoch, and 0 means no deadline.
+    pub fn new(
+        candidate_list: &CandidateList,
+        registration_deadline: u64,
+        voting_deadline: u64,
+    ) -> Result<Self, WedprError> {
+        if registration_deadline != 0
+            && voting_deadline != 0
+            && voting_deadline < registration_deadline
+        {
+            return Err(WedprError::ArgumentError);
+        }
+        let mut state = PollState::new();
+        state.set_phase(PollPhase::SETUP);
+        state.set_candidates(candidate_list.clone());
+        state.set_registration_deadline(registration_deadline);
+        state.set_voting_deadline(voting_deadline);
+        Ok(Poll { state })
+    }
+
+    /// Restores a poll from its persistent state.
+    pub fn from_state(state: PollState) -> Self {
+        Poll { state }
+    }
+
+    /// Restores a poll from the bytes of its persistent state.
+    pub fn from_bytes(state_bytes: &[u8]) -> Result<Self, WedprError> {
+        Ok(Poll {
+            state: bytes_to_proto::<PollState>(state_bytes)?,
+        })
+    }
+
+    /// Encodes the persistent state of the poll to bytes.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, WedprError> {
+        proto_to_bytes(&self.state)
+    }
+
+    pub fn get_state(&self) -> &PollState {
+        &self.state
+    }
+
+    pub fn get_phase(&self) -> PollPhase {
+        self.state.get_phase()
+    }
+
+    pub fn get_poll_parameters(&self) -> &PollParametersStorage {
+        self.state.get_poll_parameters()
+    }
+
+    pub fn get_vote_sum(&self) -> &VoteStorage {
+        self.state.get_vote_sum()
+    }
+
+    /// Adds the parameters share of a counter in the setup phase. It returns
+    /// false if the counter has been added.
+    pub fn add_counter(
+        &mut self,
+        counter_parameters_share: &CounterParametersShareRequest,
+    ) -> Result<bool, WedprError> {
+        self.check_phase(PollPhase::SETUP)?;
+        bytes_to_point(counter_parameters_share.get_poll_point_share())?;
+        if self
+            .state
+            .get_counter_parameters()
+            .get_counter_parameters_share()
+            .iter()
+            .any(|share| {
+                share.get_counter_id()
+                    == counter_parameters_share.get_counter_id()
+            })
+        {
+            return Ok(false);
+        }
+        self.state
+            .mut_counter_parameters()
+            .mut_counter_parameters_share()
+            .push(counter_parameters_share.clone());
+        Ok(true)
+    }
+
+    /// Selects the signature scheme used to certify voters in the setup
+    /// phase.
+    pub fn set_signature_scheme(
+        &mut self,
+        signature_scheme: SignatureScheme,
+    ) -> Result<(), WedprError> {
+        self.check_phase(PollPhase::SETUP)?;
+        self.state.set_signature_scheme(signature_scheme);
+        Ok(())
+    }
+
+    /// Closes the setup phase and makes the poll parameters from all added
+    /// counters, signed by the coordinator with the selected signature
+    /// scheme.
+    pub fn start_registration(
+        &mut self,
+        secret_key: &[u8],
+    ) -> Result<&PollParametersStorage, WedprError> {
+        self.check_phase(PollPhase::SETUP)?;
+        if self
+            .state
+            .get_counter_parameters()
+            .get_counter_parameters_share()
+            .is_empty()
+        {
+            wedpr_println!("no counter has been added");
+            return Err(WedprError::ArgumentError);
+        }
+        let mut poll_parameters = coordinator::make_unsigned_poll_parameters(
+            self.state.get_candidates(),
+            self.state.get_counter_parameters(),
+        )?;
+        poll_parameters.set_signature_scheme(self.state.get_signature_scheme());
+        coordinator::sign_poll_parameters(&mut poll_parameters, secret_key)?;
+        self.state.set_poll_parameters(poll_parameters);
+        self.state.set_phase(PollPhase::REGISTRATION);
+        Ok(self.state.get_poll_parameters())
+    }
+
+    /// Certifies a voter in the registration phase. A registration request
+    /// whose blank ballot has been certified is rejected.
+    pub fn register_voter(
+        &mut self,
+        secret_key: &[u8],
+        registration_request: &RegistrationRequest,
+        voter_weight: u32,
+        timestamp: u64,
+    ) -> Result<RegistrationResponse, WedprError> {
+        self.check_registration(registration_request, timestamp)?;
+        let registration_response = coordinator::certify_voter(
+            self.get_poll_parameters(),
+            secret_key,
+            registration_request,
+            voter_weight,
+        )?;
+        self.state
+            .mut_registration()
+            .push(registration_response.clone());
+        Ok(registration_response)
+    }
+
+    /// Certifies a voter with a confidential weight in the registration
+    /// phase, so the stored registration does not reveal the weight.
+    pub fn register_voter_confidential(
+        &mut self,
+        secret_key: &[u8],
+        registration_request: &RegistrationRequest,
+        voter_weight: u32,
+        timestamp: u64,
+    ) -> Result<RegistrationResponse, WedprError> {
+        self.check_registration(registration_request, timestamp)?;
+        let registration_response = coordinator::certify_voter_confidential(
+            self.get_poll_parameters(),
+            secret_key,
+            registration_request,
+            voter_weight,
+        )?;
+        self.state
+            .mut_registration()
+            .push(registration_response.clone());
+        Ok(registration_response)
+    }
+
+    /// Certifies an unbounded voter in the registration phase, who votes with
+    /// vote_unbounded.
+    pub fn register_unbounded_voter(
+        &mut self,
+        secret_key: &[u8],
+        registration_request: &RegistrationRequest,
+        voter_weight: u32,
+        timestamp: u64,
+    ) -> Result<RegistrationResponse, WedprError> {
+        self.check_registration(registration_request, timestamp)?;
+        let registration_response = coordinator::certify_unbounded_voter(
+            self.get_poll_parameters(),
+            secret_key,
+            registration_request,
+            voter_weight,
+        )?;
+        self.state
+            .mut_registration()
+            .push(registration_response.clone());
+        Ok(registration_response)
+    }
+
+    /// Closes the registration phase and starts voting.
+    pub fn start_voting(&mut self, timestamp: u64) -> Result<(), WedprError> {
+        self.check_phase(PollPhase::REGISTRATION)?;
+        check_after_deadline(
+            self.state.get_registration_deadline(),
+            timestamp,
+        )?;
+        self.state.set_phase(PollPhase::VOTING);
+        Ok(())
+    }
+
+    /// Verifies a vote request and aggregates it into the vote sum in the
+    /// voting phase. A vote linked by the nullifier of an accepted vote
+    /// replaces it, so that the last vote of a voter counts. It returns false
+    /// without updating the poll if the vote request is invalid, or cannot
+    /// replace the accepted vote of the voter.
+    pub fn vote(
+        &mut self,
+        public_key: &[u8],
+        vote_request: &VoteRequest,
+        timestamp: u64,
+    ) -> Result<bool, WedprError> {
+        self.check_phase(PollPhase::VOTING)?;
+        check_before_deadline(self.state.get_voting_deadline(), timestamp)?;
+        if !verifier::verify_vote_request(
+            self.state.get_poll_parameters(),
+            vote_request,
+            public_key,
+        )
+        .unwrap_or(false)
+        {
+            return Ok(false);
+        }
+        self.accept_vote(vote_request.get_vote())
+    }
+
+    /// Verifies a vote request of an unbounded voter and aggregates it into
+    /// the vote sum in the voting phase, as vote does.
+    pub fn vote_unbounded(
+        &mut self,
+        public_key: &[u8],
+        vote_request: &VoteRequest,
+        timestamp: u64,
+    ) -> Result<bool, WedprError> {
+        self.check_phase(PollPhase::VOTING)?;
+        check_before_deadline(self.state.get_voting_deadline(), timestamp)?;
+        if !verifier::verify_unbounded_vote_request(
+            self.state.get_poll_parameters(),
+            vote_request,
+            public_key,
+        )
+        .unwrap_or(false)
+        {
+            return Ok(false);
+        }
+        self.accept_vote(vote_request.get_vote())
+    }
+
+    /// Closes the voting phase and starts counting the vote sum.
+    pub fn start_counting(
+        &mut self,
+        timestamp: u64,
+    ) -> Result<&VoteStorage, WedprError> {
+        self.check_phase(PollPhase::VOTING)?;
+        check_after_deadline(self.state.get_voting_deadline(), timestamp)?;
+        if self.state.get_vote_sum().get_voted_ballot().is_empty() {
+            wedpr_println!("no vote has been aggregated");
+            return Err(WedprError::ArgumentError);
+        }
+        self.state.set_phase(PollPhase::COUNTING);
+        Ok(self.state.get_vote_sum())
+    }
+
+    /// Verifies a partially decrypted result sent by a counter and stores it
+    /// in the counting phase. It returns false if the result is invalid or the
+    /// counter has counted.
+    pub fn add_decrypted_part(
+        &mut self,
+        public_key: &[u8],
+        counter_id: &str,
+        partially_decrypted_result: &DecryptedResultPartStorage,
+    ) -> Result<bool, WedprError> {
+        self.check_phase(PollPhase::COUNTING)?;
+        if self
+            .state
+            .get_decrypted_part()
+            .iter()
+            .any(|part| part.get_blank_part().get_counter_id() == counter_id)
+        {
+            return Ok(false);
+        }
+        if !verifier::verify_count_request(
+            self.state.get_poll_parameters(),
+            counter_id,
+            self.state.get_vote_sum(),
+            partially_decrypted_result,
+            public_key,
+        )
+        .unwrap_or(false)
+        {
+            return Ok(false);
+        }
+        self.state
+            .mut_decrypted_part()
+            .push(partially_decrypted_result.clone());
+        Ok(true)
+    }
+
+    /// Decrypts the poll result after all counters have counted, and closes
+    /// the poll.
+    pub fn finalize(
+        &mut self,
+        max_vote_limit: i64,
+    ) -> Result<&VoteResultStorage, WedprError> {
+        self.check_phase(PollPhase::COUNTING)?;
+        if self.state.get_decrypted_part().len()
+            != self
+                .state
+                .get_counter_parameters()
+                .get_counter_parameters_share()
+                .len()
+        {
+            wedpr_println!("not all counters have counted");
+            return Err(WedprError::ArgumentError);
+        }
+        let mut aggregated_decrypted_result = DecryptedResultPartStorage::new();
+        for partially_decrypted_result in self.state.get_decrypted_part() {
+            if coordinator::aggregate_decrypted_part_sum(
+                self.state.get_poll_parameters(),
+                partially_decrypted_result.get_blank_part().get_counter_id(),
+                self.state.get_vote_sum(),
+                partially_decrypted_result,
+                &mut aggregated_decrypted_result,
+            )?
+            .is_some()
+            {
+                return Err(WedprError::VerificationError);
+            }
+        }
+        let vote_result = coordinator::finalize_vote_result(
+            self.state.get_poll_parameters(),
+            self.state.get_vote_sum(),
+            &aggregated_decrypted_result,
+            max_vote_limit,
+        )?;
+        self.state.set_vote_result(vote_result);
+        self.state.set_phase(PollPhase::FINALIZED);
+        Ok(self.state.get_vote_result())
+    }
+
+    // Checks a registration request in the registration phase. Each blank
+    // ballot can only be certified once, since the voter secret encrypting it
+    // also derives the nullifier of the voter.
+    fn check_registration(
+        &self,
+        registration_request: &RegistrationRequest,
+        timestamp: u64,
+    ) -> Result<(), WedprError> {
+        self.check_phase(PollPhase::REGISTRATION)?;
+        check_before_deadline(
+            self.state.get_registration_deadline(),
+            timestamp,
+        )?;
+        let blinding_basepoint_g2 = registration_request
+            .get_weight_point()
+            .get_blinding_basepoint_g2();
+        if self.state.get_registration().iter().any(|registration| {
+            registration.get_ballot().get_ciphertext2() == blinding_basepoint_g2
+        }) {
+            wedpr_println!("the blank ballot has been certified");
+            return Err(WedprError::ArgumentError);
+        }
+        Ok(())
+    }
+
+    // Aggregates a verified vote into the vote sum, or replaces the latest
+    // accepted vote of the same voter with it.
+    fn accept_vote(&mut self, vote: &VoteStorage) -> Result<bool, WedprError> {
+        let previous_vote_index = self
+            .state
+            .get_accepted_vote()
+            .iter()
+            .rposition(|accepted_vote| {
+                accepted_vote.get_nullifier() == vote.get_nullifier()
+            });
+        // Take the vote sum out of the state to update it in place.
+        let mut vote_sum = self.state.take_vote_sum();
+        let aggregate_result = match previous_vote_index {
+            Some(index) => coordinator::replace_vote_sum_response(
+                self.state.get_poll_parameters(),
+                &self.state.get_accepted_vote()[index],
+                vote,
+                &mut vote_sum,
+            ),
+            None => coordinator::aggregate_vote_sum_response(
+                self.state.get_poll_parameters(),
+                vote,
+                &mut vote_sum,
+            ),
+        };
+        self.state.set_vote_sum(vote_sum);
+        if !aggregate_result? {
+            return Ok(false);
+        }
+        self.state.mut_accepted_vote().push(vote.clone());
+        Ok(true)
+    }
+
+    fn check_phase(&self, phase: PollPhase) -> Result<(), WedprError> {
+        if self.state.get_phase() != phase {
+            wedpr_println!(
+                "operation of phase {:?} is not allowed in phase {:?}",
+                phase,
+                self.state.get_phase()
+            );
+            return Err(WedprError::ArgumentError);
+        }
+        Ok(())
+    }
+}
+
+fn check_before_deadline(
+    deadline: u64,
+    timestamp: u64,
+) -> Result<(), WedprError> {
+    if deadline != 0 && timestamp > deadline {
+        wedpr_println!("deadline {} has passed", deadline);
+        return Err(WedprError::ArgumentError);
+    }
+    Ok(())
+}
+
+fn check_after_deadline(
+    deadline: u64,
+    timestamp: u64,
+) -> Result<(), WedprError> {
+    if deadline != 0 && timestamp < deadline {
+        wedpr_println!("deadline {} has not been reached", deadline);
+        return Err(WedprError::ArgumentError);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{config::POLL_RESULT_KEY_ABSTAINED_BALLOTS, counter, voter};
+    use wedpr_l_utils::traits::Signature;
+    use wedpr_s_protos::config::{DEFAULT_SIGNATURE_SCHEME, SIGNATURE};
+
+    #[test]
+    fn test_poll_lifecycle() {
+        let mut candidate_list = CandidateList::new();
+        for candidate in ["Kitten", "Doge"] {
+            candidate_list.mut_candidate().push(candidate.to_string());
+        }
+        let registration_deadline = 100;
+        let voting_deadline = 200;
+        let mut poll =
+            Poll::new(&candidate_list, registration_deadline, voting_deadline)
+                .unwrap();
+        let (public_key, secret_key) = SIGNATURE.generate_keypair();
+
+        // Setup.
+        let counter_id_list = ["1001", "1002"];
+        let mut counter_secret_list = vec![];
+        for counter_id in counter_id_list {
+            let counter_secret = counter::make_counter_secret();
+            let counter_parameters_share =
+                counter::make_parameters_share(counter_id, &counter_secret)
+                    .unwrap();
+            assert!(poll.add_counter(&counter_parameters_share).unwrap());
+            assert!(!poll.add_counter(&counter_parameters_share).unwrap());
+            counter_secret_list.push(counter_secret);
+        }
+        // Voting is not allowed before registration.
+        assert!(poll.start_voting(registration_deadline).is_err());
+        let poll_parameters =
+            poll.start_registration(&secret_key).unwrap().clone();
+
+        // Registration.
+        let vote_secret = voter::make_voter_secret();
+        let registration_request = voter::make_registration_request(
+            &vote_secret,
+            &poll_parameters,
+            &public_key,
+        )
+        .unwrap();
+        assert!(poll
+            .register_voter(
+                &secret_key,
+                &registration_request,
+                10,
+                registration_deadline + 1
+            )
+            .is_err());
+        let registration_response = poll
+            .register_voter(&secret_key, &registration_request, 10, 50)
+            .unwrap();
+        // A blank ballot can only be certified once.
+        assert!(poll
+            .register_voter(&secret_key, &registration_request, 10, 50)
+            .is_err());
+        assert!(poll.start_voting(registration_deadline - 1).is_err());
+        poll.start_voting(registration_deadline).unwrap();
+
+        // Voting.
+        let vote_choices =
+            voter::make_vote_choices(&vec![3, 4], &candidate_list);
+        let vote_request = voter::vote(
+            &vote_secret,
+            &vote_choices,
+            &registration_response,
+            &poll_parameters,
+            &public_key,
+        )
+        .unwrap();
+        assert!(poll
+            .vote(&public_key, &vote_request, voting_deadline + 1)
+            .is_err());
+        assert!(poll.vote(&public_key, &vote_request, 150).unwrap());
+        assert!(!poll.vote(&public_key, &vote_request, 150).unwrap());
+        let mut forged_vote_request = vote_request.clone();
+        forged_vote_request.mut_vote().set_sequence(1);
+        assert!(!poll.vote(&public_key, &forged_vote_request, 150).unwrap());
+        // A newer vote of the voter replaces the accepted vote.
+        let mut vote_choices =
+            voter::make_vote_choices(&vec![5, 2], &candidate_list);
+        vote_choices.set_sequence(1);
+        let vote_request = voter::vote(
+            &vote_secret,
+            &vote_choices,
+            &registration_response,
+            &poll_parameters,
+            &public_key,
+        )
+        .unwrap();
+        assert!(poll.vote(&public_key, &vote_request, 150).unwrap());
+        assert_eq!(poll.get_state().get_accepted_vote().len(), 2);
+
+        // The poll can be persisted and resumed in any phase.
+        let mut poll = Poll::from_bytes(&poll.to_bytes().unwrap()).unwrap();
+        assert_eq!(poll.get_phase(), PollPhase::VOTING);
+        assert!(poll.start_counting(voting_deadline - 1).is_err());
+        let vote_sum = poll.start_counting(voting_deadline).unwrap().clone();
+
+        // Counting.
+        for index in 0..counter_id_list.len() {
+            let partially_decrypted_result = counter::count(
+                counter_id_list[index],
+                &counter_secret_list[index],
+                &vote_sum,
+            )
+            .unwrap();
+            assert!(poll.finalize(20).is_err());
+            assert!(poll
+                .add_decrypted_part(
+                    &public_key,
+                    counter_id_list[index],
+                    &partially_decrypted_result
+                )
+                .unwrap());
+            assert!(!poll
+                .add_decrypted_part(
+                    &public_key,
+                    counter_id_list[index],
+                    &partially_decrypted_result
+                )
+                .unwrap());
+        }
+        let vote_result = poll.finalize(20).unwrap().clone();
+        for candidate_result in vote_result.get_result() {
+            let expected_value = match candidate_result.get_key() {
+                "Kitten" => 5,
+                "Doge" => 2,
+                POLL_RESULT_KEY_ABSTAINED_BALLOTS => 3,
+                _ => 10,
+            };
+            assert_eq!(candidate_result.get_value(), expected_value);
+        }
+        assert_eq!(poll.get_phase(), PollPhase::FINALIZED);
+        assert!(poll.vote(&public_key, &vote_request, 150).is_err());
+
+        // Unbounded voters vote through their own path.
+        let mut poll = Poll::new(&candidate_list, 0, 0).unwrap();
+        poll.add_counter(
+            &counter::make_parameters_share(
+                "1001",
+                &counter::make_counter_secret(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let poll_parameters =
+            poll.start_registration(&secret_key).unwrap().clone();
+        let zero_secret = voter::make_voter_secret();
+        let registration_request = voter::make_unbounded_registration_request(
+            &zero_secret,
+            &vote_secret,
+            &poll_parameters,
+            &public_key,
+        )
+        .unwrap();
+        let registration_response = poll
+            .register_unbounded_voter(&secret_key, &registration_request, 10, 0)
+            .unwrap();
+        poll.start_voting(0).unwrap();
+        let vote_request = voter::vote_unbounded(
+            &vote_secret,
+            &zero_secret,
+            &voter::make_vote_choices(&vec![10, 0], &candidate_list),
+            &registration_response,
+            &poll_parameters,
+            &public_key,
+        )
+        .unwrap();
+        assert!(!poll.vote(&public_key, &vote_request, 0).unwrap());
+        assert!(poll.vote_unbounded(&public_key, &vote_request, 0).unwrap());
+    }
+
+    #[test]
+    fn test_confidential_voter_weight() {
+        let mut candidate_list = CandidateList::new();
+        for candidate in ["Kitten", "Doge"] {
+            candidate_list.mut_candidate().push(candidate.to_string());
+        }
+        let mut poll = Poll::new(&candidate_list, 0, 0).unwrap();
+        let (public_key, secret_key) = SIGNATURE.generate_keypair();
+        let counter_secret = counter::make_counter_secret();
+        poll.add_counter(
+            &counter::make_parameters_share("1001", &counter_secret).unwrap(),
+        )
+        .unwrap();
+        let poll_parameters =
+            poll.start_registration(&secret_key).unwrap().clone();
+
+        // The registration responses do not reveal the voter weights.
+        let mut voter_list = vec![];
+        for (voter_weight, choice) in [(10, vec![3, 4]), (20, vec![5, 0])] {
+            let vote_secret = voter::make_voter_secret();
+            let registration_request = voter::make_registration_request(
+                &vote_secret,
+                &poll_parameters,
+                &public_key,
+            )
+            .unwrap();
+            let registration_response = poll
+                .register_voter_confidential(
+                    &secret_key,
+                    &registration_request,
+                    voter_weight,
+                    0,
+                )
+                .unwrap();
+            assert_eq!(registration_response.get_voter_weight(), 0);
+            assert_ne!(
+                registration_response.get_encrypted_voter_weight(),
+                voter_weight
+            );
+            assert_eq!(
+                voter::get_voter_weight(
+                    &vote_secret,
+                    &poll_parameters,
+                    &registration_response
+                )
+                .unwrap(),
+                voter_weight
+            );
+            // Another voter cannot decrypt the weight.
+            assert!(voter::get_voter_weight(
+                &voter::make_voter_secret(),
+                &poll_parameters,
+                &registration_response
+            )
+            .is_err());
+            voter_list.push((vote_secret, registration_response, choice));
+        }
+        assert!(poll
+            .get_state()
+            .get_registration()
+            .iter()
+            .all(|registration| registration.get_voter_weight() == 0));
+        poll.start_voting(0).unwrap();
+
+        // Voters prove their splits sum to the hidden weights.
+        for (vote_secret, registration_response, choice) in &voter_list {
+            let vote_choices =
+                voter::make_vote_choices(choice, &candidate_list);
+            let vote_request = voter::vote(
+                vote_secret,
+                &vote_choices,
+                registration_response,
+                &poll_parameters,
+                &public_key,
+            )
+            .unwrap();
+            assert!(poll.vote(&public_key, &vote_request, 0).unwrap());
+        }
+        let vote_sum = poll.start_counting(0).unwrap().clone();
+        let partially_decrypted_result =
+            counter::count("1001", &counter_secret, &vote_sum).unwrap();
+        assert!(poll
+            .add_decrypted_part(
+                &public_key,
+                "1001",
+                &partially_decrypted_result
+            )
+            .unwrap());
+
+        // Only the total weight is revealed at finalization.
+        let vote_result = poll.finalize(100).unwrap();
+        for candidate_result in vote_result.get_result() {
+            let expected_value = match candidate_result.get_key() {
+                "Kitten" => 8,
+                "Doge" => 4,
+                POLL_RESULT_KEY_ABSTAINED_BALLOTS => 18,
+                _ => 30,
+            };
+            assert_eq!(candidate_result.get_value(), expected_value);
+        }
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "wedpr_f_sm_signature",
+        feature = "wedpr_f_secp256k1_signature"
+    ))]
+    fn test_signature_scheme() {
+        use wedpr_s_protos::config::{SECP256K1_SIGNATURE, SM2_SIGNATURE};
+
+        let mut candidate_list = CandidateList::new();
+        for candidate in ["Kitten", "Doge"] {
+            candidate_list.mut_candidate().push(candidate.to_string());
+        }
+
+        // Polls with different signature schemes run side by side.
+        let mut poll_list = vec![];
+        for (signature_scheme, (public_key, secret_key)) in [
+            (SignatureScheme::SM2_SM3, SM2_SIGNATURE.generate_keypair()),
+            (
+                SignatureScheme::SECP256K1_KECCAK256,
+                SECP256K1_SIGNATURE.generate_keypair(),
+            ),
+        ] {
+            let mut poll = Poll::new(&candidate_list, 0, 0).unwrap();
+            poll.set_signature_scheme(signature_scheme).unwrap();
+            let counter_secret = counter::make_counter_secret();
+            poll.add_counter(
+                &counter::make_parameters_share("1001", &counter_secret)
+                    .unwrap(),
+            )
+            .unwrap();
+            let poll_parameters =
+                poll.start_registration(&secret_key).unwrap().clone();
+            assert_eq!(
+                poll_parameters.get_signature_scheme(),
+                signature_scheme
+            );
+            // The scheme cannot be changed after the setup phase.
+            assert!(poll
+                .set_signature_scheme(SignatureScheme::DEFAULT_SCHEME)
+                .is_err());
+
+            let vote_secret = voter::make_voter_secret();
+            let registration_request = voter::make_registration_request(
+                &vote_secret,
+                &poll_parameters,
+                &public_key,
+            )
+            .unwrap();
+            let registration_response = poll
+                .register_voter(&secret_key, &registration_request, 10, 0)
+                .unwrap();
+            assert_eq!(
+                registration_response.get_signature_scheme(),
+                signature_scheme
+            );
+            poll.start_voting(0).unwrap();
+            let vote_request = voter::vote(
+                &vote_secret,
+                &voter::make_vote_choices(&vec![3, 5], &candidate_list),
+                &registration_response,
+                &poll_parameters,
+                &public_key,
+            )
+            .unwrap();
+            assert!(verifier::verify_vote_request(
+                &poll_parameters,
+                &vote_request,
+                &public_key
+            )
+            .unwrap());
+            poll_list.push((
+                poll_parameters,
+                public_key,
+                registration_request,
+                vote_request,
+            ));
+        }
+
+        // A vote does not verify under the scheme of another poll.
+        let (sm_poll_parameters, sm_public_key, _, sm_vote_request) =
+            &poll_list[0];
+        let mut mismatched_poll_parameters = sm_poll_parameters.clone();
+        mismatched_poll_parameters
+            .set_signature_scheme(SignatureScheme::SECP256K1_KECCAK256);
+        assert!(verifier::verify_vote_request(
+            &mismatched_poll_parameters,
+            sm_vote_request,
+            sm_public_key
+        )
+        .is_err());
+
+        // A registration signed with another scheme is rejected by the voter.
+        let (secp256k1_poll_parameters, _, registration_request, _) =
+            &poll_list[1];
+        let (_, secret_key) = SECP256K1_SIGNATURE.generate_keypair();
+        let registration_response = coordinator::certify_voter(
+            secp256k1_poll_parameters,
+            &secret_key,
+            registration_request,
+            10,
+        )
+        .unwrap();
+        assert!(voter::vote(
+            &voter::make_voter_secret(),
+            &voter::make_vote_choices(&vec![3, 5], &candidate_list),
+            &registration_response,
+            sm_poll_parameters,
+            sm_public_key
+        )
+        .is_err());
+
+        // The default scheme is the one selected at compile time.
+        let mut default_poll_parameters = sm_poll_parameters.clone();
+        default_poll_parameters
+            .set_signature_scheme(SignatureScheme::DEFAULT_SCHEME);
+        let registration_response = coordinator::certify_voter(
+            &default_poll_parameters,
+            &secret_key,
+            registration_request,
+            10,
+        )
+        .unwrap();
+        assert_eq!(
+            wedpr_s_protos::resolve_signature_scheme(
+                registration_response.get_signature_scheme()
+            ),
+            DEFAULT_SIGNATURE_SCHEME
+        );
+    }
+
+    #[test]
+    #[cfg(not(all(
+        feature = "wedpr_f_sm_signature",
+        feature = "wedpr_f_secp256k1_signature"
+    )))]
+    fn test_unsupported_signature_scheme() {
+        let mut candidate_list = CandidateList::new();
+        for candidate in ["Kitten", "Doge"] {
+            candidate_list.mut_candidate().push(candidate.to_string());
+        }
+        let (_, secret_key) = SIGNATURE.generate_keypair();
+        // Only the scheme built by its feature can sign the poll parameters.
+        let unsupported_signature_scheme = match DEFAULT_SIGNATURE_SCHEME {
+            SignatureScheme::SM2_SM3 => SignatureScheme::SECP256K1_KECCAK256,
+            _ => SignatureScheme::SM2_SM3,
+        };
+        let mut poll = Poll::new(&candidate_list, 0, 0).unwrap();
+        poll.set_signature_scheme(unsupported_signature_scheme)
+            .unwrap();
+        let counter_secret = counter::make_counter_secret();
+        poll.add_counter(
+            &counter::make_parameters_share("1001", &counter_secret).unwrap(),
+        )
+        .unwrap();
+        assert!(poll.start_registration(&secret_key).is_err());
+    }
+}
@@ -0,0 +1,221 @@
+// Copyright 2021 WeDPR Lab Project Authors. Licensed under Apache-2.0.
+
+//! Shared setup of a poll for the tests.
+
+use wedpr_l_utils::{error::WedprError, traits::Signature};
+use wedpr_s_protos::{
+    config::SIGNATURE,
+    generated::acv::{
+        CandidateList, CounterParametersStorage, CounterSecret,
+        DecryptedResultPartStorage, PollParametersStorage, Question,
+        RegistrationResponse, VoteStorage, VoterSecret,
+    },
+};
+
+use crate::{coordinator, counter, voter};
+
+/// Poll set up with its counters and a coordinator key pair, which keeps the
+/// secrets of all parties for the tests.
+pub struct TestPoll {
+    pub counter_id_list: Vec<String>,
+    pub counter_secret_list: Vec<CounterSecret>,
+    pub counter_parameters: CounterParametersStorage,
+    pub candidate_list: CandidateList,
+    pub public_key: Vec<u8>,
+    pub secret_key: Vec<u8>,
+    pub poll_parameters: PollParametersStorage,
+}
+
+impl TestPoll {
+    /// Sets up a poll of the candidates counted by the counters.
+    pub fn new(counter_id_list: &[&str], candidate_list: &[&str]) -> Self {
+        Self::with_parameters(
+            counter_id_list,
+            candidate_list,
+            coordinator::make_poll_parameters,
+        )
+    }
+
+    /// Sets up a poll as new does, whose poll parameters are made from the
+    /// candidates, the counters and the coordinator secret key by
+    /// make_poll_parameters.
+    pub fn with_parameters<F>(
+        counter_id_list: &[&str],
+        candidate_list: &[&str],
+        make_poll_parameters: F,
+    ) -> Self
+    where
+        F: FnOnce(
+            &CandidateList,
+            &CounterParametersStorage,
+            &[u8],
+        ) -> Result<PollParametersStorage, WedprError>,
+    {
+        let mut counter_secret_list = vec![];
+        let mut counter_parameters = CounterParametersStorage::new();
+        for counter_id in counter_id_list {
+            let counter_secret = counter::make_counter_secret();
+            counter_parameters.mut_counter_parameters_share().push(
+                counter::make_parameters_share(counter_id, &counter_secret)
+                    .unwrap(),
+            );
+            counter_secret_list.push(counter_secret);
+        }
+        let mut candidates = CandidateList::new();
+        for candidate in candidate_list {
+            candidates.mut_candidate().push(candidate.to_string());
+        }
+        let (public_key, secret_key) = SIGNATURE.generate_keypair();
+        let poll_parameters =
+            make_poll_parameters(&candidates, &counter_parameters, &secret_key)
+                .unwrap();
+        TestPoll {
+            counter_id_list: counter_id_list
+                .iter()
+                .map(|counter_id| counter_id.to_string())
+                .collect(),
+            counter_secret_list,
+            counter_parameters,
+            candidate_list: candidates,
+            public_key,
+            secret_key,
+            poll_parameters,
+        }
+    }
+
+    /// Sets up a poll of the candidates counted by the counters, which also
+    /// accepts the unlisted candidates numbered in the given range.
+    pub fn new_unlisted(
+        counter_id_list: &[&str],
+        candidate_list: &[&str],
+        min_unlisted_candidate_id: u32,
+        max_unlisted_candidate_id: u32,
+    ) -> Self {
+        Self::with_parameters(
+            counter_id_list,
+            candidate_list,
+            |candidate_list, counter_parameters, secret_key| {
+                coordinator::make_poll_parameters_unlisted(
+                    candidate_list,
+                    counter_parameters,
+                    secret_key,
+                    min_unlisted_candidate_id,
+                    max_unlisted_candidate_id,
+                )
+            },
+        )
+    }
+
+    /// Sets up a multi-question poll of the questions counted by the
+    /// counters.
+    pub fn with_questions(
+        counter_id_list: &[&str],
+        question_list: &[Question],
+    ) -> Self {
+        Self::with_parameters(
+            counter_id_list,
+            &[],
+            |_, counter_parameters, secret_key| {
+                coordinator::make_poll_parameters_multi_question(
+                    question_list,
+                    counter_parameters,
+                    secret_key,
+                )
+            },
+        )
+    }
+
+    /// Returns the ids and secrets of the counters.
+    pub fn counters(&self) -> impl Iterator<Item = (&String, &CounterSecret)> {
+        self.counter_id_list.iter().zip(&self.counter_secret_list)
+    }
+
+    /// Registers a voter of the given weight, and returns the voter secret and
+    /// the registration response.
+    pub fn register_voter(
+        &self,
+        voter_weight: u32,
+    ) -> (VoterSecret, RegistrationResponse) {
+        let voter_secret = voter::make_voter_secret();
+        let registration_request = voter::make_registration_request(
+            &voter_secret,
+            &self.poll_parameters,
+            &self.public_key,
+        )
+        .unwrap();
+        let registration_response = coordinator::certify_voter(
+            &self.poll_parameters,
+            &self.secret_key,
+            &registration_request,
+            voter_weight,
+        )
+        .unwrap();
+        (voter_secret, registration_response)
+    }
+
+    /// Registers a voter of the given weight for unbounded voting, and returns
+    /// the weight secret, the zero secret and the registration response.
+    pub fn register_unbounded_voter(
+        &self,
+        voter_weight: u32,
+    ) -> (VoterSecret, VoterSecret, RegistrationResponse) {
+        let weight_secret = voter::make_voter_secret();
+        let zero_secret = voter::make_voter_secret();
+        let registration_request = voter::make_unbounded_registration_request(
+            &zero_secret,
+            &weight_secret,
+            &self.poll_parameters,
+            &self.public_key,
+        )
+        .unwrap();
+        let registration_response = coordinator::certify_unbounded_voter(
+            &self.poll_parameters,
+            &self.secret_key,
+            &registration_request,
+            voter_weight,
+        )
+        .unwrap();
+        (weight_secret, zero_secret, registration_response)
+    }
+
+    /// Counts a vote sum by all counters, and returns their aggregated
+    /// counting parts.
+    pub fn count(&self, vote_sum: &VoteStorage) -> DecryptedResultPartStorage {
+        let mut aggregated_decrypted_result = DecryptedResultPartStorage::new();
+        for (counter_id, counter_secret) in self.counters() {
+            let partially_decrypted_result =
+                counter::count(counter_id, counter_secret, vote_sum).unwrap();
+            assert!(coordinator::aggregate_decrypted_part_sum(
+                &self.poll_parameters,
+                counter_id,
+                vote_sum,
+                &partially_decrypted_result,
+                &mut aggregated_decrypted_result
+            )
+            .unwrap()
+            .is_none());
+        }
+        aggregated_decrypted_result
+    }
+}
+
+/// Makes the questions of a multi-question poll, one of two candidates and one
+/// of three candidates.
+pub fn make_question_list() -> Vec<Question> {
+    let mut question_list = vec![];
+    for (question_id, candidates) in [
+        ("resolution1", vec!["For", "Against"]),
+        ("resolution2", vec!["Alice", "Bob", "Carol"]),
+    ] {
+        let mut question = Question::new();
+        question.set_question_id(question_id.to_string());
+        for candidate in candidates {
+            question
+                .mut_candidates()
+                .mut_candidate()
+                .push(candidate.to_string());
+        }
+        question_list.push(question);
+    }
+    question_list
+}
@@ -2,18 +2,51 @@
 
 //! Library of ACV utility functions.
 
+use crate::config::{
+    AGGREGATED_EITHER_EQUALITY_CHALLENGE_DOMAIN,
+    AGGREGATED_FORMAT_CHALLENGE_DOMAIN, BLIND_CERTIFICATE_CHALLENGE_DOMAIN,
+    BLIND_CERTIFICATE_INFO_DOMAIN, CHECKPOINT_CHECKSUM_DOMAIN,
+    CONFIDENTIAL_WEIGHT_KEY_DOMAIN, CREDENTIAL_CHALLENGE_DOMAIN,
+    CREDENTIAL_MATCH_CHALLENGE_DOMAIN, DELEGATION_BLINDING_DOMAIN,
+    DELEGATION_MEMBERSHIP_CHALLENGE_DOMAIN, MAX_RANKING_DIFFERENCE,
+    NULLIFIER_BASEPOINT_DOMAIN, POLL_ID_DOMAIN,
+    SEQUENCE_BINDING_BASEPOINT_DOMAIN, SHUFFLE_CHALLENGE_DOMAIN,
+    SHUFFLE_PROOF_ROUNDS, VOTE_LEAF_HASH_DOMAIN, VOTE_NODE_HASH_DOMAIN,
+};
 use curve25519_dalek::{ristretto::RistrettoPoint, scalar::Scalar};
-use wedpr_l_utils::error::WedprError;
-use wedpr_s_protos::generated::acv::{
-    Ballot, CountingPart, DecryptedResultPartStorage, VoteResultStorage,
-    VoteStorage,
+use sha3::Sha3_512;
+use wedpr_l_crypto_zkp_utils::{
+    bytes_to_point, hash_to_scalar, point_to_bytes, BASEPOINT_G1,
+};
+use wedpr_l_utils::{error::WedprError, traits::Hash};
+use wedpr_s_protos::{
+    config::HASH,
+    generated::acv::{
+        Ballot, BallotRow, CandidateBallot, CertifiedBallot, CipherPoints,
+        CountingPart, CredentialMixPart, CredentialRoster,
+        DecryptedResultPartStorage, PollParametersStorage, QuestionBallot,
+        QuestionCountingPart, QuestionResult, RankingComparison, ShuffleProof,
+        StringToCountingPartPair, StringToInt64Pair, UnlistedGroupingStorage,
+        VoteAuditRecord, VoteResultStorage, VoteStorage,
+    },
+    proto_to_bytes,
 };
 
 pub fn get_counting_part_by_candidate(
     decrypted_result: &DecryptedResultPartStorage,
     candidate: &str,
 ) -> Result<CountingPart, WedprError> {
-    for pair in decrypted_result.get_candidate_part() {
+    get_counting_part_from_list(
+        decrypted_result.get_candidate_part(),
+        candidate,
+    )
+}
+
+pub fn get_counting_part_from_list(
+    counting_part_list: &[StringToCountingPartPair],
+    candidate: &str,
+) -> Result<CountingPart, WedprError> {
+    for pair in counting_part_list {
         if pair.get_key() == candidate {
             return Ok(pair.get_value().clone());
         }
@@ -25,7 +58,14 @@ pub fn get_ballot_by_candidate(
     vote_storage: &VoteStorage,
     candidate: &str,
 ) -> Result<Ballot, WedprError> {
-    for pair in vote_storage.get_voted_ballot() {
+    get_ballot_from_list(vote_storage.get_voted_ballot(), candidate)
+}
+
+pub fn get_ballot_from_list(
+    ballot_list: &[CandidateBallot],
+    candidate: &str,
+) -> Result<Ballot, WedprError> {
+    for pair in ballot_list {
         if pair.get_candidate() == candidate {
             return Ok(pair.get_ballot().clone());
         }
@@ -37,7 +77,14 @@ pub fn get_int64_by_candidate(
     vote_result: &VoteResultStorage,
     candidate: &str,
 ) -> Result<i64, WedprError> {
-    for pair in vote_result.get_result() {
+    get_int64_from_list(vote_result.get_result(), candidate)
+}
+
+pub fn get_int64_from_list(
+    result_list: &[StringToInt64Pair],
+    candidate: &str,
+) -> Result<i64, WedprError> {
+    for pair in result_list {
         if pair.get_key() == candidate {
             return Ok(pair.get_value());
         }
@@ -45,6 +92,770 @@ pub fn get_int64_by_candidate(
     Err(WedprError::ArgumentError)
 }
 
+pub fn get_question_ballot(
+    vote_storage: &VoteStorage,
+    question_id: &str,
+) -> Result<QuestionBallot, WedprError> {
+    for question_ballot in vote_storage.get_question_ballot() {
+        if question_ballot.get_question_id() == question_id {
+            return Ok(question_ballot.clone());
+        }
+    }
+    Err(WedprError::ArgumentError)
+}
+
+pub fn get_question_counting_part(
+    decrypted_result: &DecryptedResultPartStorage,
+    question_id: &str,
+) -> Result<QuestionCountingPart, WedprError> {
+    for question_part in decrypted_result.get_question_part() {
+        if question_part.get_question_id() == question_id {
+            return Ok(question_part.clone());
+        }
+    }
+    Err(WedprError::ArgumentError)
+}
+
+pub fn get_question_result(
+    vote_result: &VoteResultStorage,
+    question_id: &str,
+) -> Result<QuestionResult, WedprError> {
+    for question_result in vote_result.get_question_result() {
+        if question_result.get_question_id() == question_id {
+            return Ok(question_result.clone());
+        }
+    }
+    Err(WedprError::ArgumentError)
+}
+
+/// Derives the basepoint of nullifiers from the poll point, so that nullifiers
+/// of the same voter secret are unlinkable across polls.
+pub fn get_nullifier_basepoint(
+    poll_parameters: &PollParametersStorage,
+) -> Result<RistrettoPoint, WedprError> {
+    // Make sure the poll point is valid before hashing it.
+    let poll_point = poll_parameters.get_poll_point();
+    bytes_to_point(poll_point)?;
+    let mut hash_vec = NULLIFIER_BASEPOINT_DOMAIN.as_bytes().to_vec();
+    hash_vec.append(&mut poll_point.to_vec());
+    Ok(RistrettoPoint::hash_from_bytes::<Sha3_512>(&hash_vec))
+}
+
+/// Derives the basepoint binding the sequence number of a vote to its voted
+/// ballots, so that a vote cannot be replayed with another sequence number.
+pub fn get_sequence_binding_basepoint(
+    poll_parameters: &PollParametersStorage,
+    vote: &VoteStorage,
+) -> RistrettoPoint {
+    let mut hash_vec = SEQUENCE_BINDING_BASEPOINT_DOMAIN.as_bytes().to_vec();
+    append_length_prefixed(&mut hash_vec, poll_parameters.get_poll_point());
+    append_length_prefixed(&mut hash_vec, vote.get_nullifier());
+    hash_vec.extend_from_slice(&vote.get_sequence().to_be_bytes());
+    for candidate_ballot in vote.get_voted_ballot() {
+        append_length_prefixed(
+            &mut hash_vec,
+            candidate_ballot.get_candidate().as_bytes(),
+        );
+        append_ballot(&mut hash_vec, candidate_ballot.get_ballot());
+    }
+    append_ballot(&mut hash_vec, vote.get_rest_ballot());
+    for unlisted_ballot in vote.get_voted_ballot_unlisted() {
+        let candidate_cipher = unlisted_ballot.get_key();
+        append_length_prefixed(
+            &mut hash_vec,
+            candidate_cipher.get_ciphertext1(),
+        );
+        append_length_prefixed(
+            &mut hash_vec,
+            candidate_cipher.get_ciphertext2(),
+        );
+        append_ballot(&mut hash_vec, unlisted_ballot.get_ballot());
+    }
+    for question_ballot in vote.get_question_ballot() {
+        append_length_prefixed(
+            &mut hash_vec,
+            question_ballot.get_question_id().as_bytes(),
+        );
+        for candidate_ballot in question_ballot.get_voted_ballot() {
+            append_length_prefixed(
+                &mut hash_vec,
+                candidate_ballot.get_candidate().as_bytes(),
+            );
+            append_ballot(&mut hash_vec, candidate_ballot.get_ballot());
+        }
+        append_ballot(&mut hash_vec, question_ballot.get_rest_ballot());
+    }
+    // Only votes of a for/against poll have against ballots.
+    for against_ballot in vote.get_against_ballot() {
+        append_length_prefixed(
+            &mut hash_vec,
+            against_ballot.get_candidate().as_bytes(),
+        );
+        append_ballot(&mut hash_vec, against_ballot.get_ballot());
+    }
+    // Only votes of a delegate have delegations.
+    for delegation in vote.get_delegation() {
+        append_length_prefixed(&mut hash_vec, delegation.get_nullifier());
+        append_ballot(&mut hash_vec, delegation.get_transfer_ballot());
+    }
+    // Votes without a credential keep the same binding.
+    if vote.has_encrypted_credential() {
+        append_ballot(&mut hash_vec, vote.get_encrypted_credential());
+    }
+    // Only quadratic votes have cost ballots and the unused credit ballot.
+    for cost_ballot in vote.get_cost_ballot() {
+        append_length_prefixed(
+            &mut hash_vec,
+            cost_ballot.get_candidate().as_bytes(),
+        );
+        append_ballot(&mut hash_vec, cost_ballot.get_ballot());
+    }
+    if vote.has_unused_credit_ballot() {
+        append_ballot(&mut hash_vec, vote.get_unused_credit_ballot());
+    }
+    RistrettoPoint::hash_from_bytes::<Sha3_512>(&hash_vec)
+}
+
+fn append_ballot(hash_vec: &mut Vec<u8>, ballot: &Ballot) {
+    append_length_prefixed(hash_vec, ballot.get_ciphertext1());
+    append_length_prefixed(hash_vec, ballot.get_ciphertext2());
+}
+
+fn append_length_prefixed(hash_vec: &mut Vec<u8>, data: &[u8]) {
+    hash_vec.extend_from_slice(&(data.len() as u64).to_be_bytes());
+    hash_vec.extend_from_slice(data);
+}
+
+/// Derives the common information point of blind certificates from the poll
+/// point and the voter weight. The weight is public to the coordinator and the
+/// verifier, so a certified voter is anonymous among the voters of the same
+/// weight.
+pub fn get_blind_certificate_info_point(
+    poll_parameters: &PollParametersStorage,
+    voter_weight: u32,
+) -> Result<RistrettoPoint, WedprError> {
+    let poll_point = poll_parameters.get_poll_point();
+    bytes_to_point(poll_point)?;
+    let mut hash_vec = BLIND_CERTIFICATE_INFO_DOMAIN.as_bytes().to_vec();
+    append_length_prefixed(&mut hash_vec, poll_point);
+    hash_vec.extend_from_slice(&voter_weight.to_be_bytes());
+    Ok(RistrettoPoint::hash_from_bytes::<Sha3_512>(&hash_vec))
+}
+
+/// Computes the challenge of a blind certificate over a blank ballot.
+pub fn compute_blind_certificate_challenge(
+    alpha: &RistrettoPoint,
+    beta: &RistrettoPoint,
+    info_point: &RistrettoPoint,
+    poll_parameters: &PollParametersStorage,
+    blank_ballot: &Ballot,
+) -> Scalar {
+    let mut hash_vec = BLIND_CERTIFICATE_CHALLENGE_DOMAIN.as_bytes().to_vec();
+    append_length_prefixed(&mut hash_vec, &point_to_bytes(alpha));
+    append_length_prefixed(&mut hash_vec, &point_to_bytes(beta));
+    append_length_prefixed(&mut hash_vec, &point_to_bytes(info_point));
+    append_length_prefixed(&mut hash_vec, poll_parameters.get_poll_point());
+    append_ballot(&mut hash_vec, blank_ballot);
+    hash_to_scalar(&hash_vec)
+}
+
+/// Computes the challenge of a credential proof, which is bound to the vote
+/// carrying the encrypted credential.
+pub fn compute_credential_challenge(
+    poll_parameters: &PollParametersStorage,
+    vote_hash: &[u8],
+    encrypted_credential: &Ballot,
+    commitment1: &RistrettoPoint,
+    commitment2: &RistrettoPoint,
+) -> Scalar {
+    let mut hash_vec = CREDENTIAL_CHALLENGE_DOMAIN.as_bytes().to_vec();
+    append_length_prefixed(&mut hash_vec, poll_parameters.get_poll_point());
+    append_length_prefixed(&mut hash_vec, vote_hash);
+    append_ballot(&mut hash_vec, encrypted_credential);
+    append_length_prefixed(&mut hash_vec, &point_to_bytes(commitment1));
+    append_length_prefixed(&mut hash_vec, &point_to_bytes(commitment2));
+    hash_to_scalar(&hash_vec)
+}
+
+/// Computes the challenge of an aggregated format proof, which covers all the
+/// ballots and the commitments in order.
+pub fn compute_aggregated_format_challenge(
+    poll_point: &RistrettoPoint,
+    ballot_list: &[&Ballot],
+    commitment_list: &[(RistrettoPoint, RistrettoPoint)],
+) -> Scalar {
+    let mut hash_vec = AGGREGATED_FORMAT_CHALLENGE_DOMAIN.as_bytes().to_vec();
+    append_length_prefixed(&mut hash_vec, &point_to_bytes(poll_point));
+    for ballot in ballot_list {
+        append_ballot(&mut hash_vec, ballot);
+    }
+    for (commitment1, commitment2) in commitment_list {
+        append_length_prefixed(&mut hash_vec, &point_to_bytes(commitment1));
+        append_length_prefixed(&mut hash_vec, &point_to_bytes(commitment2));
+    }
+    hash_to_scalar(&hash_vec)
+}
+
+/// Computes the single challenge of an aggregated either-equality proof from
+/// the blank ballot, the zero ballot, the candidate ballots and the commitments
+/// of both branches of each candidate ballot.
+pub fn compute_aggregated_either_equality_challenge(
+    poll_point: &RistrettoPoint,
+    weight_ballot: &Ballot,
+    zero_ballot: &Ballot,
+    ballot_list: &[&Ballot],
+    commitment_list: &[(RistrettoPoint, RistrettoPoint)],
+) -> Scalar {
+    let mut hash_vec = AGGREGATED_EITHER_EQUALITY_CHALLENGE_DOMAIN
+        .as_bytes()
+        .to_vec();
+    append_length_prefixed(&mut hash_vec, &point_to_bytes(poll_point));
+    append_ballot(&mut hash_vec, weight_ballot);
+    append_ballot(&mut hash_vec, zero_ballot);
+    for ballot in ballot_list {
+        append_ballot(&mut hash_vec, ballot);
+    }
+    for (weight_commitment, zero_commitment) in commitment_list {
+        append_length_prefixed(
+            &mut hash_vec,
+            &point_to_bytes(weight_commitment),
+        );
+        append_length_prefixed(&mut hash_vec, &point_to_bytes(zero_commitment));
+    }
+    hash_to_scalar(&hash_vec)
+}
+
+fn ballot_difference(
+    ballot1: &Ballot,
+    ballot2: &Ballot,
+) -> Result<(RistrettoPoint, RistrettoPoint), WedprError> {
+    Ok((
+        bytes_to_point(ballot1.get_ciphertext1())?
+            - bytes_to_point(ballot2.get_ciphertext1())?,
+        bytes_to_point(ballot1.get_ciphertext2())?
+            - bytes_to_point(ballot2.get_ciphertext2())?,
+    ))
+}
+
+/// Gets the pairs of votes in the ballot box tested for duplicate credentials,
+/// which are all the pairs (i, j) with i < j in order.
+pub fn get_credential_duplicate_pair_list(
+    vote_count: usize,
+) -> Vec<(usize, usize)> {
+    let mut pair_list = Vec::new();
+    for i in 0..vote_count {
+        for j in i + 1..vote_count {
+            pair_list.push((i, j));
+        }
+    }
+    pair_list
+}
+
+/// Computes the differences between the encrypted credentials of the pairs of
+/// votes in the ballot box, which encrypt zero if and only if the credentials
+/// are the same.
+pub fn get_credential_duplicate_difference_list(
+    ballot_box: &[VoteStorage],
+) -> Result<Vec<(RistrettoPoint, RistrettoPoint)>, WedprError> {
+    for vote in ballot_box {
+        if !vote.has_encrypted_credential() {
+            return Err(WedprError::ArgumentError);
+        }
+    }
+    get_credential_duplicate_pair_list(ballot_box.len())
+        .iter()
+        .map(|(i, j)| {
+            ballot_difference(
+                ballot_box[*i].get_encrypted_credential(),
+                ballot_box[*j].get_encrypted_credential(),
+            )
+        })
+        .collect()
+}
+
+/// Gets the vote rows to mix from the deduplicated ballot box, each of which
+/// holds the encrypted credential, the blank ballot, the ballots of the
+/// candidates in the order of the poll and the rest ballot of a vote.
+pub fn get_credential_vote_row_list(
+    poll_parameters: &PollParametersStorage,
+    votes: &[VoteStorage],
+) -> Result<Vec<Vec<Ballot>>, WedprError> {
+    let mut row_list = Vec::new();
+    for vote in votes {
+        if !vote.has_encrypted_credential() || !vote.has_rest_ballot() {
+            return Err(WedprError::ArgumentError);
+        }
+        let mut row = vec![
+            vote.get_encrypted_credential().clone(),
+            get_combined_blank_ballot(vote)?,
+        ];
+        for candidate in poll_parameters.get_candidates().get_candidate() {
+            row.push(get_ballot_by_candidate(vote, candidate)?);
+        }
+        row.push(vote.get_rest_ballot().clone());
+        row_list.push(row);
+    }
+    Ok(row_list)
+}
+
+/// Gets the roster rows to mix, each of which holds the encrypted credential
+/// and the blank ballot of a roster entry.
+pub fn get_credential_roster_row_list(
+    roster: &CredentialRoster,
+) -> Vec<Vec<Ballot>> {
+    roster
+        .get_entry()
+        .iter()
+        .map(|entry| {
+            vec![
+                entry.get_encrypted_credential().clone(),
+                entry.get_blank_ballot().clone(),
+            ]
+        })
+        .collect()
+}
+
+// Ballots of the rows to mix, where each row is shuffled and re-encrypted as a
+// whole.
+pub(crate) type BallotRowList = Vec<Vec<Ballot>>;
+
+fn ballot_rows_to_list(row_list: &[BallotRow]) -> BallotRowList {
+    row_list
+        .iter()
+        .map(|row| row.get_ballot().to_vec())
+        .collect()
+}
+
+/// Gets the vote rows and the roster rows to mix by the next counter, which
+/// are mixed by the last of the given mix parts, or the rows of the
+/// deduplicated ballot box and the roster if no counter has mixed them.
+pub fn get_credential_mix_input(
+    poll_parameters: &PollParametersStorage,
+    roster: &CredentialRoster,
+    deduplicated_votes: &[VoteStorage],
+    mix_parts: &[CredentialMixPart],
+) -> Result<(BallotRowList, BallotRowList), WedprError> {
+    match mix_parts.last() {
+        Some(mix_part) => Ok((
+            ballot_rows_to_list(mix_part.get_vote_row()),
+            ballot_rows_to_list(mix_part.get_roster_row()),
+        )),
+        None => Ok((
+            get_credential_vote_row_list(poll_parameters, deduplicated_votes)?,
+            get_credential_roster_row_list(roster),
+        )),
+    }
+}
+
+/// Computes the differences between each mixed vote row and each mixed roster
+/// row, in the order of the vote rows and then the roster rows. A difference
+/// combines the credentials and the blank ballots with a challenge derived
+/// from all rows, and encrypts zero if and only if both the credential and the
+/// voter weight are the same, so that a credential cannot carry the weight of
+/// another voter.
+pub fn get_credential_match_difference_list(
+    mix_part: &CredentialMixPart,
+) -> Result<Vec<(RistrettoPoint, RistrettoPoint)>, WedprError> {
+    let mut hash_vec = CREDENTIAL_MATCH_CHALLENGE_DOMAIN.as_bytes().to_vec();
+    for row in mix_part
+        .get_vote_row()
+        .iter()
+        .chain(mix_part.get_roster_row())
+    {
+        hash_vec
+            .extend_from_slice(&(row.get_ballot().len() as u64).to_be_bytes());
+        for ballot in row.get_ballot() {
+            append_ballot(&mut hash_vec, ballot);
+        }
+    }
+    let challenge = hash_to_scalar(&hash_vec);
+    let mut difference_list = Vec::new();
+    for vote_row in mix_part.get_vote_row() {
+        for roster_row in mix_part.get_roster_row() {
+            let (vote_ballot_list, roster_ballot_list) =
+                (vote_row.get_ballot(), roster_row.get_ballot());
+            if vote_ballot_list.len() < 2 || roster_ballot_list.len() != 2 {
+                return Err(WedprError::ArgumentError);
+            }
+            let credential_difference = ballot_difference(
+                &vote_ballot_list[0],
+                &roster_ballot_list[0],
+            )?;
+            let weight_difference = ballot_difference(
+                &vote_ballot_list[1],
+                &roster_ballot_list[1],
+            )?;
+            difference_list.push((
+                credential_difference.0 + weight_difference.0 * challenge,
+                credential_difference.1 + weight_difference.1 * challenge,
+            ));
+        }
+    }
+    Ok(difference_list)
+}
+
+/// Derives the key encrypting a confidential voter weight from the point
+/// shared by the coordinator and the voter.
+pub fn get_confidential_weight_key(shared_point: &RistrettoPoint) -> u32 {
+    let mut hash_vec = CONFIDENTIAL_WEIGHT_KEY_DOMAIN.as_bytes().to_vec();
+    hash_vec.extend_from_slice(&point_to_bytes(shared_point));
+    let key = HASH.hash(&hash_vec);
+    u32::from_be_bytes([key[0], key[1], key[2], key[3]])
+}
+
+/// Derives the blinding of a delegation transfer ballot from the point shared
+/// by the delegating voter and the delegate.
+pub fn get_delegation_blinding(shared_point: &RistrettoPoint) -> Scalar {
+    let mut hash_vec = DELEGATION_BLINDING_DOMAIN.as_bytes().to_vec();
+    hash_vec.extend_from_slice(&point_to_bytes(shared_point));
+    hash_to_scalar(&hash_vec)
+}
+
+/// Computes the challenge of a delegation membership proof from the nullifier,
+/// the delegate ballot, the transfer ballot, the ballots of the ring and the
+/// commitments of each member of the ring.
+pub fn compute_delegation_membership_challenge(
+    poll_point: &RistrettoPoint,
+    nullifier: &[u8],
+    delegate_ballot: &Ballot,
+    transfer_ballot: &Ballot,
+    ring: &[CertifiedBallot],
+    commitment_list: &[[RistrettoPoint; 4]],
+) -> Scalar {
+    let mut hash_vec =
+        DELEGATION_MEMBERSHIP_CHALLENGE_DOMAIN.as_bytes().to_vec();
+    append_length_prefixed(&mut hash_vec, &point_to_bytes(poll_point));
+    append_length_prefixed(&mut hash_vec, nullifier);
+    append_ballot(&mut hash_vec, delegate_ballot);
+    append_ballot(&mut hash_vec, transfer_ballot);
+    for member in ring {
+        append_ballot(&mut hash_vec, member.get_ballot());
+    }
+    for commitments in commitment_list {
+        for commitment in commitments {
+            append_length_prefixed(&mut hash_vec, &point_to_bytes(commitment));
+        }
+    }
+    hash_to_scalar(&hash_vec)
+}
+
+/// Gets the blank ballot of a vote combined with the transfer ballots of all
+/// its delegations, which encrypts the total weight spent by the vote.
+pub fn get_combined_blank_ballot(
+    vote: &VoteStorage,
+) -> Result<Ballot, WedprError> {
+    if vote.get_delegation().is_empty() {
+        return Ok(vote.get_blank_ballot().clone());
+    }
+    let mut c1_sum = bytes_to_point(vote.get_blank_ballot().get_ciphertext1())?;
+    let mut c2_sum = bytes_to_point(vote.get_blank_ballot().get_ciphertext2())?;
+    for delegation in vote.get_delegation() {
+        let transfer_ballot = delegation.get_transfer_ballot();
+        c1_sum += bytes_to_point(transfer_ballot.get_ciphertext1())?;
+        c2_sum += bytes_to_point(transfer_ballot.get_ciphertext2())?;
+    }
+    let mut ballot = Ballot::new();
+    ballot.set_ciphertext1(point_to_bytes(&c1_sum));
+    ballot.set_ciphertext2(point_to_bytes(&c2_sum));
+    Ok(ballot)
+}
+
+/// Computes the ciphertext of the vote difference between the candidates of a
+/// ranking comparison.
+pub fn get_ranking_difference(
+    vote_sum: &VoteStorage,
+    comparison: &RankingComparison,
+) -> Result<(RistrettoPoint, RistrettoPoint), WedprError> {
+    let greater_ballot =
+        get_ballot_by_candidate(vote_sum, comparison.get_greater_candidate())?;
+    let lesser_ballot =
+        get_ballot_by_candidate(vote_sum, comparison.get_lesser_candidate())?;
+    Ok((
+        bytes_to_point(greater_ballot.get_ciphertext1())?
+            - bytes_to_point(lesser_ballot.get_ciphertext1())?,
+        bytes_to_point(greater_ballot.get_ciphertext2())?
+            - bytes_to_point(lesser_ballot.get_ciphertext2())?,
+    ))
+}
+
+/// Gets the differences of a ranking comparison to be mixed after its first
+/// mix_count mix parts, which are the shuffled differences of the last of
+/// them, or the encrypted vote difference minus every value in [0,
+/// max_difference] before mixing. It returns an argument error if
+/// max_difference exceeds MAX_RANKING_DIFFERENCE, or the shuffled differences
+/// are not max_difference + 1.
+pub fn get_ranking_mix_input(
+    vote_sum: &VoteStorage,
+    comparison: &RankingComparison,
+    mix_count: usize,
+) -> Result<Vec<Ballot>, WedprError> {
+    check_ranking_max_difference(comparison)?;
+    if mix_count > 0 {
+        let shuffled_difference_list =
+            match comparison.get_mix_part().get(mix_count - 1) {
+                Some(mix_part) => mix_part.get_shuffled_difference(),
+                None => return Err(WedprError::ArgumentError),
+            };
+        if shuffled_difference_list.len()
+            != comparison.get_max_difference() as usize + 1
+        {
+            return Err(WedprError::ArgumentError);
+        }
+        return Ok(shuffled_difference_list.to_vec());
+    }
+    let (difference1, difference2) =
+        get_ranking_difference(vote_sum, comparison)?;
+    let ciphertext2 = point_to_bytes(&difference2);
+    let mut difference_list = Vec::new();
+    for value in 0..=comparison.get_max_difference() {
+        let mut difference = Ballot::new();
+        difference.set_ciphertext1(point_to_bytes(
+            &(difference1 - *BASEPOINT_G1 * Scalar::from(value as u64)),
+        ));
+        difference.set_ciphertext2(ciphertext2.clone());
+        difference_list.push(difference);
+    }
+    Ok(difference_list)
+}
+
+/// Checks whether the vote difference bound of a ranking comparison is at most
+/// MAX_RANKING_DIFFERENCE, which bounds the differences mixed by the counters.
+pub fn check_ranking_max_difference(
+    comparison: &RankingComparison,
+) -> Result<(), WedprError> {
+    if comparison.get_max_difference() > MAX_RANKING_DIFFERENCE {
+        wedpr_println!(
+            "max difference of the ranking comparison exceeds {}!",
+            MAX_RANKING_DIFFERENCE
+        );
+        return Err(WedprError::ArgumentError);
+    }
+    Ok(())
+}
+
+/// Gets the comparisons proving a ranking of the top candidates, which compare
+/// the ranked candidates one by one, and the last of them with all the other
+/// candidates.
+pub fn get_ranking_comparison_pair_list<'a>(
+    candidate_list: &'a [String],
+    ranked_candidate_list: &'a [String],
+) -> Vec<(&'a str, &'a str)> {
+    let mut pair_list = Vec::new();
+    for pair in ranked_candidate_list.windows(2) {
+        pair_list.push((pair[0].as_str(), pair[1].as_str()));
+    }
+    if let Some(last_candidate) = ranked_candidate_list.last() {
+        for candidate in candidate_list {
+            if !ranked_candidate_list.contains(candidate) {
+                pair_list.push((last_candidate.as_str(), candidate.as_str()));
+            }
+        }
+    }
+    pair_list
+}
+
+/// Computes the challenge bits of a shuffle proof, one for each round, which
+/// cover the input rows, the output rows and the shadow rows of all rounds.
+pub fn compute_shuffle_challenge(
+    poll_point: &RistrettoPoint,
+    input_row_list: &[Vec<Ballot>],
+    output_row_list: &[Vec<Ballot>],
+    shuffle_proof: &ShuffleProof,
+) -> Vec<bool> {
+    let mut hash_vec = SHUFFLE_CHALLENGE_DOMAIN.as_bytes().to_vec();
+    append_length_prefixed(&mut hash_vec, &point_to_bytes(poll_point));
+    for row in input_row_list.iter().chain(output_row_list) {
+        hash_vec.extend_from_slice(&(row.len() as u64).to_be_bytes());
+        for ballot in row {
+            append_ballot(&mut hash_vec, ballot);
+        }
+    }
+    for round in shuffle_proof.get_round() {
+        for shadow_row in round.get_shadow_row() {
+            hash_vec.extend_from_slice(
+                &(shadow_row.get_ballot().len() as u64).to_be_bytes(),
+            );
+            for ballot in shadow_row.get_ballot() {
+                append_ballot(&mut hash_vec, ballot);
+            }
+        }
+    }
+    let challenge = hash_to_scalar(&hash_vec).to_bytes();
+    (0..SHUFFLE_PROOF_ROUNDS)
+        .map(|i| (challenge[i / 8] >> (i % 8)) & 1 == 1)
+        .collect()
+}
+
+/// Computes the id of a poll as a hash commitment over all its parameters
+/// except the id and its signature, which include the poll point, the
+/// candidates, the counters' shares and the limits. The id is unique to the
+/// poll since the poll point is aggregated from the random shares of the
+/// counters.
+pub fn compute_poll_id(
+    poll_parameters: &PollParametersStorage,
+) -> Result<Vec<u8>, WedprError> {
+    let mut committed_parameters = poll_parameters.clone();
+    committed_parameters.clear_poll_id();
+    committed_parameters.clear_poll_signature();
+    let mut hash_vec = POLL_ID_DOMAIN.as_bytes().to_vec();
+    hash_vec.extend_from_slice(&proto_to_bytes(&committed_parameters)?);
+    Ok(HASH.hash(&hash_vec))
+}
+
+/// Computes the hash of a vote recorded in the audit trail of the vote sum.
+pub fn compute_vote_hash(vote: &VoteStorage) -> Result<Vec<u8>, WedprError> {
+    Ok(HASH.hash(&proto_to_bytes(vote)?))
+}
+
+/// Computes the checksum of an aggregator checkpoint, which binds the vote sum
+/// to the poll and the number of aggregated votes.
+pub fn compute_checkpoint_checksum(
+    poll_id: &[u8],
+    vote_count: u64,
+    vote_sum: &VoteStorage,
+) -> Result<Vec<u8>, WedprError> {
+    let mut hash_vec = CHECKPOINT_CHECKSUM_DOMAIN.as_bytes().to_vec();
+    append_length_prefixed(&mut hash_vec, poll_id);
+    hash_vec.extend_from_slice(&vote_count.to_be_bytes());
+    hash_vec.extend_from_slice(&proto_to_bytes(vote_sum)?);
+    Ok(HASH.hash(&hash_vec))
+}
+
+fn compute_vote_leaf_hash(vote_hash: &[u8]) -> Vec<u8> {
+    let mut hash_vec = VOTE_LEAF_HASH_DOMAIN.as_bytes().to_vec();
+    append_length_prefixed(&mut hash_vec, vote_hash);
+    HASH.hash(&hash_vec)
+}
+
+fn compute_vote_node_hash(left: &[u8], right: &[u8]) -> Vec<u8> {
+    let mut hash_vec = VOTE_NODE_HASH_DOMAIN.as_bytes().to_vec();
+    append_length_prefixed(&mut hash_vec, left);
+    append_length_prefixed(&mut hash_vec, right);
+    HASH.hash(&hash_vec)
+}
+
+// Computes the next level of the vote Merkle tree, where the last node of an
+// odd level is promoted unchanged.
+fn compute_next_vote_level(level: &[Vec<u8>]) -> Vec<Vec<u8>> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => compute_vote_node_hash(left, right),
+            _ => pair[0].clone(),
+        })
+        .collect()
+}
+
+fn get_vote_leaf_list(vote_sum: &VoteStorage) -> Vec<Vec<u8>> {
+    vote_sum
+        .get_audit_record()
+        .iter()
+        .map(|record| compute_vote_leaf_hash(record.get_vote_hash()))
+        .collect()
+}
+
+/// Appends an audit record to the audit trail of the vote sum, and updates the
+/// Merkle root of the hashes of all aggregated votes from the frontier of the
+/// vote Merkle tree, without rebuilding the tree.
+pub fn append_vote_audit_record(
+    vote_sum: &mut VoteStorage,
+    audit_record: VoteAuditRecord,
+) {
+    // The frontier holds one full subtree for each set bit of the leaf count,
+    // so the trailing set bits are the subtrees merged by the new leaf.
+    let merge_count = vote_sum.get_audit_record().len().trailing_ones();
+    let mut hash = compute_vote_leaf_hash(audit_record.get_vote_hash());
+    let vote_frontier = vote_sum.mut_vote_frontier();
+    for _ in 0..merge_count {
+        let left = vote_frontier.pop().unwrap_or_default();
+        hash = compute_vote_node_hash(&left, &hash);
+    }
+    vote_frontier.push(hash);
+    // The last node of an odd level is promoted unchanged, so the root folds
+    // the subtrees from the smallest one.
+    let mut subtree_roots = vote_frontier.iter().rev();
+    let mut vote_root = subtree_roots.next().cloned().unwrap_or_default();
+    for left in subtree_roots {
+        vote_root = compute_vote_node_hash(left, &vote_root);
+    }
+    vote_sum.mut_audit_record().push(audit_record);
+    vote_sum.set_vote_root(vote_root);
+}
+
+/// Gets the sibling hashes from a leaf of the vote Merkle tree up to the root.
+pub fn get_vote_merkle_path(
+    vote_sum: &VoteStorage,
+    leaf_index: usize,
+) -> Vec<Vec<u8>> {
+    let mut level = get_vote_leaf_list(vote_sum);
+    let mut index = leaf_index;
+    let mut sibling_hash_list = Vec::new();
+    while level.len() > 1 {
+        if let Some(sibling_hash) = level.get(index ^ 1) {
+            sibling_hash_list.push(sibling_hash.clone());
+        }
+        level = compute_next_vote_level(&level);
+        index /= 2;
+    }
+    sibling_hash_list
+}
+
+/// Computes the root of a vote Merkle tree with the given number of leaves
+/// from a vote hash and its sibling hashes. It returns None if the sibling
+/// hashes do not match the position of the vote.
+pub fn compute_vote_root_from_path(
+    vote_hash: &[u8],
+    leaf_index: usize,
+    leaf_count: usize,
+    sibling_hash_list: &[Vec<u8>],
+) -> Option<Vec<u8>> {
+    if leaf_index >= leaf_count {
+        return None;
+    }
+    let mut hash = compute_vote_leaf_hash(vote_hash);
+    let mut index = leaf_index;
+    let mut count = leaf_count;
+    let mut sibling_hashes = sibling_hash_list.iter();
+    while count > 1 {
+        // The last node of an odd level has no sibling.
+        if index ^ 1 < count {
+            let sibling_hash = sibling_hashes.next()?;
+            hash = if index % 2 == 0 {
+                compute_vote_node_hash(&hash, sibling_hash)
+            } else {
+                compute_vote_node_hash(sibling_hash, &hash)
+            };
+        }
+        index /= 2;
+        count = (count + 1) / 2;
+    }
+    match sibling_hashes.next() {
+        Some(_) => None,
+        None => Some(hash),
+    }
+}
+
+/// Gets the latest unlisted candidate ciphers to blind, which are blinded by
+/// the last counter, or the original ciphers of the vote sum if no counter has
+/// blinded them.
+pub fn get_unlisted_candidate_cipher_list(
+    vote_sum: &VoteStorage,
+    grouping: &UnlistedGroupingStorage,
+) -> Vec<CipherPoints> {
+    match grouping.get_blinding_part().last() {
+        Some(blinding_part) => blinding_part
+            .get_blinded_candidate_cipher()
+            .iter()
+            .map(|blinded_cipher| blinded_cipher.get_cipher().clone())
+            .collect(),
+        None => vote_sum
+            .get_voted_ballot_unlisted()
+            .iter()
+            .map(|unlisted_ballot| unlisted_ballot.get_key().clone())
+            .collect(),
+    }
+}
+
 pub fn align_commitment_list_if_needed(list: &mut Vec<RistrettoPoint>) {
     let pending_length = compute_pending_size(list.len());
     for _ in 0..pending_length {
@@ -2,67 +2,818 @@
 
 //! Library for a poll verifier.
 
-use curve25519_dalek::{ristretto::RistrettoPoint, scalar::Scalar};
+use curve25519_dalek::{
+    ristretto::RistrettoPoint,
+    scalar::Scalar,
+    traits::{MultiscalarMul, VartimeMultiscalarMul},
+};
 use wedpr_l_crypto_zkp_discrete_logarithm_proof::{
     verify_either_equality_relationship_proof,
     verify_equality_relationship_proof, verify_format_proof,
-    verify_sum_relationship,
+    verify_product_relationship, verify_sum_relationship,
+    verify_sum_relationship_in_batch,
 };
 use wedpr_l_crypto_zkp_range_proof::verify_value_range_in_batch;
 use wedpr_l_crypto_zkp_utils::{
-    bytes_to_point, Deserialize, BASEPOINT_G1, BASEPOINT_G2,
+    bytes_to_point, bytes_to_scalar, get_random_scalar, point_to_bytes,
+    ArithmeticProof, Deserialize, BASEPOINT_G1, BASEPOINT_G2,
 };
 use wedpr_l_utils::error::WedprError;
 use wedpr_s_protos::{
     generated::acv::BallotProof, verify_ballot_signature,
-    verify_ballots_signature,
+    verify_ballots_signature, verify_poll_signature,
 };
 
 use wedpr_s_protos::generated::acv::{
-    Ballot, CipherPointsToBallotPair, CipherPointsToBallotProofPair,
-    DecryptedResultPartStorage, PollParametersStorage, StringToBallotProofPair,
-    UnlistedBallotDecryptedResult, VoteRequest, VoteResultStorage, VoteStorage,
+    AggregatedEitherEqualityProof, AggregatedFormatProof, Ballot,
+    BlindCertificate, CipherPointsToBallotPair, CipherPointsToBallotProofPair,
+    CounterBlameReason, CounterBlameReport, CounterParametersStorage,
+    CountingPart, CredentialBlindingPart, CredentialDecryptionPart,
+    CredentialMixPart, CredentialRoster, DecryptedResultPartStorage,
+    Delegation, PollMode, PollParametersStorage, PollTranscript,
+    RankingComparison, ShuffleProof, StringToBallotProofPair,
+    StringToInt64Pair, UnlistedBallotDecryptedResult, UnlistedBlindingPart,
+    UnlistedGroupingStorage, UnlistedTagDecryptedPart, UnlistedVoteChoice,
+    VoteRanking, VoteReceipt, VoteRequest, VoteResultStorage, VoteStorage,
 };
 
 use crate::{
-    config::POLL_RESULT_KEY_TOTAL_BALLOTS,
+    config::{
+        POLL_RESULT_KEY_ABSTAINED_BALLOTS, POLL_RESULT_KEY_TOTAL_BALLOTS,
+        SHUFFLE_PROOF_ROUNDS,
+    },
+    coordinator::{
+        aggregate_credential_blinding_parts, aggregate_decrypted_part_sum,
+        aggregate_vote_sum_response_unlisted, decide_ranking_comparison,
+        filter_votes_by_credential, group_unlisted_ballots,
+        remove_credential_duplicates, replace_vote_sum_response_unlisted,
+    },
     utils::{
-        align_commitment_list_if_needed, get_ballot_by_candidate,
-        get_counting_part_by_candidate, get_int64_by_candidate,
+        align_commitment_list_if_needed, check_ranking_max_difference,
+        compute_aggregated_either_equality_challenge,
+        compute_aggregated_format_challenge,
+        compute_blind_certificate_challenge, compute_credential_challenge,
+        compute_delegation_membership_challenge, compute_poll_id,
+        compute_shuffle_challenge, compute_vote_hash,
+        compute_vote_root_from_path, get_ballot_by_candidate,
+        get_ballot_from_list, get_blind_certificate_info_point,
+        get_combined_blank_ballot, get_counting_part_by_candidate,
+        get_counting_part_from_list, get_credential_duplicate_difference_list,
+        get_credential_match_difference_list, get_credential_mix_input,
+        get_int64_by_candidate, get_int64_from_list, get_nullifier_basepoint,
+        get_question_ballot, get_question_counting_part, get_question_result,
+        get_ranking_comparison_pair_list, get_ranking_mix_input,
+        get_sequence_binding_basepoint, get_unlisted_candidate_cipher_list,
     },
 };
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Verifies whether the poll id commits to all the other poll parameters, and
+/// the poll point is aggregated from the committed shares of the counters.
+/// The poll id also commits to the version of the ballot signatures, so a
+/// relay cannot downgrade a poll to the legacy encoding without changing it.
+pub fn verify_poll_id(
+    poll_parameters: &PollParametersStorage,
+) -> Result<bool, WedprError> {
+    let counter_shares = poll_parameters
+        .get_counter_parameters()
+        .get_counter_parameters_share();
+    let mut poll_point = RistrettoPoint::default();
+    for share in counter_shares {
+        poll_point += bytes_to_point(share.get_poll_point_share())?;
+    }
+    if counter_shares.is_empty()
+        || point_to_bytes(&poll_point) != poll_parameters.get_poll_point()
+    {
+        wedpr_println!("verify poll point failed!");
+        return Ok(false);
+    }
+    Ok(compute_poll_id(poll_parameters)? == poll_parameters.get_poll_id())
+}
+
+/// Verifies whether the poll parameters are committed by the poll id, and the
+/// poll id is signed by the coordinator. The signature is required for every
+/// poll, and legacy polls must be signed by sign_poll_parameters before they
+/// are used.
+pub fn verify_poll_parameters(
+    poll_parameters: &PollParametersStorage,
+    public_key: &[u8],
+) -> Result<bool, WedprError> {
+    if !verify_poll_id(poll_parameters)? {
+        return Ok(false);
+    }
+    verify_poll_signature(poll_parameters, public_key)
+}
+
+// Checks the poll id and its signature of the poll parameters before they are
+// used.
+pub(crate) fn check_poll_parameters(
+    poll_parameters: &PollParametersStorage,
+    public_key: &[u8],
+) -> Result<(), WedprError> {
+    if !verify_poll_parameters(poll_parameters, public_key)? {
+        wedpr_println!("verify_poll_parameters failed!");
+        return Err(WedprError::VerificationError);
+    }
+    Ok(())
+}
+
+/// Verifies whether the nullifier in a vote request is derived from the same
+/// secret as the certified blank ballot.
+pub fn verify_nullifier(
+    poll_parameters: &PollParametersStorage,
+    vote_request: &VoteRequest,
+) -> Result<bool, WedprError> {
+    let vote = vote_request.get_vote();
+    if vote.get_nullifier().is_empty() {
+        return Ok(false);
+    }
+    let nullifier = bytes_to_point(vote.get_nullifier())?;
+    let blinding_basepoint_g2 =
+        bytes_to_point(vote.get_blank_ballot().get_ciphertext2())?;
+    let nullifier_proof =
+        Deserialize::deserialize(vote_request.get_nullifier_proof())?;
+    verify_equality_relationship_proof(
+        &blinding_basepoint_g2,
+        &nullifier,
+        &nullifier_proof,
+        &BASEPOINT_G2,
+        &get_nullifier_basepoint(poll_parameters)?,
+    )
+}
+
+/// Verifies whether the sequence number in a vote request is bound to its
+/// voted ballots by the same secret as the certified blank ballot.
+pub fn verify_sequence_binding(
+    poll_parameters: &PollParametersStorage,
+    vote_request: &VoteRequest,
+) -> Result<bool, WedprError> {
+    let vote = vote_request.get_vote();
+    if vote.get_sequence_binding().is_empty() {
+        return Ok(false);
+    }
+    let sequence_binding = bytes_to_point(vote.get_sequence_binding())?;
+    let blinding_basepoint_g2 =
+        bytes_to_point(vote.get_blank_ballot().get_ciphertext2())?;
+    let sequence_binding_proof =
+        Deserialize::deserialize(vote_request.get_sequence_binding_proof())?;
+    verify_equality_relationship_proof(
+        &blinding_basepoint_g2,
+        &sequence_binding,
+        &sequence_binding_proof,
+        &BASEPOINT_G2,
+        &get_sequence_binding_basepoint(poll_parameters, vote),
+    )
+}
+
 /// Verifies whether ciphertext ballots from a certified voter are valid.
 pub fn verify_vote_request(
     poll_parameters: &PollParametersStorage,
     vote_request: &VoteRequest,
     public_key: &[u8],
 ) -> Result<bool, WedprError> {
+    check_poll_parameters(poll_parameters, public_key)?;
+    verify_vote_signature(poll_parameters, vote_request, public_key)?;
+    verify_vote_delegations(poll_parameters, vote_request, public_key)?;
+    verify_certified_vote_request(poll_parameters, vote_request)
+}
+
+/// Verifies whether the blind certificate of a blank ballot is issued by the
+/// coordinator, and the blank ballot encrypts the certified voter weight with
+/// the voter secret as the blinding.
+pub fn verify_blind_certificate(
+    poll_parameters: &PollParametersStorage,
+    blank_ballot: &Ballot,
+    certificate: &BlindCertificate,
+    public_key: &[u8],
+    poll_public_key: &[u8],
+) -> Result<bool, WedprError> {
+    check_poll_parameters(poll_parameters, poll_public_key)?;
+    let public_key_point = bytes_to_point(public_key)?;
     let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
-    let signature = vote_request.get_vote().get_signature();
+    let voter_weight = certificate.get_voter_weight();
+    let info_point =
+        get_blind_certificate_info_point(poll_parameters, voter_weight)?;
+    let rho = bytes_to_scalar(certificate.get_rho())?;
+    let omega = bytes_to_scalar(certificate.get_omega())?;
+    let sigma = bytes_to_scalar(certificate.get_sigma())?;
+    let delta = bytes_to_scalar(certificate.get_delta())?;
+    let alpha = RistrettoPoint::multiscalar_mul(&[rho, omega], &[
+        *BASEPOINT_G1,
+        public_key_point,
+    ]);
+    let beta = RistrettoPoint::multiscalar_mul(&[sigma, delta], &[
+        *BASEPOINT_G1,
+        info_point,
+    ]);
+    if omega + delta
+        != compute_blind_certificate_challenge(
+            &alpha,
+            &beta,
+            &info_point,
+            poll_parameters,
+            blank_ballot,
+        )
+    {
+        wedpr_println!("verify blind certificate signature failed!");
+        return Ok(false);
+    }
+    let ciphertext1 = bytes_to_point(blank_ballot.get_ciphertext1())?;
+    let ciphertext2 = bytes_to_point(blank_ballot.get_ciphertext2())?;
+    let weight_proof =
+        Deserialize::deserialize(certificate.get_weight_proof())?;
+    verify_equality_relationship_proof(
+        &(ciphertext1 - *BASEPOINT_G1 * Scalar::from(voter_weight)),
+        &ciphertext2,
+        &weight_proof,
+        &poll_point,
+        &BASEPOINT_G2,
+    )
+}
+
+/// Verifies whether ciphertext ballots from a voter certified blindly are
+/// valid. Unlike verify_vote_request, the vote request cannot be linked to the
+/// registration of the voter.
+pub fn verify_anonymous_vote_request(
+    poll_parameters: &PollParametersStorage,
+    vote_request: &VoteRequest,
+    public_key: &[u8],
+    poll_public_key: &[u8],
+) -> Result<bool, WedprError> {
+    let vote = vote_request.get_vote();
+    // Delegations would reveal the unlinkable blank ballot of the vote to the
+    // delegating voters.
+    if !vote.has_certificate()
+        || !vote.get_delegation().is_empty()
+        || !verify_blind_certificate(
+            poll_parameters,
+            vote.get_blank_ballot(),
+            vote.get_certificate(),
+            public_key,
+            poll_public_key,
+        )?
+    {
+        return Err(WedprError::VerificationError);
+    }
+    verify_certified_vote_request(poll_parameters, vote_request)
+}
 
+// Verifies the signature of the coordinator on the blank ballot of a vote
+// request.
+fn verify_vote_signature(
+    poll_parameters: &PollParametersStorage,
+    vote_request: &VoteRequest,
+    public_key: &[u8],
+) -> Result<(), WedprError> {
+    let signature = vote_request.get_vote().get_signature();
     let blank_ballot = vote_request.get_vote().get_blank_ballot();
-    let verify_result =
-        verify_ballot_signature(public_key, blank_ballot, &signature.to_vec())?;
+    let verify_result = verify_ballot_signature(
+        poll_parameters,
+        public_key,
+        blank_ballot,
+        &signature.to_vec(),
+    )?;
     if !verify_result {
         return Err(WedprError::VerificationError);
     }
+    Ok(())
+}
+
+/// Verifies whether a delegation spends the weight of a voter certified by the
+/// coordinator by its nullifier, and re-encrypts the same weight in its
+/// transfer ballot. The voter is only known to be one of the certified ballots
+/// in the ring of the delegation.
+pub fn verify_delegation(
+    poll_parameters: &PollParametersStorage,
+    delegation: &Delegation,
+    public_key: &[u8],
+) -> Result<bool, WedprError> {
+    // The ring must be sorted without duplicates, so that it does not reveal
+    // the position of the voter.
+    let ring = delegation.get_ring();
+    if ring.is_empty()
+        || ring.windows(2).any(|pair| {
+            (
+                pair[0].get_ballot().get_ciphertext1(),
+                pair[0].get_ballot().get_ciphertext2(),
+            ) >= (
+                pair[1].get_ballot().get_ciphertext1(),
+                pair[1].get_ballot().get_ciphertext2(),
+            )
+        })
+    {
+        wedpr_println!("delegation ring is not sorted!");
+        return Ok(false);
+    }
+    for member in ring {
+        if !verify_ballot_signature(
+            poll_parameters,
+            public_key,
+            member.get_ballot(),
+            &member.get_signature().to_vec(),
+        )? {
+            wedpr_println!("verify delegation ring signature failed!");
+            return Ok(false);
+        }
+    }
+    verify_delegation_membership_proof(poll_parameters, delegation)
+}
+
+// Verifies a delegation membership proof by recomputing the challenge from
+// the challenges and the responses of each member of the ring.
+fn verify_delegation_membership_proof(
+    poll_parameters: &PollParametersStorage,
+    delegation: &Delegation,
+) -> Result<bool, WedprError> {
+    let ring = delegation.get_ring();
+    let membership_proof = delegation.get_membership_proof();
+    let challenge_list = membership_proof.get_challenge();
+    let secret_response_list = membership_proof.get_secret_response();
+    let blinding_response_list = membership_proof.get_blinding_response();
+    if challenge_list.len() != ring.len()
+        || secret_response_list.len() != ring.len()
+        || blinding_response_list.len() != ring.len()
+    {
+        return Ok(false);
+    }
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+    let nullifier_basepoint = get_nullifier_basepoint(poll_parameters)?;
+    let nullifier = bytes_to_point(delegation.get_nullifier())?;
+    let transfer_ballot = delegation.get_transfer_ballot();
+    let transfer_c1 = bytes_to_point(transfer_ballot.get_ciphertext1())?;
+    let transfer_c2 = bytes_to_point(transfer_ballot.get_ciphertext2())?;
+    let mut challenge_sum = Scalar::zero();
+    let mut commitment_list = Vec::new();
+    for (member, (member_challenge, (secret_response, blinding_response))) in
+        ring.iter().zip(
+            challenge_list
+                .iter()
+                .zip(secret_response_list.iter().zip(blinding_response_list)),
+        )
+    {
+        let blank_c1 = bytes_to_point(member.get_ballot().get_ciphertext1())?;
+        let blank_c2 = bytes_to_point(member.get_ballot().get_ciphertext2())?;
+        let member_challenge = bytes_to_scalar(member_challenge)?;
+        let secret_response = bytes_to_scalar(secret_response)?;
+        let blinding_response = bytes_to_scalar(blinding_response)?;
+        challenge_sum += member_challenge;
+        commitment_list.push([
+            RistrettoPoint::multiscalar_mul(
+                &[secret_response, member_challenge],
+                &[*BASEPOINT_G2, blank_c2],
+            ),
+            RistrettoPoint::multiscalar_mul(
+                &[secret_response, member_challenge],
+                &[nullifier_basepoint, nullifier],
+            ),
+            RistrettoPoint::multiscalar_mul(
+                &[blinding_response, member_challenge],
+                &[poll_point, blank_c1 - transfer_c1],
+            ),
+            RistrettoPoint::multiscalar_mul(
+                &[blinding_response, member_challenge],
+                &[*BASEPOINT_G2, blank_c2 - transfer_c2],
+            ),
+        ]);
+    }
+    Ok(compute_delegation_membership_challenge(
+        &poll_point,
+        delegation.get_nullifier(),
+        delegation.get_delegate_ballot(),
+        transfer_ballot,
+        ring,
+        &commitment_list,
+    ) == challenge_sum)
+}
+
+// Verifies the delegations of a vote request, which must be made to the blank
+// ballot of the vote, and spend distinct nullifiers other than the nullifier
+// of the vote.
+fn verify_vote_delegations(
+    poll_parameters: &PollParametersStorage,
+    vote_request: &VoteRequest,
+    public_key: &[u8],
+) -> Result<(), WedprError> {
+    let vote = vote_request.get_vote();
+    let mut nullifier_set = BTreeSet::new();
+    nullifier_set.insert(vote.get_nullifier());
+    for delegation in vote.get_delegation() {
+        if delegation.get_delegate_ballot() != vote.get_blank_ballot()
+            || !nullifier_set.insert(delegation.get_nullifier())
+            || !verify_delegation(poll_parameters, delegation, public_key)?
+        {
+            wedpr_println!("verify_delegation failed!");
+            return Err(WedprError::VerificationError);
+        }
+    }
+    Ok(())
+}
+
+// Verifies all proofs in a vote request whose blank ballot has been certified.
+fn verify_certified_vote_request(
+    poll_parameters: &PollParametersStorage,
+    vote_request: &VoteRequest,
+) -> Result<bool, WedprError> {
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+    let (
+        voted_ballot_sum,
+        rest_ballot_point,
+        blank_ballot_point,
+        balance_proof,
+        commitments,
+    ) = verify_vote_request_except_balance(
+        &poll_point,
+        poll_parameters,
+        vote_request,
+    )?;
+    if !verify_value_range_in_batch(
+        &commitments,
+        vote_request.get_range_proof(),
+        &poll_point,
+    ) {
+        wedpr_println!("verify_range_proof failed!");
+        return Err(WedprError::VerificationError);
+    }
+    if !verify_sum_relationship(
+        &voted_ballot_sum,
+        &rest_ballot_point,
+        &blank_ballot_point,
+        &balance_proof,
+        &BASEPOINT_G1,
+        &poll_point,
+    )? {
+        wedpr_println!("verify_balance failed!");
+        return Err(WedprError::VerificationError);
+    }
+    Ok(true)
+}
+
+/// Verifies whether the voter knows the credential encrypted in a vote
+/// request. A fake credential passes the verification as the real one does.
+pub fn verify_credential_proof(
+    poll_parameters: &PollParametersStorage,
+    vote_request: &VoteRequest,
+) -> Result<bool, WedprError> {
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+    let vote = vote_request.get_vote();
+    let encrypted_credential = vote.get_encrypted_credential();
+    let credential_proof = vote_request.get_credential_proof();
+    let challenge = bytes_to_scalar(credential_proof.get_challenge())?;
+    let response_credential =
+        bytes_to_scalar(credential_proof.get_response_credential())?;
+    let response_blinding =
+        bytes_to_scalar(credential_proof.get_response_blinding())?;
+    let commitment1 = RistrettoPoint::multiscalar_mul(
+        &[response_credential, response_blinding, challenge],
+        &[
+            *BASEPOINT_G1,
+            poll_point,
+            bytes_to_point(encrypted_credential.get_ciphertext1())?,
+        ],
+    );
+    let commitment2 =
+        RistrettoPoint::multiscalar_mul(&[response_blinding, challenge], &[
+            *BASEPOINT_G2,
+            bytes_to_point(encrypted_credential.get_ciphertext2())?,
+        ]);
+    Ok(compute_credential_challenge(
+        poll_parameters,
+        &compute_vote_hash(vote)?,
+        encrypted_credential,
+        &commitment1,
+        &commitment2,
+    ) == challenge)
+}
+
+// Verifies the plaintext equivalence tests of the differences, i.e. each
+// counter blinds all differences and partially decrypts their aggregated sums
+// exactly once, and returns the aggregated blinded differences, or None if
+// any part is invalid.
+fn verify_credential_tests(
+    counter_parameters: &CounterParametersStorage,
+    difference_list: &[(RistrettoPoint, RistrettoPoint)],
+    blinding_parts: &[CredentialBlindingPart],
+    decryption_parts: &[CredentialDecryptionPart],
+) -> Result<Option<Vec<Ballot>>, WedprError> {
+    let counter_share_list = counter_parameters.get_counter_parameters_share();
+    if blinding_parts.len() != counter_share_list.len()
+        || decryption_parts.len() != counter_share_list.len()
+    {
+        return Ok(None);
+    }
+    // Verify that each difference is blinded by every counter.
+    let mut blinded_counter_ids = BTreeSet::new();
+    for blinding_part in blinding_parts {
+        let counter_id = blinding_part.get_counter_id();
+        if !counter_share_list
+            .iter()
+            .any(|share| share.get_counter_id() == counter_id)
+            || !blinded_counter_ids.insert(counter_id)
+            || blinding_part.get_blinded_difference().len()
+                != difference_list.len()
+            || blinding_part.get_equality_proof().len() != difference_list.len()
+        {
+            return Ok(None);
+        }
+        for (difference, (blinded_difference, equality_proof)) in
+            difference_list.iter().zip(
+                blinding_part
+                    .get_blinded_difference()
+                    .iter()
+                    .zip(blinding_part.get_equality_proof()),
+            )
+        {
+            if !verify_equality_relationship_proof(
+                &bytes_to_point(blinded_difference.get_ciphertext1())?,
+                &bytes_to_point(blinded_difference.get_ciphertext2())?,
+                &Deserialize::deserialize(equality_proof)?,
+                &difference.0,
+                &difference.1,
+            )? {
+                wedpr_println!("verify credential blinding failed!");
+                return Ok(None);
+            }
+        }
+    }
+
+    // Verify the partial decryptions of the aggregated blinded differences.
+    let blinded_difference_sum =
+        aggregate_credential_blinding_parts(blinding_parts)?;
+    let mut decrypted_counter_ids = BTreeSet::new();
+    for decryption_part in decryption_parts {
+        let counter_id = decryption_part.get_counter_id();
+        let counter_share = match counter_share_list
+            .iter()
+            .find(|share| share.get_counter_id() == counter_id)
+        {
+            Some(v) => v,
+            None => return Ok(None),
+        };
+        if !decrypted_counter_ids.insert(counter_id)
+            || decryption_part.get_decrypted_part().len()
+                != difference_list.len()
+        {
+            return Ok(None);
+        }
+        let poll_point_share =
+            bytes_to_point(counter_share.get_poll_point_share())?;
+        for (blinded_difference, decrypted_part) in blinded_difference_sum
+            .iter()
+            .zip(decryption_part.get_decrypted_part())
+        {
+            if !verify_ballot_counting_part(
+                &poll_point_share,
+                blinded_difference,
+                decrypted_part,
+            )? {
+                wedpr_println!("verify credential decryption failed!");
+                return Ok(None);
+            }
+        }
+    }
+    Ok(Some(blinded_difference_sum))
+}
+
+/// Verifies whether the votes with duplicate credentials are removed from the
+/// ballot box correctly by all counters, i.e. the blinded differences of the
+/// duplicate tests and their partial decryptions are valid, and the
+/// deduplicated votes keep exactly one vote for each credential.
+pub fn verify_credential_deduplication(
+    counter_parameters: &CounterParametersStorage,
+    ballot_box: &[VoteStorage],
+    blinding_parts: &[CredentialBlindingPart],
+    decryption_parts: &[CredentialDecryptionPart],
+    deduplicated_votes: &[VoteStorage],
+) -> Result<bool, WedprError> {
+    let blinded_difference_sum = match verify_credential_tests(
+        counter_parameters,
+        &get_credential_duplicate_difference_list(ballot_box)?,
+        blinding_parts,
+        decryption_parts,
+    )? {
+        Some(v) => v,
+        None => return Ok(false),
+    };
+    Ok(remove_credential_duplicates(
+        ballot_box,
+        &blinded_difference_sum,
+        decryption_parts,
+    )? == deduplicated_votes)
+}
+
+/// Verifies whether the deduplicated votes and the credential roster are mixed
+/// by every counter exactly once, i.e. the shuffle proofs of the vote rows and
+/// the roster rows of all mix parts are valid.
+pub fn verify_credential_mix(
+    poll_parameters: &PollParametersStorage,
+    counter_parameters: &CounterParametersStorage,
+    roster: &CredentialRoster,
+    deduplicated_votes: &[VoteStorage],
+    mix_parts: &[CredentialMixPart],
+) -> Result<bool, WedprError> {
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+    let counter_share_list = counter_parameters.get_counter_parameters_share();
+    if mix_parts.len() != counter_share_list.len() {
+        return Ok(false);
+    }
+    let mut mixed_counter_ids = BTreeSet::new();
+    for (index, mix_part) in mix_parts.iter().enumerate() {
+        let counter_id = mix_part.get_counter_id();
+        if !counter_share_list
+            .iter()
+            .any(|share| share.get_counter_id() == counter_id)
+            || !mixed_counter_ids.insert(counter_id)
+        {
+            return Ok(false);
+        }
+        let (vote_row_list, roster_row_list) = get_credential_mix_input(
+            poll_parameters,
+            roster,
+            deduplicated_votes,
+            &mix_parts[..index],
+        )?;
+        let (shuffled_vote_row_list, shuffled_roster_row_list) =
+            get_credential_mix_input(
+                poll_parameters,
+                roster,
+                deduplicated_votes,
+                &mix_parts[..=index],
+            )?;
+        if !verify_shuffle_proof(
+            &poll_point,
+            &vote_row_list,
+            &shuffled_vote_row_list,
+            mix_part.get_vote_shuffle_proof(),
+        )? || !verify_shuffle_proof(
+            &poll_point,
+            &roster_row_list,
+            &shuffled_roster_row_list,
+            mix_part.get_roster_shuffle_proof(),
+        )? {
+            wedpr_println!("verify credential shuffle failed!");
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Verifies whether the mixed ballot box is filtered correctly by all
+/// counters, i.e. the blinded differences of the roster tests and their
+/// partial decryptions are valid, and the filtered vote sum aggregates exactly
+/// the mixed votes matching the roster. The mix parts should have been
+/// verified by `verify_credential_mix`.
+pub fn verify_credential_filter(
+    poll_parameters: &PollParametersStorage,
+    counter_parameters: &CounterParametersStorage,
+    mix_parts: &[CredentialMixPart],
+    blinding_parts: &[CredentialBlindingPart],
+    decryption_parts: &[CredentialDecryptionPart],
+    filtered_vote_sum: &VoteStorage,
+) -> Result<bool, WedprError> {
+    let mix_part = match mix_parts.last() {
+        Some(v) => v,
+        None => return Ok(false),
+    };
+    let blinded_difference_sum = match verify_credential_tests(
+        counter_parameters,
+        &get_credential_match_difference_list(mix_part)?,
+        blinding_parts,
+        decryption_parts,
+    )? {
+        Some(v) => v,
+        None => return Ok(false),
+    };
+    Ok(filter_votes_by_credential(
+        poll_parameters,
+        mix_parts,
+        &blinded_difference_sum,
+        decryption_parts,
+    )? == *filtered_vote_sum)
+}
+
+/// Verifies whether the vote result is decrypted from the vote sum filtered by
+/// the credentials, which should have been verified by
+/// `verify_credential_filter`.
+pub fn verify_filtered_vote_result(
+    poll_parameters: &PollParametersStorage,
+    filtered_vote_sum: &VoteStorage,
+    aggregated_decrypted_result: &DecryptedResultPartStorage,
+    vote_result: &VoteResultStorage,
+    public_key: &[u8],
+) -> Result<bool, WedprError> {
+    check_poll_parameters(poll_parameters, public_key)?;
+    verify_decrypted_vote_result(
+        poll_parameters,
+        filtered_vote_sum,
+        aggregated_decrypted_result,
+        vote_result,
+    )
+}
+
+// Verifies all proofs in a vote request except the certification of the blank
+// ballot, the balance proof and the range proof, and returns the points and
+// the proof to verify the balance, followed by the commitments to verify the
+// range proof of the vote request.
+fn verify_vote_request_except_balance(
+    poll_point: &RistrettoPoint,
+    poll_parameters: &PollParametersStorage,
+    vote_request: &VoteRequest,
+) -> Result<
+    (
+        RistrettoPoint,
+        RistrettoPoint,
+        RistrettoPoint,
+        ArithmeticProof,
+        Vec<RistrettoPoint>,
+    ),
+    WedprError,
+> {
+    // The delegated weights are spent together with the weight of the voter.
+    let blank_ballot = get_combined_blank_ballot(vote_request.get_vote())?;
+    if !verify_nullifier(poll_parameters, vote_request)? {
+        wedpr_println!("verify_nullifier failed!");
+        return Err(WedprError::VerificationError);
+    }
+    if !verify_sequence_binding(poll_parameters, vote_request)? {
+        wedpr_println!("verify_sequence_binding failed!");
+        return Err(WedprError::VerificationError);
+    }
+    if vote_request.get_vote().has_encrypted_credential()
+        && !verify_credential_proof(poll_parameters, vote_request)?
+    {
+        wedpr_println!("verify_credential_proof failed!");
+        return Err(WedprError::VerificationError);
+    }
+    if !verify_question_ballots(poll_point, poll_parameters, vote_request)? {
+        wedpr_println!("verify_question_ballots failed!");
+        return Err(WedprError::VerificationError);
+    }
+    if !verify_against_ballots(poll_point, poll_parameters, vote_request)? {
+        wedpr_println!("verify_against_ballots failed!");
+        return Err(WedprError::VerificationError);
+    }
+    // The voted candidates must be the candidates of the poll, so that the
+    // aggregated ballots partition the voter weight.
+    let candidate_list = poll_parameters.get_candidates().get_candidate();
+    let voted_ballot_list = vote_request.get_vote().get_voted_ballot();
+    if voted_ballot_list.len() != candidate_list.len()
+        || voted_ballot_list.iter().zip(candidate_list).any(
+            |(candidate_ballot, candidate)| {
+                candidate_ballot.get_candidate() != candidate
+            },
+        )
+    {
+        wedpr_println!("verify voted candidates failed!");
+        return Err(WedprError::VerificationError);
+    }
 
+    // The votes against a candidate follow the votes for it in the range
+    // proof, and also count toward the voter weight.
+    let against_ballot_list = vote_request.get_vote().get_against_ballot();
     let mut commitments: Vec<RistrettoPoint> = Vec::new();
     let mut voted_ballot_sum = RistrettoPoint::default();
-    for candidate_ballot_pair in vote_request.get_vote().get_voted_ballot() {
+    for (i, candidate_ballot_pair) in voted_ballot_list.iter().enumerate() {
         let ballot = candidate_ballot_pair.get_ballot();
         commitments.push(bytes_to_point(&ballot.get_ciphertext1())?);
         voted_ballot_sum += bytes_to_point(&ballot.get_ciphertext1())?;
+        if let Some(against_ballot_pair) = against_ballot_list.get(i) {
+            let against_ballot = against_ballot_pair.get_ballot();
+            let against_point =
+                bytes_to_point(against_ballot.get_ciphertext1())?;
+            commitments.push(against_point);
+            voted_ballot_sum += against_point;
+        }
     }
 
-    let rest_ballot =
-        vote_request.get_vote().get_rest_ballot().get_ciphertext1();
-    let rest_ballot_point = bytes_to_point(rest_ballot)?;
+    let rest_ballot = vote_request.get_vote().get_rest_ballot();
+    if !verify_rest_ballot_format(
+        poll_point,
+        rest_ballot,
+        vote_request.get_rest_format_proof(),
+    )? {
+        wedpr_println!("verify_rest_ballot_format failed!");
+        return Err(WedprError::VerificationError);
+    }
+    let rest_ballot_point = bytes_to_point(rest_ballot.get_ciphertext1())?;
     commitments.push(rest_ballot_point);
     align_commitment_list_if_needed(&mut commitments);
-    let range_proof = vote_request.get_range_proof();
-    if !verify_value_range_in_batch(&commitments, range_proof, &poll_point) {
-        return Err(WedprError::VerificationError);
+    if vote_request.has_aggregated_format_proof() {
+        let ballot_list: Vec<&Ballot> = voted_ballot_list
+            .iter()
+            .chain(against_ballot_list)
+            .map(|candidate_ballot| candidate_ballot.get_ballot())
+            .collect();
+        if !vote_request.get_ballot_proof().is_empty()
+            || !verify_aggregated_format_proof(
+                poll_point,
+                &ballot_list,
+                vote_request.get_aggregated_format_proof(),
+            )?
+        {
+            wedpr_println!("verify_aggregated_format_proof failed!");
+            return Err(WedprError::VerificationError);
+        }
     }
     for candidate_ballot in vote_request.get_ballot_proof() {
         let candidate = candidate_ballot.get_key();
@@ -80,88 +831,792 @@ pub fn verify_vote_request(
             &format_proof,
             &*BASEPOINT_G1,
             &*BASEPOINT_G2,
-            &poll_point,
+            poll_point,
         )? {
             return Err(WedprError::VerificationError);
         }
     }
     let balance_proof =
         Deserialize::deserialize(vote_request.get_sum_balance_proof())?;
-    if !verify_sum_relationship(
-        &voted_ballot_sum,
-        &bytes_to_point(&rest_ballot)?,
-        &bytes_to_point(&blank_ballot.get_ciphertext1())?,
-        &balance_proof,
-        &BASEPOINT_G1,
-        &poll_point,
-    )? {
-        wedpr_println!("verify_balance failed!");
-        return Err(WedprError::VerificationError);
-    }
-    Ok(true)
+    Ok((
+        voted_ballot_sum,
+        rest_ballot_point,
+        bytes_to_point(blank_ballot.get_ciphertext1())?,
+        balance_proof,
+        commitments,
+    ))
 }
 
-/// Verifies whether a partially decrypted result from a counter is valid.
-pub fn verify_count_request(
+// Verifies that a vote of a for/against poll has a ballot against each
+// candidate in the candidate order with a valid format proof, and that a vote
+// of any other poll has no against ballots. The format proofs are left to the
+// aggregated format proof if the vote has one.
+fn verify_against_ballots(
+    poll_point: &RistrettoPoint,
     poll_parameters: &PollParametersStorage,
-    encrypted_vote_sum: &VoteStorage,
-    counter_share: &RistrettoPoint,
-    partially_decrypted_result: &DecryptedResultPartStorage,
+    vote_request: &VoteRequest,
 ) -> Result<bool, WedprError> {
-    // Verify the total votes.
-    let blank_c2_sum = bytes_to_point(
-        &encrypted_vote_sum.get_blank_ballot().get_ciphertext2(),
-    )?;
-    let blank_equality_proof_bytes = partially_decrypted_result
-        .get_blank_part()
-        .get_equality_proof();
-    let blank_c2_r = bytes_to_point(
-        &partially_decrypted_result
-            .get_blank_part()
-            .get_blinding_c2(),
-    )?;
-    let blank_equality_proof =
-        Deserialize::deserialize(&blank_equality_proof_bytes)?;
-    if !verify_equality_relationship_proof(
-        &counter_share,
-        &blank_c2_r,
-        &&blank_equality_proof,
-        &BASEPOINT_G2,
-        &blank_c2_sum,
-    )? {
+    let against_ballot_list = vote_request.get_vote().get_against_ballot();
+    let against_proof_list = vote_request.get_against_ballot_proof();
+    if !poll_parameters.get_against_voting() {
+        return Ok(
+            against_ballot_list.is_empty() && against_proof_list.is_empty()
+        );
+    }
+    let candidate_list = poll_parameters.get_candidates().get_candidate();
+    if against_ballot_list.len() != candidate_list.len()
+        || against_ballot_list.iter().zip(candidate_list).any(
+            |(against_ballot_pair, candidate)| {
+                against_ballot_pair.get_candidate() != candidate
+            },
+        )
+    {
         return Ok(false);
     }
-
-    // Verify the votes for each candidate.
-    for candidate in poll_parameters.get_candidates().get_candidate() {
-        let candidate_ballot =
-            get_ballot_by_candidate(encrypted_vote_sum, candidate)?;
-        let candidate_c2_sum =
-            bytes_to_point(&candidate_ballot.get_ciphertext2())?;
-        let counting_part = get_counting_part_by_candidate(
-            partially_decrypted_result,
-            candidate,
+    if vote_request.has_aggregated_format_proof() {
+        return Ok(against_proof_list.is_empty());
+    }
+    if against_proof_list.len() != candidate_list.len() {
+        return Ok(false);
+    }
+    for (against_ballot_pair, against_proof_pair) in
+        against_ballot_list.iter().zip(against_proof_list)
+    {
+        if against_proof_pair.get_key() != against_ballot_pair.get_candidate() {
+            return Ok(false);
+        }
+        let against_ballot = against_ballot_pair.get_ballot();
+        let format_proof = Deserialize::deserialize(
+            against_proof_pair.get_value().get_format_proof(),
         )?;
-        let candidate_c2_r = bytes_to_point(&counting_part.get_blinding_c2())?;
-        let candidate_equality_proof =
-            Deserialize::deserialize(counting_part.get_equality_proof())?;
-        if !verify_equality_relationship_proof(
-            &counter_share,
-            &candidate_c2_r,
-            &candidate_equality_proof,
+        if !verify_format_proof(
+            &bytes_to_point(against_ballot.get_ciphertext1())?,
+            &bytes_to_point(against_ballot.get_ciphertext2())?,
+            &format_proof,
+            &BASEPOINT_G1,
             &BASEPOINT_G2,
-            &candidate_c2_sum,
+            poll_point,
         )? {
-            wedpr_println!("verify_equality failed!");
             return Ok(false);
         }
     }
     Ok(true)
 }
 
-/// Verifies whether the final vote result is valid.
-pub fn verify_vote_result(
-    poll_parameters: &PollParametersStorage,
+// Verifies an aggregated format proof, i.e. every ballot in the list is
+// encrypted with the poll point, by recomputing the single challenge from the
+// responses.
+fn verify_aggregated_format_proof(
+    poll_point: &RistrettoPoint,
+    ballot_list: &[&Ballot],
+    format_proof: &AggregatedFormatProof,
+) -> Result<bool, WedprError> {
+    let response_value_list = format_proof.get_response_value();
+    let response_blinding_list = format_proof.get_response_blinding();
+    if response_value_list.len() != ballot_list.len()
+        || response_blinding_list.len() != ballot_list.len()
+    {
+        return Ok(false);
+    }
+    let challenge = bytes_to_scalar(format_proof.get_challenge())?;
+    let mut commitment_list = Vec::new();
+    for ((ballot, response_value), response_blinding) in ballot_list
+        .iter()
+        .zip(response_value_list)
+        .zip(response_blinding_list)
+    {
+        let response_value = bytes_to_scalar(response_value)?;
+        let response_blinding = bytes_to_scalar(response_blinding)?;
+        commitment_list.push((
+            RistrettoPoint::multiscalar_mul(
+                &[response_value, response_blinding, challenge],
+                &[
+                    *BASEPOINT_G1,
+                    *poll_point,
+                    bytes_to_point(ballot.get_ciphertext1())?,
+                ],
+            ),
+            RistrettoPoint::multiscalar_mul(
+                &[response_blinding, challenge],
+                &[*BASEPOINT_G2, bytes_to_point(ballot.get_ciphertext2())?],
+            ),
+        ));
+    }
+    Ok(compute_aggregated_format_challenge(
+        poll_point,
+        ballot_list,
+        &commitment_list,
+    ) == challenge)
+}
+
+// Verifies whether the ballots of each question in a multi-question poll are
+// valid, and sum to the voter weight in the blank ballot.
+fn verify_question_ballots(
+    poll_point: &RistrettoPoint,
+    poll_parameters: &PollParametersStorage,
+    vote_request: &VoteRequest,
+) -> Result<bool, WedprError> {
+    let vote = vote_request.get_vote();
+    let question_list = poll_parameters.get_question();
+    if vote.get_question_ballot().len() != question_list.len()
+        || vote_request.get_question_proof().len() != question_list.len()
+    {
+        return Ok(false);
+    }
+    let blank_ballot_point =
+        bytes_to_point(get_combined_blank_ballot(vote)?.get_ciphertext1())?;
+    for (question, (question_ballot, question_proof)) in
+        question_list.iter().zip(
+            vote.get_question_ballot()
+                .iter()
+                .zip(vote_request.get_question_proof()),
+        )
+    {
+        let question_id = question.get_question_id();
+        if question_ballot.get_question_id() != question_id
+            || question_proof.get_question_id() != question_id
+        {
+            return Ok(false);
+        }
+        // The voted candidates must be the candidates of the question.
+        let candidate_list = question.get_candidates().get_candidate();
+        if question_ballot.get_voted_ballot().len() != candidate_list.len()
+            || question_ballot
+                .get_voted_ballot()
+                .iter()
+                .zip(candidate_list)
+                .any(|(candidate_ballot, candidate)| {
+                    candidate_ballot.get_candidate() != candidate
+                })
+        {
+            return Ok(false);
+        }
+
+        if question_proof.has_aggregated_format_proof() {
+            let ballot_list: Vec<&Ballot> = question_ballot
+                .get_voted_ballot()
+                .iter()
+                .map(|candidate_ballot| candidate_ballot.get_ballot())
+                .collect();
+            if !question_proof.get_ballot_proof().is_empty()
+                || !verify_aggregated_format_proof(
+                    poll_point,
+                    &ballot_list,
+                    question_proof.get_aggregated_format_proof(),
+                )?
+            {
+                return Ok(false);
+            }
+        }
+
+        let mut commitments: Vec<RistrettoPoint> = Vec::new();
+        let mut voted_ballot_sum = RistrettoPoint::default();
+        for candidate_ballot in question_ballot.get_voted_ballot() {
+            let ballot = candidate_ballot.get_ballot();
+            let ciphertext1 = bytes_to_point(ballot.get_ciphertext1())?;
+            commitments.push(ciphertext1);
+            voted_ballot_sum += ciphertext1;
+            if question_proof.has_aggregated_format_proof() {
+                continue;
+            }
+            let ciphertext2 = bytes_to_point(ballot.get_ciphertext2())?;
+            let ballot_proof =
+                match question_proof.get_ballot_proof().iter().find(|pair| {
+                    pair.get_key() == candidate_ballot.get_candidate()
+                }) {
+                    Some(v) => v.get_value(),
+                    None => return Ok(false),
+                };
+            let format_proof =
+                Deserialize::deserialize(ballot_proof.get_format_proof())?;
+            if !verify_format_proof(
+                &ciphertext1,
+                &ciphertext2,
+                &format_proof,
+                &BASEPOINT_G1,
+                &BASEPOINT_G2,
+                poll_point,
+            )? {
+                return Ok(false);
+            }
+        }
+        let rest_ballot = question_ballot.get_rest_ballot();
+        if !verify_rest_ballot_format(
+            poll_point,
+            rest_ballot,
+            question_proof.get_rest_format_proof(),
+        )? {
+            return Ok(false);
+        }
+        let rest_ballot_point = bytes_to_point(rest_ballot.get_ciphertext1())?;
+        commitments.push(rest_ballot_point);
+        align_commitment_list_if_needed(&mut commitments);
+        if !verify_value_range_in_batch(
+            &commitments,
+            question_proof.get_range_proof(),
+            poll_point,
+        ) {
+            return Ok(false);
+        }
+        let balance_proof =
+            Deserialize::deserialize(question_proof.get_sum_balance_proof())?;
+        if !verify_sum_relationship(
+            &voted_ballot_sum,
+            &rest_ballot_point,
+            &blank_ballot_point,
+            &balance_proof,
+            &BASEPOINT_G1,
+            poll_point,
+        )? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+// Verifies whether the rest ballot is a well-formed ciphertext, so that the
+// aggregated rest ballots can be decrypted as the abstained votes.
+fn verify_rest_ballot_format(
+    poll_point: &RistrettoPoint,
+    rest_ballot: &Ballot,
+    rest_format_proof: &[u8],
+) -> Result<bool, WedprError> {
+    let format_proof = Deserialize::deserialize(rest_format_proof)?;
+    verify_format_proof(
+        &bytes_to_point(rest_ballot.get_ciphertext1())?,
+        &bytes_to_point(rest_ballot.get_ciphertext2())?,
+        &format_proof,
+        &BASEPOINT_G1,
+        &BASEPOINT_G2,
+        poll_point,
+    )
+}
+
+/// Verifies many vote requests in batch, and returns the indices of invalid
+/// vote requests. The signatures and format proofs are verified concurrently,
+/// and the balance proofs of all voters are verified in one batch, which falls
+/// back to verifying them one by one only if the batch fails. The range proofs
+/// are verified last and concurrently, only for the vote requests passing all
+/// other checks, since each of them is an aggregated range proof made by a
+/// different voter.
+pub fn verify_vote_requests_in_batch(
+    poll_parameters: &PollParametersStorage,
+    vote_request_list: &[VoteRequest],
+    public_key: &[u8],
+) -> Result<Vec<usize>, WedprError> {
+    check_poll_parameters(poll_parameters, public_key)?;
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+    let thread_count = std::thread::available_parallelism()
+        .map(|v| v.get())
+        .unwrap_or(1);
+    let chunk_size =
+        ((vote_request_list.len() + thread_count - 1) / thread_count).max(1);
+    let mut balance_list = Vec::with_capacity(vote_request_list.len());
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = vote_request_list
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|vote_request| {
+                            verify_vote_signature(
+                                poll_parameters,
+                                vote_request,
+                                public_key,
+                            )
+                            .and_then(|_| {
+                                verify_vote_delegations(
+                                    poll_parameters,
+                                    vote_request,
+                                    public_key,
+                                )
+                            })
+                            .and_then(|_| {
+                                verify_vote_request_except_balance(
+                                    &poll_point,
+                                    poll_parameters,
+                                    vote_request,
+                                )
+                            })
+                            .ok()
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        for handle in handles {
+            balance_list.extend(
+                handle.join().expect("verification thread should not panic"),
+            );
+        }
+    });
+
+    let mut invalid_indices = Vec::new();
+    let mut valid_indices = Vec::new();
+    let mut c1_point_list = Vec::new();
+    let mut c2_point_list = Vec::new();
+    let mut c3_point_list = Vec::new();
+    let mut proof_list = Vec::new();
+    let mut commitment_list = Vec::new();
+    for (index, balance) in balance_list.into_iter().enumerate() {
+        match balance {
+            Some((c1_point, c2_point, c3_point, proof, commitments)) => {
+                valid_indices.push(index);
+                c1_point_list.push(c1_point);
+                c2_point_list.push(c2_point);
+                c3_point_list.push(c3_point);
+                proof_list.push(proof);
+                commitment_list.push(commitments);
+            },
+            None => invalid_indices.push(index),
+        }
+    }
+    let mut balanced = vec![true; valid_indices.len()];
+    if !proof_list.is_empty()
+        && !verify_sum_relationship_in_batch(
+            &c1_point_list,
+            &c2_point_list,
+            &c3_point_list,
+            &proof_list,
+            &BASEPOINT_G1,
+            &poll_point,
+        )
+        .unwrap_or(false)
+    {
+        // Find the invalid balance proofs.
+        for (i, balanced) in balanced.iter_mut().enumerate() {
+            *balanced = verify_sum_relationship(
+                &c1_point_list[i],
+                &c2_point_list[i],
+                &c3_point_list[i],
+                &proof_list[i],
+                &BASEPOINT_G1,
+                &poll_point,
+            )
+            .unwrap_or(false);
+        }
+    }
+
+    let mut range_list = Vec::with_capacity(valid_indices.len());
+    for ((index, commitments), balanced) in
+        valid_indices.into_iter().zip(commitment_list).zip(balanced)
+    {
+        if balanced {
+            range_list.push((index, commitments));
+        } else {
+            invalid_indices.push(index);
+        }
+    }
+    let range_chunk_size =
+        ((range_list.len() + thread_count - 1) / thread_count).max(1);
+    let poll_point = &poll_point;
+    std::thread::scope(|scope| {
+        let handles: Vec<_> = range_list
+            .chunks(range_chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .filter(|(index, commitments)| {
+                            !verify_value_range_in_batch(
+                                commitments,
+                                vote_request_list[*index].get_range_proof(),
+                                poll_point,
+                            )
+                        })
+                        .map(|(index, _)| *index)
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+        for handle in handles {
+            invalid_indices.extend(
+                handle.join().expect("verification thread should not panic"),
+            );
+        }
+    });
+    invalid_indices.sort_unstable();
+    Ok(invalid_indices)
+}
+
+/// Verifies whether a partially decrypted result sent by a counter is valid.
+/// Use blame_count_request to find out which part of the result is invalid.
+pub fn verify_count_request(
+    poll_parameters: &PollParametersStorage,
+    counter_id: &str,
+    encrypted_vote_sum: &VoteStorage,
+    partially_decrypted_result: &DecryptedResultPartStorage,
+    public_key: &[u8],
+) -> Result<bool, WedprError> {
+    match blame_count_request(
+        poll_parameters,
+        counter_id,
+        encrypted_vote_sum,
+        partially_decrypted_result,
+        public_key,
+    )? {
+        Some(blame_report) => {
+            wedpr_println!("verify_count_request failed: {:?}", blame_report);
+            Ok(false)
+        },
+        None => Ok(true),
+    }
+}
+
+/// Checks a partially decrypted result sent by a counter part by part against
+/// the share of that counter in the poll parameters, and returns a blame
+/// report of the counter with the first invalid part and the failed check, or
+/// None if the result is valid. The counter id should identify the sender of
+/// the result rather than be copied from the result, which must declare the
+/// same counter id. It only returns an error if the vote sum itself is
+/// invalid.
+pub fn blame_count_request(
+    poll_parameters: &PollParametersStorage,
+    counter_id: &str,
+    encrypted_vote_sum: &VoteStorage,
+    partially_decrypted_result: &DecryptedResultPartStorage,
+    public_key: &[u8],
+) -> Result<Option<CounterBlameReport>, WedprError> {
+    check_poll_parameters(poll_parameters, public_key)?;
+    blame_checked_count_request(
+        poll_parameters,
+        counter_id,
+        encrypted_vote_sum,
+        partially_decrypted_result,
+    )
+}
+
+// Blames a partially decrypted result as blame_count_request does, for the
+// poll parameters that have already been checked.
+pub(crate) fn blame_checked_count_request(
+    poll_parameters: &PollParametersStorage,
+    counter_id: &str,
+    encrypted_vote_sum: &VoteStorage,
+    partially_decrypted_result: &DecryptedResultPartStorage,
+) -> Result<Option<CounterBlameReport>, WedprError> {
+    let make_blame_report =
+        |question_id: &str, candidate: &str, reason: CounterBlameReason| {
+            let mut blame_report = CounterBlameReport::new();
+            blame_report.set_counter_id(counter_id.to_string());
+            blame_report.set_question_id(question_id.to_string());
+            blame_report.set_candidate(candidate.to_string());
+            blame_report.set_reason(reason);
+            Some(blame_report)
+        };
+
+    // Check the counter.
+    let counter_share = match poll_parameters
+        .get_counter_parameters()
+        .get_counter_parameters_share()
+        .iter()
+        .find(|share| share.get_counter_id() == counter_id)
+    {
+        Some(share) => bytes_to_point(share.get_poll_point_share())?,
+        None => {
+            return Ok(make_blame_report(
+                "",
+                "",
+                CounterBlameReason::UNKNOWN_COUNTER,
+            ))
+        },
+    };
+    if partially_decrypted_result.get_blank_part().get_counter_id()
+        != counter_id
+    {
+        return Ok(make_blame_report(
+            "",
+            "",
+            CounterBlameReason::MISMATCHED_COUNTER,
+        ));
+    }
+    let counter_share = &counter_share;
+
+    // Check the total votes.
+    if let Some(reason) = check_ballot_counting_part(
+        counter_share,
+        encrypted_vote_sum.get_blank_ballot(),
+        Some(partially_decrypted_result.get_blank_part())
+            .filter(|_| partially_decrypted_result.has_blank_part()),
+    )? {
+        return Ok(make_blame_report(
+            "",
+            POLL_RESULT_KEY_TOTAL_BALLOTS,
+            reason,
+        ));
+    }
+
+    // Check the votes for each candidate.
+    for candidate in poll_parameters.get_candidates().get_candidate() {
+        let candidate_ballot =
+            get_ballot_by_candidate(encrypted_vote_sum, candidate)?;
+        let counting_part = get_counting_part_by_candidate(
+            partially_decrypted_result,
+            candidate,
+        )
+        .ok();
+        if let Some(reason) = check_ballot_counting_part(
+            counter_share,
+            &candidate_ballot,
+            counting_part.as_ref(),
+        )? {
+            return Ok(make_blame_report("", candidate, reason));
+        }
+    }
+
+    // Check the votes against each candidate in a for/against poll.
+    if poll_parameters.get_against_voting() {
+        for candidate in poll_parameters.get_candidates().get_candidate() {
+            let against_ballot = get_ballot_from_list(
+                encrypted_vote_sum.get_against_ballot(),
+                candidate,
+            )?;
+            let counting_part = get_counting_part_from_list(
+                partially_decrypted_result.get_against_part(),
+                candidate,
+            )
+            .ok();
+            if let Some(reason) = check_ballot_counting_part(
+                counter_share,
+                &against_ballot,
+                counting_part.as_ref(),
+            )? {
+                let mut blame_report = make_blame_report("", candidate, reason);
+                if let Some(v) = blame_report.as_mut() {
+                    v.set_against_part(true);
+                }
+                return Ok(blame_report);
+            }
+        }
+    }
+
+    // Check the abstained votes.
+    if encrypted_vote_sum.has_rest_ballot() {
+        if let Some(reason) = check_ballot_counting_part(
+            counter_share,
+            encrypted_vote_sum.get_rest_ballot(),
+            Some(partially_decrypted_result.get_rest_part())
+                .filter(|_| partially_decrypted_result.has_rest_part()),
+        )? {
+            return Ok(make_blame_report(
+                "",
+                POLL_RESULT_KEY_ABSTAINED_BALLOTS,
+                reason,
+            ));
+        }
+    }
+
+    // Check the votes for each candidate of each question.
+    for question in poll_parameters.get_question() {
+        let question_id = question.get_question_id();
+        let question_ballot =
+            get_question_ballot(encrypted_vote_sum, question_id)?;
+        let question_part = match get_question_counting_part(
+            partially_decrypted_result,
+            question_id,
+        ) {
+            Ok(v) => v,
+            Err(_) => {
+                return Ok(make_blame_report(
+                    question_id,
+                    "",
+                    CounterBlameReason::MISSING_PART,
+                ))
+            },
+        };
+        for candidate in question.get_candidates().get_candidate() {
+            let candidate_ballot = get_ballot_from_list(
+                question_ballot.get_voted_ballot(),
+                candidate,
+            )?;
+            let counting_part = get_counting_part_from_list(
+                question_part.get_candidate_part(),
+                candidate,
+            )
+            .ok();
+            if let Some(reason) = check_ballot_counting_part(
+                counter_share,
+                &candidate_ballot,
+                counting_part.as_ref(),
+            )? {
+                return Ok(make_blame_report(question_id, candidate, reason));
+            }
+        }
+        if question_ballot.has_rest_ballot() {
+            if let Some(reason) = check_ballot_counting_part(
+                counter_share,
+                question_ballot.get_rest_ballot(),
+                Some(question_part.get_rest_part())
+                    .filter(|_| question_part.has_rest_part()),
+            )? {
+                return Ok(make_blame_report(
+                    question_id,
+                    POLL_RESULT_KEY_ABSTAINED_BALLOTS,
+                    reason,
+                ));
+            }
+        }
+    }
+    Ok(None)
+}
+
+// Checks the partially decrypted part of a ciphertext from a counter, and
+// returns the failed check if the part is invalid. It only returns an error if
+// the ciphertext itself is invalid.
+fn check_ballot_counting_part(
+    counter_share: &RistrettoPoint,
+    ballot: &Ballot,
+    counting_part: Option<&CountingPart>,
+) -> Result<Option<CounterBlameReason>, WedprError> {
+    let ciphertext2 = bytes_to_point(ballot.get_ciphertext2())?;
+    let counting_part = match counting_part {
+        Some(v) => v,
+        None => return Ok(Some(CounterBlameReason::MISSING_PART)),
+    };
+    let blinding_c2 = match bytes_to_point(counting_part.get_blinding_c2()) {
+        Ok(v) => v,
+        Err(_) => return Ok(Some(CounterBlameReason::MALFORMED_POINT)),
+    };
+    let equality_proof =
+        match Deserialize::deserialize(counting_part.get_equality_proof()) {
+            Ok(v) => v,
+            Err(_) => return Ok(Some(CounterBlameReason::MALFORMED_PROOF)),
+        };
+    match verify_equality_relationship_proof(
+        counter_share,
+        &blinding_c2,
+        &equality_proof,
+        &BASEPOINT_G2,
+        &ciphertext2,
+    ) {
+        Ok(true) => Ok(None),
+        _ => Ok(Some(CounterBlameReason::INVALID_EQUALITY_PROOF)),
+    }
+}
+
+// Verifies whether the partially decrypted part of a ciphertext, such as the
+// aggregated rest ballot, is valid.
+fn verify_ballot_counting_part(
+    counter_share: &RistrettoPoint,
+    ballot: &Ballot,
+    counting_part: &CountingPart,
+) -> Result<bool, WedprError> {
+    let equality_proof =
+        Deserialize::deserialize(counting_part.get_equality_proof())?;
+    verify_equality_relationship_proof(
+        counter_share,
+        &bytes_to_point(counting_part.get_blinding_c2())?,
+        &equality_proof,
+        &BASEPOINT_G2,
+        &bytes_to_point(ballot.get_ciphertext2())?,
+    )
+}
+
+/// Verifies whether a vote receipt proves that the vote is included in the
+/// audit trail of the vote sum with the published vote root.
+pub fn verify_vote_receipt(
+    vote_part: &VoteStorage,
+    receipt: &VoteReceipt,
+    vote_root: &[u8],
+) -> Result<bool, WedprError> {
+    if compute_vote_hash(vote_part)? != receipt.get_vote_hash()
+        || receipt.get_vote_root() != vote_root
+    {
+        return Ok(false);
+    }
+    match compute_vote_root_from_path(
+        receipt.get_vote_hash(),
+        receipt.get_leaf_index() as usize,
+        receipt.get_leaf_count() as usize,
+        receipt.get_sibling_hash(),
+    ) {
+        Some(expected_vote_root) => Ok(expected_vote_root == vote_root),
+        None => Ok(false),
+    }
+}
+
+/// Verifies whether the final vote result is valid. The vote sum is
+/// re-aggregated from all accepted votes in the order of aggregation, including
+/// the votes replaced later, and must match the published vote root.
+/// It only checks the vote sum against the accepted votes and the vote root,
+/// and the decryption of the vote result. The accepted votes carry no proofs,
+/// so their certification and proofs are not checked, and a coordinator could
+/// commit unsigned or malformed votes to the vote root. Use
+/// verify_poll_transcript to verify the vote requests of a finished poll as
+/// well.
+pub fn verify_vote_result(
+    poll_parameters: &PollParametersStorage,
+    vote_sum: &VoteStorage,
+    vote_root: &[u8],
+    accepted_votes: &[VoteStorage],
+    aggregated_decrypted_result: &DecryptedResultPartStorage,
+    vote_result: &VoteResultStorage,
+    public_key: &[u8],
+) -> Result<bool, WedprError> {
+    check_poll_parameters(poll_parameters, public_key)?;
+    let expected_vote_sum =
+        match aggregate_accepted_votes(poll_parameters, accepted_votes)? {
+            Some(v) => v,
+            None => return Ok(false),
+        };
+    if vote_sum.get_vote_root() != vote_root
+        || expected_vote_sum.get_vote_root() != vote_root
+        || expected_vote_sum.get_blank_ballot() != vote_sum.get_blank_ballot()
+        || expected_vote_sum.get_voted_ballot() != vote_sum.get_voted_ballot()
+        || expected_vote_sum.get_rest_ballot() != vote_sum.get_rest_ballot()
+        || expected_vote_sum.get_question_ballot()
+            != vote_sum.get_question_ballot()
+        || expected_vote_sum.get_against_ballot()
+            != vote_sum.get_against_ballot()
+        || expected_vote_sum.get_voted_ballot_unlisted()
+            != vote_sum.get_voted_ballot_unlisted()
+    {
+        wedpr_println!("verify vote sum against the accepted votes failed!");
+        return Ok(false);
+    }
+    verify_decrypted_vote_result(
+        poll_parameters,
+        vote_sum,
+        aggregated_decrypted_result,
+        vote_result,
+    )
+}
+
+// Re-aggregates votes in the order of aggregation, where a later vote of the
+// same voter replaces its previous vote, together with its unlisted ballots.
+// It returns None if any vote cannot be aggregated.
+fn aggregate_accepted_votes<'a>(
+    poll_parameters: &PollParametersStorage,
+    accepted_votes: impl IntoIterator<Item = &'a VoteStorage>,
+) -> Result<Option<VoteStorage>, WedprError> {
+    let mut vote_sum = VoteStorage::new();
+    let mut latest_votes: BTreeMap<&[u8], &VoteStorage> = BTreeMap::new();
+    for vote in accepted_votes {
+        let aggregated = match latest_votes.get(vote.get_nullifier()) {
+            Some(previous_vote) => replace_vote_sum_response_unlisted(
+                poll_parameters,
+                previous_vote,
+                vote,
+                &mut vote_sum,
+            )?,
+            None => aggregate_vote_sum_response_unlisted(
+                poll_parameters,
+                vote,
+                &mut vote_sum,
+            )?,
+        };
+        if !aggregated {
+            wedpr_println!("aggregate vote failed!");
+            return Ok(None);
+        }
+        latest_votes.insert(vote.get_nullifier(), vote);
+    }
+    Ok(Some(vote_sum))
+}
+
+// Verifies whether the vote result is decrypted from the vote sum.
+fn verify_decrypted_vote_result(
+    poll_parameters: &PollParametersStorage,
     vote_sum: &VoteStorage,
     aggregated_decrypted_result: &DecryptedResultPartStorage,
     vote_result: &VoteResultStorage,
@@ -183,7 +1638,25 @@ pub fn verify_vote_result(
         return Ok(false);
     }
 
-    for candidate in poll_parameters.get_candidates().get_candidate() {
+    // Every entry must be present exactly once.
+    let candidate_list = poll_parameters.get_candidates().get_candidate();
+    if vote_result.get_result().len()
+        != candidate_list.len() + 1 + vote_sum.has_rest_ballot() as usize
+        || vote_result.get_question_result().len()
+            != poll_parameters.get_question().len()
+        || vote_result.get_against_result().len()
+            != if poll_parameters.get_against_voting() {
+                candidate_list.len()
+            } else {
+                0
+            }
+    {
+        wedpr_println!("verify vote result entries failed!");
+        return Ok(false);
+    }
+
+    let mut candidate_result_sum = 0;
+    for candidate in candidate_list {
         let ballot = get_ballot_by_candidate(vote_sum, candidate)?;
         let candidate_counting_part = get_counting_part_by_candidate(
             aggregated_decrypted_result,
@@ -192,7 +1665,38 @@ pub fn verify_vote_result(
         let candidate_c2_r_sum =
             bytes_to_point(&candidate_counting_part.get_blinding_c2())?;
 
-        let candidate_result = get_int64_by_candidate(vote_result, candidate)?;
+        let mut candidate_result =
+            get_int64_by_candidate(vote_result, candidate)?;
+        // The result of a for/against poll is the net support, so the votes
+        // for the candidate are the net support plus the votes against it.
+        if poll_parameters.get_against_voting() {
+            let against_ballot =
+                get_ballot_from_list(vote_sum.get_against_ballot(), candidate)?;
+            let against_counting_part = get_counting_part_from_list(
+                aggregated_decrypted_result.get_against_part(),
+                candidate,
+            )?;
+            let against_result = get_int64_from_list(
+                vote_result.get_against_result(),
+                candidate,
+            )?;
+            candidate_result += against_result;
+            if against_result < 0 || candidate_result < 0 {
+                wedpr_println!("verify against {} failed!", candidate);
+                return Ok(false);
+            }
+            let expected_against_ballot_result =
+                bytes_to_point(against_ballot.get_ciphertext1())?
+                    - bytes_to_point(against_counting_part.get_blinding_c2())?;
+            if !expected_against_ballot_result
+                .eq(&(*BASEPOINT_G1 * Scalar::from(against_result as u64)))
+            {
+                wedpr_println!("verify against {} failed!", candidate);
+                return Ok(false);
+            }
+            candidate_result_sum += against_result;
+        }
+        candidate_result_sum += candidate_result;
         let expected_candidate_ballot_result =
             bytes_to_point(&ballot.get_ciphertext1())? - (candidate_c2_r_sum);
         if !expected_candidate_ballot_result
@@ -202,9 +1706,106 @@ pub fn verify_vote_result(
             return Ok(false);
         }
     }
+    if vote_sum.has_rest_ballot()
+        && !verify_abstained_result(
+            vote_sum.get_rest_ballot(),
+            aggregated_decrypted_result.get_rest_part(),
+            vote_result.get_result(),
+            candidate_result_sum,
+            blank_result,
+        )?
+    {
+        wedpr_println!("verify abstained result failed!");
+        return Ok(false);
+    }
+
+    for question in poll_parameters.get_question() {
+        let question_id = question.get_question_id();
+        let question_ballot = get_question_ballot(vote_sum, question_id)?;
+        let question_part = get_question_counting_part(
+            aggregated_decrypted_result,
+            question_id,
+        )?;
+        let question_result = get_question_result(vote_result, question_id)?;
+        let question_candidate_list = question.get_candidates().get_candidate();
+        if question_result.get_result().len()
+            != question_candidate_list.len()
+                + question_ballot.has_rest_ballot() as usize
+        {
+            wedpr_println!("verify result entries of {} failed!", question_id);
+            return Ok(false);
+        }
+        let mut candidate_result_sum = 0;
+        for candidate in question_candidate_list {
+            let ballot = get_ballot_from_list(
+                question_ballot.get_voted_ballot(),
+                candidate,
+            )?;
+            let candidate_counting_part = get_counting_part_from_list(
+                question_part.get_candidate_part(),
+                candidate,
+            )?;
+            let candidate_result =
+                get_int64_from_list(question_result.get_result(), candidate)?;
+            candidate_result_sum += candidate_result;
+            let expected_candidate_ballot_result =
+                bytes_to_point(ballot.get_ciphertext1())?
+                    - bytes_to_point(
+                        candidate_counting_part.get_blinding_c2(),
+                    )?;
+            if !expected_candidate_ballot_result
+                .eq(&(*BASEPOINT_G1 * Scalar::from(candidate_result as u64)))
+            {
+                wedpr_println!(
+                    "verify candidate {} of question {} failed!",
+                    candidate,
+                    question_id
+                );
+                return Ok(false);
+            }
+        }
+        if question_ballot.has_rest_ballot()
+            && !verify_abstained_result(
+                question_ballot.get_rest_ballot(),
+                question_part.get_rest_part(),
+                question_result.get_result(),
+                candidate_result_sum,
+                blank_result,
+            )?
+        {
+            wedpr_println!(
+                "verify abstained result of question {} failed!",
+                question_id
+            );
+            return Ok(false);
+        }
+    }
     Ok(true)
 }
 
+// Verifies whether the abstained votes are decrypted from the aggregated rest
+// ballot, and the candidate votes and the abstained votes partition the total
+// votes.
+fn verify_abstained_result(
+    rest_ballot: &Ballot,
+    rest_part: &CountingPart,
+    result_list: &[StringToInt64Pair],
+    candidate_result_sum: i64,
+    total_result: i64,
+) -> Result<bool, WedprError> {
+    let abstained_result =
+        get_int64_from_list(result_list, POLL_RESULT_KEY_ABSTAINED_BALLOTS)?;
+    let expected_abstained_result =
+        bytes_to_point(rest_ballot.get_ciphertext1())?
+            - bytes_to_point(rest_part.get_blinding_c2())?;
+    if !expected_abstained_result
+        .eq(&(*BASEPOINT_G1 * Scalar::from(abstained_result as u64)))
+    {
+        return Ok(false);
+    }
+    Ok(candidate_result_sum + abstained_result == total_result)
+}
+
 fn verify_count_request_for_unlisted_candidate(
     counter_share: &RistrettoPoint,
     candidate_vote_sum: &CipherPointsToBallotPair,
@@ -291,7 +1892,9 @@ pub fn verify_count_request_unlisted(
     counter_share: &RistrettoPoint,
     vote_sum: &VoteStorage,
     aggregated_decrypted_result: &DecryptedResultPartStorage,
+    public_key: &[u8],
 ) -> Result<bool, WedprError> {
+    check_poll_parameters(poll_parameters, public_key)?;
     // verify equality for blank_ballot
     let blank_blinding_c2_sum =
         bytes_to_point(vote_sum.get_blank_ballot().get_ciphertext2())?;
@@ -359,6 +1962,38 @@ pub fn verify_count_request_unlisted(
     Ok(true)
 }
 
+/// Verifies whether a partially decrypted result from a counter is valid for
+/// a vote sum whose unlisted ballots have been grouped by candidate. The
+/// grouping of the unlisted ballots is verified against the vote sum and the
+/// counters of the poll before the decrypted parts of the grouped vote sum are
+/// verified.
+pub fn verify_grouped_count_request_unlisted(
+    poll_parameters: &PollParametersStorage,
+    counter_share: &RistrettoPoint,
+    vote_sum: &VoteStorage,
+    grouping: &UnlistedGroupingStorage,
+    grouped_vote_sum: &VoteStorage,
+    aggregated_decrypted_result: &DecryptedResultPartStorage,
+    public_key: &[u8],
+) -> Result<bool, WedprError> {
+    if !verify_unlisted_grouping(
+        poll_parameters.get_counter_parameters(),
+        vote_sum,
+        grouping,
+        grouped_vote_sum,
+    )? {
+        wedpr_println!("verify_unlisted_grouping failed!");
+        return Ok(false);
+    }
+    verify_count_request_unlisted(
+        poll_parameters,
+        counter_share,
+        grouped_vote_sum,
+        aggregated_decrypted_result,
+        public_key,
+    )
+}
+
 fn verify_ballot_proof(
     poll_parameters: &PollParametersStorage,
     ballot_proof: &BallotProof,
@@ -426,6 +2061,55 @@ fn batch_verify_ballot_proof(
     Ok(true)
 }
 
+/// Verifies whether an unlisted candidate cipher is well formed and encrypts a
+/// candidate id in the valid range of the poll.
+pub fn verify_unlisted_candidate_proof(
+    poll_parameters: &PollParametersStorage,
+    unlisted_ballot_proof_info: &CipherPointsToBallotProofPair,
+) -> Result<bool, WedprError> {
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+    let candidate_cipher = unlisted_ballot_proof_info.get_key();
+    let ciphertext1 = bytes_to_point(candidate_cipher.get_ciphertext1())?;
+    let ciphertext2 = bytes_to_point(candidate_cipher.get_ciphertext2())?;
+    let format_proof = Deserialize::deserialize(
+        unlisted_ballot_proof_info.get_candidate_format_proof(),
+    )?;
+    if !verify_format_proof(
+        &ciphertext1,
+        &ciphertext2,
+        &format_proof,
+        &BASEPOINT_G1,
+        &BASEPOINT_G2,
+        &poll_point,
+    )? {
+        wedpr_println!("verify unlisted candidate format failed!");
+        return Ok(false);
+    }
+    // (id - min) and (max - id) should both be in the range, and a poll
+    // without the range of unlisted candidate ids accepts none of them.
+    let min_candidate_id = poll_parameters.get_min_unlisted_candidate_id();
+    let max_candidate_id = poll_parameters.get_max_unlisted_candidate_id();
+    if min_candidate_id == 0 || min_candidate_id > max_candidate_id {
+        wedpr_println!("poll does not accept unlisted candidates!");
+        return Ok(false);
+    }
+    let min_candidate_id = Scalar::from(min_candidate_id as u64);
+    let max_candidate_id = Scalar::from(max_candidate_id as u64);
+    let commitments = vec![
+        ciphertext1 - *BASEPOINT_G1 * min_candidate_id,
+        *BASEPOINT_G1 * max_candidate_id - ciphertext1,
+    ];
+    if !verify_value_range_in_batch(
+        &commitments,
+        unlisted_ballot_proof_info.get_candidate_range_proof(),
+        &poll_point,
+    ) {
+        wedpr_println!("verify unlisted candidate range failed!");
+        return Ok(false);
+    }
+    Ok(true)
+}
+
 fn batch_verify_unlisted_candidate_ballot_proof(
     poll_parameters: &PollParametersStorage,
     vote_request: &VoteRequest,
@@ -433,9 +2117,22 @@ fn batch_verify_unlisted_candidate_ballot_proof(
 ) -> Result<bool, WedprError> {
     let weight_ballot = vote_request.get_vote().get_blank_ballot();
     let zero_ballot = vote_request.get_vote().get_zero_ballot();
+    // every unlisted ballot should be proved
+    if unlisted_ballot_proof_infos.len()
+        != vote_request.get_vote().get_voted_ballot_unlisted().len()
+    {
+        return Err(WedprError::VerificationError);
+    }
     for unlisted_ballot_proof_info in unlisted_ballot_proof_infos {
         let candidate = unlisted_ballot_proof_info.get_key();
         let ballot_proof = unlisted_ballot_proof_info.get_value();
+        // verify the unlisted candidate cipher
+        if !verify_unlisted_candidate_proof(
+            poll_parameters,
+            unlisted_ballot_proof_info,
+        )? {
+            return Err(WedprError::VerificationError);
+        }
         let mut find_unlisted_candidate = false;
         // find the unlisted candiate ballot
         for unlisted_vote_ballot in
@@ -469,11 +2166,13 @@ pub fn verify_unbounded_vote_request(
     vote_request: &VoteRequest,
     public_key: &[u8],
 ) -> Result<bool, WedprError> {
+    check_poll_parameters(poll_parameters, public_key)?;
     // check signature for the ballot with public key
     let signature = vote_request.get_vote().get_signature();
     let weight_ballot = vote_request.get_vote().get_blank_ballot();
     let zero_ballot = vote_request.get_vote().get_zero_ballot();
     let verify_result = verify_ballots_signature(
+        poll_parameters,
         public_key,
         weight_ballot,
         zero_ballot,
@@ -482,18 +2181,132 @@ pub fn verify_unbounded_vote_request(
     if !verify_result {
         return Err(WedprError::VerificationError);
     }
-    // verify the ballot proof
-    let result = batch_verify_ballot_proof(
-        &poll_parameters,
-        &vote_request,
-        &(vote_request.get_ballot_proof()).to_vec(),
-    )?;
+    // verify the nullifier and the sequence binding
+    if !verify_nullifier(poll_parameters, vote_request)?
+        || !verify_sequence_binding(poll_parameters, vote_request)?
+    {
+        return Err(WedprError::VerificationError);
+    }
+    // unbounded votes have no against ballots or delegations
+    if poll_parameters.get_against_voting()
+        || !vote_request.get_vote().get_against_ballot().is_empty()
+        || !vote_request.get_vote().get_delegation().is_empty()
+    {
+        return Err(WedprError::VerificationError);
+    }
+    // verify the ballot proofs, which are either aggregated for all candidate
+    // ballots or given for each of them
+    let result = if vote_request.has_aggregated_format_proof()
+        || vote_request.has_aggregated_either_equality_proof()
+    {
+        vote_request.get_ballot_proof().is_empty()
+            && verify_aggregated_unbounded_ballot_proof(
+                poll_parameters,
+                vote_request,
+            )?
+    } else {
+        batch_verify_ballot_proof(
+            &poll_parameters,
+            &vote_request,
+            &(vote_request.get_ballot_proof()).to_vec(),
+        )?
+    };
     if !result {
         return Err(WedprError::VerificationError);
     }
     Ok(true)
 }
 
+// Verifies the aggregated format proof and the aggregated either-equality
+// proof of all candidate ballots of an unbounded vote.
+fn verify_aggregated_unbounded_ballot_proof(
+    poll_parameters: &PollParametersStorage,
+    vote_request: &VoteRequest,
+) -> Result<bool, WedprError> {
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+    let vote = vote_request.get_vote();
+    let ballot_list: Vec<&Ballot> = vote
+        .get_voted_ballot()
+        .iter()
+        .map(|candidate_ballot| candidate_ballot.get_ballot())
+        .collect();
+    if !verify_aggregated_format_proof(
+        &poll_point,
+        &ballot_list,
+        vote_request.get_aggregated_format_proof(),
+    )? {
+        wedpr_println!("verify_aggregated_format_proof failed!");
+        return Ok(false);
+    }
+    if !verify_aggregated_either_equality_proof(
+        &poll_point,
+        vote.get_blank_ballot(),
+        vote.get_zero_ballot(),
+        &ballot_list,
+        vote_request.get_aggregated_either_equality_proof(),
+    )? {
+        wedpr_println!("verify_aggregated_either_equality_proof failed!");
+        return Ok(false);
+    }
+    Ok(true)
+}
+
+// Verifies an aggregated either-equality proof, i.e. every ballot in the list
+// encrypts the same value as either the blank ballot or the zero ballot, by
+// recomputing the single challenge from the challenges and the responses of
+// both branches.
+fn verify_aggregated_either_equality_proof(
+    poll_point: &RistrettoPoint,
+    weight_ballot: &Ballot,
+    zero_ballot: &Ballot,
+    ballot_list: &[&Ballot],
+    either_equality_proof: &AggregatedEitherEqualityProof,
+) -> Result<bool, WedprError> {
+    let weight_challenge_list = either_equality_proof.get_weight_challenge();
+    let weight_response_list = either_equality_proof.get_weight_response();
+    let zero_response_list = either_equality_proof.get_zero_response();
+    if weight_challenge_list.len() != ballot_list.len()
+        || weight_response_list.len() != ballot_list.len()
+        || zero_response_list.len() != ballot_list.len()
+    {
+        return Ok(false);
+    }
+    let challenge = bytes_to_scalar(either_equality_proof.get_challenge())?;
+    let weight_point = bytes_to_point(weight_ballot.get_ciphertext1())?;
+    let zero_point = bytes_to_point(zero_ballot.get_ciphertext1())?;
+    let mut commitment_list = Vec::new();
+    for (ballot, (weight_challenge, (weight_response, zero_response))) in
+        ballot_list.iter().zip(
+            weight_challenge_list
+                .iter()
+                .zip(weight_response_list.iter().zip(zero_response_list)),
+        )
+    {
+        let ciphertext1 = bytes_to_point(ballot.get_ciphertext1())?;
+        let weight_challenge = bytes_to_scalar(weight_challenge)?;
+        commitment_list.push((
+            RistrettoPoint::multiscalar_mul(
+                &[bytes_to_scalar(weight_response)?, weight_challenge],
+                &[*poll_point, ciphertext1 - weight_point],
+            ),
+            RistrettoPoint::multiscalar_mul(
+                &[
+                    bytes_to_scalar(zero_response)?,
+                    challenge - weight_challenge,
+                ],
+                &[*poll_point, ciphertext1 - zero_point],
+            ),
+        ));
+    }
+    Ok(compute_aggregated_either_equality_challenge(
+        poll_point,
+        weight_ballot,
+        zero_ballot,
+        ballot_list,
+        &commitment_list,
+    ) == challenge)
+}
+
 pub fn verify_unbounded_vote_request_unlisted(
     poll_parameters: &PollParametersStorage,
     vote_request: &VoteRequest,
@@ -519,3 +2332,944 @@ pub fn verify_unbounded_vote_request_unlisted(
     }
     Ok(true)
 }
+
+/// Verifies whether the ciphertext ballots of a quadratic vote from a
+/// certified voter are valid, and the credits spent on all candidates do not
+/// exceed the voter weight.
+pub fn verify_quadratic_vote_request(
+    poll_parameters: &PollParametersStorage,
+    vote_request: &VoteRequest,
+    public_key: &[u8],
+) -> Result<bool, WedprError> {
+    check_poll_parameters(poll_parameters, public_key)?;
+    verify_vote_signature(poll_parameters, vote_request, public_key)?;
+    let vote = vote_request.get_vote();
+    if !verify_nullifier(poll_parameters, vote_request)? {
+        wedpr_println!("verify_nullifier failed!");
+        return Err(WedprError::VerificationError);
+    }
+    if !verify_sequence_binding(poll_parameters, vote_request)? {
+        wedpr_println!("verify_sequence_binding failed!");
+        return Err(WedprError::VerificationError);
+    }
+    // A quadratic vote must not carry ballots that are aggregated as votes
+    // other than the candidate ballots.
+    if poll_parameters.get_against_voting()
+        || vote.has_rest_ballot()
+        || !vote.get_question_ballot().is_empty()
+        || !vote.get_voted_ballot_unlisted().is_empty()
+        || !vote.get_against_ballot().is_empty()
+        || !vote.get_delegation().is_empty()
+    {
+        wedpr_println!("verify quadratic ballots failed!");
+        return Err(WedprError::VerificationError);
+    }
+    // The voted candidates, the cost ballots and the ballot proofs must all
+    // follow the candidates of the poll.
+    let candidate_list = poll_parameters.get_candidates().get_candidate();
+    let voted_ballot_list = vote.get_voted_ballot();
+    let cost_ballot_list = vote.get_cost_ballot();
+    let ballot_proof_list = vote_request.get_ballot_proof();
+    if voted_ballot_list.len() != candidate_list.len()
+        || cost_ballot_list.len() != candidate_list.len()
+        || ballot_proof_list.len() != candidate_list.len()
+        || candidate_list.iter().enumerate().any(|(i, candidate)| {
+            voted_ballot_list[i].get_candidate() != candidate
+                || cost_ballot_list[i].get_candidate() != candidate
+                || ballot_proof_list[i].get_key() != candidate
+        })
+    {
+        wedpr_println!("verify voted candidates failed!");
+        return Err(WedprError::VerificationError);
+    }
+
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+    let mut commitments: Vec<RistrettoPoint> = Vec::new();
+    let mut cost_sum = RistrettoPoint::default();
+    for ((candidate_ballot, cost_ballot), ballot_proof) in voted_ballot_list
+        .iter()
+        .zip(cost_ballot_list)
+        .zip(ballot_proof_list)
+    {
+        let ballot = candidate_ballot.get_ballot();
+        let ciphertext1 = bytes_to_point(ballot.get_ciphertext1())?;
+        let ciphertext2 = bytes_to_point(ballot.get_ciphertext2())?;
+        let cost_point =
+            bytes_to_point(cost_ballot.get_ballot().get_ciphertext1())?;
+        let format_proof = Deserialize::deserialize(
+            ballot_proof.get_value().get_format_proof(),
+        )?;
+        if !verify_format_proof(
+            &ciphertext1,
+            &ciphertext2,
+            &format_proof,
+            &BASEPOINT_G1,
+            &BASEPOINT_G2,
+            &poll_point,
+        )? {
+            wedpr_println!("verify_format_proof failed!");
+            return Err(WedprError::VerificationError);
+        }
+        let cost_proof = Deserialize::deserialize(
+            ballot_proof.get_value().get_cost_proof(),
+        )?;
+        if !verify_product_relationship(
+            &ciphertext1,
+            &ciphertext1,
+            &cost_point,
+            &cost_proof,
+            &BASEPOINT_G1,
+            &poll_point,
+        )? {
+            wedpr_println!("verify_cost_proof failed!");
+            return Err(WedprError::VerificationError);
+        }
+        commitments.push(ciphertext1);
+        cost_sum += cost_point;
+    }
+
+    // The vote values and the unused credits must be in range, so that the
+    // costs and the unused credits sum to the voter weight without wrapping.
+    let unused_credit_point =
+        bytes_to_point(vote.get_unused_credit_ballot().get_ciphertext1())?;
+    commitments.push(unused_credit_point);
+    align_commitment_list_if_needed(&mut commitments);
+    if !verify_value_range_in_batch(
+        &commitments,
+        vote_request.get_range_proof(),
+        &poll_point,
+    ) {
+        wedpr_println!("verify_value_range failed!");
+        return Err(WedprError::VerificationError);
+    }
+    let balance_proof =
+        Deserialize::deserialize(vote_request.get_sum_balance_proof())?;
+    if !verify_sum_relationship(
+        &cost_sum,
+        &unused_credit_point,
+        &bytes_to_point(vote.get_blank_ballot().get_ciphertext1())?,
+        &balance_proof,
+        &BASEPOINT_G1,
+        &poll_point,
+    )? {
+        wedpr_println!("verify_balance failed!");
+        return Err(WedprError::VerificationError);
+    }
+    Ok(true)
+}
+
+/// Verifies the full transcript of a finished poll end to end. It checks the
+/// poll parameters against all counter shares, verifies every vote request
+/// with the verifier of the poll mode and re-aggregates the votes, where a
+/// later vote of the same voter replaces its previous vote. The votes of a
+/// coercion-resistant poll are filtered by their credentials instead, after
+/// verifying the credential tests and the mix of every counter. Then it checks
+/// every counting part against the re-aggregated vote sum and confirms the
+/// final vote result including the unlisted result, or verifies the ranking
+/// published instead of them. The grouped counting of unlisted ballots is not
+/// covered.
+pub fn verify_poll_transcript(
+    transcript: &PollTranscript,
+) -> Result<bool, WedprError> {
+    let poll_parameters = transcript.get_poll_parameters();
+    let public_key = transcript.get_coordinator_public_key();
+    if poll_parameters.get_counter_parameters()
+        != transcript.get_counter_parameters()
+        || !verify_poll_parameters(poll_parameters, public_key)?
+    {
+        wedpr_println!("verify poll parameters failed!");
+        return Ok(false);
+    }
+
+    // Verify and re-aggregate all votes.
+    for vote_request in transcript.get_vote_request() {
+        if !verify_transcript_vote_request(transcript, vote_request)
+            .unwrap_or(false)
+        {
+            wedpr_println!("verify vote request of the transcript failed!");
+            return Ok(false);
+        }
+    }
+    let vote_sum = match transcript.get_poll_mode() {
+        PollMode::COERCION_RESISTANT => filter_transcript_votes(transcript)?,
+        _ => aggregate_accepted_votes(
+            poll_parameters,
+            transcript
+                .get_vote_request()
+                .iter()
+                .map(|vote_request| vote_request.get_vote()),
+        )?,
+    };
+    let vote_sum = match vote_sum {
+        Some(v) => v,
+        None => return Ok(false),
+    };
+
+    // A ranking is published instead of decrypting the vote sum.
+    if transcript.has_vote_ranking() {
+        return Ok(transcript.get_decrypted_part().is_empty()
+            && !transcript.has_vote_result()
+            && verify_vote_ranking(
+                poll_parameters,
+                transcript.get_counter_parameters(),
+                &vote_sum,
+                transcript.get_vote_ranking(),
+                public_key,
+            )?);
+    }
+    verify_transcript_vote_result(transcript, &vote_sum)
+}
+
+// Verifies a vote request of a poll transcript with the verifier of the poll
+// mode. A vote certified blindly cannot be linked to a registration and is
+// verified with the blind certification key, while any other vote must be
+// made with the blank ballot of a registration, and so must the ring members
+// of its delegations.
+fn verify_transcript_vote_request(
+    transcript: &PollTranscript,
+    vote_request: &VoteRequest,
+) -> Result<bool, WedprError> {
+    let poll_parameters = transcript.get_poll_parameters();
+    let public_key = transcript.get_coordinator_public_key();
+    let poll_mode = transcript.get_poll_mode();
+    let vote = vote_request.get_vote();
+    if poll_mode == PollMode::BOUNDED && vote.has_certificate() {
+        return verify_anonymous_vote_request(
+            poll_parameters,
+            vote_request,
+            transcript.get_blind_certification_public_key(),
+            public_key,
+        );
+    }
+    let is_registered = |ballot: &Ballot, signature: &[u8]| {
+        transcript.get_registration().iter().any(|registration| {
+            registration.get_ballot() == ballot
+                && registration.get_signature() == signature
+        })
+    };
+    if !is_registered(vote.get_blank_ballot(), vote.get_signature())
+        || !vote.get_delegation().iter().all(|delegation| {
+            delegation.get_ring().iter().all(|member| {
+                is_registered(member.get_ballot(), member.get_signature())
+            })
+        })
+    {
+        wedpr_println!("vote from an unknown registration!");
+        return Ok(false);
+    }
+    match poll_mode {
+        PollMode::BOUNDED => {
+            verify_vote_request(poll_parameters, vote_request, public_key)
+        },
+        PollMode::QUADRATIC => verify_quadratic_vote_request(
+            poll_parameters,
+            vote_request,
+            public_key,
+        ),
+        PollMode::UNBOUNDED => verify_unbounded_vote_request(
+            poll_parameters,
+            vote_request,
+            public_key,
+        ),
+        PollMode::UNLISTED => verify_unbounded_vote_request_unlisted(
+            poll_parameters,
+            vote_request,
+            public_key,
+        ),
+        PollMode::COERCION_RESISTANT => Ok(vote.has_encrypted_credential()
+            && verify_vote_request(poll_parameters, vote_request, public_key)?),
+    }
+}
+
+// Removes the votes with duplicate credentials from the ballot box of a
+// coercion-resistant poll transcript, and filters the mixed votes by the
+// credential roster, whose entries must belong to distinct registrations. It
+// returns the filtered vote sum to count, or None if any credential test or
+// mix part is invalid.
+fn filter_transcript_votes(
+    transcript: &PollTranscript,
+) -> Result<Option<VoteStorage>, WedprError> {
+    let poll_parameters = transcript.get_poll_parameters();
+    let counter_parameters = transcript.get_counter_parameters();
+    let credential_filter = transcript.get_credential_filter();
+    let roster = credential_filter.get_roster();
+    let mut roster_ballot_set = BTreeSet::new();
+    for entry in roster.get_entry() {
+        let blank_ballot = entry.get_blank_ballot();
+        if !transcript
+            .get_registration()
+            .iter()
+            .any(|registration| registration.get_ballot() == blank_ballot)
+            || !roster_ballot_set.insert((
+                blank_ballot.get_ciphertext1(),
+                blank_ballot.get_ciphertext2(),
+            ))
+        {
+            wedpr_println!("roster entry of an unknown registration!");
+            return Ok(None);
+        }
+    }
+
+    let ballot_box: Vec<VoteStorage> = transcript
+        .get_vote_request()
+        .iter()
+        .map(|vote_request| vote_request.get_vote().clone())
+        .collect();
+    let duplicate_decryption_parts =
+        credential_filter.get_duplicate_decryption_part();
+    let deduplicated_votes = match verify_credential_tests(
+        counter_parameters,
+        &get_credential_duplicate_difference_list(&ballot_box)?,
+        credential_filter.get_duplicate_blinding_part(),
+        duplicate_decryption_parts,
+    )? {
+        Some(v) => remove_credential_duplicates(
+            &ballot_box,
+            &v,
+            duplicate_decryption_parts,
+        )?,
+        None => return Ok(None),
+    };
+    let mix_parts = credential_filter.get_mix_part();
+    if !verify_credential_mix(
+        poll_parameters,
+        counter_parameters,
+        roster,
+        &deduplicated_votes,
+        mix_parts,
+    )? {
+        return Ok(None);
+    }
+    let mix_part = match mix_parts.last() {
+        Some(v) => v,
+        None => return Ok(None),
+    };
+    let match_decryption_parts = credential_filter.get_match_decryption_part();
+    match verify_credential_tests(
+        counter_parameters,
+        &get_credential_match_difference_list(mix_part)?,
+        credential_filter.get_match_blinding_part(),
+        match_decryption_parts,
+    )? {
+        Some(v) => Ok(Some(filter_votes_by_credential(
+            poll_parameters,
+            mix_parts,
+            &v,
+            match_decryption_parts,
+        )?)),
+        None => Ok(None),
+    }
+}
+
+// Verifies the counting parts of a poll transcript against the re-aggregated
+// vote sum, where every counter must count exactly once, and the vote result
+// decrypted from them.
+fn verify_transcript_vote_result(
+    transcript: &PollTranscript,
+    vote_sum: &VoteStorage,
+) -> Result<bool, WedprError> {
+    let poll_parameters = transcript.get_poll_parameters();
+    let mut aggregated_decrypted_result = DecryptedResultPartStorage::new();
+    for partially_decrypted_result in transcript.get_decrypted_part() {
+        match aggregate_decrypted_part_sum(
+            poll_parameters,
+            partially_decrypted_result.get_blank_part().get_counter_id(),
+            vote_sum,
+            partially_decrypted_result,
+            &mut aggregated_decrypted_result,
+        ) {
+            Ok(None) => (),
+            Ok(Some(blame_report)) => {
+                wedpr_println!("counter blamed: {:?}", blame_report);
+                return Ok(false);
+            },
+            Err(_) => return Ok(false),
+        }
+    }
+    if aggregated_decrypted_result
+        .get_aggregated_counter_id()
+        .len()
+        != transcript
+            .get_counter_parameters()
+            .get_counter_parameters_share()
+            .len()
+    {
+        wedpr_println!("not all counters have counted!");
+        return Ok(false);
+    }
+    let vote_result = transcript.get_vote_result();
+    if !verify_decrypted_vote_result(
+        poll_parameters,
+        vote_sum,
+        &aggregated_decrypted_result,
+        vote_result,
+    )
+    .unwrap_or(false)
+    {
+        return Ok(false);
+    }
+    if transcript.get_poll_mode() != PollMode::UNLISTED {
+        return Ok(vote_result.get_unlisted_result().is_empty());
+    }
+    Ok(verify_unlisted_vote_result(
+        transcript.get_counter_parameters(),
+        vote_sum,
+        transcript.get_decrypted_part(),
+        vote_result.get_unlisted_result(),
+    )
+    .unwrap_or(false))
+}
+
+// Verifies whether the unlisted result is decrypted from the unlisted ballots
+// of the vote sum, whose candidate ciphers and ballots are partially decrypted
+// by every counter in the same order. Instead of searching for the candidate
+// of each unlisted ballot, its decrypted candidate cipher is matched with the
+// candidate ids of the result, and every candidate in the result must be
+// matched by a ballot.
+fn verify_unlisted_vote_result(
+    counter_parameters: &CounterParametersStorage,
+    vote_sum: &VoteStorage,
+    decrypted_part_list: &[DecryptedResultPartStorage],
+    unlisted_result: &[UnlistedVoteChoice],
+) -> Result<bool, WedprError> {
+    let unlisted_ballot_list = vote_sum.get_voted_ballot_unlisted();
+    let mut counter_share_list = Vec::new();
+    for decrypted_part in decrypted_part_list {
+        let counter_id = decrypted_part.get_blank_part().get_counter_id();
+        let counter_share = match counter_parameters
+            .get_counter_parameters_share()
+            .iter()
+            .find(|share| share.get_counter_id() == counter_id)
+        {
+            Some(v) => bytes_to_point(v.get_poll_point_share())?,
+            None => return Ok(false),
+        };
+        if decrypted_part.get_unlisted_candidate_part().len()
+            != unlisted_ballot_list.len()
+        {
+            return Ok(false);
+        }
+        counter_share_list.push(counter_share);
+    }
+    // the ballot sum and whether any ballot is matched for each candidate
+    let mut candidate_ballot_sum = BTreeMap::new();
+    for unlisted_choice in unlisted_result {
+        if candidate_ballot_sum
+            .insert(
+                unlisted_choice.get_candidate_id(),
+                (RistrettoPoint::default(), false),
+            )
+            .is_some()
+        {
+            return Ok(false);
+        }
+    }
+    for (index, unlisted_ballot) in unlisted_ballot_list.iter().enumerate() {
+        let mut candidate_c2_r_sum = RistrettoPoint::default();
+        let mut ballot_c2_r_sum = RistrettoPoint::default();
+        for (decrypted_part, counter_share) in
+            decrypted_part_list.iter().zip(&counter_share_list)
+        {
+            let unlisted_part =
+                &decrypted_part.get_unlisted_candidate_part()[index];
+            let ballot_part_list =
+                unlisted_part.get_decrypted_unlisted_candidate_ballot();
+            if unlisted_part.get_candidate_cipher() != unlisted_ballot.get_key()
+                || ballot_part_list.len() != 1
+                || !verify_count_request_for_unlisted_candidate(
+                    counter_share,
+                    unlisted_ballot,
+                    unlisted_part,
+                )?
+            {
+                wedpr_println!("verify unlisted counting part failed!");
+                return Ok(false);
+            }
+            candidate_c2_r_sum += bytes_to_point(
+                unlisted_part
+                    .get_decrypted_unlisted_candidate()
+                    .get_blinding_c2(),
+            )?;
+            ballot_c2_r_sum +=
+                bytes_to_point(ballot_part_list[0].get_blinding_c2())?;
+        }
+        let candidate_point =
+            bytes_to_point(unlisted_ballot.get_key().get_ciphertext1())?
+                - candidate_c2_r_sum;
+        let ballot_sum =
+            match candidate_ballot_sum.iter_mut().find(|(candidate_id, _)| {
+                candidate_point == *BASEPOINT_G1 * Scalar::from(**candidate_id)
+            }) {
+                Some((_, v)) => v,
+                None => {
+                    wedpr_println!("unlisted candidate not in the result!");
+                    return Ok(false);
+                },
+            };
+        ballot_sum.0 +=
+            bytes_to_point(unlisted_ballot.get_ballot().get_ciphertext1())?
+                - ballot_c2_r_sum;
+        ballot_sum.1 = true;
+    }
+    for unlisted_choice in unlisted_result {
+        let (ballot_sum, matched) =
+            candidate_ballot_sum[&unlisted_choice.get_candidate_id()];
+        if !matched
+            || ballot_sum
+                != *BASEPOINT_G1 * Scalar::from(unlisted_choice.get_value())
+        {
+            wedpr_println!(
+                "verify unlisted candidate {} failed!",
+                unlisted_choice.get_candidate_id()
+            );
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Verifies whether a blinding part from a counter blinds the latest unlisted
+/// candidate ciphers with the same secret.
+pub fn verify_unlisted_blinding_part(
+    vote_sum: &VoteStorage,
+    grouping: &UnlistedGroupingStorage,
+    blinding_part: &UnlistedBlindingPart,
+) -> Result<bool, WedprError> {
+    let candidate_cipher_list =
+        get_unlisted_candidate_cipher_list(vote_sum, grouping);
+    let blinded_cipher_list = blinding_part.get_blinded_candidate_cipher();
+    if blinded_cipher_list.len() != candidate_cipher_list.len() {
+        return Ok(false);
+    }
+    let blinding_point = bytes_to_point(blinding_part.get_blinding_point())?;
+    for (candidate_cipher, blinded_cipher) in
+        candidate_cipher_list.iter().zip(blinded_cipher_list)
+    {
+        let ciphertext1_proof =
+            Deserialize::deserialize(blinded_cipher.get_ciphertext1_proof())?;
+        let ciphertext2_proof =
+            Deserialize::deserialize(blinded_cipher.get_ciphertext2_proof())?;
+        if !verify_equality_relationship_proof(
+            &blinding_point,
+            &bytes_to_point(blinded_cipher.get_cipher().get_ciphertext1())?,
+            &ciphertext1_proof,
+            &BASEPOINT_G2,
+            &bytes_to_point(candidate_cipher.get_ciphertext1())?,
+        )? || !verify_equality_relationship_proof(
+            &blinding_point,
+            &bytes_to_point(blinded_cipher.get_cipher().get_ciphertext2())?,
+            &ciphertext2_proof,
+            &BASEPOINT_G2,
+            &bytes_to_point(candidate_cipher.get_ciphertext2())?,
+        )? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Verifies whether the partially decrypted tags from a counter are valid.
+pub fn verify_unlisted_tag_part(
+    counter_share: &RistrettoPoint,
+    grouping: &UnlistedGroupingStorage,
+    tag_decrypted_part: &UnlistedTagDecryptedPart,
+) -> Result<bool, WedprError> {
+    let blinding_part = match grouping.get_blinding_part().last() {
+        Some(v) => v,
+        None => return Ok(false),
+    };
+    let blinded_cipher_list = blinding_part.get_blinded_candidate_cipher();
+    let tag_part_list = tag_decrypted_part.get_tag_part();
+    if tag_part_list.len() != blinded_cipher_list.len() {
+        return Ok(false);
+    }
+    for (blinded_cipher, tag_part) in
+        blinded_cipher_list.iter().zip(tag_part_list)
+    {
+        let equality_proof =
+            Deserialize::deserialize(tag_part.get_equality_proof())?;
+        if !verify_equality_relationship_proof(
+            counter_share,
+            &bytes_to_point(tag_part.get_blinding_c2())?,
+            &equality_proof,
+            &BASEPOINT_G2,
+            &bytes_to_point(blinded_cipher.get_cipher().get_ciphertext2())?,
+        )? {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Verifies whether the unlisted ballots of a vote sum are correctly grouped
+/// by candidate, which requires every counter to blind the candidate ciphers
+/// and decrypt the tags exactly once.
+pub fn verify_unlisted_grouping(
+    counter_parameters: &CounterParametersStorage,
+    vote_sum: &VoteStorage,
+    grouping: &UnlistedGroupingStorage,
+    grouped_vote_sum: &VoteStorage,
+) -> Result<bool, WedprError> {
+    let counter_shares = counter_parameters.get_counter_parameters_share();
+    if grouping.get_blinding_part().len() != counter_shares.len()
+        || grouping.get_tag_decrypted_part().len() != counter_shares.len()
+    {
+        return Ok(false);
+    }
+    // Replay the blinding in turn.
+    let mut blinded_counter_ids = BTreeSet::new();
+    let mut partial_grouping = UnlistedGroupingStorage::new();
+    for blinding_part in grouping.get_blinding_part() {
+        let counter_id = blinding_part.get_counter_id();
+        if !counter_shares
+            .iter()
+            .any(|share| share.get_counter_id() == counter_id)
+            || !blinded_counter_ids.insert(counter_id)
+        {
+            return Ok(false);
+        }
+        if !verify_unlisted_blinding_part(
+            vote_sum,
+            &partial_grouping,
+            blinding_part,
+        )? {
+            return Ok(false);
+        }
+        partial_grouping
+            .mut_blinding_part()
+            .push(blinding_part.clone());
+    }
+    let mut decrypted_counter_ids = BTreeSet::new();
+    for tag_decrypted_part in grouping.get_tag_decrypted_part() {
+        let counter_id = tag_decrypted_part.get_counter_id();
+        let counter_parameters_share = match counter_shares
+            .iter()
+            .find(|share| share.get_counter_id() == counter_id)
+        {
+            Some(v) => v,
+            None => return Ok(false),
+        };
+        if !decrypted_counter_ids.insert(counter_id) {
+            return Ok(false);
+        }
+        let counter_share =
+            bytes_to_point(counter_parameters_share.get_poll_point_share())?;
+        if !verify_unlisted_tag_part(
+            &counter_share,
+            grouping,
+            tag_decrypted_part,
+        )? {
+            return Ok(false);
+        }
+    }
+    let expected_grouped_vote_sum = group_unlisted_ballots(vote_sum, grouping)?;
+    Ok(expected_grouped_vote_sum == *grouped_vote_sum)
+}
+
+/// Verifies whether a ranking comparison is mixed by every counter exactly once
+/// and decrypted by every counter, i.e. each counter blinds every difference
+/// with a nonzero secret and shuffles the blinded differences, and the partial
+/// decryptions of the last mix part are valid. Use decide_ranking_comparison
+/// to find out whether a verified comparison holds.
+pub fn verify_ranking_comparison(
+    poll_parameters: &PollParametersStorage,
+    counter_parameters: &CounterParametersStorage,
+    vote_sum: &VoteStorage,
+    comparison: &RankingComparison,
+) -> Result<bool, WedprError> {
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+    let counter_shares = counter_parameters.get_counter_parameters_share();
+    let mix_part_list = comparison.get_mix_part();
+    if mix_part_list.len() != counter_shares.len()
+        || comparison.get_decryption_part().len() != counter_shares.len()
+        || check_ranking_max_difference(comparison).is_err()
+    {
+        return Ok(false);
+    }
+    // Replay the mixing in turn.
+    let mut mixed_counter_ids = BTreeSet::new();
+    for (index, mix_part) in mix_part_list.iter().enumerate() {
+        let counter_id = mix_part.get_counter_id();
+        if !counter_shares
+            .iter()
+            .any(|share| share.get_counter_id() == counter_id)
+            || !mixed_counter_ids.insert(counter_id)
+        {
+            return Ok(false);
+        }
+        let difference_list =
+            get_ranking_mix_input(vote_sum, comparison, index)?;
+        let blinded_difference_list = mix_part.get_blinded_difference();
+        if blinded_difference_list.len() != difference_list.len()
+            || mix_part.get_equality_proof().len() != difference_list.len()
+        {
+            return Ok(false);
+        }
+        let mut blinded_row_list = Vec::new();
+        for ((difference, blinded_difference), equality_proof) in
+            difference_list
+                .iter()
+                .zip(blinded_difference_list)
+                .zip(mix_part.get_equality_proof())
+        {
+            // A difference blinded with zero would decrypt to zero.
+            let blinded_difference2 =
+                bytes_to_point(blinded_difference.get_ciphertext2())?;
+            if blinded_difference2 == RistrettoPoint::default()
+                || !verify_equality_relationship_proof(
+                    &bytes_to_point(blinded_difference.get_ciphertext1())?,
+                    &blinded_difference2,
+                    &Deserialize::deserialize(equality_proof)?,
+                    &bytes_to_point(difference.get_ciphertext1())?,
+                    &bytes_to_point(difference.get_ciphertext2())?,
+                )?
+            {
+                wedpr_println!("verify ranking blinding failed!");
+                return Ok(false);
+            }
+            blinded_row_list.push(vec![blinded_difference.clone()]);
+        }
+        let shuffled_row_list: Vec<Vec<Ballot>> = mix_part
+            .get_shuffled_difference()
+            .iter()
+            .map(|shuffled_difference| vec![shuffled_difference.clone()])
+            .collect();
+        if !verify_shuffle_proof(
+            &poll_point,
+            &blinded_row_list,
+            &shuffled_row_list,
+            mix_part.get_shuffle_proof(),
+        )? {
+            wedpr_println!("verify ranking shuffle failed!");
+            return Ok(false);
+        }
+    }
+
+    let shuffled_difference_list =
+        get_ranking_mix_input(vote_sum, comparison, mix_part_list.len())?;
+    let mut decrypted_counter_ids = BTreeSet::new();
+    for decryption_part in comparison.get_decryption_part() {
+        let counter_id = decryption_part.get_counter_id();
+        let counter_share = match counter_shares
+            .iter()
+            .find(|share| share.get_counter_id() == counter_id)
+        {
+            Some(v) => v,
+            None => return Ok(false),
+        };
+        if !decrypted_counter_ids.insert(counter_id)
+            || decryption_part.get_decrypted_part().len()
+                != shuffled_difference_list.len()
+        {
+            return Ok(false);
+        }
+        let poll_point_share =
+            bytes_to_point(counter_share.get_poll_point_share())?;
+        for (shuffled_difference, decrypted_part) in shuffled_difference_list
+            .iter()
+            .zip(decryption_part.get_decrypted_part())
+        {
+            if !verify_ballot_counting_part(
+                &poll_point_share,
+                shuffled_difference,
+                decrypted_part,
+            )? {
+                wedpr_println!("verify ranking decryption failed!");
+                return Ok(false);
+            }
+        }
+    }
+    Ok(true)
+}
+
+/// Verifies whether a vote ranking is consistent with the encrypted vote sum,
+/// without knowing the votes of any candidate. Every comparison of the ranking
+/// must be verified and hold.
+pub fn verify_vote_ranking(
+    poll_parameters: &PollParametersStorage,
+    counter_parameters: &CounterParametersStorage,
+    vote_sum: &VoteStorage,
+    vote_ranking: &VoteRanking,
+    public_key: &[u8],
+) -> Result<bool, WedprError> {
+    check_poll_parameters(poll_parameters, public_key)?;
+    // The counters must be the counters of the poll.
+    if counter_parameters != poll_parameters.get_counter_parameters() {
+        return Ok(false);
+    }
+    let candidate_list = poll_parameters.get_candidates().get_candidate();
+    let ranked_candidate_list = vote_ranking.get_ranked_candidate();
+    let top_k = ranked_candidate_list.len();
+    if top_k == 0
+        || top_k > candidate_list.len()
+        || poll_parameters.get_against_voting()
+    {
+        return Ok(false);
+    }
+    let mut ranked_candidate_set = BTreeSet::new();
+    for candidate in ranked_candidate_list {
+        if !candidate_list.contains(candidate)
+            || !ranked_candidate_set.insert(candidate)
+        {
+            return Ok(false);
+        }
+    }
+    let expected_comparison_list =
+        get_ranking_comparison_pair_list(candidate_list, ranked_candidate_list);
+    let comparison_list = vote_ranking.get_comparison();
+    if comparison_list.len() != expected_comparison_list.len()
+        || comparison_list.iter().zip(expected_comparison_list).any(
+            |(comparison, (greater_candidate, lesser_candidate))| {
+                comparison.get_greater_candidate() != greater_candidate
+                    || comparison.get_lesser_candidate() != lesser_candidate
+            },
+        )
+    {
+        return Ok(false);
+    }
+    for comparison in comparison_list {
+        if !verify_ranking_comparison(
+            poll_parameters,
+            counter_parameters,
+            vote_sum,
+            comparison,
+        )? || !decide_ranking_comparison(comparison)?
+        {
+            wedpr_println!(
+                "verify ranking comparison between {} and {} failed!",
+                comparison.get_greater_candidate(),
+                comparison.get_lesser_candidate()
+            );
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+// Verifies whether the output rows re-encrypt a permutation of the input rows
+// with a shuffle proof. The openings of all rounds are checked together with
+// random weights in a single multiscalar multiplication.
+fn verify_shuffle_proof(
+    poll_point: &RistrettoPoint,
+    input_row_list: &[Vec<Ballot>],
+    output_row_list: &[Vec<Ballot>],
+    shuffle_proof: &ShuffleProof,
+) -> Result<bool, WedprError> {
+    let row_count = input_row_list.len();
+    let round_list = shuffle_proof.get_round();
+    if output_row_list.len() != row_count
+        || round_list.len() != SHUFFLE_PROOF_ROUNDS
+    {
+        return Ok(false);
+    }
+    let row_width = match input_row_list.first() {
+        Some(row) => row.len(),
+        None => return Ok(true),
+    };
+    if input_row_list
+        .iter()
+        .chain(output_row_list)
+        .any(|row| row.len() != row_width)
+    {
+        return Ok(false);
+    }
+    let challenge = compute_shuffle_challenge(
+        poll_point,
+        input_row_list,
+        output_row_list,
+        shuffle_proof,
+    );
+    // The input rows and the output rows are opened in many rounds, so their
+    // weights are accumulated before the multiplication.
+    let mut input_point_list = Vec::with_capacity(row_count * row_width);
+    let mut output_point_list = Vec::with_capacity(row_count * row_width);
+    for (input_row, output_row) in input_row_list.iter().zip(output_row_list) {
+        for (input, output) in input_row.iter().zip(output_row) {
+            input_point_list.push(bytes_to_point(input.get_ciphertext1())?);
+            input_point_list.push(bytes_to_point(input.get_ciphertext2())?);
+            output_point_list.push(bytes_to_point(output.get_ciphertext1())?);
+            output_point_list.push(bytes_to_point(output.get_ciphertext2())?);
+        }
+    }
+    let mut input_weight_list = vec![Scalar::zero(); input_point_list.len()];
+    let mut output_weight_list = vec![Scalar::zero(); output_point_list.len()];
+    let mut shadow_weight_list = Vec::new();
+    let mut shadow_point_list = Vec::new();
+    let mut blinding_sum1 = Scalar::zero();
+    let mut blinding_sum2 = Scalar::zero();
+    for (round, opened) in round_list.iter().zip(challenge) {
+        let shadow_row_list = round.get_shadow_row();
+        let permutation = round.get_permutation();
+        let blinding_list = round.get_blinding();
+        if shadow_row_list.len() != row_count
+            || shadow_row_list
+                .iter()
+                .any(|row| row.get_ballot().len() != row_width)
+            || permutation.len() != row_count
+            || blinding_list.len() != row_count * row_width
+        {
+            return Ok(false);
+        }
+        // The opening must be a permutation.
+        let mut source_set = BTreeSet::new();
+        for index in permutation {
+            if *index as usize >= row_count || !source_set.insert(*index) {
+                return Ok(false);
+            }
+        }
+        // The opened rows are either the shadow rows from the input rows, or
+        // the output rows from the shadow rows. Every shadow ballot is opened
+        // exactly once in a round.
+        for (position, index) in permutation.iter().enumerate() {
+            let index = *index as usize;
+            let (shadow_index, row_index) = if opened {
+                (index, position)
+            } else {
+                (position, index)
+            };
+            let shadow_row = shadow_row_list[shadow_index].get_ballot();
+            for (offset, shadow) in shadow_row.iter().enumerate() {
+                let blinding = bytes_to_scalar(
+                    &blinding_list[position * row_width + offset],
+                )?;
+                let weight1 = get_random_scalar();
+                let weight2 = get_random_scalar();
+                let point_index = (row_index * row_width + offset) * 2;
+                // target - source = blinding * (poll_point, G2)
+                let (shadow_weight1, shadow_weight2) = if opened {
+                    output_weight_list[point_index] += weight1;
+                    output_weight_list[point_index + 1] += weight2;
+                    (-weight1, -weight2)
+                } else {
+                    input_weight_list[point_index] -= weight1;
+                    input_weight_list[point_index + 1] -= weight2;
+                    (weight1, weight2)
+                };
+                shadow_weight_list.push(shadow_weight1);
+                shadow_weight_list.push(shadow_weight2);
+                shadow_point_list
+                    .push(bytes_to_point(shadow.get_ciphertext1())?);
+                shadow_point_list
+                    .push(bytes_to_point(shadow.get_ciphertext2())?);
+                blinding_sum1 += weight1 * blinding;
+                blinding_sum2 += weight2 * blinding;
+            }
+        }
+    }
+    let blinding_scalar_list = [-blinding_sum1, -blinding_sum2];
+    let blinding_point_list = [*poll_point, *BASEPOINT_G2];
+    let scalar_list = input_weight_list
+        .iter()
+        .chain(&output_weight_list)
+        .chain(&shadow_weight_list)
+        .chain(&blinding_scalar_list);
+    let point_list = input_point_list
+        .iter()
+        .chain(&output_point_list)
+        .chain(&shadow_point_list)
+        .chain(&blinding_point_list);
+    Ok(
+        RistrettoPoint::vartime_multiscalar_mul(scalar_list, point_list)
+            == RistrettoPoint::default(),
+    )
+}
@@ -2,15 +2,28 @@
 
 //! Library for a poll voter.
 
-use crate::utils::{align_scalar_list_if_needed, align_u64_list_if_needed};
-use wedpr_s_protos::generated::acv::CipherPoints;
+use crate::{
+    utils::{
+        align_scalar_list_if_needed, align_u64_list_if_needed,
+        compute_aggregated_either_equality_challenge,
+        compute_aggregated_format_challenge,
+        compute_blind_certificate_challenge, compute_credential_challenge,
+        compute_delegation_membership_challenge, compute_vote_hash,
+        get_blind_certificate_info_point, get_confidential_weight_key,
+        get_delegation_blinding, get_nullifier_basepoint,
+        get_sequence_binding_basepoint,
+    },
+    verifier::{check_poll_parameters, verify_blind_certificate},
+};
+use wedpr_s_protos::{generated::acv::CipherPoints, resolve_signature_scheme};
 
 use curve25519_dalek::{
     ristretto::RistrettoPoint, scalar::Scalar, traits::MultiscalarMul,
 };
 use wedpr_l_crypto_zkp_discrete_logarithm_proof::{
-    prove_either_equality_relationship_proof, prove_format_proof,
-    prove_sum_relationship,
+    prove_either_equality_relationship_proof,
+    prove_equality_relationship_proof, prove_format_proof,
+    prove_product_relationship, prove_sum_relationship,
 };
 use wedpr_l_crypto_zkp_range_proof::prove_value_range_in_batch;
 use wedpr_l_crypto_zkp_utils::{
@@ -19,11 +32,16 @@ use wedpr_l_crypto_zkp_utils::{
 };
 use wedpr_l_utils::error::WedprError;
 use wedpr_s_protos::generated::acv::{
-    Ballot, BallotProof, CandidateBallot, CandidateList,
-    CipherPointsToBallotPair, CipherPointsToBallotProofPair,
-    PollParametersStorage, RegistrationBlindingPoint, RegistrationRequest,
-    RegistrationResponse, StringToBallotProofPair, VoteChoice, VoteChoices,
-    VoteRequest, VoterSecret,
+    AggregatedEitherEqualityProof, AggregatedFormatProof, Ballot, BallotProof,
+    BlindCertificate, BlindCertificationCommitment, BlindCertificationRequest,
+    BlindCertificationResponse, BlindCertificationVoterSecret, CandidateBallot,
+    CandidateList, CertifiedBallot, CipherPointsToBallotPair,
+    CipherPointsToBallotProofPair, CredentialProof, Delegation,
+    DelegationMembershipProof, PollParametersStorage, Question, QuestionBallot,
+    QuestionBallotProof, QuestionVoteChoices, RegistrationBlindingPoint,
+    RegistrationRequest, RegistrationResponse, StringToBallotProofPair,
+    VoteChoice, VoteChoices, VoteRequest, VoteStorage, VoterCredential,
+    VoterSecret,
 };
 
 /// Makes secrets used by a voter.
@@ -52,11 +70,15 @@ pub fn generate_registration_blinding_point(
     Ok(registration_blinding_point)
 }
 
-/// Makes a request for voter registration.
+/// Makes a request for voter registration. Like the other voter APIs, it
+/// rejects the poll parameters unless they are signed by the coordinator with
+/// the given public key.
 pub fn make_registration_request(
     secret: &VoterSecret,
     poll_parameters: &PollParametersStorage,
+    public_key: &[u8],
 ) -> Result<RegistrationRequest, WedprError> {
+    check_poll_parameters(poll_parameters, public_key)?;
     let mut request = RegistrationRequest::new();
     request.set_weight_point(generate_registration_blinding_point(
         secret,
@@ -69,7 +91,9 @@ pub fn make_unbounded_registration_request(
     zero_secret: &VoterSecret,
     weight_secret: &VoterSecret,
     poll_parameters: &PollParametersStorage,
+    public_key: &[u8],
 ) -> Result<RegistrationRequest, WedprError> {
+    check_poll_parameters(poll_parameters, public_key)?;
     let mut request = RegistrationRequest::new();
     request.set_weight_point(generate_registration_blinding_point(
         weight_secret,
@@ -104,6 +128,218 @@ pub fn verify_blank_ballot(
         && response_ciphertext2 == request_ciphertext2)
 }
 
+/// Gets the weight of a voter from a registration response. A confidential
+/// weight is decrypted by the voter secret and checked against the blank
+/// ballot.
+pub fn get_voter_weight(
+    voter_secret: &VoterSecret,
+    poll_parameters: &PollParametersStorage,
+    registration_response: &RegistrationResponse,
+) -> Result<u32, WedprError> {
+    if registration_response.get_weight_key_point().is_empty() {
+        return Ok(registration_response.get_voter_weight());
+    }
+    let secret = bytes_to_scalar(voter_secret.get_voter_secret())?;
+    let weight_key_point =
+        bytes_to_point(registration_response.get_weight_key_point())?;
+    let voter_weight = registration_response.get_encrypted_voter_weight()
+        ^ get_confidential_weight_key(&(weight_key_point * secret));
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+    let expected_ciphertext1 = RistrettoPoint::multiscalar_mul(
+        &[Scalar::from(voter_weight), secret],
+        &[*BASEPOINT_G1, poll_point],
+    );
+    if point_to_bytes(&expected_ciphertext1)
+        != registration_response.get_ballot().get_ciphertext1()
+    {
+        wedpr_println!("decrypt confidential voter weight failed!");
+        return Err(WedprError::DecodeError);
+    }
+    Ok(voter_weight)
+}
+
+// Checks whether the blank ballot in a registration response is signed with the
+// signature scheme of the poll. A blind certificate does not use the scheme.
+fn check_signature_scheme(
+    registration_response: &RegistrationResponse,
+    poll_parameters: &PollParametersStorage,
+) -> Result<(), WedprError> {
+    if !registration_response.has_certificate()
+        && resolve_signature_scheme(
+            registration_response.get_signature_scheme(),
+        ) != resolve_signature_scheme(poll_parameters.get_signature_scheme())
+    {
+        wedpr_println!("signature scheme of the registration mismatched!");
+        return Err(WedprError::ArgumentError);
+    }
+    Ok(())
+}
+
+// Makes the blank ballot of a voter to be certified blindly, which encrypts the
+// voter weight with the voter secret as the blinding.
+fn make_blind_blank_ballot(
+    voter_secret: &VoterSecret,
+    poll_parameters: &PollParametersStorage,
+    voter_weight: u32,
+) -> Result<Ballot, WedprError> {
+    let secret = bytes_to_scalar(voter_secret.get_voter_secret())?;
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+    let ciphertext1 = RistrettoPoint::multiscalar_mul(
+        &[Scalar::from(voter_weight), secret],
+        &[*BASEPOINT_G1, poll_point],
+    );
+    let mut blank_ballot = Ballot::new();
+    blank_ballot.set_ciphertext1(point_to_bytes(&ciphertext1));
+    blank_ballot.set_ciphertext2(point_to_bytes(&(secret * *BASEPOINT_G2)));
+    Ok(blank_ballot)
+}
+
+/// Makes a request for certifying the blank ballot of a voter blindly, in
+/// response to the commitment of a blind certification session. The returned
+/// secret is used to unblind the certificate.
+pub fn make_blind_certification_request(
+    voter_secret: &VoterSecret,
+    poll_parameters: &PollParametersStorage,
+    public_key: &[u8],
+    commitment: &BlindCertificationCommitment,
+    poll_public_key: &[u8],
+) -> Result<
+    (BlindCertificationVoterSecret, BlindCertificationRequest),
+    WedprError,
+> {
+    check_poll_parameters(poll_parameters, poll_public_key)?;
+    let public_key_point = bytes_to_point(public_key)?;
+    let commitment_a = bytes_to_point(commitment.get_commitment_a())?;
+    let commitment_b = bytes_to_point(commitment.get_commitment_b())?;
+    let info_point = get_blind_certificate_info_point(
+        poll_parameters,
+        commitment.get_voter_weight(),
+    )?;
+    let blank_ballot = make_blind_blank_ballot(
+        voter_secret,
+        poll_parameters,
+        commitment.get_voter_weight(),
+    )?;
+    let t1 = get_random_scalar();
+    let t2 = get_random_scalar();
+    let t3 = get_random_scalar();
+    let t4 = get_random_scalar();
+    let alpha = commitment_a
+        + RistrettoPoint::multiscalar_mul(&[t1, t2], &[
+            *BASEPOINT_G1,
+            public_key_point,
+        ]);
+    let beta = commitment_b
+        + RistrettoPoint::multiscalar_mul(&[t3, t4], &[
+            *BASEPOINT_G1,
+            info_point,
+        ]);
+    let challenge = compute_blind_certificate_challenge(
+        &alpha,
+        &beta,
+        &info_point,
+        poll_parameters,
+        &blank_ballot,
+    );
+    let mut blinding_secret = BlindCertificationVoterSecret::new();
+    blinding_secret.set_t1(scalar_to_bytes(&t1));
+    blinding_secret.set_t2(scalar_to_bytes(&t2));
+    blinding_secret.set_t3(scalar_to_bytes(&t3));
+    blinding_secret.set_t4(scalar_to_bytes(&t4));
+    let mut request = BlindCertificationRequest::new();
+    request.set_blinded_challenge(scalar_to_bytes(&(challenge - t2 - t4)));
+    Ok((blinding_secret, request))
+}
+
+/// Unblinds the certificate from the response of a blind certification
+/// session, and returns a registration response carrying the certified blank
+/// ballot and the proof that it encrypts the voter weight.
+pub fn unblind_certificate(
+    voter_secret: &VoterSecret,
+    poll_parameters: &PollParametersStorage,
+    public_key: &[u8],
+    commitment: &BlindCertificationCommitment,
+    blinding_secret: &BlindCertificationVoterSecret,
+    response: &BlindCertificationResponse,
+    poll_public_key: &[u8],
+) -> Result<RegistrationResponse, WedprError> {
+    let voter_weight = commitment.get_voter_weight();
+    let secret = bytes_to_scalar(voter_secret.get_voter_secret())?;
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+    let blank_ballot =
+        make_blind_blank_ballot(voter_secret, poll_parameters, voter_weight)?;
+    let rho = bytes_to_scalar(response.get_r())?
+        + bytes_to_scalar(blinding_secret.get_t1())?;
+    let omega = bytes_to_scalar(response.get_c())?
+        + bytes_to_scalar(blinding_secret.get_t2())?;
+    let sigma = bytes_to_scalar(response.get_s())?
+        + bytes_to_scalar(blinding_secret.get_t3())?;
+    let delta = bytes_to_scalar(response.get_d())?
+        + bytes_to_scalar(blinding_secret.get_t4())?;
+    let weight_proof =
+        prove_equality_relationship_proof(&secret, &poll_point, &BASEPOINT_G2);
+
+    let mut certificate = BlindCertificate::new();
+    certificate.set_voter_weight(voter_weight);
+    certificate.set_rho(scalar_to_bytes(&rho));
+    certificate.set_omega(scalar_to_bytes(&omega));
+    certificate.set_sigma(scalar_to_bytes(&sigma));
+    certificate.set_delta(scalar_to_bytes(&delta));
+    certificate.set_weight_proof(weight_proof.serialize());
+    if !verify_blind_certificate(
+        poll_parameters,
+        &blank_ballot,
+        &certificate,
+        public_key,
+        poll_public_key,
+    )? {
+        wedpr_println!("unblind_certificate failed!");
+        return Err(WedprError::VerificationError);
+    }
+    let mut registration_response = RegistrationResponse::new();
+    registration_response.set_voter_weight(voter_weight);
+    registration_response.set_ballot(blank_ballot);
+    registration_response.set_certificate(certificate);
+    Ok(registration_response)
+}
+
+/// Generates the nullifier of a voter for a poll and the ZKP proof that it is
+/// derived from the same secret as the certified blank ballot.
+pub fn generate_nullifier(
+    voter_secret: &VoterSecret,
+    poll_parameters: &PollParametersStorage,
+) -> Result<(Vec<u8>, Vec<u8>), WedprError> {
+    let secret = bytes_to_scalar(voter_secret.get_voter_secret())?;
+    let nullifier_basepoint = get_nullifier_basepoint(poll_parameters)?;
+    let nullifier = secret * nullifier_basepoint;
+    let nullifier_proof = prove_equality_relationship_proof(
+        &secret,
+        &BASEPOINT_G2,
+        &nullifier_basepoint,
+    );
+    Ok((point_to_bytes(&nullifier), nullifier_proof.serialize()))
+}
+
+/// Binds the sequence number of a vote to its voted ballots and generates the
+/// ZKP proof that the binding is derived from the voter secret. It should be
+/// called after all ballots and the nullifier of the vote are set.
+pub fn generate_sequence_binding(
+    voter_secret: &VoterSecret,
+    poll_parameters: &PollParametersStorage,
+    vote: &mut VoteStorage,
+) -> Result<Vec<u8>, WedprError> {
+    let secret = bytes_to_scalar(voter_secret.get_voter_secret())?;
+    let binding_basepoint =
+        get_sequence_binding_basepoint(poll_parameters, vote);
+    vote.set_sequence_binding(point_to_bytes(&(secret * binding_basepoint)));
+    let sequence_binding_proof = prove_equality_relationship_proof(
+        &secret,
+        &BASEPOINT_G2,
+        &binding_basepoint,
+    );
+    Ok(sequence_binding_proof.serialize())
+}
+
 /// Makes choices for all candidates.
 pub fn make_vote_choices(
     choice_list: &Vec<u32>,
@@ -119,53 +355,970 @@ pub fn make_vote_choices(
     choices
 }
 
-/// Votes the ciphertext ballots and generates associated ZKP proofs.
+/// Makes choices for all candidates of a for/against poll, where each choice is
+/// a pair of the votes for and against the candidate.
+pub fn make_for_against_vote_choices(
+    choice_list: &[(u32, u32)],
+    candidate_list: &CandidateList,
+) -> VoteChoices {
+    let mut choices = VoteChoices::new();
+    for (candidate, (for_value, against_value)) in
+        candidate_list.get_candidate().iter().zip(choice_list)
+    {
+        let mut pair = VoteChoice::new();
+        pair.set_candidate(candidate.to_string());
+        pair.set_value(*for_value);
+        pair.set_against_value(*against_value);
+        choices.mut_choice().push(pair);
+    }
+    choices
+}
+
+/// Makes choices for all candidates of all questions in a multi-question poll.
+pub fn make_question_vote_choices(
+    choice_list: &[Vec<u32>],
+    question_list: &[Question],
+) -> VoteChoices {
+    let mut choices = VoteChoices::new();
+    for (question, question_choice_list) in
+        question_list.iter().zip(choice_list.iter())
+    {
+        let mut question_choices = QuestionVoteChoices::new();
+        question_choices
+            .set_question_id(question.get_question_id().to_string());
+        question_choices.set_choice(
+            make_vote_choices(question_choice_list, question.get_candidates())
+                .take_choice(),
+        );
+        choices.mut_question_choices().push(question_choices);
+    }
+    choices
+}
+
+/// Votes the ciphertext ballots and generates associated ZKP proofs. For a
+/// multi-question poll, the choices of each question are voted with the whole
+/// voter weight.
 pub fn vote(
     voter_secret: &VoterSecret,
     vote_choices: &VoteChoices,
     registration_response: &RegistrationResponse,
     poll_parameters: &PollParametersStorage,
+    public_key: &[u8],
+) -> Result<VoteRequest, WedprError> {
+    check_poll_parameters(poll_parameters, public_key)?;
+    make_vote_request(
+        voter_secret,
+        vote_choices,
+        registration_response,
+        poll_parameters,
+        None,
+        &[],
+        false,
+    )
+}
+
+/// Votes as vote does, and proves the format of all candidate ballots with a
+/// single aggregated proof instead of a proof for each ballot, which reduces
+/// the size of the vote request.
+pub fn vote_with_aggregated_format_proof(
+    voter_secret: &VoterSecret,
+    vote_choices: &VoteChoices,
+    registration_response: &RegistrationResponse,
+    poll_parameters: &PollParametersStorage,
+    public_key: &[u8],
+) -> Result<VoteRequest, WedprError> {
+    check_poll_parameters(poll_parameters, public_key)?;
+    make_vote_request(
+        voter_secret,
+        vote_choices,
+        registration_response,
+        poll_parameters,
+        None,
+        &[],
+        true,
+    )
+}
+
+/// Makes a secret credential for coercion-resistant voting, which a coerced
+/// voter hands over instead of the real credential. The votes with a fake
+/// credential are discarded when the counters filter the ballot box.
+pub fn make_fake_credential() -> VoterCredential {
+    let mut credential = VoterCredential::new();
+    credential.set_credential(scalar_to_bytes(&get_random_scalar()));
+    credential
+}
+
+/// Votes as vote does, and attaches the encrypted credential of the voter
+/// with the ZKP proof of knowledge of the credential. The vote cannot be told
+/// from a vote with a fake credential until the ballot box is filtered.
+pub fn vote_with_credential(
+    voter_secret: &VoterSecret,
+    credential: &VoterCredential,
+    vote_choices: &VoteChoices,
+    registration_response: &RegistrationResponse,
+    poll_parameters: &PollParametersStorage,
+    public_key: &[u8],
+) -> Result<VoteRequest, WedprError> {
+    check_poll_parameters(poll_parameters, public_key)?;
+    make_vote_request(
+        voter_secret,
+        vote_choices,
+        registration_response,
+        poll_parameters,
+        Some(credential),
+        &[],
+        false,
+    )
+}
+
+/// Delegates the weight of a voter to a delegate identified by its blank
+/// ballot, without revealing the weight or the voter. The blank ballot of the
+/// voter is re-encrypted to the delegate with a blinding that only the
+/// delegate can derive, and the nullifier of the voter is spent by the vote of
+/// the delegate, so that the voter cannot vote by itself any more. The blank
+/// ballot of the voter is hidden among the certified ballots of other
+/// registered voters in ring_ballot_list, so the delegation is only linked to
+/// the ring.
+pub fn make_delegation(
+    voter_secret: &VoterSecret,
+    registration_response: &RegistrationResponse,
+    delegate_ballot: &Ballot,
+    ring_ballot_list: &[CertifiedBallot],
+    poll_parameters: &PollParametersStorage,
+    public_key: &[u8],
+) -> Result<Delegation, WedprError> {
+    check_poll_parameters(poll_parameters, public_key)?;
+    check_signature_scheme(registration_response, poll_parameters)?;
+    // Only a blank ballot signed by the coordinator can be delegated, and only
+    // to another voter.
+    if registration_response.has_certificate()
+        || registration_response.has_zero_ballot()
+        || registration_response.get_ballot() == delegate_ballot
+    {
+        return Err(WedprError::ArgumentError);
+    }
+    let voter_weight =
+        get_voter_weight(voter_secret, poll_parameters, registration_response)?;
+    let secret = bytes_to_scalar(voter_secret.get_voter_secret())?;
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+
+    // Derive the blinding of the transfer ballot and the weight key from the
+    // point shared with the delegate.
+    let ephemeral_secret = get_random_scalar();
+    let shared_point =
+        bytes_to_point(delegate_ballot.get_ciphertext2())? * ephemeral_secret;
+    let transfer_blinding = get_delegation_blinding(&shared_point);
+    let mut transfer_ballot = Ballot::new();
+    transfer_ballot.set_ciphertext1(point_to_bytes(
+        &RistrettoPoint::multiscalar_mul(
+            &[Scalar::from(voter_weight), transfer_blinding],
+            &[*BASEPOINT_G1, poll_point],
+        ),
+    ));
+    transfer_ballot
+        .set_ciphertext2(point_to_bytes(&(*BASEPOINT_G2 * transfer_blinding)));
+
+    // Sort the ring so that the position of the voter is not revealed.
+    let mut own_ballot = CertifiedBallot::new();
+    own_ballot.set_ballot(registration_response.get_ballot().clone());
+    own_ballot.set_signature(registration_response.get_signature().to_vec());
+    let mut ring = vec![own_ballot];
+    for member in ring_ballot_list {
+        if member.get_ballot() != registration_response.get_ballot() {
+            ring.push(member.clone());
+        }
+    }
+    ring.sort_by(|member1, member2| {
+        get_ring_order_key(member1).cmp(&get_ring_order_key(member2))
+    });
+    ring.dedup_by(|member1, member2| {
+        get_ring_order_key(member1) == get_ring_order_key(member2)
+    });
+    let real_index = match ring.iter().position(|member| {
+        member.get_ballot() == registration_response.get_ballot()
+    }) {
+        Some(v) => v,
+        None => return Err(WedprError::ArgumentError),
+    };
+
+    let mut delegation = Delegation::new();
+    delegation.set_ring(ring.into());
+    delegation.set_nullifier(point_to_bytes(
+        &(get_nullifier_basepoint(poll_parameters)? * secret),
+    ));
+    delegation.set_delegate_ballot(delegate_ballot.clone());
+    delegation.set_transfer_ballot(transfer_ballot);
+    let membership_proof = prove_delegation_membership_proof(
+        poll_parameters,
+        &secret,
+        &transfer_blinding,
+        &delegation,
+        real_index,
+    )?;
+    delegation.set_membership_proof(membership_proof);
+    delegation.set_transfer_key_point(point_to_bytes(
+        &(*BASEPOINT_G2 * ephemeral_secret),
+    ));
+    delegation.set_encrypted_voter_weight(
+        voter_weight ^ get_confidential_weight_key(&shared_point),
+    );
+    Ok(delegation)
+}
+
+// Orders the certified ballots of a delegation ring by their ciphertexts.
+fn get_ring_order_key(member: &CertifiedBallot) -> (&[u8], &[u8]) {
+    (
+        member.get_ballot().get_ciphertext1(),
+        member.get_ballot().get_ciphertext2(),
+    )
+}
+
+// Proves that the nullifier and the transfer ballot of a delegation are
+// derived from one of the ballots in its ring. For the ballot of the voter,
+// the secret is the voter secret behind both the ballot and the nullifier,
+// and the blinding is the one of its difference to the transfer ballot, which
+// encrypts zero. The other members are simulated with random challenges.
+fn prove_delegation_membership_proof(
+    poll_parameters: &PollParametersStorage,
+    secret: &Scalar,
+    transfer_blinding: &Scalar,
+    delegation: &Delegation,
+    real_index: usize,
+) -> Result<DelegationMembershipProof, WedprError> {
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+    let nullifier_basepoint = get_nullifier_basepoint(poll_parameters)?;
+    let nullifier = bytes_to_point(delegation.get_nullifier())?;
+    let transfer_ballot = delegation.get_transfer_ballot();
+    let transfer_c1 = bytes_to_point(transfer_ballot.get_ciphertext1())?;
+    let transfer_c2 = bytes_to_point(transfer_ballot.get_ciphertext2())?;
+    let (secret_nonce, blinding_nonce) =
+        (get_random_scalar(), get_random_scalar());
+    let mut simulation_list = Vec::new();
+    let mut commitment_list = Vec::new();
+    for (index, member) in delegation.get_ring().iter().enumerate() {
+        let blank_c1 = bytes_to_point(member.get_ballot().get_ciphertext1())?;
+        let blank_c2 = bytes_to_point(member.get_ballot().get_ciphertext2())?;
+        let (simulated_challenge, secret_response, blinding_response) = (
+            get_random_scalar(),
+            get_random_scalar(),
+            get_random_scalar(),
+        );
+        if index == real_index {
+            commitment_list.push([
+                *BASEPOINT_G2 * secret_nonce,
+                nullifier_basepoint * secret_nonce,
+                poll_point * blinding_nonce,
+                *BASEPOINT_G2 * blinding_nonce,
+            ]);
+        } else {
+            commitment_list.push([
+                RistrettoPoint::multiscalar_mul(
+                    &[secret_response, simulated_challenge],
+                    &[*BASEPOINT_G2, blank_c2],
+                ),
+                RistrettoPoint::multiscalar_mul(
+                    &[secret_response, simulated_challenge],
+                    &[nullifier_basepoint, nullifier],
+                ),
+                RistrettoPoint::multiscalar_mul(
+                    &[blinding_response, simulated_challenge],
+                    &[poll_point, blank_c1 - transfer_c1],
+                ),
+                RistrettoPoint::multiscalar_mul(
+                    &[blinding_response, simulated_challenge],
+                    &[*BASEPOINT_G2, blank_c2 - transfer_c2],
+                ),
+            ]);
+        }
+        simulation_list.push((
+            simulated_challenge,
+            secret_response,
+            blinding_response,
+        ));
+    }
+    let challenge = compute_delegation_membership_challenge(
+        &poll_point,
+        delegation.get_nullifier(),
+        delegation.get_delegate_ballot(),
+        transfer_ballot,
+        delegation.get_ring(),
+        &commitment_list,
+    );
+    let mut real_challenge = challenge;
+    for (index, (simulated_challenge, _, _)) in
+        simulation_list.iter().enumerate()
+    {
+        if index != real_index {
+            real_challenge -= simulated_challenge;
+        }
+    }
+    simulation_list[real_index] = (
+        real_challenge,
+        secret_nonce - real_challenge * secret,
+        blinding_nonce - real_challenge * (secret - transfer_blinding),
+    );
+    let mut membership_proof = DelegationMembershipProof::new();
+    for (member_challenge, secret_response, blinding_response) in
+        simulation_list
+    {
+        membership_proof
+            .mut_challenge()
+            .push(scalar_to_bytes(&member_challenge));
+        membership_proof
+            .mut_secret_response()
+            .push(scalar_to_bytes(&secret_response));
+        membership_proof
+            .mut_blinding_response()
+            .push(scalar_to_bytes(&blinding_response));
+    }
+    Ok(membership_proof)
+}
+
+/// Votes as vote does, and spends the weights delegated to the voter together
+/// with its own weight. The delegations should be checked by
+/// verify_delegation before voting.
+pub fn vote_with_delegations(
+    voter_secret: &VoterSecret,
+    vote_choices: &VoteChoices,
+    registration_response: &RegistrationResponse,
+    delegation_list: &[Delegation],
+    poll_parameters: &PollParametersStorage,
+    public_key: &[u8],
 ) -> Result<VoteRequest, WedprError> {
+    check_poll_parameters(poll_parameters, public_key)?;
+    make_vote_request(
+        voter_secret,
+        vote_choices,
+        registration_response,
+        poll_parameters,
+        None,
+        delegation_list,
+        false,
+    )
+}
+
+// Receives the weights delegated to a voter, and returns the total weight and
+// the blinding of the blank ballot combined with the transfer ballots.
+fn receive_delegations(
+    voter_secret: &VoterSecret,
+    poll_parameters: &PollParametersStorage,
+    registration_response: &RegistrationResponse,
+    delegation_list: &[Delegation],
+) -> Result<(u32, Scalar), WedprError> {
+    let mut voter_weight =
+        get_voter_weight(voter_secret, poll_parameters, registration_response)?;
+    let secret = bytes_to_scalar(voter_secret.get_voter_secret())?;
+    let mut blinding = secret;
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+    for delegation in delegation_list {
+        if delegation.get_delegate_ballot()
+            != registration_response.get_ballot()
+        {
+            wedpr_println!("the delegation is made to another voter!");
+            return Err(WedprError::ArgumentError);
+        }
+        let shared_point =
+            bytes_to_point(delegation.get_transfer_key_point())? * secret;
+        let transfer_blinding = get_delegation_blinding(&shared_point);
+        let delegated_weight = delegation.get_encrypted_voter_weight()
+            ^ get_confidential_weight_key(&shared_point);
+        let expected_ciphertext1 = RistrettoPoint::multiscalar_mul(
+            &[Scalar::from(delegated_weight), transfer_blinding],
+            &[*BASEPOINT_G1, poll_point],
+        );
+        let transfer_ballot = delegation.get_transfer_ballot();
+        if point_to_bytes(&expected_ciphertext1)
+            != transfer_ballot.get_ciphertext1()
+            || point_to_bytes(&(*BASEPOINT_G2 * transfer_blinding))
+                != transfer_ballot.get_ciphertext2()
+        {
+            wedpr_println!("decrypt delegated voter weight failed!");
+            return Err(WedprError::DecodeError);
+        }
+        voter_weight = match voter_weight.checked_add(delegated_weight) {
+            Some(v) => v,
+            None => return Err(WedprError::ArgumentError),
+        };
+        blinding += transfer_blinding;
+    }
+    Ok((voter_weight, blinding))
+}
+
+// Makes a vote request with the poll parameters that have been checked by the
+// caller.
+fn make_vote_request(
+    voter_secret: &VoterSecret,
+    vote_choices: &VoteChoices,
+    registration_response: &RegistrationResponse,
+    poll_parameters: &PollParametersStorage,
+    credential: Option<&VoterCredential>,
+    delegation_list: &[Delegation],
+    aggregated_format_proof: bool,
+) -> Result<VoteRequest, WedprError> {
+    check_signature_scheme(registration_response, poll_parameters)?;
     let mut vote_request = VoteRequest::new();
+    // The delegated weights are spent together with the weight of the voter.
+    let (voter_weight, vote_secret) = receive_delegations(
+        voter_secret,
+        poll_parameters,
+        registration_response,
+        delegation_list,
+    )?;
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+
+    // Vote for the candidates.
+    let (mut candidate_ballot, mut candidate_proof, against_ballots) =
+        vote_question(
+            vote_choices.get_choice(),
+            voter_weight,
+            &vote_secret,
+            &poll_point,
+            poll_parameters.get_against_voting(),
+            aggregated_format_proof,
+        )?;
+
+    // Vote for the questions.
+    for question_choices in vote_choices.get_question_choices() {
+        let (mut question_ballot, mut question_proof, _) = vote_question(
+            question_choices.get_choice(),
+            voter_weight,
+            &vote_secret,
+            &poll_point,
+            false,
+            aggregated_format_proof,
+        )?;
+        question_ballot
+            .set_question_id(question_choices.get_question_id().to_string());
+        question_proof
+            .set_question_id(question_choices.get_question_id().to_string());
+        vote_request
+            .mut_vote()
+            .mut_question_ballot()
+            .push(question_ballot);
+        vote_request.mut_question_proof().push(question_proof);
+    }
+
+    // Generate the nullifier.
+    let (nullifier, nullifier_proof) =
+        generate_nullifier(voter_secret, poll_parameters)?;
+
+    // Write back.
+    vote_request.set_ballot_proof(candidate_proof.take_ballot_proof());
+    vote_request
+        .set_sum_balance_proof(candidate_proof.take_sum_balance_proof());
+    vote_request.set_range_proof(candidate_proof.take_range_proof());
+    vote_request
+        .set_rest_format_proof(candidate_proof.take_rest_format_proof());
+    vote_request.set_nullifier_proof(nullifier_proof);
+    if candidate_proof.has_aggregated_format_proof() {
+        vote_request.set_aggregated_format_proof(
+            candidate_proof.take_aggregated_format_proof(),
+        );
+    }
+    vote_request
+        .mut_vote()
+        .set_against_ballot(against_ballots.ballot_list.into());
+    vote_request.set_against_ballot_proof(against_ballots.proof_list.into());
+    let vote = vote_request.mut_vote();
+    vote.set_voted_ballot(candidate_ballot.take_voted_ballot());
+    vote.set_signature(registration_response.get_signature().to_vec());
+    if registration_response.has_certificate() {
+        vote.set_certificate(registration_response.get_certificate().clone());
+    }
+    vote.set_rest_ballot(candidate_ballot.take_rest_ballot());
+    vote.set_blank_ballot(registration_response.get_ballot().clone());
+    vote.set_delegation(delegation_list.into());
+    vote.set_nullifier(nullifier);
+    vote.set_sequence(vote_choices.get_sequence());
+    // The sequence binding also binds the encrypted credential, so that it
+    // cannot be replaced without the voter secret.
+    let credential_secret = match credential {
+        Some(v) => {
+            let (encrypted_credential, credential_secret) =
+                encrypt_credential(v, &poll_point)?;
+            vote.set_encrypted_credential(encrypted_credential);
+            Some(credential_secret)
+        },
+        None => None,
+    };
+    let sequence_binding_proof =
+        generate_sequence_binding(voter_secret, poll_parameters, vote)?;
+    vote_request.set_sequence_binding_proof(sequence_binding_proof);
+    if let Some((credential, blinding)) = credential_secret {
+        let credential_proof = generate_credential_proof(
+            poll_parameters,
+            &poll_point,
+            vote_request.get_vote(),
+            &credential,
+            &blinding,
+        )?;
+        vote_request.set_credential_proof(credential_proof);
+    }
+    Ok(vote_request)
+}
+
+// Encrypts the credential point with the poll point, and returns the
+// credential and the blinding.
+fn encrypt_credential(
+    credential: &VoterCredential,
+    poll_point: &RistrettoPoint,
+) -> Result<(Ballot, (Scalar, Scalar)), WedprError> {
+    let credential_scalar = bytes_to_scalar(credential.get_credential())?;
+    let blinding = get_random_scalar();
+    let mut encrypted_credential = Ballot::new();
+    encrypted_credential.set_ciphertext1(point_to_bytes(
+        &RistrettoPoint::multiscalar_mul(&[credential_scalar, blinding], &[
+            *BASEPOINT_G1,
+            *poll_point,
+        ]),
+    ));
+    encrypted_credential
+        .set_ciphertext2(point_to_bytes(&(*BASEPOINT_G2 * blinding)));
+    Ok((encrypted_credential, (credential_scalar, blinding)))
+}
+
+// Proves the knowledge of the credential and the blinding of the encrypted
+// credential in a vote, so that the encrypted credential on the roster cannot
+// be copied into a vote.
+fn generate_credential_proof(
+    poll_parameters: &PollParametersStorage,
+    poll_point: &RistrettoPoint,
+    vote: &VoteStorage,
+    credential: &Scalar,
+    blinding: &Scalar,
+) -> Result<CredentialProof, WedprError> {
+    let credential_nonce = get_random_scalar();
+    let blinding_nonce = get_random_scalar();
+    let commitment1 = RistrettoPoint::multiscalar_mul(
+        &[credential_nonce, blinding_nonce],
+        &[*BASEPOINT_G1, *poll_point],
+    );
+    let commitment2 = *BASEPOINT_G2 * blinding_nonce;
+    let challenge = compute_credential_challenge(
+        poll_parameters,
+        &compute_vote_hash(vote)?,
+        vote.get_encrypted_credential(),
+        &commitment1,
+        &commitment2,
+    );
+    let mut credential_proof = CredentialProof::new();
+    credential_proof.set_challenge(scalar_to_bytes(&challenge));
+    credential_proof.set_response_credential(scalar_to_bytes(
+        &(credential_nonce - challenge * credential),
+    ));
+    credential_proof.set_response_blinding(scalar_to_bytes(
+        &(blinding_nonce - challenge * blinding),
+    ));
+    Ok(credential_proof)
+}
+
+// Ballots against the candidates with their format proofs, which are only
+// made in a for/against poll.
+#[derive(Default)]
+struct AgainstBallots {
+    ballot_list: Vec<CandidateBallot>,
+    proof_list: Vec<StringToBallotProofPair>,
+}
+
+// Votes the ciphertext ballots for the candidates of a question, and proves
+// that the votes and the rest unused votes sum to the voter weight. In a
+// for/against poll, the votes against the candidates are also voted and
+// count toward the voter weight.
+fn vote_question(
+    choice_list: &[VoteChoice],
+    voter_weight: u32,
+    vote_secret: &Scalar,
+    poll_point: &RistrettoPoint,
+    against_voting: bool,
+    aggregated_format_proof: bool,
+) -> Result<(QuestionBallot, QuestionBallotProof, AgainstBallots), WedprError> {
+    let mut question_ballot = QuestionBallot::new();
+    let mut question_proof = QuestionBallotProof::new();
+    let mut against_ballots = AgainstBallots::default();
 
     // Compute for each choice.
     let mut blinding_sum = Scalar::zero();
     let mut blinding_list: Vec<Scalar> = Vec::new();
-    let mut choice_list: Vec<u64> = Vec::new();
-    let mut unused_vote_weight =
-        registration_response.get_voter_weight() as i64;
-    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
-    for choice_keypair in vote_choices.get_choice() {
+    let mut value_list: Vec<u64> = Vec::new();
+    let mut candidate_secret_list: Vec<(u64, Scalar)> = Vec::new();
+    let mut against_secret_list: Vec<(u64, Scalar)> = Vec::new();
+    let mut unused_vote_weight = voter_weight as i64;
+    for choice_keypair in choice_list {
         let candidate_address = choice_keypair.get_candidate();
-        let value = choice_keypair.get_value();
-        unused_vote_weight -= value as i64;
+        let value = choice_keypair.get_value() as u64;
+        let against_value = choice_keypair.get_against_value() as u64;
+        if !against_voting && against_value != 0 {
+            return Err(WedprError::ArgumentError);
+        }
+        unused_vote_weight -= (value + against_value) as i64;
         // Max voter weight has been used up.
         if unused_vote_weight < 0 {
             return Err(WedprError::ArgumentError);
         }
 
         // Make a ciphertext ballot.
-        let mut vote_ballot = Ballot::new();
+        let (ballot_pair, blinding) =
+            encrypt_candidate_ballot(candidate_address, value, poll_point);
+        question_ballot.mut_voted_ballot().push(ballot_pair);
+        candidate_secret_list.push((value, blinding));
+        blinding_sum += blinding;
+        blinding_list.push(blinding);
+        value_list.push(value);
+
+        // Make a ciphertext ballot against the candidate.
+        if against_voting {
+            let (ballot_pair, blinding) = encrypt_candidate_ballot(
+                candidate_address,
+                against_value,
+                poll_point,
+            );
+            against_ballots.ballot_list.push(ballot_pair);
+            against_secret_list.push((against_value, blinding));
+            blinding_sum += blinding;
+            blinding_list.push(blinding);
+            value_list.push(against_value);
+        }
+    }
+
+    // Prove the format of the candidate ballots, either with a single
+    // aggregated proof or with a proof for each ballot.
+    if aggregated_format_proof {
+        let ballot_list: Vec<&Ballot> = question_ballot
+            .get_voted_ballot()
+            .iter()
+            .chain(&against_ballots.ballot_list)
+            .map(|ballot_pair| ballot_pair.get_ballot())
+            .collect();
+        let secret_list: Vec<(u64, Scalar)> = candidate_secret_list
+            .iter()
+            .chain(&against_secret_list)
+            .copied()
+            .collect();
+        question_proof.set_aggregated_format_proof(
+            prove_aggregated_format_proof(
+                poll_point,
+                &ballot_list,
+                &secret_list,
+            ),
+        );
+    } else {
+        for (ballot_pair, (value, blinding)) in question_ballot
+            .get_voted_ballot()
+            .iter()
+            .zip(&candidate_secret_list)
+        {
+            question_proof
+                .mut_ballot_proof()
+                .push(prove_candidate_format(
+                    ballot_pair.get_candidate(),
+                    *value,
+                    blinding,
+                    poll_point,
+                ));
+        }
+        for (ballot_pair, (value, blinding)) in
+            against_ballots.ballot_list.iter().zip(&against_secret_list)
+        {
+            against_ballots.proof_list.push(prove_candidate_format(
+                ballot_pair.get_candidate(),
+                *value,
+                blinding,
+                poll_point,
+            ));
+        }
+    }
+
+    // Compute for the rest unused ballots.
+    let blinding_rest = get_random_scalar();
+    let rest_ballot = RistrettoPoint::multiscalar_mul(
+        &[Scalar::from(unused_vote_weight as u64), blinding_rest],
+        &[*BASEPOINT_G1, *poll_point],
+    );
+
+    // Prove the balance.
+    let used_vote_weight_sum =
+        (voter_weight - unused_vote_weight as u32) as u64;
+    let balance_proof = prove_sum_relationship(
+        used_vote_weight_sum,
+        unused_vote_weight as u64,
+        &blinding_sum,
+        &blinding_rest,
+        vote_secret,
+        &BASEPOINT_G1,
+        poll_point,
+    );
+
+    // Prove the format of the rest ballot, so that it can be aggregated and
+    // decrypted as the abstained votes.
+    let rest_ciphertext2 = *BASEPOINT_G2 * blinding_rest;
+    let rest_format_proof = prove_format_proof(
+        unused_vote_weight as u64,
+        &blinding_rest,
+        &BASEPOINT_G1,
+        &BASEPOINT_G2,
+        poll_point,
+    );
+
+    // Prove the range.
+    value_list.push(unused_vote_weight as u64);
+    blinding_list.push(blinding_rest);
+    align_u64_list_if_needed(&mut value_list);
+    align_scalar_list_if_needed(&mut blinding_list);
+    let (range_proof, _) =
+        prove_value_range_in_batch(&value_list, &blinding_list, poll_point)?;
+
+    // Write back.
+    question_ballot
+        .mut_rest_ballot()
+        .set_ciphertext1(point_to_bytes(&rest_ballot));
+    question_ballot
+        .mut_rest_ballot()
+        .set_ciphertext2(point_to_bytes(&rest_ciphertext2));
+    question_proof.set_rest_format_proof(rest_format_proof.serialize());
+    question_proof.set_sum_balance_proof(balance_proof.serialize());
+    question_proof.set_range_proof(range_proof);
+    Ok((question_ballot, question_proof, against_ballots))
+}
+
+// Encrypts the votes for a candidate, and returns the ciphertext ballot with
+// the blinding.
+fn encrypt_candidate_ballot(
+    candidate: &str,
+    value: u64,
+    poll_point: &RistrettoPoint,
+) -> (CandidateBallot, Scalar) {
+    let blinding = get_random_scalar();
+    let ciphertext1 =
+        RistrettoPoint::multiscalar_mul(&[Scalar::from(value), blinding], &[
+            *BASEPOINT_G1,
+            *poll_point,
+        ]);
+    let ciphertext2 = *BASEPOINT_G2 * blinding;
+    let mut ballot_pair = CandidateBallot::new();
+    ballot_pair.set_candidate(candidate.to_string());
+    ballot_pair
+        .mut_ballot()
+        .set_ciphertext1(point_to_bytes(&ciphertext1));
+    ballot_pair
+        .mut_ballot()
+        .set_ciphertext2(point_to_bytes(&ciphertext2));
+    (ballot_pair, blinding)
+}
+
+// Proves the format of the ciphertext ballot for a candidate.
+fn prove_candidate_format(
+    candidate: &str,
+    value: u64,
+    blinding: &Scalar,
+    poll_point: &RistrettoPoint,
+) -> StringToBallotProofPair {
+    let format_proof = prove_format_proof(
+        value,
+        blinding,
+        &BASEPOINT_G1,
+        &BASEPOINT_G2,
+        poll_point,
+    );
+    let mut proof_pair = StringToBallotProofPair::new();
+    proof_pair.set_key(candidate.to_string());
+    proof_pair
+        .mut_value()
+        .set_format_proof(format_proof.serialize());
+    proof_pair
+}
+
+// Proves the format of all the ballots with a single challenge, where each
+// ballot encrypts the value with the blinding of the same index.
+fn prove_aggregated_format_proof(
+    poll_point: &RistrettoPoint,
+    ballot_list: &[&Ballot],
+    secret_list: &[(u64, Scalar)],
+) -> AggregatedFormatProof {
+    let mut nonce_list = Vec::new();
+    let mut commitment_list = Vec::new();
+    for _ in secret_list {
+        let value_nonce = get_random_scalar();
+        let blinding_nonce = get_random_scalar();
+        commitment_list.push((
+            RistrettoPoint::multiscalar_mul(&[value_nonce, blinding_nonce], &[
+                *BASEPOINT_G1,
+                *poll_point,
+            ]),
+            *BASEPOINT_G2 * blinding_nonce,
+        ));
+        nonce_list.push((value_nonce, blinding_nonce));
+    }
+    let challenge = compute_aggregated_format_challenge(
+        poll_point,
+        ballot_list,
+        &commitment_list,
+    );
+    let mut format_proof = AggregatedFormatProof::new();
+    format_proof.set_challenge(scalar_to_bytes(&challenge));
+    for ((value, blinding), (value_nonce, blinding_nonce)) in
+        secret_list.iter().zip(nonce_list)
+    {
+        format_proof.mut_response_value().push(scalar_to_bytes(
+            &(value_nonce - challenge * Scalar::from(*value)),
+        ));
+        format_proof
+            .mut_response_blinding()
+            .push(scalar_to_bytes(&(blinding_nonce - challenge * blinding)));
+    }
+    format_proof
+}
+
+// Proves that each ballot encrypts the same value as either the blank ballot
+// or the zero ballot with a single challenge. The secret of a ballot is
+// whether it matches the blank ballot, and the blinding of its difference to
+// the matched ballot. The branch that does not match is simulated with a
+// random challenge.
+fn prove_aggregated_either_equality_proof(
+    poll_point: &RistrettoPoint,
+    weight_ballot: &Ballot,
+    zero_ballot: &Ballot,
+    ballot_list: &[&Ballot],
+    secret_list: &[(bool, Scalar)],
+) -> Result<AggregatedEitherEqualityProof, WedprError> {
+    let weight_point = bytes_to_point(weight_ballot.get_ciphertext1())?;
+    let zero_point = bytes_to_point(zero_ballot.get_ciphertext1())?;
+    let mut simulation_list = Vec::new();
+    let mut commitment_list = Vec::new();
+    for (ballot, (match_weight, _)) in ballot_list.iter().zip(secret_list) {
+        let ciphertext1 = bytes_to_point(ballot.get_ciphertext1())?;
+        let (nonce, simulated_challenge, simulated_response) = (
+            get_random_scalar(),
+            get_random_scalar(),
+            get_random_scalar(),
+        );
+        let real_commitment = *poll_point * nonce;
+        let simulated_commitment = if *match_weight {
+            RistrettoPoint::multiscalar_mul(
+                &[simulated_response, simulated_challenge],
+                &[*poll_point, ciphertext1 - zero_point],
+            )
+        } else {
+            RistrettoPoint::multiscalar_mul(
+                &[simulated_response, simulated_challenge],
+                &[*poll_point, ciphertext1 - weight_point],
+            )
+        };
+        commitment_list.push(if *match_weight {
+            (real_commitment, simulated_commitment)
+        } else {
+            (simulated_commitment, real_commitment)
+        });
+        simulation_list.push((nonce, simulated_challenge, simulated_response));
+    }
+    let challenge = compute_aggregated_either_equality_challenge(
+        poll_point,
+        weight_ballot,
+        zero_ballot,
+        ballot_list,
+        &commitment_list,
+    );
+    let mut either_equality_proof = AggregatedEitherEqualityProof::new();
+    either_equality_proof.set_challenge(scalar_to_bytes(&challenge));
+    for (
+        (match_weight, difference_secret),
+        (nonce, simulated_challenge, simulated_response),
+    ) in secret_list.iter().zip(simulation_list)
+    {
+        let real_challenge = challenge - simulated_challenge;
+        let real_response = nonce - real_challenge * difference_secret;
+        let (weight_challenge, weight_response, zero_response) =
+            if *match_weight {
+                (real_challenge, real_response, simulated_response)
+            } else {
+                (simulated_challenge, simulated_response, real_response)
+            };
+        either_equality_proof
+            .mut_weight_challenge()
+            .push(scalar_to_bytes(&weight_challenge));
+        either_equality_proof
+            .mut_weight_response()
+            .push(scalar_to_bytes(&weight_response));
+        either_equality_proof
+            .mut_zero_response()
+            .push(scalar_to_bytes(&zero_response));
+    }
+    Ok(either_equality_proof)
+}
+
+/// Votes the ciphertext ballots in a quadratic poll, where voting w votes on a
+/// candidate costs w * w credits from the voter weight. It proves that each
+/// cost ballot commits the square of the vote value, and that the costs and
+/// the unused credits sum to the voter weight. The votes of a quadratic poll
+/// are aggregated and counted as usual, and the result has no abstained votes.
+pub fn vote_quadratic(
+    voter_secret: &VoterSecret,
+    vote_choices: &VoteChoices,
+    registration_response: &RegistrationResponse,
+    poll_parameters: &PollParametersStorage,
+    public_key: &[u8],
+) -> Result<VoteRequest, WedprError> {
+    check_poll_parameters(poll_parameters, public_key)?;
+    check_signature_scheme(registration_response, poll_parameters)?;
+    // Quadratic voting is only supported for the candidates of the poll.
+    if !vote_choices.get_question_choices().is_empty()
+        || poll_parameters.get_against_voting()
+    {
+        return Err(WedprError::ArgumentError);
+    }
+    let mut vote_request = VoteRequest::new();
+    let voter_weight =
+        get_voter_weight(voter_secret, poll_parameters, registration_response)?;
+    let vote_secret = bytes_to_scalar(voter_secret.get_voter_secret())?;
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+
+    // Compute for each choice.
+    let mut cost_sum = 0u64;
+    let mut cost_blinding_sum = Scalar::zero();
+    let mut blinding_list: Vec<Scalar> = Vec::new();
+    let mut value_list: Vec<u64> = Vec::new();
+    for choice_keypair in vote_choices.get_choice() {
+        let candidate_address = choice_keypair.get_candidate();
+        let value = choice_keypair.get_value() as u64;
+        cost_sum += value * value;
+        // Max voter credits have been used up.
+        if cost_sum > voter_weight as u64 {
+            return Err(WedprError::ArgumentError);
+        }
+
+        // Make a ciphertext ballot and the commitment of its cost.
         let blinding = get_random_scalar();
         let ciphertext1 = RistrettoPoint::multiscalar_mul(
-            &[Scalar::from(value as u64), blinding],
+            &[Scalar::from(value), blinding],
             &[*BASEPOINT_G1, poll_point],
         );
-        blinding_sum += blinding;
         let ciphertext2 = *BASEPOINT_G2 * blinding;
-        vote_ballot.set_ciphertext1(point_to_bytes(&ciphertext1));
-        vote_ballot.set_ciphertext2(point_to_bytes(&ciphertext2));
+        let cost_blinding = get_random_scalar();
+        let cost_point = RistrettoPoint::multiscalar_mul(
+            &[Scalar::from(value * value), cost_blinding],
+            &[*BASEPOINT_G1, poll_point],
+        );
+        cost_blinding_sum += cost_blinding;
 
-        // Prove ballot format.
+        // Prove the ballot format and the cost.
         let format_proof = prove_format_proof(
-            value as u64,
+            value,
             &blinding,
-            &*BASEPOINT_G1,
-            &*BASEPOINT_G2,
+            &BASEPOINT_G1,
+            &BASEPOINT_G2,
+            &poll_point,
+        );
+        let cost_proof = prove_product_relationship(
+            value,
+            value,
+            &blinding,
+            &blinding,
+            &cost_blinding,
+            &BASEPOINT_G1,
             &poll_point,
         );
         let mut ballot_proof = BallotProof::new();
         ballot_proof.set_format_proof(format_proof.serialize());
+        ballot_proof.set_cost_proof(cost_proof.serialize());
 
         // Write back.
         let mut proof_pair = StringToBallotProofPair::new();
@@ -173,52 +1326,76 @@ pub fn vote(
         proof_pair.set_value(ballot_proof);
         vote_request.mut_ballot_proof().push(proof_pair);
 
-        blinding_list.push(blinding);
-        choice_list.push(value as u64);
-
         let mut ballot_pair = CandidateBallot::new();
         ballot_pair.set_candidate(candidate_address.to_string());
-        ballot_pair.set_ballot(vote_ballot);
+        ballot_pair
+            .mut_ballot()
+            .set_ciphertext1(point_to_bytes(&ciphertext1));
+        ballot_pair
+            .mut_ballot()
+            .set_ciphertext2(point_to_bytes(&ciphertext2));
         vote_request.mut_vote().mut_voted_ballot().push(ballot_pair);
+
+        let mut cost_pair = CandidateBallot::new();
+        cost_pair.set_candidate(candidate_address.to_string());
+        cost_pair
+            .mut_ballot()
+            .set_ciphertext1(point_to_bytes(&cost_point));
+        vote_request.mut_vote().mut_cost_ballot().push(cost_pair);
+
+        blinding_list.push(blinding);
+        value_list.push(value);
     }
 
-    // Compute for the rest unused ballots.
-    let blinding_rest = get_random_scalar();
-    let rest_ballot = RistrettoPoint::multiscalar_mul(
-        &[Scalar::from(unused_vote_weight as u64), blinding_rest],
+    // Compute for the unused credits.
+    let unused_credit = voter_weight as u64 - cost_sum;
+    let blinding_unused = get_random_scalar();
+    let unused_credit_point = RistrettoPoint::multiscalar_mul(
+        &[Scalar::from(unused_credit), blinding_unused],
         &[*BASEPOINT_G1, poll_point],
     );
 
     // Prove the balance.
-    let used_vote_weight_sum = (registration_response.get_voter_weight()
-        - unused_vote_weight as u32) as u64;
-    let vote_secret = bytes_to_scalar(voter_secret.get_voter_secret())?;
     let balance_proof = prove_sum_relationship(
-        used_vote_weight_sum,
-        unused_vote_weight as u64,
-        &blinding_sum,
-        &blinding_rest,
+        cost_sum,
+        unused_credit,
+        &cost_blinding_sum,
+        &blinding_unused,
         &vote_secret,
         &BASEPOINT_G1,
         &poll_point,
     );
 
-    // Prove the range.
-    choice_list.push(unused_vote_weight as u64);
-    blinding_list.push(blinding_rest);
-    align_u64_list_if_needed(&mut choice_list);
+    // Prove the range of the vote values and the unused credits, so that the
+    // costs cannot be squares of negative values.
+    value_list.push(unused_credit);
+    blinding_list.push(blinding_unused);
+    align_u64_list_if_needed(&mut value_list);
     align_scalar_list_if_needed(&mut blinding_list);
     let (range_proof, _) =
-        prove_value_range_in_batch(&choice_list, &blinding_list, &poll_point)?;
+        prove_value_range_in_batch(&value_list, &blinding_list, &poll_point)?;
+
+    // Generate the nullifier.
+    let (nullifier, nullifier_proof) =
+        generate_nullifier(voter_secret, poll_parameters)?;
 
     // Write back.
     vote_request.set_sum_balance_proof(balance_proof.serialize());
     vote_request.set_range_proof(range_proof);
+    vote_request.set_nullifier_proof(nullifier_proof);
     let vote = vote_request.mut_vote();
+    vote.mut_unused_credit_ballot()
+        .set_ciphertext1(point_to_bytes(&unused_credit_point));
     vote.set_signature(registration_response.get_signature().to_vec());
-    vote.mut_rest_ballot()
-        .set_ciphertext1(point_to_bytes(&rest_ballot));
+    if registration_response.has_certificate() {
+        vote.set_certificate(registration_response.get_certificate().clone());
+    }
     vote.set_blank_ballot(registration_response.get_ballot().clone());
+    vote.set_nullifier(nullifier);
+    vote.set_sequence(vote_choices.get_sequence());
+    let sequence_binding_proof =
+        generate_sequence_binding(voter_secret, poll_parameters, vote)?;
+    vote_request.set_sequence_binding_proof(sequence_binding_proof);
     Ok(vote_request)
 }
 
@@ -271,27 +1448,96 @@ pub fn vote_unbounded(
     vote_choices: &VoteChoices,
     registration_response: &RegistrationResponse,
     poll_parameters: &PollParametersStorage,
+    public_key: &[u8],
 ) -> Result<VoteRequest, WedprError> {
+    make_unbounded_vote_request(
+        voter_secret,
+        zero_secret,
+        vote_choices,
+        registration_response,
+        poll_parameters,
+        public_key,
+        false,
+    )
+}
+
+/// Votes as vote_unbounded does, and proves the format of all candidate
+/// ballots and that each of them encrypts either the voter weight or zero with
+/// two aggregated proofs instead of the proofs for each ballot, which reduces
+/// the size of the vote request. The ballots of the unlisted candidates are
+/// not covered by the aggregated proofs.
+pub fn vote_unbounded_with_aggregated_proof(
+    voter_secret: &VoterSecret,
+    zero_secret: &VoterSecret,
+    vote_choices: &VoteChoices,
+    registration_response: &RegistrationResponse,
+    poll_parameters: &PollParametersStorage,
+    public_key: &[u8],
+) -> Result<VoteRequest, WedprError> {
+    make_unbounded_vote_request(
+        voter_secret,
+        zero_secret,
+        vote_choices,
+        registration_response,
+        poll_parameters,
+        public_key,
+        true,
+    )
+}
+
+fn make_unbounded_vote_request(
+    voter_secret: &VoterSecret,
+    zero_secret: &VoterSecret,
+    vote_choices: &VoteChoices,
+    registration_response: &RegistrationResponse,
+    poll_parameters: &PollParametersStorage,
+    public_key: &[u8],
+    aggregated_proof: bool,
+) -> Result<VoteRequest, WedprError> {
+    check_poll_parameters(poll_parameters, public_key)?;
+    check_signature_scheme(registration_response, poll_parameters)?;
     let mut vote_request = VoteRequest::new();
     let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+    let voter_weight = registration_response.get_voter_weight() as u64;
+    let weight_secret = bytes_to_scalar(voter_secret.get_voter_secret())?;
+    let zero_blinding = bytes_to_scalar(zero_secret.get_voter_secret())?;
+    let mut secret_list = Vec::new();
     // generate ballot for every vote choice
     for vote_choice in vote_choices.get_choice() {
-        let mut ballot_proof = BallotProof::new();
+        let vote_value = vote_choice.get_value() as u64;
         let mut vote_ballot = Ballot::new();
-        generate_ballot_proof(
-            vote_choice.get_value() as u64,
-            registration_response,
-            &poll_point,
-            voter_secret,
-            zero_secret,
-            &mut ballot_proof,
-            &mut vote_ballot,
-        )?;
-        // push ballot_proof
-        let mut proof_pair = StringToBallotProofPair::new();
-        proof_pair.set_key(vote_choice.get_candidate().to_owned());
-        proof_pair.set_value(ballot_proof);
-        vote_request.mut_ballot_proof().push(proof_pair);
+        if aggregated_proof {
+            // Each ballot must encrypt either the voter weight or zero.
+            if vote_value != voter_weight && vote_value != 0 {
+                return Err(WedprError::ArgumentError);
+            }
+            let blinding = get_random_scalar();
+            vote_ballot.set_ciphertext1(point_to_bytes(
+                &RistrettoPoint::multiscalar_mul(
+                    &[Scalar::from(vote_value), blinding],
+                    &[*BASEPOINT_G1, poll_point],
+                ),
+            ));
+            vote_ballot
+                .set_ciphertext2(point_to_bytes(&(*BASEPOINT_G2 * blinding)));
+            secret_list.push((vote_value, blinding));
+        } else {
+            let mut ballot_proof = BallotProof::new();
+            generate_ballot_proof(
+                vote_value,
+                registration_response,
+                &poll_point,
+                voter_secret,
+                zero_secret,
+                &mut ballot_proof,
+                &mut vote_ballot,
+            )?;
+            // push ballot_proof
+            let mut proof_pair = StringToBallotProofPair::new();
+            proof_pair.set_key(vote_choice.get_candidate().to_owned());
+            proof_pair.set_value(ballot_proof);
+            vote_request.mut_ballot_proof().push(proof_pair);
+        }
         // set ballot info
         let mut ballot_pair = CandidateBallot::new();
         ballot_pair.set_candidate(vote_choice.get_candidate().to_owned());
@@ -304,9 +1550,58 @@ pub fn vote_unbounded(
     vote_request
         .mut_vote()
         .set_zero_ballot(registration_response.get_zero_ballot().clone());
+    if aggregated_proof {
+        let ballot_list: Vec<&Ballot> = vote_request
+            .get_vote()
+            .get_voted_ballot()
+            .iter()
+            .map(|candidate_ballot| candidate_ballot.get_ballot())
+            .collect();
+        let format_proof = prove_aggregated_format_proof(
+            &poll_point,
+            &ballot_list,
+            &secret_list,
+        );
+        // The difference to the blank ballot or the zero ballot is blinded by
+        // the difference of the blindings.
+        let difference_secret_list: Vec<(bool, Scalar)> = secret_list
+            .iter()
+            .map(|(value, blinding)| {
+                if *value == voter_weight {
+                    (true, blinding - weight_secret)
+                } else {
+                    (false, blinding - zero_blinding)
+                }
+            })
+            .collect();
+        let either_equality_proof = prove_aggregated_either_equality_proof(
+            &poll_point,
+            registration_response.get_ballot(),
+            registration_response.get_zero_ballot(),
+            &ballot_list,
+            &difference_secret_list,
+        )?;
+        vote_request.set_aggregated_format_proof(format_proof);
+        vote_request
+            .set_aggregated_either_equality_proof(either_equality_proof);
+    }
     vote_request
         .mut_vote()
         .set_signature(registration_response.get_signature().to_vec());
+    // generate the nullifier with the secret of the weight ballot
+    let (nullifier, nullifier_proof) =
+        generate_nullifier(voter_secret, poll_parameters)?;
+    vote_request.mut_vote().set_nullifier(nullifier);
+    vote_request.set_nullifier_proof(nullifier_proof);
+    vote_request
+        .mut_vote()
+        .set_sequence(vote_choices.get_sequence());
+    let sequence_binding_proof = generate_sequence_binding(
+        voter_secret,
+        poll_parameters,
+        vote_request.mut_vote(),
+    )?;
+    vote_request.set_sequence_binding_proof(sequence_binding_proof);
     Ok(vote_request)
 }
 
@@ -326,12 +1621,58 @@ pub fn generate_candidate_cipher(
     Ok(cipher_point)
 }
 
+/// Encrypts an unlisted candidate id, and generates the ZKP proofs that the
+/// cipher is well formed and the id is in the valid range of the poll.
+pub fn generate_candidate_cipher_with_proof(
+    candidate_id: u32,
+    poll_parameters: &PollParametersStorage,
+    public_key: &[u8],
+) -> Result<(CipherPoints, Vec<u8>, Vec<u8>), WedprError> {
+    check_poll_parameters(poll_parameters, public_key)?;
+    let min_candidate_id = poll_parameters.get_min_unlisted_candidate_id();
+    let max_candidate_id = poll_parameters.get_max_unlisted_candidate_id();
+    if min_candidate_id == 0
+        || candidate_id < min_candidate_id
+        || candidate_id > max_candidate_id
+    {
+        return Err(WedprError::ArgumentError);
+    }
+    let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
+    let blinding = get_random_scalar();
+    let ciphertext1 = RistrettoPoint::multiscalar_mul(
+        &[Scalar::from(candidate_id as u64), blinding],
+        &[*BASEPOINT_G1, poll_point],
+    );
+    let ciphertext2 = *BASEPOINT_G2 * blinding;
+    let format_proof = prove_format_proof(
+        candidate_id as u64,
+        &blinding,
+        &BASEPOINT_G1,
+        &BASEPOINT_G2,
+        &poll_point,
+    );
+    // Prove that both (id - min) and (max - id) are not negative.
+    let (range_proof, _) = prove_value_range_in_batch(
+        &[
+            (candidate_id - min_candidate_id) as u64,
+            (max_candidate_id - candidate_id) as u64,
+        ],
+        &[blinding, -blinding],
+        &poll_point,
+    )?;
+    let mut cipher_point = CipherPoints::new();
+    cipher_point.set_ciphertext1(point_to_bytes(&ciphertext1));
+    cipher_point.set_ciphertext2(point_to_bytes(&ciphertext2));
+    Ok((cipher_point, format_proof.serialize(), range_proof))
+}
+
 pub fn vote_unbounded_unlisted(
     voter_secret: &VoterSecret,
     zero_secret: &VoterSecret,
     vote_choices: &VoteChoices,
     registration_response: &RegistrationResponse,
     poll_parameters: &PollParametersStorage,
+    public_key: &[u8],
 ) -> Result<VoteRequest, WedprError> {
     let mut vote_request = vote_unbounded(
         voter_secret,
@@ -339,6 +1680,7 @@ pub fn vote_unbounded_unlisted(
         vote_choices,
         registration_response,
         poll_parameters,
+        public_key,
     )?;
     let poll_point = bytes_to_point(poll_parameters.get_poll_point())?;
     for unlisted_vote_choice in vote_choices.get_unlisted_choice() {
@@ -356,12 +1698,16 @@ pub fn vote_unbounded_unlisted(
         // update the vote_request
         // push ballot_proof
         let mut proof_pair = CipherPointsToBallotProofPair::new();
-        let candidate_cipher = generate_candidate_cipher(
-            unlisted_vote_choice.get_candidate_id() as u64,
-            &poll_point,
-        )?;
+        let (candidate_cipher, candidate_format_proof, candidate_range_proof) =
+            generate_candidate_cipher_with_proof(
+                unlisted_vote_choice.get_candidate_id(),
+                poll_parameters,
+                public_key,
+            )?;
         proof_pair.set_key(candidate_cipher.clone());
         proof_pair.set_value(ballot_proof);
+        proof_pair.set_candidate_format_proof(candidate_format_proof);
+        proof_pair.set_candidate_range_proof(candidate_range_proof);
         vote_request.mut_unlisted_ballot_proof().push(proof_pair);
         // set the unlisted ballot info
         let mut unlisted_ballot = CipherPointsToBallotPair::new();
@@ -372,5 +1718,12 @@ pub fn vote_unbounded_unlisted(
             .mut_voted_ballot_unlisted()
             .push(unlisted_ballot);
     }
+    // bind the sequence number to the unlisted ballots as well
+    let sequence_binding_proof = generate_sequence_binding(
+        voter_secret,
+        poll_parameters,
+        vote_request.mut_vote(),
+    )?;
+    vote_request.set_sequence_binding_proof(sequence_binding_proof);
     Ok(vote_request)
 }